@@ -39,6 +39,20 @@ pub const HOME: &str = "HOME";
 pub const XDG_DATA_HOME: &str = "XDG_DATA_HOME";
 /// Environment variable: XDG config home directory.
 pub const XDG_CONFIG_HOME: &str = "XDG_CONFIG_HOME";
+/// Environment variable: Cargo home directory (registry cache).
+pub const CARGO_HOME: &str = "CARGO_HOME";
+/// Environment variable: Go module cache directory.
+pub const GOMODCACHE: &str = "GOMODCACHE";
+/// Environment variable: Go workspace path list.
+pub const GOPATH: &str = "GOPATH";
+/// Environment variable: author name git exports to hooks.
+pub const GIT_AUTHOR_NAME: &str = "GIT_AUTHOR_NAME";
+/// Environment variable: author email git exports to hooks.
+pub const GIT_AUTHOR_EMAIL: &str = "GIT_AUTHOR_EMAIL";
+/// Environment variable: source branch of a GitHub Actions pull request.
+pub const GITHUB_HEAD_REF: &str = "GITHUB_HEAD_REF";
+/// Environment variable: source branch of a GitLab CI merge request.
+pub const CI_MERGE_REQUEST_SOURCE_BRANCH_NAME: &str = "CI_MERGE_REQUEST_SOURCE_BRANCH_NAME";
 "#;
 
     fs::write(dest, contents).expect("failed to write env_names.rs");
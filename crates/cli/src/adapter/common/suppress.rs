@@ -4,18 +4,18 @@
 //! Common suppress directive utilities.
 
 /// Comment style configuration for different languages.
-pub struct CommentStyle {
+pub struct CommentStyle<'a> {
     /// Comment line prefix (e.g., "//" for Rust, "#" for Shell).
-    pub prefix: &'static str,
+    pub prefix: &'a str,
     /// Patterns that indicate a directive line, not a justification comment.
     /// Checked with `contains`.
-    pub directive_patterns: &'static [&'static str],
+    pub directive_patterns: &'a [&'a str],
     /// Line prefixes to skip when walking backward (e.g., "@" for Python decorators).
     /// Checked with `starts_with` on the trimmed line.
-    pub skip_prefixes: &'static [&'static str],
+    pub skip_prefixes: &'a [&'a str],
 }
 
-impl CommentStyle {
+impl CommentStyle<'static> {
     /// Rust comment style: `//` prefix, `#[` directives.
     pub const RUST: Self = Self { prefix: "//", directive_patterns: &["#["], skip_prefixes: &[] };
 
@@ -34,6 +34,44 @@ impl CommentStyle {
         skip_prefixes: &[],
     };
 
+    /// Java/Kotlin comment style: `//` prefix, `@Suppress`/`noinspection` directives.
+    /// Skips other annotations between the justification and the directive.
+    pub const JVM: Self = Self {
+        prefix: "//",
+        directive_patterns: &["noinspection", "@Suppress"],
+        skip_prefixes: &["@"],
+    };
+
+    /// C/C++ comment style: `//` prefix, clang-tidy `NOLINT` directives.
+    pub const CPP: Self =
+        Self { prefix: "//", directive_patterns: &["NOLINT"], skip_prefixes: &[] };
+
+    /// C# comment style: `//` prefix, `#pragma warning`/`SuppressMessage` directives.
+    /// Skips `[Attribute]` lines when walking backward for justification comments.
+    pub const CSHARP: Self = Self {
+        prefix: "//",
+        directive_patterns: &["#pragma warning", "SuppressMessage"],
+        skip_prefixes: &["["],
+    };
+
+    /// PHP comment style: `//` prefix, phpstan/psalm/phpcs directives.
+    /// Skips `#[Attribute]` lines when walking backward for justification comments.
+    pub const PHP: Self = Self {
+        prefix: "//",
+        directive_patterns: &["@phpstan-", "@psalm-", "phpcs:"],
+        skip_prefixes: &["#["],
+    };
+
+    /// Elixir comment style: `#` prefix, Credo directives.
+    /// Skips `@doc`/`@spec` module attributes when walking backward for justification comments.
+    pub const ELIXIR: Self =
+        Self { prefix: "#", directive_patterns: &["credo:"], skip_prefixes: &["@"] };
+
+    /// Swift comment style: `//` prefix, SwiftLint directives.
+    /// Skips `@attribute` lines when walking backward for justification comments.
+    pub const SWIFT: Self =
+        Self { prefix: "//", directive_patterns: &["swiftlint:"], skip_prefixes: &["@"] };
+
     /// Python comment style: `#` prefix, noqa/type/pylint/pragma directives.
     /// Skips `@decorator` lines when walking backward for justification comments.
     pub const PYTHON: Self = Self {
@@ -61,6 +99,21 @@ pub fn check_justification_comment(
     directive_line: usize,
     required_pattern: Option<&str>,
     style: &CommentStyle,
+) -> (bool, Option<String>) {
+    check_justification_comment_with(lines, directive_line, required_pattern, style, |line| {
+        style.directive_patterns.iter().any(|p| line.contains(p))
+    })
+}
+
+/// Like [`check_justification_comment`], with a custom test for directive lines.
+///
+/// Used when directives are recognized by regex rather than fixed substrings.
+pub fn check_justification_comment_with(
+    lines: &[&str],
+    directive_line: usize,
+    required_pattern: Option<&str>,
+    style: &CommentStyle,
+    is_directive: impl Fn(&str) -> bool,
 ) -> (bool, Option<String>) {
     let mut check_line = directive_line;
 
@@ -75,7 +128,7 @@ pub fn check_justification_comment(
 
         // Skip directive lines (not justification comments)
         // This includes: Rust attributes (#[...]), Shell shebangs (#!/...), etc.
-        if style.directive_patterns.iter().any(|p| line.contains(p)) {
+        if is_directive(line) {
             continue;
         }
 
@@ -0,0 +1,150 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! C/C++ language adapter.
+//!
+//! Provides C- and C++-specific behavior for checks:
+//! - File classification (`tests/`, `*_test.cc`, GoogleTest `*_unittest.cc`)
+//! - Default patterns for C/C++ sources and headers
+//! - Default escape patterns (`reinterpret_cast`, `const_cast`, `goto`,
+//!   `#pragma ... diagnostic ignored`)
+//! - clang-tidy `// NOLINT` directive parsing
+//!
+//! See docs/specs/langs/cpp.md for specification.
+
+use std::path::Path;
+
+use globset::GlobSet;
+
+mod suppress;
+
+pub use suppress::{NolintKind, NolintSuppress, parse_cpp_suppresses};
+
+use super::common;
+use super::common::patterns::normalize_exclude_patterns;
+use super::glob::build_glob_set;
+use super::{Adapter, EscapeAction, EscapePattern, FileKind};
+use crate::config::CppConfig;
+
+/// Default escape patterns for C/C++.
+const CPP_ESCAPE_PATTERNS: &[EscapePattern] = &[
+    EscapePattern {
+        name: "reinterpret_cast",
+        pattern: r"\breinterpret_cast\s*<",
+        action: EscapeAction::Comment,
+        comment: Some("// SAFETY:"),
+        advice: "Add a // SAFETY: comment explaining why the reinterpreted type is valid for this memory.",
+        in_tests: None,
+    },
+    EscapePattern {
+        name: "const_cast",
+        pattern: r"\bconst_cast\s*<",
+        action: EscapeAction::Comment,
+        comment: Some("// SAFETY:"),
+        advice: "Add a // SAFETY: comment explaining why the object is never modified through the cast, or fix the const-correctness of the API.",
+        in_tests: None,
+    },
+    EscapePattern {
+        name: "goto",
+        pattern: r"\bgoto\s+\w+\s*;",
+        action: EscapeAction::Comment,
+        comment: Some("// GOTO:"),
+        advice: "Add a // GOTO: comment explaining the jump (e.g. shared cleanup path), or restructure with early returns or RAII.",
+        in_tests: None,
+    },
+    EscapePattern {
+        name: "pragma_diagnostic_ignored",
+        pattern: r"#\s*pragma\s+(?:GCC|clang)\s+diagnostic\s+ignored\b",
+        action: EscapeAction::Comment,
+        comment: Some("// SUPPRESS:"),
+        advice: "Add a // SUPPRESS: comment explaining why the compiler warning is a false positive.",
+        in_tests: None,
+    },
+];
+
+/// C/C++ language adapter.
+pub struct CppAdapter {
+    source_patterns: GlobSet,
+    test_patterns: GlobSet,
+    exclude_patterns: GlobSet,
+}
+
+impl CppAdapter {
+    /// Create a new C/C++ adapter with default patterns.
+    pub fn new() -> Self {
+        Self {
+            source_patterns: build_glob_set(&CppConfig::default_source()),
+            test_patterns: build_glob_set(&CppConfig::default_tests()),
+            exclude_patterns: build_glob_set(&normalize_exclude_patterns(
+                &CppConfig::default_exclude(),
+            )),
+        }
+    }
+
+    /// Create a C/C++ adapter with resolved patterns from config.
+    pub fn with_patterns(patterns: super::ResolvedPatterns) -> Self {
+        let exclude_globs = normalize_exclude_patterns(&patterns.exclude);
+
+        Self {
+            source_patterns: build_glob_set(&patterns.source),
+            test_patterns: build_glob_set(&patterns.test),
+            exclude_patterns: build_glob_set(&exclude_globs),
+        }
+    }
+
+    /// Check if a path matches exclude patterns.
+    pub fn should_exclude(&self, path: &Path) -> bool {
+        common::patterns::check_exclude_patterns(
+            path,
+            &self.exclude_patterns,
+            Some(&["build", "out", "third_party", "vendor"]),
+        )
+    }
+}
+
+impl Default for CppAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Adapter for CppAdapter {
+    fn name(&self) -> &'static str {
+        "cpp"
+    }
+
+    fn extensions(&self) -> &'static [&'static str] {
+        &["c", "h", "cc", "cpp", "cxx", "hh", "hpp", "hxx"]
+    }
+
+    fn classify(&self, path: &Path) -> FileKind {
+        // Check exclude patterns first
+        if self.should_exclude(path) {
+            return FileKind::Other;
+        }
+
+        // Test patterns take precedence
+        if self.test_patterns.is_match(path) {
+            return FileKind::Test;
+        }
+
+        // Source patterns
+        if self.source_patterns.is_match(path) {
+            return FileKind::Source;
+        }
+
+        FileKind::Other
+    }
+
+    fn default_escapes(&self) -> &'static [EscapePattern] {
+        CPP_ESCAPE_PATTERNS
+    }
+
+    fn declaration_keywords(&self) -> &'static [&'static str] {
+        &["class", "struct", "enum", "namespace"]
+    }
+}
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Unit tests for the C/C++ adapter.
+
+use std::path::Path;
+
+use super::*;
+
+#[test]
+fn classifies_sources_and_headers_as_source() {
+    let adapter = CppAdapter::new();
+    assert_eq!(adapter.classify(Path::new("src/parser.c")), FileKind::Source);
+    assert_eq!(adapter.classify(Path::new("src/parser.h")), FileKind::Source);
+    assert_eq!(adapter.classify(Path::new("lib/engine/core.cpp")), FileKind::Source);
+    assert_eq!(adapter.classify(Path::new("include/engine/core.hpp")), FileKind::Source);
+    assert_eq!(adapter.classify(Path::new("ext/native.cc")), FileKind::Source);
+}
+
+#[test]
+fn classifies_test_directories_as_test() {
+    let adapter = CppAdapter::new();
+    assert_eq!(adapter.classify(Path::new("tests/parser_check.c")), FileKind::Test);
+    assert_eq!(adapter.classify(Path::new("test/helpers.h")), FileKind::Test);
+    assert_eq!(adapter.classify(Path::new("engine/tests/core.cpp")), FileKind::Test);
+}
+
+#[test]
+fn classifies_test_named_files_as_test() {
+    let adapter = CppAdapter::new();
+    assert_eq!(adapter.classify(Path::new("src/parser_test.cc")), FileKind::Test);
+    assert_eq!(adapter.classify(Path::new("src/test_parser.c")), FileKind::Test);
+    assert_eq!(adapter.classify(Path::new("src/core_unittest.cc")), FileKind::Test);
+}
+
+#[test]
+fn ignores_build_and_vendored_code() {
+    let adapter = CppAdapter::new();
+    assert_eq!(adapter.classify(Path::new("build/generated/version.h")), FileKind::Other);
+    assert_eq!(adapter.classify(Path::new("cmake-build-debug/config.h")), FileKind::Other);
+    assert_eq!(adapter.classify(Path::new("third_party/zlib/inflate.c")), FileKind::Other);
+}
+
+#[test]
+fn non_cpp_files_are_other() {
+    let adapter = CppAdapter::new();
+    assert_eq!(adapter.classify(Path::new("CMakeLists.txt")), FileKind::Other);
+    assert_eq!(adapter.classify(Path::new("README.md")), FileKind::Other);
+}
+
+#[test]
+fn handles_c_and_cpp_extensions() {
+    let adapter = CppAdapter::new();
+    assert!(adapter.extensions().contains(&"c"));
+    assert!(adapter.extensions().contains(&"hpp"));
+    assert_eq!(adapter.name(), "cpp");
+}
+
+// =============================================================================
+// DEFAULT ESCAPE PATTERNS
+// =============================================================================
+
+fn escape_regex(name: &str) -> regex::Regex {
+    let pattern = CPP_ESCAPE_PATTERNS.iter().find(|p| p.name == name).unwrap().pattern;
+    regex::Regex::new(pattern).unwrap()
+}
+
+#[test]
+fn cast_patterns() {
+    let re = escape_regex("reinterpret_cast");
+    assert!(re.is_match("auto* p = reinterpret_cast<uint8_t*>(buf);"));
+    assert!(!re.is_match("auto p = static_cast<int>(x);"));
+
+    let re = escape_regex("const_cast");
+    assert!(re.is_match("call(const_cast<char*>(s));"));
+}
+
+#[test]
+fn goto_pattern() {
+    let re = escape_regex("goto");
+    assert!(re.is_match("    goto cleanup;"));
+    assert!(!re.is_match("int gotoCount = 0;"));
+    assert!(!re.is_match("// we goto the store"));
+}
+
+#[test]
+fn pragma_diagnostic_ignored_pattern() {
+    let re = escape_regex("pragma_diagnostic_ignored");
+    assert!(re.is_match("#pragma GCC diagnostic ignored \"-Wunused-parameter\""));
+    assert!(re.is_match("#  pragma clang diagnostic ignored \"-Wdeprecated\""));
+    assert!(!re.is_match("#pragma GCC diagnostic push"));
+    assert!(!re.is_match("#pragma once"));
+}
@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! clang-tidy NOLINT directive parsing.
+//!
+//! Parses `// NOLINT`, `// NOLINT(check)`, `// NOLINTNEXTLINE(check)` and
+//! `// NOLINTBEGIN(check)` comments in C/C++ source. `NOLINTEND` closes a
+//! region and is not counted separately.
+
+use crate::adapter::common::suppress::{CommentStyle, check_justification_comment};
+
+/// NOLINT directive found in C/C++ source.
+#[derive(Debug, Clone)]
+pub struct NolintSuppress {
+    /// Line number (0-indexed).
+    pub line: usize,
+    /// Directive type: `NOLINT`, `NOLINTNEXTLINE`, or `NOLINTBEGIN`.
+    pub kind: NolintKind,
+    /// clang-tidy checks being suppressed (empty = all checks).
+    pub codes: Vec<String>,
+    /// Whether a justification comment was found.
+    pub has_comment: bool,
+    /// The comment text if found.
+    pub comment_text: Option<String>,
+}
+
+/// Kind of NOLINT directive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NolintKind {
+    /// `NOLINT`: suppresses diagnostics on the same line.
+    Nolint,
+    /// `NOLINTNEXTLINE`: suppresses diagnostics on the following line.
+    NextLine,
+    /// `NOLINTBEGIN`: suppresses diagnostics until `NOLINTEND`.
+    Begin,
+}
+
+impl std::fmt::Display for NolintKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Nolint => write!(f, "NOLINT"),
+            Self::NextLine => write!(f, "NOLINTNEXTLINE"),
+            Self::Begin => write!(f, "NOLINTBEGIN"),
+        }
+    }
+}
+
+/// Parse NOLINT directives from C/C++ source.
+pub fn parse_cpp_suppresses(content: &str, comment_pattern: Option<&str>) -> Vec<NolintSuppress> {
+    let mut suppresses = Vec::new();
+    let lines: Vec<&str> = content.lines().collect();
+
+    for (line_idx, line) in lines.iter().enumerate() {
+        let Some(parsed) = parse_nolint_line(line) else {
+            continue;
+        };
+
+        let (has_comment, comment_text) = if let Some(reason) = parsed.inline_reason {
+            // Text after the directive counts as justification:
+            // `// NOLINT(check): reason`
+            (true, Some(reason))
+        } else {
+            check_justification_comment(&lines, line_idx, comment_pattern, &CommentStyle::CPP)
+        };
+
+        suppresses.push(NolintSuppress {
+            line: line_idx,
+            kind: parsed.kind,
+            codes: parsed.codes,
+            has_comment,
+            comment_text,
+        });
+    }
+
+    suppresses
+}
+
+/// Parsed NOLINT directive from a single line.
+struct ParsedNolint {
+    kind: NolintKind,
+    codes: Vec<String>,
+    inline_reason: Option<String>,
+}
+
+/// Parse a NOLINT directive from a single line.
+///
+/// The directive must start a `//` or `/* */` comment. Returns None for lines
+/// without a directive and for `NOLINTEND`.
+fn parse_nolint_line(line: &str) -> Option<ParsedNolint> {
+    let (pos, _) = line.match_indices("NOLINT").find(|(pos, _)| {
+        let before = line[..*pos].trim_end();
+        before.ends_with("//") || before.ends_with("/*")
+    })?;
+    let rest = &line[pos + "NOLINT".len()..];
+
+    let (kind, rest) = if let Some(rest) = rest.strip_prefix("NEXTLINE") {
+        (NolintKind::NextLine, rest)
+    } else if let Some(rest) = rest.strip_prefix("BEGIN") {
+        (NolintKind::Begin, rest)
+    } else if rest.starts_with("END") {
+        return None;
+    } else {
+        (NolintKind::Nolint, rest)
+    };
+
+    // Reject longer identifiers like NOLINTFOO
+    if rest.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_') {
+        return None;
+    }
+
+    let (codes, rest) = match rest.strip_prefix('(').and_then(|r| r.split_once(')')) {
+        Some((codes, rest)) => (
+            codes.split(',').map(str::trim).filter(|c| !c.is_empty()).map(String::from).collect(),
+            rest,
+        ),
+        None => (Vec::new(), rest),
+    };
+
+    let reason = rest
+        .trim()
+        .trim_end_matches("*/")
+        .trim_start_matches([':', '-'])
+        .trim_start_matches("//")
+        .trim();
+    let inline_reason = (!reason.is_empty()).then(|| reason.to_string());
+
+    Some(ParsedNolint { kind, codes, inline_reason })
+}
+
+#[cfg(test)]
+#[path = "suppress_tests.rs"]
+mod tests;
@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Unit tests for clang-tidy NOLINT directive parsing.
+
+use super::*;
+
+#[test]
+fn parse_bare_nolint() {
+    let content = "int x = legacy(); // NOLINT";
+    let suppresses = parse_cpp_suppresses(content, None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert_eq!(suppresses[0].kind, NolintKind::Nolint);
+    assert!(suppresses[0].codes.is_empty());
+    assert!(!suppresses[0].has_comment);
+}
+
+#[test]
+fn parse_nolint_with_checks() {
+    let content = "auto p = (char*)buf; // NOLINT(cppcoreguidelines-pro-type-cstyle-cast, google-readability-casting)";
+    let suppresses = parse_cpp_suppresses(content, None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert_eq!(
+        suppresses[0].codes,
+        vec!["cppcoreguidelines-pro-type-cstyle-cast", "google-readability-casting"]
+    );
+}
+
+#[test]
+fn parse_nolintnextline() {
+    let content = "// NOLINTNEXTLINE(bugprone-narrowing-conversions)\nint y = big;";
+    let suppresses = parse_cpp_suppresses(content, None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert_eq!(suppresses[0].kind, NolintKind::NextLine);
+    assert_eq!(suppresses[0].line, 0);
+    assert_eq!(suppresses[0].codes, vec!["bugprone-narrowing-conversions"]);
+}
+
+#[test]
+fn parse_nolint_region_counts_begin_only() {
+    let content = "// NOLINTBEGIN(readability-magic-numbers)\nint a = 42;\n// NOLINTEND(readability-magic-numbers)";
+    let suppresses = parse_cpp_suppresses(content, None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert_eq!(suppresses[0].kind, NolintKind::Begin);
+    assert_eq!(suppresses[0].codes, vec!["readability-magic-numbers"]);
+}
+
+#[test]
+fn parse_block_comment_nolint() {
+    let content = "int x = legacy(); /* NOLINT(cert-err33-c) */";
+    let suppresses = parse_cpp_suppresses(content, None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert_eq!(suppresses[0].codes, vec!["cert-err33-c"]);
+    assert!(!suppresses[0].has_comment);
+}
+
+#[test]
+fn inline_reason_is_justification() {
+    let content = "int x = legacy(); // NOLINT(cert-err33-c): return value is always 0";
+    let suppresses = parse_cpp_suppresses(content, None);
+
+    assert!(suppresses[0].has_comment);
+    assert_eq!(suppresses[0].comment_text.as_deref(), Some("return value is always 0"));
+}
+
+#[test]
+fn comment_above_is_justification() {
+    let content = "// The C API takes a mutable pointer but never writes through it\n// NOLINTNEXTLINE(cppcoreguidelines-pro-type-const-cast)\ncall(const_cast<char*>(s));";
+    let suppresses = parse_cpp_suppresses(content, None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert!(suppresses[0].has_comment);
+}
+
+#[test]
+fn comment_pattern_required() {
+    let content = "// just because\n// NOLINTNEXTLINE(misc-no-recursion)\nvoid walk();";
+    let suppresses = parse_cpp_suppresses(content, Some("// RECURSION:"));
+    assert!(!suppresses[0].has_comment);
+
+    let content = "// RECURSION: depth is bounded by the tree height\n// NOLINTNEXTLINE(misc-no-recursion)\nvoid walk();";
+    let suppresses = parse_cpp_suppresses(content, Some("// RECURSION:"));
+    assert!(suppresses[0].has_comment);
+}
+
+#[test]
+fn nolint_outside_comment_is_ignored() {
+    let content = "const char* s = \"NOLINT\";\n#define NOLINT_WRAPPER 1";
+    assert!(parse_cpp_suppresses(content, None).is_empty());
+}
+
+#[test]
+fn nolint_prefixed_identifiers_are_ignored() {
+    let content = "// NOLINTFOO(bar)";
+    assert!(parse_cpp_suppresses(content, None).is_empty());
+}
@@ -0,0 +1,143 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! C# language adapter.
+//!
+//! Provides C#-specific behavior for checks:
+//! - File classification (`*.Tests` projects, `*Tests.cs`)
+//! - Default patterns for C# sources
+//! - Default escape patterns (`unsafe`, `!` null-forgiving operator, `dynamic`)
+//! - `#pragma warning disable` and `[SuppressMessage]` parsing
+//!
+//! See docs/specs/langs/csharp.md for specification.
+
+use std::path::Path;
+
+use globset::GlobSet;
+
+mod suppress;
+
+pub use suppress::{CSharpSuppress, CSharpSuppressKind, parse_csharp_suppresses};
+
+use super::common;
+use super::common::patterns::normalize_exclude_patterns;
+use super::glob::build_glob_set;
+use super::{Adapter, EscapeAction, EscapePattern, FileKind};
+use crate::config::CSharpConfig;
+
+/// Default escape patterns for C#.
+const CSHARP_ESCAPE_PATTERNS: &[EscapePattern] = &[
+    // `unsafe { ... }` blocks and `unsafe` members/types, but not prose like "unsafe code"
+    EscapePattern {
+        name: "unsafe",
+        pattern: r"\bunsafe\s*\{|\bunsafe\s+(?:(?:static|extern|override|virtual|sealed|partial|readonly|new|public|private|protected|internal)\s+)*(?:void|class|struct|interface|\w+\s*\*|\w+(?:<[^>]*>)?\s+\w+\s*[(;={])",
+        action: EscapeAction::Comment,
+        comment: Some("// SAFETY:"),
+        advice: "Add a // SAFETY: comment explaining why the pointer access stays within valid memory.",
+        in_tests: None,
+    },
+    // Null-forgiving operator (`value!.Name`, `null!;`), ignoring `!=` and "Hi!"
+    EscapePattern {
+        name: "null_forgiving",
+        pattern: r"[\w)\]]![.),;\[\]]",
+        action: EscapeAction::Comment,
+        comment: Some("// SAFETY:"),
+        advice: "Add a // SAFETY: comment explaining why the value can't be null, or handle null with ?. / ?? instead.",
+        in_tests: None,
+    },
+    EscapePattern {
+        name: "dynamic",
+        pattern: r"\bdynamic(?:\s+[A-Za-z_]\w*\s*[=;,)]|\s*[>)\[\],])",
+        action: EscapeAction::Comment,
+        comment: Some("// DYNAMIC:"),
+        advice: "Add a // DYNAMIC: comment explaining why static typing isn't possible here (e.g. COM interop).",
+        in_tests: None,
+    },
+];
+
+/// C# language adapter.
+pub struct CSharpAdapter {
+    source_patterns: GlobSet,
+    test_patterns: GlobSet,
+    exclude_patterns: GlobSet,
+}
+
+impl CSharpAdapter {
+    /// Create a new C# adapter with default patterns.
+    pub fn new() -> Self {
+        Self {
+            source_patterns: build_glob_set(&CSharpConfig::default_source()),
+            test_patterns: build_glob_set(&CSharpConfig::default_tests()),
+            exclude_patterns: build_glob_set(&normalize_exclude_patterns(
+                &CSharpConfig::default_exclude(),
+            )),
+        }
+    }
+
+    /// Create a C# adapter with resolved patterns from config.
+    pub fn with_patterns(patterns: super::ResolvedPatterns) -> Self {
+        let exclude_globs = normalize_exclude_patterns(&patterns.exclude);
+
+        Self {
+            source_patterns: build_glob_set(&patterns.source),
+            test_patterns: build_glob_set(&patterns.test),
+            exclude_patterns: build_glob_set(&exclude_globs),
+        }
+    }
+
+    /// Check if a path matches exclude patterns.
+    pub fn should_exclude(&self, path: &Path) -> bool {
+        common::patterns::check_exclude_patterns(
+            path,
+            &self.exclude_patterns,
+            Some(&["bin", "obj", "packages", ".vs", "TestResults"]),
+        )
+    }
+}
+
+impl Default for CSharpAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Adapter for CSharpAdapter {
+    fn name(&self) -> &'static str {
+        "csharp"
+    }
+
+    fn extensions(&self) -> &'static [&'static str] {
+        &["cs"]
+    }
+
+    fn classify(&self, path: &Path) -> FileKind {
+        // Check exclude patterns first
+        if self.should_exclude(path) {
+            return FileKind::Other;
+        }
+
+        // Test patterns take precedence
+        if self.test_patterns.is_match(path) {
+            return FileKind::Test;
+        }
+
+        // Source patterns
+        if self.source_patterns.is_match(path) {
+            return FileKind::Source;
+        }
+
+        FileKind::Other
+    }
+
+    fn default_escapes(&self) -> &'static [EscapePattern] {
+        CSHARP_ESCAPE_PATTERNS
+    }
+
+    fn declaration_keywords(&self) -> &'static [&'static str] {
+        &["class", "struct", "interface", "enum", "record"]
+    }
+}
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
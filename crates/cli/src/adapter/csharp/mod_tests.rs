@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Unit tests for the C# adapter.
+
+use std::path::Path;
+
+use super::*;
+
+#[test]
+fn classifies_cs_files_as_source() {
+    let adapter = CSharpAdapter::new();
+    assert_eq!(adapter.classify(Path::new("src/Acme.Core/Parser.cs")), FileKind::Source);
+    assert_eq!(adapter.classify(Path::new("Program.cs")), FileKind::Source);
+}
+
+#[test]
+fn classifies_test_projects_as_test() {
+    let adapter = CSharpAdapter::new();
+    assert_eq!(adapter.classify(Path::new("tests/Acme.Core.Tests/ParserFacts.cs")), FileKind::Test);
+    assert_eq!(adapter.classify(Path::new("Acme.Core.UnitTests/Helpers.cs")), FileKind::Test);
+    assert_eq!(
+        adapter.classify(Path::new("src/Acme.Api.IntegrationTests/Startup.cs")),
+        FileKind::Test
+    );
+}
+
+#[test]
+fn classifies_test_named_files_as_test() {
+    let adapter = CSharpAdapter::new();
+    assert_eq!(adapter.classify(Path::new("src/Acme.Core/ParserTests.cs")), FileKind::Test);
+    assert_eq!(adapter.classify(Path::new("src/Acme.Core/ParserTest.cs")), FileKind::Test);
+}
+
+#[test]
+fn ignores_build_output() {
+    let adapter = CSharpAdapter::new();
+    assert_eq!(adapter.classify(Path::new("src/Acme.Core/obj/Debug/Gen.cs")), FileKind::Other);
+    assert_eq!(adapter.classify(Path::new("bin/Release/Acme.cs")), FileKind::Other);
+    assert_eq!(adapter.classify(Path::new("packages/Newtonsoft/Json.cs")), FileKind::Other);
+}
+
+#[test]
+fn non_cs_files_are_other() {
+    let adapter = CSharpAdapter::new();
+    assert_eq!(adapter.classify(Path::new("Acme.sln")), FileKind::Other);
+    assert_eq!(adapter.classify(Path::new("src/Acme.Core/Acme.Core.csproj")), FileKind::Other);
+    assert_eq!(adapter.name(), "csharp");
+}
+
+// =============================================================================
+// DEFAULT ESCAPE PATTERNS
+// =============================================================================
+
+fn escape_regex(name: &str) -> regex::Regex {
+    let pattern = CSHARP_ESCAPE_PATTERNS.iter().find(|p| p.name == name).unwrap().pattern;
+    regex::Regex::new(pattern).unwrap()
+}
+
+#[test]
+fn unsafe_pattern() {
+    let re = escape_regex("unsafe");
+    assert!(re.is_match("        unsafe {"));
+    assert!(re.is_match("    unsafe{ p[0] = 1; }"));
+    assert!(re.is_match("public unsafe void Copy(byte* src)"));
+    assert!(re.is_match("internal unsafe struct Buffer"));
+    assert!(re.is_match("private static unsafe int Sum(int* values, int n)"));
+    assert!(re.is_match("unsafe byte* ptr;"));
+    assert!(!re.is_match("// this is unsafe code"));
+    assert!(!re.is_match("var message = \"unsafe operation\";"));
+}
+
+#[test]
+fn null_forgiving_pattern() {
+    let re = escape_regex("null_forgiving");
+    assert!(re.is_match("var name = user!.Name;"));
+    assert!(re.is_match("public string Name { get; set; } = null!;"));
+    assert!(re.is_match("Process(items[0]!);"));
+    assert!(re.is_match("return Find(id)!.Value;"));
+    assert!(!re.is_match("if (a != b) return;"));
+    assert!(!re.is_match("if (!ready) return;"));
+    assert!(!re.is_match("Console.WriteLine(\"Done!\");"));
+}
+
+#[test]
+fn dynamic_pattern() {
+    let re = escape_regex("dynamic");
+    assert!(re.is_match("dynamic excel = Activator.CreateInstance(type);"));
+    assert!(re.is_match("var items = new List<dynamic>();"));
+    assert!(re.is_match("object Invoke(dynamic target, string name)"));
+    assert!(re.is_match("var x = (dynamic)value;"));
+    assert!(!re.is_match("// dynamic dispatch is handled elsewhere"));
+    assert!(!re.is_match("var dynamicRange = true;"));
+}
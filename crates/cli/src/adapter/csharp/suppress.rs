@@ -0,0 +1,216 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! C# suppress directive parsing.
+//!
+//! Parses `#pragma warning disable CS0618, IDE0051` directives and
+//! `[SuppressMessage("Category", "CA1822:MarkMembersAsStatic")]` attributes
+//! (including `[assembly: SuppressMessage(...)]`). `#pragma warning restore`
+//! closes a region and is not counted separately.
+
+use crate::adapter::common::suppress::{CommentStyle, check_justification_comment};
+
+/// Maximum lines a `[SuppressMessage(...)]` argument list may span.
+const MAX_ATTRIBUTE_LINES: usize = 10;
+
+/// Suppress directive found in C# source.
+#[derive(Debug, Clone)]
+pub struct CSharpSuppress {
+    /// Line number (0-indexed).
+    pub line: usize,
+    /// Directive type: `#pragma warning disable` or `[SuppressMessage]`.
+    pub kind: CSharpSuppressKind,
+    /// Diagnostic IDs being suppressed (empty = all warnings).
+    pub codes: Vec<String>,
+    /// Whether a justification comment was found.
+    pub has_comment: bool,
+    /// The comment text if found.
+    pub comment_text: Option<String>,
+}
+
+/// Kind of C# suppress directive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CSharpSuppressKind {
+    /// `#pragma warning disable` directive.
+    PragmaWarning,
+    /// `[SuppressMessage(...)]` attribute.
+    SuppressMessage,
+}
+
+impl std::fmt::Display for CSharpSuppressKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PragmaWarning => write!(f, "#pragma warning disable"),
+            Self::SuppressMessage => write!(f, "SuppressMessage"),
+        }
+    }
+}
+
+/// Parse `#pragma warning disable` and `[SuppressMessage]` directives from C# source.
+pub fn parse_csharp_suppresses(
+    content: &str,
+    comment_pattern: Option<&str>,
+) -> Vec<CSharpSuppress> {
+    let mut suppresses = Vec::new();
+    let lines: Vec<&str> = content.lines().collect();
+
+    for (line_idx, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        let parsed = if let Some(parsed) = parse_pragma_warning(trimmed) {
+            Some((CSharpSuppressKind::PragmaWarning, parsed))
+        } else if trimmed.starts_with('[') {
+            parse_suppress_message(&lines, line_idx)
+                .map(|p| (CSharpSuppressKind::SuppressMessage, p))
+        } else {
+            None
+        };
+
+        let Some((kind, parsed)) = parsed else {
+            continue;
+        };
+
+        let (has_comment, comment_text) = match parsed.justification {
+            // `#pragma warning disable CS0618 // reason` or `Justification = "reason"`
+            Some(reason) => (true, Some(reason)),
+            None => check_justification_comment(
+                &lines,
+                line_idx,
+                comment_pattern,
+                &CommentStyle::CSHARP,
+            ),
+        };
+
+        suppresses.push(CSharpSuppress {
+            line: line_idx,
+            kind,
+            codes: parsed.codes,
+            has_comment,
+            comment_text,
+        });
+    }
+
+    suppresses
+}
+
+/// Parsed directive from a single line or attribute.
+struct ParsedDirective {
+    codes: Vec<String>,
+    justification: Option<String>,
+}
+
+/// Parse `#pragma warning disable A, B // reason`.
+///
+/// Returns None for other directives, including `#pragma warning restore`.
+fn parse_pragma_warning(trimmed: &str) -> Option<ParsedDirective> {
+    let rest = trimmed.strip_prefix('#')?.trim_start();
+    let rest = rest.strip_prefix("pragma")?;
+    let rest = rest.trim_start().strip_prefix("warning")?;
+    let rest = rest.trim_start().strip_prefix("disable")?;
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    let (codes, reason) = match rest.split_once("//") {
+        Some((codes, reason)) => (codes, reason.trim()),
+        None => (rest, ""),
+    };
+    let codes = codes
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+        .collect();
+    let justification = (!reason.is_empty()).then(|| reason.to_string());
+
+    Some(ParsedDirective { codes, justification })
+}
+
+/// Parse a `[SuppressMessage(...)]` attribute starting on `line_idx`.
+///
+/// The rule is the second positional argument (or `CheckId = "..."`), reported
+/// without its title: `"CA1822:MarkMembersAsStatic"` -> `CA1822`. A non-empty
+/// `Justification = "..."` argument justifies the suppression.
+fn parse_suppress_message(lines: &[&str], line_idx: usize) -> Option<ParsedDirective> {
+    let line = lines[line_idx];
+    let start = ["SuppressMessage(", "SuppressMessageAttribute("]
+        .iter()
+        .filter_map(|marker| line.find(marker).map(|pos| pos + marker.len()))
+        .min()?;
+
+    let text = lines[line_idx..].iter().take(MAX_ATTRIBUTE_LINES).copied().collect::<Vec<_>>();
+    let text = text.join("\n");
+    let args = split_arguments(&text[start..]);
+
+    let mut positional = Vec::new();
+    let mut check_id = None;
+    let mut justification = None;
+    for arg in &args {
+        match arg.split_once('=') {
+            Some((name, value)) if is_identifier(name.trim()) => match name.trim() {
+                "CheckId" => check_id = string_literal(value),
+                "Justification" => justification = string_literal(value),
+                _ => {}
+            },
+            _ => positional.push(arg.as_str()),
+        }
+    }
+
+    let rule = check_id.or_else(|| positional.get(1).and_then(|a| string_literal(a)));
+    let codes = rule
+        .map(|r| r.split(':').next().unwrap_or_default().trim().to_string())
+        .filter(|c| !c.is_empty())
+        .into_iter()
+        .collect();
+    let justification = justification.filter(|j| !j.trim().is_empty());
+
+    Some(ParsedDirective { codes, justification })
+}
+
+/// Split an argument list at top-level commas, up to the closing parenthesis.
+fn split_arguments(text: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut prev = '\0';
+
+    for c in text.chars() {
+        if in_string {
+            if c == '"' && prev != '\\' {
+                in_string = false;
+            }
+        } else {
+            match c {
+                '"' => in_string = true,
+                '(' => depth += 1,
+                ')' if depth == 0 => break,
+                ')' => depth -= 1,
+                ',' if depth == 0 => {
+                    args.push(std::mem::take(&mut current));
+                    prev = c;
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        current.push(c);
+        prev = c;
+    }
+    args.push(current);
+
+    args.into_iter().map(|a| a.trim().to_string()).filter(|a| !a.is_empty()).collect()
+}
+
+/// Extract the contents of a string literal argument (`"..."`, `@"..."`).
+fn string_literal(arg: &str) -> Option<String> {
+    let start = arg.find('"')?;
+    let end = arg.rfind('"')?;
+    (end > start).then(|| arg[start + 1..end].to_string())
+}
+
+fn is_identifier(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+#[path = "suppress_tests.rs"]
+mod tests;
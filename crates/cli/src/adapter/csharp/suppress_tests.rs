@@ -0,0 +1,141 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Unit tests for C# `#pragma warning disable` and `[SuppressMessage]` parsing.
+
+use super::*;
+
+#[test]
+fn parse_pragma_warning_disable() {
+    let content = "#pragma warning disable CS0618, IDE0051\nLegacy.Call();\n#pragma warning restore CS0618, IDE0051";
+    let suppresses = parse_csharp_suppresses(content, None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert_eq!(suppresses[0].kind, CSharpSuppressKind::PragmaWarning);
+    assert_eq!(suppresses[0].line, 0);
+    assert_eq!(suppresses[0].codes, vec!["CS0618", "IDE0051"]);
+    assert!(!suppresses[0].has_comment);
+}
+
+#[test]
+fn bare_pragma_disables_all_warnings() {
+    let content = "    #pragma warning disable";
+    let suppresses = parse_csharp_suppresses(content, None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert!(suppresses[0].codes.is_empty());
+}
+
+#[test]
+fn pragma_restore_and_other_pragmas_ignored() {
+    let content =
+        "#pragma warning restore CS0618\n#pragma checksum \"file.cs\"\n#pragma warning disabled";
+    assert!(parse_csharp_suppresses(content, None).is_empty());
+}
+
+#[test]
+fn pragma_trailing_comment_is_justification() {
+    let content = "#pragma warning disable CS0618 // Obsolete API kept until v3 migration";
+    let suppresses = parse_csharp_suppresses(content, None);
+
+    assert_eq!(suppresses[0].codes, vec!["CS0618"]);
+    assert!(suppresses[0].has_comment);
+    assert_eq!(suppresses[0].comment_text.as_deref(), Some("Obsolete API kept until v3 migration"));
+}
+
+#[test]
+fn comment_above_pragma_is_justification() {
+    let content = "// Generated proxies reference obsolete members\n#pragma warning disable CS0612";
+    let suppresses = parse_csharp_suppresses(content, None);
+
+    assert!(suppresses[0].has_comment);
+}
+
+#[test]
+fn parse_suppress_message_attribute() {
+    let content = "[SuppressMessage(\"Performance\", \"CA1822:MarkMembersAsStatic\")]\npublic int Compute() => 1;";
+    let suppresses = parse_csharp_suppresses(content, None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert_eq!(suppresses[0].kind, CSharpSuppressKind::SuppressMessage);
+    assert_eq!(suppresses[0].codes, vec!["CA1822"]);
+    assert!(!suppresses[0].has_comment);
+}
+
+#[test]
+fn parse_assembly_and_qualified_attributes() {
+    let content = r#"[assembly: SuppressMessage("Style", "IDE0005:Using directive is unnecessary", Scope = "module")]
+[System.Diagnostics.CodeAnalysis.SuppressMessageAttribute("Design", "CA1031")]"#;
+    let suppresses = parse_csharp_suppresses(content, None);
+
+    assert_eq!(suppresses.len(), 2);
+    assert_eq!(suppresses[0].codes, vec!["IDE0005"]);
+    assert_eq!(suppresses[1].codes, vec!["CA1031"]);
+}
+
+#[test]
+fn justification_argument_is_justification() {
+    let content = r#"[SuppressMessage("Design", "CA1031:Do not catch general exception types", Justification = "Top-level handler logs and rethrows")]"#;
+    let suppresses = parse_csharp_suppresses(content, None);
+
+    assert!(suppresses[0].has_comment);
+    assert_eq!(suppresses[0].comment_text.as_deref(), Some("Top-level handler logs and rethrows"));
+}
+
+#[test]
+fn empty_justification_is_not_justification() {
+    let content = r#"[SuppressMessage("Design", "CA1031", Justification = "")]"#;
+    let suppresses = parse_csharp_suppresses(content, None);
+
+    assert!(!suppresses[0].has_comment);
+}
+
+#[test]
+fn multiline_attribute_arguments() {
+    let content = r#"[SuppressMessage(
+    "Microsoft.Design",
+    "CA1062:Validate arguments of public methods",
+    Justification = "Validated by the caller (see Guard.NotNull)")]
+public void Run(Options options) { }"#;
+    let suppresses = parse_csharp_suppresses(content, None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert_eq!(suppresses[0].line, 0);
+    assert_eq!(suppresses[0].codes, vec!["CA1062"]);
+    assert!(suppresses[0].has_comment);
+}
+
+#[test]
+fn check_id_named_argument() {
+    let content =
+        r#"[SuppressMessage(Category = "Style", CheckId = "IDE0060:Remove unused parameter")]"#;
+    let suppresses = parse_csharp_suppresses(content, None);
+
+    assert_eq!(suppresses[0].codes, vec!["IDE0060"]);
+}
+
+#[test]
+fn comment_above_attribute_stack_is_justification() {
+    let content = "// Reflection-invoked by the plugin host\n[Obsolete]\n[SuppressMessage(\"Performance\", \"CA1822\")]\npublic void Hook() { }";
+    let suppresses = parse_csharp_suppresses(content, None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert!(suppresses[0].has_comment);
+}
+
+#[test]
+fn comment_pattern_must_match() {
+    let content = "// just because\n#pragma warning disable CS0168";
+    let suppresses = parse_csharp_suppresses(content, Some("// WARN:"));
+    assert!(!suppresses[0].has_comment);
+
+    let content = "// WARN: variable kept for debugger inspection\n#pragma warning disable CS0168";
+    let suppresses = parse_csharp_suppresses(content, Some("// WARN:"));
+    assert!(suppresses[0].has_comment);
+}
+
+#[test]
+fn other_attributes_ignored() {
+    let content = "[Obsolete(\"Use Parse2\")]\n[Fact]\npublic void Parses() { }";
+    assert!(parse_csharp_suppresses(content, None).is_empty());
+}
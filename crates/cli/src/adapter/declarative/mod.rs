@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Declarative adapters: languages described in configuration.
+//!
+//! See docs/specs/10-language-adapters.md#declarative-adapters.
+//!
+//! A spec from `[[adapter]]` or `.quench/adapters/*.toml` supplies everything a
+//! built-in adapter hard-codes: which files it handles, how comments look,
+//! source/test/exclude patterns, escape patterns, suppress directives, lint
+//! config files and a test command.
+
+use std::path::Path;
+
+use globset::GlobSet;
+
+mod suppress;
+
+pub use suppress::{DeclarativeSuppress, compile_directives, parse_declarative_suppresses};
+
+use super::common;
+use super::common::patterns::normalize_exclude_patterns;
+use super::glob::build_glob_set;
+use super::{Adapter, FileKind};
+use crate::cloc::CommentStyle;
+use crate::config::DeclarativeAdapterConfig;
+
+/// Adapter built from a declarative spec.
+pub struct DeclarativeAdapter {
+    name: String,
+    extensions: Vec<String>,
+    filenames: GlobSet,
+    source_patterns: GlobSet,
+    test_patterns: GlobSet,
+    exclude_patterns: GlobSet,
+    line_comments: Vec<String>,
+    block_comments: Vec<(String, String)>,
+}
+
+impl DeclarativeAdapter {
+    /// Create an adapter from its spec.
+    ///
+    /// `fallback_test` applies when the spec has no `tests` patterns.
+    pub fn new(spec: &DeclarativeAdapterConfig, fallback_test: &[String]) -> Self {
+        let tests = if spec.tests.is_empty() { fallback_test } else { &spec.tests };
+
+        Self {
+            name: spec.name.clone(),
+            extensions: spec.extensions.clone(),
+            filenames: build_glob_set(&spec.filenames),
+            source_patterns: build_glob_set(&spec.effective_source()),
+            test_patterns: build_glob_set(tests),
+            exclude_patterns: build_glob_set(&normalize_exclude_patterns(&spec.exclude)),
+            line_comments: spec.comment.line.clone(),
+            block_comments: spec.comment.block.clone(),
+        }
+    }
+
+    /// Whether this adapter handles the file, by extension or file name glob.
+    pub fn matches(&self, path: &Path) -> bool {
+        let by_extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|ext| self.extensions.iter().any(|e| e.eq_ignore_ascii_case(ext)));
+        by_extension || path.file_name().is_some_and(|name| self.filenames.is_match(name))
+    }
+
+    /// Check if a path matches exclude patterns.
+    pub fn should_exclude(&self, path: &Path) -> bool {
+        common::patterns::check_exclude_patterns(path, &self.exclude_patterns, None)
+    }
+
+    /// Prefix of justification comments above suppress directives.
+    pub fn comment_prefix(&self) -> Option<&str> {
+        self.line_comments.first().map(String::as_str)
+    }
+
+    /// Run `f` with the configured comment style for line counting.
+    ///
+    /// Returns `None` when the spec declares no comment syntax.
+    pub fn with_comment_style<R>(&self, f: impl FnOnce(&CommentStyle) -> R) -> Option<R> {
+        if self.line_comments.is_empty() && self.block_comments.is_empty() {
+            return None;
+        }
+        let line: Vec<&str> = self.line_comments.iter().map(String::as_str).collect();
+        let block: Vec<(&str, &str)> = self
+            .block_comments
+            .iter()
+            .map(|(open, close)| (open.as_str(), close.as_str()))
+            .collect();
+        Some(f(&CommentStyle { line: &line, block: &block }))
+    }
+}
+
+impl Adapter for DeclarativeAdapter {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Declarative adapters register through `AdapterRegistry::register_declarative`,
+    /// which matches on the spec's extensions and file name globs.
+    fn extensions(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn classify(&self, path: &Path) -> FileKind {
+        // Check exclude patterns first
+        if self.should_exclude(path) {
+            return FileKind::Other;
+        }
+
+        // Test patterns take precedence
+        if self.test_patterns.is_match(path) {
+            return FileKind::Test;
+        }
+
+        // Source patterns
+        if self.source_patterns.is_match(path) {
+            return FileKind::Source;
+        }
+
+        FileKind::Other
+    }
+}
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
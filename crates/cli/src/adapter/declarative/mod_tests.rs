@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Unit tests for declarative adapters.
+
+use std::path::Path;
+
+use super::*;
+
+fn spec(toml: &str) -> DeclarativeAdapterConfig {
+    toml::from_str(toml).unwrap()
+}
+
+fn zig() -> DeclarativeAdapter {
+    let spec = spec(
+        r#"
+name = "zig"
+extensions = ["zig"]
+filenames = ["build.zig.zon"]
+tests = ["tests/**"]
+exclude = ["zig-cache/", "zig-out/"]
+
+[comment]
+line = ["//"]
+"#,
+    );
+    DeclarativeAdapter::new(&spec, &[])
+}
+
+#[test]
+fn matches_extensions_and_filenames() {
+    let adapter = zig();
+    assert!(adapter.matches(Path::new("src/main.zig")));
+    assert!(adapter.matches(Path::new("src/MAIN.ZIG")));
+    assert!(adapter.matches(Path::new("build.zig.zon")));
+    assert!(!adapter.matches(Path::new("src/main.rs")));
+    assert!(!adapter.matches(Path::new("README.md")));
+}
+
+#[test]
+fn classifies_source_test_and_excluded() {
+    let adapter = zig();
+    assert_eq!(adapter.classify(Path::new("src/main.zig")), FileKind::Source);
+    assert_eq!(adapter.classify(Path::new("tests/parser.zig")), FileKind::Test);
+    assert_eq!(adapter.classify(Path::new("zig-cache/o/abc/main.zig")), FileKind::Other);
+    assert_eq!(adapter.classify(Path::new("docs/notes.md")), FileKind::Other);
+}
+
+#[test]
+fn falls_back_to_provided_test_patterns() {
+    let spec = spec("name = \"zig\"\nextensions = [\"zig\"]\n");
+    let adapter = DeclarativeAdapter::new(&spec, &["**/*_test.zig".to_string()]);
+    assert_eq!(adapter.classify(Path::new("src/parser_test.zig")), FileKind::Test);
+    assert_eq!(adapter.classify(Path::new("src/parser.zig")), FileKind::Source);
+}
+
+#[test]
+fn comment_style_uses_spec_syntax() {
+    let spec = spec(
+        r#"
+name = "lua"
+extensions = ["lua"]
+
+[comment]
+line = ["--"]
+block = [["--[[", "]]"]]
+"#,
+    );
+    let adapter = DeclarativeAdapter::new(&spec, &[]);
+    assert_eq!(adapter.comment_prefix(), Some("--"));
+
+    let metrics = adapter
+        .with_comment_style(|style| {
+            crate::cloc::count_metrics_with_style(
+                "-- header\n--[[\nblock\n]]\nlocal x = 1\n",
+                Some(style),
+            )
+        })
+        .unwrap();
+    assert_eq!(metrics.code, 1);
+    assert_eq!(metrics.comment, 4);
+}
+
+#[test]
+fn no_comment_syntax_has_no_style() {
+    let adapter = DeclarativeAdapter::new(&spec("name = \"data\"\nextensions = [\"dat\"]\n"), &[]);
+    assert!(adapter.comment_prefix().is_none());
+    assert!(adapter.with_comment_style(|_| ()).is_none());
+}
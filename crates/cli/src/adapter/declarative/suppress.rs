@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Suppress directive parsing for declarative adapters.
+//!
+//! Directives are regexes from the adapter spec. Lint codes come from the
+//! `codes` named group, or the first capture group, split on commas and
+//! whitespace (e.g., `// zlint:ignore unused-var, shadow`).
+
+use regex::Regex;
+
+use crate::adapter::common::suppress::{CommentStyle, check_justification_comment_with};
+
+/// Suppress directive found by a declarative adapter.
+#[derive(Debug, Clone)]
+pub struct DeclarativeSuppress {
+    /// Line number (0-indexed).
+    pub line: usize,
+    /// The matched directive text.
+    pub directive: String,
+    /// Lint codes being suppressed (empty = all).
+    pub codes: Vec<String>,
+    /// Whether a justification comment was found.
+    pub has_comment: bool,
+    /// The comment text if found.
+    pub comment_text: Option<String>,
+}
+
+/// Compile directive regexes from an adapter spec.
+pub fn compile_directives(directives: &[String]) -> Result<Vec<Regex>, regex::Error> {
+    directives.iter().map(|d| Regex::new(d)).collect()
+}
+
+/// Parse suppress directives from source.
+///
+/// `comment_prefix` marks justification comments on the lines above a
+/// directive; without one, no directive is considered justified.
+pub fn parse_declarative_suppresses(
+    content: &str,
+    directives: &[Regex],
+    comment_prefix: Option<&str>,
+    comment_pattern: Option<&str>,
+) -> Vec<DeclarativeSuppress> {
+    let mut suppresses = Vec::new();
+    let lines: Vec<&str> = content.lines().collect();
+    let is_directive = |line: &str| directives.iter().any(|re| re.is_match(line));
+
+    for (line_idx, line) in lines.iter().enumerate() {
+        let Some(captures) = directives.iter().find_map(|re| re.captures(line)) else {
+            continue;
+        };
+
+        let codes = captures
+            .name("codes")
+            .or_else(|| captures.get(1))
+            .map(|m| {
+                m.as_str()
+                    .split(|c: char| c == ',' || c.is_whitespace())
+                    .filter(|code| !code.is_empty())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let (has_comment, comment_text) = match comment_prefix {
+            Some(prefix) => {
+                let style = CommentStyle { prefix, directive_patterns: &[], skip_prefixes: &[] };
+                check_justification_comment_with(
+                    &lines,
+                    line_idx,
+                    comment_pattern,
+                    &style,
+                    is_directive,
+                )
+            }
+            None => (false, None),
+        };
+
+        suppresses.push(DeclarativeSuppress {
+            line: line_idx,
+            directive: captures[0].trim().to_string(),
+            codes,
+            has_comment,
+            comment_text,
+        });
+    }
+
+    suppresses
+}
+
+#[cfg(test)]
+#[path = "suppress_tests.rs"]
+mod tests;
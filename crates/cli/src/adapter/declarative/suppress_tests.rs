@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Unit tests for declarative suppress directive parsing.
+
+use super::*;
+
+fn directives(patterns: &[&str]) -> Vec<Regex> {
+    let patterns: Vec<String> = patterns.iter().map(|p| p.to_string()).collect();
+    compile_directives(&patterns).unwrap()
+}
+
+#[test]
+fn captures_codes_from_named_group() {
+    let re = directives(&[r"zlint:ignore\s+(?P<codes>[\w,\s-]+)"]);
+    let suppresses = parse_declarative_suppresses(
+        "// zlint:ignore unused-var, shadow\nconst x = 1;",
+        &re,
+        Some("//"),
+        None,
+    );
+
+    assert_eq!(suppresses.len(), 1);
+    assert_eq!(suppresses[0].line, 0);
+    assert_eq!(suppresses[0].codes, vec!["unused-var", "shadow"]);
+    assert!(!suppresses[0].has_comment);
+}
+
+#[test]
+fn falls_back_to_first_capture_group() {
+    let re = directives(&[r"@lint-ignore\((\w+)\)"]);
+    let suppresses =
+        parse_declarative_suppresses("x = 1 # @lint-ignore(E501)", &re, Some("#"), None);
+
+    assert_eq!(suppresses[0].codes, vec!["E501"]);
+    assert_eq!(suppresses[0].directive, "@lint-ignore(E501)");
+}
+
+#[test]
+fn directive_without_group_suppresses_everything() {
+    let re = directives(&[r"zlint:ignore-all"]);
+    let suppresses = parse_declarative_suppresses("// zlint:ignore-all", &re, Some("//"), None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert!(suppresses[0].codes.is_empty());
+}
+
+#[test]
+fn detects_justification_comment_above() {
+    let re = directives(&[r"zlint:ignore\s+(\S+)"]);
+    let content = "// Generated bindings use C names\n// zlint:ignore naming\nconst FOO_bar = 1;";
+    let suppresses = parse_declarative_suppresses(content, &re, Some("//"), None);
+
+    assert!(suppresses[0].has_comment);
+    assert_eq!(suppresses[0].comment_text.as_deref(), Some("Generated bindings use C names"));
+}
+
+#[test]
+fn stacked_directives_are_not_justification() {
+    let re = directives(&[r"zlint:ignore\s+(\S+)"]);
+    let content = "// zlint:ignore naming\n// zlint:ignore shadow\nconst x = 1;";
+    let suppresses = parse_declarative_suppresses(content, &re, Some("//"), None);
+
+    assert_eq!(suppresses.len(), 2);
+    assert!(!suppresses[1].has_comment);
+}
+
+#[test]
+fn required_comment_pattern() {
+    let re = directives(&[r"zlint:ignore\s+(\S+)"]);
+    let content = "// Some note\n// zlint:ignore naming";
+
+    let suppresses = parse_declarative_suppresses(content, &re, Some("//"), Some("// REASON:"));
+    assert!(!suppresses[0].has_comment);
+
+    let content = "// REASON: FFI naming\n// zlint:ignore naming";
+    let suppresses = parse_declarative_suppresses(content, &re, Some("//"), Some("// REASON:"));
+    assert!(suppresses[0].has_comment);
+}
+
+#[test]
+fn no_comment_syntax_means_unjustified() {
+    let re = directives(&[r"lint:off"]);
+    let suppresses = parse_declarative_suppresses("-- note\nlint:off", &re, None, None);
+
+    assert!(!suppresses[0].has_comment);
+}
+
+#[test]
+fn invalid_directive_regex_is_an_error() {
+    assert!(compile_directives(&["(unclosed".to_string()]).is_err());
+}
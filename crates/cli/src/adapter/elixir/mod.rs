@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Elixir language adapter.
+//!
+//! Provides Elixir-specific behavior for checks:
+//! - File classification (`test/`, `*_test.exs`)
+//! - Default patterns for Elixir sources
+//! - Default escape patterns (`String.to_atom`, `:erlang.binary_to_term`, `Code.eval_string`)
+//! - Credo `# credo:disable-for-*` directive parsing
+//!
+//! See docs/specs/langs/elixir.md for specification.
+
+use std::path::Path;
+
+use globset::GlobSet;
+
+mod suppress;
+
+pub use suppress::{CredoKind, CredoSuppress, parse_elixir_suppresses};
+
+use super::common;
+use super::common::patterns::normalize_exclude_patterns;
+use super::glob::build_glob_set;
+use super::{Adapter, EscapeAction, EscapePattern, FileKind};
+use crate::config::ElixirConfig;
+
+/// Default escape patterns for Elixir.
+const ELIXIR_ESCAPE_PATTERNS: &[EscapePattern] = &[
+    // Atoms are never garbage collected; converting untrusted input exhausts the atom table
+    EscapePattern {
+        name: "string_to_atom",
+        pattern: r"\bString\.to_atom\b",
+        action: EscapeAction::Comment,
+        comment: Some("# ATOM:"),
+        advice: "Add a # ATOM: comment explaining why the input is a bounded set, or use String.to_existing_atom.",
+        in_tests: None,
+    },
+    EscapePattern {
+        name: "binary_to_term",
+        pattern: r":erlang\.binary_to_term\b",
+        action: EscapeAction::Comment,
+        comment: Some("# SAFETY:"),
+        advice: "Add a # SAFETY: comment explaining why the binary is trusted, or pass [:safe] and validate the decoded term.",
+        in_tests: None,
+    },
+    EscapePattern {
+        name: "eval_string",
+        pattern: r"\bCode\.eval_string\b",
+        action: EscapeAction::Comment,
+        comment: Some("# EVAL:"),
+        advice: "Add a # EVAL: comment explaining why eval is necessary and where its input comes from.",
+        in_tests: None,
+    },
+];
+
+/// Elixir language adapter.
+pub struct ElixirAdapter {
+    source_patterns: GlobSet,
+    test_patterns: GlobSet,
+    exclude_patterns: GlobSet,
+}
+
+impl ElixirAdapter {
+    /// Create a new Elixir adapter with default patterns.
+    pub fn new() -> Self {
+        Self {
+            source_patterns: build_glob_set(&ElixirConfig::default_source()),
+            test_patterns: build_glob_set(&ElixirConfig::default_tests()),
+            exclude_patterns: build_glob_set(&normalize_exclude_patterns(
+                &ElixirConfig::default_exclude(),
+            )),
+        }
+    }
+
+    /// Create an Elixir adapter with resolved patterns from config.
+    pub fn with_patterns(patterns: super::ResolvedPatterns) -> Self {
+        let exclude_globs = normalize_exclude_patterns(&patterns.exclude);
+
+        Self {
+            source_patterns: build_glob_set(&patterns.source),
+            test_patterns: build_glob_set(&patterns.test),
+            exclude_patterns: build_glob_set(&exclude_globs),
+        }
+    }
+
+    /// Check if a path matches exclude patterns.
+    pub fn should_exclude(&self, path: &Path) -> bool {
+        common::patterns::check_exclude_patterns(
+            path,
+            &self.exclude_patterns,
+            Some(&["_build", "deps"]),
+        )
+    }
+}
+
+impl Default for ElixirAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Adapter for ElixirAdapter {
+    fn name(&self) -> &'static str {
+        "elixir"
+    }
+
+    fn extensions(&self) -> &'static [&'static str] {
+        &["ex", "exs"]
+    }
+
+    fn classify(&self, path: &Path) -> FileKind {
+        // Check exclude patterns first
+        if self.should_exclude(path) {
+            return FileKind::Other;
+        }
+
+        // Test patterns take precedence
+        if self.test_patterns.is_match(path) {
+            return FileKind::Test;
+        }
+
+        // Source patterns
+        if self.source_patterns.is_match(path) {
+            return FileKind::Source;
+        }
+
+        FileKind::Other
+    }
+
+    fn default_escapes(&self) -> &'static [EscapePattern] {
+        ELIXIR_ESCAPE_PATTERNS
+    }
+
+    fn declaration_keywords(&self) -> &'static [&'static str] {
+        &["def", "defp", "defmacro", "defmodule"]
+    }
+}
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Unit tests for the Elixir adapter.
+
+use std::path::Path;
+
+use super::*;
+
+#[test]
+fn classifies_elixir_files_as_source() {
+    let adapter = ElixirAdapter::new();
+    assert_eq!(adapter.classify(Path::new("lib/acme/accounts.ex")), FileKind::Source);
+    assert_eq!(adapter.classify(Path::new("mix.exs")), FileKind::Source);
+    assert_eq!(adapter.classify(Path::new("config/runtime.exs")), FileKind::Source);
+}
+
+#[test]
+fn classifies_exunit_files_as_test() {
+    let adapter = ElixirAdapter::new();
+    assert_eq!(adapter.classify(Path::new("test/acme/accounts_test.exs")), FileKind::Test);
+    assert_eq!(adapter.classify(Path::new("test/support/conn_case.ex")), FileKind::Test);
+    assert_eq!(adapter.classify(Path::new("test/test_helper.exs")), FileKind::Test);
+    assert_eq!(adapter.classify(Path::new("apps/web/test/web/router_test.exs")), FileKind::Test);
+}
+
+#[test]
+fn ignores_build_and_deps() {
+    let adapter = ElixirAdapter::new();
+    assert_eq!(adapter.classify(Path::new("deps/plug/lib/plug/conn.ex")), FileKind::Other);
+    assert_eq!(
+        adapter.classify(Path::new("_build/test/lib/acme/consolidated.ex")),
+        FileKind::Other
+    );
+}
+
+#[test]
+fn non_elixir_files_are_other() {
+    let adapter = ElixirAdapter::new();
+    assert_eq!(adapter.classify(Path::new("mix.lock")), FileKind::Other);
+    assert_eq!(adapter.name(), "elixir");
+}
+
+// =============================================================================
+// DEFAULT ESCAPE PATTERNS
+// =============================================================================
+
+fn escape_regex(name: &str) -> regex::Regex {
+    let pattern = ELIXIR_ESCAPE_PATTERNS.iter().find(|p| p.name == name).unwrap().pattern;
+    regex::Regex::new(pattern).unwrap()
+}
+
+#[test]
+fn string_to_atom_pattern() {
+    let re = escape_regex("string_to_atom");
+    assert!(re.is_match("key = String.to_atom(param)"));
+    assert!(re.is_match("params |> Map.get(\"kind\") |> String.to_atom()"));
+    assert!(!re.is_match("key = String.to_existing_atom(param)"));
+}
+
+#[test]
+fn binary_to_term_pattern() {
+    let re = escape_regex("binary_to_term");
+    assert!(re.is_match("term = :erlang.binary_to_term(payload)"));
+    assert!(re.is_match("term = :erlang.binary_to_term(payload, [:safe])"));
+    assert!(!re.is_match("bin = :erlang.term_to_binary(term)"));
+}
+
+#[test]
+fn eval_string_pattern() {
+    let re = escape_regex("eval_string");
+    assert!(re.is_match("{result, _} = Code.eval_string(expr)"));
+    assert!(!re.is_match("Code.string_to_quoted(expr)"));
+}
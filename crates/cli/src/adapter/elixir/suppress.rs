@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Credo directive parsing.
+//!
+//! Parses `# credo:disable-for-next-line`, `# credo:disable-for-previous-line`,
+//! `# credo:disable-for-lines:N` and `# credo:disable-for-this-file` comments,
+//! optionally followed by the check being disabled
+//! (e.g., `Credo.Check.Warning.IoInspect`).
+
+use crate::adapter::common::suppress::{CommentStyle, check_justification_comment};
+
+/// Credo directive found in Elixir source.
+#[derive(Debug, Clone)]
+pub struct CredoSuppress {
+    /// Line number (0-indexed).
+    pub line: usize,
+    /// Directive type.
+    pub kind: CredoKind,
+    /// Credo checks being disabled (empty = all checks).
+    pub codes: Vec<String>,
+    /// Whether a justification comment was found.
+    pub has_comment: bool,
+    /// The comment text if found.
+    pub comment_text: Option<String>,
+}
+
+/// Kind of Credo directive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CredoKind {
+    /// `credo:disable-for-next-line`.
+    NextLine,
+    /// `credo:disable-for-previous-line`.
+    PreviousLine,
+    /// `credo:disable-for-lines:N`: disables checks on the next N lines.
+    Lines,
+    /// `credo:disable-for-this-file`.
+    ThisFile,
+}
+
+impl std::fmt::Display for CredoKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NextLine => write!(f, "credo:disable-for-next-line"),
+            Self::PreviousLine => write!(f, "credo:disable-for-previous-line"),
+            Self::Lines => write!(f, "credo:disable-for-lines"),
+            Self::ThisFile => write!(f, "credo:disable-for-this-file"),
+        }
+    }
+}
+
+/// Directive markers following `credo:disable-for-`.
+const MARKERS: &[(&str, CredoKind)] = &[
+    ("next-line", CredoKind::NextLine),
+    ("previous-line", CredoKind::PreviousLine),
+    ("lines:", CredoKind::Lines),
+    ("this-file", CredoKind::ThisFile),
+];
+
+/// Parse Credo directives from Elixir source.
+pub fn parse_elixir_suppresses(content: &str, comment_pattern: Option<&str>) -> Vec<CredoSuppress> {
+    let mut suppresses = Vec::new();
+    let lines: Vec<&str> = content.lines().collect();
+
+    for (line_idx, line) in lines.iter().enumerate() {
+        let Some((kind, codes)) = parse_credo_line(line) else {
+            continue;
+        };
+
+        let (has_comment, comment_text) =
+            check_justification_comment(&lines, line_idx, comment_pattern, &CommentStyle::ELIXIR);
+
+        suppresses.push(CredoSuppress { line: line_idx, kind, codes, has_comment, comment_text });
+    }
+
+    suppresses
+}
+
+/// Parse a Credo directive from a single line.
+///
+/// The directive must directly follow the `#` that opens a comment.
+fn parse_credo_line(line: &str) -> Option<(CredoKind, Vec<String>)> {
+    let (pos, _) = line
+        .match_indices("credo:disable-for-")
+        .find(|(pos, _)| line[..*pos].trim_end().ends_with('#'))?;
+    let rest = &line[pos + "credo:disable-for-".len()..];
+
+    let (kind, rest) = MARKERS
+        .iter()
+        .find_map(|(marker, kind)| rest.strip_prefix(marker).map(|rest| (*kind, rest)))?;
+
+    // `lines:N` carries a line count before the checks
+    let rest = if kind == CredoKind::Lines {
+        rest.trim_start_matches(|c: char| c.is_ascii_digit())
+    } else {
+        rest
+    };
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    let codes = rest.split_whitespace().map(String::from).collect();
+    Some((kind, codes))
+}
+
+#[cfg(test)]
+#[path = "suppress_tests.rs"]
+mod tests;
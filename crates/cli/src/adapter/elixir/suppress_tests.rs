@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Unit tests for Credo directive parsing.
+
+use super::*;
+
+#[test]
+fn parse_next_line_with_check() {
+    let content = "# credo:disable-for-next-line Credo.Check.Warning.IoInspect\nIO.inspect(conn)";
+    let suppresses = parse_elixir_suppresses(content, None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert_eq!(suppresses[0].kind, CredoKind::NextLine);
+    assert_eq!(suppresses[0].line, 0);
+    assert_eq!(suppresses[0].codes, vec!["Credo.Check.Warning.IoInspect"]);
+    assert!(!suppresses[0].has_comment);
+}
+
+#[test]
+fn parse_without_check_disables_all() {
+    let suppresses = parse_elixir_suppresses("  # credo:disable-for-next-line\n", None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert!(suppresses[0].codes.is_empty());
+}
+
+#[test]
+fn parse_trailing_previous_line() {
+    let content = "IO.inspect(conn) # credo:disable-for-previous-line";
+    let suppresses = parse_elixir_suppresses(content, None);
+
+    assert_eq!(suppresses[0].kind, CredoKind::PreviousLine);
+}
+
+#[test]
+fn parse_lines_count() {
+    let content = "# credo:disable-for-lines:5 Credo.Check.Refactor.Nesting";
+    let suppresses = parse_elixir_suppresses(content, None);
+
+    assert_eq!(suppresses[0].kind, CredoKind::Lines);
+    assert_eq!(suppresses[0].codes, vec!["Credo.Check.Refactor.Nesting"]);
+}
+
+#[test]
+fn parse_this_file() {
+    let suppresses = parse_elixir_suppresses("# credo:disable-for-this-file", None);
+    assert_eq!(suppresses[0].kind, CredoKind::ThisFile);
+}
+
+#[test]
+fn comment_above_is_justification() {
+    let content = "# Debug output is required by the ops runbook\n# credo:disable-for-next-line Credo.Check.Warning.IoInspect\nIO.inspect(conn)";
+    let suppresses = parse_elixir_suppresses(content, None);
+
+    assert!(suppresses[0].has_comment);
+    assert_eq!(
+        suppresses[0].comment_text.as_deref(),
+        Some("Debug output is required by the ops runbook")
+    );
+}
+
+#[test]
+fn module_attributes_between_comment_and_directive_are_skipped() {
+    let content =
+        "# Generated router helpers\n@doc false\n# credo:disable-for-next-line\ndef route, do: :ok";
+    let suppresses = parse_elixir_suppresses(content, None);
+
+    assert!(suppresses[0].has_comment);
+}
+
+#[test]
+fn comment_pattern_required() {
+    let content = "# Debug output\n# credo:disable-for-next-line";
+    let suppresses = parse_elixir_suppresses(content, Some("# OPS:"));
+    assert!(!suppresses[0].has_comment);
+
+    let content = "# OPS: debug output\n# credo:disable-for-next-line";
+    let suppresses = parse_elixir_suppresses(content, Some("# OPS:"));
+    assert!(suppresses[0].has_comment);
+}
+
+#[test]
+fn unknown_directives_and_strings_ignored() {
+    let content = "# credo:disable-for-next-lines\nmsg = \"credo:disable-for-next-line\"\n# credo:enable-for-next-line";
+    assert!(parse_elixir_suppresses(content, None).is_empty());
+}
+
+#[test]
+fn display_matches_directive() {
+    assert_eq!(CredoKind::NextLine.to_string(), "credo:disable-for-next-line");
+    assert_eq!(CredoKind::Lines.to_string(), "credo:disable-for-lines");
+    assert_eq!(CredoKind::ThisFile.to_string(), "credo:disable-for-this-file");
+}
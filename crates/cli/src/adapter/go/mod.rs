@@ -124,6 +124,10 @@ impl Adapter for GoAdapter {
     fn default_escapes(&self) -> &'static [EscapePattern] {
         GO_ESCAPE_PATTERNS
     }
+
+    fn declaration_keywords(&self) -> &'static [&'static str] {
+        &["func", "type"]
+    }
 }
 
 impl GoAdapter {
@@ -0,0 +1,160 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Infrastructure-as-code adapter.
+//!
+//! Provides behavior for Terraform/HCL, Dockerfiles and Kubernetes manifests:
+//! - File classification (`*.tf`, `*.hcl`, `Dockerfile`, `k8s/**/*.yaml`, `*.tftest.hcl`)
+//! - Default escape patterns (scanner ignores, root users, `latest` image tags)
+//!
+//! Unlike language adapters, the IaC adapter is registered alongside the
+//! detected project language, and matches `Dockerfile` by file name.
+//!
+//! See docs/specs/langs/iac.md for specification.
+
+use std::path::Path;
+
+use globset::GlobSet;
+
+use super::common;
+use super::common::patterns::normalize_exclude_patterns;
+use super::glob::build_glob_set;
+use super::{Adapter, EscapeAction, EscapePattern, FileKind};
+use crate::config::IacConfig;
+
+/// Default escape patterns for infrastructure code.
+const IAC_ESCAPE_PATTERNS: &[EscapePattern] = &[
+    // tfsec was folded into trivy; both use `#tfsec:ignore:<rule>` style comments
+    EscapePattern {
+        name: "tfsec_ignore",
+        pattern: r"\b(?:tfsec|trivy):ignore\b",
+        action: EscapeAction::Comment,
+        comment: Some("# SECURITY:"),
+        advice: "Add a # SECURITY: comment explaining why the finding does not apply here.",
+        in_tests: None,
+    },
+    EscapePattern {
+        name: "checkov_skip",
+        pattern: r"\bcheckov:skip=",
+        action: EscapeAction::Comment,
+        comment: Some("# SECURITY:"),
+        advice: "Add a # SECURITY: comment explaining why the check does not apply here.",
+        in_tests: None,
+    },
+    EscapePattern {
+        name: "hadolint_ignore",
+        pattern: r"\bhadolint\s+(?:global\s+)?ignore=",
+        action: EscapeAction::Comment,
+        comment: Some("# LINT:"),
+        advice: "Add a # LINT: comment explaining why the rule is ignored.",
+        in_tests: None,
+    },
+    // `USER root` in a Dockerfile, `runAsUser: 0` in a pod security context
+    EscapePattern {
+        name: "user_root",
+        pattern: r"(?im)^\s*USER\s+(?:root|0)\b|\brunAsUser:\s*0\b",
+        action: EscapeAction::Comment,
+        comment: Some("# ROOT:"),
+        advice: "Add a # ROOT: comment explaining why the container must run as root, or switch to an unprivileged user.",
+        in_tests: None,
+    },
+    // `FROM image:latest` and `image: name:latest` (Kubernetes) or `image = "name:latest"` (Terraform)
+    EscapePattern {
+        name: "latest_tag",
+        pattern: r#"(?im)^\s*FROM\s+(?:--platform=\S+\s+)?\S+:latest\b|\bimage\s*[:=]\s*["']?[^\s"']+:latest\b"#,
+        action: EscapeAction::Comment,
+        comment: Some("# LATEST:"),
+        advice: "Pin the image to a version or digest, or add a # LATEST: comment explaining why the newest image is required.",
+        in_tests: None,
+    },
+];
+
+/// File names matched regardless of extension.
+const IAC_FILENAMES: &[&str] = &["Dockerfile", "Containerfile"];
+
+/// Infrastructure-as-code adapter.
+pub struct IacAdapter {
+    source_patterns: GlobSet,
+    test_patterns: GlobSet,
+    exclude_patterns: GlobSet,
+}
+
+impl IacAdapter {
+    /// Create a new IaC adapter with default patterns.
+    pub fn new() -> Self {
+        Self {
+            source_patterns: build_glob_set(&IacConfig::default_source()),
+            test_patterns: build_glob_set(&IacConfig::default_tests()),
+            exclude_patterns: build_glob_set(&normalize_exclude_patterns(
+                &IacConfig::default_exclude(),
+            )),
+        }
+    }
+
+    /// Create an IaC adapter with resolved patterns from config.
+    pub fn with_patterns(patterns: super::ResolvedPatterns) -> Self {
+        let exclude_globs = normalize_exclude_patterns(&patterns.exclude);
+
+        Self {
+            source_patterns: build_glob_set(&patterns.source),
+            test_patterns: build_glob_set(&patterns.test),
+            exclude_patterns: build_glob_set(&exclude_globs),
+        }
+    }
+
+    /// Check if a path matches exclude patterns.
+    pub fn should_exclude(&self, path: &Path) -> bool {
+        common::patterns::check_exclude_patterns(
+            path,
+            &self.exclude_patterns,
+            Some(&[".terraform", ".terragrunt-cache"]),
+        )
+    }
+}
+
+impl Default for IacAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Adapter for IacAdapter {
+    fn name(&self) -> &'static str {
+        "iac"
+    }
+
+    fn extensions(&self) -> &'static [&'static str] {
+        &["tf", "tfvars", "hcl", "dockerfile"]
+    }
+
+    fn filenames(&self) -> &'static [&'static str] {
+        IAC_FILENAMES
+    }
+
+    fn classify(&self, path: &Path) -> FileKind {
+        // Check exclude patterns first
+        if self.should_exclude(path) {
+            return FileKind::Other;
+        }
+
+        // Test patterns take precedence
+        if self.test_patterns.is_match(path) {
+            return FileKind::Test;
+        }
+
+        // Source patterns
+        if self.source_patterns.is_match(path) {
+            return FileKind::Source;
+        }
+
+        FileKind::Other
+    }
+
+    fn default_escapes(&self) -> &'static [EscapePattern] {
+        IAC_ESCAPE_PATTERNS
+    }
+}
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
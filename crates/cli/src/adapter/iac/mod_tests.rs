@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Unit tests for the infrastructure-as-code adapter.
+
+use std::path::Path;
+
+use super::*;
+
+#[test]
+fn classifies_infra_files_as_source() {
+    let adapter = IacAdapter::new();
+    assert_eq!(adapter.classify(Path::new("main.tf")), FileKind::Source);
+    assert_eq!(adapter.classify(Path::new("infra/envs/prod.tfvars")), FileKind::Source);
+    assert_eq!(adapter.classify(Path::new("terragrunt.hcl")), FileKind::Source);
+    assert_eq!(adapter.classify(Path::new("Dockerfile")), FileKind::Source);
+    assert_eq!(adapter.classify(Path::new("services/api/Dockerfile.dev")), FileKind::Source);
+    assert_eq!(adapter.classify(Path::new("k8s/base/deployment.yaml")), FileKind::Source);
+    assert_eq!(adapter.classify(Path::new("deploy/kustomization.yml")), FileKind::Source);
+}
+
+#[test]
+fn classifies_terraform_tests_as_test() {
+    let adapter = IacAdapter::new();
+    assert_eq!(adapter.classify(Path::new("tests/defaults.tftest.hcl")), FileKind::Test);
+    assert_eq!(adapter.classify(Path::new("modules/vpc/tests/vpc.tftest.hcl")), FileKind::Test);
+}
+
+#[test]
+fn ignores_provider_caches() {
+    let adapter = IacAdapter::new();
+    assert_eq!(adapter.classify(Path::new(".terraform/modules/vpc/main.tf")), FileKind::Other);
+    assert_eq!(
+        adapter.classify(Path::new("infra/live/.terragrunt-cache/abc/main.tf")),
+        FileKind::Other
+    );
+}
+
+#[test]
+fn non_infra_files_are_other() {
+    let adapter = IacAdapter::new();
+    assert_eq!(adapter.classify(Path::new(".github/workflows/ci.yaml")), FileKind::Other);
+    assert_eq!(adapter.classify(Path::new("config/settings.yaml")), FileKind::Other);
+    assert_eq!(adapter.name(), "iac");
+}
+
+#[test]
+fn matches_dockerfile_by_name() {
+    let adapter = IacAdapter::new();
+    assert!(adapter.filenames().contains(&"Dockerfile"));
+    assert!(adapter.extensions().contains(&"dockerfile"));
+}
+
+// =============================================================================
+// DEFAULT ESCAPE PATTERNS
+// =============================================================================
+
+fn escape_regex(name: &str) -> regex::Regex {
+    let pattern = IAC_ESCAPE_PATTERNS.iter().find(|p| p.name == name).unwrap().pattern;
+    regex::Regex::new(pattern).unwrap()
+}
+
+#[test]
+fn tfsec_ignore_pattern() {
+    let re = escape_regex("tfsec_ignore");
+    assert!(re.is_match("  #tfsec:ignore:aws-s3-enable-versioning"));
+    assert!(re.is_match("  # trivy:ignore:AVD-AWS-0089"));
+    assert!(!re.is_match("  # tfsec is run in CI"));
+}
+
+#[test]
+fn checkov_skip_pattern() {
+    let re = escape_regex("checkov_skip");
+    assert!(re.is_match("  #checkov:skip=CKV_AWS_20:Public website bucket"));
+    assert!(!re.is_match("# checkov runs in the pipeline"));
+}
+
+#[test]
+fn hadolint_ignore_pattern() {
+    let re = escape_regex("hadolint_ignore");
+    assert!(re.is_match("# hadolint ignore=DL3008,DL3015"));
+    assert!(re.is_match("# hadolint global ignore=DL3059"));
+    assert!(!re.is_match("# hadolint runs via pre-commit"));
+}
+
+#[test]
+fn user_root_pattern() {
+    let re = escape_regex("user_root");
+    assert!(re.is_match("FROM alpine\nUSER root\nRUN apk add curl\n"));
+    assert!(re.is_match("USER 0\n"));
+    assert!(re.is_match("        runAsUser: 0\n"));
+    assert!(!re.is_match("USER app\n"));
+    assert!(!re.is_match("USER rootless\n"));
+    assert!(!re.is_match("runAsUser: 1000\n"));
+}
+
+#[test]
+fn latest_tag_pattern() {
+    let re = escape_regex("latest_tag");
+    assert!(re.is_match("FROM node:latest\n"));
+    assert!(re.is_match("FROM --platform=linux/amd64 registry:5000/app:latest AS build\n"));
+    assert!(re.is_match("      - image: nginx:latest\n"));
+    assert!(re.is_match("  image = \"ghcr.io/acme/api:latest\"\n"));
+    assert!(!re.is_match("FROM node:20.11-alpine\n"));
+    assert!(!re.is_match("      image: nginx:1.27\n"));
+    assert!(!re.is_match("RUN echo latest\n"));
+}
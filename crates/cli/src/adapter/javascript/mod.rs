@@ -167,6 +167,10 @@ impl Adapter for JavaScriptAdapter {
     fn default_escapes(&self) -> &'static [EscapePattern] {
         JS_ESCAPE_PATTERNS
     }
+
+    fn declaration_keywords(&self) -> &'static [&'static str] {
+        &["function", "class", "interface", "type", "enum"]
+    }
 }
 
 #[cfg(test)]
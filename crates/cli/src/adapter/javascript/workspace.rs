@@ -3,7 +3,7 @@
 
 //! JavaScript workspace detection for npm/yarn/pnpm monorepos.
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::fs;
 use std::path::Path;
 
@@ -20,6 +20,8 @@ pub struct JsWorkspace {
     pub package_names: HashMap<String, String>,
     /// Workspace patterns from config (e.g., ["packages/*"]).
     pub patterns: Vec<String>,
+    /// Workspace-internal dependencies, keyed by package path (values are package paths).
+    pub dependencies: HashMap<String, Vec<String>>,
 }
 
 impl JsWorkspace {
@@ -55,7 +57,8 @@ impl JsWorkspace {
         }
 
         let (package_paths, package_names) = expand_workspace_patterns(&patterns, root);
-        Some(Self { is_workspace: true, package_paths, package_names, patterns })
+        let dependencies = read_workspace_dependencies(&package_paths, root);
+        Some(Self { is_workspace: true, package_paths, package_names, patterns, dependencies })
     }
 
     fn from_package_json(root: &Path) -> Option<Self> {
@@ -84,8 +87,66 @@ impl JsWorkspace {
         }
 
         let (package_paths, package_names) = expand_workspace_patterns(&patterns, root);
-        Some(Self { is_workspace: true, package_paths, package_names, patterns })
+        let dependencies = read_workspace_dependencies(&package_paths, root);
+        Some(Self { is_workspace: true, package_paths, package_names, patterns, dependencies })
     }
+
+    /// Find the package path containing the given root-relative path.
+    pub fn package_for_path(&self, path: &Path) -> Option<&str> {
+        self.package_paths
+            .iter()
+            .filter(|pkg| path.starts_with(pkg.as_str()))
+            .max_by_key(|pkg| pkg.len())
+            .map(String::as_str)
+    }
+
+    /// Expand a set of package paths with every workspace package they depend on.
+    pub fn with_dependencies(&self, paths: &BTreeSet<String>) -> BTreeSet<String> {
+        let mut result = paths.clone();
+        let mut stack: Vec<String> = paths.iter().cloned().collect();
+        while let Some(path) = stack.pop() {
+            for dep in self.dependencies.get(&path).into_iter().flatten() {
+                if result.insert(dep.clone()) {
+                    stack.push(dep.clone());
+                }
+            }
+        }
+        result
+    }
+}
+
+/// Resolve each package's dependencies on other workspace packages.
+///
+/// Dependencies are matched by the `name` field of each member's package.json.
+fn read_workspace_dependencies(paths: &[String], root: &Path) -> HashMap<String, Vec<String>> {
+    let manifests: Vec<(String, Value)> = paths
+        .iter()
+        .filter_map(|path| {
+            let content = fs::read_to_string(root.join(path).join("package.json")).ok()?;
+            Some((path.clone(), serde_json::from_str(&content).ok()?))
+        })
+        .collect();
+
+    let by_name: HashMap<&str, &str> = manifests
+        .iter()
+        .filter_map(|(path, value)| Some((value.get("name")?.as_str()?, path.as_str())))
+        .collect();
+
+    manifests
+        .iter()
+        .map(|(path, value)| {
+            let mut deps: Vec<String> =
+                ["dependencies", "devDependencies", "peerDependencies", "optionalDependencies"]
+                    .iter()
+                    .filter_map(|section| value.get(section).and_then(|d| d.as_object()))
+                    .flat_map(|deps| deps.keys())
+                    .filter_map(|name| by_name.get(name.as_str()).map(|p| p.to_string()))
+                    .collect();
+            deps.sort();
+            deps.dedup();
+            (path.clone(), deps)
+        })
+        .collect()
 }
 
 /// Expand workspace patterns to find package paths and names.
@@ -161,3 +161,24 @@ fn handles_direct_path_pattern() {
     assert!(ws.package_paths.contains(&"packages/core".to_string()));
     assert_eq!(ws.package_names.get("packages/core"), Some(&"core".to_string()));
 }
+
+#[test]
+fn resolves_workspace_internal_dependencies() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("package.json"), r#"{"workspaces": ["packages/*"]}"#).unwrap();
+    fs::create_dir_all(dir.path().join("packages/core")).unwrap();
+    fs::write(dir.path().join("packages/core/package.json"), r#"{"name": "@app/core"}"#).unwrap();
+    fs::create_dir_all(dir.path().join("packages/web")).unwrap();
+    fs::write(
+        dir.path().join("packages/web/package.json"),
+        r#"{"name": "@app/web", "dependencies": {"@app/core": "*", "react": "^18"}}"#,
+    )
+    .unwrap();
+
+    let ws = JsWorkspace::from_root(dir.path());
+    assert_eq!(ws.dependencies.get("packages/web"), Some(&vec!["packages/core".to_string()]));
+    assert_eq!(
+        ws.package_for_path(std::path::Path::new("packages/web/src/app.ts")),
+        Some("packages/web")
+    );
+}
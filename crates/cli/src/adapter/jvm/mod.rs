@@ -0,0 +1,151 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! JVM (Java/Kotlin) language adapter.
+//!
+//! Provides Java- and Kotlin-specific behavior for checks:
+//! - File classification (`src/main` vs `src/test` source sets)
+//! - Default patterns for Java and Kotlin files
+//! - Default escape patterns (`@SuppressWarnings`, `!!`, `as Any`)
+//! - `@Suppress` / `// noinspection` directive parsing
+//!
+//! See docs/specs/langs/jvm.md for specification.
+
+use std::path::Path;
+
+use globset::GlobSet;
+
+mod suppress;
+
+pub use suppress::{JvmSuppress, JvmSuppressKind, parse_jvm_suppresses};
+
+use super::common;
+use super::common::patterns::normalize_exclude_patterns;
+use super::glob::build_glob_set;
+use super::{Adapter, EscapeAction, EscapePattern, FileKind};
+use crate::config::{JavaConfig, KotlinConfig};
+
+/// Default escape patterns for Java and Kotlin.
+const JVM_ESCAPE_PATTERNS: &[EscapePattern] = &[
+    EscapePattern {
+        name: "suppress_warnings",
+        pattern: r"@SuppressWarnings\b",
+        action: EscapeAction::Comment,
+        comment: Some("// SUPPRESS:"),
+        advice: "Add a // SUPPRESS: comment explaining why the compiler warning is a false positive.",
+        in_tests: None,
+    },
+    // Kotlin not-null assertion (`value!!`), ignoring `!!` inside strings like "Hi!!"
+    EscapePattern {
+        name: "not_null_assertion",
+        pattern: r"[\w)\]}]!!(?:[.\s),;:?\]}]|$)",
+        action: EscapeAction::Comment,
+        comment: Some("// SAFETY:"),
+        advice: "Add a // SAFETY: comment explaining why the value can't be null, or handle null with ?. / ?: instead.",
+        in_tests: None,
+    },
+    EscapePattern {
+        name: "as_any",
+        pattern: r"\bas\??\s+Any\b",
+        action: EscapeAction::Comment,
+        comment: Some("// CAST:"),
+        advice: "Add a // CAST: comment explaining why the value must be widened to Any.",
+        in_tests: None,
+    },
+];
+
+/// JVM (Java/Kotlin) language adapter.
+pub struct JvmAdapter {
+    source_patterns: GlobSet,
+    test_patterns: GlobSet,
+    exclude_patterns: GlobSet,
+}
+
+impl JvmAdapter {
+    /// Create a new JVM adapter with default Java and Kotlin patterns.
+    pub fn new() -> Self {
+        let mut source = JavaConfig::default_source();
+        source.extend(KotlinConfig::default_source());
+        let mut tests = JavaConfig::default_tests();
+        tests.extend(KotlinConfig::default_tests());
+
+        Self {
+            source_patterns: build_glob_set(&source),
+            test_patterns: build_glob_set(&tests),
+            exclude_patterns: build_glob_set(&[
+                "build/**".to_string(),
+                "target/**".to_string(),
+                ".gradle/**".to_string(),
+                "out/**".to_string(),
+                ".kotlin/**".to_string(),
+            ]),
+        }
+    }
+
+    /// Create a JVM adapter with resolved patterns from config.
+    pub fn with_patterns(patterns: super::ResolvedPatterns) -> Self {
+        let exclude_globs = normalize_exclude_patterns(&patterns.exclude);
+
+        Self {
+            source_patterns: build_glob_set(&patterns.source),
+            test_patterns: build_glob_set(&patterns.test),
+            exclude_patterns: build_glob_set(&exclude_globs),
+        }
+    }
+
+    /// Check if a path matches exclude patterns.
+    pub fn should_exclude(&self, path: &Path) -> bool {
+        common::patterns::check_exclude_patterns(
+            path,
+            &self.exclude_patterns,
+            Some(&["build", "target", ".gradle", "out"]),
+        )
+    }
+}
+
+impl Default for JvmAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Adapter for JvmAdapter {
+    fn name(&self) -> &'static str {
+        "jvm"
+    }
+
+    fn extensions(&self) -> &'static [&'static str] {
+        &["java", "kt", "kts"]
+    }
+
+    fn classify(&self, path: &Path) -> FileKind {
+        // Check exclude patterns first
+        if self.should_exclude(path) {
+            return FileKind::Other;
+        }
+
+        // Test patterns take precedence
+        if self.test_patterns.is_match(path) {
+            return FileKind::Test;
+        }
+
+        // Source patterns
+        if self.source_patterns.is_match(path) {
+            return FileKind::Source;
+        }
+
+        FileKind::Other
+    }
+
+    fn default_escapes(&self) -> &'static [EscapePattern] {
+        JVM_ESCAPE_PATTERNS
+    }
+
+    fn declaration_keywords(&self) -> &'static [&'static str] {
+        &["class", "interface", "enum", "record", "fun", "object"]
+    }
+}
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Unit tests for the JVM adapter.
+
+use std::path::Path;
+
+use super::*;
+
+#[test]
+fn classifies_main_source_set_as_source() {
+    let adapter = JvmAdapter::new();
+    assert_eq!(adapter.classify(Path::new("src/main/java/com/example/App.java")), FileKind::Source);
+    assert_eq!(
+        adapter.classify(Path::new("app/src/main/kotlin/com/example/App.kt")),
+        FileKind::Source
+    );
+}
+
+#[test]
+fn classifies_gradle_kotlin_scripts_as_source() {
+    let adapter = JvmAdapter::new();
+    assert_eq!(adapter.classify(Path::new("build.gradle.kts")), FileKind::Source);
+}
+
+#[test]
+fn classifies_test_source_sets_as_test() {
+    let adapter = JvmAdapter::new();
+    assert_eq!(
+        adapter.classify(Path::new("src/test/java/com/example/AppTest.java")),
+        FileKind::Test
+    );
+    assert_eq!(
+        adapter.classify(Path::new("core/src/test/kotlin/com/example/Fixtures.kt")),
+        FileKind::Test
+    );
+    assert_eq!(
+        adapter.classify(Path::new("src/integrationTest/java/com/example/DbIT.java")),
+        FileKind::Test
+    );
+    assert_eq!(
+        adapter.classify(Path::new("app/src/androidTest/kotlin/MainActivityTest.kt")),
+        FileKind::Test
+    );
+}
+
+#[test]
+fn classifies_test_named_files_as_test() {
+    let adapter = JvmAdapter::new();
+    assert_eq!(adapter.classify(Path::new("tests/ParserTest.java")), FileKind::Test);
+    assert_eq!(adapter.classify(Path::new("lib/ParserTests.kt")), FileKind::Test);
+}
+
+#[test]
+fn ignores_build_output() {
+    let adapter = JvmAdapter::new();
+    assert_eq!(adapter.classify(Path::new("build/generated/source/Foo.java")), FileKind::Other);
+    assert_eq!(adapter.classify(Path::new("target/classes/Foo.java")), FileKind::Other);
+    assert_eq!(adapter.classify(Path::new(".gradle/8.5/Foo.kt")), FileKind::Other);
+}
+
+#[test]
+fn non_jvm_files_are_other() {
+    let adapter = JvmAdapter::new();
+    assert_eq!(adapter.classify(Path::new("pom.xml")), FileKind::Other);
+    assert_eq!(adapter.classify(Path::new("README.md")), FileKind::Other);
+}
+
+#[test]
+fn handles_java_and_kotlin_extensions() {
+    let adapter = JvmAdapter::new();
+    assert_eq!(adapter.extensions(), &["java", "kt", "kts"]);
+    assert_eq!(adapter.name(), "jvm");
+}
+
+// =============================================================================
+// DEFAULT ESCAPE PATTERNS
+// =============================================================================
+
+fn escape_regex(name: &str) -> regex::Regex {
+    let pattern = JVM_ESCAPE_PATTERNS.iter().find(|p| p.name == name).unwrap().pattern;
+    regex::Regex::new(pattern).unwrap()
+}
+
+#[test]
+fn suppress_warnings_pattern_matches_annotation() {
+    let re = escape_regex("suppress_warnings");
+    assert!(re.is_match("@SuppressWarnings(\"unchecked\")"));
+    assert!(!re.is_match("@Suppress(\"UNCHECKED_CAST\")"));
+}
+
+#[test]
+fn not_null_assertion_pattern() {
+    let re = escape_regex("not_null_assertion");
+    assert!(re.is_match("val name = user!!.name"));
+    assert!(re.is_match("call(map[key]!!)"));
+    assert!(re.is_match("return value!!\n"));
+    assert!(!re.is_match("println(\"Hello!!\")"));
+    assert!(!re.is_match("if (!!flag) {}"));
+}
+
+#[test]
+fn as_any_pattern() {
+    let re = escape_regex("as_any");
+    assert!(re.is_match("val x = value as Any"));
+    assert!(re.is_match("val x = value as? Any"));
+    assert!(!re.is_match("val x = value as AnyRef"));
+}
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Java/Kotlin suppress directive parsing.
+//!
+//! Parses Kotlin `@Suppress("UNCHECKED_CAST")` / `@file:Suppress(...)`
+//! annotations and IntelliJ `// noinspection UnusedAssignment` comments.
+
+use crate::adapter::common::suppress::{CommentStyle, check_justification_comment};
+
+/// Maximum lines an `@Suppress(...)` argument list may span.
+const MAX_ANNOTATION_LINES: usize = 10;
+
+/// Suppress directive found in Java or Kotlin source.
+#[derive(Debug, Clone)]
+pub struct JvmSuppress {
+    /// Line number (0-indexed).
+    pub line: usize,
+    /// Directive type: `@Suppress` or `noinspection`.
+    pub kind: JvmSuppressKind,
+    /// Inspection codes being suppressed (e.g., ["UNCHECKED_CAST"]).
+    pub codes: Vec<String>,
+    /// Whether a justification comment was found.
+    pub has_comment: bool,
+    /// The comment text if found.
+    pub comment_text: Option<String>,
+}
+
+/// Kind of JVM suppress directive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JvmSuppressKind {
+    /// Kotlin `@Suppress(...)` or `@file:Suppress(...)` annotation.
+    Suppress,
+    /// IntelliJ `// noinspection` comment.
+    Noinspection,
+}
+
+impl std::fmt::Display for JvmSuppressKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Suppress => write!(f, "@Suppress"),
+            Self::Noinspection => write!(f, "noinspection"),
+        }
+    }
+}
+
+/// Parse `@Suppress` and `// noinspection` directives from Java/Kotlin source.
+pub fn parse_jvm_suppresses(content: &str, comment_pattern: Option<&str>) -> Vec<JvmSuppress> {
+    let mut suppresses = Vec::new();
+    let lines: Vec<&str> = content.lines().collect();
+
+    for (line_idx, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        let parsed = if let Some(codes) = parse_noinspection(trimmed) {
+            Some((JvmSuppressKind::Noinspection, codes))
+        } else if trimmed.starts_with("//") || trimmed.starts_with('*') {
+            // Annotations mentioned in comments or KDoc don't suppress anything
+            None
+        } else {
+            parse_suppress_annotation(&lines, line_idx).map(|c| (JvmSuppressKind::Suppress, c))
+        };
+
+        let Some((kind, codes)) = parsed else {
+            continue;
+        };
+        if codes.is_empty() {
+            continue;
+        }
+
+        let (has_comment, comment_text) =
+            check_justification_comment(&lines, line_idx, comment_pattern, &CommentStyle::JVM);
+
+        suppresses.push(JvmSuppress { line: line_idx, kind, codes, has_comment, comment_text });
+    }
+
+    suppresses
+}
+
+/// Parse `//noinspection A,B` (with or without a space after `//`).
+fn parse_noinspection(trimmed: &str) -> Option<Vec<String>> {
+    let comment = trimmed.strip_prefix("//")?.trim_start();
+    let rest = comment.strip_prefix("noinspection")?;
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    Some(
+        rest.split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|s| !s.is_empty())
+            .map(String::from)
+            .collect(),
+    )
+}
+
+/// Parse the codes of an `@Suppress(...)` annotation starting on `line_idx`.
+///
+/// The argument list may continue over following lines.
+fn parse_suppress_annotation(lines: &[&str], line_idx: usize) -> Option<Vec<String>> {
+    let line = lines[line_idx];
+    let start = ["@Suppress(", "@file:Suppress(", "@Suppress ("]
+        .iter()
+        .filter_map(|marker| line.find(marker).map(|pos| pos + marker.len()))
+        .min()?;
+
+    let mut args = String::new();
+    for (offset, text) in lines[line_idx..].iter().take(MAX_ANNOTATION_LINES).enumerate() {
+        let text = if offset == 0 { &text[start..] } else { text };
+        match text.find(')') {
+            Some(end) => {
+                args.push_str(&text[..end]);
+                break;
+            }
+            None => {
+                args.push_str(text);
+                args.push('\n');
+            }
+        }
+    }
+
+    // Codes are the string literals: @Suppress("A", "B") or @Suppress(names = ["A"])
+    Some(args.split('"').skip(1).step_by(2).map(String::from).collect())
+}
+
+#[cfg(test)]
+#[path = "suppress_tests.rs"]
+mod tests;
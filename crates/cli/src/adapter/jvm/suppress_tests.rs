@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Unit tests for Java/Kotlin suppress directive parsing.
+
+use super::*;
+
+#[test]
+fn parse_kotlin_suppress_single_code() {
+    let content = "@Suppress(\"UNCHECKED_CAST\")\nfun cast(x: Any) = x as List<String>";
+    let suppresses = parse_jvm_suppresses(content, None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert_eq!(suppresses[0].kind, JvmSuppressKind::Suppress);
+    assert_eq!(suppresses[0].codes, vec!["UNCHECKED_CAST"]);
+    assert_eq!(suppresses[0].line, 0);
+}
+
+#[test]
+fn parse_kotlin_suppress_multiple_codes() {
+    let content = "@Suppress(\"UNUSED_PARAMETER\", \"DEPRECATION\")\nfun f(x: Int) {}";
+    let suppresses = parse_jvm_suppresses(content, None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert_eq!(suppresses[0].codes, vec!["UNUSED_PARAMETER", "DEPRECATION"]);
+}
+
+#[test]
+fn parse_file_suppress() {
+    let content = "@file:Suppress(\"MatchingDeclarationName\")\npackage com.example";
+    let suppresses = parse_jvm_suppresses(content, None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert_eq!(suppresses[0].codes, vec!["MatchingDeclarationName"]);
+}
+
+#[test]
+fn parse_multiline_suppress() {
+    let content = "@Suppress(\n    \"LongMethod\",\n    \"ComplexMethod\",\n)\nfun big() {}";
+    let suppresses = parse_jvm_suppresses(content, None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert_eq!(suppresses[0].line, 0);
+    assert_eq!(suppresses[0].codes, vec!["LongMethod", "ComplexMethod"]);
+}
+
+#[test]
+fn suppress_warnings_is_not_a_suppress_directive() {
+    // @SuppressWarnings is covered by the suppress_warnings escape pattern
+    let content = "@SuppressWarnings(\"unchecked\")\nvoid f() {}";
+    assert!(parse_jvm_suppresses(content, None).is_empty());
+}
+
+#[test]
+fn parse_noinspection_comment() {
+    let content = "//noinspection SpellCheckingInspection,unused\nString teh = \"x\";";
+    let suppresses = parse_jvm_suppresses(content, None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert_eq!(suppresses[0].kind, JvmSuppressKind::Noinspection);
+    assert_eq!(suppresses[0].codes, vec!["SpellCheckingInspection", "unused"]);
+}
+
+#[test]
+fn parse_noinspection_with_space() {
+    let content = "// noinspection UnusedAssignment\nint x = 1;";
+    let suppresses = parse_jvm_suppresses(content, None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert_eq!(suppresses[0].codes, vec!["UnusedAssignment"]);
+}
+
+#[test]
+fn annotation_in_comment_is_ignored() {
+    let content = "// Use @Suppress(\"X\") sparingly\n * @Suppress(\"Y\") in KDoc\nfun f() {}";
+    assert!(parse_jvm_suppresses(content, None).is_empty());
+}
+
+#[test]
+fn detects_justification_comment() {
+    let content = "// Generic type is erased at runtime\n@Suppress(\"UNCHECKED_CAST\")\nfun f() {}";
+    let suppresses = parse_jvm_suppresses(content, None);
+
+    assert!(suppresses[0].has_comment);
+    assert_eq!(suppresses[0].comment_text.as_deref(), Some("Generic type is erased at runtime"));
+}
+
+#[test]
+fn justification_skips_other_annotations() {
+    let content =
+        "// Framework calls this reflectively\n@JvmStatic\n@Suppress(\"unused\")\nfun f() {}";
+    let suppresses = parse_jvm_suppresses(content, None);
+
+    assert!(suppresses[0].has_comment);
+}
+
+#[test]
+fn no_justification_comment() {
+    let content = "fun g() {}\n@Suppress(\"unused\")\nfun f() {}";
+    let suppresses = parse_jvm_suppresses(content, None);
+
+    assert!(!suppresses[0].has_comment);
+}
+
+#[test]
+fn required_comment_pattern() {
+    let content = "// Some note\n@Suppress(\"unused\")\nfun f() {}";
+    let suppresses = parse_jvm_suppresses(content, Some("// REASON:"));
+    assert!(!suppresses[0].has_comment);
+
+    let content = "// REASON: called via reflection\n@Suppress(\"unused\")\nfun f() {}";
+    let suppresses = parse_jvm_suppresses(content, Some("// REASON:"));
+    assert!(suppresses[0].has_comment);
+}
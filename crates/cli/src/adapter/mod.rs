@@ -40,6 +40,7 @@
 //!
 //! - `name() -> &'static str`
 //! - `extensions() -> &'static [&'static str]`
+//! - `filenames() -> &'static [&'static str]` (optional, for files without an extension)
 //! - `classify(&Path) -> FileKind`
 //! - `default_escapes() -> &'static [EscapePattern]`
 //!
@@ -70,17 +71,26 @@ use std::path::Path;
 use std::sync::Arc;
 
 pub mod common;
+pub mod cpp;
+pub mod csharp;
+pub mod declarative;
+pub mod elixir;
 pub mod generic;
 pub mod glob;
 pub mod go;
+pub mod iac;
 pub mod javascript;
+pub mod jvm;
 pub mod patterns;
+pub mod php;
 pub mod project;
 pub mod python;
 pub mod ruby;
 pub mod rust;
 pub mod shell;
+pub mod swift;
 
+pub use declarative::DeclarativeAdapter;
 pub use generic::GenericAdapter;
 pub use go::{enumerate_packages, parse_nolint_directives};
 pub use javascript::JsWorkspace;
@@ -89,11 +99,18 @@ pub use rust::parse_suppress_attrs;
 pub(crate) use glob::build_glob_set;
 pub(crate) use shell::{ShellAdapter, parse_shellcheck_suppresses};
 
+pub(crate) use cpp::{CppAdapter, parse_cpp_suppresses};
+pub(crate) use csharp::{CSharpAdapter, parse_csharp_suppresses};
+pub(crate) use elixir::{ElixirAdapter, parse_elixir_suppresses};
 pub(crate) use go::GoAdapter;
+pub(crate) use iac::IacAdapter;
 pub(crate) use javascript::{Bundler, JavaScriptAdapter, detect_bundler};
+pub(crate) use jvm::{JvmAdapter, JvmSuppressKind, parse_jvm_suppresses};
+pub(crate) use php::{PhpAdapter, parse_php_suppresses};
 pub(crate) use python::PythonAdapter;
 pub(crate) use ruby::{RubyAdapter, parse_ruby_suppresses};
 pub use rust::{CfgTestInfo, RustAdapter};
+pub(crate) use swift::{SwiftAdapter, parse_swift_suppresses};
 
 /// File classification result.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -111,15 +128,22 @@ pub enum FileKind {
 /// Adapters are responsible for:
 /// - Classifying files as source, test, or other
 /// - Providing default escape patterns
+/// - Naming declaration keywords for the symbol index
 /// - TODO(Future): Inline test detection, lint suppression patterns
 pub trait Adapter: Send + Sync {
     /// Adapter identifier (e.g., "rust", "shell", "generic").
-    fn name(&self) -> &'static str;
+    fn name(&self) -> &str;
 
     /// File extensions this adapter handles (e.g., ["rs"] for Rust).
     /// Empty slice means this adapter doesn't match by extension (generic fallback).
     fn extensions(&self) -> &'static [&'static str];
 
+    /// File names this adapter handles regardless of extension (e.g., ["Dockerfile"]).
+    /// Variants such as `Dockerfile.dev` match by the part before the first dot.
+    fn filenames(&self) -> &'static [&'static str] {
+        &[]
+    }
+
     /// Classify a file by its path relative to the project root.
     fn classify(&self, path: &Path) -> FileKind;
 
@@ -128,6 +152,13 @@ pub trait Adapter: Send + Sync {
     fn default_escapes(&self) -> &'static [EscapePattern] {
         &[]
     }
+
+    /// Keywords that introduce a named declaration (e.g., `fn`, `struct`),
+    /// used to index symbols referenced from agent files.
+    /// Returns empty slice for languages without an index (generic).
+    fn declaration_keywords(&self) -> &'static [&'static str] {
+        &[]
+    }
 }
 
 /// An escape pattern with its action.
@@ -163,6 +194,10 @@ pub enum EscapeAction {
 pub struct AdapterRegistry {
     /// Adapters by extension (e.g., "rs" -> RustAdapter).
     by_extension: HashMap<&'static str, Arc<dyn Adapter>>,
+    /// Adapters by file name (e.g., "Dockerfile" -> IacAdapter), checked before extensions.
+    by_filename: HashMap<&'static str, Arc<dyn Adapter>>,
+    /// Declarative adapters from config, checked before built-in adapters.
+    declarative: Vec<Arc<DeclarativeAdapter>>,
     /// Fallback adapter for unrecognized extensions.
     fallback: Arc<dyn Adapter>,
 }
@@ -170,23 +205,62 @@ pub struct AdapterRegistry {
 impl AdapterRegistry {
     /// Create a new registry with the given fallback adapter.
     pub fn new(fallback: Arc<dyn Adapter>) -> Self {
-        Self { by_extension: HashMap::new(), fallback }
+        Self {
+            by_extension: HashMap::new(),
+            by_filename: HashMap::new(),
+            declarative: Vec::new(),
+            fallback,
+        }
     }
 
-    /// Register an adapter for its declared extensions.
+    /// Register an adapter for its declared extensions and file names.
     pub fn register(&mut self, adapter: Arc<dyn Adapter>) {
         for ext in adapter.extensions() {
             self.by_extension.insert(ext, Arc::clone(&adapter));
         }
+        for name in adapter.filenames() {
+            self.by_filename.insert(name, Arc::clone(&adapter));
+        }
+    }
+
+    /// Register a declarative adapter for the extensions and file name globs in its spec.
+    ///
+    /// Declarative adapters take precedence over built-in adapters, so a spec can
+    /// take over an extension. The first matching spec wins.
+    pub fn register_declarative(&mut self, adapter: Arc<DeclarativeAdapter>) {
+        self.declarative.push(adapter);
     }
 
-    /// Get the adapter for a file path based on extension.
+    /// Get the declarative adapter handling a file, if any.
+    pub fn declarative_for(&self, path: &Path) -> Option<&DeclarativeAdapter> {
+        self.declarative.iter().find(|a| a.matches(path)).map(|a| a.as_ref())
+    }
+
+    /// Get the adapter for a file path: declarative adapters, then file name, then extension.
     pub fn adapter_for(&self, path: &Path) -> &dyn Adapter {
+        if let Some(adapter) = self.declarative_for(path) {
+            return adapter;
+        }
+
+        if let Some(adapter) = self.adapter_by_filename(path) {
+            return adapter;
+        }
+
         let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
 
         self.by_extension.get(ext).map(|a| a.as_ref()).unwrap_or(self.fallback.as_ref())
     }
 
+    /// Match `Dockerfile` and variants like `Dockerfile.dev` against registered file names.
+    fn adapter_by_filename(&self, path: &Path) -> Option<&dyn Adapter> {
+        if self.by_filename.is_empty() {
+            return None;
+        }
+        let name = path.file_name()?.to_str()?;
+        let stem = name.split_once('.').map_or(name, |(stem, _)| stem);
+        self.by_filename.get(stem).map(|a| a.as_ref())
+    }
+
     /// Classify a file using the appropriate adapter.
     pub fn classify(&self, path: &Path) -> FileKind {
         self.adapter_for(path).classify(path)
@@ -208,9 +282,15 @@ impl Default for AdapterRegistry {
 pub enum ProjectLanguage {
     Rust,
     Go,
+    Jvm,
+    CSharp,
+    Php,
+    Swift,
+    Elixir,
     JavaScript,
     Python,
     Ruby,
+    Cpp,
     Shell,
     Generic,
 }
@@ -220,9 +300,15 @@ impl std::fmt::Display for ProjectLanguage {
         match self {
             ProjectLanguage::Rust => write!(f, "Rust"),
             ProjectLanguage::Go => write!(f, "Go"),
+            ProjectLanguage::Jvm => write!(f, "JVM"),
+            ProjectLanguage::CSharp => write!(f, "C#"),
+            ProjectLanguage::Php => write!(f, "PHP"),
+            ProjectLanguage::Swift => write!(f, "Swift"),
+            ProjectLanguage::Elixir => write!(f, "Elixir"),
             ProjectLanguage::JavaScript => write!(f, "JavaScript"),
             ProjectLanguage::Python => write!(f, "Python"),
             ProjectLanguage::Ruby => write!(f, "Ruby"),
+            ProjectLanguage::Cpp => write!(f, "C/C++"),
             ProjectLanguage::Shell => write!(f, "Shell"),
             ProjectLanguage::Generic => write!(f, "Generic"),
         }
@@ -239,6 +325,31 @@ pub fn detect_language(root: &Path) -> ProjectLanguage {
         return ProjectLanguage::Go;
     }
 
+    // JVM detection (before JavaScript: Gradle/Maven builds may carry a package.json)
+    if has_jvm_markers(root) {
+        return ProjectLanguage::Jvm;
+    }
+
+    // C# detection (before JavaScript: ASP.NET projects often carry a package.json)
+    if has_csharp_markers(root) {
+        return ProjectLanguage::CSharp;
+    }
+
+    // PHP detection (before JavaScript: Laravel/Symfony apps carry a package.json)
+    if has_php_markers(root) {
+        return ProjectLanguage::Php;
+    }
+
+    // Swift detection (before JavaScript: SwiftPM packages may carry web tooling)
+    if has_swift_markers(root) {
+        return ProjectLanguage::Swift;
+    }
+
+    // Elixir detection (before JavaScript: Phoenix apps may carry a package.json for assets)
+    if has_elixir_markers(root) {
+        return ProjectLanguage::Elixir;
+    }
+
     // JavaScript detection (before Shell check)
     if root.join("package.json").exists()
         || root.join("tsconfig.json").exists()
@@ -257,6 +368,11 @@ pub fn detect_language(root: &Path) -> ProjectLanguage {
         return ProjectLanguage::Ruby;
     }
 
+    // C/C++ detection (after other languages: native extensions often carry a CMakeLists.txt)
+    if has_cpp_markers(root) {
+        return ProjectLanguage::Cpp;
+    }
+
     // Check for Shell project markers: *.sh in root, bin/, or scripts/
     if has_shell_markers(root) {
         return ProjectLanguage::Shell;
@@ -265,6 +381,50 @@ pub fn detect_language(root: &Path) -> ProjectLanguage {
     ProjectLanguage::Generic
 }
 
+/// Check if project has JVM (Java/Kotlin) markers.
+/// Detection: pom.xml, build.gradle(.kts), or settings.gradle(.kts)
+fn has_jvm_markers(root: &Path) -> bool {
+    ["pom.xml", "build.gradle", "build.gradle.kts", "settings.gradle", "settings.gradle.kts"]
+        .iter()
+        .any(|marker| root.join(marker).exists())
+}
+
+/// Check if project has C# (.NET) markers.
+/// Detection: *.sln, *.slnx, or *.csproj in the project root
+fn has_csharp_markers(root: &Path) -> bool {
+    root.read_dir()
+        .ok()
+        .map(|entries| {
+            entries.filter_map(|e| e.ok()).any(|entry| {
+                let path = entry.path();
+                path.is_file()
+                    && matches!(
+                        path.extension().and_then(|e| e.to_str()),
+                        Some("sln" | "slnx" | "csproj")
+                    )
+            })
+        })
+        .unwrap_or(false)
+}
+
+/// Check if project has PHP markers.
+/// Detection: composer.json
+fn has_php_markers(root: &Path) -> bool {
+    root.join("composer.json").exists()
+}
+
+/// Check if project has Swift markers.
+/// Detection: Package.swift
+fn has_swift_markers(root: &Path) -> bool {
+    root.join("Package.swift").exists()
+}
+
+/// Check if project has Elixir markers.
+/// Detection: mix.exs
+fn has_elixir_markers(root: &Path) -> bool {
+    root.join("mix.exs").exists()
+}
+
 /// Check if project has Python markers.
 /// Detection: pyproject.toml, setup.py, setup.cfg, or requirements.txt
 fn has_python_markers(root: &Path) -> bool {
@@ -283,6 +443,14 @@ fn has_ruby_markers(root: &Path) -> bool {
         || root.join("config/application.rb").exists()
 }
 
+/// Check if project has C/C++ markers.
+/// Detection: CMakeLists.txt, meson.build, conanfile.txt/py, or vcpkg.json
+fn has_cpp_markers(root: &Path) -> bool {
+    ["CMakeLists.txt", "meson.build", "conanfile.txt", "conanfile.py", "vcpkg.json"]
+        .iter()
+        .any(|marker| root.join(marker).exists())
+}
+
 /// Check if a directory contains *.gemspec files.
 fn has_gemspec(root: &Path) -> bool {
     root.read_dir()
@@ -328,6 +496,21 @@ pub fn detect_all_languages(root: &Path) -> Vec<ProjectLanguage> {
     if root.join("go.mod").exists() {
         langs.push(ProjectLanguage::Go);
     }
+    if has_jvm_markers(root) {
+        langs.push(ProjectLanguage::Jvm);
+    }
+    if has_csharp_markers(root) {
+        langs.push(ProjectLanguage::CSharp);
+    }
+    if has_php_markers(root) {
+        langs.push(ProjectLanguage::Php);
+    }
+    if has_swift_markers(root) {
+        langs.push(ProjectLanguage::Swift);
+    }
+    if has_elixir_markers(root) {
+        langs.push(ProjectLanguage::Elixir);
+    }
     if root.join("package.json").exists()
         || root.join("tsconfig.json").exists()
         || root.join("jsconfig.json").exists()
@@ -340,6 +523,9 @@ pub fn detect_all_languages(root: &Path) -> Vec<ProjectLanguage> {
     if has_ruby_markers(root) {
         langs.push(ProjectLanguage::Ruby);
     }
+    if has_cpp_markers(root) {
+        langs.push(ProjectLanguage::Cpp);
+    }
     if has_shell_markers(root) {
         langs.push(ProjectLanguage::Shell);
     }
@@ -377,6 +563,21 @@ impl AdapterRegistry {
             ProjectLanguage::Go => {
                 registry.register(Arc::new(GoAdapter::new()));
             }
+            ProjectLanguage::Jvm => {
+                registry.register(Arc::new(JvmAdapter::new()));
+            }
+            ProjectLanguage::CSharp => {
+                registry.register(Arc::new(CSharpAdapter::new()));
+            }
+            ProjectLanguage::Php => {
+                registry.register(Arc::new(PhpAdapter::new()));
+            }
+            ProjectLanguage::Swift => {
+                registry.register(Arc::new(SwiftAdapter::new()));
+            }
+            ProjectLanguage::Elixir => {
+                registry.register(Arc::new(ElixirAdapter::new()));
+            }
             ProjectLanguage::JavaScript => {
                 registry.register(Arc::new(JavaScriptAdapter::new()));
             }
@@ -386,12 +587,18 @@ impl AdapterRegistry {
             ProjectLanguage::Ruby => {
                 registry.register(Arc::new(RubyAdapter::new()));
             }
+            ProjectLanguage::Cpp => {
+                registry.register(Arc::new(CppAdapter::new()));
+            }
             ProjectLanguage::Shell => {
                 registry.register(Arc::new(ShellAdapter::new()));
             }
             ProjectLanguage::Generic => {}
         }
 
+        // Infrastructure code sits alongside any project language
+        registry.register(Arc::new(IacAdapter::new()));
+
         registry
     }
 
@@ -420,6 +627,21 @@ impl AdapterRegistry {
             ProjectLanguage::Go => {
                 registry.register(Arc::new(GoAdapter::with_patterns(resolved)));
             }
+            ProjectLanguage::Jvm => {
+                registry.register(Arc::new(JvmAdapter::with_patterns(resolved)));
+            }
+            ProjectLanguage::CSharp => {
+                registry.register(Arc::new(CSharpAdapter::with_patterns(resolved)));
+            }
+            ProjectLanguage::Php => {
+                registry.register(Arc::new(PhpAdapter::with_patterns(resolved)));
+            }
+            ProjectLanguage::Swift => {
+                registry.register(Arc::new(SwiftAdapter::with_patterns(resolved)));
+            }
+            ProjectLanguage::Elixir => {
+                registry.register(Arc::new(ElixirAdapter::with_patterns(resolved)));
+            }
             ProjectLanguage::JavaScript => {
                 registry.register(Arc::new(JavaScriptAdapter::with_patterns(resolved)));
             }
@@ -429,12 +651,23 @@ impl AdapterRegistry {
             ProjectLanguage::Ruby => {
                 registry.register(Arc::new(RubyAdapter::with_patterns(resolved)));
             }
+            ProjectLanguage::Cpp => {
+                registry.register(Arc::new(CppAdapter::with_patterns(resolved)));
+            }
             ProjectLanguage::Shell => {
                 registry.register(Arc::new(ShellAdapter::with_patterns(resolved)));
             }
             ProjectLanguage::Generic => {}
         }
 
+        // Infrastructure code sits alongside any project language
+        let iac = resolve_iac_patterns(config, &config.project.tests);
+        registry.register(Arc::new(IacAdapter::with_patterns(iac)));
+
+        for adapter in declarative_adapters(config) {
+            registry.register_declarative(Arc::new(adapter));
+        }
+
         registry
     }
 }
@@ -457,9 +690,15 @@ pub fn resolve_project_patterns(root: &Path, config: &crate::config::Config) ->
     match detect_language(root) {
         ProjectLanguage::Rust => resolve_rust_patterns(config, &fallback_test_patterns),
         ProjectLanguage::Go => resolve_go_patterns(config, &fallback_test_patterns),
+        ProjectLanguage::Jvm => resolve_jvm_patterns(config, &fallback_test_patterns),
+        ProjectLanguage::CSharp => resolve_csharp_patterns(config, &fallback_test_patterns),
+        ProjectLanguage::Php => resolve_php_patterns(config, &fallback_test_patterns),
+        ProjectLanguage::Swift => resolve_swift_patterns(config, &fallback_test_patterns),
+        ProjectLanguage::Elixir => resolve_elixir_patterns(config, &fallback_test_patterns),
         ProjectLanguage::JavaScript => resolve_javascript_patterns(config, &fallback_test_patterns),
         ProjectLanguage::Python => resolve_python_patterns(config, &fallback_test_patterns),
         ProjectLanguage::Ruby => resolve_ruby_patterns(config, &fallback_test_patterns),
+        ProjectLanguage::Cpp => resolve_cpp_patterns(config, &fallback_test_patterns),
         ProjectLanguage::Shell => resolve_shell_patterns(config, &fallback_test_patterns),
         ProjectLanguage::Generic => ResolvedPatterns {
             source: config.project.source.clone(),
@@ -492,9 +731,51 @@ macro_rules! define_resolve_patterns {
 define_resolve_patterns!(resolve_rust_patterns, rust, crate::config::RustConfig);
 define_resolve_patterns!(resolve_go_patterns, golang, crate::config::GoConfig);
 define_resolve_patterns!(resolve_javascript_patterns, javascript, crate::config::JavaScriptConfig);
+define_resolve_patterns!(resolve_java_patterns, java, crate::config::JavaConfig);
+define_resolve_patterns!(resolve_kotlin_patterns, kotlin, crate::config::KotlinConfig);
+define_resolve_patterns!(resolve_csharp_patterns, csharp, crate::config::CSharpConfig);
+define_resolve_patterns!(resolve_php_patterns, php, crate::config::PhpConfig);
+define_resolve_patterns!(resolve_swift_patterns, swift, crate::config::SwiftConfig);
+define_resolve_patterns!(resolve_elixir_patterns, elixir, crate::config::ElixirConfig);
 define_resolve_patterns!(resolve_python_patterns, python, crate::config::PythonConfig);
 define_resolve_patterns!(resolve_ruby_patterns, ruby, crate::config::RubyConfig);
+define_resolve_patterns!(resolve_cpp_patterns, cpp, crate::config::CppConfig);
 define_resolve_patterns!(resolve_shell_patterns, shell, crate::config::ShellConfig);
+define_resolve_patterns!(resolve_iac_patterns, iac, crate::config::IacConfig);
+
+/// Build adapters for the declarative specs in config.
+///
+/// Specs without `tests` fall back to `[project].tests`, then generic test patterns.
+pub fn declarative_adapters(config: &crate::config::Config) -> Vec<DeclarativeAdapter> {
+    let fallback_test = if !config.project.tests.is_empty() {
+        config.project.tests.clone()
+    } else {
+        GenericAdapter::default_test_patterns()
+    };
+    config.adapters.iter().map(|spec| DeclarativeAdapter::new(spec, &fallback_test)).collect()
+}
+
+/// JVM projects mix Java and Kotlin, so `[java]` and `[kotlin]` patterns are combined.
+pub(crate) fn resolve_jvm_patterns(
+    config: &crate::config::Config,
+    fallback_test: &[String],
+) -> ResolvedPatterns {
+    let java = resolve_java_patterns(config, fallback_test);
+    let kotlin = resolve_kotlin_patterns(config, fallback_test);
+    let merge = |mut a: Vec<String>, b: Vec<String>| {
+        for pattern in b {
+            if !a.contains(&pattern) {
+                a.push(pattern);
+            }
+        }
+        a
+    };
+    ResolvedPatterns {
+        source: merge(java.source, kotlin.source),
+        test: merge(java.test, kotlin.test),
+        exclude: merge(java.exclude, kotlin.exclude),
+    }
+}
 
 #[cfg(test)]
 #[path = "mod_tests.rs"]
@@ -40,6 +40,138 @@ fn detect_language_generic_without_cargo_toml() {
     assert_eq!(detect_language(dir.path()), ProjectLanguage::Generic);
 }
 
+#[test]
+fn detect_language_jvm_with_build_files() {
+    for marker in ["pom.xml", "build.gradle", "build.gradle.kts", "settings.gradle.kts"] {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join(marker), "").unwrap();
+
+        assert_eq!(detect_language(dir.path()), ProjectLanguage::Jvm, "{marker}");
+    }
+}
+
+#[test]
+fn detect_language_jvm_before_javascript() {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(dir.path().join("build.gradle.kts"), "").unwrap();
+    std::fs::write(dir.path().join("package.json"), "{}").unwrap();
+
+    assert_eq!(detect_language(dir.path()), ProjectLanguage::Jvm);
+    assert_eq!(
+        detect_all_languages(dir.path()),
+        vec![ProjectLanguage::Jvm, ProjectLanguage::JavaScript]
+    );
+}
+
+#[test]
+fn resolve_jvm_patterns_merges_java_and_kotlin() {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(dir.path().join("pom.xml"), "").unwrap();
+    let mut config = crate::config::Config::default();
+    config.kotlin.source = vec!["src/main/kotlin/**/*.kt".to_string()];
+
+    let patterns = resolve_project_patterns(dir.path(), &config);
+
+    assert!(patterns.source.contains(&"**/*.java".to_string()));
+    assert!(patterns.source.contains(&"src/main/kotlin/**/*.kt".to_string()));
+    assert!(patterns.exclude.contains(&"build/".to_string()));
+    let unique: std::collections::HashSet<_> = patterns.exclude.iter().collect();
+    assert_eq!(unique.len(), patterns.exclude.len());
+}
+
+#[test]
+fn detect_language_cpp_with_build_files() {
+    for marker in ["CMakeLists.txt", "meson.build", "conanfile.txt", "vcpkg.json"] {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join(marker), "").unwrap();
+
+        assert_eq!(detect_language(dir.path()), ProjectLanguage::Cpp, "{marker}");
+    }
+}
+
+#[test]
+fn detect_language_cpp_after_python() {
+    // Native extensions: a Python package built with CMake stays a Python project
+    let dir = TempDir::new().unwrap();
+    std::fs::write(dir.path().join("CMakeLists.txt"), "").unwrap();
+    std::fs::write(dir.path().join("pyproject.toml"), "").unwrap();
+
+    assert_eq!(detect_language(dir.path()), ProjectLanguage::Python);
+    assert_eq!(
+        detect_all_languages(dir.path()),
+        vec![ProjectLanguage::Python, ProjectLanguage::Cpp]
+    );
+}
+
+#[test]
+fn detect_language_csharp_with_solution_or_project() {
+    for marker in ["Acme.sln", "Acme.slnx", "Acme.Core.csproj"] {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join(marker), "").unwrap();
+
+        assert_eq!(detect_language(dir.path()), ProjectLanguage::CSharp, "{marker}");
+    }
+}
+
+#[test]
+fn detect_language_php_with_composer_json() {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(dir.path().join("composer.json"), "{}").unwrap();
+
+    assert_eq!(detect_language(dir.path()), ProjectLanguage::Php);
+}
+
+#[test]
+fn detect_language_php_before_javascript() {
+    // Laravel and Symfony apps carry a package.json for front-end assets
+    let dir = TempDir::new().unwrap();
+    std::fs::write(dir.path().join("composer.json"), "{}").unwrap();
+    std::fs::write(dir.path().join("package.json"), "{}").unwrap();
+
+    assert_eq!(detect_language(dir.path()), ProjectLanguage::Php);
+    assert_eq!(
+        detect_all_languages(dir.path()),
+        vec![ProjectLanguage::Php, ProjectLanguage::JavaScript]
+    );
+}
+
+#[test]
+fn detect_language_swift_with_package_swift() {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(dir.path().join("Package.swift"), "// swift-tools-version:5.9").unwrap();
+
+    assert_eq!(detect_language(dir.path()), ProjectLanguage::Swift);
+    assert_eq!(detect_all_languages(dir.path()), vec![ProjectLanguage::Swift]);
+}
+
+#[test]
+fn detect_language_elixir_before_javascript() {
+    // Phoenix apps may carry a package.json for front-end assets
+    let dir = TempDir::new().unwrap();
+    std::fs::write(dir.path().join("mix.exs"), "defmodule Acme.MixProject do\nend\n").unwrap();
+    std::fs::write(dir.path().join("package.json"), "{}").unwrap();
+
+    assert_eq!(detect_language(dir.path()), ProjectLanguage::Elixir);
+    assert_eq!(
+        detect_all_languages(dir.path()),
+        vec![ProjectLanguage::Elixir, ProjectLanguage::JavaScript]
+    );
+}
+
+#[test]
+fn detect_language_csharp_before_javascript() {
+    // ASP.NET projects often carry a package.json for front-end assets
+    let dir = TempDir::new().unwrap();
+    std::fs::write(dir.path().join("Acme.Web.csproj"), "").unwrap();
+    std::fs::write(dir.path().join("package.json"), "{}").unwrap();
+
+    assert_eq!(detect_language(dir.path()), ProjectLanguage::CSharp);
+    assert_eq!(
+        detect_all_languages(dir.path()),
+        vec![ProjectLanguage::CSharp, ProjectLanguage::JavaScript]
+    );
+}
+
 #[test]
 fn for_project_registers_rust_adapter() {
     let dir = TempDir::new().unwrap();
@@ -64,6 +196,56 @@ fn for_project_generic_fallback() {
     assert_eq!(registry.adapter_for(Path::new("src/lib.rs")).name(), "generic");
 }
 
+#[test]
+fn for_project_registers_iac_by_filename_and_extension() {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(dir.path().join("Cargo.toml"), "[package]\nname = \"test\"\n").unwrap();
+
+    let registry = AdapterRegistry::for_project(dir.path());
+    assert_eq!(registry.adapter_for(Path::new("Dockerfile")).name(), "iac");
+    assert_eq!(registry.adapter_for(Path::new("docker/Dockerfile.dev")).name(), "iac");
+    assert_eq!(registry.adapter_for(Path::new("infra/main.tf")).name(), "iac");
+    assert_eq!(registry.adapter_for(Path::new("src/lib.rs")).name(), "rust");
+    assert_eq!(registry.classify(Path::new("Containerfile")), FileKind::Source);
+}
+
+#[test]
+fn for_project_registers_declarative_adapters_first() {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(dir.path().join("Cargo.toml"), "[package]\nname = \"test\"\n").unwrap();
+    let config: crate::config::Config = toml::from_str(
+        r#"
+version = 1
+
+[[adapter]]
+name = "zig"
+extensions = ["zig"]
+tests = ["tests/**"]
+
+[[adapter]]
+name = "just"
+filenames = ["Justfile"]
+"#,
+    )
+    .unwrap();
+
+    let registry = AdapterRegistry::for_project_with_config(dir.path(), &config);
+    assert_eq!(registry.adapter_for(Path::new("src/main.zig")).name(), "zig");
+    assert_eq!(registry.adapter_for(Path::new("Justfile")).name(), "just");
+    assert_eq!(registry.adapter_for(Path::new("src/lib.rs")).name(), "rust");
+    assert_eq!(registry.classify(Path::new("tests/parser.zig")), FileKind::Test);
+    assert!(registry.declarative_for(Path::new("src/lib.rs")).is_none());
+}
+
+#[test]
+fn registry_filename_lookup_ignores_other_names() {
+    let dir = TempDir::new().unwrap();
+
+    let registry = AdapterRegistry::for_project(dir.path());
+    assert_eq!(registry.adapter_for(Path::new("Dockerfiles/README.md")).name(), "generic");
+    assert_eq!(registry.adapter_for(Path::new("Makefile")).name(), "generic");
+}
+
 #[test]
 fn detect_all_languages_single() {
     let dir = TempDir::new().unwrap();
@@ -103,9 +285,15 @@ fn detect_all_languages_empty_is_generic() {
 fn project_language_display() {
     assert_eq!(ProjectLanguage::Rust.to_string(), "Rust");
     assert_eq!(ProjectLanguage::Go.to_string(), "Go");
+    assert_eq!(ProjectLanguage::Jvm.to_string(), "JVM");
+    assert_eq!(ProjectLanguage::CSharp.to_string(), "C#");
+    assert_eq!(ProjectLanguage::Php.to_string(), "PHP");
+    assert_eq!(ProjectLanguage::Swift.to_string(), "Swift");
+    assert_eq!(ProjectLanguage::Elixir.to_string(), "Elixir");
     assert_eq!(ProjectLanguage::JavaScript.to_string(), "JavaScript");
     assert_eq!(ProjectLanguage::Python.to_string(), "Python");
     assert_eq!(ProjectLanguage::Ruby.to_string(), "Ruby");
+    assert_eq!(ProjectLanguage::Cpp.to_string(), "C/C++");
     assert_eq!(ProjectLanguage::Shell.to_string(), "Shell");
     assert_eq!(ProjectLanguage::Generic.to_string(), "Generic");
 }
@@ -59,8 +59,16 @@ impl_language_defaults!(
     crate::config::RustConfig,
     crate::config::GoConfig,
     crate::config::JavaScriptConfig,
+    crate::config::JavaConfig,
+    crate::config::KotlinConfig,
     crate::config::PythonConfig,
     crate::config::RubyConfig,
+    crate::config::CppConfig,
+    crate::config::CSharpConfig,
+    crate::config::PhpConfig,
+    crate::config::SwiftConfig,
+    crate::config::ElixirConfig,
+    crate::config::IacConfig,
     crate::config::ShellConfig,
 );
 
@@ -116,6 +124,25 @@ pub fn correlation_exclude_defaults(lang: super::ProjectLanguage) -> Vec<String>
         super::ProjectLanguage::Go => {
             patterns.push("**/main.go".to_string());
         }
+        super::ProjectLanguage::Jvm => {
+            patterns.extend(["**/package-info.java", "**/module-info.java"].map(String::from));
+        }
+        super::ProjectLanguage::CSharp => {
+            patterns.extend(
+                ["**/Program.cs", "**/AssemblyInfo.cs", "**/GlobalUsings.cs"].map(String::from),
+            );
+        }
+        super::ProjectLanguage::Php => {
+            patterns.extend(["**/index.php", "**/bootstrap.php"].map(String::from));
+        }
+        super::ProjectLanguage::Swift => {
+            patterns.extend(["**/main.swift", "**/Package.swift"].map(String::from));
+        }
+        super::ProjectLanguage::Elixir => {
+            patterns.extend(
+                ["**/mix.exs", "**/application.ex", "config/**", "**/config/**"].map(String::from),
+            );
+        }
         super::ProjectLanguage::Python => {
             patterns.push("**/__init__.py".to_string());
         }
@@ -125,6 +152,9 @@ pub fn correlation_exclude_defaults(lang: super::ProjectLanguage) -> Vec<String>
             );
         }
         super::ProjectLanguage::Ruby => {}
+        super::ProjectLanguage::Cpp => {
+            patterns.push("**/main.c*".to_string());
+        }
         super::ProjectLanguage::Shell => {}
         super::ProjectLanguage::Generic => {}
     }
@@ -0,0 +1,139 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! PHP language adapter.
+//!
+//! Provides PHP-specific behavior for checks:
+//! - File classification (`tests/`, `*Test.php`)
+//! - Default patterns for PHP sources
+//! - Default escape patterns (`eval(`, `@` error suppression, `extract(`)
+//! - phpstan, psalm and phpcs suppression parsing
+//!
+//! See docs/specs/langs/php.md for specification.
+
+use std::path::Path;
+
+use globset::GlobSet;
+
+mod suppress;
+
+pub use suppress::{PhpSuppress, PhpSuppressKind, parse_php_suppresses};
+
+use super::common;
+use super::common::patterns::normalize_exclude_patterns;
+use super::glob::build_glob_set;
+use super::{Adapter, EscapeAction, EscapePattern, FileKind};
+use crate::config::PhpConfig;
+
+/// Default escape patterns for PHP.
+const PHP_ESCAPE_PATTERNS: &[EscapePattern] = &[
+    EscapePattern {
+        name: "eval",
+        pattern: r"\beval\s*\(",
+        action: EscapeAction::Comment,
+        comment: Some("// EVAL:"),
+        advice: "Add a // EVAL: comment explaining why eval is necessary and where its input comes from.",
+        in_tests: None,
+    },
+    // `@` before a call, variable or property access (`@unlink($f)`, `$x = @$a['k']`),
+    // but not docblock tags (`* @param`) or email addresses
+    EscapePattern {
+        name: "error_suppression",
+        pattern: r"(?:^|[=(,!?:;.&|]|\breturn|\becho)\s*@\s*\$?[A-Za-z_\\][\w\\]*\s*(?:[(\[]|->)",
+        action: EscapeAction::Comment,
+        comment: Some("// SUPPRESS:"),
+        advice: "Add a // SUPPRESS: comment explaining why the error can be ignored, or check the result and handle the failure explicitly.",
+        in_tests: None,
+    },
+    EscapePattern {
+        name: "extract",
+        pattern: r"\bextract\s*\(",
+        action: EscapeAction::Comment,
+        comment: Some("// EXTRACT:"),
+        advice: "Add a // EXTRACT: comment explaining which variables are created, or assign them explicitly from the array.",
+        in_tests: None,
+    },
+];
+
+/// PHP language adapter.
+pub struct PhpAdapter {
+    source_patterns: GlobSet,
+    test_patterns: GlobSet,
+    exclude_patterns: GlobSet,
+}
+
+impl PhpAdapter {
+    /// Create a new PHP adapter with default patterns.
+    pub fn new() -> Self {
+        Self {
+            source_patterns: build_glob_set(&PhpConfig::default_source()),
+            test_patterns: build_glob_set(&PhpConfig::default_tests()),
+            exclude_patterns: build_glob_set(&normalize_exclude_patterns(
+                &PhpConfig::default_exclude(),
+            )),
+        }
+    }
+
+    /// Create a PHP adapter with resolved patterns from config.
+    pub fn with_patterns(patterns: super::ResolvedPatterns) -> Self {
+        let exclude_globs = normalize_exclude_patterns(&patterns.exclude);
+
+        Self {
+            source_patterns: build_glob_set(&patterns.source),
+            test_patterns: build_glob_set(&patterns.test),
+            exclude_patterns: build_glob_set(&exclude_globs),
+        }
+    }
+
+    /// Check if a path matches exclude patterns.
+    pub fn should_exclude(&self, path: &Path) -> bool {
+        common::patterns::check_exclude_patterns(path, &self.exclude_patterns, Some(&["vendor"]))
+    }
+}
+
+impl Default for PhpAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Adapter for PhpAdapter {
+    fn name(&self) -> &'static str {
+        "php"
+    }
+
+    fn extensions(&self) -> &'static [&'static str] {
+        &["php"]
+    }
+
+    fn classify(&self, path: &Path) -> FileKind {
+        // Check exclude patterns first
+        if self.should_exclude(path) {
+            return FileKind::Other;
+        }
+
+        // Test patterns take precedence
+        if self.test_patterns.is_match(path) {
+            return FileKind::Test;
+        }
+
+        // Source patterns
+        if self.source_patterns.is_match(path) {
+            return FileKind::Source;
+        }
+
+        FileKind::Other
+    }
+
+    fn default_escapes(&self) -> &'static [EscapePattern] {
+        PHP_ESCAPE_PATTERNS
+    }
+
+    fn declaration_keywords(&self) -> &'static [&'static str] {
+        &["function", "class", "interface", "trait", "enum"]
+    }
+}
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Unit tests for the PHP adapter.
+
+use std::path::Path;
+
+use super::*;
+
+#[test]
+fn classifies_php_files_as_source() {
+    let adapter = PhpAdapter::new();
+    assert_eq!(adapter.classify(Path::new("src/Http/Kernel.php")), FileKind::Source);
+    assert_eq!(adapter.classify(Path::new("index.php")), FileKind::Source);
+}
+
+#[test]
+fn classifies_tests_as_test() {
+    let adapter = PhpAdapter::new();
+    assert_eq!(adapter.classify(Path::new("tests/Unit/KernelTest.php")), FileKind::Test);
+    assert_eq!(adapter.classify(Path::new("tests/Pest.php")), FileKind::Test);
+    assert_eq!(adapter.classify(Path::new("test/bootstrap.php")), FileKind::Test);
+    assert_eq!(adapter.classify(Path::new("src/Http/KernelTest.php")), FileKind::Test);
+}
+
+#[test]
+fn ignores_vendor_and_caches() {
+    let adapter = PhpAdapter::new();
+    assert_eq!(
+        adapter.classify(Path::new("vendor/laravel/framework/src/App.php")),
+        FileKind::Other
+    );
+    assert_eq!(adapter.classify(Path::new("var/cache/dev/Container.php")), FileKind::Other);
+    assert_eq!(adapter.classify(Path::new("bootstrap/cache/services.php")), FileKind::Other);
+}
+
+#[test]
+fn non_php_files_are_other() {
+    let adapter = PhpAdapter::new();
+    assert_eq!(adapter.classify(Path::new("composer.json")), FileKind::Other);
+    assert_eq!(adapter.classify(Path::new("phpunit.xml.dist")), FileKind::Other);
+    assert_eq!(adapter.name(), "php");
+}
+
+// =============================================================================
+// DEFAULT ESCAPE PATTERNS
+// =============================================================================
+
+fn escape_regex(name: &str) -> regex::Regex {
+    let pattern = PHP_ESCAPE_PATTERNS.iter().find(|p| p.name == name).unwrap().pattern;
+    regex::Regex::new(pattern).unwrap()
+}
+
+#[test]
+fn eval_pattern() {
+    let re = escape_regex("eval");
+    assert!(re.is_match("eval($code);"));
+    assert!(re.is_match("    $result = eval ('return 1;');"));
+    assert!(!re.is_match("$evaluate($x);"));
+    assert!(!re.is_match("$this->evaluator->run();"));
+}
+
+#[test]
+fn error_suppression_pattern() {
+    let re = escape_regex("error_suppression");
+    assert!(re.is_match("@unlink($path);"));
+    assert!(re.is_match("$data = @file_get_contents($url);"));
+    assert!(re.is_match("$value = @$options['key'];"));
+    assert!(re.is_match("if (!@mkdir($dir)) {"));
+    assert!(re.is_match("return @$this->cache->get($key);"));
+    assert!(re.is_match("$fh = @\\fopen($file, 'r');"));
+    assert!(!re.is_match(" * @param string $path"));
+    assert!(!re.is_match("// @phpstan-ignore-next-line"));
+    assert!(!re.is_match("$email = 'admin@example.com';"));
+    assert!(!re.is_match("#[Route('/users/{id}')]"));
+}
+
+#[test]
+fn extract_pattern() {
+    let re = escape_regex("extract");
+    assert!(re.is_match("extract($vars);"));
+    assert!(re.is_match("    extract($data, EXTR_SKIP);"));
+    assert!(!re.is_match("$this->extractor->run($data);"));
+    assert!(!re.is_match("$extracted = parse($x);"));
+}
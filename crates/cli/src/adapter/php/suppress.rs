@@ -0,0 +1,243 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! PHP suppress directive parsing.
+//!
+//! Parses static analysis and code style suppressions in comments:
+//! - phpstan: `@phpstan-ignore-next-line`, `@phpstan-ignore-line`,
+//!   `@phpstan-ignore identifier.name (reason)`
+//! - psalm: `@psalm-suppress IssueName`
+//! - PHP_CodeSniffer: `// phpcs:ignore Sniff.Name -- reason`, `// phpcs:disable`
+
+use crate::adapter::common::suppress::{CommentStyle, check_justification_comment};
+
+/// Suppress directive found in PHP source.
+#[derive(Debug, Clone)]
+pub struct PhpSuppress {
+    /// Line number (0-indexed).
+    pub line: usize,
+    /// Directive type.
+    pub kind: PhpSuppressKind,
+    /// Identifiers, issue names, or sniffs being suppressed (empty = all).
+    pub codes: Vec<String>,
+    /// Whether a justification comment was found.
+    pub has_comment: bool,
+    /// The comment text if found.
+    pub comment_text: Option<String>,
+}
+
+/// Kind of PHP suppress directive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhpSuppressKind {
+    /// `@phpstan-ignore identifier` (same or next line, per phpstan 1.11+).
+    PhpstanIgnore,
+    /// `@phpstan-ignore-line`.
+    PhpstanIgnoreLine,
+    /// `@phpstan-ignore-next-line`.
+    PhpstanIgnoreNextLine,
+    /// `@psalm-suppress IssueName`.
+    PsalmSuppress,
+    /// `phpcs:ignore` (current or next line).
+    PhpcsIgnore,
+    /// `phpcs:disable` (until `phpcs:enable`).
+    PhpcsDisable,
+}
+
+impl std::fmt::Display for PhpSuppressKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PhpstanIgnore => write!(f, "@phpstan-ignore"),
+            Self::PhpstanIgnoreLine => write!(f, "@phpstan-ignore-line"),
+            Self::PhpstanIgnoreNextLine => write!(f, "@phpstan-ignore-next-line"),
+            Self::PsalmSuppress => write!(f, "@psalm-suppress"),
+            Self::PhpcsIgnore => write!(f, "phpcs:ignore"),
+            Self::PhpcsDisable => write!(f, "phpcs:disable"),
+        }
+    }
+}
+
+/// Directive markers, longest first so `-next-line` wins over `-line`.
+const MARKERS: &[(&str, PhpSuppressKind)] = &[
+    ("@phpstan-ignore-next-line", PhpSuppressKind::PhpstanIgnoreNextLine),
+    ("@phpstan-ignore-line", PhpSuppressKind::PhpstanIgnoreLine),
+    ("@phpstan-ignore", PhpSuppressKind::PhpstanIgnore),
+    ("@psalm-suppress", PhpSuppressKind::PsalmSuppress),
+    ("phpcs:ignore", PhpSuppressKind::PhpcsIgnore),
+    ("phpcs:disable", PhpSuppressKind::PhpcsDisable),
+];
+
+/// Parse phpstan, psalm and phpcs suppress directives from PHP source.
+pub fn parse_php_suppresses(content: &str, comment_pattern: Option<&str>) -> Vec<PhpSuppress> {
+    let mut suppresses = Vec::new();
+    let lines: Vec<&str> = content.lines().collect();
+
+    for (line_idx, line) in lines.iter().enumerate() {
+        let Some(parsed) = parse_directive(line) else {
+            continue;
+        };
+
+        let (has_comment, comment_text) = if let Some(reason) = parsed.inline_reason {
+            // `phpcs:ignore Sniff -- reason`, `@phpstan-ignore id (reason)`
+            (true, Some(reason))
+        } else if let Some(reason) = docblock_justification(&lines, line_idx, comment_pattern) {
+            // Description line in the same docblock as `@psalm-suppress`
+            (true, Some(reason))
+        } else {
+            let directive_line = docblock_start(&lines, line_idx);
+            check_justification_comment(&lines, directive_line, comment_pattern, &CommentStyle::PHP)
+        };
+
+        suppresses.push(PhpSuppress {
+            line: line_idx,
+            kind: parsed.kind,
+            codes: parsed.codes,
+            has_comment,
+            comment_text,
+        });
+    }
+
+    suppresses
+}
+
+/// Parsed directive from a single line.
+struct ParsedDirective {
+    kind: PhpSuppressKind,
+    codes: Vec<String>,
+    inline_reason: Option<String>,
+}
+
+/// Parse a suppress directive from a single line.
+///
+/// The directive must be inside a comment (`//`, `#`, `/*`, or a docblock
+/// continuation line starting with `*`).
+fn parse_directive(line: &str) -> Option<ParsedDirective> {
+    let (pos, marker, kind) = MARKERS.iter().find_map(|(marker, kind)| {
+        let pos = line.find(marker)?;
+        let rest = &line[pos + marker.len()..];
+        // Reject longer names like `@phpstan-ignore-error` or `phpcs:ignoreFile`
+        if rest.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            return None;
+        }
+        Some((pos, *marker, *kind))
+    })?;
+
+    if !in_comment(&line[..pos]) {
+        return None;
+    }
+
+    let rest = line[pos + marker.len()..].trim();
+    let rest = rest.strip_suffix("*/").unwrap_or(rest).trim();
+
+    let (codes, reason) = match kind {
+        PhpSuppressKind::PhpstanIgnore => {
+            // `@phpstan-ignore argument.type, return.type (reason)`
+            match rest.split_once('(') {
+                Some((codes, reason)) => {
+                    (split_codes(codes), reason.rsplit_once(')').map_or(reason, |(r, _)| r))
+                }
+                None => (split_codes(rest), ""),
+            }
+        }
+        PhpSuppressKind::PhpcsIgnore | PhpSuppressKind::PhpcsDisable => {
+            // `phpcs:ignore Generic.Files.LineLength -- reason`
+            match rest.split_once("--") {
+                Some((codes, reason)) => (split_codes(codes), reason),
+                None => (split_codes(rest), ""),
+            }
+        }
+        PhpSuppressKind::PsalmSuppress => {
+            // `@psalm-suppress InvalidArgument, PossiblyNullReference reason`
+            let mut codes = String::new();
+            let mut tail = rest;
+            while let Some(token) = tail.split_whitespace().next() {
+                if !codes.is_empty() && !codes.ends_with(',') && !token.starts_with(',') {
+                    break;
+                }
+                codes.push_str(token);
+                tail = tail.trim_start()[token.len()..].trim_start();
+            }
+            (split_codes(&codes), tail)
+        }
+        PhpSuppressKind::PhpstanIgnoreLine | PhpSuppressKind::PhpstanIgnoreNextLine => {
+            (Vec::new(), rest)
+        }
+    };
+
+    let reason = reason.trim().trim_start_matches([':', '-']).trim();
+    let inline_reason = (!reason.is_empty()).then(|| reason.to_string());
+
+    Some(ParsedDirective { kind, codes, inline_reason })
+}
+
+/// Whether text before a directive opens a comment.
+fn in_comment(before: &str) -> bool {
+    let trimmed = before.trim_start();
+    trimmed.starts_with('*')
+        || before.contains("//")
+        || before.contains('#')
+        || before.contains("/*")
+}
+
+fn split_codes(codes: &str) -> Vec<String> {
+    codes.split(',').map(str::trim).filter(|c| !c.is_empty()).map(String::from).collect()
+}
+
+/// Look for a description line in the docblock enclosing `line_idx`.
+///
+/// ```php
+/// /**
+///  * Legacy callers still pass strings here.
+///  * @psalm-suppress InvalidArgument
+///  */
+/// ```
+fn docblock_justification(
+    lines: &[&str],
+    line_idx: usize,
+    comment_pattern: Option<&str>,
+) -> Option<String> {
+    let pattern = comment_pattern.map(|p| p.trim_start_matches("//").trim());
+    let mut idx = line_idx;
+    loop {
+        let line = lines[idx].trim();
+        let (text, is_start) = if let Some(text) = line.strip_prefix("/**") {
+            (text, true)
+        } else if let Some(text) = line.strip_prefix('*') {
+            if text.starts_with('/') {
+                return None;
+            }
+            (text, false)
+        } else {
+            return None;
+        };
+
+        let text = text.trim().trim_end_matches("*/").trim();
+        if idx != line_idx
+            && !text.is_empty()
+            && !text.starts_with('@')
+            && pattern.is_none_or(|p| text.starts_with(p))
+        {
+            return Some(text.to_string());
+        }
+        if is_start || idx == 0 {
+            return None;
+        }
+        idx -= 1;
+    }
+}
+
+/// First line of the docblock enclosing `line_idx` (or `line_idx` itself).
+fn docblock_start(lines: &[&str], line_idx: usize) -> usize {
+    let mut idx = line_idx;
+    while idx > 0 {
+        let line = lines[idx].trim();
+        if line.starts_with("/**") || !line.starts_with('*') {
+            break;
+        }
+        idx -= 1;
+    }
+    idx
+}
+
+#[cfg(test)]
+#[path = "suppress_tests.rs"]
+mod tests;
@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Unit tests for phpstan, psalm and phpcs suppress parsing.
+
+use super::*;
+
+#[test]
+fn parse_phpstan_ignore_next_line() {
+    let content = "// @phpstan-ignore-next-line\n$user->legacyName();";
+    let suppresses = parse_php_suppresses(content, None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert_eq!(suppresses[0].kind, PhpSuppressKind::PhpstanIgnoreNextLine);
+    assert_eq!(suppresses[0].line, 0);
+    assert!(suppresses[0].codes.is_empty());
+    assert!(!suppresses[0].has_comment);
+}
+
+#[test]
+fn parse_phpstan_ignore_line_trailing() {
+    let content = "$x = $legacy->value; // @phpstan-ignore-line";
+    let suppresses = parse_php_suppresses(content, None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert_eq!(suppresses[0].kind, PhpSuppressKind::PhpstanIgnoreLine);
+    assert!(!suppresses[0].has_comment);
+}
+
+#[test]
+fn parse_phpstan_ignore_identifiers_with_reason() {
+    let content =
+        "/** @phpstan-ignore argument.type, return.type (Laravel facade returns mixed) */";
+    let suppresses = parse_php_suppresses(content, None);
+
+    assert_eq!(suppresses[0].kind, PhpSuppressKind::PhpstanIgnore);
+    assert_eq!(suppresses[0].codes, vec!["argument.type", "return.type"]);
+    assert!(suppresses[0].has_comment);
+    assert_eq!(suppresses[0].comment_text.as_deref(), Some("Laravel facade returns mixed"));
+}
+
+#[test]
+fn parse_psalm_suppress_issue_names() {
+    let content = "/** @psalm-suppress InvalidArgument, PossiblyNullReference */\n$x->run(null);";
+    let suppresses = parse_php_suppresses(content, None);
+
+    assert_eq!(suppresses[0].kind, PhpSuppressKind::PsalmSuppress);
+    assert_eq!(suppresses[0].codes, vec!["InvalidArgument", "PossiblyNullReference"]);
+    assert!(!suppresses[0].has_comment);
+}
+
+#[test]
+fn psalm_suppress_trailing_text_is_justification() {
+    let content = "    /** @psalm-suppress MixedAssignment decoded JSON is validated below */";
+    let suppresses = parse_php_suppresses(content, None);
+
+    assert_eq!(suppresses[0].codes, vec!["MixedAssignment"]);
+    assert_eq!(suppresses[0].comment_text.as_deref(), Some("decoded JSON is validated below"));
+}
+
+#[test]
+fn docblock_description_is_justification() {
+    let content = "/**\n * Legacy callers still pass strings here.\n *\n * @param int $id\n * @psalm-suppress InvalidArgument\n */\nfunction find($id) {}";
+    let suppresses = parse_php_suppresses(content, None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert_eq!(suppresses[0].line, 4);
+    assert!(suppresses[0].has_comment);
+    assert_eq!(
+        suppresses[0].comment_text.as_deref(),
+        Some("Legacy callers still pass strings here.")
+    );
+}
+
+#[test]
+fn comment_above_docblock_is_justification() {
+    let content = "// Generated by the ORM, types are checked at runtime\n/**\n * @psalm-suppress MixedReturnStatement\n */\nfunction load() {}";
+    let suppresses = parse_php_suppresses(content, None);
+
+    assert!(suppresses[0].has_comment);
+}
+
+#[test]
+fn parse_phpcs_ignore_with_reason() {
+    let content = "// phpcs:ignore Generic.Files.LineLength, PSR1.Methods -- generated SQL fixture\n$sql = '...';";
+    let suppresses = parse_php_suppresses(content, None);
+
+    assert_eq!(suppresses[0].kind, PhpSuppressKind::PhpcsIgnore);
+    assert_eq!(suppresses[0].codes, vec!["Generic.Files.LineLength", "PSR1.Methods"]);
+    assert_eq!(suppresses[0].comment_text.as_deref(), Some("generated SQL fixture"));
+}
+
+#[test]
+fn bare_phpcs_disable_suppresses_all() {
+    let content = "# phpcs:disable\n$a=1;\n# phpcs:enable";
+    let suppresses = parse_php_suppresses(content, None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert_eq!(suppresses[0].kind, PhpSuppressKind::PhpcsDisable);
+    assert!(suppresses[0].codes.is_empty());
+}
+
+#[test]
+fn comment_above_directive_is_justification() {
+    let content = "// Vendor SDK has no stubs\n// @phpstan-ignore-next-line\n$client->send();";
+    let suppresses = parse_php_suppresses(content, None);
+
+    assert!(suppresses[0].has_comment);
+    assert_eq!(suppresses[0].comment_text.as_deref(), Some("Vendor SDK has no stubs"));
+}
+
+#[test]
+fn comment_pattern_required() {
+    let content = "// Vendor SDK has no stubs\n// @phpstan-ignore-next-line\n$client->send();";
+    let suppresses = parse_php_suppresses(content, Some("// STUBS:"));
+    assert!(!suppresses[0].has_comment);
+
+    let content =
+        "// STUBS: vendor SDK has no stubs\n// @phpstan-ignore-next-line\n$client->send();";
+    let suppresses = parse_php_suppresses(content, Some("// STUBS:"));
+    assert!(suppresses[0].has_comment);
+}
+
+#[test]
+fn directives_outside_comments_ignored() {
+    let content = "$tag = '@psalm-suppress';\n$doc = \"phpcs:ignore\";\n// @phpstan-ignore-error\n// phpcs:ignoreFile";
+    assert!(parse_php_suppresses(content, None).is_empty());
+}
+
+#[test]
+fn display_matches_directive() {
+    assert_eq!(PhpSuppressKind::PhpstanIgnoreNextLine.to_string(), "@phpstan-ignore-next-line");
+    assert_eq!(PhpSuppressKind::PsalmSuppress.to_string(), "@psalm-suppress");
+    assert_eq!(PhpSuppressKind::PhpcsIgnore.to_string(), "phpcs:ignore");
+}
@@ -193,6 +193,54 @@ pub fn apply_language_defaults(root: &Path, config: &mut Config) -> Vec<String>
                 tracing::debug!("auto-detected Python package: {:?}", config.project.packages);
             }
         }
+        ProjectLanguage::Jvm => {
+            // Exclude Gradle/Maven build output and caches
+            for pattern in ["build", "target", ".gradle", "out"] {
+                if !exclude_patterns.iter().any(|p| p.contains(pattern)) {
+                    exclude_patterns.push(pattern.to_string());
+                }
+            }
+        }
+        ProjectLanguage::CSharp => {
+            // Exclude MSBuild output, NuGet packages, and IDE state
+            for pattern in ["bin", "obj", "packages", ".vs"] {
+                if !exclude_patterns.iter().any(|p| p.contains(pattern)) {
+                    exclude_patterns.push(pattern.to_string());
+                }
+            }
+        }
+        ProjectLanguage::Php => {
+            // Exclude Composer dependencies and framework caches
+            for pattern in ["vendor", "var/cache", "storage/framework", "bootstrap/cache"] {
+                if !exclude_patterns.iter().any(|p| p.contains(pattern)) {
+                    exclude_patterns.push(pattern.to_string());
+                }
+            }
+        }
+        ProjectLanguage::Swift => {
+            // Exclude SwiftPM build products, Xcode state, and CocoaPods/Carthage checkouts
+            for pattern in [".build", ".swiftpm", "DerivedData", "Pods", "Carthage"] {
+                if !exclude_patterns.iter().any(|p| p.contains(pattern)) {
+                    exclude_patterns.push(pattern.to_string());
+                }
+            }
+        }
+        ProjectLanguage::Elixir => {
+            // Exclude Mix build products, fetched dependencies, and tool state
+            for pattern in ["_build", "deps", ".elixir_ls", "cover"] {
+                if !exclude_patterns.iter().any(|p| p.contains(pattern)) {
+                    exclude_patterns.push(pattern.to_string());
+                }
+            }
+        }
+        ProjectLanguage::Cpp => {
+            // Exclude CMake/Meson build trees and vendored dependencies
+            for pattern in ["build", "cmake-build-*", "out", "third_party", "vendor"] {
+                if !exclude_patterns.iter().any(|p| p.contains(pattern)) {
+                    exclude_patterns.push(pattern.to_string());
+                }
+            }
+        }
         ProjectLanguage::Ruby => {
             // Exclude vendor, tmp, log, coverage for Ruby projects
             for pattern in ["vendor", "tmp", "log", "coverage"] {
@@ -204,5 +252,14 @@ pub fn apply_language_defaults(root: &Path, config: &mut Config) -> Vec<String>
         ProjectLanguage::Generic => {}
     }
 
+    // Declarative adapters bring their own build and dependency directories
+    for adapter in &config.adapters {
+        for pattern in &adapter.exclude {
+            if !exclude_patterns.contains(pattern) {
+                exclude_patterns.push(pattern.clone());
+            }
+        }
+    }
+
     exclude_patterns
 }
@@ -220,6 +220,10 @@ impl Adapter for PythonAdapter {
     fn default_escapes(&self) -> &'static [EscapePattern] {
         PYTHON_ESCAPE_PATTERNS
     }
+
+    fn declaration_keywords(&self) -> &'static [&'static str] {
+        &["def", "class"]
+    }
 }
 
 impl PythonAdapter {
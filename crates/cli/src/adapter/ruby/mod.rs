@@ -171,6 +171,10 @@ impl Adapter for RubyAdapter {
     fn default_escapes(&self) -> &'static [EscapePattern] {
         RUBY_ESCAPE_PATTERNS
     }
+
+    fn declaration_keywords(&self) -> &'static [&'static str] {
+        &["def", "class", "module"]
+    }
 }
 
 impl RubyAdapter {
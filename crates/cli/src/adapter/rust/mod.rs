@@ -129,6 +129,10 @@ impl Adapter for RustAdapter {
     fn default_escapes(&self) -> &'static [EscapePattern] {
         RUST_ESCAPE_PATTERNS
     }
+
+    fn declaration_keywords(&self) -> &'static [&'static str] {
+        &["fn", "struct", "enum", "trait", "type", "mod"]
+    }
 }
 
 /// Result of classifying lines within a single file.
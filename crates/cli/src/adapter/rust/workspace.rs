@@ -5,6 +5,7 @@
 //!
 //! Parses Cargo.toml to detect workspace structure and member packages.
 
+use std::collections::{BTreeSet, HashMap};
 use std::fs;
 use std::path::Path;
 
@@ -19,6 +20,10 @@ pub struct CargoWorkspace {
     pub packages: Vec<String>,
     /// Member glob patterns (e.g., "crates/*").
     pub member_patterns: Vec<String>,
+    /// Package directory relative to root, keyed by package name.
+    pub package_dirs: HashMap<String, String>,
+    /// Workspace-internal dependencies, keyed by package name.
+    pub dependencies: HashMap<String, Vec<String>>,
 }
 
 impl CargoWorkspace {
@@ -46,10 +51,13 @@ impl CargoWorkspace {
         let Some(workspace) = value.get("workspace") else {
             // Single package, not a workspace
             if let Some(pkg) = value.get("package").and_then(|p| p.get("name")) {
+                let name = pkg.as_str().unwrap_or("").to_string();
                 return Self {
                     is_workspace: false,
-                    packages: vec![pkg.as_str().unwrap_or("").to_string()],
+                    packages: vec![name.clone()],
                     member_patterns: vec![],
+                    package_dirs: HashMap::from([(name, String::new())]),
+                    dependencies: HashMap::new(),
                 };
             }
             return Self::default();
@@ -62,14 +70,73 @@ impl CargoWorkspace {
             .unwrap_or_default();
 
         // Expand member patterns to find actual packages
-        let packages = expand_workspace_members(&members, root);
+        let found = expand_workspace_members(&members, root);
+        let packages = found.iter().map(|p| p.name.clone()).collect::<Vec<_>>();
+
+        // Keep only dependencies on other workspace members
+        let dependencies = found
+            .iter()
+            .map(|p| {
+                let deps = p.deps.iter().filter(|d| packages.contains(d)).cloned().collect();
+                (p.name.clone(), deps)
+            })
+            .collect();
+        let package_dirs = found.into_iter().map(|p| (p.name, p.dir)).collect();
+
+        Self { is_workspace: true, packages, member_patterns: members, package_dirs, dependencies }
+    }
+
+    /// Find the package whose directory contains the given root-relative path.
+    ///
+    /// Nested packages win over their parents.
+    pub fn package_for_path(&self, path: &Path) -> Option<&str> {
+        self.package_dirs
+            .iter()
+            .filter(|(_, dir)| dir.is_empty() || path.starts_with(dir.as_str()))
+            .max_by_key(|(_, dir)| dir.len())
+            .map(|(name, _)| name.as_str())
+    }
 
-        Self { is_workspace: true, packages, member_patterns: members }
+    /// Expand a set of packages with every workspace package that depends on them.
+    pub fn with_dependents(&self, packages: &BTreeSet<String>) -> BTreeSet<String> {
+        let mut result = packages.clone();
+        loop {
+            let before = result.len();
+            for (name, deps) in &self.dependencies {
+                if deps.iter().any(|d| result.contains(d)) {
+                    result.insert(name.clone());
+                }
+            }
+            if result.len() == before {
+                return result;
+            }
+        }
+    }
+
+    /// Expand a set of packages with every workspace package they depend on.
+    pub fn with_dependencies(&self, packages: &BTreeSet<String>) -> BTreeSet<String> {
+        let mut result = packages.clone();
+        let mut stack: Vec<String> = packages.iter().cloned().collect();
+        while let Some(name) = stack.pop() {
+            for dep in self.dependencies.get(&name).into_iter().flatten() {
+                if result.insert(dep.clone()) {
+                    stack.push(dep.clone());
+                }
+            }
+        }
+        result
     }
 }
 
-/// Expand workspace member patterns to package names.
-fn expand_workspace_members(patterns: &[String], root: &Path) -> Vec<String> {
+/// A workspace member discovered on disk.
+struct MemberPackage {
+    name: String,
+    dir: String,
+    deps: Vec<String>,
+}
+
+/// Expand workspace member patterns to packages.
+fn expand_workspace_members(patterns: &[String], root: &Path) -> Vec<MemberPackage> {
     let mut packages = Vec::new();
 
     for pattern in patterns {
@@ -92,37 +159,51 @@ fn expand_workspace_members(patterns: &[String], root: &Path) -> Vec<String> {
             // Other glob patterns are not supported - skip silently
         } else {
             // Direct path to package
-            let pkg_dir = root.join(pattern);
-            if let Some(name) = read_package_name(&pkg_dir) {
-                packages.push(name);
+            if let Some(pkg) = read_package(root, pattern) {
+                packages.push(pkg);
             }
         }
     }
 
-    packages.sort();
+    packages.sort_by(|a, b| a.name.cmp(&b.name));
     packages
 }
 
 /// Expand a single-level directory to find packages.
-fn expand_single_level(root: &Path, base: &str, packages: &mut Vec<String>) {
+fn expand_single_level(root: &Path, base: &str, packages: &mut Vec<MemberPackage>) {
     let dir = root.join(base);
     if let Ok(entries) = fs::read_dir(&dir) {
         for entry in entries.flatten() {
-            if entry.path().is_dir()
-                && let Some(name) = read_package_name(&entry.path())
-            {
-                packages.push(name);
+            if !entry.path().is_dir() {
+                continue;
+            }
+            let rel = format!("{}/{}", base, entry.file_name().to_string_lossy());
+            if let Some(pkg) = read_package(root, &rel) {
+                packages.push(pkg);
             }
         }
     }
 }
 
-/// Read package name from a directory's Cargo.toml.
-fn read_package_name(dir: &Path) -> Option<String> {
-    let cargo_toml = dir.join("Cargo.toml");
+/// Read package name and dependency names from a member directory's Cargo.toml.
+fn read_package(root: &Path, rel_dir: &str) -> Option<MemberPackage> {
+    let cargo_toml = root.join(rel_dir).join("Cargo.toml");
     let content = fs::read_to_string(&cargo_toml).ok()?;
     let value: Value = toml::from_str(&content).ok()?;
-    value.get("package")?.get("name")?.as_str().map(String::from)
+    let name = value.get("package")?.get("name")?.as_str()?.to_string();
+
+    let deps = ["dependencies", "dev-dependencies", "build-dependencies"]
+        .iter()
+        .filter_map(|section| value.get(section).and_then(|t| t.as_table()))
+        .flat_map(|table| {
+            table.iter().map(|(key, spec)| {
+                // `foo = { package = "real-name", ... }` renames a dependency
+                spec.get("package").and_then(|p| p.as_str()).unwrap_or(key).to_string()
+            })
+        })
+        .collect();
+
+    Some(MemberPackage { name, dir: rel_dir.trim_end_matches('/').to_string(), deps })
 }
 
 #[cfg(test)]
@@ -113,3 +113,29 @@ members = ["crates/**"]
     assert!(workspace.is_workspace);
     assert!(workspace.packages.contains(&"foo".to_string()));
 }
+
+#[test]
+fn tracks_member_dirs_and_internal_dependencies() {
+    let dir = TempDir::new().unwrap();
+    std::fs::create_dir_all(dir.path().join("crates/core")).unwrap();
+    std::fs::create_dir_all(dir.path().join("crates/cli")).unwrap();
+    std::fs::write(dir.path().join("crates/core/Cargo.toml"), "[package]\nname = \"core\"\n")
+        .unwrap();
+    std::fs::write(
+        dir.path().join("crates/cli/Cargo.toml"),
+        "[package]\nname = \"cli\"\n\n[dependencies]\ncore = { path = \"../core\" }\nserde = \"1\"\n",
+    )
+    .unwrap();
+    create_workspace(dir.path(), "[workspace]\nmembers = [\"crates/*\"]\n");
+
+    let workspace = CargoWorkspace::from_root(dir.path());
+    assert_eq!(workspace.package_dirs.get("cli").map(String::as_str), Some("crates/cli"));
+    assert_eq!(workspace.dependencies.get("cli"), Some(&vec!["core".to_string()]));
+    assert_eq!(workspace.package_for_path(Path::new("crates/core/src/lib.rs")), Some("core"));
+    assert_eq!(workspace.package_for_path(Path::new("README.md")), None);
+
+    let core = std::collections::BTreeSet::from(["core".to_string()]);
+    assert!(workspace.with_dependents(&core).contains("cli"));
+    let cli = std::collections::BTreeSet::from(["cli".to_string()]);
+    assert!(workspace.with_dependencies(&cli).contains("core"));
+}
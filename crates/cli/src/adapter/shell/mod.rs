@@ -125,6 +125,10 @@ impl Adapter for ShellAdapter {
     fn default_escapes(&self) -> &'static [EscapePattern] {
         SHELL_ESCAPE_PATTERNS
     }
+
+    fn declaration_keywords(&self) -> &'static [&'static str] {
+        &["function"]
+    }
 }
 
 impl ShellAdapter {
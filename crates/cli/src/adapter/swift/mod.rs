@@ -0,0 +1,147 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Swift language adapter.
+//!
+//! Provides Swift-specific behavior for checks:
+//! - File classification (SwiftPM `Tests/` targets, `*Tests.swift`)
+//! - Default patterns for Swift sources
+//! - Default escape patterns (`try!`, `as!`, force unwrap `!`, `unsafeBitCast`)
+//! - SwiftLint `// swiftlint:disable` directive parsing
+//!
+//! See docs/specs/langs/swift.md for specification.
+
+use std::path::Path;
+
+use globset::GlobSet;
+
+mod suppress;
+
+pub use suppress::{SwiftlintKind, SwiftlintSuppress, parse_swift_suppresses};
+
+use super::common;
+use super::common::patterns::normalize_exclude_patterns;
+use super::glob::build_glob_set;
+use super::{Adapter, EscapeAction, EscapePattern, FileKind};
+use crate::config::SwiftConfig;
+
+/// Default escape patterns for Swift.
+const SWIFT_ESCAPE_PATTERNS: &[EscapePattern] = &[
+    EscapePattern {
+        name: "force_try",
+        pattern: r"\btry!",
+        action: EscapeAction::Comment,
+        comment: Some("// SAFETY:"),
+        advice: "Add a // SAFETY: comment explaining why the call cannot throw, or handle the error with do/catch or try?.",
+        in_tests: None,
+    },
+    EscapePattern {
+        name: "force_cast",
+        pattern: r"\bas!",
+        action: EscapeAction::Comment,
+        comment: Some("// SAFETY:"),
+        advice: "Add a // SAFETY: comment explaining why the cast always succeeds, or use as? and handle the failure.",
+        in_tests: None,
+    },
+    // `!` directly after an expression and followed by `.`, `)`, `,`, `;`, `[`, `]`
+    // or the end of the line: not `!=`, `!flag`, `try!` or `as!`
+    EscapePattern {
+        name: "force_unwrap",
+        pattern: r"[\w)\]]!(?:[.),;\[\]]|$)",
+        action: EscapeAction::Comment,
+        comment: Some("// SAFETY:"),
+        advice: "Add a // SAFETY: comment explaining why the value is never nil, or unwrap with guard let / if let.",
+        in_tests: None,
+    },
+    EscapePattern {
+        name: "unsafe_bit_cast",
+        pattern: r"\bunsafeBitCast\s*\(",
+        action: EscapeAction::Comment,
+        comment: Some("// SAFETY:"),
+        advice: "Add a // SAFETY: comment explaining why both types have the same size and layout.",
+        in_tests: None,
+    },
+];
+
+/// Swift language adapter.
+pub struct SwiftAdapter {
+    source_patterns: GlobSet,
+    test_patterns: GlobSet,
+    exclude_patterns: GlobSet,
+}
+
+impl SwiftAdapter {
+    /// Create a new Swift adapter with default patterns.
+    pub fn new() -> Self {
+        Self {
+            source_patterns: build_glob_set(&SwiftConfig::default_source()),
+            test_patterns: build_glob_set(&SwiftConfig::default_tests()),
+            exclude_patterns: build_glob_set(&normalize_exclude_patterns(
+                &SwiftConfig::default_exclude(),
+            )),
+        }
+    }
+
+    /// Create a Swift adapter with resolved patterns from config.
+    pub fn with_patterns(patterns: super::ResolvedPatterns) -> Self {
+        let exclude_globs = normalize_exclude_patterns(&patterns.exclude);
+
+        Self {
+            source_patterns: build_glob_set(&patterns.source),
+            test_patterns: build_glob_set(&patterns.test),
+            exclude_patterns: build_glob_set(&exclude_globs),
+        }
+    }
+
+    /// Check if a path matches exclude patterns.
+    pub fn should_exclude(&self, path: &Path) -> bool {
+        common::patterns::check_exclude_patterns(path, &self.exclude_patterns, Some(&[".build"]))
+    }
+}
+
+impl Default for SwiftAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Adapter for SwiftAdapter {
+    fn name(&self) -> &'static str {
+        "swift"
+    }
+
+    fn extensions(&self) -> &'static [&'static str] {
+        &["swift"]
+    }
+
+    fn classify(&self, path: &Path) -> FileKind {
+        // Check exclude patterns first
+        if self.should_exclude(path) {
+            return FileKind::Other;
+        }
+
+        // Test patterns take precedence
+        if self.test_patterns.is_match(path) {
+            return FileKind::Test;
+        }
+
+        // Source patterns
+        if self.source_patterns.is_match(path) {
+            return FileKind::Source;
+        }
+
+        FileKind::Other
+    }
+
+    fn default_escapes(&self) -> &'static [EscapePattern] {
+        SWIFT_ESCAPE_PATTERNS
+    }
+
+    fn declaration_keywords(&self) -> &'static [&'static str] {
+        &["func", "class", "struct", "enum", "protocol"]
+    }
+}
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Unit tests for the Swift adapter.
+
+use std::path::Path;
+
+use super::*;
+
+#[test]
+fn classifies_swift_files_as_source() {
+    let adapter = SwiftAdapter::new();
+    assert_eq!(adapter.classify(Path::new("Sources/Acme/Parser.swift")), FileKind::Source);
+    assert_eq!(adapter.classify(Path::new("Package.swift")), FileKind::Source);
+}
+
+#[test]
+fn classifies_test_targets_as_test() {
+    let adapter = SwiftAdapter::new();
+    assert_eq!(adapter.classify(Path::new("Tests/AcmeTests/ParserTests.swift")), FileKind::Test);
+    assert_eq!(adapter.classify(Path::new("Tests/AcmeTests/Fixtures.swift")), FileKind::Test);
+    assert_eq!(
+        adapter.classify(Path::new("Packages/Core/Tests/CoreTests/Helpers.swift")),
+        FileKind::Test
+    );
+    assert_eq!(adapter.classify(Path::new("Sources/Acme/ParserTests.swift")), FileKind::Test);
+}
+
+#[test]
+fn ignores_build_products() {
+    let adapter = SwiftAdapter::new();
+    assert_eq!(
+        adapter.classify(Path::new(".build/checkouts/swift-nio/Sources/NIO/Channel.swift")),
+        FileKind::Other
+    );
+    assert_eq!(adapter.classify(Path::new(".swiftpm/xcode/Gen.swift")), FileKind::Other);
+}
+
+#[test]
+fn non_swift_files_are_other() {
+    let adapter = SwiftAdapter::new();
+    assert_eq!(adapter.classify(Path::new("Package.resolved")), FileKind::Other);
+    assert_eq!(adapter.name(), "swift");
+}
+
+// =============================================================================
+// DEFAULT ESCAPE PATTERNS
+// =============================================================================
+
+fn escape_regex(name: &str) -> regex::Regex {
+    let pattern = SWIFT_ESCAPE_PATTERNS.iter().find(|p| p.name == name).unwrap().pattern;
+    regex::Regex::new(pattern).unwrap()
+}
+
+#[test]
+fn force_try_pattern() {
+    let re = escape_regex("force_try");
+    assert!(re.is_match("let data = try! Data(contentsOf: url)"));
+    assert!(!re.is_match("let data = try? Data(contentsOf: url)"));
+    assert!(!re.is_match("let data = try Data(contentsOf: url)"));
+    assert!(!re.is_match("let entry = retry!.next"));
+}
+
+#[test]
+fn force_cast_pattern() {
+    let re = escape_regex("force_cast");
+    assert!(re.is_match("let cell = view as! CustomCell"));
+    assert!(!re.is_match("guard let cell = view as? CustomCell else { return }"));
+    assert!(!re.is_match("let n = value as Int"));
+}
+
+#[test]
+fn force_unwrap_pattern() {
+    let re = escape_regex("force_unwrap");
+    assert!(re.is_match("let name = user!.name"));
+    assert!(re.is_match("process(items.first!)"));
+    assert!(re.is_match("let url = URL(string: raw)!"));
+    assert!(re.is_match("let value = dict[key]!"));
+    assert!(!re.is_match("if a != b { return }"));
+    assert!(!re.is_match("guard !isEmpty else { return }"));
+    assert!(!re.is_match("let data = try! load()"));
+    assert!(!re.is_match("let cell = view as! CustomCell"));
+    assert!(!re.is_match("print(\"Done!\")"));
+}
+
+#[test]
+fn unsafe_bit_cast_pattern() {
+    let re = escape_regex("unsafe_bit_cast");
+    assert!(re.is_match("let f = unsafeBitCast(ptr, to: Callback.self)"));
+    assert!(!re.is_match("let f = unsafeBitCastHelper"));
+}
@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! SwiftLint directive parsing.
+//!
+//! Parses `// swiftlint:disable rule`, `// swiftlint:disable:next rule`,
+//! `// swiftlint:disable:this rule` and `// swiftlint:disable:previous rule`
+//! comments in Swift source. `swiftlint:enable` closes a region and is not
+//! counted.
+
+use crate::adapter::common::suppress::{CommentStyle, check_justification_comment};
+
+/// SwiftLint directive found in Swift source.
+#[derive(Debug, Clone)]
+pub struct SwiftlintSuppress {
+    /// Line number (0-indexed).
+    pub line: usize,
+    /// Directive type.
+    pub kind: SwiftlintKind,
+    /// SwiftLint rules being disabled (empty = all rules).
+    pub codes: Vec<String>,
+    /// Whether a justification comment was found.
+    pub has_comment: bool,
+    /// The comment text if found.
+    pub comment_text: Option<String>,
+}
+
+/// Kind of SwiftLint directive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwiftlintKind {
+    /// `swiftlint:disable`: disables rules until `swiftlint:enable` or end of file.
+    Disable,
+    /// `swiftlint:disable:next`: disables rules on the following line.
+    Next,
+    /// `swiftlint:disable:this`: disables rules on the same line.
+    This,
+    /// `swiftlint:disable:previous`: disables rules on the preceding line.
+    Previous,
+}
+
+impl std::fmt::Display for SwiftlintKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Disable => write!(f, "swiftlint:disable"),
+            Self::Next => write!(f, "swiftlint:disable:next"),
+            Self::This => write!(f, "swiftlint:disable:this"),
+            Self::Previous => write!(f, "swiftlint:disable:previous"),
+        }
+    }
+}
+
+/// Parse SwiftLint directives from Swift source.
+pub fn parse_swift_suppresses(
+    content: &str,
+    comment_pattern: Option<&str>,
+) -> Vec<SwiftlintSuppress> {
+    let mut suppresses = Vec::new();
+    let lines: Vec<&str> = content.lines().collect();
+
+    for (line_idx, line) in lines.iter().enumerate() {
+        let Some(parsed) = parse_swiftlint_line(line) else {
+            continue;
+        };
+
+        let (has_comment, comment_text) = if let Some(reason) = parsed.inline_reason {
+            // SwiftLint's trailing comment syntax: `// swiftlint:disable:next rule - reason`
+            (true, Some(reason))
+        } else {
+            check_justification_comment(&lines, line_idx, comment_pattern, &CommentStyle::SWIFT)
+        };
+
+        suppresses.push(SwiftlintSuppress {
+            line: line_idx,
+            kind: parsed.kind,
+            codes: parsed.codes,
+            has_comment,
+            comment_text,
+        });
+    }
+
+    suppresses
+}
+
+/// Parsed SwiftLint directive from a single line.
+struct ParsedDirective {
+    kind: SwiftlintKind,
+    codes: Vec<String>,
+    inline_reason: Option<String>,
+}
+
+/// Parse a SwiftLint directive from a single line.
+///
+/// The directive must start a `//` or `/* */` comment. Returns None for lines
+/// without a directive and for `swiftlint:enable`.
+fn parse_swiftlint_line(line: &str) -> Option<ParsedDirective> {
+    let (pos, _) = line.match_indices("swiftlint:disable").find(|(pos, _)| {
+        let before = line[..*pos].trim_end();
+        before.ends_with("//") || before.ends_with("/*")
+    })?;
+    let rest = &line[pos + "swiftlint:disable".len()..];
+
+    let (kind, rest) = if let Some(rest) = rest.strip_prefix(":next") {
+        (SwiftlintKind::Next, rest)
+    } else if let Some(rest) = rest.strip_prefix(":this") {
+        (SwiftlintKind::This, rest)
+    } else if let Some(rest) = rest.strip_prefix(":previous") {
+        (SwiftlintKind::Previous, rest)
+    } else {
+        (SwiftlintKind::Disable, rest)
+    };
+
+    // Rules are separated from the directive by whitespace
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    let rest = rest.trim().trim_end_matches("*/").trim_end();
+    let (rules, reason) = match rest.split_once(" - ") {
+        Some((rules, reason)) => (rules, reason.trim()),
+        None => (rest.strip_suffix(" -").unwrap_or(rest), ""),
+    };
+    let codes = rules.split_whitespace().filter(|r| *r != "all").map(String::from).collect();
+    let inline_reason = (!reason.is_empty()).then(|| reason.to_string());
+
+    Some(ParsedDirective { kind, codes, inline_reason })
+}
+
+#[cfg(test)]
+#[path = "suppress_tests.rs"]
+mod tests;
@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Unit tests for SwiftLint directive parsing.
+
+use super::*;
+
+#[test]
+fn parse_disable_next() {
+    let content = "// swiftlint:disable:next force_cast\nlet view = cell as! CustomCell";
+    let suppresses = parse_swift_suppresses(content, None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert_eq!(suppresses[0].kind, SwiftlintKind::Next);
+    assert_eq!(suppresses[0].line, 0);
+    assert_eq!(suppresses[0].codes, vec!["force_cast"]);
+    assert!(!suppresses[0].has_comment);
+}
+
+#[test]
+fn parse_disable_this_trailing() {
+    let content = "let data = try! Data(contentsOf: url) // swiftlint:disable:this force_try";
+    let suppresses = parse_swift_suppresses(content, None);
+
+    assert_eq!(suppresses[0].kind, SwiftlintKind::This);
+    assert_eq!(suppresses[0].codes, vec!["force_try"]);
+}
+
+#[test]
+fn parse_region_with_multiple_rules() {
+    let content = "// swiftlint:disable identifier_name line_length\nlet x = 1\n// swiftlint:enable identifier_name line_length";
+    let suppresses = parse_swift_suppresses(content, None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert_eq!(suppresses[0].kind, SwiftlintKind::Disable);
+    assert_eq!(suppresses[0].codes, vec!["identifier_name", "line_length"]);
+}
+
+#[test]
+fn disable_all_suppresses_every_rule() {
+    let suppresses = parse_swift_suppresses("// swiftlint:disable all", None);
+
+    assert_eq!(suppresses.len(), 1);
+    assert!(suppresses[0].codes.is_empty());
+}
+
+#[test]
+fn parse_disable_previous() {
+    let content = "let x = y!\n// swiftlint:disable:previous force_unwrapping";
+    let suppresses = parse_swift_suppresses(content, None);
+
+    assert_eq!(suppresses[0].kind, SwiftlintKind::Previous);
+    assert_eq!(suppresses[0].line, 1);
+}
+
+#[test]
+fn trailing_reason_is_justification() {
+    let content = "// swiftlint:disable:next force_try - bundled resource always exists";
+    let suppresses = parse_swift_suppresses(content, None);
+
+    assert_eq!(suppresses[0].codes, vec!["force_try"]);
+    assert!(suppresses[0].has_comment);
+    assert_eq!(suppresses[0].comment_text.as_deref(), Some("bundled resource always exists"));
+}
+
+#[test]
+fn comment_above_is_justification() {
+    let content =
+        "// Generated code mirrors the server's field names\n// swiftlint:disable identifier_name";
+    let suppresses = parse_swift_suppresses(content, None);
+
+    assert!(suppresses[0].has_comment);
+}
+
+#[test]
+fn attributes_between_comment_and_directive_are_skipped() {
+    let content = "// Callback signature is fixed by the C API\n@available(*, deprecated)\n// swiftlint:disable:next function_parameter_count";
+    let suppresses = parse_swift_suppresses(content, None);
+
+    assert!(suppresses[0].has_comment);
+}
+
+#[test]
+fn comment_pattern_required() {
+    let content = "// Fixed by the C API\n// swiftlint:disable:next function_parameter_count";
+    let suppresses = parse_swift_suppresses(content, Some("// FFI:"));
+    assert!(!suppresses[0].has_comment);
+
+    let content = "// FFI: fixed by the C API\n// swiftlint:disable:next function_parameter_count";
+    let suppresses = parse_swift_suppresses(content, Some("// FFI:"));
+    assert!(suppresses[0].has_comment);
+}
+
+#[test]
+fn enable_and_non_comment_text_ignored() {
+    let content = "// swiftlint:enable force_cast\nlet s = \"swiftlint:disable force_cast\"\n// swiftlint:disabled";
+    assert!(parse_swift_suppresses(content, None).is_empty());
+}
+
+#[test]
+fn display_matches_directive() {
+    assert_eq!(SwiftlintKind::Disable.to_string(), "swiftlint:disable");
+    assert_eq!(SwiftlintKind::Next.to_string(), "swiftlint:disable:next");
+    assert_eq!(SwiftlintKind::This.to_string(), "swiftlint:disable:this");
+}
@@ -53,6 +53,10 @@ pub struct BaselineMetrics {
     /// Test execution times in seconds.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub test_time: Option<TestTimeMetrics>,
+
+    /// Mutation score percentage.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mutation: Option<MutationMetrics>,
 }
 
 /// Coverage metrics with optional per-package breakdown.
@@ -63,6 +67,14 @@ pub struct CoverageMetrics {
     pub by_package: Option<HashMap<String, f64>>,
 }
 
+/// Mutation score with optional per-package breakdown.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MutationMetrics {
+    pub total: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub by_package: Option<HashMap<String, f64>>,
+}
+
 /// Escape hatch counts.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EscapesMetrics {
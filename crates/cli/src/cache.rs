@@ -193,6 +193,7 @@ impl CachedViolation {
             scope: None,
             expected: None,
             found: None,
+            files: None,
         }
     }
 }
@@ -411,6 +412,8 @@ pub fn hash_config(config: &crate::config::Config) -> u64 {
     // Hash check config fields that affect results
     config.check.cloc.max_lines.hash(&mut hasher);
     config.check.cloc.max_lines_test.hash(&mut hasher);
+    config.check.cloc.max_tokens.hash(&mut hasher);
+    config.check.cloc.tokenizer.hash(&mut hasher);
     config.check.cloc.exclude.hash(&mut hasher);
     config.project.packages.hash(&mut hasher);
 
@@ -425,8 +428,15 @@ pub fn hash_config(config: &crate::config::Config) -> u64 {
     config.rust.suppress.check.hash(&mut hasher);
     config.javascript.suppress.check.hash(&mut hasher);
     config.golang.suppress.check.hash(&mut hasher);
+    config.cpp.suppress.check.hash(&mut hasher);
+    config.csharp.suppress.check.hash(&mut hasher);
+    config.php.suppress.check.hash(&mut hasher);
+    config.swift.suppress.check.hash(&mut hasher);
+    config.elixir.suppress.check.hash(&mut hasher);
     config.shell.suppress.check.hash(&mut hasher);
     config.ruby.suppress.check.hash(&mut hasher);
+    config.java.suppress.check.hash(&mut hasher);
+    config.kotlin.suppress.check.hash(&mut hasher);
     config.python.suppress.check.hash(&mut hasher);
 
     // Hash test/source patterns from resolution hierarchy:
@@ -440,11 +450,55 @@ pub fn hash_config(config: &crate::config::Config) -> u64 {
     config.rust.exclude.hash(&mut hasher);
     config.golang.tests.hash(&mut hasher);
     config.golang.source.hash(&mut hasher);
+    config.cpp.tests.hash(&mut hasher);
+    config.cpp.source.hash(&mut hasher);
+    config.csharp.tests.hash(&mut hasher);
+    config.csharp.source.hash(&mut hasher);
+    config.php.tests.hash(&mut hasher);
+    config.php.source.hash(&mut hasher);
+    config.swift.tests.hash(&mut hasher);
+    config.swift.source.hash(&mut hasher);
+    config.elixir.tests.hash(&mut hasher);
+    config.elixir.source.hash(&mut hasher);
+    config.iac.tests.hash(&mut hasher);
+    config.iac.source.hash(&mut hasher);
     config.javascript.tests.hash(&mut hasher);
     config.javascript.source.hash(&mut hasher);
+    config.java.tests.hash(&mut hasher);
+    config.java.source.hash(&mut hasher);
+    config.kotlin.tests.hash(&mut hasher);
+    config.kotlin.source.hash(&mut hasher);
     config.shell.tests.hash(&mut hasher);
     config.shell.source.hash(&mut hasher);
 
+    // Declarative adapters decide which files are counted, how comments are
+    // recognized, and which suppress directives are reported.
+    for adapter in &config.adapters {
+        adapter.name.hash(&mut hasher);
+        adapter.extensions.hash(&mut hasher);
+        adapter.filenames.hash(&mut hasher);
+        adapter.comment.line.hash(&mut hasher);
+        adapter.comment.block.hash(&mut hasher);
+        adapter.source.hash(&mut hasher);
+        adapter.tests.hash(&mut hasher);
+        adapter.exclude.hash(&mut hasher);
+        adapter.suppress.directives.hash(&mut hasher);
+        adapter.suppress.check.hash(&mut hasher);
+    }
+
+    // License header settings decide which header violations are reported.
+    // Dependency violations are never cached, so [check.license.deps] is not hashed.
+    let license = &config.check.license;
+    license.check.hash(&mut hasher);
+    license.license.hash(&mut hasher);
+    license.copyright.hash(&mut hasher);
+    license.template.hash(&mut hasher);
+    license.year.hash(&mut hasher);
+    license.exclude.hash(&mut hasher);
+    let mut license_patterns: Vec<_> = license.patterns.iter().collect();
+    license_patterns.sort();
+    license_patterns.hash(&mut hasher);
+
     hasher.finish()
 }
 
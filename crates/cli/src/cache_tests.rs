@@ -369,3 +369,36 @@ fn hash_config_changes_when_suppress_check_changes() {
         "config hash must change when javascript.suppress.check changes"
     );
 }
+
+#[test]
+fn hash_config_changes_when_declarative_adapter_added() {
+    let mut config = crate::config::Config::default();
+    let hash_default = hash_config(&config);
+
+    config.adapters.push(toml::from_str("name = \"zig\"\nextensions = [\"zig\"]\n").unwrap());
+    let hash_changed = hash_config(&config);
+
+    assert_ne!(hash_default, hash_changed, "config hash must change when an adapter is declared");
+}
+
+#[test]
+fn hash_config_changes_with_license_template() {
+    let mut config = crate::config::Config::default();
+    let hash_default = hash_config(&config);
+
+    config.check.license.template = Some("apache-2.0".to_string());
+    let hash_changed = hash_config(&config);
+
+    assert_ne!(hash_default, hash_changed, "config hash must change with the license template");
+}
+
+#[test]
+fn hash_config_changes_with_cloc_tokenizer() {
+    let mut config = crate::config::Config::default();
+    let hash_default = hash_config(&config);
+
+    config.check.cloc.tokenizer = crate::tokens::Tokenizer::Cl100k;
+    let hash_changed = hash_config(&config);
+
+    assert_ne!(hash_default, hash_changed, "config hash must change with the cloc tokenizer");
+}
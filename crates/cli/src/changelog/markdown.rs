@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Merging entries into a Keep a Changelog markdown file.
+
+use super::{Entry, SECTIONS, Version};
+
+/// Name of the release collecting changes that aren't released yet.
+pub const UNRELEASED: &str = "Unreleased";
+
+/// Header of a new `CHANGELOG.md`.
+pub const HEADER: &str = "# Changelog
+
+All notable changes to this project will be documented in this file.
+
+The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.1.0/),
+and this project adheres to [Semantic Versioning](https://semver.org/spec/v2.0.0.html).
+";
+
+/// Section order defined by Keep a Changelog.
+const SECTION_ORDER: &[&str] = &["Added", "Changed", "Deprecated", "Removed", "Fixed", "Security"];
+
+/// Release name of a `## [1.2.0] - 2026-01-31` or `## Unreleased` heading.
+fn release_name(line: &str) -> Option<&str> {
+    let rest = line.strip_prefix("## ")?.trim();
+    let name = match rest.strip_prefix('[').and_then(|r| r.split_once(']')) {
+        Some((name, _)) => name,
+        None => rest.split(" - ").next().unwrap_or(rest),
+    };
+    Some(name.trim())
+}
+
+/// Most recent version released in the changelog.
+pub fn latest_version(changelog: &str) -> Option<Version> {
+    changelog.lines().filter_map(release_name).find_map(Version::parse)
+}
+
+/// Whether the `Unreleased` section of the changelog has any changes.
+pub fn has_unreleased(changelog: &str) -> bool {
+    changelog
+        .lines()
+        .skip_while(|l| !release_name(l).is_some_and(|n| n.eq_ignore_ascii_case(UNRELEASED)))
+        .skip(1)
+        .take_while(|l| release_name(l).is_none())
+        .any(|l| l.trim_start().starts_with(['-', '*']))
+}
+
+/// Add `entries` to the `release` section of `changelog`.
+///
+/// A missing release is created above the previous releases. Creating a
+/// version moves the `Unreleased` changes into it, leaving `Unreleased`
+/// empty. `date` is added to the heading of a new version.
+pub fn insert(changelog: &str, release: &str, date: Option<&str>, entries: &[&Entry]) -> String {
+    let mut lines: Vec<String> = changelog.lines().map(String::from).collect();
+    let find = |lines: &[String], name: &str| {
+        lines.iter().position(|l| release_name(l).is_some_and(|n| n.eq_ignore_ascii_case(name)))
+    };
+
+    let start = match find(&lines, release) {
+        Some(_) if entries.is_empty() => return changelog.to_string(),
+        Some(start) => start,
+        None => {
+            let heading = match date {
+                Some(date) if release != UNRELEASED => format!("## [{release}] - {date}"),
+                _ => format!("## [{release}]"),
+            };
+            match find(&lines, UNRELEASED) {
+                Some(unreleased) if release != UNRELEASED => {
+                    lines[unreleased] = heading;
+                    let fresh = [format!("## [{UNRELEASED}]"), String::new()];
+                    lines.splice(unreleased..unreleased, fresh);
+                    unreleased + 2
+                }
+                _ => {
+                    let pos = lines.iter().position(|l| release_name(l).is_some());
+                    let mut pos = pos.unwrap_or(lines.len());
+                    if pos > 0 && !lines[pos - 1].trim().is_empty() {
+                        lines.insert(pos, String::new());
+                        pos += 1;
+                    }
+                    lines.splice(pos..pos, [heading, String::new()]);
+                    pos
+                }
+            }
+        }
+    };
+
+    let end = lines[start + 1..]
+        .iter()
+        .position(|l| release_name(l).is_some())
+        .map_or(lines.len(), |offset| start + 1 + offset);
+    let body = render_release(&lines[start + 1..end], entries);
+    lines.splice(start + 1..end, body);
+
+    let mut output = lines.join("\n").trim_end().to_string();
+    output.push('\n');
+    output
+}
+
+/// Body of a release with `entries` added to its sections.
+fn render_release(body: &[String], entries: &[&Entry]) -> Vec<String> {
+    let mut preamble: Vec<String> = Vec::new();
+    let mut sections: Vec<(String, Vec<String>)> = Vec::new();
+    for line in body {
+        if let Some(name) = line.strip_prefix("### ") {
+            sections.push((name.trim().to_string(), Vec::new()));
+        } else if let Some((_, lines)) = sections.last_mut() {
+            lines.push(line.clone());
+        } else {
+            preamble.push(line.clone());
+        }
+    }
+
+    for (section, _) in SECTIONS {
+        let mut new: Vec<&&Entry> = entries.iter().filter(|e| e.section == *section).collect();
+        if new.is_empty() {
+            continue;
+        }
+        // Group by scope, unscoped first, keeping commit order within a scope
+        new.sort_by(|a, b| a.scope.cmp(&b.scope));
+
+        let idx = match sections.iter().position(|(name, _)| name.eq_ignore_ascii_case(section)) {
+            Some(idx) => idx,
+            None => {
+                let order = section_order(section);
+                let idx = sections
+                    .iter()
+                    .position(|(name, _)| section_order(name) > order)
+                    .unwrap_or(sections.len());
+                sections.insert(idx, (section.to_string(), Vec::new()));
+                idx
+            }
+        };
+        let lines = &mut sections[idx].1;
+        trim_blank(lines);
+        lines.extend(new.iter().map(|entry| entry.line()));
+    }
+
+    let mut rendered = vec![String::new()];
+    trim_blank(&mut preamble);
+    if !preamble.is_empty() {
+        rendered.append(&mut preamble);
+        rendered.push(String::new());
+    }
+    for (name, mut lines) in sections {
+        trim_blank(&mut lines);
+        rendered.push(format!("### {name}"));
+        rendered.push(String::new());
+        rendered.append(&mut lines);
+        rendered.push(String::new());
+    }
+    rendered
+}
+
+/// Position of a section in the Keep a Changelog order (unknown sections last).
+fn section_order(name: &str) -> usize {
+    SECTION_ORDER.iter().position(|s| s.eq_ignore_ascii_case(name)).unwrap_or(usize::MAX)
+}
+
+/// Remove leading and trailing blank lines.
+fn trim_blank(lines: &mut Vec<String>) {
+    while lines.last().is_some_and(|l| l.trim().is_empty()) {
+        lines.pop();
+    }
+    let leading = lines.iter().take_while(|l| l.trim().is_empty()).count();
+    lines.drain(..leading);
+}
+
+#[cfg(test)]
+#[path = "markdown_tests.rs"]
+mod tests;
@@ -0,0 +1,183 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Unit tests for changelog markdown merging.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+
+fn entry(section: &'static str, scope: Option<&str>, description: &str) -> Entry {
+    Entry {
+        section,
+        commit_type: "feat".to_string(),
+        scope: scope.map(String::from),
+        description: description.to_string(),
+        breaking: false,
+        hash: "abc1234".to_string(),
+    }
+}
+
+const EXISTING: &str = "# Changelog
+
+## [Unreleased]
+
+### Fixed
+
+- Handle empty input
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Initial release
+";
+
+// =============================================================================
+// INSERT TESTS
+// =============================================================================
+
+#[test]
+fn creates_unreleased_in_new_changelog() {
+    let export = entry("Added", Some("api"), "add export");
+    let fix = entry("Fixed", None, "handle empty input");
+
+    let output = insert(HEADER, UNRELEASED, None, &[&fix, &export]);
+
+    assert!(output.starts_with(HEADER));
+    assert!(output.ends_with(
+        "Semantic Versioning](https://semver.org/spec/v2.0.0.html).
+
+## [Unreleased]
+
+### Added
+
+- **api**: Add export
+
+### Fixed
+
+- Handle empty input
+"
+    ));
+}
+
+#[test]
+fn merges_into_existing_sections_in_order() {
+    let export = entry("Added", None, "add export");
+    let timeout = entry("Fixed", None, "respect timeout");
+
+    let output = insert(EXISTING, UNRELEASED, None, &[&export, &timeout]);
+
+    assert_eq!(
+        output,
+        "# Changelog
+
+## [Unreleased]
+
+### Added
+
+- Add export
+
+### Fixed
+
+- Handle empty input
+- Respect timeout
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Initial release
+"
+    );
+}
+
+#[test]
+fn groups_new_entries_by_scope() {
+    let cli = entry("Added", Some("cli"), "add flag");
+    let plain = entry("Added", None, "add docs");
+    let api = entry("Added", Some("api"), "add export");
+
+    let output = insert("", UNRELEASED, None, &[&cli, &plain, &api]);
+
+    assert!(output.contains("- Add docs\n- **api**: Add export\n- **cli**: Add flag\n"));
+}
+
+#[test]
+fn new_version_takes_unreleased_changes() {
+    let export = entry("Added", None, "add export");
+
+    let output = insert(EXISTING, "0.2.0", Some("2026-02-01"), &[&export]);
+
+    assert_eq!(
+        output,
+        "# Changelog
+
+## [Unreleased]
+
+## [0.2.0] - 2026-02-01
+
+### Added
+
+- Add export
+
+### Fixed
+
+- Handle empty input
+
+## [0.1.0] - 2026-01-01
+
+### Added
+
+- Initial release
+"
+    );
+}
+
+#[test]
+fn new_version_without_unreleased_goes_above_releases() {
+    let changelog = "# Changelog\n\n## [0.1.0]\n\n- Initial release\n";
+    let export = entry("Added", None, "add export");
+
+    let output = insert(changelog, "0.2.0", None, &[&export]);
+
+    assert_eq!(
+        output,
+        "# Changelog\n\n## [0.2.0]\n\n### Added\n\n- Add export\n\n## [0.1.0]\n\n- Initial release\n"
+    );
+}
+
+#[test]
+fn insert_without_entries_leaves_existing_release_unchanged() {
+    let untidy = "# Changelog\n## [Unreleased]\n### Fixed\n- Handle empty input\n\n\n";
+    assert_eq!(insert(untidy, UNRELEASED, None, &[]), untidy);
+}
+
+#[test]
+fn matches_unbracketed_release_headings() {
+    let changelog = "# Changelog\n\n## Unreleased\n\n### Added\n\n- Add export\n";
+    let fix = entry("Fixed", None, "fix crash");
+
+    let output = insert(changelog, UNRELEASED, None, &[&fix]);
+
+    assert!(
+        output.contains("## Unreleased\n\n### Added\n\n- Add export\n\n### Fixed\n\n- Fix crash\n")
+    );
+}
+
+// =============================================================================
+// VERSION TESTS
+// =============================================================================
+
+#[test]
+fn latest_version_is_first_released_heading() {
+    assert_eq!(latest_version(EXISTING), Version::parse("0.1.0"));
+    assert_eq!(latest_version("# Changelog\n\n## [Unreleased]\n"), None);
+}
+
+#[test]
+fn has_unreleased_detects_unreleased_entries() {
+    assert!(has_unreleased(EXISTING));
+    assert!(!has_unreleased("# Changelog\n\n## [Unreleased]\n\n## [0.1.0]\n\n- Initial release\n"));
+    assert!(!has_unreleased("# Changelog\n\n## [0.1.0]\n\n- Initial release\n"));
+}
@@ -0,0 +1,177 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Changelog generation from conventional commits.
+//!
+//! `quench changelog` groups the commits since the last tag into
+//! [Keep a Changelog](https://keepachangelog.com) sections, merges them into
+//! `CHANGELOG.md`, and computes the next semver version from `feat`, `fix`
+//! and breaking markers.
+
+mod markdown;
+
+use std::fmt;
+
+pub use markdown::{HEADER, UNRELEASED, has_unreleased, insert, latest_version};
+
+use crate::checks::git::parse::{
+    ParseResult, has_breaking_footer, is_autosquash_commit, parse_conventional_commit,
+};
+use crate::git::Commit;
+
+/// Changelog sections and the commit types listed in them.
+///
+/// Breaking commits of other types are listed under `Changed`.
+pub const SECTIONS: &[(&str, &[&str])] =
+    &[("Added", &["feat"]), ("Changed", &["perf", "refactor"]), ("Fixed", &["fix"])];
+
+/// A changelog entry for one commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// Section the entry belongs to (e.g., `Added`).
+    pub section: &'static str,
+    /// Commit type (e.g., `feat`).
+    pub commit_type: String,
+    pub scope: Option<String>,
+    pub description: String,
+    pub breaking: bool,
+    /// Short hash of the commit.
+    pub hash: String,
+}
+
+impl Entry {
+    /// Markdown list item, e.g. `- **api**: Add export endpoint`.
+    pub fn line(&self) -> String {
+        let mut chars = self.description.chars();
+        let description: String =
+            chars.next().map(|c| c.to_uppercase().chain(chars).collect()).unwrap_or_default();
+        let breaking = if self.breaking { " (breaking)" } else { "" };
+        match &self.scope {
+            Some(scope) => format!("- **{scope}**: {description}{breaking}"),
+            None => format!("- {description}{breaking}"),
+        }
+    }
+}
+
+/// Changelog entries for `commits` (newest first, as returned by git), oldest first.
+///
+/// Non-conventional commits, autosquash commits, and types without a
+/// section (like `chore` or `docs`) are left out.
+pub fn entries(commits: &[Commit]) -> Vec<Entry> {
+    commits
+        .iter()
+        .rev()
+        .filter(|commit| !is_autosquash_commit(&commit.message))
+        .filter_map(|commit| {
+            let ParseResult::Conventional(parsed) = parse_conventional_commit(&commit.message)
+            else {
+                return None;
+            };
+            let breaking = parsed.breaking || has_breaking_footer(&commit.full_message);
+            let section = SECTIONS
+                .iter()
+                .find(|(_, types)| types.contains(&parsed.commit_type.as_str()))
+                .map(|(section, _)| *section)
+                .or(breaking.then_some("Changed"))?;
+            Some(Entry {
+                section,
+                commit_type: parsed.commit_type,
+                scope: parsed.scope,
+                description: parsed.description.trim().trim_end_matches('.').to_string(),
+                breaking,
+                hash: commit.hash.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Entries the changelog doesn't list yet.
+///
+/// An entry is present when a list item has its description (case-insensitive),
+/// with or without the `**scope**:` prefix and `(breaking)` marker.
+pub fn missing<'a>(changelog: &str, entries: &'a [Entry]) -> Vec<&'a Entry> {
+    let items: Vec<String> = changelog.lines().filter_map(list_item_description).collect();
+    entries
+        .iter()
+        .filter(|entry| {
+            let description = entry.description.trim().to_lowercase();
+            !items.contains(&description)
+        })
+        .collect()
+}
+
+/// Lowercased description of a `- **scope**: Description (breaking)` list item.
+fn list_item_description(line: &str) -> Option<String> {
+    let trimmed = line.trim();
+    let item = trimmed.strip_prefix("- ").or_else(|| trimmed.strip_prefix("* "))?;
+    let item = match item.strip_prefix("**").and_then(|rest| rest.split_once("**: ")) {
+        Some((_, description)) => description,
+        None => item,
+    };
+    let item = item.strip_suffix(" (breaking)").unwrap_or(item);
+    Some(item.trim().to_lowercase())
+}
+
+/// A semver version increment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Bump {
+    Patch,
+    Minor,
+    Major,
+}
+
+/// Increment implied by `entries`: major for breaking changes, minor for
+/// features, patch otherwise. None when there is nothing to release.
+pub fn bump(entries: &[Entry]) -> Option<Bump> {
+    entries
+        .iter()
+        .map(|entry| {
+            if entry.breaking {
+                Bump::Major
+            } else if entry.commit_type == "feat" {
+                Bump::Minor
+            } else {
+                Bump::Patch
+            }
+        })
+        .max()
+}
+
+/// A `major.minor.patch` version.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+impl Version {
+    /// Parse `1.2.3` or `v1.2.3`. Pre-release and build suffixes are not supported.
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.strip_prefix('v').unwrap_or(s);
+        let mut parts = s.split('.').map(|part| part.parse::<u64>().ok());
+        let version = Self { major: parts.next()??, minor: parts.next()??, patch: parts.next()?? };
+        parts.next().is_none().then_some(version)
+    }
+
+    /// The next version after `self`.
+    ///
+    /// Before 1.0.0, breaking changes bump the minor version, as cargo does.
+    pub fn bump(self, bump: Bump) -> Self {
+        match bump {
+            Bump::Major if self.major > 0 => Self { major: self.major + 1, minor: 0, patch: 0 },
+            Bump::Major | Bump::Minor => Self { minor: self.minor + 1, patch: 0, ..self },
+            Bump::Patch => Self { patch: self.patch + 1, ..self },
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
@@ -0,0 +1,131 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Unit tests for changelog entries and versions.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+
+/// Create a test commit with the given hash and full message.
+fn commit(hash: &str, message: &str) -> Commit {
+    Commit {
+        hash: hash.to_string(),
+        message: message.lines().next().unwrap_or_default().to_string(),
+        full_message: message.to_string(),
+        author: "Test User <test@example.com>".to_string(),
+    }
+}
+
+/// Entries for messages given oldest first.
+fn entries_for(messages: &[&str]) -> Vec<Entry> {
+    let commits: Vec<Commit> = messages
+        .iter()
+        .enumerate()
+        .rev()
+        .map(|(i, message)| commit(&format!("{i:07}"), message))
+        .collect();
+    entries(&commits)
+}
+
+// =============================================================================
+// ENTRY TESTS
+// =============================================================================
+
+#[test]
+fn entries_are_oldest_first_in_sections() {
+    let entries = entries_for(&["feat(api): add export", "fix: handle empty input", "perf: cache"]);
+
+    let sections: Vec<_> = entries.iter().map(|e| (e.section, e.description.as_str())).collect();
+    assert_eq!(
+        sections,
+        [("Added", "add export"), ("Fixed", "handle empty input"), ("Changed", "cache")]
+    );
+    assert_eq!(entries[0].hash, "0000000");
+    assert_eq!(entries[0].scope.as_deref(), Some("api"));
+}
+
+#[test]
+fn entries_skip_unlisted_and_non_conventional_commits() {
+    let entries = entries_for(&[
+        "chore: update deps",
+        "docs: fix typo",
+        "update stuff",
+        "Merge branch 'feature'",
+        "fixup! feat: add export",
+    ]);
+    assert!(entries.is_empty());
+}
+
+#[test]
+fn breaking_commits_are_listed_under_changed() {
+    let entries = entries_for(&["chore!: drop node 16", "build: bump\n\nBREAKING CHANGE: msrv"]);
+
+    assert_eq!(entries.len(), 2);
+    assert!(entries.iter().all(|e| e.section == "Changed" && e.breaking));
+}
+
+#[test]
+fn entry_line_formats_scope_and_breaking() {
+    let entries = entries_for(&["feat(api)!: drop v1.", "fix: handle empty input"]);
+
+    assert_eq!(entries[0].line(), "- **api**: Drop v1 (breaking)");
+    assert_eq!(entries[1].line(), "- Handle empty input");
+}
+
+#[test]
+fn missing_matches_descriptions_case_insensitively() {
+    let entries = entries_for(&["feat: add export", "fix: handle empty input"]);
+    let changelog = "## [Unreleased]\n\n### Added\n\n- Add export\n";
+
+    let missing = missing(changelog, &entries);
+
+    assert_eq!(missing.len(), 1);
+    assert_eq!(missing[0].description, "handle empty input");
+}
+
+#[test]
+fn missing_matches_whole_list_items_only() {
+    let entries = entries_for(&["fix: typo", "feat: tests", "feat(api)!: drop v1"]);
+    let changelog = "# Changelog\n\nFix typo in the tests.\n\n### Added\n\n- Add tests for typo handling\n\n### Changed\n\n- **api**: Drop v1 (breaking)\n";
+
+    let missing = missing(changelog, &entries);
+
+    let descriptions: Vec<_> = missing.iter().map(|e| e.description.as_str()).collect();
+    assert_eq!(descriptions, vec!["typo", "tests"]);
+}
+
+// =============================================================================
+// VERSION TESTS
+// =============================================================================
+
+#[test]
+fn bump_follows_most_significant_change() {
+    assert_eq!(bump(&entries_for(&["chore: tidy"])), None);
+    assert_eq!(bump(&entries_for(&["fix: a", "perf: b"])), Some(Bump::Patch));
+    assert_eq!(bump(&entries_for(&["fix: a", "feat: b"])), Some(Bump::Minor));
+    assert_eq!(bump(&entries_for(&["feat: a", "fix!: b"])), Some(Bump::Major));
+}
+
+#[test]
+fn parses_versions() {
+    assert_eq!(Version::parse("1.2.3"), Some(Version { major: 1, minor: 2, patch: 3 }));
+    assert_eq!(Version::parse("v0.4.0"), Some(Version { major: 0, minor: 4, patch: 0 }));
+    for invalid in ["1.2", "1.2.3.4", "1.2.x", "1.2.3-rc.1", "main", ""] {
+        assert_eq!(Version::parse(invalid), None, "{invalid}");
+    }
+}
+
+#[test]
+fn bumps_versions() {
+    let version = Version::parse("1.2.3").unwrap();
+    assert_eq!(version.bump(Bump::Patch).to_string(), "1.2.4");
+    assert_eq!(version.bump(Bump::Minor).to_string(), "1.3.0");
+    assert_eq!(version.bump(Bump::Major).to_string(), "2.0.0");
+}
+
+#[test]
+fn breaking_changes_bump_minor_before_1_0() {
+    let version = Version::parse("0.4.3").unwrap();
+    assert_eq!(version.bump(Bump::Major).to_string(), "0.5.0");
+}
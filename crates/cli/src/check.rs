@@ -37,6 +37,8 @@ pub struct CheckContext<'a> {
     pub base_branch: Option<&'a str>,
     /// Whether checking only staged changes (--staged flag).
     pub staged: bool,
+    /// Commit message file to validate (--commit-msg flag).
+    pub commit_msg: Option<&'a Path>,
     /// Whether verbose diagnostic output is enabled.
     pub verbose: bool,
 }
@@ -135,7 +137,7 @@ pub struct Violation {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub path: Option<String>,
 
-    /// Link target for broken_link violations.
+    /// Link target for broken_link and broken_anchor violations.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target: Option<String>,
 
@@ -158,6 +160,10 @@ pub struct Violation {
     /// Found value (for license check violations - e.g., actual license or year).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub found: Option<String>,
+
+    /// Contributing files (for agents context budget violations).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files: Option<Vec<PathBuf>>,
 }
 
 impl Violation {
@@ -192,6 +198,7 @@ impl Violation {
             scope: None,
             expected: None,
             found: None,
+            files: None,
         }
     }
 
@@ -225,6 +232,7 @@ impl Violation {
             scope: None,
             expected: None,
             found: None,
+            files: None,
         }
     }
 
@@ -259,6 +267,37 @@ impl Violation {
             scope: None,
             expected: None,
             found: None,
+            files: None,
+        }
+    }
+
+    /// Create a project-level violation (no file or commit).
+    pub fn project(violation_type: impl Into<String>, advice: impl Into<String>) -> Self {
+        Self {
+            file: None,
+            line: None,
+            violation_type: violation_type.into(),
+            advice: advice.into(),
+            value: None,
+            threshold: None,
+            pattern: None,
+            lines: None,
+            nonblank: None,
+            other_file: None,
+            section: None,
+            commit: None,
+            message: None,
+            expected_docs: None,
+            area: None,
+            area_match: None,
+            path: None,
+            target: None,
+            change_type: None,
+            lines_changed: None,
+            scope: None,
+            expected: None,
+            found: None,
+            files: None,
         }
     }
 
@@ -314,7 +353,7 @@ impl Violation {
         self
     }
 
-    /// Add target context for broken_link violations.
+    /// Add target context for broken_link and broken_anchor violations.
     pub fn with_target(mut self, target: impl Into<String>) -> Self {
         self.target = Some(target.into());
         self
@@ -333,6 +372,18 @@ impl Violation {
         self
     }
 
+    /// Add a found value without an expected one.
+    pub fn with_found(mut self, found: impl Into<String>) -> Self {
+        self.found = Some(found.into());
+        self
+    }
+
+    /// Add contributing files for context budget violations.
+    pub fn with_files(mut self, files: Vec<PathBuf>) -> Self {
+        self.files = Some(files);
+        self
+    }
+
     /// Add expected/found values for license check violations.
     pub fn with_expected_found(
         mut self,
@@ -7,6 +7,7 @@ use serde::Deserialize;
 use serde::de::{self, Deserializer};
 
 use crate::config::CheckLevel;
+use crate::tokens::Tokenizer;
 
 /// Custom deserializer for optional usize that accepts false to mean None.
 pub fn deserialize_optional_usize<'de, D>(deserializer: D) -> Result<Option<usize>, D::Error>
@@ -122,6 +123,19 @@ pub struct AgentsConfig {
     )]
     pub max_tokens: Option<usize>,
 
+    /// Maximum combined tokens of the agent files loaded together in one
+    /// directory (default: disabled).
+    #[serde(default, deserialize_with = "deserialize_optional_usize")]
+    pub effective_max_tokens: Option<usize>,
+
+    /// Tokenizer for `max_tokens` limits, including spec files (default: approx).
+    #[serde(default)]
+    pub tokenizer: Tokenizer,
+
+    /// Stale path and symbol reference validation.
+    #[serde(default)]
+    pub references: ReferencesConfig,
+
     /// Root scope settings (overrides flat config).
     #[serde(default)]
     pub root: Option<AgentsScopeConfig>,
@@ -151,6 +165,9 @@ impl Default for AgentsConfig {
             mermaid: ContentRule::allow(),
             max_lines: Self::default_max_lines(),
             max_tokens: Self::default_max_tokens(),
+            effective_max_tokens: None,
+            tokenizer: Tokenizer::default(),
+            references: ReferencesConfig::default(),
             root: None,
             package: None,
             module: None,
@@ -167,6 +184,12 @@ impl AgentsConfig {
             ".cursorrules".to_string(),
             ".cursor/rules/*.md".to_string(),
             ".cursor/rules/*.mdc".to_string(),
+            "GEMINI.md".to_string(),
+            ".github/copilot-instructions.md".to_string(),
+            ".github/instructions/*.instructions.md".to_string(),
+            ".claude/commands/*.md".to_string(),
+            ".claude/agents/*.md".to_string(),
+            ".claude/skills/*/SKILL.md".to_string(),
         ]
     }
 
@@ -216,6 +239,33 @@ pub struct AgentsScopeConfig {
     pub max_tokens: Option<usize>,
 }
 
+/// Reference validation configuration (`[check.agents.references]`).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ReferencesConfig {
+    /// Check level: error, warn, or off (default: off).
+    #[serde(default = "ReferencesConfig::default_check")]
+    pub check: CheckLevel,
+
+    /// References to skip, as glob patterns matched against the path or
+    /// declared name (e.g., "target/**", "generated_*").
+    #[serde(default)]
+    pub ignore: Vec<String>,
+}
+
+impl Default for ReferencesConfig {
+    fn default() -> Self {
+        Self { check: Self::default_check(), ignore: Vec::new() }
+    }
+}
+
+impl ReferencesConfig {
+    /// Default check level (off - opt in per project).
+    fn default_check() -> CheckLevel {
+        CheckLevel::Off
+    }
+}
+
 /// Section validation configuration.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(deny_unknown_fields)]
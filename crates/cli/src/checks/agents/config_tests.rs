@@ -36,6 +36,21 @@ fn default_files_includes_cursor_rules_mdc_glob() {
     assert!(config.files.contains(&".cursor/rules/*.mdc".to_string()));
 }
 
+#[test]
+fn default_files_includes_claude_copilot_and_gemini_files() {
+    let config = AgentsConfig::default();
+    for pattern in [
+        "GEMINI.md",
+        ".github/copilot-instructions.md",
+        ".github/instructions/*.instructions.md",
+        ".claude/commands/*.md",
+        ".claude/agents/*.md",
+        ".claude/skills/*/SKILL.md",
+    ] {
+        assert!(config.files.contains(&pattern.to_string()), "{pattern}");
+    }
+}
+
 #[test]
 fn default_config_requires_any_agent_file() {
     let config = AgentsConfig::default();
@@ -79,6 +94,33 @@ fn default_max_tokens_is_20000() {
     assert_eq!(config.max_tokens, Some(20000));
 }
 
+#[test]
+fn default_effective_max_tokens_is_disabled() {
+    let config = AgentsConfig::default();
+    assert_eq!(config.effective_max_tokens, None);
+}
+
+#[test]
+fn effective_max_tokens_deserializes() {
+    let config: AgentsConfig = toml::from_str("effective_max_tokens = 4000").unwrap();
+    assert_eq!(config.effective_max_tokens, Some(4000));
+}
+
+#[test]
+fn default_references_are_off() {
+    let config = AgentsConfig::default();
+    assert_eq!(config.references.check, CheckLevel::Off);
+    assert!(config.references.ignore.is_empty());
+}
+
+#[test]
+fn references_deserialize() {
+    let config: AgentsConfig =
+        toml::from_str("[references]\ncheck = \"warn\"\nignore = [\"target/**\"]").unwrap();
+    assert_eq!(config.references.check, CheckLevel::Warn);
+    assert_eq!(config.references.ignore, vec!["target/**"]);
+}
+
 #[test]
 fn content_rule_deserialize_allow() {
     let json = r#""allow""#;
@@ -5,6 +5,8 @@
 //!
 //! Detects tables, box diagrams, and mermaid blocks in markdown content.
 
+use crate::tokens::Tokenizer;
+
 /// A detected content issue.
 #[derive(Debug)]
 pub struct ContentIssue {
@@ -241,15 +243,17 @@ pub fn check_line_count(content: &str, max_lines: usize) -> Option<SizeViolation
     }
 }
 
-/// Check if content exceeds the token limit.
-///
-/// Uses `chars / 4` as a fast approximation.
-pub fn check_token_count(content: &str, max_tokens: usize) -> Option<SizeViolation> {
-    let token_estimate = content.chars().count() / 4;
-    if token_estimate > max_tokens {
+/// Check if content exceeds the token limit, counted with `tokenizer`.
+pub fn check_token_count(
+    content: &str,
+    max_tokens: usize,
+    tokenizer: Tokenizer,
+) -> Option<SizeViolation> {
+    let token_count = tokenizer.count(content);
+    if token_count > max_tokens {
         Some(SizeViolation {
             limit_type: SizeLimitType::Tokens,
-            value: token_estimate,
+            value: token_count,
             threshold: max_tokens,
         })
     } else {
@@ -253,7 +253,7 @@ fn check_line_count_empty() {
 fn check_token_count_under_limit() {
     // 20 chars = ~5 tokens
     let content = "12345678901234567890";
-    let result = check_token_count(content, 10);
+    let result = check_token_count(content, 10, Tokenizer::Approx);
     assert!(result.is_none());
 }
 
@@ -261,7 +261,7 @@ fn check_token_count_under_limit() {
 fn check_token_count_over_limit() {
     // 80 chars = ~20 tokens
     let content = "a".repeat(80);
-    let result = check_token_count(&content, 10);
+    let result = check_token_count(&content, 10, Tokenizer::Approx);
     assert!(result.is_some());
     let violation = result.unwrap();
     assert_eq!(violation.value, 20);
@@ -270,7 +270,7 @@ fn check_token_count_over_limit() {
 
 #[test]
 fn check_token_count_empty() {
-    let result = check_token_count("", 10);
+    let result = check_token_count("", 10, Tokenizer::Approx);
     assert!(result.is_none());
 }
 
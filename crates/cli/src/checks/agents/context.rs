@@ -0,0 +1,228 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Effective agent context per directory.
+//!
+//! An agent working in a directory loads every agent file from the project
+//! root down to that directory, plus the Cursor rules whose frontmatter
+//! applies there. Individual file limits don't bound that combined context,
+//! so this module assembles it for `effective_max_tokens` and
+//! `quench agents context`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use globset::GlobSet;
+
+use super::detection::{Scope, classify_scope, detect_agent_files};
+use super::frontmatter::{FrontmatterKind, parse_frontmatter, split_globs};
+use super::mdc::{self, RuleScope};
+use crate::adapter::glob::build_glob_set;
+use crate::adapter::{AdapterRegistry, FileKind};
+use crate::config::Config;
+use crate::tokens::Tokenizer;
+use crate::walker::FileWalker;
+
+/// An agent file loaded into a directory's context.
+#[derive(Debug)]
+pub struct ContextFile {
+    /// Path relative to the project root.
+    pub path: PathBuf,
+    /// Why the file is loaded ("root", "package crates/cli", "rule: always").
+    pub reason: String,
+    /// File content.
+    pub content: String,
+    /// Token count with the configured tokenizer.
+    pub tokens: usize,
+}
+
+/// Where a candidate file is loaded.
+#[derive(Debug)]
+enum Applies {
+    /// In this directory (relative to the root) and everything below it.
+    Under(PathBuf),
+    /// In directories containing a file that matches the rule's globs.
+    Globs(GlobSet),
+    /// Only when requested, so never part of the effective context.
+    OnDemand,
+}
+
+#[derive(Debug)]
+struct Candidate {
+    file: ContextFile,
+    applies: Applies,
+}
+
+/// Agent files that may be loaded into an agent's context.
+#[derive(Debug, Default)]
+pub struct ContextSources {
+    candidates: Vec<Candidate>,
+}
+
+impl ContextSources {
+    /// Collect agent files relevant to `dirs` (relative to `root`).
+    ///
+    /// Includes files detected at the root and in packages, module-level
+    /// files named by an exact pattern in any ancestor of `dirs`, and Cursor
+    /// rules matched by glob patterns.
+    pub fn collect<'a>(
+        root: &Path,
+        packages: &[String],
+        patterns: &[String],
+        tokenizer: Tokenizer,
+        dirs: impl IntoIterator<Item = &'a Path>,
+    ) -> Self {
+        let mut sources = Self::default();
+        let mut seen = BTreeSet::new();
+
+        for detected in detect_agent_files(root, packages, patterns) {
+            let rel = detected.path.strip_prefix(root).unwrap_or(&detected.path).to_path_buf();
+            if !seen.insert(rel.clone()) {
+                continue;
+            }
+            let Ok(content) = std::fs::read_to_string(&detected.path) else {
+                continue;
+            };
+
+            // Exact root patterns (CLAUDE.md, .cursorrules) are project-wide
+            let is_root_file = patterns.iter().any(|p| !p.contains('*') && Path::new(p) == rel);
+            let (applies, reason) = if is_root_file {
+                (Applies::Under(PathBuf::new()), "root".to_string())
+            } else if let Scope::Package(name) = &detected.scope {
+                let dir = rel.parent().unwrap_or(Path::new("")).to_path_buf();
+                (Applies::Under(dir), format!("package {name}"))
+            } else if FrontmatterKind::for_path(&rel) == Some(FrontmatterKind::CopilotInstructions)
+            {
+                instructions_applies(&content)
+            } else {
+                rule_applies(&content, &detected.path)
+            };
+            sources.push(rel, reason, content, tokenizer, applies);
+        }
+
+        // Module-level files live next to the code they describe
+        let names: Vec<&String> =
+            patterns.iter().filter(|p| !p.contains('*') && !p.contains('/')).collect();
+        let ancestors: BTreeSet<&Path> = dirs
+            .into_iter()
+            .flat_map(Path::ancestors)
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .collect();
+        for dir in ancestors {
+            for name in &names {
+                let rel = dir.join(name);
+                let path = root.join(&rel);
+                if seen.contains(&rel) || !path.is_file() {
+                    continue;
+                }
+                let Ok(content) = std::fs::read_to_string(&path) else {
+                    continue;
+                };
+                let reason = match classify_scope(&path, root, packages) {
+                    Scope::Root => "root".to_string(),
+                    Scope::Package(name) => format!("package {name}"),
+                    Scope::Module => "module".to_string(),
+                };
+                seen.insert(rel.clone());
+                sources.push(rel, reason, content, tokenizer, Applies::Under(dir.to_path_buf()));
+            }
+        }
+
+        sources
+    }
+
+    fn push(
+        &mut self,
+        path: PathBuf,
+        reason: String,
+        content: String,
+        tokenizer: Tokenizer,
+        applies: Applies,
+    ) {
+        let tokens = tokenizer.count(&content);
+        self.candidates
+            .push(Candidate { file: ContextFile { path, reason, content, tokens }, applies });
+    }
+
+    /// Agent files loaded for `dir`, given the files it contains (both
+    /// relative to the root).
+    pub fn assemble(&self, dir: &Path, files: &[PathBuf]) -> Vec<&ContextFile> {
+        self.candidates
+            .iter()
+            .filter(|c| match &c.applies {
+                Applies::Under(base) => dir.starts_with(base),
+                Applies::Globs(globs) => files.iter().any(|f| globs.is_match(f)),
+                Applies::OnDemand => false,
+            })
+            .map(|c| &c.file)
+            .collect()
+    }
+}
+
+/// Where a Cursor rule applies, from its frontmatter.
+fn rule_applies(content: &str, path: &Path) -> (Applies, String) {
+    // Malformed frontmatter is reported by cursor reconciliation
+    let Ok(rule) = mdc::parse_mdc(content, path.to_path_buf()) else {
+        return (Applies::OnDemand, "rule: on demand".to_string());
+    };
+    match mdc::classify_scope(&rule) {
+        RuleScope::AlwaysApply => (Applies::Under(PathBuf::new()), "rule: always".to_string()),
+        RuleScope::SingleDirectory(dir) => {
+            let reason = format!("rule: {}", dir.display());
+            (Applies::Under(dir), reason)
+        }
+        RuleScope::FilePattern => {
+            let globs = build_glob_set(rule.globs.as_deref().unwrap_or_default());
+            (Applies::Globs(globs), "rule: globs".to_string())
+        }
+        RuleScope::OnDemand => (Applies::OnDemand, "rule: on demand".to_string()),
+    }
+}
+
+/// Where Copilot path-specific instructions apply, from `applyTo`.
+fn instructions_applies(content: &str) -> (Applies, String) {
+    let fields = parse_frontmatter(content).ok().flatten().unwrap_or_default();
+    let Some(apply_to) = fields.iter().find(|f| f.key == "applyTo") else {
+        return (Applies::OnDemand, "instructions: on demand".to_string());
+    };
+    let globs = build_glob_set(&split_globs(&apply_to.value));
+    (Applies::Globs(globs), "instructions: applyTo".to_string())
+}
+
+/// Whether `rel` (relative to the root) is a source or test file.
+///
+/// Same notion of source code as cloc: known languages and declarative adapters.
+pub(super) fn is_source_file(rel: &Path, registry: &AdapterRegistry) -> bool {
+    let is_code = crate::cloc::file_extension(rel)
+        .is_some_and(|ext| crate::cloc::is_text_extension(&ext))
+        || registry.declarative_for(rel).is_some();
+    is_code && registry.classify(rel) != FileKind::Other
+}
+
+/// Directories containing source or test files, each with its files
+/// (relative to `root`).
+///
+/// Walks the project rather than using the checked files, which exclude
+/// cached files on warm runs.
+pub fn source_directories(root: &Path, config: &Config) -> BTreeMap<PathBuf, Vec<PathBuf>> {
+    let registry = AdapterRegistry::for_project_with_config(root, config);
+    let (files, _) = FileWalker::from_exclude_config(&config.project.exclude).walk_collect(root);
+
+    let mut dirs: BTreeMap<PathBuf, Vec<PathBuf>> = BTreeMap::new();
+    for file in files {
+        let rel = file.path.strip_prefix(root).unwrap_or(&file.path);
+        if !is_source_file(rel, &registry) {
+            continue;
+        }
+        let dir = rel.parent().unwrap_or(Path::new("")).to_path_buf();
+        dirs.entry(dir).or_default().push(rel.to_path_buf());
+    }
+    for files in dirs.values_mut() {
+        files.sort();
+    }
+    dirs
+}
+
+#[cfg(test)]
+#[path = "context_tests.rs"]
+mod tests;
@@ -0,0 +1,192 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+use crate::checks::agents::AgentsConfig;
+
+// =============================================================================
+// HELPER
+// =============================================================================
+
+fn write_file(root: &Path, rel: &str, content: &str) {
+    let path = root.join(rel);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).unwrap();
+    }
+    std::fs::write(path, content).unwrap();
+}
+
+/// Paths of the files loaded in `dir`, which contains `files`.
+fn loaded(root: &Path, packages: &[&str], dir: &str, files: &[&str]) -> Vec<String> {
+    let packages: Vec<String> = packages.iter().map(|p| p.to_string()).collect();
+    let files: Vec<PathBuf> = files.iter().map(PathBuf::from).collect();
+    let sources = ContextSources::collect(
+        root,
+        &packages,
+        &AgentsConfig::default_files(),
+        Tokenizer::Approx,
+        [Path::new(dir)],
+    );
+    sources
+        .assemble(Path::new(dir), &files)
+        .iter()
+        .map(|f| f.path.to_string_lossy().to_string())
+        .collect()
+}
+
+// =============================================================================
+// DIRECTORY FILES
+// =============================================================================
+
+#[test]
+fn root_file_applies_everywhere() {
+    let dir = tempfile::tempdir().unwrap();
+    write_file(dir.path(), "CLAUDE.md", "# Root\n");
+
+    assert_eq!(loaded(dir.path(), &[], "", &[]), vec!["CLAUDE.md"]);
+    assert_eq!(loaded(dir.path(), &[], "src/deep", &[]), vec!["CLAUDE.md"]);
+}
+
+#[test]
+fn module_files_layer_by_ancestor() {
+    let dir = tempfile::tempdir().unwrap();
+    write_file(dir.path(), "CLAUDE.md", "# Root\n");
+    write_file(dir.path(), "src/CLAUDE.md", "# Src\n");
+    write_file(dir.path(), "src/api/CLAUDE.md", "# Api\n");
+    write_file(dir.path(), "src/db/CLAUDE.md", "# Db\n");
+
+    assert_eq!(
+        loaded(dir.path(), &[], "src/api/v1", &[]),
+        vec!["CLAUDE.md", "src/CLAUDE.md", "src/api/CLAUDE.md"]
+    );
+    assert_eq!(loaded(dir.path(), &[], "src", &[]), vec!["CLAUDE.md", "src/CLAUDE.md"]);
+}
+
+#[test]
+fn package_file_applies_within_package() {
+    let dir = tempfile::tempdir().unwrap();
+    write_file(dir.path(), "CLAUDE.md", "# Root\n");
+    write_file(dir.path(), "crates/cli/CLAUDE.md", "# Cli\n");
+
+    let packages = ["crates/*"];
+    assert_eq!(
+        loaded(dir.path(), &packages, "crates/cli/src", &[]),
+        vec!["CLAUDE.md", "crates/cli/CLAUDE.md"]
+    );
+    assert_eq!(loaded(dir.path(), &packages, "crates/core/src", &[]), vec!["CLAUDE.md"]);
+}
+
+// =============================================================================
+// CURSOR RULES
+// =============================================================================
+
+#[test]
+fn always_apply_rule_applies_everywhere() {
+    let dir = tempfile::tempdir().unwrap();
+    write_file(dir.path(), ".cursor/rules/general.mdc", "---\nalwaysApply: true\n---\n\nRule.\n");
+
+    assert_eq!(loaded(dir.path(), &[], "src", &[]), vec![".cursor/rules/general.mdc"]);
+}
+
+#[test]
+fn directory_rule_applies_under_its_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    write_file(dir.path(), ".cursor/rules/api.mdc", "---\nglobs: \"src/api/**\"\n---\n\nRule.\n");
+
+    assert_eq!(loaded(dir.path(), &[], "src/api/v1", &[]), vec![".cursor/rules/api.mdc"]);
+    assert!(loaded(dir.path(), &[], "src/db", &[]).is_empty());
+}
+
+#[test]
+fn file_pattern_rule_applies_when_a_file_matches() {
+    let dir = tempfile::tempdir().unwrap();
+    write_file(
+        dir.path(),
+        ".cursor/rules/tsx.mdc",
+        "---\nglobs: [\"**/*.tsx\", \"**/*.jsx\"]\n---\n\nRule.\n",
+    );
+
+    assert_eq!(
+        loaded(dir.path(), &[], "src/ui", &["src/ui/App.tsx"]),
+        vec![".cursor/rules/tsx.mdc"]
+    );
+    assert!(loaded(dir.path(), &[], "src/db", &["src/db/mod.rs"]).is_empty());
+}
+
+#[test]
+fn on_demand_rule_never_applies() {
+    let dir = tempfile::tempdir().unwrap();
+    write_file(
+        dir.path(),
+        ".cursor/rules/manual.mdc",
+        "---\ndescription: \"Manual\"\n---\n\nRule.\n",
+    );
+
+    assert!(loaded(dir.path(), &[], "", &[]).is_empty());
+}
+
+#[test]
+fn copilot_instructions_apply_by_apply_to() {
+    let dir = tempfile::tempdir().unwrap();
+    write_file(
+        dir.path(),
+        ".github/instructions/ts.instructions.md",
+        "---\napplyTo: \"**/*.ts,**/*.tsx\"\n---\n\nInstructions.\n",
+    );
+    write_file(dir.path(), ".claude/commands/review.md", "Review the diff.\n");
+
+    assert_eq!(
+        loaded(dir.path(), &[], "src/ui", &["src/ui/App.tsx"]),
+        vec![".github/instructions/ts.instructions.md"]
+    );
+    assert!(loaded(dir.path(), &[], "src/db", &["src/db/mod.rs"]).is_empty());
+}
+
+#[test]
+fn copilot_repository_instructions_apply_everywhere() {
+    let dir = tempfile::tempdir().unwrap();
+    write_file(dir.path(), ".github/copilot-instructions.md", "# Copilot\n");
+    write_file(dir.path(), "GEMINI.md", "# Gemini\n");
+
+    assert_eq!(
+        loaded(dir.path(), &[], "src", &[]),
+        vec!["GEMINI.md", ".github/copilot-instructions.md"]
+    );
+}
+
+// =============================================================================
+// TOKENS AND DIRECTORIES
+// =============================================================================
+
+#[test]
+fn files_are_counted_with_tokenizer() {
+    let dir = tempfile::tempdir().unwrap();
+    write_file(dir.path(), "CLAUDE.md", &"a".repeat(40));
+
+    let sources = ContextSources::collect(
+        dir.path(),
+        &[],
+        &AgentsConfig::default_files(),
+        Tokenizer::Approx,
+        [Path::new("")],
+    );
+    let files = sources.assemble(Path::new(""), &[]);
+
+    assert_eq!(files[0].tokens, 10);
+    assert_eq!(files[0].reason, "root");
+}
+
+#[test]
+fn source_directories_groups_source_files() {
+    let dir = tempfile::tempdir().unwrap();
+    write_file(dir.path(), "src/lib.rs", "");
+    write_file(dir.path(), "src/api/mod.rs", "");
+    write_file(dir.path(), "docs/guide.md", "");
+
+    let dirs = source_directories(dir.path(), &Config::default());
+
+    assert_eq!(dirs.keys().collect::<Vec<_>>(), vec![Path::new("src"), Path::new("src/api")]);
+    assert_eq!(dirs[Path::new("src")], vec![PathBuf::from("src/lib.rs")]);
+}
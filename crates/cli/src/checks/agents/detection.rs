@@ -154,31 +154,66 @@ fn match_glob_pattern(pattern: &str, root: &Path) -> Vec<PathBuf> {
         return vec![];
     };
 
-    let search_dir = root.join(parent);
-    if !search_dir.is_dir() {
-        return vec![];
-    }
-
     // Build a glob matcher for the file pattern
     let Ok(glob) = Glob::new(file_pattern) else {
         return vec![];
     };
     let matcher = glob.compile_matcher();
 
-    // Read the directory and match files
-    let Ok(entries) = std::fs::read_dir(&search_dir) else {
-        return vec![];
-    };
+    let mut matches = Vec::new();
+    for search_dir in expand_dir_pattern(root, parent) {
+        // Read the directory and match files
+        let Ok(entries) = std::fs::read_dir(&search_dir) else {
+            continue;
+        };
+        let mut found: Vec<PathBuf> = entries
+            .filter_map(|entry| {
+                let entry = entry.ok()?;
+                let file_name = entry.file_name();
+                let file_name_str = file_name.to_str()?;
+
+                if matcher.is_match(file_name_str) { Some(entry.path()) } else { None }
+            })
+            .collect();
+        found.sort();
+        matches.extend(found);
+    }
+    matches
+}
 
-    entries
-        .filter_map(|entry| {
-            let entry = entry.ok()?;
-            let file_name = entry.file_name();
-            let file_name_str = file_name.to_str()?;
+/// Expand a directory pattern whose components may contain globs.
+///
+/// For example, ".claude/skills/*" expands to every skill directory.
+fn expand_dir_pattern(root: &Path, pattern: &Path) -> Vec<PathBuf> {
+    let mut dirs = vec![root.to_path_buf()];
+    for component in pattern.components() {
+        let Some(name) = component.as_os_str().to_str() else {
+            return vec![];
+        };
+        if !name.contains('*') {
+            dirs = dirs.into_iter().map(|dir| dir.join(name)).filter(|dir| dir.is_dir()).collect();
+            continue;
+        }
 
-            if matcher.is_match(file_name_str) { Some(entry.path()) } else { None }
-        })
-        .collect()
+        let Ok(glob) = Glob::new(name) else {
+            return vec![];
+        };
+        let matcher = glob.compile_matcher();
+        let mut expanded = Vec::new();
+        for dir in &dirs {
+            let Ok(entries) = std::fs::read_dir(dir) else {
+                continue;
+            };
+            expanded.extend(entries.filter_map(|entry| {
+                let path = entry.ok()?.path();
+                let matched = path.file_name()?.to_str().is_some_and(|n| matcher.is_match(n));
+                (matched && path.is_dir()).then_some(path)
+            }));
+        }
+        expanded.sort();
+        dirs = expanded;
+    }
+    dirs
 }
 
 /// Classify the scope of a file path.
@@ -194,7 +229,19 @@ pub fn classify_scope(file_path: &Path, root: &Path, packages: &[String]) -> Sco
 
     // Direct child of root = Root scope
     // Deeper nesting = Module scope
-    if relative.components().count() == 1 { Scope::Root } else { Scope::Module }
+    if relative.components().count() == 1 || is_project_wide(relative) {
+        Scope::Root
+    } else {
+        Scope::Module
+    }
+}
+
+/// Files outside the root directory that apply to the whole project.
+///
+/// Copilot's repository-wide instructions live under `.github/` but play the
+/// same role as a root CLAUDE.md.
+fn is_project_wide(relative: &Path) -> bool {
+    relative == Path::new(".github/copilot-instructions.md")
 }
 
 /// Check if a relative path is under a package pattern.
@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
 use std::path::Path;
 
 use super::*;
@@ -70,3 +72,32 @@ fn extract_package_name_exact() {
     let relative = Path::new("packages/api/CLAUDE.md");
     assert_eq!(extract_package_name(relative, "packages/api"), "packages/api");
 }
+
+#[test]
+fn classify_scope_root_for_copilot_instructions() {
+    let root = Path::new("/project");
+    let file = Path::new("/project/.github/copilot-instructions.md");
+
+    assert_eq!(classify_scope(file, root, &[]), Scope::Root);
+}
+
+#[test]
+fn match_glob_pattern_expands_directory_globs() {
+    let dir = tempfile::tempdir().unwrap();
+    for rel in [".claude/skills/review/SKILL.md", ".claude/skills/deploy/SKILL.md"] {
+        let path = dir.path().join(rel);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, "").unwrap();
+    }
+    std::fs::create_dir_all(dir.path().join(".claude/skills/empty")).unwrap();
+
+    let matches = match_glob_pattern(".claude/skills/*/SKILL.md", dir.path());
+
+    assert_eq!(
+        matches,
+        vec![
+            dir.path().join(".claude/skills/deploy/SKILL.md"),
+            dir.path().join(".claude/skills/review/SKILL.md"),
+        ]
+    );
+}
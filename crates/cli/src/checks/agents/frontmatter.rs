@@ -0,0 +1,296 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Frontmatter validation for Claude and Copilot agent files.
+//!
+//! Claude commands, subagents and skills, and Copilot path-specific
+//! instructions carry YAML frontmatter between `---` delimiters, like the
+//! `.mdc` rules parsed by [`super::mdc`]. A misspelled key silently disables
+//! a setting, and an `applyTo` pattern that matches no files means the
+//! instructions never load.
+
+use std::path::{Path, PathBuf};
+
+use globset::Glob;
+
+use super::detection::DetectedFile;
+use super::mdc::{parse_globs, unquote};
+use crate::check::{CheckContext, Violation};
+use crate::walker::FileWalker;
+
+/// Kind of agent file whose frontmatter is validated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrontmatterKind {
+    /// `.claude/commands/*.md` slash command.
+    ClaudeCommand,
+    /// `.claude/agents/*.md` subagent.
+    ClaudeAgent,
+    /// `.claude/skills/*/SKILL.md` skill.
+    ClaudeSkill,
+    /// `.github/instructions/*.instructions.md` path-specific instructions.
+    CopilotInstructions,
+}
+
+impl FrontmatterKind {
+    /// Kind of the file at `rel` (relative to the project root), if it has
+    /// validated frontmatter.
+    pub fn for_path(rel: &Path) -> Option<Self> {
+        let name = rel.file_name()?.to_str()?;
+        if rel.starts_with(".claude/commands") && name.ends_with(".md") {
+            Some(FrontmatterKind::ClaudeCommand)
+        } else if rel.starts_with(".claude/agents") && name.ends_with(".md") {
+            Some(FrontmatterKind::ClaudeAgent)
+        } else if rel.starts_with(".claude/skills") && name == "SKILL.md" {
+            Some(FrontmatterKind::ClaudeSkill)
+        } else if rel.starts_with(".github/instructions") && name.ends_with(".instructions.md") {
+            Some(FrontmatterKind::CopilotInstructions)
+        } else {
+            None
+        }
+    }
+
+    /// Human-readable name for advice messages.
+    pub fn label(self) -> &'static str {
+        match self {
+            FrontmatterKind::ClaudeCommand => "Claude command",
+            FrontmatterKind::ClaudeAgent => "Claude subagent",
+            FrontmatterKind::ClaudeSkill => "Claude skill",
+            FrontmatterKind::CopilotInstructions => "Copilot instructions",
+        }
+    }
+
+    /// Keys the tool recognizes.
+    fn allowed_keys(self) -> &'static [&'static str] {
+        match self {
+            FrontmatterKind::ClaudeCommand => &[
+                "description",
+                "allowed-tools",
+                "argument-hint",
+                "model",
+                "disable-model-invocation",
+            ],
+            FrontmatterKind::ClaudeAgent => {
+                &["name", "description", "tools", "model", "color", "permissionMode", "skills"]
+            }
+            FrontmatterKind::ClaudeSkill => {
+                &["name", "description", "license", "allowed-tools", "metadata", "compatibility"]
+            }
+            FrontmatterKind::CopilotInstructions => &["applyTo", "description", "excludeAgent"],
+        }
+    }
+
+    /// Keys that must be present.
+    fn required_keys(self) -> &'static [&'static str] {
+        match self {
+            FrontmatterKind::ClaudeCommand => &[],
+            FrontmatterKind::ClaudeAgent | FrontmatterKind::ClaudeSkill => &["name", "description"],
+            FrontmatterKind::CopilotInstructions => &["applyTo"],
+        }
+    }
+}
+
+/// A top-level frontmatter key.
+#[derive(Debug, PartialEq, Eq)]
+pub struct FrontmatterField {
+    pub key: String,
+    /// Raw value on the key's line (empty for nested values).
+    pub value: String,
+    /// Line number (1-indexed).
+    pub line: u32,
+}
+
+/// Error when parsing frontmatter.
+#[derive(Debug, PartialEq, Eq)]
+pub struct FrontmatterError {
+    pub message: String,
+    /// Line number (1-indexed).
+    pub line: u32,
+}
+
+/// Parse the top-level keys of the frontmatter between `---` delimiters.
+///
+/// Returns `Ok(None)` when the content has no frontmatter. Indented lines
+/// and list items belong to the previous key and are skipped.
+pub fn parse_frontmatter(content: &str) -> Result<Option<Vec<FrontmatterField>>, FrontmatterError> {
+    let mut lines = content.lines().peekable();
+
+    // Check for frontmatter delimiter
+    if lines.peek().map(|l| l.trim()) != Some("---") {
+        return Ok(None);
+    }
+    lines.next(); // skip opening ---
+
+    let mut fields = Vec::new();
+    for (idx, line) in lines.enumerate() {
+        let line_num = idx as u32 + 2;
+        if line.trim() == "---" {
+            return Ok(Some(fields));
+        }
+        let nested = line.starts_with([' ', '\t']) || line.starts_with("- ");
+        if nested || line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((key, value)) = line.split_once(':') else {
+            return Err(FrontmatterError {
+                message: format!("expected `key: value`, found `{}`", line.trim()),
+                line: line_num,
+            });
+        };
+        fields.push(FrontmatterField {
+            key: key.trim().to_string(),
+            value: value.trim().to_string(),
+            line: line_num,
+        });
+    }
+
+    Err(FrontmatterError {
+        message: "unterminated frontmatter (missing closing ---)".to_string(),
+        line: 1,
+    })
+}
+
+/// Malformed frontmatter, a missing or unknown key, or an invalid glob.
+#[derive(Debug, PartialEq, Eq)]
+pub struct FrontmatterIssue {
+    /// Line number (1-indexed).
+    pub line: u32,
+    pub advice: String,
+}
+
+/// A glob pattern from the frontmatter, to be matched against project files.
+#[derive(Debug, PartialEq, Eq)]
+pub struct FrontmatterGlob {
+    pub pattern: String,
+    /// Line number of the key (1-indexed).
+    pub line: u32,
+}
+
+/// Validate the frontmatter of a `kind` file.
+///
+/// Returns the issues found and the valid globs from `applyTo`.
+pub fn validate_frontmatter(
+    kind: FrontmatterKind,
+    content: &str,
+) -> (Vec<FrontmatterIssue>, Vec<FrontmatterGlob>) {
+    let label = kind.label();
+    let fields = match parse_frontmatter(content) {
+        Ok(fields) => fields.unwrap_or_default(),
+        Err(err) => {
+            let advice = format!("Malformed {label} frontmatter: {}", err.message);
+            return (vec![FrontmatterIssue { line: err.line, advice }], Vec::new());
+        }
+    };
+
+    let mut issues = Vec::new();
+    let mut globs = Vec::new();
+
+    for key in kind.required_keys() {
+        if !fields.iter().any(|f| f.key == *key) {
+            let advice = format!(
+                "{label} frontmatter requires `{key}`. Add `{key}:` between the `---` delimiters."
+            );
+            issues.push(FrontmatterIssue { line: 1, advice });
+        }
+    }
+
+    for field in &fields {
+        let allowed = kind.allowed_keys();
+        if !allowed.contains(&field.key.as_str()) {
+            let advice = format!(
+                "Unknown {label} frontmatter key `{}` (expected one of: {}).",
+                field.key,
+                allowed.join(", ")
+            );
+            issues.push(FrontmatterIssue { line: field.line, advice });
+            continue;
+        }
+        if field.key == "applyTo" {
+            for pattern in split_globs(&field.value) {
+                match Glob::new(&pattern) {
+                    Ok(_) => globs.push(FrontmatterGlob { pattern, line: field.line }),
+                    Err(err) => {
+                        let advice =
+                            format!("Invalid glob `{pattern}` in `applyTo`: {}", err.kind());
+                        issues.push(FrontmatterIssue { line: field.line, advice });
+                    }
+                }
+            }
+        }
+    }
+
+    (issues, globs)
+}
+
+/// Check frontmatter in Claude and Copilot agent files, and that `applyTo`
+/// globs match files in the project.
+pub(super) fn check_frontmatter(
+    ctx: &CheckContext,
+    detected: &[DetectedFile],
+    violations: &mut Vec<Violation>,
+) {
+    // Walked on first use: checked files exclude cached files on warm runs
+    let mut project_files: Option<Vec<PathBuf>> = None;
+
+    for file in detected {
+        let rel_path = file.path.strip_prefix(ctx.root).unwrap_or(&file.path);
+        let Some(kind) = FrontmatterKind::for_path(rel_path) else {
+            continue;
+        };
+        let Ok(content) = std::fs::read_to_string(&file.path) else {
+            continue;
+        };
+
+        let (issues, globs) = validate_frontmatter(kind, &content);
+        for issue in issues {
+            violations.push(Violation::file(
+                rel_path,
+                issue.line,
+                "invalid_frontmatter",
+                issue.advice,
+            ));
+        }
+        if globs.is_empty() {
+            continue;
+        }
+
+        let files = project_files.get_or_insert_with(|| {
+            let walker = FileWalker::from_exclude_config(&ctx.config.project.exclude);
+            let (files, _) = walker.walk_collect(ctx.root);
+            files
+                .into_iter()
+                .map(|f| f.path.strip_prefix(ctx.root).unwrap_or(&f.path).to_path_buf())
+                .collect()
+        });
+        for glob in globs {
+            let Ok(matcher) = Glob::new(&glob.pattern).map(|g| g.compile_matcher()) else {
+                continue;
+            };
+            if files.iter().any(|f| matcher.is_match(f)) {
+                continue;
+            }
+            let advice = format!(
+                "`{}` matches no files, so these {} never load. Update the pattern or remove the file.",
+                glob.pattern,
+                kind.label()
+            );
+            violations.push(
+                Violation::file(rel_path, glob.line, "unmatched_glob", advice)
+                    .with_pattern(glob.pattern),
+            );
+        }
+    }
+}
+
+/// Glob patterns in an `applyTo` value: a comma-separated string or array.
+pub fn split_globs(value: &str) -> Vec<String> {
+    parse_globs(value)
+        .iter()
+        .flat_map(|globs| globs.split(','))
+        .map(|glob| unquote(glob.trim()))
+        .filter(|glob| !glob.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "frontmatter_tests.rs"]
+mod tests;
@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+
+// =============================================================================
+// KINDS
+// =============================================================================
+
+#[test]
+fn kind_for_path() {
+    let kind = |p: &str| FrontmatterKind::for_path(Path::new(p));
+
+    assert_eq!(kind(".claude/commands/review.md"), Some(FrontmatterKind::ClaudeCommand));
+    assert_eq!(kind(".claude/agents/tester.md"), Some(FrontmatterKind::ClaudeAgent));
+    assert_eq!(kind(".claude/skills/deploy/SKILL.md"), Some(FrontmatterKind::ClaudeSkill));
+    assert_eq!(
+        kind(".github/instructions/ts.instructions.md"),
+        Some(FrontmatterKind::CopilotInstructions)
+    );
+    assert_eq!(kind(".github/copilot-instructions.md"), None);
+    assert_eq!(kind("CLAUDE.md"), None);
+}
+
+// =============================================================================
+// PARSING
+// =============================================================================
+
+#[test]
+fn parses_top_level_keys_with_lines() {
+    let content = "---\nname: tester\ntools:\n  - Read\n  - Grep\ndescription: \"Runs tests\"\n---\n\nBody.\n";
+    let fields = parse_frontmatter(content).unwrap().unwrap();
+
+    let keys: Vec<(&str, u32)> = fields.iter().map(|f| (f.key.as_str(), f.line)).collect();
+    assert_eq!(keys, vec![("name", 2), ("tools", 3), ("description", 6)]);
+    assert_eq!(fields[2].value, "\"Runs tests\"");
+}
+
+#[test]
+fn no_frontmatter_parses_to_none() {
+    assert_eq!(parse_frontmatter("# Review\n\nReview the diff.\n"), Ok(None));
+}
+
+#[test]
+fn unterminated_frontmatter_is_an_error() {
+    let err = parse_frontmatter("---\nname: tester\n").unwrap_err();
+    assert_eq!(err.line, 1);
+    assert!(err.message.contains("unterminated"));
+}
+
+#[test]
+fn line_without_colon_is_an_error() {
+    let err = parse_frontmatter("---\nname tester\n---\n").unwrap_err();
+    assert_eq!(err.line, 2);
+}
+
+// =============================================================================
+// VALIDATION
+// =============================================================================
+
+#[test]
+fn valid_subagent_has_no_issues() {
+    let content = "---\nname: tester\ndescription: Runs tests\nmodel: sonnet\n---\n";
+    let (issues, globs) = validate_frontmatter(FrontmatterKind::ClaudeAgent, content);
+
+    assert!(issues.is_empty(), "{issues:?}");
+    assert!(globs.is_empty());
+}
+
+#[test]
+fn missing_required_keys_are_reported() {
+    let (issues, _) = validate_frontmatter(FrontmatterKind::ClaudeSkill, "# Skill\n");
+
+    assert_eq!(issues.len(), 2);
+    assert!(issues[0].advice.contains("requires `name`"));
+    assert!(issues[1].advice.contains("requires `description`"));
+}
+
+#[test]
+fn command_frontmatter_is_optional() {
+    let (issues, _) = validate_frontmatter(FrontmatterKind::ClaudeCommand, "Review the diff.\n");
+
+    assert!(issues.is_empty());
+}
+
+#[test]
+fn unknown_keys_are_reported_at_their_line() {
+    let content = "---\ndescription: Review\nallowed_tools: Bash\n---\n";
+    let (issues, _) = validate_frontmatter(FrontmatterKind::ClaudeCommand, content);
+
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].line, 3);
+    assert!(issues[0].advice.contains("`allowed_tools`"));
+}
+
+#[test]
+fn apply_to_globs_are_returned() {
+    let content = "---\napplyTo: \"**/*.ts,**/*.tsx\"\n---\n";
+    let (issues, globs) = validate_frontmatter(FrontmatterKind::CopilotInstructions, content);
+
+    assert!(issues.is_empty());
+    let patterns: Vec<&str> = globs.iter().map(|g| g.pattern.as_str()).collect();
+    assert_eq!(patterns, vec!["**/*.ts", "**/*.tsx"]);
+    assert_eq!(globs[0].line, 2);
+}
+
+#[test]
+fn invalid_apply_to_glob_is_reported() {
+    let content = "---\napplyTo: \"src/[abc\"\n---\n";
+    let (issues, globs) = validate_frontmatter(FrontmatterKind::CopilotInstructions, content);
+
+    assert_eq!(issues.len(), 1);
+    assert!(issues[0].advice.contains("Invalid glob"));
+    assert!(globs.is_empty());
+}
+
+#[test]
+fn split_globs_accepts_strings_and_arrays() {
+    assert_eq!(split_globs("src/**"), vec!["src/**"]);
+    assert_eq!(split_globs("\"a/**, b/**\""), vec!["a/**", "b/**"]);
+    assert_eq!(split_globs("[\"a/**\", \"b/**\"]"), vec!["a/**", "b/**"]);
+}
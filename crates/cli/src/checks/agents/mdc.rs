@@ -153,7 +153,7 @@ pub fn classify_scope(rule: &MdcRule) -> RuleScope {
 }
 
 /// Remove surrounding quotes from a string value.
-fn unquote(s: &str) -> String {
+pub(super) fn unquote(s: &str) -> String {
     if (s.starts_with('"') && s.ends_with('"')) || (s.starts_with('\'') && s.ends_with('\'')) {
         s[1..s.len() - 1].to_string()
     } else {
@@ -167,7 +167,7 @@ fn unquote(s: &str) -> String {
 /// - `"src/api/**"` (single quoted string)
 /// - `src/api/**` (unquoted string)
 /// - `["src/**", "lib/**"]` (YAML array)
-fn parse_globs(value: &str) -> Vec<String> {
+pub(super) fn parse_globs(value: &str) -> Vec<String> {
     let trimmed = value.trim();
 
     if trimmed.starts_with('[') && trimmed.ends_with(']') {
@@ -7,16 +7,23 @@
 //! This phase implements:
 //! - File detection at root, package, and module scopes
 //! - Required/optional/forbid file validation
+//! - Claude, Copilot, and Gemini files alongside CLAUDE.md and Cursor rules
 //! - Basic metrics output
 
 pub mod config;
 pub mod content;
-mod detection;
+pub mod context;
+pub mod detection;
+pub mod frontmatter;
 pub mod mdc;
 mod reconcile;
+mod references;
 pub mod sections;
 mod sync;
 
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
 use serde_json::json;
 
 use crate::check::{Check, CheckContext, CheckResult, Violation};
@@ -27,6 +34,7 @@ use config::ContentRule;
 use content::{
     check_line_count, check_token_count, detect_box_diagrams, detect_mermaid_blocks, detect_tables,
 };
+use context::ContextSources;
 use detection::{DetectedFile, Scope, detect_agent_files, file_exists_at_root};
 use sections::validate_sections;
 use sync::{DiffType, compare_files};
@@ -79,6 +87,15 @@ impl Check for AgentsCheck {
         // Check content rules (tables, diagrams, size limits)
         check_content(ctx, config, &detected, &mut violations);
 
+        // Check Claude and Copilot frontmatter
+        frontmatter::check_frontmatter(ctx, &detected, &mut violations);
+
+        // Check that referenced paths and declarations still exist
+        references::check_references(ctx, config, &detected, &mut violations);
+
+        // Check combined size of the agent files loaded in each directory
+        let max_context_tokens = check_context_budget(ctx, config, &mut violations);
+
         // Check cursor rule reconciliation
         // Always call - it checks sync internally and returns early if disabled
         check_cursor_reconciliation(ctx, config, &mut violations, &mut fixes);
@@ -92,11 +109,14 @@ impl Check for AgentsCheck {
         // Update in_sync metric based on whether we fixed things
         let final_in_sync = in_sync || !fixes.is_empty();
 
-        let metrics = json!({
+        let mut metrics = json!({
             "files_found": files_found,
             "files_missing": files_missing,
             "in_sync": final_in_sync,
         });
+        if let Some(tokens) = max_context_tokens {
+            metrics["max_context_tokens"] = json!(tokens);
+        }
 
         // Determine result based on violations and fixes
         let result = if violations.is_empty() {
@@ -283,9 +303,10 @@ fn check_sync(
     };
 
     // Find source file in detected
-    let source_file = root_files
-        .iter()
-        .find(|f| f.path.file_name().map(|n| n.to_string_lossy() == source_name).unwrap_or(false));
+    let source_file = root_files.iter().find(|f| {
+        relative_path(ctx.root, f) == source_name
+            || f.path.file_name().map(|n| n.to_string_lossy() == source_name).unwrap_or(false)
+    });
 
     let Some(source_file) = source_file else {
         return true; // Source not present, nothing to sync
@@ -311,11 +332,8 @@ fn check_sync(
         let comparison = compare_files(&source_content, &target_content);
 
         if !comparison.in_sync {
-            let target_name = target_file
-                .path
-                .file_name()
-                .map(|n| n.to_string_lossy().to_string())
-                .unwrap_or_else(|| target_file.path.display().to_string());
+            // Relative path: project-wide files may live outside the root directory
+            let target_name = relative_path(ctx.root, target_file);
 
             // If fix mode is enabled, sync the target file from source
             if ctx.fix {
@@ -547,7 +565,7 @@ fn check_content(
         }
 
         if let Some(limit) = max_tokens
-            && let Some(violation) = check_token_count(&content, limit)
+            && let Some(violation) = check_token_count(&content, limit, config.tokenizer)
         {
             violations.push(
                 Violation::file_only(
@@ -612,6 +630,67 @@ fn check_cursor_reconciliation(
     }
 }
 
+/// Check `effective_max_tokens` against the agent files loaded together in
+/// each source directory. Returns the largest context, if enabled.
+///
+/// Directories that load the same files share one violation, reported at the
+/// first of them.
+fn check_context_budget(
+    ctx: &CheckContext,
+    config: &AgentsConfig,
+    violations: &mut Vec<Violation>,
+) -> Option<usize> {
+    let limit = config.effective_max_tokens?;
+
+    let dirs = context::source_directories(ctx.root, ctx.config);
+    let sources = ContextSources::collect(
+        ctx.root,
+        &ctx.config.project.packages,
+        &config.files,
+        config.tokenizer,
+        dirs.keys().map(PathBuf::as_path),
+    );
+
+    let mut max_tokens = 0;
+    // Contributing files -> (first directory, tokens, other directory count)
+    let mut exceeded: BTreeMap<Vec<PathBuf>, (&Path, usize, usize)> = BTreeMap::new();
+    for (dir, files) in &dirs {
+        let loaded = sources.assemble(dir, files);
+        let tokens: usize = loaded.iter().map(|f| f.tokens).sum();
+        max_tokens = max_tokens.max(tokens);
+        if tokens <= limit {
+            continue;
+        }
+        let key = loaded.iter().map(|f| f.path.clone()).collect();
+        exceeded.entry(key).and_modify(|e| e.2 += 1).or_insert((dir, tokens, 0));
+    }
+
+    let mut found: Vec<_> = exceeded.into_iter().collect();
+    found.sort_by(|a, b| a.1.0.cmp(b.1.0));
+    for (files, (dir, tokens, others)) in found {
+        let dir = if dir.as_os_str().is_empty() { Path::new(".") } else { dir };
+        let also = match others {
+            0 => String::new(),
+            1 => " (and 1 other directory)".to_string(),
+            n => format!(" (and {n} other directories)"),
+        };
+        let advice = format!(
+            "Agents working here{also} load {} agent files together. \
+             Trim the largest, or move guidance into narrower directory or rule files.\n\
+             Run `quench agents context {}` to see the assembled context.",
+            files.len(),
+            dir.display(),
+        );
+        violations.push(
+            Violation::file_only(dir, "context_budget_exceeded", advice)
+                .with_threshold(tokens as i64, limit as i64)
+                .with_files(files),
+        );
+    }
+
+    Some(max_tokens)
+}
+
 /// Get effective size limits for a scope, with inheritance.
 fn get_scope_limits(config: &AgentsConfig, scope: &Scope) -> (Option<usize>, Option<usize>) {
     let scope_config = match scope {
@@ -0,0 +1,177 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Stale reference detection for agent files.
+//!
+//! Agent files point agents at paths (`src/walker.rs`) and declarations
+//! (`fn detect_agent_files`). When code moves or is renamed, these
+//! references go stale silently and send agents looking for code that no
+//! longer exists.
+
+mod symbols;
+
+use std::collections::HashSet;
+use std::ffi::OsStr;
+use std::path::Path;
+
+use super::AgentsConfig;
+use super::context::is_source_file;
+use super::detection::DetectedFile;
+use crate::adapter::{AdapterRegistry, build_glob_set};
+use crate::check::{CheckContext, Violation};
+use crate::checks::docs::PathCache;
+use crate::config::CheckLevel;
+use crate::walker::FileWalker;
+use symbols::SymbolIndex;
+
+/// Non-code extensions that still mark a span as a file path.
+const PATH_EXTENSIONS: &[&str] =
+    &["md", "mdc", "toml", "json", "yaml", "yml", "txt", "lock", "cfg", "ini", "xml", "html"];
+
+/// Check backticked paths and declarations in agent files.
+pub(super) fn check_references(
+    ctx: &CheckContext,
+    config: &AgentsConfig,
+    detected: &[DetectedFile],
+    violations: &mut Vec<Violation>,
+) {
+    if config.references.check == CheckLevel::Off || detected.is_empty() {
+        return;
+    }
+    let ignore = build_glob_set(&config.references.ignore);
+
+    // Walk the project: checked files exclude cached files on warm runs
+    let (files, _) =
+        FileWalker::from_exclude_config(&ctx.config.project.exclude).walk_collect(ctx.root);
+    let cache = PathCache::new();
+    cache.populate(&files.iter().collect::<Vec<_>>());
+    let file_names: HashSet<&OsStr> = files.iter().filter_map(|f| f.path.file_name()).collect();
+
+    let registry = AdapterRegistry::for_project_with_config(ctx.root, ctx.config);
+    let sources = files
+        .iter()
+        .map(|f| f.path.strip_prefix(ctx.root).unwrap_or(&f.path))
+        .filter(|rel| is_source_file(rel, &registry))
+        .map(|rel| (rel.to_path_buf(), registry.adapter_for(rel).declaration_keywords()))
+        .collect();
+    let index = SymbolIndex::new(ctx.root, sources);
+
+    for file in detected {
+        let Ok(content) = std::fs::read_to_string(&file.path) else {
+            continue;
+        };
+        let rel_path = file.path.strip_prefix(ctx.root).unwrap_or(&file.path);
+        let agent_dir = file.path.parent().unwrap_or(ctx.root);
+
+        for (line, span) in inline_code_spans(&content) {
+            if let Some(path) = as_path(span) {
+                if ignore.is_match(path) {
+                    continue;
+                }
+                let found = [ctx.root, agent_dir].iter().any(|base| cache.exists(&base.join(path)));
+                if found || !is_project_path(path, ctx.root, agent_dir, &cache, &file_names) {
+                    continue;
+                }
+                let advice = format!(
+                    "`{path}` does not exist. Update or remove the reference.\n\
+                     Paths resolve from the project root or the agent file's directory."
+                );
+                violations.push(
+                    Violation::file(rel_path, line, "stale_reference", advice).with_path(path),
+                );
+            } else if let Some(name) = index.declared_name(span) {
+                if ignore.is_match(name) || index.contains(name) {
+                    continue;
+                }
+                let advice = format!(
+                    "No declaration of `{name}` found in the project's source files. \
+                     Update or remove the reference."
+                );
+                violations.push(
+                    Violation::file(rel_path, line, "stale_reference", advice).with_path(span),
+                );
+            }
+        }
+    }
+}
+
+/// Single-backtick code spans outside fenced code blocks, with 1-indexed
+/// line numbers.
+fn inline_code_spans(content: &str) -> Vec<(u32, &str)> {
+    let mut spans = Vec::new();
+    let mut in_fenced_block = false;
+
+    for (idx, line) in content.lines().enumerate() {
+        if line.trim_start().starts_with("```") {
+            in_fenced_block = !in_fenced_block;
+            continue;
+        }
+        if in_fenced_block {
+            continue;
+        }
+
+        // Odd segments between backticks are code spans
+        let segments: Vec<&str> = line.split('`').collect();
+        // An unmatched backtick leaves a final segment that isn't a span
+        let closed = segments.len() - (segments.len() + 1) % 2;
+        for (i, segment) in segments[..closed].iter().enumerate() {
+            if i % 2 == 1 && !segment.trim().is_empty() {
+                spans.push((idx as u32 + 1, segment.trim()));
+            }
+        }
+    }
+    spans
+}
+
+/// The path in `span` if it looks like a file or directory path, without a
+/// `./` prefix or `:line` suffix.
+fn as_path(span: &str) -> Option<&str> {
+    let rejected = span.contains(char::is_whitespace)
+        || span.contains("://")
+        || span.contains("::")
+        || span.starts_with(['/', '~', '$', '-'])
+        || span.contains(['*', '?', '{', '}', '[', ']', '<', '>', '|', '=', '@', '\'', '"']);
+    if rejected {
+        return None;
+    }
+
+    // `src/main.rs:42` or `src/main.rs:42:7`
+    let path = match span.split_once(':') {
+        Some((path, pos)) if pos.chars().all(|c| c.is_ascii_digit() || c == ':') => path,
+        Some(_) => return None,
+        None => span,
+    };
+    let path = path.strip_prefix("./").unwrap_or(path);
+    if !path.contains(char::is_alphabetic) {
+        return None;
+    }
+
+    (path.contains('/') || has_path_extension(path)).then_some(path)
+}
+
+fn has_path_extension(path: &str) -> bool {
+    crate::cloc::file_extension(Path::new(path))
+        .is_some_and(|ext| crate::cloc::is_text_extension(&ext) || PATH_EXTENSIONS.contains(&&*ext))
+}
+
+/// Whether a missing `path` still refers to the project, as opposed to a
+/// branch name (`origin/main`) or a file mentioned by name only.
+fn is_project_path(
+    path: &str,
+    root: &Path,
+    agent_dir: &Path,
+    cache: &PathCache,
+    file_names: &HashSet<&OsStr>,
+) -> bool {
+    let Some((first, _)) = path.split_once('/') else {
+        // A bare file name may live anywhere in the project
+        return !Path::new(path).file_name().is_some_and(|name| file_names.contains(name));
+    };
+    first == ".."
+        || has_path_extension(path)
+        || [root, agent_dir].iter().any(|base| cache.exists(&base.join(first)))
+}
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+
+// =============================================================================
+// CODE SPANS
+// =============================================================================
+
+#[test]
+fn extracts_inline_code_spans_with_lines() {
+    let content = "# Title\n\nSee `src/lib.rs` and `fn run`.\n";
+
+    assert_eq!(inline_code_spans(content), vec![(3, "src/lib.rs"), (3, "fn run")]);
+}
+
+#[test]
+fn skips_fenced_code_blocks() {
+    let content = "```bash\necho `date`\n```\n`Cargo.toml`\n";
+
+    assert_eq!(inline_code_spans(content), vec![(4, "Cargo.toml")]);
+}
+
+#[test]
+fn ignores_unmatched_backtick() {
+    assert_eq!(inline_code_spans("`a` and `b"), vec![(1, "a")]);
+}
+
+// =============================================================================
+// PATHS
+// =============================================================================
+
+#[test]
+fn recognizes_paths() {
+    assert_eq!(as_path("src/walker.rs"), Some("src/walker.rs"));
+    assert_eq!(as_path("./docs/"), Some("docs/"));
+    assert_eq!(as_path("Cargo.toml"), Some("Cargo.toml"));
+    assert_eq!(as_path("src/main.rs:42"), Some("src/main.rs"));
+    assert_eq!(as_path("../README.md"), Some("../README.md"));
+}
+
+#[test]
+fn rejects_non_paths() {
+    for span in [
+        "cargo test",
+        "https://example.com/a.md",
+        "std::fs",
+        "/etc/hosts",
+        "~/.config",
+        "**/*.rs",
+        "--features",
+        "self.config",
+        "key=value",
+        "1.0",
+        "TODO",
+    ] {
+        assert_eq!(as_path(span), None, "{span}");
+    }
+}
+
+#[test]
+fn missing_path_under_unknown_directory_is_not_a_project_path() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir(dir.path().join("src")).unwrap();
+    let cache = PathCache::new();
+    let names = HashSet::new();
+    let is_project = |path| is_project_path(path, dir.path(), dir.path(), &cache, &names);
+
+    assert!(is_project("src/old"));
+    assert!(is_project("docs/guide.md"));
+    assert!(is_project("../shared"));
+    assert!(!is_project("origin/main"));
+    assert!(!is_project("feat/"));
+}
+
+#[test]
+fn bare_file_name_resolves_anywhere() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = PathCache::new();
+    let names: HashSet<&OsStr> = [OsStr::new("walker.rs")].into_iter().collect();
+    let is_project = |path| is_project_path(path, dir.path(), dir.path(), &cache, &names);
+
+    assert!(!is_project("walker.rs"));
+    assert!(is_project("runner.rs"));
+}
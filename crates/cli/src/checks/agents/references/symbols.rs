@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Lightweight symbol index for declarations referenced from agent files.
+//!
+//! Each language adapter names its declaration keywords (`fn`, `struct`,
+//! `class`, `def`, `func`, ...). The index is a line-based scan, not a
+//! parser: names mentioned after a keyword in comments or strings are
+//! indexed too, which only makes the reference check more lenient.
+
+use std::cell::OnceCell;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+
+/// Declared names in the project's source files, read on first lookup.
+pub(super) struct SymbolIndex<'a> {
+    root: &'a Path,
+    /// Source files (relative to the root) with their declaration keywords.
+    sources: Vec<(PathBuf, &'static [&'static str])>,
+    /// Matches a whole span that declares a name with an indexed keyword.
+    declaration: Option<Regex>,
+    names: OnceCell<HashSet<String>>,
+}
+
+impl<'a> SymbolIndex<'a> {
+    /// Index `sources`, skipping languages without declaration keywords.
+    pub(super) fn new(root: &'a Path, sources: Vec<(PathBuf, &'static [&'static str])>) -> Self {
+        let sources: Vec<_> = sources.into_iter().filter(|(_, kw)| !kw.is_empty()).collect();
+        let keywords: BTreeSet<&str> =
+            sources.iter().flat_map(|(_, kw)| kw.iter().copied()).collect();
+        let keywords: Vec<&str> = keywords.into_iter().collect();
+
+        // Modifiers may precede the keyword (`pub(crate) fn`, `export class`)
+        let declaration = (!keywords.is_empty())
+            .then(|| declaration_regex(&keywords, r"^(?:[\w()]+\s+)*?"))
+            .flatten();
+
+        Self { root, sources, declaration, names: OnceCell::new() }
+    }
+
+    /// Name declared by a span such as `fn name` or `class Name`, when the
+    /// keyword belongs to one of the project's languages.
+    pub(super) fn declared_name<'s>(&self, span: &'s str) -> Option<&'s str> {
+        let caps = self.declaration.as_ref()?.captures(span)?;
+        caps.get(1).map(|m| m.as_str())
+    }
+
+    /// Whether any source file declares `name`.
+    pub(super) fn contains(&self, name: &str) -> bool {
+        self.names.get_or_init(|| self.scan()).contains(name)
+    }
+
+    fn scan(&self) -> HashSet<String> {
+        let mut patterns: HashMap<&[&str], Option<Regex>> = HashMap::new();
+        let mut names = HashSet::new();
+
+        for (rel, keywords) in &self.sources {
+            let pattern =
+                patterns.entry(keywords).or_insert_with(|| declaration_regex(keywords, r"\b"));
+            let Some(pattern) = pattern else {
+                continue;
+            };
+            let Ok(content) = std::fs::read_to_string(self.root.join(rel)) else {
+                continue;
+            };
+            for caps in pattern.captures_iter(&content) {
+                if let Some(name) = caps.get(1) {
+                    names.insert(name.as_str().to_string());
+                }
+            }
+        }
+        names
+    }
+}
+
+/// Regex capturing the name after one of `keywords`.
+///
+/// Skips a Go receiver (`func (r *Repo) Name`) and a Ruby `self.` prefix.
+fn declaration_regex(keywords: &[&str], prefix: &str) -> Option<Regex> {
+    let keywords: Vec<String> = keywords.iter().map(|kw| regex::escape(kw)).collect();
+    let pattern = format!(
+        r"{prefix}(?:{})\s+(?:\([^)]*\)\s*)?(?:self\.)?([A-Za-z_][A-Za-z0-9_]*)",
+        keywords.join("|")
+    );
+    Regex::new(&pattern).ok()
+}
+
+#[cfg(test)]
+#[path = "symbols_tests.rs"]
+mod tests;
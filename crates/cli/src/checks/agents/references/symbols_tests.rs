@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+
+const RUST: &[&str] = &["fn", "struct", "enum", "trait", "type", "mod"];
+const GO: &[&str] = &["func", "type"];
+
+fn index_of<'a>(
+    root: &'a Path,
+    files: &[(&str, &'static [&'static str], &str)],
+) -> SymbolIndex<'a> {
+    let mut sources = Vec::new();
+    for (rel, keywords, content) in files {
+        std::fs::write(root.join(rel), content).unwrap();
+        sources.push((PathBuf::from(rel), *keywords));
+    }
+    SymbolIndex::new(root, sources)
+}
+
+#[test]
+fn declared_name_parses_declaration_spans() {
+    let dir = tempfile::tempdir().unwrap();
+    let index = index_of(dir.path(), &[("lib.rs", RUST, "")]);
+
+    assert_eq!(index.declared_name("fn detect_agent_files"), Some("detect_agent_files"));
+    assert_eq!(index.declared_name("pub(crate) struct Walker<T>"), Some("Walker"));
+    assert_eq!(index.declared_name("fn run()"), Some("run"));
+}
+
+#[test]
+fn declared_name_ignores_other_languages_and_prose() {
+    let dir = tempfile::tempdir().unwrap();
+    let index = index_of(dir.path(), &[("lib.rs", RUST, "")]);
+
+    assert_eq!(index.declared_name("def parse"), None);
+    assert_eq!(index.declared_name("cargo test"), None);
+    assert_eq!(index.declared_name("type = \"rust\""), None);
+}
+
+#[test]
+fn declared_name_skips_go_receiver() {
+    let dir = tempfile::tempdir().unwrap();
+    let index = index_of(dir.path(), &[("main.go", GO, "")]);
+
+    assert_eq!(index.declared_name("func (r *Repo) Save"), Some("Save"));
+}
+
+#[test]
+fn contains_names_declared_in_sources() {
+    let dir = tempfile::tempdir().unwrap();
+    let index = index_of(
+        dir.path(),
+        &[
+            ("lib.rs", RUST, "pub struct Walker;\n\npub(crate) fn walk_collect() {}\n"),
+            ("main.go", GO, "func (r *Repo) Save() error {\n"),
+        ],
+    );
+
+    assert!(index.contains("Walker"));
+    assert!(index.contains("walk_collect"));
+    assert!(index.contains("Save"));
+    assert!(!index.contains("Repo"));
+}
+
+#[test]
+fn languages_without_keywords_are_not_indexed() {
+    let dir = tempfile::tempdir().unwrap();
+    let index = index_of(dir.path(), &[("notes.txt", &[], "fn hidden")]);
+
+    assert_eq!(index.declared_name("fn hidden"), None);
+}
@@ -119,6 +119,7 @@ impl Check for BuildCheck {
                         scope: None,
                         expected: None,
                         found: None,
+                        files: None,
                     });
                 }
             }
@@ -154,6 +155,7 @@ impl Check for BuildCheck {
                         scope: None,
                         expected: None,
                         found: None,
+                        files: None,
                     });
                 }
             }
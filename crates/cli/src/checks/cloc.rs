@@ -18,6 +18,7 @@ use crate::adapter::{AdapterRegistry, FileKind, RustAdapter};
 use crate::check::{Check, CheckContext, CheckResult, Violation};
 use crate::config::{CfgTestSplitMode, CheckLevel, ClocConfig, LineMetric};
 use crate::file_reader::FileContent;
+use crate::tokens::Tokenizer;
 
 /// Parameters for creating a line-count violation.
 struct LineViolationInfo {
@@ -114,12 +115,12 @@ impl Check for ClocCheck {
         let mut package_metrics: HashMap<String, PackageMetrics> = HashMap::new();
 
         for file in ctx.files {
-            // Skip non-text files
-            if !is_text_file(&file.path) {
+            // Skip non-text files (declarative adapters declare their own)
+            if !is_text_file(&file.path) && registry.declarative_for(&file.path).is_none() {
                 continue;
             }
 
-            match count_file_metrics(&file.path) {
+            match count_file_metrics(&file.path, cloc_config.tokenizer) {
                 Ok(metrics) => {
                     let total_lines = metrics.lines;
                     let nonblank_lines = metrics.nonblank_lines;
@@ -510,9 +511,7 @@ fn create_inline_cfg_test_violation(
 /// Check if a file is a source code file (for LOC counting).
 /// Delegates to the shared `cloc` module's text extension check.
 fn is_text_file(path: &Path) -> bool {
-    let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("").to_lowercase();
-
-    crate::cloc::is_text_extension(&ext)
+    crate::cloc::file_extension(path).is_some_and(|ext| crate::cloc::is_text_extension(&ext))
 }
 
 /// Metrics computed from a single file read.
@@ -527,8 +526,8 @@ struct FileMetrics {
 /// Count lines and tokens from a single file read.
 /// - `lines`: total line count (matches `wc -l`)
 /// - `nonblank_lines`: lines with at least one non-whitespace character
-/// - `tokens`: chars/4 approximation (standard LLM heuristic)
-fn count_file_metrics(path: &Path) -> std::io::Result<FileMetrics> {
+/// - `tokens`: counted with the configured tokenizer
+fn count_file_metrics(path: &Path, tokenizer: Tokenizer) -> std::io::Result<FileMetrics> {
     let content = std::fs::read(path)?;
     // Try UTF-8, fall back to lossy conversion for encoding issues
     let text = String::from_utf8(content)
@@ -536,7 +535,7 @@ fn count_file_metrics(path: &Path) -> std::io::Result<FileMetrics> {
 
     let lines = text.lines().count();
     let nonblank_lines = text.lines().filter(|l| !l.trim().is_empty()).count();
-    let tokens = text.chars().count() / 4;
+    let tokens = tokenizer.count(&text);
 
     Ok(FileMetrics { lines, nonblank_lines, tokens })
 }
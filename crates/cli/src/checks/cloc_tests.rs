@@ -72,7 +72,7 @@ fn cloc_check_default_enabled() {
 )]
 fn file_metrics_nonblank_lines(content: &str, expected: usize) {
     let file = temp_file_with_content(content);
-    let metrics = count_file_metrics(file.path()).unwrap();
+    let metrics = count_file_metrics(file.path(), Tokenizer::Approx).unwrap();
     assert_eq!(
         metrics.nonblank_lines, expected,
         "content {:?} should have {} nonblank lines",
@@ -84,7 +84,7 @@ fn file_metrics_nonblank_lines(content: &str, expected: usize) {
 fn file_metrics_empty_file_tokens() {
     // Separate test for empty file also having 0 tokens
     let file = temp_file_with_content("");
-    let metrics = count_file_metrics(file.path()).unwrap();
+    let metrics = count_file_metrics(file.path(), Tokenizer::Approx).unwrap();
     assert_eq!(metrics.tokens, 0);
 }
 
@@ -155,7 +155,7 @@ fn exclude_matcher_exclusion(pattern: &str, path: &str, expected: bool) {
 )]
 fn file_metrics_tokens(content: &str, expected: usize) {
     let file = temp_file_with_content(content);
-    let metrics = count_file_metrics(file.path()).unwrap();
+    let metrics = count_file_metrics(file.path(), Tokenizer::Approx).unwrap();
     assert_eq!(metrics.tokens, expected, "content {:?} should have {} tokens", content, expected);
 }
 
@@ -163,7 +163,7 @@ fn file_metrics_tokens(content: &str, expected: usize) {
 fn file_metrics_tokens_exact_math() {
     // Keep separate: requires String::repeat which can't be a &str literal
     let file = temp_file_with_content(&"a".repeat(100));
-    let metrics = count_file_metrics(file.path()).unwrap();
+    let metrics = count_file_metrics(file.path(), Tokenizer::Approx).unwrap();
     assert_eq!(metrics.tokens, 25); // 100 / 4 = 25
 }
 
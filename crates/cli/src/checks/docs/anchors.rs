@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Heading anchors for markdown link fragments.
+//!
+//! Generates GitHub-compatible heading slugs so that `guide.md#section` and
+//! same-file `#section` links can be validated.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+/// Pre-compiled regex for explicit HTML anchors: `<a id="x">` or `<a name="x">`.
+#[allow(clippy::expect_used)]
+static HTML_ANCHOR_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"<a\s[^>]*?\b(?:id|name)\s*=\s*["']([^"']+)["']"#).expect("valid regex pattern")
+});
+
+/// Pre-compiled regex for inline links and images in heading text.
+#[allow(clippy::expect_used)]
+static INLINE_LINK_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"!?\[([^\]]*)\]\([^)]*\)").expect("valid regex pattern"));
+
+/// Collect all anchors a markdown document defines.
+///
+/// Includes ATX (`## Heading`) and setext (`Heading` over `---`) heading
+/// slugs, with duplicates getting `-1`, `-2`, ... suffixes like GitHub, and
+/// explicit `<a id>`/`<a name>` anchors. Skips fenced code blocks.
+pub(super) fn extract_anchors(content: &str) -> HashSet<String> {
+    let mut anchors = HashSet::new();
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    let mut in_fenced_block = false;
+    // Lines of the current paragraph, which a setext underline turns into a heading
+    let mut paragraph: Vec<&str> = Vec::new();
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fenced_block = !in_fenced_block;
+            paragraph.clear();
+            continue;
+        }
+        if in_fenced_block {
+            continue;
+        }
+
+        for cap in HTML_ANCHOR_REGEX.captures_iter(line) {
+            anchors.insert(cap[1].to_string());
+        }
+
+        let heading = if let Some(heading) = heading_text(line) {
+            paragraph.clear();
+            heading.to_string()
+        } else if is_setext_underline(line) {
+            let text = paragraph.join(" ");
+            paragraph.clear();
+            if text.is_empty() {
+                continue;
+            }
+            text
+        } else {
+            if starts_paragraph(line) {
+                paragraph.push(trimmed);
+            } else {
+                paragraph.clear();
+            }
+            continue;
+        };
+
+        let slug = slugify(&heading);
+        if slug.is_empty() {
+            continue;
+        }
+        let count = seen.entry(slug.clone()).or_insert(0);
+        if *count == 0 {
+            anchors.insert(slug);
+        } else {
+            anchors.insert(format!("{slug}-{count}"));
+        }
+        *count += 1;
+    }
+    anchors
+}
+
+/// Check if a line is a setext heading underline (`===` or `---`).
+fn is_setext_underline(line: &str) -> bool {
+    let trimmed = line.trim_start_matches(' ');
+    if line.len() - trimmed.len() > 3 {
+        return false;
+    }
+    let underline = trimmed.trim_end();
+    !underline.is_empty()
+        && (underline.bytes().all(|b| b == b'=') || underline.bytes().all(|b| b == b'-'))
+}
+
+/// Check if a line can be paragraph text (and so part of a setext heading).
+///
+/// Blank lines, list items, block quotes, tables, HTML and indented code
+/// can't be underlined into a heading.
+fn starts_paragraph(line: &str) -> bool {
+    let trimmed = line.trim_start_matches(' ');
+    if trimmed.trim().is_empty() || line.len() - trimmed.len() > 3 {
+        return false;
+    }
+    let ordered = trimmed.trim_start_matches(|c: char| c.is_ascii_digit());
+    let is_list = ["- ", "* ", "+ "].iter().any(|m| trimmed.starts_with(m))
+        || (ordered.len() < trimmed.len()
+            && (ordered.starts_with(". ") || ordered.starts_with(") ")));
+    !is_list && !trimmed.starts_with(['>', '|', '<'])
+}
+
+/// Text of an ATX heading (`## Heading`), without the optional closing `#`s.
+fn heading_text(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start_matches(' ');
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+    let level = trimmed.bytes().take_while(|&b| b == b'#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    let rest = &trimmed[level..];
+    if !rest.is_empty() && !rest.starts_with([' ', '\t']) {
+        return None;
+    }
+    let rest = rest.trim();
+
+    // Closing sequence must be preceded by whitespace: `## Heading ##`
+    let without_closing = rest.trim_end_matches('#');
+    if without_closing.is_empty() || without_closing.ends_with([' ', '\t']) {
+        Some(without_closing.trim_end())
+    } else {
+        Some(rest)
+    }
+}
+
+/// GitHub-style anchor slug for heading text.
+///
+/// Lowercases, drops punctuation (keeping `-` and `_`), and turns spaces
+/// into hyphens. Links inside the heading contribute only their text.
+pub(super) fn slugify(heading: &str) -> String {
+    let text = INLINE_LINK_REGEX.replace_all(heading.trim(), "$1");
+    text.to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            '-' | '_' => Some(c),
+            c if c.is_alphanumeric() => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "anchors_tests.rs"]
+mod tests;
@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+fn sorted(anchors: HashSet<String>) -> Vec<String> {
+    let mut anchors: Vec<_> = anchors.into_iter().collect();
+    anchors.sort();
+    anchors
+}
+
+// =============================================================================
+// SLUG TESTS
+// =============================================================================
+
+#[test]
+fn slugifies_like_github() {
+    assert_eq!(slugify("Getting Started"), "getting-started");
+    assert_eq!(slugify("What's New?"), "whats-new");
+    assert_eq!(slugify("`quench check`"), "quench-check");
+    assert_eq!(slugify("Fast Mode: TOC Validation"), "fast-mode-toc-validation");
+    assert_eq!(slugify("snake_case and kebab-case"), "snake_case-and-kebab-case");
+    assert_eq!(slugify("Comparison to `agents` Check"), "comparison-to-agents-check");
+}
+
+#[test]
+fn slug_keeps_link_text_only() {
+    assert_eq!(slugify("See [the guide](guide.md)"), "see-the-guide");
+}
+
+#[test]
+fn slug_keeps_unicode_letters() {
+    assert_eq!(slugify("Café Ünïcode"), "café-ünïcode");
+}
+
+// =============================================================================
+// ANCHOR EXTRACTION TESTS
+// =============================================================================
+
+#[test]
+fn extracts_heading_anchors() {
+    let content = "# Title\n\nText\n\n## Sub Section ##\n\n###### Deep\n";
+    assert_eq!(sorted(extract_anchors(content)), ["deep", "sub-section", "title"]);
+}
+
+#[test]
+fn numbers_duplicate_headings() {
+    let content = "## Output\n\n## Output\n\n## Output\n";
+    assert_eq!(sorted(extract_anchors(content)), ["output", "output-1", "output-2"]);
+}
+
+#[test]
+fn extracts_setext_heading_anchors() {
+    let content = "Project Title\n=============\n\nSetup Steps\n-----------\n\nText\n";
+    assert_eq!(sorted(extract_anchors(content)), ["project-title", "setup-steps"]);
+}
+
+#[test]
+fn numbers_duplicate_atx_and_setext_headings_together() {
+    let content = "## Output\n\nOutput\n------\n";
+    assert_eq!(sorted(extract_anchors(content)), ["output", "output-1"]);
+}
+
+#[test]
+fn joins_multi_line_setext_headings() {
+    let content = "Getting\nStarted\n===\n";
+    assert_eq!(sorted(extract_anchors(content)), ["getting-started"]);
+}
+
+#[test]
+fn thematic_breaks_are_not_setext_headings() {
+    let content = "Text\n\n---\n\n- item\n---\n\n> quote\n---\n\n    code\n---\n";
+    assert!(extract_anchors(content).is_empty());
+}
+
+#[test]
+fn extracts_explicit_html_anchors() {
+    let content = "<a id=\"custom-anchor\"></a>\n\nText <a name='legacy'></a>\n";
+    assert_eq!(sorted(extract_anchors(content)), ["custom-anchor", "legacy"]);
+}
+
+#[test]
+fn skips_headings_in_fenced_code_blocks() {
+    let content = "# Real\n\n```bash\n# not a heading\n```\n\n~~~\n## Also not\n~~~\n";
+    assert_eq!(sorted(extract_anchors(content)), ["real"]);
+}
+
+#[test]
+fn ignores_non_headings() {
+    let content = "#hashtag\n    # indented code\n####### seven\n";
+    assert!(extract_anchors(content).is_empty());
+}
@@ -133,7 +133,7 @@ fn validate_size_limits(
 
     // Token limit
     if let Some(max_tokens) = config.max_tokens
-        && let Some(violation) = check_token_count(content, max_tokens)
+        && let Some(violation) = check_token_count(content, max_tokens, config.tokenizer)
     {
         violations.push(
             Violation::file_only(
@@ -3,11 +3,13 @@
 
 //! Markdown link validation.
 
+use std::collections::HashSet;
 use std::path::Path;
 use std::sync::LazyLock;
 
 use regex::Regex;
 
+use super::anchors;
 use crate::check::{CheckContext, Violation};
 
 /// Regex pattern string for markdown links: [text](url)
@@ -88,6 +90,21 @@ pub(super) fn strip_fragment(target: &str) -> &str {
     target.split('#').next().unwrap_or(target)
 }
 
+/// Fragment of a link target (after the first `#`), if non-empty.
+pub(super) fn fragment(target: &str) -> Option<&str> {
+    target.split_once('#').map(|(_, fragment)| fragment).filter(|f| !f.is_empty())
+}
+
+/// Check if a path is a markdown file (the only targets with heading anchors).
+pub fn is_markdown(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "md" || ext == "mdc")
+}
+
+/// Check if an anchor set contains a link fragment.
+fn has_anchor(anchors: &HashSet<String>, fragment: &str) -> bool {
+    anchors.contains(fragment) || anchors.contains(&fragment.to_lowercase())
+}
+
 /// Resolve a link target relative to the markdown file.
 pub(super) fn resolve_link(md_file: &Path, target: &str) -> std::path::PathBuf {
     let target = strip_fragment(target);
@@ -140,8 +157,18 @@ fn validate_file_links_cached(
     let mut violations = Vec::new();
     let links = extract_links(content);
     let abs_file = ctx.root.join(relative_path);
+    let mut own_anchors: Option<HashSet<String>> = None;
 
     for link in links {
+        // Same-file fragment links (#section)
+        if let Some(fragment) = link.target.strip_prefix('#') {
+            let anchors = own_anchors.get_or_insert_with(|| anchors::extract_anchors(content));
+            if !fragment.is_empty() && !has_anchor(anchors, fragment) {
+                violations.push(broken_anchor(relative_path, &link));
+            }
+            continue;
+        }
+
         // Skip external links
         if !is_local_link(&link.target) {
             continue;
@@ -159,11 +186,32 @@ fn validate_file_links_cached(
                 )
                 .with_target(strip_fragment(&link.target)),
             );
+            continue;
+        }
+
+        // Validate fragments against the headings of linked markdown files
+        if let Some(fragment) = fragment(&link.target)
+            && is_markdown(&resolved)
+            && let Some(anchors) = path_cache.anchors(&resolved)
+            && !has_anchor(&anchors, fragment)
+        {
+            violations.push(broken_anchor(relative_path, &link));
         }
     }
     violations
 }
 
+/// Violation for a link whose fragment matches no heading or anchor.
+fn broken_anchor(relative_path: &Path, link: &ExtractedLink) -> Violation {
+    Violation::file(
+        relative_path,
+        link.line,
+        "broken_anchor",
+        "Linked heading does not exist. Update the link or the heading.",
+    )
+    .with_target(&link.target)
+}
+
 #[cfg(test)]
 #[path = "links_tests.rs"]
 mod tests;
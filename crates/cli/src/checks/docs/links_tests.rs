@@ -184,6 +184,23 @@ fn handles_multiple_hashes() {
     assert_eq!(strip_fragment("file.md#sec#tion"), "file.md");
 }
 
+#[test]
+fn extracts_fragment() {
+    assert_eq!(fragment("file.md#section"), Some("section"));
+    assert_eq!(fragment("#section"), Some("section"));
+    assert_eq!(fragment("file.md#sec#tion"), Some("sec#tion"));
+    assert_eq!(fragment("file.md#"), None);
+    assert_eq!(fragment("file.md"), None);
+}
+
+#[test]
+fn detects_markdown_targets() {
+    assert!(is_markdown(Path::new("docs/guide.md")));
+    assert!(is_markdown(Path::new(".cursor/rules/style.mdc")));
+    assert!(!is_markdown(Path::new("src/lib.rs")));
+    assert!(!is_markdown(Path::new("docs/")));
+}
+
 // =============================================================================
 // PATH RESOLUTION TESTS
 // =============================================================================
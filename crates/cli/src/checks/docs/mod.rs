@@ -5,17 +5,22 @@
 //!
 //! Validates:
 //! - TOC entries reference existing files
-//! - Markdown links point to existing files
+//! - Markdown links point to existing files and headings
 //! - Specs have required sections
+//! - Docs are reachable from the README, specs index, or agent files
 //! - Feature commits have documentation (CI mode)
 
+mod anchors;
 mod commit;
 mod content;
 mod links;
+mod orphans;
 mod specs;
 mod toc;
 
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use dashmap::DashMap;
 use rayon::prelude::*;
@@ -24,17 +29,35 @@ use crate::adapter::build_glob_set;
 use crate::check::{Check, CheckContext, CheckResult, Violation};
 use crate::file_reader::FileContent;
 
+pub use links::is_markdown;
+pub use orphans::ORPHANED_DOC;
+
 /// Per-run cache for path existence checks.
 ///
 /// Shared across all docs sub-checks to avoid redundant filesystem calls.
 pub(super) struct PathCache {
     /// Maps paths to existence result.
     exists: DashMap<PathBuf, bool>,
+    /// Maps markdown files to the anchors they define.
+    anchors: DashMap<PathBuf, Arc<HashSet<String>>>,
 }
 
 impl PathCache {
     pub fn new() -> Self {
-        Self { exists: DashMap::new() }
+        Self { exists: DashMap::new(), anchors: DashMap::new() }
+    }
+
+    /// Get the heading anchors of a markdown file, using cache.
+    ///
+    /// Returns `None` if the file can't be read as UTF-8.
+    pub fn anchors(&self, path: &Path) -> Option<Arc<HashSet<String>>> {
+        if let Some(anchors) = self.anchors.get(path) {
+            return Some(Arc::clone(&anchors));
+        }
+        let content = std::fs::read_to_string(path).ok()?;
+        let anchors = Arc::new(anchors::extract_anchors(&content));
+        self.anchors.insert(path.to_path_buf(), Arc::clone(&anchors));
+        Some(anchors)
     }
 
     /// Check if a path exists, using cache.
@@ -142,6 +165,9 @@ impl Check for DocsCheck {
         // Run specs validation (uses path cache internally)
         specs::validate_specs(ctx, &mut violations, &path_cache);
 
+        // Run orphaned doc detection (uses path cache internally)
+        orphans::validate_orphans(ctx, &mut violations, &path_cache);
+
         // Run commit validation (CI mode only)
         if ctx.ci_mode {
             commit::validate_commit_docs(ctx, &mut violations);
@@ -0,0 +1,160 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Orphaned doc detection.
+//!
+//! Reports markdown files under the docs directory that can't be reached
+//! by following markdown links from the README, the specs index, or agent
+//! files.
+
+use std::collections::{HashSet, VecDeque};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::{PathCache, links, specs};
+use crate::adapter::build_glob_set;
+use crate::check::{CheckContext, Violation};
+use crate::checks::agents::detection::detect_agent_files;
+
+/// Violation type for docs no entry point links to.
+///
+/// Reachability depends on links in other files, so the runner re-evaluates
+/// these every run instead of caching them per file.
+pub const ORPHANED_DOC: &str = "orphaned_doc";
+
+/// Files linked to directories render one of these on GitHub.
+const DIRECTORY_INDEXES: &[&str] = &["README.md", "index.md"];
+
+/// Entry point for orphaned doc detection from docs check.
+pub fn validate_orphans(
+    ctx: &CheckContext,
+    violations: &mut Vec<Violation>,
+    path_cache: &PathCache,
+) {
+    let config = &ctx.config.check.docs.orphans;
+
+    // Skip if disabled
+    if config.check == "off" {
+        return;
+    }
+
+    let docs_dir = ctx.root.join(&config.path);
+    if !path_cache.exists(&docs_dir) || !docs_dir.is_dir() {
+        return;
+    }
+    let Ok(canonical_root) = ctx.root.canonicalize() else {
+        return;
+    };
+
+    let reachable = reachable_files(&canonical_root, &entry_points(ctx, path_cache), path_cache);
+    let exclude = build_glob_set(&config.exclude);
+
+    let mut orphans: Vec<PathBuf> = collect_docs(&docs_dir)
+        .into_iter()
+        .filter(|doc| !reachable.contains(doc))
+        .filter_map(|doc| doc.strip_prefix(&canonical_root).ok().map(Path::to_path_buf))
+        .filter(|doc| !exclude.is_match(doc))
+        .collect();
+    orphans.sort();
+
+    for doc in orphans {
+        if ctx.limit.is_some_and(|l| violations.len() >= l) {
+            break;
+        }
+        violations.push(Violation::file_only(
+            doc,
+            ORPHANED_DOC,
+            "Doc is not linked from the README, the specs index, or agent files.\n\
+             Link it from an index or remove it.",
+        ));
+    }
+}
+
+/// README, specs index, and agent files that exist in the project.
+fn entry_points(ctx: &CheckContext, path_cache: &PathCache) -> Vec<PathBuf> {
+    let specs = &ctx.config.check.docs.specs;
+    let index = specs
+        .index_file
+        .as_ref()
+        .map(PathBuf::from)
+        .or_else(|| specs::detect_index_file_cached(ctx.root, &specs.path, path_cache));
+
+    let mut entries: Vec<PathBuf> = std::iter::once(ctx.root.join("README.md"))
+        .chain(index.map(|i| ctx.root.join(i)))
+        .collect();
+    entries.extend(
+        detect_agent_files(ctx.root, &ctx.config.project.packages, &ctx.config.check.agents.files)
+            .into_iter()
+            .map(|detected| detected.path),
+    );
+    entries.into_iter().filter(|path| path_cache.exists(path)).collect()
+}
+
+/// All files reachable by following local markdown links from `entries`.
+///
+/// Returns canonicalized paths. Links leaving `root` are not followed.
+fn reachable_files(root: &Path, entries: &[PathBuf], path_cache: &PathCache) -> HashSet<PathBuf> {
+    let mut reachable: HashSet<PathBuf> = HashSet::new();
+    let mut queue: VecDeque<PathBuf> = VecDeque::new();
+
+    for entry in entries {
+        if let Ok(canonical) = entry.canonicalize()
+            && reachable.insert(canonical.clone())
+        {
+            queue.push_back(canonical);
+        }
+    }
+
+    while let Some(current) = queue.pop_front() {
+        if !links::is_markdown(&current) {
+            continue;
+        }
+        let Ok(content) = fs::read_to_string(&current) else {
+            continue;
+        };
+
+        for link in links::extract_links(&content) {
+            if !links::is_local_link(&link.target) {
+                continue;
+            }
+            let resolved = links::resolve_link(&current, &link.target);
+            if !path_cache.exists(&resolved) {
+                continue;
+            }
+            let Ok(canonical) = resolved.canonicalize() else {
+                continue;
+            };
+            if !canonical.starts_with(root) {
+                continue;
+            }
+
+            // A directory link reaches the index GitHub renders for it
+            let targets = if canonical.is_dir() {
+                DIRECTORY_INDEXES.iter().map(|name| canonical.join(name)).collect()
+            } else {
+                vec![canonical]
+            };
+            for target in targets {
+                if path_cache.exists(&target) && reachable.insert(target.clone()) {
+                    queue.push_back(target);
+                }
+            }
+        }
+    }
+    reachable
+}
+
+/// Collect markdown files under the docs directory (canonicalized).
+fn collect_docs(docs_dir: &Path) -> Vec<PathBuf> {
+    ignore::WalkBuilder::new(docs_dir)
+        .build()
+        .flatten()
+        .filter(|e| e.file_type().is_some_and(|t| t.is_file()))
+        .filter(|e| links::is_markdown(e.path()))
+        .filter_map(|e| e.into_path().canonicalize().ok())
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "orphans_tests.rs"]
+mod tests;
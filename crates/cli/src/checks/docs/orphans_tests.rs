@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::fs;
+
+use super::*;
+use tempfile::tempdir;
+
+/// Write files into `root`, creating parent directories.
+fn write_files(root: &Path, files: &[(&str, &str)]) {
+    for (path, content) in files {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+}
+
+/// Relative paths of reachable files, sorted.
+fn reachable_from(root: &Path, entries: &[&str]) -> Vec<String> {
+    let root = root.canonicalize().unwrap();
+    let entries: Vec<PathBuf> = entries.iter().map(|e| root.join(e)).collect();
+    let mut reachable: Vec<String> = reachable_files(&root, &entries, &PathCache::new())
+        .iter()
+        .map(|p| p.strip_prefix(&root).unwrap().to_string_lossy().to_string())
+        .collect();
+    reachable.sort();
+    reachable
+}
+
+#[test]
+fn follows_links_transitively() {
+    let temp = tempdir().unwrap();
+    write_files(
+        temp.path(),
+        &[
+            ("README.md", "See [guide](docs/guide.md#setup)."),
+            ("docs/guide.md", "Also [api](api/overview.md)."),
+            ("docs/api/overview.md", "Back to [guide](../guide.md)."),
+            ("docs/orphan.md", "Nobody links here."),
+        ],
+    );
+
+    assert_eq!(
+        reachable_from(temp.path(), &["README.md"]),
+        ["README.md", "docs/api/overview.md", "docs/guide.md"]
+    );
+}
+
+#[test]
+fn directory_links_reach_directory_index() {
+    let temp = tempdir().unwrap();
+    write_files(
+        temp.path(),
+        &[
+            ("README.md", "See [the docs](docs/)."),
+            ("docs/README.md", "Start with [setup](setup.md)."),
+            ("docs/setup.md", "# Setup"),
+        ],
+    );
+
+    assert_eq!(
+        reachable_from(temp.path(), &["README.md"]),
+        ["README.md", "docs/README.md", "docs/setup.md"]
+    );
+}
+
+#[test]
+fn ignores_external_and_broken_links() {
+    let temp = tempdir().unwrap();
+    write_files(
+        temp.path(),
+        &[
+            ("CLAUDE.md", "[web](https://example.com) [gone](docs/gone.md) [self](#top)"),
+            ("docs/guide.md", "# Guide"),
+        ],
+    );
+
+    assert_eq!(reachable_from(temp.path(), &["CLAUDE.md"]), ["CLAUDE.md"]);
+}
+
+#[test]
+fn collects_markdown_docs_only() {
+    let temp = tempdir().unwrap();
+    write_files(
+        temp.path(),
+        &[("docs/guide.md", ""), ("docs/rules/style.mdc", ""), ("docs/schema.json", "{}")],
+    );
+
+    let mut docs: Vec<_> = collect_docs(&temp.path().join("docs"))
+        .into_iter()
+        .map(|p| p.file_name().unwrap().to_string_lossy().to_string())
+        .collect();
+    docs.sort();
+    assert_eq!(docs, ["guide.md", "style.mdc"]);
+}
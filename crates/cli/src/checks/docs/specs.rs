@@ -51,7 +51,7 @@ fn detect_index_file(root: &Path, specs_path: &str) -> Option<PathBuf> {
 }
 
 /// Detect index file using priority order (cached version).
-fn detect_index_file_cached(
+pub(super) fn detect_index_file_cached(
     root: &Path,
     specs_path: &str,
     path_cache: &super::PathCache,
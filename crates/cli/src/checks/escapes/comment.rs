@@ -111,6 +111,7 @@ pub(super) fn is_comment_line(line: &str) -> bool {
 /// Special cases that return false (detected, not skipped):
 /// - Go directive patterns (//go:xxx) at the start of a line
 /// - TypeScript directive patterns (@ts-ignore, @ts-expect-error) in comments
+/// - IaC ignores (tfsec:ignore, checkov:skip=, hadolint ignore=) in comments
 pub(super) fn is_match_in_comment(line_content: &str, match_offset_in_line: usize) -> bool {
     // Find comment start in the line
     if let Some(comment_start) = find_comment_start(line_content) {
@@ -124,6 +125,10 @@ pub(super) fn is_match_in_comment(line_content: &str, match_offset_in_line: usiz
         if is_typescript_directive(line_content) {
             return false;
         }
+        // Special case: IaC scanner and linter ignores are comments by design.
+        if is_iac_directive(line_content) {
+            return false;
+        }
         // If match starts at or after the comment marker, it's in a comment
         return match_offset_in_line >= comment_start;
     }
@@ -136,6 +141,16 @@ fn is_go_directive(line: &str) -> bool {
     trimmed.starts_with("//go:")
 }
 
+/// Check if a line contains an IaC scanner or linter ignore that should be detected.
+fn is_iac_directive(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed.contains("tfsec:ignore")
+        || trimmed.contains("trivy:ignore")
+        || trimmed.contains("checkov:skip=")
+        || trimmed.contains("hadolint ignore=")
+        || trimmed.contains("hadolint global ignore=")
+}
+
 /// Check if a line contains a TypeScript directive that should be detected.
 /// These directives are comments but should not be skipped by comment detection.
 fn is_typescript_directive(line: &str) -> bool {
@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! C/C++ clang-tidy NOLINT directive checking for the escapes check.
+
+use std::path::Path;
+
+use crate::adapter::parse_cpp_suppresses;
+use crate::check::{CheckContext, Violation};
+use crate::config::CppSuppressConfig;
+
+use super::suppress_common::{UnifiedSuppressDirective, check_suppress_violations_generic};
+
+/// Check NOLINT directives and return violations.
+pub fn check_cpp_suppress_violations(
+    ctx: &CheckContext,
+    path: &Path,
+    content: &str,
+    config: &CppSuppressConfig,
+    is_test_file: bool,
+    limit_reached: &mut bool,
+) -> Vec<Violation> {
+    let suppresses = parse_cpp_suppresses(content, config.comment.as_deref());
+
+    // Convert to unified format
+    let unified: Vec<UnifiedSuppressDirective> = suppresses
+        .into_iter()
+        .map(|s| {
+            let pattern = if s.codes.is_empty() {
+                format!("// {}", s.kind)
+            } else {
+                format!("// {}({})", s.kind, s.codes.join(","))
+            };
+            UnifiedSuppressDirective {
+                line: s.line,
+                codes: s.codes,
+                has_comment: s.has_comment,
+                comment_text: s.comment_text,
+                pattern,
+            }
+        })
+        .collect();
+
+    check_suppress_violations_generic(
+        ctx,
+        path,
+        unified,
+        config,
+        "cpp",
+        "suppress",
+        is_test_file,
+        limit_reached,
+    )
+}
@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! C# `#pragma warning disable` and `[SuppressMessage]` checking for the escapes check.
+
+use std::path::Path;
+
+use crate::adapter::csharp::CSharpSuppressKind;
+use crate::adapter::parse_csharp_suppresses;
+use crate::check::{CheckContext, Violation};
+use crate::config::CSharpSuppressConfig;
+
+use super::suppress_common::{UnifiedSuppressDirective, check_suppress_violations_generic};
+
+/// Check C# suppress directives and return violations.
+pub fn check_csharp_suppress_violations(
+    ctx: &CheckContext,
+    path: &Path,
+    content: &str,
+    config: &CSharpSuppressConfig,
+    is_test_file: bool,
+    limit_reached: &mut bool,
+) -> Vec<Violation> {
+    let suppresses = parse_csharp_suppresses(content, config.comment.as_deref());
+
+    // Convert to unified format
+    let unified: Vec<UnifiedSuppressDirective> = suppresses
+        .into_iter()
+        .map(|s| {
+            let pattern = match (s.kind, s.codes.is_empty()) {
+                (CSharpSuppressKind::PragmaWarning, true) => s.kind.to_string(),
+                (CSharpSuppressKind::PragmaWarning, false) => {
+                    format!("{} {}", s.kind, s.codes.join(", "))
+                }
+                (CSharpSuppressKind::SuppressMessage, _) => {
+                    format!("[{}(\"{}\")]", s.kind, s.codes.join(","))
+                }
+            };
+            UnifiedSuppressDirective {
+                line: s.line,
+                codes: s.codes,
+                has_comment: s.has_comment,
+                comment_text: s.comment_text,
+                pattern,
+            }
+        })
+        .collect();
+
+    check_suppress_violations_generic(
+        ctx,
+        path,
+        unified,
+        config,
+        "csharp",
+        "suppress",
+        is_test_file,
+        limit_reached,
+    )
+}
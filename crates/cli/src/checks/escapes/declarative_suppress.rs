@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Declarative adapter suppress directive checking for the escapes check.
+
+use std::path::Path;
+
+use regex::Regex;
+
+use crate::adapter::declarative::{compile_directives, parse_declarative_suppresses};
+use crate::adapter::{Adapter, DeclarativeAdapter};
+use crate::check::{CheckContext, Violation};
+use crate::config::{Config, DeclarativeAdapterConfig};
+
+use super::suppress_common::{UnifiedSuppressDirective, check_suppress_violations_generic};
+
+/// A declarative adapter with its suppress directives compiled.
+pub struct DeclarativeChecker<'a> {
+    pub spec: &'a DeclarativeAdapterConfig,
+    pub adapter: DeclarativeAdapter,
+    directives: Vec<Regex>,
+}
+
+impl<'a> DeclarativeChecker<'a> {
+    /// Prepare every declarative adapter in config, compiling directives once.
+    pub fn compile_all(config: &'a Config) -> Result<Vec<Self>, regex::Error> {
+        let adapters = crate::adapter::declarative_adapters(config);
+        config
+            .adapters
+            .iter()
+            .zip(adapters)
+            .map(|(spec, adapter)| {
+                let directives = compile_directives(&spec.suppress.directives)?;
+                Ok(Self { spec, adapter, directives })
+            })
+            .collect()
+    }
+}
+
+/// Check declarative suppress directives and return violations.
+pub fn check_declarative_suppress_violations(
+    ctx: &CheckContext,
+    path: &Path,
+    content: &str,
+    checker: &DeclarativeChecker,
+    is_test_file: bool,
+    limit_reached: &mut bool,
+) -> Vec<Violation> {
+    if checker.directives.is_empty() {
+        return Vec::new();
+    }
+
+    let config = &checker.spec.suppress;
+    let suppresses = parse_declarative_suppresses(
+        content,
+        &checker.directives,
+        checker.adapter.comment_prefix(),
+        config.comment.as_deref(),
+    );
+
+    // Convert to unified format
+    let unified: Vec<UnifiedSuppressDirective> = suppresses
+        .into_iter()
+        .map(|s| UnifiedSuppressDirective {
+            line: s.line,
+            codes: s.codes,
+            has_comment: s.has_comment,
+            comment_text: s.comment_text,
+            pattern: s.directive,
+        })
+        .collect();
+
+    check_suppress_violations_generic(
+        ctx,
+        path,
+        unified,
+        config,
+        checker.adapter.name(),
+        "suppress",
+        is_test_file,
+        limit_reached,
+    )
+}
@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Elixir Credo directive checking for the escapes check.
+
+use std::path::Path;
+
+use crate::adapter::parse_elixir_suppresses;
+use crate::check::{CheckContext, Violation};
+use crate::config::ElixirSuppressConfig;
+
+use super::suppress_common::{UnifiedSuppressDirective, check_suppress_violations_generic};
+
+/// Check Credo directives and return violations.
+pub fn check_elixir_suppress_violations(
+    ctx: &CheckContext,
+    path: &Path,
+    content: &str,
+    config: &ElixirSuppressConfig,
+    is_test_file: bool,
+    limit_reached: &mut bool,
+) -> Vec<Violation> {
+    let suppresses = parse_elixir_suppresses(content, config.comment.as_deref());
+
+    // Convert to unified format
+    let unified: Vec<UnifiedSuppressDirective> = suppresses
+        .into_iter()
+        .map(|s| {
+            let pattern = if s.codes.is_empty() {
+                s.kind.to_string()
+            } else {
+                format!("{} {}", s.kind, s.codes.join(" "))
+            };
+            UnifiedSuppressDirective {
+                line: s.line,
+                codes: s.codes,
+                has_comment: s.has_comment,
+                comment_text: s.comment_text,
+                pattern,
+            }
+        })
+        .collect();
+
+    check_suppress_violations_generic(
+        ctx,
+        path,
+        unified,
+        config,
+        "elixir",
+        "suppress",
+        is_test_file,
+        limit_reached,
+    )
+}
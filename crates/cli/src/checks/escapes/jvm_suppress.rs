@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Java/Kotlin `@Suppress` and `// noinspection` directive checking.
+
+use std::path::Path;
+
+use crate::adapter::{JvmSuppressKind, parse_jvm_suppresses};
+use crate::check::{CheckContext, Violation};
+
+use super::suppress_common::{UnifiedSuppressDirective, check_suppress_violations_generic};
+
+/// Check suppress directives in a Java or Kotlin file.
+///
+/// `.java` files use `[java.suppress]`; `.kt`/`.kts` files use `[kotlin.suppress]`.
+pub(super) fn check_jvm_suppress_violations(
+    ctx: &CheckContext,
+    path: &Path,
+    content: &str,
+    is_test_file: bool,
+    limit_reached: &mut bool,
+) -> Vec<Violation> {
+    let (config, language) = if path.extension().is_some_and(|e| e == "java") {
+        (&ctx.config.java.suppress, "java")
+    } else {
+        (&ctx.config.kotlin.suppress, "kotlin")
+    };
+
+    let suppresses = parse_jvm_suppresses(content, None);
+
+    // Convert to unified format
+    let unified: Vec<UnifiedSuppressDirective> = suppresses
+        .into_iter()
+        .map(|s| {
+            let code = s.codes.first().map(|c| c.as_str()).unwrap_or("unknown");
+            let pattern = match s.kind {
+                JvmSuppressKind::Suppress => format!("@Suppress(\"{code}\")"),
+                JvmSuppressKind::Noinspection => format!("// noinspection {code}"),
+            };
+            UnifiedSuppressDirective {
+                line: s.line,
+                codes: s.codes,
+                has_comment: s.has_comment,
+                comment_text: s.comment_text,
+                pattern,
+            }
+        })
+        .collect();
+
+    check_suppress_violations_generic(
+        ctx,
+        path,
+        unified,
+        config,
+        language,
+        "suppress",
+        is_test_file,
+        limit_reached,
+    )
+}
@@ -7,8 +7,9 @@ use std::path::Path;
 
 use crate::adapter::common::policy::{self, PolicyConfig};
 use crate::adapter::{
-    GoAdapter, JavaScriptAdapter, ProjectLanguage, PythonAdapter, RubyAdapter, RustAdapter,
-    ShellAdapter, detect_language,
+    CSharpAdapter, CppAdapter, ElixirAdapter, GoAdapter, IacAdapter, JavaScriptAdapter, JvmAdapter,
+    PhpAdapter, ProjectLanguage, PythonAdapter, RubyAdapter, RustAdapter, ShellAdapter,
+    SwiftAdapter, detect_language, resolve_iac_patterns,
 };
 use crate::check::{CheckContext, Violation};
 use crate::config::{CheckLevel, LintChangesPolicy};
@@ -23,6 +24,42 @@ pub struct PolicyCheckResult {
 
 /// Check lint policy and return violations with their check level.
 pub fn check_lint_policy(ctx: &CheckContext) -> PolicyCheckResult {
+    let language = check_project_lint_policy(ctx);
+    if !language.violations.is_empty() {
+        return language;
+    }
+
+    // Infrastructure lint config (.tflint.hcl, .hadolint.yaml) applies to every project
+    let iac = check_language_lint_policy(
+        ctx,
+        "iac",
+        &ctx.config.iac.policy,
+        ctx.config.iac.policy.lint_changes,
+        || IacAdapter::with_patterns(resolve_iac_patterns(ctx.config, &ctx.config.project.tests)),
+    );
+    if !iac.violations.is_empty() {
+        return iac;
+    }
+
+    // Declarative adapters carry their own lint config files
+    let adapters = crate::adapter::declarative_adapters(ctx.config);
+    for (spec, adapter) in ctx.config.adapters.iter().zip(adapters) {
+        let result = check_language_lint_policy(
+            ctx,
+            &spec.name,
+            &spec.policy,
+            spec.policy.lint_changes,
+            || adapter,
+        );
+        if !result.violations.is_empty() {
+            return result;
+        }
+    }
+    language
+}
+
+/// Check lint policy for the detected project language.
+fn check_project_lint_policy(ctx: &CheckContext) -> PolicyCheckResult {
     match detect_language(ctx.root) {
         ProjectLanguage::Rust => check_language_lint_policy(
             ctx,
@@ -38,6 +75,26 @@ pub fn check_lint_policy(ctx: &CheckContext) -> PolicyCheckResult {
             ctx.config.golang.policy.lint_changes,
             GoAdapter::new,
         ),
+        ProjectLanguage::Jvm => {
+            // Java and Kotlin share a build but have separate lint tooling
+            let java = check_language_lint_policy(
+                ctx,
+                "java",
+                &ctx.config.java.policy,
+                ctx.config.java.policy.lint_changes,
+                JvmAdapter::new,
+            );
+            if !java.violations.is_empty() {
+                return java;
+            }
+            check_language_lint_policy(
+                ctx,
+                "kotlin",
+                &ctx.config.kotlin.policy,
+                ctx.config.kotlin.policy.lint_changes,
+                JvmAdapter::new,
+            )
+        }
         ProjectLanguage::Python => check_language_lint_policy(
             ctx,
             "python",
@@ -52,6 +109,41 @@ pub fn check_lint_policy(ctx: &CheckContext) -> PolicyCheckResult {
             ctx.config.ruby.policy.lint_changes,
             RubyAdapter::new,
         ),
+        ProjectLanguage::Cpp => check_language_lint_policy(
+            ctx,
+            "cpp",
+            &ctx.config.cpp.policy,
+            ctx.config.cpp.policy.lint_changes,
+            CppAdapter::new,
+        ),
+        ProjectLanguage::CSharp => check_language_lint_policy(
+            ctx,
+            "csharp",
+            &ctx.config.csharp.policy,
+            ctx.config.csharp.policy.lint_changes,
+            CSharpAdapter::new,
+        ),
+        ProjectLanguage::Php => check_language_lint_policy(
+            ctx,
+            "php",
+            &ctx.config.php.policy,
+            ctx.config.php.policy.lint_changes,
+            PhpAdapter::new,
+        ),
+        ProjectLanguage::Swift => check_language_lint_policy(
+            ctx,
+            "swift",
+            &ctx.config.swift.policy,
+            ctx.config.swift.policy.lint_changes,
+            SwiftAdapter::new,
+        ),
+        ProjectLanguage::Elixir => check_language_lint_policy(
+            ctx,
+            "elixir",
+            &ctx.config.elixir.policy,
+            ctx.config.elixir.policy.lint_changes,
+            ElixirAdapter::new,
+        ),
         ProjectLanguage::Shell => check_language_lint_policy(
             ctx,
             "shell",
@@ -149,6 +241,7 @@ fn make_policy_violation(
         scope: None,
         expected: None,
         found: None,
+        files: None,
     }]
 }
 
@@ -7,15 +7,22 @@
 //! See docs/specs/checks/escape-hatches.md.
 
 mod comment;
+mod cpp_suppress;
+mod csharp_suppress;
+mod declarative_suppress;
+mod elixir_suppress;
 mod go_suppress;
 mod javascript_suppress;
+mod jvm_suppress;
 mod lint_policy;
 mod metrics;
 mod patterns;
+mod php_suppress;
 mod python_suppress;
 mod ruby_suppress;
 mod shell_suppress;
 mod suppress_common;
+mod swift_suppress;
 mod violations;
 
 use std::collections::HashSet;
@@ -24,23 +31,31 @@ use std::path::Path;
 use globset::GlobSet;
 
 use crate::adapter::glob::build_glob_set;
-use crate::adapter::{CfgTestInfo, FileKind, GenericAdapter, parse_suppress_attrs};
+use crate::adapter::{Adapter, CfgTestInfo, FileKind, GenericAdapter, parse_suppress_attrs};
 use crate::check::{Check, CheckContext, CheckResult, Violation};
 use crate::config::{CheckLevel, EscapeAction, SuppressConfig, SuppressLevel};
 use crate::file_reader::FileContent;
+use cpp_suppress::check_cpp_suppress_violations;
+use csharp_suppress::check_csharp_suppress_violations;
+use declarative_suppress::{DeclarativeChecker, check_declarative_suppress_violations};
+use elixir_suppress::check_elixir_suppress_violations;
 use go_suppress::check_go_suppress_violations;
 use javascript_suppress::check_javascript_suppress_violations;
+use jvm_suppress::check_jvm_suppress_violations;
+use php_suppress::check_php_suppress_violations;
 use python_suppress::check_python_suppress_violations;
 use ruby_suppress::check_ruby_suppress_violations;
 use shell_suppress::check_shell_suppress_violations;
 use suppress_common::{
     SuppressAttrInfo, SuppressCheckParams, SuppressViolationKind, check_suppress_attr,
 };
+use swift_suppress::check_swift_suppress_violations;
 
 use comment::{has_justification_comment, is_match_in_comment};
 use metrics::EscapesMetrics;
 use patterns::{
-    compile_merged_patterns, default_test_patterns, get_adapter_escape_patterns, merge_patterns,
+    compile_merged_patterns, default_test_patterns, get_adapter_escape_patterns,
+    get_declarative_escape_patterns, get_iac_escape_patterns, merge_patterns,
 };
 use violations::{create_threshold_violation, format_comment_advice, try_create_violation};
 
@@ -66,14 +81,25 @@ impl Check for EscapesCheck {
         // Check lint policy for language-specific projects (only when --base is provided)
         let policy_result = lint_policy::check_lint_policy(ctx);
 
-        // Get adapter default patterns for the detected language
-        let adapter_patterns = get_adapter_escape_patterns(ctx.root);
+        // Get adapter default patterns for the detected language, plus infrastructure
+        // code patterns (scoped to IaC files) which apply alongside any language
+        let iac = crate::adapter::resolve_iac_patterns(ctx.config, &ctx.config.project.tests);
+        let iac_files = build_glob_set(&iac.source);
+        let mut adapter_patterns = get_adapter_escape_patterns(ctx.root);
+        adapter_patterns.extend(get_iac_escape_patterns(&iac.source));
+        adapter_patterns.extend(get_declarative_escape_patterns(ctx.config));
+
+        // Declarative adapters classify and check suppress directives for their own files
+        let declarative = match DeclarativeChecker::compile_all(ctx.config) {
+            Ok(d) => d,
+            Err(e) => return CheckResult::skipped(self.name(), e.to_string()),
+        };
 
         // Merge patterns: config patterns override adapter defaults by name
         let merged_patterns = merge_patterns(&config.patterns, &adapter_patterns);
 
         // No patterns to check = pass
-        if merged_patterns.is_empty() {
+        if merged_patterns.is_empty() && declarative.is_empty() {
             return CheckResult::passed(self.name());
         }
 
@@ -111,8 +137,13 @@ impl Check for EscapesCheck {
                 break;
             }
 
-            // Skip non-source files (configs, docs, etc.)
-            if !is_source_file(&file.path) {
+            let relative = file.path.strip_prefix(ctx.root).unwrap_or(&file.path);
+
+            // Skip non-source files (configs, docs, etc.); infrastructure files
+            // are checked against IaC patterns only
+            let checker = declarative.iter().find(|d| d.adapter.matches(&file.path));
+            let iac_only = !is_source_file(&file.path) && checker.is_none();
+            if iac_only && !iac_files.is_match(relative) {
                 continue;
             }
 
@@ -130,10 +161,11 @@ impl Check for EscapesCheck {
                 continue; // Skip non-UTF-8 files
             };
 
-            let relative = file.path.strip_prefix(ctx.root).unwrap_or(&file.path);
-
             // Classify file as source or test
-            let is_test_file = classify_file(&file_adapter, &file.path, ctx.root) == FileKind::Test;
+            let is_test_file = match checker {
+                Some(checker) => checker.adapter.classify(relative) == FileKind::Test,
+                None => classify_file(&file_adapter, &file.path, ctx.root) == FileKind::Test,
+            };
             let package = find_package(&file.path, ctx.root, packages);
 
             // Parse cfg(test) info for Rust files (reuse for suppress + escape checking)
@@ -143,6 +175,23 @@ impl Check for EscapesCheck {
                 None
             };
 
+            // Check for declarative adapter suppress directive violations
+            if let Some(checker) = checker {
+                let declarative_violations = check_declarative_suppress_violations(
+                    ctx,
+                    relative,
+                    content,
+                    checker,
+                    is_test_file,
+                    &mut limit_reached,
+                );
+                violations.extend(declarative_violations);
+
+                if limit_reached {
+                    break;
+                }
+            }
+
             // Check for Rust suppress attribute violations
             if let Some(ref info) = cfg_info {
                 let suppress_violations = check_suppress_violations(
@@ -212,6 +261,107 @@ impl Check for EscapesCheck {
                 }
             }
 
+            // Check for Java/Kotlin @Suppress and noinspection directive violations
+            if has_extension(&file.path, &["java", "kt", "kts"]) {
+                let jvm_violations = check_jvm_suppress_violations(
+                    ctx,
+                    relative,
+                    content,
+                    is_test_file,
+                    &mut limit_reached,
+                );
+                violations.extend(jvm_violations);
+
+                if limit_reached {
+                    break;
+                }
+            }
+
+            // Check for C/C++ clang-tidy NOLINT directive violations
+            if has_extension(&file.path, &["c", "h", "cc", "cpp", "cxx", "hh", "hpp", "hxx"]) {
+                let cpp_violations = check_cpp_suppress_violations(
+                    ctx,
+                    relative,
+                    content,
+                    &ctx.config.cpp.suppress,
+                    is_test_file,
+                    &mut limit_reached,
+                );
+                violations.extend(cpp_violations);
+
+                if limit_reached {
+                    break;
+                }
+            }
+
+            // Check for C# #pragma warning / [SuppressMessage] violations
+            if has_extension(&file.path, &["cs"]) {
+                let csharp_violations = check_csharp_suppress_violations(
+                    ctx,
+                    relative,
+                    content,
+                    &ctx.config.csharp.suppress,
+                    is_test_file,
+                    &mut limit_reached,
+                );
+                violations.extend(csharp_violations);
+
+                if limit_reached {
+                    break;
+                }
+            }
+
+            // Check for PHP phpstan/psalm/phpcs suppress violations
+            if has_extension(&file.path, &["php"]) {
+                let php_violations = check_php_suppress_violations(
+                    ctx,
+                    relative,
+                    content,
+                    &ctx.config.php.suppress,
+                    is_test_file,
+                    &mut limit_reached,
+                );
+                violations.extend(php_violations);
+
+                if limit_reached {
+                    break;
+                }
+            }
+
+            // Check for SwiftLint directive violations
+            if has_extension(&file.path, &["swift"]) {
+                let swift_violations = check_swift_suppress_violations(
+                    ctx,
+                    relative,
+                    content,
+                    &ctx.config.swift.suppress,
+                    is_test_file,
+                    &mut limit_reached,
+                );
+                violations.extend(swift_violations);
+
+                if limit_reached {
+                    break;
+                }
+            }
+
+            // Check for Elixir Credo directive violations
+            if has_extension(&file.path, &["ex", "exs"]) {
+                let elixir_violations = check_elixir_suppress_violations(
+                    ctx,
+                    relative,
+                    content,
+                    &ctx.config.elixir.suppress,
+                    is_test_file,
+                    &mut limit_reached,
+                );
+                violations.extend(elixir_violations);
+
+                if limit_reached {
+                    break;
+                }
+            }
+
             // Check for Ruby RuboCop/Standard suppress directive violations
             if has_extension(&file.path, &["rb", "rake"]) {
                 let ruby_violations = check_ruby_suppress_violations(
@@ -248,6 +398,13 @@ impl Check for EscapesCheck {
 
             // Find matches for each pattern
             for pattern in &patterns {
+                // Patterns scoped to specific files (e.g., IaC defaults)
+                match &pattern.files {
+                    Some(files) if !files.is_match(relative) => continue,
+                    None if iac_only => continue,
+                    _ => {}
+                }
+
                 let matches = pattern.matcher.find_all_with_lines(content);
 
                 // Deduplicate matches by line - keep only first match per line
@@ -414,7 +571,6 @@ impl Check for EscapesCheck {
 
 /// Classify file as source or test using a pre-built adapter.
 fn classify_file(adapter: &GenericAdapter, path: &Path, root: &Path) -> FileKind {
-    use crate::adapter::Adapter;
     let relative = path.strip_prefix(root).unwrap_or(path);
     adapter.classify(relative)
 }
@@ -458,9 +614,9 @@ fn is_source_file(path: &Path) -> bool {
     matches!(
         ext.as_str(),
         // Systems languages
-        "rs" | "c" | "cpp" | "h" | "hpp" | "go"
+        "rs" | "c" | "h" | "cc" | "cpp" | "cxx" | "hh" | "hpp" | "hxx" | "go"
         // JVM languages
-        | "java" | "kt" | "scala"
+        | "java" | "kt" | "kts" | "scala"
         // Dynamic languages
         | "py" | "rb" | "php" | "lua" | "pl" | "pm" | "r"
         // JavaScript/TypeScript
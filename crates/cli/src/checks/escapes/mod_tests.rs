@@ -61,6 +61,9 @@ fn is_comment_line_cases(input: &str, expected: bool) {
     shell_line_comment = { "# used with eval", 0, true },
     // Match exactly at comment boundary
     at_comment_start = { "x // eval", 5, true },
+    // IaC ignores are detected even though they are comments
+    tfsec_directive = { "  #tfsec:ignore:aws-s3-enable-versioning", 3, false },
+    hadolint_directive = { "# hadolint ignore=DL3008", 2, false },
 )]
 fn is_match_in_comment_cases(line: &str, offset: usize, expected: bool) {
     assert_eq!(
@@ -340,3 +343,15 @@ mod benchmarks {
         println!("Target: < 1µs per classification");
     }
 }
+
+#[test]
+fn iac_patterns_are_scoped_to_iac_files() {
+    let source = vec!["**/*.tf".to_string(), "**/Dockerfile".to_string()];
+    let patterns = compile_merged_patterns(&get_iac_escape_patterns(&source)).unwrap();
+    let latest = patterns.iter().find(|p| p.name == "latest_tag").unwrap();
+    let files = latest.files.as_ref().unwrap();
+
+    assert!(files.is_match("Dockerfile"));
+    assert!(files.is_match("infra/main.tf"));
+    assert!(!files.is_match("src/deploy.py"));
+}
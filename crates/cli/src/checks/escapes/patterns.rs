@@ -6,11 +6,15 @@
 use std::collections::HashSet;
 use std::path::Path;
 
+use globset::GlobSet;
+
+use crate::adapter::glob::build_glob_set;
 use crate::adapter::{
-    EscapePattern as AdapterEscapePattern, GoAdapter, JavaScriptAdapter, ProjectLanguage,
-    PythonAdapter, RubyAdapter, RustAdapter, ShellAdapter, detect_language,
+    CSharpAdapter, CppAdapter, ElixirAdapter, EscapePattern as AdapterEscapePattern, GoAdapter,
+    IacAdapter, JavaScriptAdapter, JvmAdapter, PhpAdapter, ProjectLanguage, PythonAdapter,
+    RubyAdapter, RustAdapter, ShellAdapter, SwiftAdapter, detect_language,
 };
-use crate::config::{EscapeAction, EscapePattern as ConfigEscapePattern};
+use crate::config::{Config, EscapeAction, EscapePattern as ConfigEscapePattern};
 use crate::pattern::{CompiledPattern, PatternError};
 
 use super::violations::default_advice;
@@ -27,6 +31,8 @@ pub(super) struct CompiledEscapePattern {
     pub(super) threshold: usize,
     /// Override action for test code ("allow" | "comment" | "forbid").
     pub(super) in_tests: Option<String>,
+    /// Files this pattern applies to (None = all source files).
+    pub(super) files: Option<GlobSet>,
 }
 
 /// Default test patterns for file classification.
@@ -48,6 +54,14 @@ pub(super) fn default_test_patterns() -> Vec<String> {
         // Ruby Cucumber/features patterns
         "features/**/*.rb".to_string(),
         "**/features/**/*.rb".to_string(),
+        // JVM test source sets (src/integrationTest, src/androidTest)
+        "**/src/*Test/**".to_string(),
+        // SwiftPM test targets
+        "Tests/**".to_string(),
+        "**/Tests/**".to_string(),
+        "**/*Tests.swift".to_string(),
+        // Terraform test files
+        "**/*.tftest.hcl".to_string(),
     ]
 }
 
@@ -67,6 +81,10 @@ pub(super) fn get_adapter_escape_patterns(root: &Path) -> Vec<ConfigEscapePatter
             let go_adapter = GoAdapter::new();
             patterns.extend(convert_adapter_patterns(go_adapter.default_escapes()));
         }
+        ProjectLanguage::Jvm => {
+            let jvm_adapter = JvmAdapter::new();
+            patterns.extend(convert_adapter_patterns(jvm_adapter.default_escapes()));
+        }
         ProjectLanguage::Shell => {
             let shell_adapter = ShellAdapter::new();
             patterns.extend(convert_adapter_patterns(shell_adapter.default_escapes()));
@@ -83,6 +101,26 @@ pub(super) fn get_adapter_escape_patterns(root: &Path) -> Vec<ConfigEscapePatter
             let ruby_adapter = RubyAdapter::new();
             patterns.extend(convert_adapter_patterns(ruby_adapter.default_escapes()));
         }
+        ProjectLanguage::Cpp => {
+            let cpp_adapter = CppAdapter::new();
+            patterns.extend(convert_adapter_patterns(cpp_adapter.default_escapes()));
+        }
+        ProjectLanguage::CSharp => {
+            let csharp_adapter = CSharpAdapter::new();
+            patterns.extend(convert_adapter_patterns(csharp_adapter.default_escapes()));
+        }
+        ProjectLanguage::Php => {
+            let php_adapter = PhpAdapter::new();
+            patterns.extend(convert_adapter_patterns(php_adapter.default_escapes()));
+        }
+        ProjectLanguage::Swift => {
+            let swift_adapter = SwiftAdapter::new();
+            patterns.extend(convert_adapter_patterns(swift_adapter.default_escapes()));
+        }
+        ProjectLanguage::Elixir => {
+            let elixir_adapter = ElixirAdapter::new();
+            patterns.extend(convert_adapter_patterns(elixir_adapter.default_escapes()));
+        }
         ProjectLanguage::Generic => {
             // No default patterns for generic projects
         }
@@ -91,6 +129,37 @@ pub(super) fn get_adapter_escape_patterns(root: &Path) -> Vec<ConfigEscapePatter
     patterns
 }
 
+/// Get infrastructure-as-code escape patterns, scoped to the given IaC source patterns.
+///
+/// IaC files live alongside any project language, so these apply regardless of
+/// the detected language but only to files matching `source`.
+pub(super) fn get_iac_escape_patterns(source: &[String]) -> Vec<ConfigEscapePattern> {
+    use crate::adapter::Adapter;
+
+    let mut patterns = convert_adapter_patterns(IacAdapter::new().default_escapes());
+    for pattern in &mut patterns {
+        pattern.source = source.to_vec();
+    }
+    patterns
+}
+
+/// Get escape patterns from declarative adapter specs.
+///
+/// Patterns without `source` are scoped to the files the adapter handles.
+pub(super) fn get_declarative_escape_patterns(config: &Config) -> Vec<ConfigEscapePattern> {
+    let mut patterns = Vec::new();
+    for spec in &config.adapters {
+        for pattern in &spec.escapes {
+            let mut pattern = pattern.clone();
+            if pattern.source.is_empty() {
+                pattern.source = spec.file_patterns();
+            }
+            patterns.push(pattern);
+        }
+    }
+    patterns
+}
+
 /// Convert adapter escape patterns to config format.
 fn convert_adapter_patterns(adapter_patterns: &[AdapterEscapePattern]) -> Vec<ConfigEscapePattern> {
     adapter_patterns
@@ -157,6 +226,7 @@ pub(super) fn compile_merged_patterns(
                 comment: p.comment.clone(),
                 threshold: p.threshold,
                 in_tests: p.in_tests.clone(),
+                files: (!p.source.is_empty()).then(|| build_glob_set(&p.source)),
             })
         })
         .collect()
@@ -4,14 +4,16 @@
 //! Shared suppress checking logic for all language suppress checkers.
 //!
 //! Provides common traits and functions to eliminate duplication across
-//! Go, JavaScript, Shell, Ruby, and Rust suppress checkers.
+//! C/C++, C#, declarative adapters, Elixir, Go, JavaScript, JVM, PHP, Shell, Ruby, Rust, and Swift suppress checkers.
 
 use std::path::Path;
 
 use crate::check::{CheckContext, Violation};
 use crate::config::{
-    GoSuppressConfig, JavaScriptSuppressConfig, PythonSuppressConfig, RubySuppressConfig,
-    ShellSuppressConfig, SuppressConfig, SuppressLevel, SuppressScopeConfig,
+    CSharpSuppressConfig, CppSuppressConfig, DeclarativeSuppressConfig, ElixirSuppressConfig,
+    GoSuppressConfig, JavaScriptSuppressConfig, JvmSuppressConfig, PhpSuppressConfig,
+    PythonSuppressConfig, RubySuppressConfig, ShellSuppressConfig, SuppressConfig, SuppressLevel,
+    SuppressScopeConfig, SwiftSuppressConfig,
 };
 
 use super::violations::try_create_violation;
@@ -22,8 +24,8 @@ use super::violations::try_create_violation;
 
 /// Trait for accessing suppress configuration fields uniformly.
 ///
-/// Implemented by GoSuppressConfig, ShellSuppressConfig, RubySuppressConfig,
-/// and SuppressConfig (used by JavaScript and Rust).
+/// Implemented by CppSuppressConfig, CSharpSuppressConfig, DeclarativeSuppressConfig, ElixirSuppressConfig, GoSuppressConfig, ShellSuppressConfig, RubySuppressConfig,
+/// JvmSuppressConfig, PhpSuppressConfig, SwiftSuppressConfig, and SuppressConfig (used by JavaScript and Rust).
 pub trait SuppressConfigAccess {
     /// Get the base check level.
     fn check(&self) -> SuppressLevel;
@@ -56,6 +58,96 @@ pub struct UnifiedSuppressDirective {
 // SuppressConfigAccess Implementations
 // =============================================================================
 
+impl SuppressConfigAccess for CppSuppressConfig {
+    fn check(&self) -> SuppressLevel {
+        self.check
+    }
+    fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+    fn source(&self) -> &SuppressScopeConfig {
+        &self.source
+    }
+    fn test(&self) -> &SuppressScopeConfig {
+        &self.test
+    }
+}
+
+impl SuppressConfigAccess for CSharpSuppressConfig {
+    fn check(&self) -> SuppressLevel {
+        self.check
+    }
+    fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+    fn source(&self) -> &SuppressScopeConfig {
+        &self.source
+    }
+    fn test(&self) -> &SuppressScopeConfig {
+        &self.test
+    }
+}
+
+impl SuppressConfigAccess for DeclarativeSuppressConfig {
+    fn check(&self) -> SuppressLevel {
+        self.check
+    }
+    fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+    fn source(&self) -> &SuppressScopeConfig {
+        &self.source
+    }
+    fn test(&self) -> &SuppressScopeConfig {
+        &self.test
+    }
+}
+
+impl SuppressConfigAccess for ElixirSuppressConfig {
+    fn check(&self) -> SuppressLevel {
+        self.check
+    }
+    fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+    fn source(&self) -> &SuppressScopeConfig {
+        &self.source
+    }
+    fn test(&self) -> &SuppressScopeConfig {
+        &self.test
+    }
+}
+
+impl SuppressConfigAccess for SwiftSuppressConfig {
+    fn check(&self) -> SuppressLevel {
+        self.check
+    }
+    fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+    fn source(&self) -> &SuppressScopeConfig {
+        &self.source
+    }
+    fn test(&self) -> &SuppressScopeConfig {
+        &self.test
+    }
+}
+
+impl SuppressConfigAccess for PhpSuppressConfig {
+    fn check(&self) -> SuppressLevel {
+        self.check
+    }
+    fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+    fn source(&self) -> &SuppressScopeConfig {
+        &self.source
+    }
+    fn test(&self) -> &SuppressScopeConfig {
+        &self.test
+    }
+}
+
 impl SuppressConfigAccess for GoSuppressConfig {
     fn check(&self) -> SuppressLevel {
         self.check
@@ -101,6 +193,21 @@ impl SuppressConfigAccess for RubySuppressConfig {
     }
 }
 
+impl SuppressConfigAccess for JvmSuppressConfig {
+    fn check(&self) -> SuppressLevel {
+        self.check
+    }
+    fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+    fn source(&self) -> &SuppressScopeConfig {
+        &self.source
+    }
+    fn test(&self) -> &SuppressScopeConfig {
+        &self.test
+    }
+}
+
 impl SuppressConfigAccess for PythonSuppressConfig {
     fn check(&self) -> SuppressLevel {
         self.check
@@ -468,6 +575,18 @@ pub fn build_suppress_missing_comment_advice(
             "javascript" => {
                 "Only if the lint is a false positive, add a comment above the directive or use inline reason (-- reason)."
             }
+            "cpp" => {
+                "Only if the lint is a false positive, add a comment above the directive or inline (// NOLINT(check): reason)."
+            }
+            "csharp" => {
+                "Only if the lint is a false positive, add a comment above the directive or set Justification = \"...\" on [SuppressMessage]."
+            }
+            "php" => {
+                "Only if the static analysis finding is a false positive, add a comment above the directive or inline (phpcs:ignore Sniff -- reason)."
+            }
+            "swift" => {
+                "Only if the lint is a false positive, add a comment above the directive or inline (// swiftlint:disable:next rule - reason)."
+            }
             "python" => {
                 "Only if the lint is a false positive, add a justification comment on the preceding line."
             }
@@ -79,6 +79,7 @@ pub(super) fn create_threshold_violation(
         scope: None,
         expected: None,
         found: None,
+        files: None,
     })
 }
 
@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Branch rules: naming pattern and scope limits.
+//!
+//! Checks the branch name against `[git.branch] pattern`, and flags
+//! branches whose commits use more than `max_scopes` conventional commit
+//! scopes or whose changes span more than `max_scopes` areas.
+
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+use git2::Repository;
+use globset::{Glob, GlobMatcher};
+use regex::Regex;
+
+use super::parse::{ParseResult, is_autosquash_commit, parse_conventional_commit};
+use crate::check::Violation;
+use crate::config::DocsAreaConfig;
+use crate::git::Commit;
+
+/// Name of the branch being checked: the source branch of a CI pull
+/// request, otherwise the checked out branch (None when detached).
+pub fn current_branch(root: &Path) -> Option<String> {
+    if let Some(branch) = crate::env::ci_branch() {
+        return Some(branch);
+    }
+    let repo = Repository::discover(root).ok()?;
+    let head = repo.head().ok()?;
+    if !head.is_branch() {
+        return None;
+    }
+    head.shorthand().map(String::from)
+}
+
+/// Check that `branch` matches `pattern`.
+pub fn check_branch_name(branch: &str, pattern: &Regex) -> Option<Violation> {
+    if pattern.is_match(branch) {
+        return None;
+    }
+    let advice = format!("Rename the branch to match `{pattern}`: git branch -m <new-name>");
+    Some(
+        Violation::project("invalid_branch_name", advice)
+            .with_expected_found(pattern.as_str(), branch),
+    )
+}
+
+/// Distinct conventional commit scopes used by `commits`.
+pub fn commit_scopes(commits: &[Commit]) -> BTreeSet<String> {
+    commits
+        .iter()
+        .filter(|commit| !is_autosquash_commit(&commit.message))
+        .filter_map(|commit| match parse_conventional_commit(&commit.message) {
+            ParseResult::Conventional(parsed) => parsed.scope,
+            ParseResult::NonConventional => None,
+        })
+        .collect()
+}
+
+/// Compiled `source` patterns of the `[check.docs.area]` entries that have one.
+pub fn area_sources(
+    areas: &HashMap<String, DocsAreaConfig>,
+) -> Result<Vec<(String, GlobMatcher)>, String> {
+    let mut sources = Vec::new();
+    for (name, area) in areas {
+        let Some(source) = &area.source else {
+            continue;
+        };
+        let glob = Glob::new(source).map_err(|e| format!("area `{name}`: {e}"))?;
+        sources.push((name.clone(), glob.compile_matcher()));
+    }
+    Ok(sources)
+}
+
+/// Distinct areas touched by `files`.
+///
+/// Areas are the `[check.docs.area]` entries with a `source` pattern (see
+/// [`area_sources`]) when any are configured, otherwise the `[project] packages`.
+pub fn changed_areas(
+    files: &[PathBuf],
+    root: &Path,
+    sources: &[(String, GlobMatcher)],
+    packages: &[String],
+) -> BTreeSet<String> {
+    let relative: Vec<&Path> =
+        files.iter().map(|file| file.strip_prefix(root).unwrap_or(file)).collect();
+
+    if !sources.is_empty() {
+        return sources
+            .iter()
+            .filter(|(_, matcher)| relative.iter().any(|file| matcher.is_match(file)))
+            .map(|(name, _)| name.clone())
+            .collect();
+    }
+
+    // The root package (".") contains everything, so it doesn't count
+    packages
+        .iter()
+        .filter(|pkg| pkg.as_str() != ".")
+        .filter(|pkg| relative.iter().any(|file| file.starts_with(pkg.as_str())))
+        .cloned()
+        .collect()
+}
+
+/// Check that a branch uses at most `max` scopes.
+pub fn check_scopes(scopes: &BTreeSet<String>, max: usize) -> Option<Violation> {
+    check_limit("too_many_scopes", "commit scopes", scopes, max)
+}
+
+/// Check that a branch changes at most `max` areas.
+pub fn check_areas(areas: &BTreeSet<String>, max: usize) -> Option<Violation> {
+    check_limit("too_many_areas", "areas", areas, max)
+}
+
+fn check_limit(
+    violation_type: &str,
+    what: &str,
+    names: &BTreeSet<String>,
+    max: usize,
+) -> Option<Violation> {
+    if names.len() <= max {
+        return None;
+    }
+    let names: Vec<&str> = names.iter().map(String::as_str).collect();
+    let advice = format!(
+        "This branch spans {} {what} ({}). Split it into smaller branches with at most {max} each.",
+        names.len(),
+        names.join(", ")
+    );
+    Some(Violation::project(violation_type, advice).with_threshold(names.len() as i64, max as i64))
+}
+
+#[cfg(test)]
+#[path = "branch_tests.rs"]
+mod tests;
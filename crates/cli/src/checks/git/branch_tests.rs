@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Unit tests for branch rules.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+
+fn commit(message: &str) -> Commit {
+    Commit {
+        hash: "abc1234".to_string(),
+        message: message.to_string(),
+        full_message: message.to_string(),
+        author: "Test User <test@example.com>".to_string(),
+    }
+}
+
+fn names(names: &[&str]) -> BTreeSet<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+fn files(root: &Path, paths: &[&str]) -> Vec<PathBuf> {
+    paths.iter().map(|path| root.join(path)).collect()
+}
+
+// =============================================================================
+// BRANCH NAME TESTS
+// =============================================================================
+
+#[test]
+fn branch_matching_pattern_passes() {
+    let pattern = Regex::new(r"^(feat|fix)/PROJ-\d+-[a-z0-9-]+$").unwrap();
+    assert!(check_branch_name("feat/PROJ-123-short-desc", &pattern).is_none());
+}
+
+#[test]
+fn branch_not_matching_pattern_is_violation() {
+    let pattern = Regex::new(r"^(feat|fix)/PROJ-\d+-[a-z0-9-]+$").unwrap();
+
+    let violation = check_branch_name("my-branch", &pattern).unwrap();
+
+    assert_eq!(violation.violation_type, "invalid_branch_name");
+    assert!(violation.file.is_none());
+    assert_eq!(violation.found.as_deref(), Some("my-branch"));
+    assert_eq!(violation.expected.as_deref(), Some(pattern.as_str()));
+}
+
+// =============================================================================
+// SCOPE TESTS
+// =============================================================================
+
+#[test]
+fn commit_scopes_are_distinct_conventional_scopes() {
+    let commits = [
+        commit("feat(api): add export"),
+        commit("fix(api): handle empty input"),
+        commit("docs(cli): document flag"),
+        commit("chore: update deps"),
+        commit("update stuff"),
+        commit("fixup! feat(core): tweak"),
+    ];
+    assert_eq!(commit_scopes(&commits), names(&["api", "cli"]));
+}
+
+#[test]
+fn scopes_over_max_is_violation() {
+    assert!(check_scopes(&names(&["api", "cli"]), 2).is_none());
+
+    let violation = check_scopes(&names(&["api", "cli", "core"]), 2).unwrap();
+
+    assert_eq!(violation.violation_type, "too_many_scopes");
+    assert_eq!((violation.value, violation.threshold), (Some(3), Some(2)));
+    assert!(violation.advice.contains("(api, cli, core)"));
+}
+
+// =============================================================================
+// AREA TESTS
+// =============================================================================
+
+#[test]
+fn areas_from_docs_area_sources() {
+    let root = Path::new("/repo");
+    let mut areas = HashMap::new();
+    for (name, source) in [("api", "src/api/**"), ("cli", "src/cli/**"), ("core", "src/core/**")] {
+        let area = DocsAreaConfig { docs: "docs/**".to_string(), source: Some(source.to_string()) };
+        areas.insert(name.to_string(), area);
+    }
+    areas.insert("guide".to_string(), DocsAreaConfig { docs: "docs/**".to_string(), source: None });
+    let changed = files(root, &["src/api/mod.rs", "src/cli/main.rs", "README.md"]);
+
+    let sources = area_sources(&areas).unwrap();
+    assert_eq!(sources.len(), 3);
+    assert_eq!(changed_areas(&changed, root, &sources, &[]), names(&["api", "cli"]));
+}
+
+#[test]
+fn invalid_area_source_is_error() {
+    let mut areas = HashMap::new();
+    let area = DocsAreaConfig { docs: "docs/**".to_string(), source: Some("src/{api".to_string()) };
+    areas.insert("api".to_string(), area);
+
+    let err = area_sources(&areas).unwrap_err();
+    assert!(err.starts_with("area `api`:"), "{err}");
+}
+
+#[test]
+fn areas_fall_back_to_packages() {
+    let root = Path::new("/repo");
+    let packages = ["crates/core".to_string(), "crates/cli".to_string(), ".".to_string()];
+    let changed = files(root, &["crates/cli/src/main.rs", "crates/cli/Cargo.toml", "README.md"]);
+
+    assert_eq!(changed_areas(&changed, root, &[], &packages), names(&["crates/cli"]));
+}
+
+#[test]
+fn areas_over_max_is_violation() {
+    let violation = check_areas(&names(&["api", "cli"]), 1).unwrap();
+
+    assert_eq!(violation.violation_type, "too_many_areas");
+    assert_eq!((violation.value, violation.threshold), (Some(2), Some(1)));
+}
@@ -9,21 +9,31 @@
 use std::path::Path;
 
 use git2::Repository;
+use globset::GlobMatcher;
+use regex::Regex;
 
 use crate::check::{Check, CheckContext, CheckResult, Violation};
 use crate::config::GitCommitConfig;
 use crate::git::{Commit, get_all_branch_commits, get_commits_since, is_git_repo};
 
+pub mod branch;
 pub mod docs;
 pub mod parse;
+pub mod rules;
 mod template;
 
 use template::{TEMPLATE_PATH, generate_template};
 
+use branch::{
+    area_sources, changed_areas, check_areas, check_branch_name, check_scopes, commit_scopes,
+    current_branch,
+};
 use docs::{DocsResult, check_commit_docs, primary_agent_file};
 pub use parse::{
-    DEFAULT_TYPES, ParseResult, ParsedCommit, is_merge_commit, parse_conventional_commit,
+    DEFAULT_TYPES, ParseResult, ParsedCommit, clean_message, is_autosquash_commit, is_merge_commit,
+    parse_conventional_commit,
 };
+use rules::{TrailerRule, check_rules, compile_trailers};
 
 /// The git check validates commit message format.
 pub struct GitCheck;
@@ -58,15 +68,65 @@ impl Check for GitCheck {
             return CheckResult::skipped(self.name(), "Check disabled");
         }
 
-        // Skip format validation if format = "none"
-        if config.effective_format() == "none" {
+        // Skip message validation if format = "none"
+        let check_messages = config.effective_format() != "none";
+
+        // Report invalid patterns once, before validating
+        if check_messages && let Err(e) = compile_trailers(config) {
+            return CheckResult::skipped(self.name(), format!("Invalid trailer pattern: {e}"));
+        }
+        let branch_pattern = match ctx.config.git.branch.pattern.as_deref().map(Regex::new) {
+            Some(Ok(pattern)) => Some(pattern),
+            Some(Err(e)) => {
+                return CheckResult::skipped(self.name(), format!("Invalid branch pattern: {e}"));
+            }
+            None => None,
+        };
+        let area_sources = match ctx.config.git.branch.max_scopes {
+            Some(_) => match area_sources(&ctx.config.check.docs.area) {
+                Ok(sources) => sources,
+                Err(e) => {
+                    return CheckResult::skipped(
+                        self.name(),
+                        format!("Invalid area source pattern: {e}"),
+                    );
+                }
+            },
+            None => Vec::new(),
+        };
+        if !check_messages && branch_pattern.is_none() && ctx.config.git.branch.max_scopes.is_none()
+        {
             return CheckResult::passed(self.name());
         }
 
         let mut violations = Vec::new();
 
+        // Commit-msg hook: validate the message being committed instead
+        if let Some(path) = ctx.commit_msg {
+            if !check_messages {
+                return CheckResult::passed(self.name());
+            }
+            let message = match std::fs::read_to_string(path) {
+                Ok(content) => clean_message(&content),
+                Err(e) => {
+                    return CheckResult::skipped(
+                        self.name(),
+                        format!("Cannot read commit message: {e}"),
+                    );
+                }
+            };
+            let file = path.strip_prefix(ctx.root).unwrap_or(path);
+            let author = if config.signoff { pending_author(ctx.root) } else { None };
+            validate_message_file(file, &message, author.as_deref(), config, &mut violations);
+            return if violations.is_empty() {
+                CheckResult::passed(self.name())
+            } else {
+                CheckResult::failed(self.name(), violations)
+            };
+        }
+
         // Check agent documentation (if enabled)
-        if config.agents {
+        if check_messages && config.agents {
             check_agent_docs(ctx.root, &mut violations);
         }
 
@@ -78,14 +138,19 @@ impl Check for GitCheck {
 
         // Validate each commit (if any)
         let mut validated_count = 0;
-        for commit in &commits {
+        for commit in commits.iter().filter(|_| check_messages) {
             if validate_commit(commit, config, &mut violations) {
                 validated_count += 1;
             }
         }
 
+        // Branch rules cover the whole branch (CI mode or --base)
+        if ctx.ci_mode || ctx.base_branch.is_some() {
+            check_branch(ctx, &commits, branch_pattern.as_ref(), &area_sources, &mut violations);
+        }
+
         // Handle --fix for template creation
-        let fix_summary = if ctx.fix && config.template {
+        let fix_summary = if ctx.fix && check_messages && config.template {
             fix_template(ctx.root, config, ctx.dry_run)
         } else {
             None
@@ -156,6 +221,36 @@ fn check_agent_docs(root: &Path, violations: &mut Vec<Violation>) {
     }
 }
 
+/// Check the branch name and the number of scopes and areas it spans.
+fn check_branch(
+    ctx: &CheckContext,
+    commits: &[Commit],
+    pattern: Option<&Regex>,
+    area_sources: &[(String, GlobMatcher)],
+    violations: &mut Vec<Violation>,
+) {
+    let config = &ctx.config.git.branch;
+
+    // The base branch itself isn't named by contributors
+    if let Some(pattern) = pattern
+        && let Some(name) = current_branch(ctx.root)
+        && ctx.base_branch.is_none_or(|base| base.trim_start_matches("origin/") != name)
+    {
+        violations.extend(check_branch_name(&name, pattern));
+    }
+
+    // Without a base, commits and changes span the whole history
+    let (Some(max), Some(_)) = (config.max_scopes, ctx.base_branch) else {
+        return;
+    };
+    violations.extend(check_scopes(&commit_scopes(commits), max));
+    if let Some(files) = ctx.changed_files {
+        let packages = &ctx.config.project.packages;
+        let areas = changed_areas(files, ctx.root, area_sources, packages);
+        violations.extend(check_areas(&areas, max));
+    }
+}
+
 /// Get commits to validate based on context.
 fn get_commits_to_check(ctx: &CheckContext) -> anyhow::Result<Vec<Commit>> {
     // Staged mode: no commit message to check yet
@@ -182,46 +277,103 @@ pub fn validate_commit(
     config: &GitCommitConfig,
     violations: &mut Vec<Violation>,
 ) -> bool {
+    let message = Message {
+        subject: &commit.message,
+        full: &commit.full_message,
+        author: Some(&commit.author),
+    };
+    validate_message(&message, config, violations, |violation_type, advice| {
+        Violation::commit_violation(&commit.hash, &commit.message, violation_type, advice)
+    })
+}
+
+/// Validate the message file of a commit being created (`--commit-msg`).
+///
+/// `message` is the cleaned file content, checked like committed messages.
+/// Autosquash commits are skipped.
+pub fn validate_message_file(
+    file: &Path,
+    message: &str,
+    author: Option<&str>,
+    config: &GitCommitConfig,
+    violations: &mut Vec<Violation>,
+) -> bool {
+    if is_autosquash_commit(message) {
+        return false; // Squashed away before landing
+    }
+    let subject = message.lines().next().unwrap_or_default();
+    let message = Message { subject, full: message, author };
+    validate_message(&message, config, violations, |violation_type, advice| {
+        let mut violation = Violation::file_only(file, violation_type, advice);
+        violation.message = Some(subject.to_string());
+        violation
+    })
+}
+
+/// A commit message to validate.
+struct Message<'a> {
+    /// Subject line.
+    subject: &'a str,
+    /// Complete message, including the body and trailers.
+    full: &'a str,
+    /// Author as `Name <email>`, when known.
+    author: Option<&'a str>,
+}
+
+/// Author of the commit being created: git exports it to hooks, otherwise
+/// it comes from the git config.
+fn pending_author(root: &Path) -> Option<String> {
+    if let Some(author) = crate::env::git_author() {
+        return Some(author);
+    }
+    let repo = Repository::discover(root).ok()?;
+    let signature = repo.signature().ok()?;
+    Some(signature.to_string())
+}
+
+/// Validate a commit message, creating violations with `violation`.
+///
+/// Returns `true` if the message was validated, `false` if skipped.
+fn validate_message(
+    message: &Message,
+    config: &GitCommitConfig,
+    violations: &mut Vec<Violation>,
+    violation: impl Fn(&str, String) -> Violation,
+) -> bool {
+    let subject = message.subject;
+
     // Skip merge commits if configured
-    if config.skip_merge && is_merge_commit(&commit.message) {
+    if config.skip_merge && is_merge_commit(subject) {
         return false; // Skipped
     }
 
     // Handle empty commit messages specially
-    if commit.message.trim().is_empty() {
-        violations.push(Violation::commit_violation(
-            &commit.hash,
-            &commit.message,
+    if subject.trim().is_empty() {
+        violations.push(violation(
             "empty_message",
-            "Commit message cannot be empty. Use format: <type>(<scope>): <description>",
+            "Commit message cannot be empty. Use format: <type>(<scope>): <description>"
+                .to_string(),
         ));
         return true;
     }
 
-    match parse_conventional_commit(&commit.message) {
+    let parsed = match parse_conventional_commit(subject) {
         ParseResult::NonConventional => {
-            violations.push(Violation::commit_violation(
-                &commit.hash,
-                &commit.message,
+            violations.push(violation(
                 "invalid_format",
-                "Expected: <type>(<scope>): <description>",
+                "Expected: <type>(<scope>): <description>".to_string(),
             ));
+            None
         }
         ParseResult::Conventional(parsed) => {
             // Check type
             let allowed_types = config.types.as_deref();
             if !parsed.is_type_allowed(allowed_types) {
-                let advice = format_type_advice(allowed_types);
-                let mut violation = Violation::commit_violation(
-                    &commit.hash,
-                    &commit.message,
-                    "invalid_type",
-                    advice,
-                );
+                let mut v = violation("invalid_type", format_type_advice(allowed_types));
                 if let Some(ref scope) = parsed.scope {
-                    violation = violation.with_scope(scope.clone());
+                    v = v.with_scope(scope.clone());
                 }
-                violations.push(violation);
+                violations.push(v);
             }
 
             // Check scope (only if scopes are configured)
@@ -229,18 +381,24 @@ pub fn validate_commit(
                 && !parsed.is_scope_allowed(Some(scopes))
             {
                 let advice = format!("Allowed scopes: {}", scopes.join(", "));
-                let mut violation = Violation::commit_violation(
-                    &commit.hash,
-                    &commit.message,
-                    "invalid_scope",
-                    advice,
-                );
+                let mut v = violation("invalid_scope", advice);
                 if let Some(ref scope) = parsed.scope {
-                    violation = violation.with_scope(scope.clone());
+                    v = v.with_scope(scope.clone());
                 }
-                violations.push(violation);
+                violations.push(v);
             }
+            Some(parsed)
+        }
+    };
+
+    // Invalid patterns are reported by the check before validating messages
+    let trailers: Vec<TrailerRule> = compile_trailers(config).unwrap_or_default();
+    for rule in check_rules(message.full, parsed.as_ref(), message.author, config, &trailers) {
+        let mut v = violation(rule.violation_type, rule.advice);
+        if let Some((value, threshold)) = rule.threshold {
+            v = v.with_threshold(value, threshold);
         }
+        violations.push(v);
     }
 
     true // Validated
@@ -16,7 +16,12 @@ use crate::git::Commit;
 
 /// Create a test commit with the given hash and message.
 fn test_commit(hash: &str, message: &str) -> Commit {
-    Commit { hash: hash.to_string(), message: message.to_string() }
+    Commit {
+        hash: hash.to_string(),
+        message: message.to_string(),
+        full_message: message.to_string(),
+        author: "Test User <test@example.com>".to_string(),
+    }
 }
 
 // =============================================================================
@@ -401,3 +406,109 @@ fn validates_multiple_commits_with_different_violations() {
     // Third commit: invalid_type (1 violation)
     assert_eq!(violations.len(), 2);
 }
+
+// =============================================================================
+// COMMIT MESSAGE FILE TESTS
+// =============================================================================
+
+#[test]
+fn message_file_violation_points_at_file() {
+    let config = GitCommitConfig::default();
+    let mut violations = Vec::new();
+
+    let file = Path::new(".git/COMMIT_EDITMSG");
+    validate_message_file(file, "update stuff\n\nMore detail.", None, &config, &mut violations);
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].violation_type, "invalid_format");
+    assert_eq!(violations[0].file.as_deref(), Some(file));
+    assert_eq!(violations[0].message.as_deref(), Some("update stuff"));
+    assert!(violations[0].commit.is_none());
+}
+
+#[test]
+fn message_file_checks_configured_scopes() {
+    let mut config = GitCommitConfig::default();
+    config.scopes = Some(vec!["api".to_string()]);
+    let mut violations = Vec::new();
+
+    let file = Path::new(".git/COMMIT_EDITMSG");
+    validate_message_file(file, "feat(cli): add flag", None, &config, &mut violations);
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].violation_type, "invalid_scope");
+    assert_eq!(violations[0].scope.as_deref(), Some("cli"));
+}
+
+#[test]
+fn message_file_empty_is_violation() {
+    let config = GitCommitConfig::default();
+    let mut violations = Vec::new();
+
+    validate_message_file(Path::new("msg"), "", None, &config, &mut violations);
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].violation_type, "empty_message");
+}
+
+#[test]
+fn message_file_skips_autosquash_commits() {
+    let config = GitCommitConfig::default();
+    let mut violations = Vec::new();
+
+    let validated =
+        validate_message_file(Path::new("msg"), "fixup! feat: add", None, &config, &mut violations);
+
+    assert!(!validated);
+    assert!(violations.is_empty());
+}
+
+// =============================================================================
+// MESSAGE RULE TESTS
+// =============================================================================
+
+#[test]
+fn rule_violations_reported_for_commits() {
+    let mut config = GitCommitConfig::default();
+    config.subject_max = Some(10);
+    config.signoff = true;
+    let commit = test_commit("abc123", "feat: add a long subject");
+    let mut violations = Vec::new();
+
+    validate_commit(&commit, &config, &mut violations);
+
+    let types: Vec<_> = violations.iter().map(|v| v.violation_type.as_str()).collect();
+    assert_eq!(types, ["subject_too_long", "missing_signoff"]);
+    assert_eq!(violations[0].commit.as_deref(), Some("abc123"));
+    assert_eq!(violations[0].value, Some(24));
+    assert_eq!(violations[0].threshold, Some(10));
+}
+
+#[test]
+fn rule_violations_reported_alongside_invalid_format() {
+    let mut config = GitCommitConfig::default();
+    config.imperative = true;
+    let mut violations = Vec::new();
+
+    validate_message_file(Path::new("msg"), "Added stuff", None, &config, &mut violations);
+
+    let types: Vec<_> = violations.iter().map(|v| v.violation_type.as_str()).collect();
+    assert_eq!(types, ["invalid_format", "non_imperative_subject"]);
+}
+
+#[test]
+fn signoff_checked_against_pending_author() {
+    let mut config = GitCommitConfig::default();
+    config.signoff = true;
+    let author = Some("Ann <ann@example.com>");
+    let mut violations = Vec::new();
+
+    let message = "fix: typo\n\nSigned-off-by: Ann <ann@example.com>";
+    validate_message_file(Path::new("msg"), message, author, &config, &mut violations);
+    assert!(violations.is_empty());
+
+    let message = "fix: typo\n\nSigned-off-by: Bob <bob@example.com>";
+    validate_message_file(Path::new("msg"), message, author, &config, &mut violations);
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].violation_type, "missing_signoff");
+}
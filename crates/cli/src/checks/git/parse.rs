@@ -16,14 +16,25 @@ use regex::Regex;
 /// - Group 1: type (required)
 /// - Group 2: scope with parens (optional)
 /// - Group 3: scope without parens (optional)
-/// - Group 4: description (required)
+/// - Group 4: breaking change marker (empty if absent)
+/// - Group 5: description (required)
 ///
 /// Supports breaking change marker `!` before the colon:
 /// - `feat!: breaking change`
 /// - `feat(api)!: breaking change`
 #[allow(clippy::expect_used)]
 static CONVENTIONAL_COMMIT: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^([a-z]+)(\(([^)]+)\))?!?:\s*(.+)$").expect("valid regex"));
+    LazyLock::new(|| Regex::new(r"^([a-z]+)(\(([^)]+)\))?(!?):\s*(.+)$").expect("valid regex"));
+
+/// Pattern for a trailer line: `Key: value`.
+///
+/// Captures:
+/// - Group 1: key (a token, or `BREAKING CHANGE`)
+/// - Group 2: value
+#[allow(clippy::expect_used)]
+static TRAILER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^([A-Za-z][A-Za-z0-9-]*|BREAKING CHANGE):\s+(.+)$").expect("valid regex")
+});
 
 /// Default conventional commit types.
 pub const DEFAULT_TYPES: &[&str] =
@@ -38,6 +49,8 @@ pub struct ParsedCommit {
     pub scope: Option<String>,
     /// Commit description.
     pub description: String,
+    /// Whether the type is marked breaking (`feat!:`).
+    pub breaking: bool,
 }
 
 impl ParsedCommit {
@@ -94,6 +107,28 @@ pub fn is_merge_commit(message: &str) -> bool {
     message.starts_with("Merge ")
 }
 
+/// Check if a commit message will be squashed by `git rebase --autosquash`.
+///
+/// Detects `fixup!`, `squash!` and `amend!` subjects created by
+/// `git commit --fixup` and `--squash`.
+pub fn is_autosquash_commit(message: &str) -> bool {
+    ["fixup! ", "squash! ", "amend! "].iter().any(|prefix| message.starts_with(prefix))
+}
+
+/// Clean a commit message file the way git does before committing.
+///
+/// Removes `#` comment lines and everything below the scissors line
+/// (`# ---- >8 ----`, written by `git commit --verbose`), then trims
+/// surrounding blank lines.
+pub fn clean_message(content: &str) -> String {
+    let lines: Vec<&str> = content
+        .lines()
+        .take_while(|line| !(line.starts_with("# ") && line.contains(">8")))
+        .filter(|line| !line.starts_with('#'))
+        .collect();
+    lines.join("\n").trim().to_string()
+}
+
 /// Parse a commit message as a conventional commit.
 ///
 /// Returns `ParseResult::Conventional` if the message matches the format,
@@ -115,8 +150,8 @@ pub fn parse_conventional_commit(message: &str) -> ParseResult {
         return ParseResult::NonConventional;
     };
 
-    // Groups 1 and 4 are always present when the regex matches
-    let (Some(commit_type), Some(description)) = (caps.get(1), caps.get(4)) else {
+    // Groups 1 and 5 are always present when the regex matches
+    let (Some(commit_type), Some(description)) = (caps.get(1), caps.get(5)) else {
         return ParseResult::NonConventional;
     };
 
@@ -126,6 +161,63 @@ pub fn parse_conventional_commit(message: &str) -> ParseResult {
         commit_type: commit_type.as_str().to_string(),
         scope,
         description: description.as_str().to_string(),
+        breaking: caps.get(4).is_some_and(|m| !m.as_str().is_empty()),
+    })
+}
+
+/// A `Key: value` trailer at the end of a commit message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trailer {
+    pub key: String,
+    pub value: String,
+}
+
+/// Parse the trailers of a full commit message.
+///
+/// Trailers are the last paragraph after the subject when every line is a
+/// `Key: value` trailer (indented lines continue the previous value), like
+/// `git interpret-trailers`. `BREAKING CHANGE` is accepted as a key.
+pub fn parse_trailers(message: &str) -> Vec<Trailer> {
+    let paragraphs: Vec<Vec<&str>> = message
+        .lines()
+        .collect::<Vec<_>>()
+        .split(|line| line.trim().is_empty())
+        .filter(|paragraph| !paragraph.is_empty())
+        .map(<[&str]>::to_vec)
+        .collect();
+    let [_subject, .., last] = paragraphs.as_slice() else {
+        return Vec::new();
+    };
+
+    let mut trailers: Vec<Trailer> = Vec::new();
+    for line in last {
+        if line.starts_with([' ', '\t'])
+            && let Some(trailer) = trailers.last_mut()
+        {
+            trailer.value.push(' ');
+            trailer.value.push_str(line.trim());
+            continue;
+        }
+        let Some(caps) = TRAILER.captures(line) else {
+            return Vec::new();
+        };
+        trailers.push(Trailer { key: caps[1].to_string(), value: caps[2].trim().to_string() });
+    }
+    trailers
+}
+
+/// Keys of the conventional commits breaking change footer.
+const BREAKING_KEYS: &[&str] = &["BREAKING CHANGE", "BREAKING-CHANGE"];
+
+/// Check if a full commit message has a `BREAKING CHANGE:` footer.
+///
+/// Footers may follow the body without forming a trailer block, so any line
+/// after the subject counts.
+pub fn has_breaking_footer(message: &str) -> bool {
+    message.lines().skip(1).any(|line| {
+        BREAKING_KEYS
+            .iter()
+            .any(|key| line.strip_prefix(key).is_some_and(|rest| rest.starts_with(": ")))
     })
 }
 
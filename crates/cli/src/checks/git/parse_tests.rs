@@ -242,3 +242,107 @@ fn does_not_detect_conventional_commit() {
 fn does_not_detect_message_containing_merge() {
     assert!(!is_merge_commit("fix: merge conflict in parser"));
 }
+
+// =============================================================================
+// MESSAGE FILE TESTS
+// =============================================================================
+
+#[test]
+fn clean_message_strips_comments() {
+    let content = "feat: add hooks\n\nBody text.\n# Please enter the commit message\n#\n";
+    assert_eq!(clean_message(content), "feat: add hooks\n\nBody text.");
+}
+
+#[test]
+fn clean_message_stops_at_scissors_line() {
+    let content =
+        "fix: typo\n# ------------------------ >8 ------------------------\ndiff --git a/x b/x\n";
+    assert_eq!(clean_message(content), "fix: typo");
+}
+
+#[test]
+fn clean_message_of_comments_only_is_empty() {
+    assert_eq!(clean_message("\n# Please enter the commit message\n"), "");
+}
+
+#[test]
+fn detects_autosquash_commits() {
+    assert!(is_autosquash_commit("fixup! feat: add hooks"));
+    assert!(is_autosquash_commit("squash! fix: typo"));
+    assert!(is_autosquash_commit("amend! fix: typo"));
+    assert!(!is_autosquash_commit("fix: fixup! handling"));
+}
+
+// =============================================================================
+// BREAKING CHANGE AND TRAILER TESTS
+// =============================================================================
+
+#[test]
+fn parses_breaking_change_marker() {
+    for message in ["feat!: drop v1 api", "feat(api)!: drop v1 api"] {
+        let ParseResult::Conventional(parsed) = parse_conventional_commit(message) else {
+            panic!("expected Conventional for {message}");
+        };
+        assert!(parsed.breaking, "{message} should be breaking");
+        assert_eq!(parsed.description, "drop v1 api");
+    }
+}
+
+#[test]
+fn non_breaking_commit_has_no_marker() {
+    let ParseResult::Conventional(parsed) = parse_conventional_commit("feat(api): add") else {
+        panic!("expected Conventional");
+    };
+    assert!(!parsed.breaking);
+}
+
+#[test]
+fn parses_trailers_from_last_paragraph() {
+    let message = "feat: add hooks\n\nBody text.\n\nRefs: TICKET-123\nSigned-off-by: A <a@x.org>\n";
+    let trailers = parse_trailers(message);
+    assert_eq!(
+        trailers,
+        vec![
+            Trailer { key: "Refs".to_string(), value: "TICKET-123".to_string() },
+            Trailer { key: "Signed-off-by".to_string(), value: "A <a@x.org>".to_string() },
+        ]
+    );
+}
+
+#[test]
+fn parses_breaking_change_trailer_with_continuation() {
+    let message = "feat!: drop v1\n\nBREAKING CHANGE: the v1 api\n  is removed\n";
+    let trailers = parse_trailers(message);
+    assert_eq!(trailers.len(), 1);
+    assert_eq!(trailers[0].key, "BREAKING CHANGE");
+    assert_eq!(trailers[0].value, "the v1 api is removed");
+}
+
+#[test]
+fn subject_alone_has_no_trailers() {
+    assert!(parse_trailers("fix: Refs: TICKET-1").is_empty());
+}
+
+#[test]
+fn body_paragraph_is_not_trailers() {
+    let message = "fix: typo\n\nThis fixes a typo.\nRefs: TICKET-1\n";
+    assert!(parse_trailers(message).is_empty());
+}
+
+// =============================================================================
+// BREAKING FOOTER TESTS
+// =============================================================================
+
+#[test]
+fn detects_breaking_change_footer() {
+    assert!(has_breaking_footer("feat!: drop v1\n\nBREAKING CHANGE: v1 is removed"));
+    assert!(has_breaking_footer("feat: drop v1\n\nDetails.\nBREAKING-CHANGE: v1 is removed"));
+}
+
+#[test]
+fn breaking_change_footer_needs_key_and_colon() {
+    assert!(!has_breaking_footer("feat!: drop v1"));
+    assert!(!has_breaking_footer("BREAKING CHANGE: in subject"));
+    assert!(!has_breaking_footer("feat: drop v1\n\nThis is a BREAKING CHANGE: sorry"));
+    assert!(!has_breaking_footer("feat: drop v1\n\nBREAKING CHANGE"));
+}
@@ -0,0 +1,235 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Commit message rules beyond the conventional format.
+//!
+//! Checks the subject length and mood, the body layout, required trailers,
+//! the DCO sign-off, and the footer of breaking changes. Every rule is off
+//! unless configured in `[git.commit]`.
+
+use regex::Regex;
+
+use super::parse::{ParsedCommit, Trailer, has_breaking_footer, parse_trailers};
+use crate::config::GitCommitConfig;
+
+/// Common commit verbs, to recognize their third-person forms (`adds`).
+const VERBS: &[&str] = &[
+    "add",
+    "allow",
+    "apply",
+    "bump",
+    "change",
+    "clean",
+    "copy",
+    "create",
+    "delete",
+    "disable",
+    "document",
+    "drop",
+    "enable",
+    "ensure",
+    "extract",
+    "fix",
+    "handle",
+    "implement",
+    "improve",
+    "introduce",
+    "make",
+    "modify",
+    "move",
+    "prevent",
+    "refactor",
+    "remove",
+    "rename",
+    "replace",
+    "set",
+    "show",
+    "simplify",
+    "support",
+    "update",
+    "use",
+    "verify",
+];
+
+/// Words ending in `-ed` or `-ing` that are not past tense or progressive.
+const NOT_INFLECTED: &[&str] = &[
+    "bleed", "breed", "embed", "exceed", "feed", "need", "proceed", "seed", "shed", "shred",
+    "speed", "succeed", "bring", "cling", "fling", "sling", "spring", "sting", "string", "swing",
+    "thing", "wring",
+];
+
+/// A required trailer and the pattern its value must match.
+#[derive(Debug)]
+pub struct TrailerRule {
+    pub key: String,
+    pub pattern: Regex,
+}
+
+/// Compile the `trailers` patterns from the config.
+pub fn compile_trailers(config: &GitCommitConfig) -> Result<Vec<TrailerRule>, regex::Error> {
+    config
+        .trailers
+        .iter()
+        .map(|(key, pattern)| Ok(TrailerRule { key: key.clone(), pattern: Regex::new(pattern)? }))
+        .collect()
+}
+
+/// A commit message that breaks a rule.
+#[derive(Debug, PartialEq, Eq)]
+pub struct RuleViolation {
+    pub violation_type: &'static str,
+    pub advice: String,
+    /// Measured value and limit, for length rules.
+    pub threshold: Option<(i64, i64)>,
+}
+
+impl RuleViolation {
+    fn new(violation_type: &'static str, advice: String) -> Self {
+        Self { violation_type, advice, threshold: None }
+    }
+}
+
+/// Check a complete commit message against the configured rules.
+///
+/// `parsed` is the conventional subject, if it parsed; `author` is the
+/// commit author as `Name <email>`, when known.
+pub fn check_rules(
+    message: &str,
+    parsed: Option<&ParsedCommit>,
+    author: Option<&str>,
+    config: &GitCommitConfig,
+    trailers: &[TrailerRule],
+) -> Vec<RuleViolation> {
+    let mut violations = Vec::new();
+    let mut lines = message.lines();
+    let subject = lines.next().unwrap_or_default();
+
+    if let Some(max) = config.subject_max {
+        let len = subject.chars().count();
+        if len > max {
+            let mut v = RuleViolation::new(
+                "subject_too_long",
+                format!(
+                    "Shorten the subject to {max} characters or less; move details to the body."
+                ),
+            );
+            v.threshold = Some((len as i64, max as i64));
+            violations.push(v);
+        }
+    }
+
+    if config.imperative {
+        let description = parsed.map_or(subject, |p| p.description.as_str());
+        if let Some(word) = non_imperative_word(description) {
+            violations.push(RuleViolation::new(
+                "non_imperative_subject",
+                format!(
+                    "Write the subject in the imperative mood, as a command: \
+                     \"add feature\", not \"added\" or \"adds feature\" (found `{word}`)."
+                ),
+            ));
+        }
+    }
+
+    if config.body_blank_line && lines.next().is_some_and(|line| !line.trim().is_empty()) {
+        violations.push(RuleViolation::new(
+            "missing_blank_line",
+            "Separate the subject from the body with a blank line.".to_string(),
+        ));
+    }
+
+    if let Some(wrap) = config.body_wrap {
+        // Lines without spaces (URLs, paths) can't be wrapped
+        let long = message.lines().enumerate().skip(1).find(|(_, line)| {
+            line.chars().count() > wrap && line.trim().contains(char::is_whitespace)
+        });
+        if let Some((idx, line)) = long {
+            let mut v = RuleViolation::new(
+                "body_line_too_long",
+                format!("Wrap body lines at {wrap} characters (line {} of the message).", idx + 1),
+            );
+            v.threshold = Some((line.chars().count() as i64, wrap as i64));
+            violations.push(v);
+        }
+    }
+
+    let found = parse_trailers(message);
+
+    for rule in trailers {
+        let matched = trailer_values(&found, &rule.key).any(|value| rule.pattern.is_match(value));
+        if !matched {
+            violations.push(RuleViolation::new(
+                "missing_trailer",
+                format!(
+                    "Add a `{}: <value>` trailer matching `{}` in the last paragraph of the message.",
+                    rule.key, rule.pattern
+                ),
+            ));
+        }
+    }
+
+    if config.signoff {
+        let email = author.and_then(email_of);
+        let signed = trailer_values(&found, "Signed-off-by")
+            .any(|value| email.is_none() || email_of(value) == email);
+        if !signed {
+            let signer = author.unwrap_or("Your Name <you@example.com>");
+            violations.push(RuleViolation::new(
+                "missing_signoff",
+                format!(
+                    "Add `Signed-off-by: {signer}` to certify the Developer Certificate of Origin \
+                     (use `git commit -s`)."
+                ),
+            ));
+        }
+    }
+
+    if config.breaking_footer && parsed.is_some_and(|p| p.breaking) && !has_breaking_footer(message)
+    {
+        violations.push(RuleViolation::new(
+            "missing_breaking_change",
+            "Breaking changes (`!`) need a `BREAKING CHANGE: <description>` footer \
+             describing the impact and how to migrate."
+                .to_string(),
+        ));
+    }
+
+    violations
+}
+
+/// Values of the trailers with `key` (case-insensitive, like git).
+fn trailer_values<'a>(trailers: &'a [Trailer], key: &'a str) -> impl Iterator<Item = &'a str> {
+    trailers.iter().filter(move |t| t.key.eq_ignore_ascii_case(key)).map(|t| t.value.as_str())
+}
+
+/// Lowercased email in `Name <email>`.
+fn email_of(ident: &str) -> Option<String> {
+    let (_, rest) = ident.split_once('<')?;
+    let (email, _) = rest.split_once('>')?;
+    Some(email.trim().to_lowercase())
+}
+
+/// First word of `description` when it is past tense (`added`), progressive
+/// (`adding`) or third person (`adds`) rather than imperative (`add`).
+///
+/// A heuristic: only suffixes and a list of common verbs are checked.
+pub fn non_imperative_word(description: &str) -> Option<&str> {
+    let word = description.split_whitespace().next()?;
+    let lower = word.to_lowercase();
+    if !lower.chars().all(|c| c.is_ascii_alphabetic()) || NOT_INFLECTED.contains(&lower.as_str()) {
+        return None;
+    }
+
+    let past = lower.len() > 3 && lower.ends_with("ed");
+    let progressive = lower.len() > 4 && lower.ends_with("ing");
+    let third_person = [("ies", "y"), ("es", ""), ("s", "")].iter().any(|(suffix, stem)| {
+        lower
+            .strip_suffix(suffix)
+            .is_some_and(|base| VERBS.contains(&format!("{base}{stem}").as_str()))
+    });
+    (past || progressive || third_person).then_some(word)
+}
+
+#[cfg(test)]
+#[path = "rules_tests.rs"]
+mod tests;
@@ -0,0 +1,186 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Unit tests for commit message rules.
+
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::field_reassign_with_default
+)]
+
+use super::*;
+use crate::checks::git::parse::{ParseResult, parse_conventional_commit};
+
+const AUTHOR: &str = "Test User <test@example.com>";
+
+/// Violation types for `message` with `config`.
+fn violation_types(message: &str, config: &GitCommitConfig) -> Vec<&'static str> {
+    let parsed = match parse_conventional_commit(message.lines().next().unwrap_or_default()) {
+        ParseResult::Conventional(parsed) => Some(parsed),
+        ParseResult::NonConventional => None,
+    };
+    let trailers = compile_trailers(config).unwrap();
+    check_rules(message, parsed.as_ref(), Some(AUTHOR), config, &trailers)
+        .into_iter()
+        .map(|v| v.violation_type)
+        .collect()
+}
+
+#[test]
+fn default_config_has_no_rules() {
+    let message = "feat!: Added a very long subject line that goes on and on and on and on and on\n\
+                   body right after the subject";
+    assert!(violation_types(message, &GitCommitConfig::default()).is_empty());
+}
+
+// =============================================================================
+// SUBJECT TESTS
+// =============================================================================
+
+#[test]
+fn subject_over_max_is_violation() {
+    let mut config = GitCommitConfig::default();
+    config.subject_max = Some(20);
+
+    let parsed = None;
+    let rules = check_rules("fix: a subject that is too long", parsed, None, &config, &[]);
+
+    assert_eq!(rules.len(), 1);
+    assert_eq!(rules[0].violation_type, "subject_too_long");
+    assert_eq!(rules[0].threshold, Some((31, 20)));
+}
+
+#[test]
+fn subject_at_max_passes() {
+    let mut config = GitCommitConfig::default();
+    config.subject_max = Some(14);
+    assert!(violation_types("fix: it works\n\nBody is not counted.", &config).is_empty());
+}
+
+#[test]
+fn non_imperative_subject_is_violation() {
+    let mut config = GitCommitConfig::default();
+    config.imperative = true;
+    assert_eq!(violation_types("feat: added hooks", &config), ["non_imperative_subject"]);
+    assert!(violation_types("feat: add hooks", &config).is_empty());
+}
+
+#[test]
+fn detects_non_imperative_words() {
+    for description in ["added hooks", "Fixed typo", "adding hooks", "updates docs", "fixes bug"] {
+        assert!(non_imperative_word(description).is_some(), "{description} is not imperative");
+    }
+    for description in ["add hooks", "embed fonts", "bring back", "process files", "use v2"] {
+        assert!(non_imperative_word(description).is_none(), "{description} is imperative");
+    }
+}
+
+// =============================================================================
+// BODY TESTS
+// =============================================================================
+
+#[test]
+fn body_without_blank_line_is_violation() {
+    let mut config = GitCommitConfig::default();
+    config.body_blank_line = true;
+    assert_eq!(violation_types("fix: typo\nin the readme", &config), ["missing_blank_line"]);
+    assert!(violation_types("fix: typo\n\nIn the readme.", &config).is_empty());
+    assert!(violation_types("fix: typo", &config).is_empty());
+}
+
+#[test]
+fn body_line_over_wrap_is_violation() {
+    let mut config = GitCommitConfig::default();
+    config.body_wrap = Some(20);
+
+    let message = "fix: typo\n\nshort line\nthis line is longer than twenty\n";
+    let rules = check_rules(message, None, None, &config, &[]);
+
+    assert_eq!(rules.len(), 1);
+    assert_eq!(rules[0].violation_type, "body_line_too_long");
+    assert_eq!(rules[0].threshold, Some((31, 20)));
+    assert!(rules[0].advice.contains("line 4"));
+}
+
+#[test]
+fn body_wrap_skips_unbreakable_lines() {
+    let mut config = GitCommitConfig::default();
+    config.body_wrap = Some(20);
+    let message = "fix: typo\n\nhttps://example.com/a/very/long/url/to/an/issue\n";
+    assert!(violation_types(message, &config).is_empty());
+}
+
+// =============================================================================
+// TRAILER TESTS
+// =============================================================================
+
+#[test]
+fn missing_required_trailer_is_violation() {
+    let mut config = GitCommitConfig::default();
+    config.trailers.insert("Refs".to_string(), r"^TICKET-\d+$".to_string());
+
+    assert_eq!(violation_types("fix: typo", &config), ["missing_trailer"]);
+    assert_eq!(violation_types("fix: typo\n\nRefs: other-1", &config), ["missing_trailer"]);
+    assert!(violation_types("fix: typo\n\nRefs: TICKET-123", &config).is_empty());
+}
+
+#[test]
+fn trailer_keys_match_case_insensitively() {
+    let mut config = GitCommitConfig::default();
+    config.trailers.insert("Refs".to_string(), r"^TICKET-\d+$".to_string());
+    assert!(violation_types("fix: typo\n\nrefs: TICKET-7", &config).is_empty());
+}
+
+#[test]
+fn invalid_trailer_pattern_fails_to_compile() {
+    let mut config = GitCommitConfig::default();
+    config.trailers.insert("Refs".to_string(), "(".to_string());
+    assert!(compile_trailers(&config).is_err());
+}
+
+#[test]
+fn signoff_must_match_author() {
+    let mut config = GitCommitConfig::default();
+    config.signoff = true;
+
+    assert_eq!(violation_types("fix: typo", &config), ["missing_signoff"]);
+    let other = "fix: typo\n\nSigned-off-by: Someone Else <else@example.com>";
+    assert_eq!(violation_types(other, &config), ["missing_signoff"]);
+    let signed = "fix: typo\n\nSigned-off-by: Test User <Test@Example.com>";
+    assert!(violation_types(signed, &config).is_empty());
+}
+
+#[test]
+fn signoff_advice_names_author() {
+    let mut config = GitCommitConfig::default();
+    config.signoff = true;
+
+    let rules = check_rules("fix: typo", None, Some(AUTHOR), &config, &[]);
+
+    assert!(rules[0].advice.contains("Signed-off-by: Test User <test@example.com>"));
+}
+
+// =============================================================================
+// BREAKING CHANGE TESTS
+// =============================================================================
+
+#[test]
+fn breaking_commit_without_footer_is_violation() {
+    let mut config = GitCommitConfig::default();
+    config.breaking_footer = true;
+
+    assert_eq!(violation_types("feat(api)!: drop v1", &config), ["missing_breaking_change"]);
+    let footer = "feat(api)!: drop v1\n\nBREAKING CHANGE: v1 endpoints are removed.";
+    assert!(violation_types(footer, &config).is_empty());
+    let hyphen = "feat!: drop v1\n\nDetails.\n\nBREAKING-CHANGE: v1 is gone.\nRefs: T-1";
+    assert!(violation_types(hyphen, &config).is_empty());
+}
+
+#[test]
+fn non_breaking_commit_needs_no_footer() {
+    let mut config = GitCommitConfig::default();
+    config.breaking_footer = true;
+    assert!(violation_types("feat(api): add v2", &config).is_empty());
+}
@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust dependencies from `Cargo.lock` and the local registry cache.
+
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use super::{Dependency, classify};
+
+#[derive(Deserialize)]
+struct Lockfile {
+    #[serde(default)]
+    package: Vec<LockedPackage>,
+}
+
+#[derive(Deserialize)]
+struct LockedPackage {
+    name: String,
+    version: String,
+    source: Option<String>,
+    #[serde(default)]
+    dependencies: Vec<String>,
+}
+
+/// Read third-party packages from `Cargo.lock`, resolving licenses from
+/// `$CARGO_HOME/registry/src`.
+///
+/// Workspace members (packages without a `source`) are the roots of each
+/// dependency path and are not checked themselves.
+pub(super) fn collect(root: &Path, cargo_home: Option<&Path>) -> Vec<Dependency> {
+    let Ok(content) = std::fs::read_to_string(root.join("Cargo.lock")) else {
+        return Vec::new();
+    };
+    let Ok(lockfile) = toml::from_str::<Lockfile>(&content) else {
+        return Vec::new();
+    };
+
+    let registry_dirs = cargo_home.map(registry_src_dirs).unwrap_or_default();
+    let paths = dependency_paths(&lockfile.package);
+
+    lockfile
+        .package
+        .iter()
+        .filter(|pkg| pkg.source.is_some())
+        .map(|pkg| {
+            let key = package_key(pkg);
+            let chain = paths.get(&key).cloned().unwrap_or_default();
+            let license = registry_dirs
+                .iter()
+                .map(|dir| dir.join(format!("{}-{}", pkg.name, pkg.version)))
+                .find(|dir| dir.is_dir())
+                .and_then(|dir| manifest_license(&dir));
+            Dependency {
+                name: pkg.name.clone(),
+                version: pkg.version.clone(),
+                lockfile: PathBuf::from("Cargo.lock"),
+                path: super::format_path(&chain, &pkg.name, &pkg.version),
+                license,
+            }
+        })
+        .collect()
+}
+
+/// Unpacked crate directories of every registry index.
+fn registry_src_dirs(cargo_home: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(cargo_home.join("registry/src")) else {
+        return Vec::new();
+    };
+    let mut dirs: Vec<PathBuf> = entries.filter_map(Result::ok).map(|e| e.path()).collect();
+    dirs.sort();
+    dirs
+}
+
+/// License from an unpacked crate's `Cargo.toml` (`license`, or the text of
+/// `license-file`), falling back to the crate's license files.
+fn manifest_license(dir: &Path) -> Option<String> {
+    let manifest: toml::Value = std::fs::read_to_string(dir.join("Cargo.toml"))
+        .ok()
+        .and_then(|c| toml::from_str(&c).ok())?;
+    let package = manifest.get("package")?;
+
+    if let Some(license) = package.get("license").and_then(|v| v.as_str()) {
+        return Some(license.to_string());
+    }
+    if let Some(file) = package.get("license-file").and_then(|v| v.as_str())
+        && let Ok(text) = std::fs::read_to_string(dir.join(file))
+        && let Some(id) = classify::classify_text(&text)
+    {
+        return Some(id.to_string());
+    }
+    classify::classify_dir(dir)
+}
+
+/// Lockfile key for a package ("name version").
+fn package_key(pkg: &LockedPackage) -> String {
+    format!("{} {}", pkg.name, pkg.version)
+}
+
+/// Shortest chain of package names from a workspace member to each package.
+fn dependency_paths(packages: &[LockedPackage]) -> HashMap<String, Vec<String>> {
+    // Dependency entries are "name", "name version" or "name version (source)"
+    let mut versions: HashMap<&str, Vec<&str>> = HashMap::new();
+    for pkg in packages {
+        versions.entry(pkg.name.as_str()).or_default().push(pkg.version.as_str());
+    }
+    let resolve = |entry: &str| -> Option<String> {
+        let mut parts = entry.split_whitespace();
+        let name = parts.next()?;
+        let version = match parts.next() {
+            Some(version) => version,
+            None => versions.get(name)?.first()?,
+        };
+        Some(format!("{name} {version}"))
+    };
+    let by_key: HashMap<String, &LockedPackage> =
+        packages.iter().map(|pkg| (package_key(pkg), pkg)).collect();
+
+    let mut paths: HashMap<String, Vec<String>> = HashMap::new();
+    let mut queue = VecDeque::new();
+    for pkg in packages.iter().filter(|pkg| pkg.source.is_none()) {
+        paths.insert(package_key(pkg), vec![pkg.name.clone()]);
+        queue.push_back(pkg);
+    }
+
+    while let Some(pkg) = queue.pop_front() {
+        let chain = paths.get(&package_key(pkg)).cloned().unwrap_or_default();
+        for key in pkg.dependencies.iter().filter_map(|entry| resolve(entry)) {
+            if paths.contains_key(&key) {
+                continue;
+            }
+            let Some(dep) = by_key.get(&key) else {
+                continue;
+            };
+            let mut dep_chain = chain.clone();
+            dep_chain.push(dep.name.clone());
+            paths.insert(key, dep_chain);
+            queue.push_back(dep);
+        }
+    }
+
+    // Drop each package's own name; the path holds its ancestors only
+    for chain in paths.values_mut() {
+        chain.pop();
+    }
+    paths
+}
+
+#[cfg(test)]
+#[path = "cargo_tests.rs"]
+mod tests;
@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+const LOCKFILE: &str = r#"
+version = 3
+
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = ["serde", "log 0.4.20"]
+
+[[package]]
+name = "serde"
+version = "1.0.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+dependencies = ["serde_derive"]
+
+[[package]]
+name = "serde_derive"
+version = "1.0.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "log"
+version = "0.4.20"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+"#;
+
+fn write_crate(cargo_home: &Path, dir_name: &str, manifest: &str) -> PathBuf {
+    let dir = cargo_home.join("registry/src/index.crates.io-6f17d22bba15001f").join(dir_name);
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("Cargo.toml"), manifest).unwrap();
+    dir
+}
+
+#[test]
+fn reads_registry_licenses_and_paths() {
+    let project = tempfile::tempdir().unwrap();
+    let cargo_home = tempfile::tempdir().unwrap();
+    std::fs::write(project.path().join("Cargo.lock"), LOCKFILE).unwrap();
+    write_crate(
+        cargo_home.path(),
+        "serde-1.0.0",
+        "[package]\nname = \"serde\"\nlicense = \"MIT OR Apache-2.0\"\n",
+    );
+    write_crate(
+        cargo_home.path(),
+        "serde_derive-1.0.0",
+        "[package]\nname = \"serde_derive\"\nlicense = \"MIT/Apache-2.0\"\n",
+    );
+    let log = write_crate(
+        cargo_home.path(),
+        "log-0.4.20",
+        "[package]\nname = \"log\"\nlicense-file = \"LICENSE\"\n",
+    );
+    std::fs::write(log.join("LICENSE"), "Permission is hereby granted, free of charge").unwrap();
+
+    let deps = collect(project.path(), Some(cargo_home.path()));
+    let find = |name: &str| deps.iter().find(|d| d.name == name).unwrap();
+
+    assert_eq!(deps.len(), 3, "workspace members are not dependencies");
+    assert_eq!(find("serde").license.as_deref(), Some("MIT OR Apache-2.0"));
+    assert_eq!(find("serde_derive").path, "app > serde > serde_derive@1.0.0");
+    assert_eq!(find("log").path, "app > log@0.4.20");
+    assert_eq!(find("log").license.as_deref(), Some("MIT"));
+    assert_eq!(find("log").lockfile, PathBuf::from("Cargo.lock"));
+}
+
+#[test]
+fn missing_registry_entry_has_no_license() {
+    let project = tempfile::tempdir().unwrap();
+    std::fs::write(project.path().join("Cargo.lock"), LOCKFILE).unwrap();
+
+    let deps = collect(project.path(), None);
+    assert!(deps.iter().all(|d| d.license.is_none()));
+}
+
+#[test]
+fn no_lockfile() {
+    let project = tempfile::tempdir().unwrap();
+    assert!(collect(project.path(), None).is_empty());
+}
@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Offline license text classification.
+//!
+//! Identifies common licenses from their full text by distinctive phrases, for
+//! packages that ship a LICENSE file instead of an SPDX identifier.
+
+use std::path::Path;
+
+/// Distinctive phrases per license, checked in order (most specific first).
+/// All phrases of an entry must appear in the normalized text.
+const SIGNATURES: &[(&str, &[&str])] = &[
+    ("AGPL-3.0-only", &["gnu affero general public license"]),
+    ("LGPL-3.0-only", &["gnu lesser general public license", "version 3"]),
+    ("LGPL-2.1-only", &["gnu lesser general public license", "version 2.1"]),
+    ("GPL-3.0-only", &["gnu general public license", "version 3"]),
+    ("GPL-2.0-only", &["gnu general public license", "version 2"]),
+    ("MPL-2.0", &["mozilla public license", "2.0"]),
+    ("Apache-2.0", &["apache license", "version 2.0"]),
+    ("BSL-1.0", &["boost software license"]),
+    ("CC0-1.0", &["cc0 1.0 universal"]),
+    ("Unlicense", &["this is free and unencumbered software released into the public domain"]),
+    ("ISC", &["permission to use, copy, modify, and/or distribute this software for any purpose"]),
+    ("MIT", &["permission is hereby granted, free of charge"]),
+    ("BSD-3-Clause", &["redistribution and use in source and binary forms", "endorse or promote"]),
+    ("BSD-2-Clause", &["redistribution and use in source and binary forms"]),
+];
+
+/// Identify a license from its text.
+pub(super) fn classify_text(text: &str) -> Option<&'static str> {
+    let normalized = text.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+    SIGNATURES
+        .iter()
+        .find(|(_, phrases)| phrases.iter().all(|p| normalized.contains(p)))
+        .map(|(id, _)| *id)
+}
+
+/// Identify the licenses of a package directory from its license files
+/// (`LICENSE*`, `LICENCE*`, `COPYING*`).
+///
+/// Several recognized files are combined with `AND`, so every license must
+/// pass the policy.
+pub(super) fn classify_dir(dir: &Path) -> Option<String> {
+    let mut names: Vec<String> = std::fs::read_dir(dir)
+        .ok()?
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_ok_and(|t| t.is_file()))
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .filter(|name| is_license_file(name))
+        .collect();
+    names.sort();
+
+    let mut ids: Vec<&str> = Vec::new();
+    for name in names {
+        let Ok(text) = std::fs::read_to_string(dir.join(&name)) else {
+            continue;
+        };
+        if let Some(id) = classify_text(&text)
+            && !ids.contains(&id)
+        {
+            ids.push(id);
+        }
+    }
+
+    (!ids.is_empty()).then(|| ids.join(" AND "))
+}
+
+fn is_license_file(name: &str) -> bool {
+    let upper = name.to_ascii_uppercase();
+    ["LICENSE", "LICENCE", "COPYING"].iter().any(|prefix| upper.starts_with(prefix))
+}
+
+#[cfg(test)]
+#[path = "classify_tests.rs"]
+mod tests;
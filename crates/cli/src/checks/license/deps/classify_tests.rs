@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+#[yare::parameterized(
+    mit = { "Permission is hereby granted, free of charge, to any person", "MIT" },
+    apache = { "Apache License\n  Version 2.0, January 2004", "Apache-2.0" },
+    gpl3 = { "GNU GENERAL PUBLIC LICENSE\n Version 3, 29 June 2007", "GPL-3.0-only" },
+    lgpl3 = { "GNU LESSER GENERAL PUBLIC LICENSE Version 3 ... GNU General Public License", "LGPL-3.0-only" },
+    agpl = { "GNU AFFERO GENERAL PUBLIC LICENSE Version 3", "AGPL-3.0-only" },
+    bsd3 = { "Redistribution and use in source and binary forms ... used to endorse or promote", "BSD-3-Clause" },
+    bsd2 = { "Redistribution and use in source and binary forms, with or without", "BSD-2-Clause" },
+    isc = { "Permission to use, copy, modify, and/or distribute this software for any purpose", "ISC" },
+    mpl = { "Mozilla Public License Version 2.0", "MPL-2.0" },
+)]
+fn classifies_license_text(text: &str, expected: &str) {
+    assert_eq!(classify_text(text), Some(expected));
+}
+
+#[test]
+fn unrecognized_text() {
+    assert_eq!(classify_text("All rights reserved."), None);
+}
+
+#[test]
+fn classify_dir_combines_license_files() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("LICENSE-MIT"), "Permission is hereby granted, free of charge")
+        .unwrap();
+    std::fs::write(dir.path().join("LICENSE-APACHE"), "Apache License Version 2.0").unwrap();
+    std::fs::write(dir.path().join("README.md"), "GNU General Public License version 3").unwrap();
+
+    assert_eq!(classify_dir(dir.path()).as_deref(), Some("Apache-2.0 AND MIT"));
+}
+
+#[test]
+fn classify_dir_missing() {
+    let dir = tempfile::tempdir().unwrap();
+    assert_eq!(classify_dir(&dir.path().join("absent")), None);
+    assert_eq!(classify_dir(dir.path()), None);
+}
@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Go dependencies from `go.sum` and the module cache.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use super::{Dependency, classify};
+
+/// Read modules from `go.sum`, classifying license files in the module cache.
+///
+/// Only modules whose source is checksummed are included; `/go.mod`-only
+/// entries are consulted for version selection but never compiled in.
+pub(super) fn collect(root: &Path, mod_cache: Option<&Path>) -> Vec<Dependency> {
+    let Ok(content) = std::fs::read_to_string(root.join("go.sum")) else {
+        return Vec::new();
+    };
+
+    let modules: BTreeSet<(&str, &str)> = content
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let module = parts.next()?;
+            let version = parts.next()?;
+            (!version.ends_with("/go.mod")).then_some((module, version))
+        })
+        .collect();
+
+    modules
+        .into_iter()
+        .map(|(module, version)| {
+            let license = mod_cache
+                .map(|cache| {
+                    cache.join(format!("{}@{}", escape_path(module), escape_path(version)))
+                })
+                .and_then(|dir| classify::classify_dir(&dir));
+            Dependency {
+                name: module.to_string(),
+                version: version.to_string(),
+                lockfile: PathBuf::from("go.sum"),
+                path: super::format_path(&[], module, version),
+                license,
+            }
+        })
+        .collect()
+}
+
+/// Module cache path escaping: uppercase letters become `!` + lowercase.
+fn escape_path(path: &str) -> String {
+    let mut escaped = String::with_capacity(path.len());
+    for c in path.chars() {
+        if c.is_ascii_uppercase() {
+            escaped.push('!');
+            escaped.push(c.to_ascii_lowercase());
+        } else {
+            escaped.push(c);
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+#[path = "go_tests.rs"]
+mod tests;
@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+#[test]
+fn escapes_uppercase_letters() {
+    assert_eq!(escape_path("github.com/BurntSushi/toml"), "github.com/!burnt!sushi/toml");
+}
+
+#[test]
+fn reads_modules_with_source_checksums() {
+    let root = tempfile::tempdir().unwrap();
+    let cache = tempfile::tempdir().unwrap();
+    std::fs::write(
+        root.path().join("go.sum"),
+        "github.com/BurntSushi/toml v1.3.2 h1:abc=\n\
+         github.com/BurntSushi/toml v1.3.2/go.mod h1:def=\n\
+         golang.org/x/text v0.3.0/go.mod h1:ghi=\n",
+    )
+    .unwrap();
+    let module_dir = cache.path().join("github.com/!burnt!sushi/toml@v1.3.2");
+    std::fs::create_dir_all(&module_dir).unwrap();
+    std::fs::write(module_dir.join("COPYING"), "Permission is hereby granted, free of charge")
+        .unwrap();
+
+    let deps = collect(root.path(), Some(cache.path()));
+
+    assert_eq!(deps.len(), 1, "go.mod-only entries are skipped");
+    assert_eq!(deps[0].path, "github.com/BurntSushi/toml@v1.3.2");
+    assert_eq!(deps[0].license.as_deref(), Some("MIT"));
+    assert_eq!(deps[0].lockfile, PathBuf::from("go.sum"));
+}
+
+#[test]
+fn missing_module_cache_has_no_license() {
+    let root = tempfile::tempdir().unwrap();
+    std::fs::write(root.path().join("go.sum"), "example.com/m v1.0.0 h1:abc=\n").unwrap();
+
+    let deps = collect(root.path(), None);
+    assert_eq!(deps.len(), 1);
+    assert_eq!(deps[0].license, None);
+}
@@ -0,0 +1,159 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Dependency license compliance (`[check.license.deps]`).
+//!
+//! Reads lockfiles and locally installed package metadata (no network access)
+//! and evaluates each dependency's SPDX expression against allow/deny lists.
+
+mod cargo;
+mod classify;
+mod go;
+mod npm;
+mod python;
+mod spdx;
+
+use std::path::{Path, PathBuf};
+
+use serde_json::json;
+
+use crate::check::{CheckContext, Violation};
+use crate::config::LicenseDepsConfig;
+use spdx::{LicensePolicy, Verdict};
+
+/// Violation types derived from lockfiles and package caches rather than file
+/// contents, so they are never cached per file.
+pub const DEPENDENCY_VIOLATION_TYPES: &[&str] =
+    &["disallowed_dependency_license", "unknown_license"];
+
+/// A third-party dependency and its declared license.
+#[derive(Debug)]
+struct Dependency {
+    name: String,
+    version: String,
+    /// File the dependency was read from, relative to the project root.
+    lockfile: PathBuf,
+    /// How the dependency is reached ("app > serde > serde_derive@1.0.0").
+    path: String,
+    /// SPDX expression, if license metadata was found locally.
+    license: Option<String>,
+}
+
+/// Local package caches consulted for license metadata.
+struct Caches {
+    cargo_home: Option<PathBuf>,
+    go_mod_cache: Option<PathBuf>,
+}
+
+impl Caches {
+    fn from_env() -> Self {
+        let home = crate::env::home();
+        let cargo_home =
+            crate::env::cargo_home().or_else(|| home.as_ref().map(|h| h.join(".cargo")));
+        let go_mod_cache = crate::env::go_mod_cache()
+            .or_else(|| {
+                let gopath = crate::env::go_path()?;
+                let first = std::env::split_paths(&gopath).next()?;
+                Some(first.join("pkg/mod"))
+            })
+            .or_else(|| home.as_ref().map(|h| h.join("go/pkg/mod")));
+        Self { cargo_home, go_mod_cache }
+    }
+}
+
+/// Join a dependency chain into a display path ("a > b > name@version").
+fn format_path(ancestors: &[String], name: &str, version: &str) -> String {
+    let leaf = if version.is_empty() { name.to_string() } else { format!("{name}@{version}") };
+    ancestors.iter().map(String::as_str).chain([leaf.as_str()]).collect::<Vec<_>>().join(" > ")
+}
+
+/// Collect dependencies from every lockfile found at the project root.
+fn collect(root: &Path, caches: &Caches) -> Vec<Dependency> {
+    let mut deps = cargo::collect(root, caches.cargo_home.as_deref());
+    deps.extend(npm::collect(root));
+    deps.extend(go::collect(root, caches.go_mod_cache.as_deref()));
+    deps.extend(python::collect(root));
+    deps
+}
+
+/// Whether a dependency matches an `ignore` entry ("name" or "name@version").
+fn is_ignored(config: &LicenseDepsConfig, dep: &Dependency) -> bool {
+    config.ignore.iter().any(|entry| match entry.rsplit_once('@') {
+        Some((name, version)) if !name.is_empty() => name == dep.name && version == dep.version,
+        _ => *entry == dep.name,
+    })
+}
+
+/// Check dependency licenses, appending violations. Returns metrics when the
+/// sub-check is enabled.
+pub fn check_dependencies(
+    ctx: &CheckContext,
+    config: &LicenseDepsConfig,
+    violations: &mut Vec<Violation>,
+) -> Option<serde_json::Value> {
+    if !config.is_enabled() {
+        return None;
+    }
+
+    let policy = LicensePolicy { allow: &config.allow, deny: &config.deny };
+    let mut checked = 0;
+    let mut disallowed = 0;
+    let mut unknown = 0;
+
+    for dep in collect(ctx.root, &Caches::from_env()) {
+        if is_ignored(config, &dep) {
+            continue;
+        }
+        checked += 1;
+
+        let verdict = dep.license.as_deref().map_or(Verdict::Unknown, |l| policy.evaluate(l));
+        let violation = match verdict {
+            Verdict::Allowed => continue,
+            Verdict::Disallowed => {
+                disallowed += 1;
+                let license = dep.license.as_deref().unwrap_or_default();
+                Violation::file_only(
+                    &dep.lockfile,
+                    "disallowed_dependency_license",
+                    format!(
+                        "{license} is not allowed by [check.license.deps]. \
+                         Replace the dependency, or add it to `ignore` if it is approved."
+                    ),
+                )
+                .with_found(license)
+            }
+            Verdict::Unknown => {
+                unknown += 1;
+                let advice = match &dep.license {
+                    Some(license) => format!(
+                        "License \"{license}\" is not a valid SPDX expression. \
+                         Add the dependency to `ignore` once reviewed."
+                    ),
+                    None => "No license metadata found locally. Fetch or install dependencies \
+                             first, or add the dependency to `ignore` once reviewed."
+                        .to_string(),
+                };
+                let violation = Violation::file_only(&dep.lockfile, "unknown_license", advice);
+                match &dep.license {
+                    Some(license) => violation.with_found(license),
+                    None => violation,
+                }
+            }
+        };
+
+        if ctx.limit.is_some_and(|limit| violations.len() >= limit) {
+            continue;
+        }
+        violations.push(violation.with_path(dep.path));
+    }
+
+    Some(json!({
+        "dependencies_checked": checked,
+        "dependencies_disallowed": disallowed,
+        "dependencies_unknown": unknown,
+    }))
+}
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
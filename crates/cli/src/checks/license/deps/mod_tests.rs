@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+fn dep(name: &str, version: &str) -> Dependency {
+    Dependency {
+        name: name.to_string(),
+        version: version.to_string(),
+        lockfile: PathBuf::from("Cargo.lock"),
+        path: format_path(&[], name, version),
+        license: None,
+    }
+}
+
+#[test]
+fn format_path_joins_ancestors() {
+    let ancestors = vec!["app".to_string(), "serde".to_string()];
+    assert_eq!(
+        format_path(&ancestors, "serde_derive", "1.0.0"),
+        "app > serde > serde_derive@1.0.0"
+    );
+    assert_eq!(format_path(&[], "pkg", ""), "pkg");
+}
+
+#[test]
+fn ignore_by_name_or_version() {
+    let config = LicenseDepsConfig {
+        ignore: vec!["ring".to_string(), "@s/b@2.0.0".to_string()],
+        ..Default::default()
+    };
+    assert!(is_ignored(&config, &dep("ring", "0.17.0")));
+    assert!(is_ignored(&config, &dep("@s/b", "2.0.0")));
+    assert!(!is_ignored(&config, &dep("@s/b", "3.0.0")));
+    assert!(!is_ignored(&config, &dep("serde", "1.0.0")));
+}
+
+#[test]
+fn config_enabled_by_lists_or_level() {
+    assert!(!LicenseDepsConfig::default().is_enabled());
+    let deny = LicenseDepsConfig { deny: vec!["GPL-*".to_string()], ..Default::default() };
+    assert!(deny.is_enabled());
+    let off = LicenseDepsConfig { check: Some("off".to_string()), ..deny };
+    assert!(!off.is_enabled());
+    let level = LicenseDepsConfig { check: Some("error".to_string()), ..Default::default() };
+    assert!(level.is_enabled());
+}
@@ -0,0 +1,193 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! JavaScript dependencies from `package-lock.json` or `pnpm-lock.yaml` and
+//! installed `node_modules`.
+
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use super::{Dependency, classify};
+
+/// Read dependencies from whichever npm or pnpm lockfile exists.
+pub(super) fn collect(root: &Path) -> Vec<Dependency> {
+    let mut deps = collect_package_lock(root);
+    deps.extend(collect_pnpm_lock(root));
+    deps
+}
+
+/// Read `package-lock.json` (lockfile v1 nested `dependencies`, or v2/v3
+/// `packages` keyed by install path).
+fn collect_package_lock(root: &Path) -> Vec<Dependency> {
+    let Some(lock) = std::fs::read_to_string(root.join("package-lock.json"))
+        .ok()
+        .and_then(|c| serde_json::from_str::<Value>(&c).ok())
+    else {
+        return Vec::new();
+    };
+
+    let mut deps = Vec::new();
+    if let Some(packages) = lock.get("packages").and_then(Value::as_object) {
+        for (key, entry) in packages {
+            // "" is the root project; links point at workspace packages
+            if key.is_empty() || entry.get("link").and_then(Value::as_bool) == Some(true) {
+                continue;
+            }
+            let Some(chain) = install_path_chain(key) else {
+                continue;
+            };
+            let Some((name, ancestors)) = chain.split_last() else {
+                continue;
+            };
+            let version = entry.get("version").and_then(Value::as_str).unwrap_or("");
+            let license = license_value(entry).or_else(|| installed_license(&root.join(key)));
+            deps.push(dependency("package-lock.json", ancestors, name, version, license));
+        }
+    } else if let Some(tree) = lock.get("dependencies").and_then(Value::as_object) {
+        collect_v1_tree(root, tree, &mut Vec::new(), &mut deps);
+    }
+    deps
+}
+
+fn collect_v1_tree(
+    root: &Path,
+    tree: &serde_json::Map<String, Value>,
+    ancestors: &mut Vec<String>,
+    deps: &mut Vec<Dependency>,
+) {
+    for (name, entry) in tree {
+        let version = entry.get("version").and_then(Value::as_str).unwrap_or("");
+        let install_dir = ancestors
+            .iter()
+            .chain([name])
+            .fold(root.to_path_buf(), |dir, n| dir.join("node_modules").join(n));
+        let license = installed_license(&install_dir);
+        deps.push(dependency("package-lock.json", ancestors, name, version, license));
+
+        if let Some(nested) = entry.get("dependencies").and_then(Value::as_object) {
+            ancestors.push(name.clone());
+            collect_v1_tree(root, nested, ancestors, deps);
+            ancestors.pop();
+        }
+    }
+}
+
+/// Package names along an install path ("node_modules/a/node_modules/@s/b"
+/// → ["a", "@s/b"]). Returns `None` for paths outside `node_modules`.
+fn install_path_chain(key: &str) -> Option<Vec<String>> {
+    let mut names = Vec::new();
+    for segment in key.split("node_modules/").skip(1) {
+        let name = segment.trim_end_matches('/');
+        if name.is_empty() {
+            return None;
+        }
+        names.push(name.to_string());
+    }
+    if !key.starts_with("node_modules/") || names.is_empty() {
+        return None;
+    }
+    Some(names)
+}
+
+/// Read `pnpm-lock.yaml` package keys ("/name/1.0.0", "/name@1.0.0",
+/// "name@1.0.0(peer@2.0.0)").
+fn collect_pnpm_lock(root: &Path) -> Vec<Dependency> {
+    let Some(lock) = std::fs::read_to_string(root.join("pnpm-lock.yaml"))
+        .ok()
+        .and_then(|c| serde_yaml::from_str::<serde_yaml::Value>(&c).ok())
+    else {
+        return Vec::new();
+    };
+    let Some(packages) = lock.get("packages").and_then(|p| p.as_mapping()) else {
+        return Vec::new();
+    };
+
+    let mut deps = Vec::new();
+    for key in packages.keys().filter_map(|k| k.as_str()) {
+        let Some((name, version)) = parse_pnpm_key(key) else {
+            continue;
+        };
+        let store_dir = root
+            .join("node_modules/.pnpm")
+            .join(format!("{}@{version}", name.replace('/', "+")))
+            .join("node_modules")
+            .join(name);
+        let license = installed_license(&store_dir)
+            .or_else(|| installed_license(&root.join("node_modules").join(name)));
+        deps.push(dependency("pnpm-lock.yaml", &[], name, version, license));
+    }
+    deps
+}
+
+/// Split a pnpm package key into name and version.
+fn parse_pnpm_key(key: &str) -> Option<(&str, &str)> {
+    let key = key.strip_prefix('/').unwrap_or(key);
+    let key = key.split('(').next().unwrap_or(key);
+
+    // Lockfile v5: "name/1.0.0" or "@scope/name/1.0.0_peer@2.0.0"
+    let version_slash = key
+        .match_indices('/')
+        .map(|(i, _)| i)
+        .find(|&i| key[i + 1..].starts_with(|c: char| c.is_ascii_digit()));
+    if let Some(slash) = version_slash {
+        let version = key[slash + 1..].split('_').next().unwrap_or_default();
+        return Some((&key[..slash], version));
+    }
+
+    // "@scope/name@1.0.0" or "name@1.0.0" (the '@' after position 0)
+    let at = key.get(1..)?.find('@')? + 1;
+    let (name, version) = (&key[..at], &key[at + 1..]);
+    (!version.is_empty()).then_some((name, version))
+}
+
+/// License declared in an installed package's `package.json`, falling back
+/// to its license files.
+fn installed_license(dir: &Path) -> Option<String> {
+    std::fs::read_to_string(dir.join("package.json"))
+        .ok()
+        .and_then(|c| serde_json::from_str::<Value>(&c).ok())
+        .and_then(|manifest| license_value(&manifest))
+        .or_else(|| classify::classify_dir(dir))
+}
+
+/// License from a `license` field (string or `{ "type": ... }`) or the
+/// legacy `licenses` array, joined with `OR`.
+fn license_value(manifest: &Value) -> Option<String> {
+    match manifest.get("license") {
+        Some(Value::String(s)) if !s.is_empty() => return Some(s.clone()),
+        Some(Value::Object(o)) => {
+            if let Some(t) = o.get("type").and_then(Value::as_str) {
+                return Some(t.to_string());
+            }
+        }
+        _ => {}
+    }
+    let types: Vec<&str> = manifest
+        .get("licenses")?
+        .as_array()?
+        .iter()
+        .filter_map(|l| l.get("type").and_then(Value::as_str).or_else(|| l.as_str()))
+        .collect();
+    (!types.is_empty()).then(|| types.join(" OR "))
+}
+
+fn dependency(
+    lockfile: &str,
+    ancestors: &[String],
+    name: &str,
+    version: &str,
+    license: Option<String>,
+) -> Dependency {
+    Dependency {
+        name: name.to_string(),
+        version: version.to_string(),
+        lockfile: PathBuf::from(lockfile),
+        path: super::format_path(ancestors, name, version),
+        license,
+    }
+}
+
+#[cfg(test)]
+#[path = "npm_tests.rs"]
+mod tests;
@@ -0,0 +1,110 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+fn write(root: &Path, path: &str, content: &str) {
+    let path = root.join(path);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, content).unwrap();
+}
+
+#[test]
+fn install_path_chain_nested_and_scoped() {
+    assert_eq!(
+        install_path_chain("node_modules/a/node_modules/@s/b"),
+        Some(vec!["a".to_string(), "@s/b".to_string()])
+    );
+    assert_eq!(install_path_chain("packages/app"), None);
+}
+
+#[yare::parameterized(
+    v5 = { "/left-pad/1.3.0", "left-pad", "1.3.0" },
+    v5_scoped_peer = { "/@babel/core/7.0.0_react@17.0.0", "@babel/core", "7.0.0" },
+    v6 = { "/left-pad@1.3.0", "left-pad", "1.3.0" },
+    v9_scoped = { "@babel/core@7.0.0", "@babel/core", "7.0.0" },
+    v9_peer = { "react-dom@18.0.0(react@18.0.0)", "react-dom", "18.0.0" },
+)]
+fn pnpm_keys(key: &str, name: &str, version: &str) {
+    assert_eq!(parse_pnpm_key(key), Some((name, version)));
+}
+
+#[test]
+fn license_value_forms() {
+    let string = serde_json::json!({ "license": "MIT" });
+    let object = serde_json::json!({ "license": { "type": "ISC" } });
+    let legacy = serde_json::json!({ "licenses": [{ "type": "MIT" }, { "type": "Apache-2.0" }] });
+    assert_eq!(license_value(&string).as_deref(), Some("MIT"));
+    assert_eq!(license_value(&object).as_deref(), Some("ISC"));
+    assert_eq!(license_value(&legacy).as_deref(), Some("MIT OR Apache-2.0"));
+    assert_eq!(license_value(&serde_json::json!({})), None);
+}
+
+#[test]
+fn package_lock_v3_uses_lockfile_then_node_modules() {
+    let root = tempfile::tempdir().unwrap();
+    write(
+        root.path(),
+        "package-lock.json",
+        r#"{
+  "lockfileVersion": 3,
+  "packages": {
+    "": { "name": "app" },
+    "node_modules/a": { "version": "1.0.0", "license": "MIT" },
+    "node_modules/a/node_modules/b": { "version": "2.0.0" },
+    "node_modules/local": { "link": true }
+  }
+}"#,
+    );
+    write(
+        root.path(),
+        "node_modules/a/node_modules/b/package.json",
+        r#"{"license": "GPL-3.0-only"}"#,
+    );
+
+    let deps = collect(root.path());
+    assert_eq!(deps.len(), 2);
+    assert_eq!(deps[0].path, "a@1.0.0");
+    assert_eq!(deps[1].path, "a > b@2.0.0");
+    assert_eq!(deps[1].license.as_deref(), Some("GPL-3.0-only"));
+}
+
+#[test]
+fn package_lock_v1_nested_dependencies() {
+    let root = tempfile::tempdir().unwrap();
+    write(
+        root.path(),
+        "package-lock.json",
+        r#"{ "lockfileVersion": 1, "dependencies": {
+  "a": { "version": "1.0.0", "dependencies": { "b": { "version": "2.0.0" } } }
+} }"#,
+    );
+    write(root.path(), "node_modules/a/package.json", r#"{"license": "MIT"}"#);
+
+    let deps = collect(root.path());
+    assert_eq!(deps.len(), 2);
+    assert_eq!(deps[0].license.as_deref(), Some("MIT"));
+    assert_eq!(deps[1].path, "a > b@2.0.0");
+    assert_eq!(deps[1].license, None);
+}
+
+#[test]
+fn pnpm_lock_reads_store_package_json() {
+    let root = tempfile::tempdir().unwrap();
+    write(
+        root.path(),
+        "pnpm-lock.yaml",
+        "lockfileVersion: '9.0'\npackages:\n  '@s/b@2.0.0':\n    resolution: {integrity: sha512-x}\n",
+    );
+    write(
+        root.path(),
+        "node_modules/.pnpm/@s+b@2.0.0/node_modules/@s/b/package.json",
+        r#"{"license": "Apache-2.0"}"#,
+    );
+
+    let deps = collect(root.path());
+    assert_eq!(deps.len(), 1);
+    assert_eq!(deps[0].path, "@s/b@2.0.0");
+    assert_eq!(deps[0].license.as_deref(), Some("Apache-2.0"));
+    assert_eq!(deps[0].lockfile, PathBuf::from("pnpm-lock.yaml"));
+}
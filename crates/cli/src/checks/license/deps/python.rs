@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Python dependencies from installed `*.dist-info/METADATA` files.
+
+use std::path::{Path, PathBuf};
+
+use super::{Dependency, classify};
+
+/// Virtual environment directories searched for installed packages.
+const VENV_DIRS: &[&str] = &[".venv", "venv"];
+
+/// Trove classifiers mapped to SPDX identifiers.
+const CLASSIFIERS: &[(&str, &str)] = &[
+    ("MIT License", "MIT"),
+    ("Apache Software License", "Apache-2.0"),
+    ("ISC License (ISCL)", "ISC"),
+    ("Mozilla Public License 2.0 (MPL 2.0)", "MPL-2.0"),
+    ("GNU Affero General Public License v3", "AGPL-3.0-only"),
+    ("GNU General Public License v2 (GPLv2)", "GPL-2.0-only"),
+    ("GNU General Public License v3 (GPLv3)", "GPL-3.0-only"),
+    ("GNU Lesser General Public License v3 (LGPLv3)", "LGPL-3.0-only"),
+    ("Python Software Foundation License", "PSF-2.0"),
+    ("The Unlicense (Unlicense)", "Unlicense"),
+];
+
+/// Read installed distributions from the project's virtual environments.
+pub(super) fn collect(root: &Path) -> Vec<Dependency> {
+    let mut deps = Vec::new();
+    for site_packages in site_packages_dirs(root) {
+        let Ok(entries) = std::fs::read_dir(&site_packages) else {
+            continue;
+        };
+        let mut dist_infos: Vec<PathBuf> = entries
+            .filter_map(Result::ok)
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|e| e == "dist-info"))
+            .collect();
+        dist_infos.sort();
+
+        for dist_info in dist_infos {
+            let metadata = dist_info.join("METADATA");
+            let Ok(content) = std::fs::read_to_string(&metadata) else {
+                continue;
+            };
+            let fields = parse_metadata(&content);
+            let Some(name) = fields.name else {
+                continue;
+            };
+            let version = fields.version.unwrap_or_default();
+            let license = fields.license.or_else(|| classify_license_files(&dist_info));
+            deps.push(Dependency {
+                path: super::format_path(&[], &name, &version),
+                name,
+                version,
+                lockfile: metadata.strip_prefix(root).unwrap_or(&metadata).to_path_buf(),
+                license,
+            });
+        }
+    }
+    deps
+}
+
+/// `site-packages` directories of each virtual environment
+/// (`lib/python3.*/site-packages`, or `Lib/site-packages` on Windows).
+fn site_packages_dirs(root: &Path) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    for venv in VENV_DIRS.iter().map(|v| root.join(v)) {
+        let windows = venv.join("Lib/site-packages");
+        if windows.is_dir() {
+            dirs.push(windows);
+        }
+        let Ok(entries) = std::fs::read_dir(venv.join("lib")) else {
+            continue;
+        };
+        let mut pythons: Vec<PathBuf> = entries
+            .filter_map(Result::ok)
+            .map(|e| e.path().join("site-packages"))
+            .filter(|p| p.is_dir())
+            .collect();
+        pythons.sort();
+        dirs.extend(pythons);
+    }
+    dirs
+}
+
+/// Fields read from a METADATA file.
+#[derive(Debug, Default)]
+pub(super) struct Metadata {
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub license: Option<String>,
+}
+
+/// Parse METADATA headers. The license comes from `License-Expression`, then
+/// a short `License` value, then a license classifier.
+pub(super) fn parse_metadata(content: &str) -> Metadata {
+    let mut metadata = Metadata::default();
+    let mut expression = None;
+    let mut license_field = None;
+    let mut classifier = None;
+
+    // Headers end at the first blank line; the body is the description
+    for line in content.lines().take_while(|l| !l.trim().is_empty()) {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key {
+            "Name" => metadata.name = Some(value.to_string()),
+            "Version" => metadata.version = Some(value.to_string()),
+            "License-Expression" => expression = Some(value.to_string()),
+            "License" if !value.is_empty() && !value.eq_ignore_ascii_case("UNKNOWN") => {
+                license_field = Some(value.to_string());
+            }
+            "Classifier" if classifier.is_none() => {
+                classifier = value.strip_prefix("License :: ").and_then(classifier_license);
+            }
+            _ => {}
+        }
+    }
+
+    metadata.license = expression
+        .or_else(|| {
+            license_field.map(|value| match classify::classify_text(&value) {
+                // Some packages paste the full license text into `License`
+                Some(id) if value.len() > 100 => id.to_string(),
+                _ => value,
+            })
+        })
+        .or_else(|| classifier.map(String::from));
+    metadata
+}
+
+/// Map a classifier ("OSI Approved :: MIT License") to an SPDX identifier.
+fn classifier_license(classifier: &str) -> Option<&'static str> {
+    let name = classifier.rsplit(" :: ").next()?;
+    CLASSIFIERS.iter().find(|(c, _)| *c == name).map(|(_, id)| *id)
+}
+
+/// License files shipped in the dist-info directory (or its `licenses/`).
+fn classify_license_files(dist_info: &Path) -> Option<String> {
+    classify::classify_dir(dist_info)
+        .or_else(|| classify::classify_dir(&dist_info.join("licenses")))
+}
+
+#[cfg(test)]
+#[path = "python_tests.rs"]
+mod tests;
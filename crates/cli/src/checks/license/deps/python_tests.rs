@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+#[test]
+fn license_expression_takes_precedence() {
+    let metadata = parse_metadata(
+        "Metadata-Version: 2.4\nName: pkg\nVersion: 1.0\nLicense: BSD\nLicense-Expression: MIT\n",
+    );
+    assert_eq!(metadata.name.as_deref(), Some("pkg"));
+    assert_eq!(metadata.version.as_deref(), Some("1.0"));
+    assert_eq!(metadata.license.as_deref(), Some("MIT"));
+}
+
+#[test]
+fn license_field_then_classifier() {
+    let field = parse_metadata("Name: a\nLicense: Apache-2.0\n");
+    assert_eq!(field.license.as_deref(), Some("Apache-2.0"));
+
+    let classifier = parse_metadata(
+        "Name: b\nLicense: UNKNOWN\nClassifier: License :: OSI Approved :: MIT License\n",
+    );
+    assert_eq!(classifier.license.as_deref(), Some("MIT"));
+}
+
+#[test]
+fn full_license_text_in_license_field_is_classified() {
+    let text = "Permission is hereby granted, free of charge, to any person obtaining a copy of \
+                this software and associated documentation files";
+    let metadata = parse_metadata(&format!("Name: c\nLicense: {text}\n"));
+    assert_eq!(metadata.license.as_deref(), Some("MIT"));
+}
+
+#[test]
+fn description_body_is_ignored() {
+    let metadata = parse_metadata("Name: d\n\nLicense: GPL-3.0-only\n");
+    assert_eq!(metadata.license, None);
+}
+
+#[test]
+fn reads_dist_info_from_venv() {
+    let root = tempfile::tempdir().unwrap();
+    let dist_info =
+        root.path().join(".venv/lib/python3.12/site-packages/requests-2.31.0.dist-info");
+    std::fs::create_dir_all(&dist_info).unwrap();
+    std::fs::write(
+        dist_info.join("METADATA"),
+        "Metadata-Version: 2.1\nName: requests\nVersion: 2.31.0\nLicense: Apache 2.0\n",
+    )
+    .unwrap();
+
+    let deps = collect(root.path());
+
+    assert_eq!(deps.len(), 1);
+    assert_eq!(deps[0].path, "requests@2.31.0");
+    assert_eq!(deps[0].license.as_deref(), Some("Apache 2.0"));
+    assert_eq!(
+        deps[0].lockfile,
+        PathBuf::from(".venv/lib/python3.12/site-packages/requests-2.31.0.dist-info/METADATA")
+    );
+}
@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! SPDX license expressions and allow/deny evaluation.
+//!
+//! Supports `AND`, `OR`, `WITH`, parentheses, and the legacy `/` separator
+//! used by older Cargo manifests (`MIT/Apache-2.0`, read as `OR`).
+
+/// A parsed SPDX license expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Expr {
+    /// A license identifier, with an optional `WITH` exception.
+    License {
+        id: String,
+        exception: Option<String>,
+    },
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+}
+
+/// Parse an SPDX expression. Returns `None` for malformed input.
+pub(super) fn parse(text: &str) -> Option<Expr> {
+    let tokens = tokenize(text);
+    let mut pos = 0;
+    let expr = parse_or(&tokens, &mut pos)?;
+    (pos == tokens.len()).then_some(expr)
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    let spaced = text.replace('(', " ( ").replace(')', " ) ").replace('/', " OR ");
+    spaced.split_whitespace().map(String::from).collect()
+}
+
+fn is_keyword(token: &str, keyword: &str) -> bool {
+    token.eq_ignore_ascii_case(keyword)
+}
+
+fn parse_or(tokens: &[String], pos: &mut usize) -> Option<Expr> {
+    let mut left = parse_and(tokens, pos)?;
+    while tokens.get(*pos).is_some_and(|t| is_keyword(t, "OR")) {
+        *pos += 1;
+        let right = parse_and(tokens, pos)?;
+        left = Expr::Or(Box::new(left), Box::new(right));
+    }
+    Some(left)
+}
+
+fn parse_and(tokens: &[String], pos: &mut usize) -> Option<Expr> {
+    let mut left = parse_atom(tokens, pos)?;
+    while tokens.get(*pos).is_some_and(|t| is_keyword(t, "AND")) {
+        *pos += 1;
+        let right = parse_atom(tokens, pos)?;
+        left = Expr::And(Box::new(left), Box::new(right));
+    }
+    Some(left)
+}
+
+fn parse_atom(tokens: &[String], pos: &mut usize) -> Option<Expr> {
+    let token = tokens.get(*pos)?;
+    *pos += 1;
+
+    if token == "(" {
+        let expr = parse_or(tokens, pos)?;
+        if tokens.get(*pos).map(String::as_str) != Some(")") {
+            return None;
+        }
+        *pos += 1;
+        return Some(expr);
+    }
+    if token == ")" || ["AND", "OR", "WITH"].iter().any(|k| is_keyword(token, k)) {
+        return None;
+    }
+
+    let mut exception = None;
+    if tokens.get(*pos).is_some_and(|t| is_keyword(t, "WITH")) {
+        let id = tokens.get(*pos + 1)?;
+        if id == "(" || id == ")" {
+            return None;
+        }
+        exception = Some(id.clone());
+        *pos += 2;
+    }
+    Some(Expr::License { id: token.clone(), exception })
+}
+
+/// Outcome of evaluating a license against the policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Verdict {
+    Allowed,
+    Disallowed,
+    /// The expression could not be parsed.
+    Unknown,
+}
+
+/// Allow and deny lists from `[check.license.deps]`.
+///
+/// Entries are SPDX identifiers compared case-insensitively; a trailing `*`
+/// matches any suffix (`GPL-*`). An empty allow list allows everything not
+/// denied.
+pub(super) struct LicensePolicy<'a> {
+    pub allow: &'a [String],
+    pub deny: &'a [String],
+}
+
+impl LicensePolicy<'_> {
+    /// Evaluate a license expression: `OR` needs one allowed branch, `AND`
+    /// needs every branch allowed.
+    pub(super) fn evaluate(&self, expression: &str) -> Verdict {
+        match parse(expression) {
+            Some(expr) if self.allows(&expr) => Verdict::Allowed,
+            Some(_) => Verdict::Disallowed,
+            None => Verdict::Unknown,
+        }
+    }
+
+    fn allows(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Or(a, b) => self.allows(a) || self.allows(b),
+            Expr::And(a, b) => self.allows(a) && self.allows(b),
+            Expr::License { id, exception } => {
+                let full = exception.as_ref().map(|e| format!("{id} WITH {e}"));
+                let names: Vec<&str> = full.as_deref().into_iter().chain([id.as_str()]).collect();
+
+                let denied = names.iter().any(|name| matches_any(self.deny, name));
+                let allowed =
+                    self.allow.is_empty() || names.iter().any(|name| matches_any(self.allow, name));
+                !denied && allowed
+            }
+        }
+    }
+}
+
+fn matches_any(patterns: &[String], id: &str) -> bool {
+    patterns.iter().any(|pattern| match pattern.strip_suffix('*') {
+        Some(prefix) => id.to_ascii_lowercase().starts_with(&prefix.to_ascii_lowercase()),
+        None => pattern.eq_ignore_ascii_case(id),
+    })
+}
+
+#[cfg(test)]
+#[path = "spdx_tests.rs"]
+mod tests;
@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+fn license(id: &str) -> Expr {
+    Expr::License { id: id.to_string(), exception: None }
+}
+
+fn strings(items: &[&str]) -> Vec<String> {
+    items.iter().map(|s| s.to_string()).collect()
+}
+
+// =============================================================================
+// PARSING TESTS
+// =============================================================================
+
+#[test]
+fn parses_single_identifier() {
+    assert_eq!(parse("MIT"), Some(license("MIT")));
+}
+
+#[test]
+fn and_binds_tighter_than_or() {
+    assert_eq!(
+        parse("MIT OR Apache-2.0 AND BSD-3-Clause"),
+        Some(Expr::Or(
+            Box::new(license("MIT")),
+            Box::new(Expr::And(Box::new(license("Apache-2.0")), Box::new(license("BSD-3-Clause")))),
+        ))
+    );
+}
+
+#[test]
+fn parses_parentheses_and_with() {
+    let expr = parse("(GPL-2.0-only WITH Classpath-exception-2.0) AND MIT").unwrap();
+    let Expr::And(left, _) = expr else { panic!("expected AND") };
+    assert_eq!(
+        *left,
+        Expr::License {
+            id: "GPL-2.0-only".to_string(),
+            exception: Some("Classpath-exception-2.0".to_string())
+        }
+    );
+}
+
+#[test]
+fn legacy_slash_is_or() {
+    assert_eq!(parse("MIT/Apache-2.0"), parse("MIT OR Apache-2.0"));
+}
+
+#[test]
+fn lowercase_operators() {
+    assert_eq!(parse("MIT or Apache-2.0"), parse("MIT OR Apache-2.0"));
+}
+
+#[yare::parameterized(
+    empty = { "" },
+    trailing_operator = { "MIT OR" },
+    unbalanced = { "(MIT OR Apache-2.0" },
+    free_text = { "SEE LICENSE IN LICENSE.txt" },
+    dangling_with = { "GPL-2.0 WITH" },
+)]
+fn rejects_malformed(text: &str) {
+    assert_eq!(parse(text), None);
+}
+
+// =============================================================================
+// POLICY TESTS
+// =============================================================================
+
+#[test]
+fn empty_allow_list_allows_everything_not_denied() {
+    let deny = strings(&["GPL-*"]);
+    let policy = LicensePolicy { allow: &[], deny: &deny };
+    assert_eq!(policy.evaluate("MIT"), Verdict::Allowed);
+    assert_eq!(policy.evaluate("GPL-3.0-only"), Verdict::Disallowed);
+}
+
+#[test]
+fn or_needs_one_allowed_branch() {
+    let allow = strings(&["MIT"]);
+    let policy = LicensePolicy { allow: &allow, deny: &[] };
+    assert_eq!(policy.evaluate("MIT OR GPL-3.0-only"), Verdict::Allowed);
+    assert_eq!(policy.evaluate("MIT AND GPL-3.0-only"), Verdict::Disallowed);
+}
+
+#[test]
+fn deny_wins_over_allow() {
+    let allow = strings(&["*"]);
+    let deny = strings(&["agpl-3.0-only"]);
+    let policy = LicensePolicy { allow: &allow, deny: &deny };
+    assert_eq!(policy.evaluate("AGPL-3.0-only"), Verdict::Disallowed);
+    assert_eq!(policy.evaluate("MIT"), Verdict::Allowed);
+}
+
+#[test]
+fn exception_can_be_allowed_as_a_whole() {
+    let allow = strings(&["GPL-2.0-only WITH Classpath-exception-2.0"]);
+    let policy = LicensePolicy { allow: &allow, deny: &[] };
+    assert_eq!(policy.evaluate("GPL-2.0-only WITH Classpath-exception-2.0"), Verdict::Allowed);
+    assert_eq!(policy.evaluate("GPL-2.0-only"), Verdict::Disallowed);
+}
+
+#[test]
+fn malformed_expression_is_unknown() {
+    let policy = LicensePolicy { allow: &[], deny: &[] };
+    assert_eq!(policy.evaluate("SEE LICENSE IN LICENSE"), Verdict::Unknown);
+}
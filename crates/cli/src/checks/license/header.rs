@@ -0,0 +1,265 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! License header templates and comment styles.
+//!
+//! A template is plain text with `{year}`, `{holder}` and `{license}`
+//! placeholders. It is rendered into the file's comment syntax when inserting
+//! headers, and matched line by line (ignoring comment markers and surrounding
+//! whitespace) when validating them.
+
+use regex::Regex;
+
+/// Default template: SPDX identifier and copyright line.
+pub(super) const SPDX_TEMPLATE: &str = "SPDX-License-Identifier: {license}\n\
+Copyright (c) {year} {holder}";
+
+/// Apache-2.0 boilerplate from the license appendix, with an SPDX identifier.
+pub(super) const APACHE_2_0_TEMPLATE: &str = "SPDX-License-Identifier: {license}\n\
+Copyright (c) {year} {holder}\n\
+\n\
+Licensed under the Apache License, Version 2.0 (the \"License\");\n\
+you may not use this file except in compliance with the License.\n\
+You may obtain a copy of the License at\n\n    http://www.apache.org/licenses/LICENSE-2.0\n\
+\n\
+Unless required by applicable law or agreed to in writing, software\n\
+distributed under the License is distributed on an \"AS IS\" BASIS,\n\
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.\n\
+See the License for the specific language governing permissions and\n\
+limitations under the License.";
+
+/// How header lines are wrapped in comments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum HeaderComment {
+    /// Every line starts with a prefix (e.g., `// `, `# `).
+    Line(&'static str),
+    /// One block comment with a per-line prefix (e.g., `/*`, ` * `, ` */`).
+    Block { open: &'static str, prefix: &'static str, close: &'static str },
+}
+
+/// Get the header comment style for a file extension.
+pub(super) fn header_comment_for_extension(ext: &str) -> HeaderComment {
+    match ext {
+        "css" | "scss" | "less" | "sql" => {
+            HeaderComment::Block { open: "/*", prefix: " * ", close: " */" }
+        }
+        "html" | "htm" | "xml" | "svg" => {
+            HeaderComment::Block { open: "<!--", prefix: "  ", close: "-->" }
+        }
+        "lua" => HeaderComment::Block { open: "--[[", prefix: "  ", close: "]]" },
+        _ => HeaderComment::Line(super::comment_prefix_for_extension(ext)),
+    }
+}
+
+/// Values substituted into a template.
+pub(super) struct HeaderFields<'a> {
+    pub license: &'a str,
+    pub holder: &'a str,
+    pub year: &'a str,
+}
+
+/// Values captured from a header that matches the template.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(super) struct TemplateMatch {
+    pub year: Option<String>,
+    pub license: Option<String>,
+}
+
+/// A compiled header template.
+pub(super) struct HeaderTemplate {
+    lines: Vec<String>,
+    matchers: Vec<Regex>,
+}
+
+impl HeaderTemplate {
+    /// Compile a template from config: a built-in name ("spdx", "apache-2.0")
+    /// or custom text.
+    pub(super) fn from_config(template: Option<&str>) -> Result<Self, regex::Error> {
+        let text = match template {
+            None => SPDX_TEMPLATE,
+            Some(name) if name.eq_ignore_ascii_case("spdx") => SPDX_TEMPLATE,
+            Some(name) if name.eq_ignore_ascii_case("apache-2.0") => APACHE_2_0_TEMPLATE,
+            Some(custom) => custom,
+        };
+        Self::new(text)
+    }
+
+    fn new(text: &str) -> Result<Self, regex::Error> {
+        let lines: Vec<String> = trim_blank_edges(text.lines().map(str::trim_end))
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let matchers = lines.iter().map(|line| line_matcher(line)).collect::<Result<_, _>>()?;
+        Ok(Self { lines, matchers })
+    }
+
+    /// Number of lines in the template.
+    pub(super) fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Render the template in the given comment style, ending with a newline.
+    pub(super) fn render(&self, fields: &HeaderFields, style: HeaderComment) -> String {
+        let lines = self.lines.iter().map(|line| {
+            line.replace("{year}", fields.year)
+                .replace("{holder}", fields.holder)
+                .replace("{license}", fields.license)
+        });
+
+        let mut out = String::new();
+        match style {
+            HeaderComment::Line(prefix) => {
+                for line in lines {
+                    push_prefixed(&mut out, prefix, &line);
+                }
+            }
+            HeaderComment::Block { open, prefix, close } => {
+                out.push_str(open);
+                out.push('\n');
+                for line in lines {
+                    push_prefixed(&mut out, prefix, &line);
+                }
+                out.push_str(close);
+                out.push('\n');
+            }
+        }
+        out
+    }
+
+    /// Match header text (comment markers already stripped) against the template.
+    pub(super) fn captures(&self, lines: &[String]) -> Option<TemplateMatch> {
+        let lines = trim_blank_edges(lines.iter().map(String::as_str));
+        if lines.len() != self.matchers.len() {
+            return None;
+        }
+
+        let mut found = TemplateMatch::default();
+        for (line, matcher) in lines.iter().zip(&self.matchers) {
+            let caps = matcher.captures(line.trim())?;
+            if let Some(year) = caps.name("year") {
+                found.year = Some(year.as_str().split_whitespace().collect());
+            }
+            if let Some(license) = caps.name("license") {
+                found.license = Some(license.as_str().to_string());
+            }
+        }
+        Some(found)
+    }
+}
+
+/// Push one rendered line, dropping trailing whitespace on blank lines.
+fn push_prefixed(out: &mut String, prefix: &str, line: &str) {
+    if line.is_empty() {
+        out.push_str(prefix.trim_end());
+    } else {
+        out.push_str(prefix);
+        out.push_str(line);
+    }
+    out.push('\n');
+}
+
+/// Drop leading and trailing blank lines.
+fn trim_blank_edges<'a>(lines: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+    let lines: Vec<&str> = lines.collect();
+    let start = lines.iter().position(|l| !l.trim().is_empty()).unwrap_or(lines.len());
+    let end = lines.iter().rposition(|l| !l.trim().is_empty()).map_or(start, |i| i + 1);
+    lines[start..end].to_vec()
+}
+
+/// Build an anchored regex for one template line.
+fn line_matcher(line: &str) -> Result<Regex, regex::Error> {
+    let mut pattern = regex::escape(line.trim());
+    for (placeholder, capture) in [
+        (r"\{year\}", r"\d{4}(?:\s*-\s*\d{4})?"),
+        (r"\{holder\}", r".+?"),
+        (r"\{license\}", r"\S+"),
+    ] {
+        let name = &placeholder[2..placeholder.len() - 2];
+        pattern = pattern.replacen(placeholder, &format!("(?P<{name}>{capture})"), 1);
+        pattern = pattern.replace(placeholder, &format!("(?:{capture})"));
+    }
+    Regex::new(&format!("^{pattern}$"))
+}
+
+/// The leading comment block of a file.
+#[derive(Debug, PartialEq, Eq)]
+pub(super) struct HeaderBlock {
+    /// Byte offset where the block starts.
+    pub start: usize,
+    /// Byte offset just past the block's last line.
+    pub end: usize,
+    /// Block text with comment markers and surrounding whitespace removed.
+    pub lines: Vec<String>,
+}
+
+/// Find the comment block at the top of the file, after any preamble line.
+///
+/// Line comments run until a blank line or a doc comment (`///`, `//!`).
+pub(super) fn find_header_block(content: &str, style: HeaderComment) -> Option<HeaderBlock> {
+    let start = preamble_end(content);
+    let mut offset = start;
+    let mut lines = Vec::new();
+
+    match style {
+        HeaderComment::Line(prefix) => {
+            let marker = prefix.trim_end();
+            for line in content[start..].split_inclusive('\n') {
+                let Some(rest) = line.trim().strip_prefix(marker) else {
+                    break;
+                };
+                if rest.starts_with(['/', '!']) {
+                    break;
+                }
+                lines.push(rest.trim().to_string());
+                offset += line.len();
+            }
+        }
+        HeaderComment::Block { open, prefix, close } => {
+            let close = close.trim();
+            let marker = prefix.trim();
+            let mut rest_lines = content[start..].split_inclusive('\n');
+            let first = rest_lines.next()?;
+            let mut text = first.trim().strip_prefix(open)?;
+            offset += first.len();
+            loop {
+                let close_pos = text.find(close);
+                let segment = text[..close_pos.unwrap_or(text.len())].trim();
+                let segment = match segment.strip_prefix(marker) {
+                    Some(rest) if !marker.is_empty() => rest,
+                    _ => segment,
+                };
+                lines.push(segment.trim().to_string());
+                if close_pos.is_some() {
+                    break;
+                }
+                let next = rest_lines.next()?;
+                text = next;
+                offset += next.len();
+            }
+        }
+    }
+
+    if offset == start {
+        return None;
+    }
+    Some(HeaderBlock { start, end: offset, lines })
+}
+
+/// Whether a line must stay above the license header (shebang, XML declaration).
+pub(super) fn is_preamble_line(line: &str) -> bool {
+    line.starts_with("#!")
+        || line.starts_with("<?xml")
+        || line.get(..9).is_some_and(|l| l.eq_ignore_ascii_case("<!doctype"))
+}
+
+/// Byte offset just past the preamble line, or 0 without one.
+pub(super) fn preamble_end(content: &str) -> usize {
+    if !is_preamble_line(content) {
+        return 0;
+    }
+    content.find('\n').map_or(content.len(), |pos| pos + 1)
+}
+
+#[cfg(test)]
+#[path = "header_tests.rs"]
+mod tests;
@@ -0,0 +1,216 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+fn fields() -> HeaderFields<'static> {
+    HeaderFields { license: "MIT", holder: "Test Org", year: "2026" }
+}
+
+fn lines(text: &[&str]) -> Vec<String> {
+    text.iter().map(|l| l.to_string()).collect()
+}
+
+// =============================================================================
+// COMMENT STYLE TESTS
+// =============================================================================
+
+#[test]
+fn line_comment_languages() {
+    assert_eq!(header_comment_for_extension("rs"), HeaderComment::Line("// "));
+    assert_eq!(header_comment_for_extension("py"), HeaderComment::Line("# "));
+}
+
+#[test]
+fn block_comment_languages() {
+    for ext in ["css", "scss", "sql"] {
+        assert!(matches!(
+            header_comment_for_extension(ext),
+            HeaderComment::Block { open: "/*", .. }
+        ));
+    }
+    for ext in ["html", "xml", "svg"] {
+        assert!(matches!(
+            header_comment_for_extension(ext),
+            HeaderComment::Block { open: "<!--", .. }
+        ));
+    }
+    assert!(matches!(
+        header_comment_for_extension("lua"),
+        HeaderComment::Block { open: "--[[", .. }
+    ));
+}
+
+// =============================================================================
+// RENDERING TESTS
+// =============================================================================
+
+#[test]
+fn render_default_template_line_style() {
+    let template = HeaderTemplate::from_config(None).unwrap();
+    assert_eq!(
+        template.render(&fields(), HeaderComment::Line("# ")),
+        "# SPDX-License-Identifier: MIT\n# Copyright (c) 2026 Test Org\n"
+    );
+}
+
+#[test]
+fn render_css_block() {
+    let template = HeaderTemplate::from_config(Some("spdx")).unwrap();
+    assert_eq!(
+        template.render(&fields(), header_comment_for_extension("css")),
+        "/*\n * SPDX-License-Identifier: MIT\n * Copyright (c) 2026 Test Org\n */\n"
+    );
+}
+
+#[test]
+fn render_html_block() {
+    let template = HeaderTemplate::from_config(None).unwrap();
+    assert_eq!(
+        template.render(&fields(), header_comment_for_extension("html")),
+        "<!--\n  SPDX-License-Identifier: MIT\n  Copyright (c) 2026 Test Org\n-->\n"
+    );
+}
+
+#[test]
+fn render_apache_blank_lines_have_no_trailing_space() {
+    let template = HeaderTemplate::from_config(Some("Apache-2.0")).unwrap();
+    let header = template.render(&fields(), HeaderComment::Line("// "));
+
+    assert!(
+        header.starts_with("// SPDX-License-Identifier: MIT\n// Copyright (c) 2026 Test Org\n//\n")
+    );
+    assert!(header.contains("//     http://www.apache.org/licenses/LICENSE-2.0\n"));
+    assert!(header.ends_with("// limitations under the License.\n"));
+    assert!(!header.lines().any(|l| l.ends_with(' ')));
+}
+
+#[test]
+fn render_custom_template() {
+    let template =
+        HeaderTemplate::from_config(Some("\nCopyright {year} {holder}. All rights reserved.\n"))
+            .unwrap();
+    assert_eq!(template.line_count(), 1);
+    assert_eq!(
+        template.render(&fields(), HeaderComment::Line("// ")),
+        "// Copyright 2026 Test Org. All rights reserved.\n"
+    );
+}
+
+// =============================================================================
+// MATCHING TESTS
+// =============================================================================
+
+#[test]
+fn captures_year_and_license() {
+    let template = HeaderTemplate::from_config(None).unwrap();
+    let found = template
+        .captures(&lines(&["SPDX-License-Identifier: Apache-2.0", "Copyright (c) 2020-2025 Other"]))
+        .unwrap();
+    assert_eq!(found.year.as_deref(), Some("2020-2025"));
+    assert_eq!(found.license.as_deref(), Some("Apache-2.0"));
+}
+
+#[test]
+fn captures_ignore_surrounding_blank_lines() {
+    let template = HeaderTemplate::from_config(None).unwrap();
+    let found = template.captures(&lines(&[
+        "",
+        "SPDX-License-Identifier: MIT",
+        "Copyright (c) 2026 Test Org",
+        "",
+    ]));
+    assert!(found.is_some());
+}
+
+#[test]
+fn captures_rejects_different_layout() {
+    let template = HeaderTemplate::from_config(Some("apache-2.0")).unwrap();
+    let found = template
+        .captures(&lines(&["SPDX-License-Identifier: Apache-2.0", "Copyright (c) 2026 Test Org"]));
+    assert!(found.is_none());
+}
+
+#[test]
+fn template_literal_text_is_escaped() {
+    let template = HeaderTemplate::from_config(Some("(c) {year} {holder} [*]")).unwrap();
+    assert!(template.captures(&lines(&["(c) 2026 Test Org [*]"])).is_some());
+    assert!(template.captures(&lines(&["c 2026 Test Org *"])).is_none());
+}
+
+// =============================================================================
+// HEADER BLOCK TESTS
+// =============================================================================
+
+#[test]
+fn finds_line_comment_block_until_blank_line() {
+    let content = "// SPDX-License-Identifier: MIT\n// Copyright (c) 2026 Test Org\n\n// other\nfn main() {}\n";
+    let block = find_header_block(content, HeaderComment::Line("// ")).unwrap();
+
+    assert_eq!(block.start, 0);
+    assert_eq!(&content[block.end..], "\n// other\nfn main() {}\n");
+    assert_eq!(
+        block.lines,
+        lines(&["SPDX-License-Identifier: MIT", "Copyright (c) 2026 Test Org"])
+    );
+}
+
+#[test]
+fn line_block_stops_at_doc_comments() {
+    let content = "// Copyright (c) 2026 Test Org\n//! Crate docs\n";
+    let block = find_header_block(content, HeaderComment::Line("// ")).unwrap();
+    assert_eq!(block.lines, lines(&["Copyright (c) 2026 Test Org"]));
+}
+
+#[test]
+fn finds_block_comment_after_xml_declaration() {
+    let content = "<?xml version=\"1.0\"?>\n<!--\n  Copyright (c) 2026 Test Org\n-->\n<root/>\n";
+    let block = find_header_block(content, header_comment_for_extension("xml")).unwrap();
+
+    assert_eq!(&content[..block.start], "<?xml version=\"1.0\"?>\n");
+    assert_eq!(&content[block.end..], "<root/>\n");
+    assert_eq!(block.lines, lines(&["", "Copyright (c) 2026 Test Org", ""]));
+}
+
+#[test]
+fn finds_single_line_block_comment() {
+    let content = "/* Copyright (c) 2026 Test Org */\nbody {}\n";
+    let block = find_header_block(content, header_comment_for_extension("css")).unwrap();
+    assert_eq!(block.lines, lines(&["Copyright (c) 2026 Test Org"]));
+    assert_eq!(&content[block.end..], "body {}\n");
+}
+
+#[test]
+fn strips_block_line_prefixes() {
+    let content = "/*\n * SPDX-License-Identifier: MIT\n * Copyright (c) 2026 Test Org */\n";
+    let block = find_header_block(content, header_comment_for_extension("css")).unwrap();
+    assert_eq!(
+        block.lines,
+        lines(&["", "SPDX-License-Identifier: MIT", "Copyright (c) 2026 Test Org"])
+    );
+}
+
+#[test]
+fn unterminated_block_is_not_a_header() {
+    assert!(find_header_block("/*\n * Copyright\n", header_comment_for_extension("css")).is_none());
+}
+
+#[test]
+fn no_leading_comment_is_not_a_header() {
+    assert!(find_header_block("fn main() {}\n", HeaderComment::Line("// ")).is_none());
+}
+
+// =============================================================================
+// PREAMBLE TESTS
+// =============================================================================
+
+#[test]
+fn preamble_lines() {
+    assert!(is_preamble_line("#!/bin/sh"));
+    assert!(is_preamble_line("<?xml version=\"1.0\"?>"));
+    assert!(is_preamble_line("<!DOCTYPE html>"));
+    assert!(is_preamble_line("<!doctype html>"));
+    assert!(!is_preamble_line("<!-- comment -->"));
+    assert_eq!(preamble_end("#!/bin/sh\necho\n"), 10);
+    assert_eq!(preamble_end("echo\n"), 0);
+}
@@ -3,8 +3,16 @@
 
 //! License header check.
 //!
-//! Validates SPDX license headers per docs/specs/checks/license-headers.md.
+//! Validates license headers per docs/specs/checks/license-headers.md,
+//! and dependency licenses when `[check.license.deps]` is configured.
 
+mod deps;
+mod header;
+mod year;
+
+pub use deps::DEPENDENCY_VIOLATION_TYPES;
+
+use std::collections::HashMap;
 use std::path::Path;
 use std::sync::LazyLock;
 
@@ -14,7 +22,12 @@ use regex::Regex;
 use serde_json::json;
 
 use crate::check::{Check, CheckContext, CheckResult, Violation};
+use crate::config::LicenseYearPolicy;
 use crate::file_reader::FileContent;
+use header::{
+    HeaderFields, HeaderTemplate, find_header_block, header_comment_for_extension, is_preamble_line,
+};
+use year::YearRule;
 
 /// Regex pattern for matching SPDX-License-Identifier header lines.
 #[allow(clippy::expect_used)]
@@ -56,29 +69,66 @@ impl Check for LicenseCheck {
             return CheckResult::passed(self.name());
         }
 
-        // If license is not configured, skip silently (disabled by default)
+        let mut violations = Vec::new();
+        let deps_metrics = deps::check_dependencies(ctx, &config.deps, &mut violations);
+
+        // If license is not configured, only dependencies are checked
         let expected_license = match &config.license {
             Some(l) => l.as_str(),
-            None => return CheckResult::passed(self.name()),
+            None => {
+                let result = if violations.is_empty() {
+                    CheckResult::passed(self.name())
+                } else {
+                    CheckResult::failed(self.name(), violations)
+                };
+                return match deps_metrics {
+                    Some(deps) => result.with_metrics(json!({ "dependencies": deps })),
+                    None => result,
+                };
+            }
         };
 
         // Copyright holder for fix mode
         let expected_copyright = config.copyright.as_deref().unwrap_or("Unknown");
         let current_year = chrono::Utc::now().year();
 
+        let template = match HeaderTemplate::from_config(config.template.as_deref()) {
+            Ok(t) => t,
+            Err(e) => {
+                return CheckResult::skipped(self.name(), format!("invalid license template: {e}"));
+            }
+        };
+        // Scan enough lines for the whole template (at least the first 10)
+        let header_window = template.line_count().max(8) + 2;
+
+        // First-commit years are only needed for history-based year policies.
+        // Without readable history every header would look wrong, so skip instead.
+        let first_years = match config.year {
+            LicenseYearPolicy::Current => HashMap::new(),
+            _ => match crate::git::first_commit_years(ctx.root) {
+                Ok(years) => years,
+                Err(e) => {
+                    return CheckResult::skipped(
+                        self.name(),
+                        format!("cannot read first-commit years from git history: {e}"),
+                    );
+                }
+            },
+        };
+
         // Build include patterns matcher (if patterns are configured)
         let include_matcher = build_include_matcher(&config.patterns);
 
         // Build exclude patterns matcher
         let exclude_matcher = build_exclude_matcher(&config.exclude);
 
-        let mut violations = Vec::new();
         let mut fixes = LicenseFixes::new();
         let mut files_checked = 0;
         let mut files_with_headers = 0;
         let mut files_missing_headers = 0;
         let mut files_outdated_year = 0;
         let mut files_wrong_license = 0;
+        let mut files_header_mismatch = 0;
 
         for file in ctx.files {
             let relative_path = file.path.strip_prefix(ctx.root).unwrap_or(&file.path);
@@ -106,91 +156,190 @@ impl Check for LicenseCheck {
 
             // Get file extension for comment syntax
             let ext = file.path.extension().and_then(|e| e.to_str()).unwrap_or("");
+            let style = header_comment_for_extension(ext);
 
             // Get first N lines for header check (skip shebang)
-            let header_lines = get_header_lines(content, 10);
+            let header_lines = get_header_lines(content, header_window);
+            let block = find_header_block(content, style);
+            let template_match = block.as_ref().and_then(|b| template.captures(&b.lines));
 
             // Check for SPDX header
             let spdx_match = SPDX_PATTERN.captures(&header_lines);
             let copyright_match = COPYRIGHT_PATTERN.captures(&header_lines);
+            let has_header =
+                template_match.is_some() || (spdx_match.is_some() && copyright_match.is_some());
+
+            // Files missing from the history are untracked, so new this year
+            let year_rule = YearRule {
+                policy: config.year,
+                first: first_years.get(relative_path).copied().unwrap_or(current_year),
+                current: current_year,
+            };
+
+            if !has_header {
+                // Missing header
+                files_missing_headers += 1;
+
+                if ctx.fix {
+                    // Generate and insert header
+                    let year = year_rule.expected();
+                    let fields = HeaderFields {
+                        license: expected_license,
+                        holder: expected_copyright,
+                        year: &year,
+                    };
+                    let header = template.render(&fields, style);
+
+                    // With a template, an unrecognized copyright block is replaced
+                    // rather than left below the new header
+                    let replaced = block.as_ref().filter(|b| {
+                        config.template.is_some() && b.lines.iter().any(|l| mentions_license(l))
+                    });
+                    let new_content = match replaced {
+                        Some(block) => {
+                            format!("{}{header}{}", &content[..block.start], &content[block.end..])
+                        }
+                        None => insert_header_preserving_shebang(content, &header),
+                    };
 
-            match (spdx_match, copyright_match) {
-                (None, _) | (_, None) => {
-                    // Missing header
-                    files_missing_headers += 1;
+                    if !ctx.dry_run {
+                        let _ = std::fs::write(&file.path, &new_content);
+                    }
+                    match replaced {
+                        Some(_) => {
+                            fixes.headers_normalized.push(relative_path.display().to_string())
+                        }
+                        None => fixes.headers_added.push(relative_path.display().to_string()),
+                    }
+                } else {
+                    violations.push(Violation::file_only(
+                        relative_path,
+                        "missing_header",
+                        "missing license header. Add SPDX-License-Identifier and Copyright at file start.",
+                    ));
+                }
+            } else {
+                files_with_headers += 1;
+                let found_license = spdx_match
+                    .as_ref()
+                    .and_then(|spdx| spdx.get(1))
+                    .map(|m| m.as_str())
+                    .or_else(|| template_match.as_ref().and_then(|t| t.license.as_deref()));
+                let found_year =
+                    template_match.as_ref().and_then(|t| t.year.as_deref()).or_else(|| {
+                        copyright_match.as_ref().and_then(|c| c.get(1)).map(|m| m.as_str())
+                    });
+
+                // Check license identifier
+                let license_ok = found_license.is_none_or(|found| found == expected_license);
+                if let Some(found_license) = found_license
+                    && !license_ok
+                {
+                    files_wrong_license += 1;
+                    // Don't auto-fix wrong license (too risky), just report
+                    violations.push(
+                        Violation::file(
+                            relative_path,
+                            find_line_number(content, "SPDX-License-Identifier"),
+                            "wrong_license",
+                            format!(
+                                "Expected: {}, found: {}. Update or run --fix to correct.",
+                                expected_license, found_license
+                            ),
+                        )
+                        .with_expected_found(expected_license, found_license),
+                    );
+                }
+
+                // Check header layout against an explicitly configured template
+                let template_ok = config.template.is_none() || template_match.is_some();
+                let year_ok = found_year.is_some_and(|found| year_rule.accepts(found));
+
+                if !template_ok && ctx.fix && license_ok {
+                    // Normalize the header to the template, keeping a valid year
+                    files_header_mismatch += 1;
+                    let year = year_rule.fixed(found_year);
+                    let fields = HeaderFields {
+                        license: expected_license,
+                        holder: expected_copyright,
+                        year: &year,
+                    };
+                    let header = template.render(&fields, style);
+                    let new_content = match &block {
+                        Some(block) => {
+                            format!("{}{header}{}", &content[..block.start], &content[block.end..])
+                        }
+                        None => insert_header_preserving_shebang(content, &header),
+                    };
+
+                    if !ctx.dry_run {
+                        let _ = std::fs::write(&file.path, &new_content);
+                    }
+                    fixes.headers_normalized.push(relative_path.display().to_string());
+                    continue;
+                }
+
+                if !template_ok {
+                    files_header_mismatch += 1;
+                    let line = block.as_ref().map_or(1, |b| line_number_at(content, b.start));
+                    violations.push(Violation::file(
+                        relative_path,
+                        line,
+                        "header_mismatch",
+                        "License header does not match the configured template. Update it or run --fix.",
+                    ));
+                }
+
+                // Check copyright year against the year policy
+                if !year_ok {
+                    files_outdated_year += 1;
+                    let found_year = found_year.unwrap_or("");
 
                     if ctx.fix {
-                        // Generate and insert header
-                        let header = generate_header(
-                            expected_license,
-                            expected_copyright,
-                            current_year,
-                            ext,
-                        );
-                        let new_content = insert_header_preserving_shebang(content, &header);
+                        // Update year in content
+                        let new_content = if config.year == LicenseYearPolicy::Current
+                            && copyright_match.is_some()
+                        {
+                            update_copyright_year(content, current_year)
+                        } else {
+                            let end = block.as_ref().map_or_else(
+                                || {
+                                    content
+                                        .split_inclusive('\n')
+                                        .take(header_window + 1)
+                                        .map(str::len)
+                                        .sum()
+                                },
+                                |b| b.end,
+                            );
+                            let year = year_rule.fixed(Some(found_year));
+                            replace_header_year(content, end, found_year, &year)
+                        };
 
                         if !ctx.dry_run {
                             let _ = std::fs::write(&file.path, &new_content);
                         }
-                        fixes.headers_added.push(relative_path.display().to_string());
+                        fixes.years_updated.push(relative_path.display().to_string());
                     } else {
-                        violations.push(Violation::file_only(
-                            relative_path,
-                            "missing_header",
-                            "missing license header. Add SPDX-License-Identifier and Copyright at file start.",
-                        ));
-                    }
-                }
-                (Some(spdx), Some(copyright)) => {
-                    files_with_headers += 1;
-                    let found_license = spdx.get(1).map(|m| m.as_str()).unwrap_or("");
-                    let found_year = copyright.get(1).map(|m| m.as_str()).unwrap_or("");
-
-                    // Check license identifier
-                    if found_license != expected_license {
-                        files_wrong_license += 1;
-                        // Don't auto-fix wrong license (too risky), just report
+                        let expected_year = year_rule.expected();
+                        let line = if copyright_match.is_some() {
+                            find_line_number(content, "Copyright")
+                        } else {
+                            find_line_number(content, found_year)
+                        };
                         violations.push(
                             Violation::file(
                                 relative_path,
-                                find_line_number(content, "SPDX-License-Identifier"),
-                                "wrong_license",
+                                line,
+                                year_rule.violation_type(),
                                 format!(
-                                    "Expected: {}, found: {}. Update or run --fix to correct.",
-                                    expected_license, found_license
+                                    "Expected: {}, found: {}. Update copyright year or run --fix.",
+                                    expected_year, found_year
                                 ),
                             )
-                            .with_expected_found(expected_license, found_license),
+                            .with_expected_found(expected_year, found_year),
                         );
                     }
-
-                    // Check copyright year includes current year
-                    if !year_includes_current(found_year, current_year) {
-                        files_outdated_year += 1;
-
-                        if ctx.fix {
-                            // Update year in content
-                            let new_content = update_copyright_year(content, current_year);
-
-                            if !ctx.dry_run {
-                                let _ = std::fs::write(&file.path, &new_content);
-                            }
-                            fixes.years_updated.push(relative_path.display().to_string());
-                        } else {
-                            violations.push(
-                                Violation::file(
-                                    relative_path,
-                                    find_line_number(content, "Copyright"),
-                                    "outdated_year",
-                                    format!(
-                                        "Expected: {}, found: {}. Update copyright year or run --fix.",
-                                        current_year, found_year
-                                    ),
-                                )
-                                .with_expected_found(current_year.to_string(), found_year),
-                            );
-                        }
-                    }
                 }
             }
 
@@ -233,13 +382,17 @@ impl Check for LicenseCheck {
             );
         }
 
-        let metrics = json!({
+        let mut metrics = json!({
             "files_checked": files_checked,
             "files_with_headers": files_with_headers,
             "files_missing_headers": files_missing_headers,
             "files_outdated_year": files_outdated_year,
             "files_wrong_license": files_wrong_license,
+            "files_header_mismatch": files_header_mismatch,
         });
+        if let (Some(metrics), Some(deps)) = (metrics.as_object_mut(), deps_metrics) {
+            metrics.insert("dependencies".to_string(), deps);
+        }
 
         // Determine result based on violations and fixes
         if violations.is_empty() {
@@ -326,15 +479,17 @@ fn is_supported_extension(ext: &str) -> bool {
         "rs" | "ts" | "tsx" | "js" | "jsx" | "go" | "c" | "cpp" | "h"
         // Shell/Python/Ruby/YAML
         | "sh" | "bash" | "py" | "rb" | "yaml" | "yml"
+        // Block comment languages
+        | "css" | "scss" | "less" | "html" | "htm" | "xml" | "svg" | "sql" | "lua"
     )
 }
 
-/// Get header lines from content, skipping shebang if present.
+/// Get header lines from content, skipping shebang or XML declaration if present.
 fn get_header_lines(content: &str, max_lines: usize) -> String {
     let lines: Vec<&str> = content.lines().take(max_lines + 1).collect();
 
-    // Skip shebang line
-    let start = if lines.first().map(|l| l.starts_with("#!")).unwrap_or(false) { 1 } else { 0 };
+    // Skip preamble line
+    let start = if lines.first().is_some_and(|l| is_preamble_line(l)) { 1 } else { 0 };
 
     lines[start..].iter().take(max_lines).copied().collect::<Vec<_>>().join("\n")
 }
@@ -349,6 +504,17 @@ fn find_line_number(content: &str, pattern: &str) -> u32 {
     1
 }
 
+/// Whether a header comment line reads like a license notice.
+fn mentions_license(line: &str) -> bool {
+    let line = line.to_ascii_lowercase();
+    line.contains("copyright") || line.contains("spdx-license-identifier")
+}
+
+/// Line number (1-indexed) of a byte offset.
+fn line_number_at(content: &str, offset: usize) -> u32 {
+    content[..offset].matches('\n').count() as u32 + 1
+}
+
 /// Check if year string includes the current year.
 /// Handles formats: "2026" or "2020-2026"
 fn year_includes_current(year_str: &str, current_year: i32) -> bool {
@@ -379,19 +545,10 @@ fn comment_prefix_for_extension(ext: &str) -> &'static str {
     }
 }
 
-/// Generate a license header for a file.
-fn generate_header(license: &str, copyright_holder: &str, year: i32, ext: &str) -> String {
-    let prefix = comment_prefix_for_extension(ext);
-    format!(
-        "{prefix}SPDX-License-Identifier: {license}\n\
-         {prefix}Copyright (c) {year} {copyright_holder}\n"
-    )
-}
-
-/// Insert header at file start, preserving shebang if present.
+/// Insert header at file start, preserving a shebang or XML declaration if present.
 fn insert_header_preserving_shebang(content: &str, header: &str) -> String {
-    if content.starts_with("#!") {
-        // Find end of shebang line
+    if is_preamble_line(content) {
+        // Find end of preamble line
         if let Some(newline_pos) = content.find('\n') {
             let shebang = &content[..=newline_pos];
             let rest = &content[newline_pos + 1..];
@@ -436,6 +593,16 @@ fn update_copyright_year(content: &str, current_year: i32) -> String {
     result
 }
 
+/// Replace the first occurrence of `found` year before byte offset `end`.
+fn replace_header_year(content: &str, end: usize, found: &str, year: &str) -> String {
+    match content[..end.min(content.len())].find(found) {
+        Some(pos) if !found.is_empty() => {
+            format!("{}{year}{}", &content[..pos], &content[pos + found.len()..])
+        }
+        _ => content.to_string(),
+    }
+}
+
 /// Check LICENSE or README.md file for outdated copyright year.
 // TODO(refactor): Extract common parameters into LicenseCheckContext
 #[allow(clippy::too_many_arguments)]
@@ -513,6 +680,7 @@ fn check_root_file(
 #[derive(Debug, Default)]
 struct LicenseFixes {
     headers_added: Vec<String>,
+    headers_normalized: Vec<String>,
     years_updated: Vec<String>,
 }
 
@@ -522,15 +690,19 @@ impl LicenseFixes {
     }
 
     fn is_empty(&self) -> bool {
-        self.headers_added.is_empty() && self.years_updated.is_empty()
+        self.headers_added.is_empty()
+            && self.headers_normalized.is_empty()
+            && self.years_updated.is_empty()
     }
 
     fn to_json(&self) -> serde_json::Value {
         json!({
             "headers_added": self.headers_added.len(),
+            "headers_normalized": self.headers_normalized.len(),
             "years_updated": self.years_updated.len(),
             "files": {
                 "added": self.headers_added,
+                "normalized": self.headers_normalized,
                 "updated": self.years_updated,
             }
         })
@@ -538,5 +710,5 @@ impl LicenseFixes {
 }
 
 #[cfg(test)]
-#[path = "license_tests.rs"]
+#[path = "mod_tests.rs"]
 mod tests;
@@ -3,6 +3,14 @@
 
 use super::*;
 
+/// Generate the default SPDX license header for a file.
+fn generate_header(license: &str, copyright_holder: &str, year: i32, ext: &str) -> String {
+    let template = HeaderTemplate::from_config(None).unwrap();
+    let year = year.to_string();
+    let fields = HeaderFields { license, holder: copyright_holder, year: &year };
+    template.render(&fields, header_comment_for_extension(ext))
+}
+
 #[test]
 fn year_includes_current_single_year_match() {
     assert!(year_includes_current("2026", 2026));
@@ -225,3 +233,64 @@ fn update_year_no_trailing_newline() {
     assert!(!result.ends_with('\n'));
     assert!(result.contains("2020-2026"));
 }
+
+// =============================================================================
+// BLOCK COMMENT AND TEMPLATE TESTS
+// =============================================================================
+
+#[test]
+fn is_supported_extension_block_comment_languages() {
+    for ext in ["css", "scss", "html", "xml", "sql", "lua"] {
+        assert!(is_supported_extension(ext), "{ext}");
+    }
+}
+
+#[test]
+fn generate_header_css_uses_block_comment() {
+    let header = generate_header("MIT", "Test Org", 2026, "css");
+    assert_eq!(
+        header,
+        "/*\n * SPDX-License-Identifier: MIT\n * Copyright (c) 2026 Test Org\n */\n"
+    );
+}
+
+#[test]
+fn insert_header_after_xml_declaration() {
+    let content = "<?xml version=\"1.0\"?>\n<root/>\n";
+    let result = insert_header_preserving_shebang(content, "<!-- SPDX -->\n");
+    assert_eq!(result, "<?xml version=\"1.0\"?>\n<!-- SPDX -->\n\n<root/>\n");
+}
+
+#[test]
+fn get_header_lines_skips_xml_declaration() {
+    let lines = get_header_lines("<?xml version=\"1.0\"?>\n<!--\n  SPDX\n-->", 10);
+    assert!(lines.starts_with("<!--"));
+}
+
+#[test]
+fn replace_header_year_within_header_only() {
+    let content = "# Copyright 2020 Test Org\n\nprint(2020)\n";
+    let end = content.find("\n\n").unwrap();
+    let result = replace_header_year(content, end, "2020", "2020-2026");
+    assert_eq!(result, "# Copyright 2020-2026 Test Org\n\nprint(2020)\n");
+}
+
+#[test]
+fn replace_header_year_missing_leaves_content() {
+    let content = "# Copyright Test Org\n";
+    assert_eq!(replace_header_year(content, content.len(), "2020", "2026"), content);
+}
+
+#[test]
+fn mentions_license_detects_notices() {
+    assert!(mentions_license("Copyright 2020 Test Org"));
+    assert!(mentions_license("spdx-license-identifier: MIT"));
+    assert!(!mentions_license("Parser for config files"));
+}
+
+#[test]
+fn line_number_at_counts_newlines() {
+    let content = "a\nb\nc\n";
+    assert_eq!(line_number_at(content, 0), 1);
+    assert_eq!(line_number_at(content, 4), 3);
+}
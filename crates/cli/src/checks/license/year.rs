@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Copyright year policies for license headers.
+
+use crate::config::LicenseYearPolicy;
+
+/// The copyright year rule for one file.
+pub(super) struct YearRule {
+    pub policy: LicenseYearPolicy,
+    /// Year the file was first committed (current year for untracked files).
+    pub first: i32,
+    pub current: i32,
+}
+
+impl YearRule {
+    /// Whether a header year satisfies the policy.
+    pub(super) fn accepts(&self, found: &str) -> bool {
+        let found: String = found.split_whitespace().collect();
+        match self.policy {
+            LicenseYearPolicy::Current => super::year_includes_current(&found, self.current),
+            LicenseYearPolicy::FirstCommit | LicenseYearPolicy::Range => found == self.expected(),
+        }
+    }
+
+    /// The year a new header gets, and the one reported in violations.
+    pub(super) fn expected(&self) -> String {
+        match self.policy {
+            LicenseYearPolicy::Current => self.current.to_string(),
+            LicenseYearPolicy::FirstCommit => self.first.to_string(),
+            LicenseYearPolicy::Range if self.first < self.current => {
+                format!("{}-{}", self.first, self.current)
+            }
+            LicenseYearPolicy::Range => self.current.to_string(),
+        }
+    }
+
+    /// The year to write when fixing a header that has `found`.
+    ///
+    /// The current-year policy extends the existing year into a range
+    /// (2020 → 2020-2026); the others use the expected year.
+    pub(super) fn fixed(&self, found: Option<&str>) -> String {
+        match (self.policy, found) {
+            (LicenseYearPolicy::Current, Some(found)) if self.accepts(found) => found.to_string(),
+            (LicenseYearPolicy::Current, Some(found)) => {
+                let start = found.split('-').next().unwrap_or(found).trim();
+                if start.parse::<i32>().is_ok_and(|start| start < self.current) {
+                    format!("{start}-{}", self.current)
+                } else {
+                    self.current.to_string()
+                }
+            }
+            _ => self.expected(),
+        }
+    }
+
+    /// Violation type for a header year that fails the policy.
+    pub(super) fn violation_type(&self) -> &'static str {
+        match self.policy {
+            LicenseYearPolicy::Current => "outdated_year",
+            LicenseYearPolicy::FirstCommit | LicenseYearPolicy::Range => "wrong_year",
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "year_tests.rs"]
+mod tests;
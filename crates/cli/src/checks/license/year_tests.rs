@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+fn rule(policy: LicenseYearPolicy, first: i32) -> YearRule {
+    YearRule { policy, first, current: 2026 }
+}
+
+#[test]
+fn current_policy_accepts_years_including_current() {
+    let rule = rule(LicenseYearPolicy::Current, 2020);
+    assert!(rule.accepts("2026"));
+    assert!(rule.accepts("2020-2026"));
+    assert!(!rule.accepts("2025"));
+    assert_eq!(rule.expected(), "2026");
+    assert_eq!(rule.violation_type(), "outdated_year");
+}
+
+#[test]
+fn current_policy_fix_extends_range() {
+    let rule = rule(LicenseYearPolicy::Current, 2020);
+    assert_eq!(rule.fixed(Some("2020")), "2020-2026");
+    assert_eq!(rule.fixed(Some("2018-2024")), "2018-2026");
+    assert_eq!(rule.fixed(Some("2020-2026")), "2020-2026");
+    assert_eq!(rule.fixed(None), "2026");
+}
+
+#[test]
+fn first_commit_policy_requires_first_year() {
+    let rule = rule(LicenseYearPolicy::FirstCommit, 2020);
+    assert!(rule.accepts("2020"));
+    assert!(!rule.accepts("2026"));
+    assert!(!rule.accepts("2020-2026"));
+    assert_eq!(rule.fixed(Some("2020-2026")), "2020");
+    assert_eq!(rule.violation_type(), "wrong_year");
+}
+
+#[test]
+fn range_policy_requires_first_through_current() {
+    let rule = rule(LicenseYearPolicy::Range, 2020);
+    assert!(rule.accepts("2020-2026"));
+    assert!(rule.accepts("2020 - 2026"));
+    assert!(!rule.accepts("2020-2025"));
+    assert!(!rule.accepts("2026"));
+    assert_eq!(rule.fixed(Some("2020-2025")), "2020-2026");
+}
+
+#[test]
+fn range_policy_single_year_for_new_files() {
+    let rule = rule(LicenseYearPolicy::Range, 2026);
+    assert_eq!(rule.expected(), "2026");
+    assert!(rule.accepts("2026"));
+}
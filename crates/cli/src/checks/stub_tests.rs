@@ -48,6 +48,7 @@ fn stub_check_result_marked_as_stub() {
         ci_mode: false,
         base_branch: None,
         staged: false,
+        commit_msg: None,
         verbose: false,
     };
 
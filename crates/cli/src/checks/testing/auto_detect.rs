@@ -8,7 +8,7 @@ use std::path::Path;
 use serde_json::json;
 
 use crate::check::{CheckResult, Violation};
-use crate::config::TestSuiteConfig;
+use crate::config::{Config, TestSuiteConfig};
 
 use super::runners::{
     RunnerContext, detect_go_runner, detect_js_runner, detect_py_runner, detect_rust_runner,
@@ -19,8 +19,10 @@ use super::suite::run_single_suite;
 ///
 /// Returns None if no runner can be detected.
 pub fn auto_detect_js_suite(root: &Path) -> Option<(TestSuiteConfig, String)> {
-    // Only auto-detect if package.json exists
-    if !root.join("package.json").exists() {
+    // Only auto-detect if package.json (or a Deno config) exists
+    let has_manifest =
+        ["package.json", "deno.json", "deno.jsonc"].iter().any(|name| root.join(name).exists());
+    if !has_manifest {
         return None;
     }
 
@@ -38,6 +40,8 @@ pub fn auto_detect_js_suite(root: &Path) -> Option<(TestSuiteConfig, String)> {
         max_avg: None,
         max_test: None,
         timeout: None,
+        exclusive: false,
+        envs: vec![],
     };
 
     Some((suite, detection.source.to_metric_string()))
@@ -61,6 +65,8 @@ pub fn auto_detect_py_suite(root: &Path) -> Option<(TestSuiteConfig, String)> {
         max_avg: None,
         max_test: None,
         timeout: None,
+        exclusive: false,
+        envs: vec![],
     };
 
     Some((suite, detection.source.to_metric_string()))
@@ -84,6 +90,8 @@ pub fn auto_detect_rust_suite(root: &Path) -> Option<(TestSuiteConfig, String)>
         max_avg: None,
         max_test: None,
         timeout: None,
+        exclusive: false,
+        envs: vec![],
     };
 
     Some((suite, detection.source.to_metric_string()))
@@ -107,11 +115,42 @@ pub fn auto_detect_go_suite(root: &Path) -> Option<(TestSuiteConfig, String)> {
         max_avg: None,
         max_test: None,
         timeout: None,
+        exclusive: false,
+        envs: vec![],
     };
 
     Some((suite, detection.source.to_metric_string()))
 }
 
+/// Auto-detect test suites from declarative adapters with a `test_command`.
+///
+/// Each command runs through the custom runner.
+pub fn auto_detect_adapter_suites(config: &Config) -> Vec<(TestSuiteConfig, String)> {
+    config
+        .adapters
+        .iter()
+        .filter_map(|adapter| {
+            let command = adapter.test_command.clone()?;
+            let suite = TestSuiteConfig {
+                runner: "custom".to_string(),
+                name: Some(format!("{} (auto-detected)", adapter.name)),
+                path: None,
+                setup: None,
+                command: Some(command),
+                targets: vec![],
+                ci: false,
+                max_total: None,
+                max_avg: None,
+                max_test: None,
+                timeout: None,
+                exclusive: false,
+                envs: vec![],
+            };
+            Some((suite, format!("adapter:{}", adapter.name)))
+        })
+        .collect()
+}
+
 /// Run an auto-detected test suite with optional detection source.
 pub fn run_auto_detected_suite(
     check_name: &str,
@@ -0,0 +1,292 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Test impact selection.
+//!
+//! With `[check.tests] select = "affected"`, suites only run when files they
+//! depend on changed relative to `--base` (or the staged set). Runners that
+//! support it are further narrowed to the affected packages.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use globset::Glob;
+
+use crate::adapter::JsWorkspace;
+use crate::adapter::rust::CargoWorkspace;
+use crate::check::CheckContext;
+use crate::config::TestSuiteConfig;
+
+use super::runners::is_glob_pattern;
+
+/// Changed files used to select affected suites.
+#[derive(Debug, Clone, Copy)]
+pub struct ChangeSet<'a> {
+    /// Changed files (absolute or root-relative).
+    pub files: &'a [PathBuf],
+    /// Base ref the files were compared against (None for staged changes).
+    pub base: Option<&'a str>,
+}
+
+/// Build the change set for a check run, if affected-suite selection applies.
+///
+/// Returns None when `select` isn't "affected" or no changed files are known
+/// (no `--base` or `--staged`), in which case every suite runs.
+pub fn change_set<'a>(ctx: &CheckContext<'a>) -> Option<ChangeSet<'a>> {
+    if !ctx.config.check.tests.select_affected() {
+        return None;
+    }
+    let files = ctx.changed_files?;
+    let base = if ctx.staged { None } else { ctx.base_branch };
+    Some(ChangeSet { files, base })
+}
+
+/// Outcome of impact selection for a single suite.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Selection {
+    /// Run the suite.
+    Run,
+    /// Skip the suite, with the reason reported to the user.
+    Skip(String),
+}
+
+/// File extensions and manifest names that feed each runner's tests.
+///
+/// Returns None for runners whose inputs can't be inferred (e.g., `custom`).
+fn runner_inputs(runner: &str) -> Option<(&'static [&'static str], &'static [&'static str])> {
+    match runner {
+        "cargo" => Some((&["rs"], &["Cargo.toml", "Cargo.lock", "rust-toolchain.toml"])),
+        "go" => Some((&["go"], &["go.mod", "go.sum"])),
+        "vitest" | "jest" | "bun" | "deno" | "mocha" | "playwright" => Some((
+            &["js", "jsx", "ts", "tsx", "mjs", "mts", "cjs", "cts", "vue", "svelte"],
+            &[
+                "package.json",
+                "package-lock.json",
+                "pnpm-lock.yaml",
+                "yarn.lock",
+                "bun.lockb",
+                "bun.lock",
+                "tsconfig.json",
+                "deno.json",
+                "deno.jsonc",
+                "deno.lock",
+            ],
+        )),
+        "pytest" | "unittest" | "tox" | "nox" => Some((
+            &["py", "pyi"],
+            &[
+                "pyproject.toml",
+                "setup.py",
+                "setup.cfg",
+                "requirements.txt",
+                "uv.lock",
+                "tox.ini",
+                "noxfile.py",
+            ],
+        )),
+        "rspec" | "minitest" | "cucumber" => {
+            Some((&["rb", "feature", "rake"], &["Gemfile", "Gemfile.lock", "Rakefile"]))
+        }
+        "gradle" | "maven" => Some((
+            &["java", "kt", "kts", "groovy", "scala"],
+            &[
+                "pom.xml",
+                "build.gradle",
+                "build.gradle.kts",
+                "settings.gradle",
+                "settings.gradle.kts",
+                "gradle.properties",
+                "libs.versions.toml",
+            ],
+        )),
+        "ctest" => Some((
+            &["c", "h", "cc", "cpp", "cxx", "hh", "hpp", "hxx", "cmake"],
+            &[
+                "CMakeLists.txt",
+                "CMakePresets.json",
+                "meson.build",
+                "conanfile.txt",
+                "conanfile.py",
+                "vcpkg.json",
+            ],
+        )),
+        "dotnet" => Some((
+            &["cs", "csproj", "sln", "slnx", "props", "targets"],
+            &["global.json", "Directory.Build.props", "Directory.Packages.props", "nuget.config"],
+        )),
+        "phpunit" | "pest" => {
+            Some((&["php"], &["composer.json", "composer.lock", "phpunit.xml", "phpunit.xml.dist"]))
+        }
+        "swift" => Some((&["swift"], &["Package.swift", "Package.resolved"])),
+        "mix" => Some((&["ex", "exs", "eex", "heex"], &["mix.exs", "mix.lock"])),
+        "bats" => Some((&["sh", "bash", "bats"], &[])),
+        _ => None,
+    }
+}
+
+/// Make a changed path relative to the project root.
+fn relative<'a>(path: &'a Path, root: &Path) -> &'a Path {
+    path.strip_prefix(root).unwrap_or(path)
+}
+
+/// Check if a file is an input for the given runner.
+fn is_runner_input(path: &Path, runner: &str) -> bool {
+    let Some((extensions, manifests)) = runner_inputs(runner) else {
+        return true;
+    };
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    extensions.contains(&ext) || manifests.contains(&name)
+}
+
+/// Decide whether a suite is affected by the changed files.
+pub fn select_suite(suite: &TestSuiteConfig, changes: &ChangeSet, root: &Path) -> Selection {
+    let changed: Vec<&Path> = changes.files.iter().map(|f| relative(f, root)).collect();
+
+    // Glob targets (e.g., "scripts/*.sh") are direct inputs of the suite
+    let target_matchers: Vec<_> = suite
+        .targets
+        .iter()
+        .filter(|t| is_glob_pattern(t))
+        .filter_map(|t| Glob::new(t).ok().map(|g| g.compile_matcher()))
+        .collect();
+    if changed.iter().any(|f| target_matchers.iter().any(|m| m.is_match(f))) {
+        return Selection::Run;
+    }
+
+    // Build targets exercise Rust code, so any Rust change is an input
+    let has_build_targets = suite.targets.iter().any(|t| !is_glob_pattern(t));
+    if has_build_targets && changed.iter().any(|f| is_runner_input(f, "cargo")) {
+        return Selection::Run;
+    }
+
+    // A ctest suite's path is its build tree, not a source directory; a dotnet
+    // suite's path is a test project whose `ProjectReference`s aren't tracked;
+    // a PHP or mix suite's path is a test directory exercising sources elsewhere
+    let path = suite.path.as_ref().filter(|_| {
+        !matches!(suite.runner.as_str(), "ctest" | "dotnet" | "phpunit" | "pest" | "mix")
+    });
+    let Some(path) = path else {
+        if runner_inputs(&suite.runner).is_none() {
+            return Selection::Run;
+        }
+        if changed.iter().any(|f| is_runner_input(f, &suite.runner)) {
+            return Selection::Run;
+        }
+        return Selection::Skip(format!("no changed {} inputs", suite.runner));
+    };
+
+    let suite_path = Path::new(path.trim_start_matches("./").trim_end_matches('/'));
+    let input_dirs = suite_input_dirs(suite_path, root);
+    let touches_inputs = changed.iter().any(|f| {
+        f.starts_with(suite_path)
+            || input_dirs.iter().any(|dir| f.starts_with(dir))
+                && (suite.command.is_some() || is_runner_input(f, &suite.runner))
+    });
+    if touches_inputs {
+        Selection::Run
+    } else {
+        Selection::Skip(format!("no changes under {} or its dependencies", path))
+    }
+}
+
+/// Directories whose changes affect a suite rooted at `path`.
+///
+/// These are the workspace packages (Cargo or JS) that the package enclosing
+/// the suite path depends on, including that package itself.
+fn suite_input_dirs(suite_path: &Path, root: &Path) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+
+    let cargo = CargoWorkspace::from_root(root);
+    if cargo.is_workspace
+        && let Some(pkg) = cargo.package_for_path(suite_path)
+    {
+        let deps = cargo.with_dependencies(&BTreeSet::from([pkg.to_string()]));
+        dirs.extend(deps.iter().filter_map(|d| cargo.package_dirs.get(d)).map(PathBuf::from));
+    }
+
+    let js = JsWorkspace::from_root(root);
+    if js.is_workspace
+        && let Some(pkg) = js.package_for_path(suite_path)
+    {
+        let deps = js.with_dependencies(&BTreeSet::from([pkg.to_string()]));
+        dirs.extend(deps.into_iter().map(PathBuf::from));
+    }
+
+    dirs
+}
+
+/// Cargo packages affected by the changes (changed members plus their dependents).
+///
+/// Returns None when every package should be tested, e.g. when the workspace
+/// manifest or lockfile changed or a Rust file lies outside any member.
+pub fn cargo_packages(changes: &ChangeSet, root: &Path) -> Option<Vec<String>> {
+    let workspace = CargoWorkspace::from_root(root);
+    if !workspace.is_workspace {
+        return None;
+    }
+
+    let mut changed = BTreeSet::new();
+    for file in changes.files.iter().map(|f| relative(f, root)) {
+        if !is_runner_input(file, "cargo") {
+            continue;
+        }
+        match workspace.package_for_path(file) {
+            Some(pkg) => {
+                changed.insert(pkg.to_string());
+            }
+            None => return None,
+        }
+    }
+
+    Some(workspace.with_dependents(&changed).into_iter().collect())
+}
+
+/// Go package patterns containing changed Go files (e.g., `./internal/api/...`).
+///
+/// Returns None when `go.mod`/`go.sum` changed and every package should be tested.
+pub fn go_packages(changes: &ChangeSet, root: &Path) -> Option<Vec<String>> {
+    let mut packages = BTreeSet::new();
+    for file in changes.files.iter().map(|f| relative(f, root)) {
+        let name = file.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        if name == "go.mod" || name == "go.sum" {
+            return None;
+        }
+        if file.extension().and_then(|e| e.to_str()) != Some("go") {
+            continue;
+        }
+        // Files in the module root belong to the root package, which may be imported anywhere
+        let dir = file.parent().map(|p| p.to_string_lossy().into_owned()).unwrap_or_default();
+        if dir.is_empty() {
+            return None;
+        }
+        packages.insert(format!("./{}/...", dir));
+    }
+    Some(packages.into_iter().collect())
+}
+
+/// Whether the runner itself limits tests to changed files (vitest `--changed`,
+/// jest `--changedSince`), so its coverage only reflects part of the project.
+pub fn runner_filters_changes(suite: &TestSuiteConfig) -> bool {
+    suite.command.is_none() && matches!(suite.runner.as_str(), "vitest" | "jest")
+}
+
+/// Package narrowing reported for a suite (for metrics and verbose output).
+pub fn narrowed_packages(
+    suite: &TestSuiteConfig,
+    changes: &ChangeSet,
+    root: &Path,
+) -> Option<Vec<String>> {
+    if suite.path.is_some() || suite.command.is_some() {
+        return None;
+    }
+    match suite.runner.as_str() {
+        "cargo" => cargo_packages(changes, root),
+        "go" => go_packages(changes, root),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[path = "impact_tests.rs"]
+mod tests;
@@ -0,0 +1,364 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use tempfile::TempDir;
+
+use super::*;
+
+fn suite(runner: &str, path: Option<&str>) -> TestSuiteConfig {
+    TestSuiteConfig {
+        runner: runner.to_string(),
+        name: None,
+        path: path.map(String::from),
+        setup: None,
+        command: None,
+        targets: vec![],
+        ci: false,
+        max_total: None,
+        max_avg: None,
+        max_test: None,
+        timeout: None,
+        exclusive: false,
+        envs: vec![],
+    }
+}
+
+fn files(paths: &[&str]) -> Vec<PathBuf> {
+    paths.iter().map(PathBuf::from).collect()
+}
+
+fn write(root: &Path, rel: &str, content: &str) {
+    let path = root.join(rel);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+/// Workspace with `cli` depending on `core`, and an unrelated `docs-gen`.
+fn cargo_workspace() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    write(dir.path(), "Cargo.toml", "[workspace]\nmembers = [\"crates/*\"]\n");
+    write(dir.path(), "crates/core/Cargo.toml", "[package]\nname = \"core\"\n");
+    write(
+        dir.path(),
+        "crates/cli/Cargo.toml",
+        "[package]\nname = \"cli\"\n\n[dependencies]\ncore = { path = \"../core\" }\n",
+    );
+    write(dir.path(), "crates/docs-gen/Cargo.toml", "[package]\nname = \"docs-gen\"\n");
+    dir
+}
+
+// =============================================================================
+// SUITE SELECTION
+// =============================================================================
+
+#[test]
+fn runs_suite_when_runner_inputs_changed() {
+    let dir = TempDir::new().unwrap();
+    let changed = files(&["src/lib.rs"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+
+    assert_eq!(select_suite(&suite("cargo", None), &changes, dir.path()), Selection::Run);
+}
+
+#[test]
+fn skips_suite_when_no_runner_inputs_changed() {
+    let dir = TempDir::new().unwrap();
+    let changed = files(&["README.md", "src/app.py"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+
+    assert_eq!(
+        select_suite(&suite("cargo", None), &changes, dir.path()),
+        Selection::Skip("no changed cargo inputs".to_string())
+    );
+}
+
+#[test]
+fn manifest_changes_select_suite() {
+    let dir = TempDir::new().unwrap();
+    let changed = files(&["package.json"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+
+    assert_eq!(select_suite(&suite("vitest", None), &changes, dir.path()), Selection::Run);
+}
+
+#[test]
+fn jvm_build_file_changes_select_suite() {
+    let dir = TempDir::new().unwrap();
+    let changed = files(&["build.gradle.kts"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+
+    assert_eq!(select_suite(&suite("gradle", None), &changes, dir.path()), Selection::Run);
+
+    let changed = files(&["src/main/kotlin/App.kt"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+    assert_eq!(select_suite(&suite("maven", None), &changes, dir.path()), Selection::Run);
+}
+
+#[test]
+fn cpp_changes_select_ctest_suite_regardless_of_build_dir() {
+    let dir = TempDir::new().unwrap();
+    let changed = files(&["src/parser.cc"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+    assert_eq!(select_suite(&suite("ctest", Some("build")), &changes, dir.path()), Selection::Run);
+
+    let changed = files(&["cmake/Warnings.cmake"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+    assert_eq!(select_suite(&suite("ctest", None), &changes, dir.path()), Selection::Run);
+
+    let changed = files(&["README.md"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+    assert!(matches!(
+        select_suite(&suite("ctest", Some("build")), &changes, dir.path()),
+        Selection::Skip(_)
+    ));
+}
+
+#[test]
+fn csharp_changes_select_dotnet_suite_regardless_of_test_project() {
+    let dir = TempDir::new().unwrap();
+    let suite = suite("dotnet", Some("tests/Acme.Tests/Acme.Tests.csproj"));
+
+    let changed = files(&["src/Acme.Core/Parser.cs"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+    assert_eq!(select_suite(&suite, &changes, dir.path()), Selection::Run);
+
+    let changed = files(&["Directory.Packages.props"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+    assert_eq!(select_suite(&suite, &changes, dir.path()), Selection::Run);
+
+    let changed = files(&["docs/index.md"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+    assert!(matches!(select_suite(&suite, &changes, dir.path()), Selection::Skip(_)));
+}
+
+#[test]
+fn php_changes_select_phpunit_suite_regardless_of_test_directory() {
+    let dir = TempDir::new().unwrap();
+    let suite = suite("phpunit", Some("tests/Unit"));
+
+    let changed = files(&["src/Http/Kernel.php"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+    assert_eq!(select_suite(&suite, &changes, dir.path()), Selection::Run);
+
+    let changed = files(&["composer.lock"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+    assert_eq!(select_suite(&suite, &changes, dir.path()), Selection::Run);
+
+    let changed = files(&["resources/js/app.ts"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+    assert!(matches!(select_suite(&suite, &changes, dir.path()), Selection::Skip(_)));
+}
+
+#[test]
+fn swift_changes_select_swift_suite_in_package_path() {
+    let dir = TempDir::new().unwrap();
+    let suite = suite("swift", Some("Packages/Core"));
+
+    let changed = files(&["Packages/Core/Sources/Core/Model.swift"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+    assert_eq!(select_suite(&suite, &changes, dir.path()), Selection::Run);
+
+    let changed = files(&["Packages/Core/Package.resolved"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+    assert_eq!(select_suite(&suite, &changes, dir.path()), Selection::Run);
+
+    let changed = files(&["Packages/App/Sources/App/View.swift"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+    assert!(matches!(select_suite(&suite, &changes, dir.path()), Selection::Skip(_)));
+}
+
+#[test]
+fn elixir_changes_select_mix_suite_regardless_of_test_directory() {
+    let dir = TempDir::new().unwrap();
+    let suite = suite("mix", Some("test/acme_web"));
+
+    let changed = files(&["lib/acme_web/templates/page.html.heex"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+    assert_eq!(select_suite(&suite, &changes, dir.path()), Selection::Run);
+
+    let changed = files(&["mix.lock"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+    assert_eq!(select_suite(&suite, &changes, dir.path()), Selection::Run);
+
+    let changed = files(&["assets/js/app.js"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+    assert!(matches!(select_suite(&suite, &changes, dir.path()), Selection::Skip(_)));
+}
+
+#[test]
+fn custom_runner_without_path_always_runs() {
+    let dir = TempDir::new().unwrap();
+    let changed = files(&["README.md"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+
+    assert_eq!(select_suite(&suite("custom", None), &changes, dir.path()), Selection::Run);
+}
+
+#[test]
+fn path_suite_runs_when_files_under_path_changed() {
+    let dir = TempDir::new().unwrap();
+    let changed = files(&["tests/cli/help.bats"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+
+    assert_eq!(
+        select_suite(&suite("bats", Some("tests/cli/")), &changes, dir.path()),
+        Selection::Run
+    );
+}
+
+#[test]
+fn path_suite_skips_when_changes_are_elsewhere() {
+    let dir = TempDir::new().unwrap();
+    let changed = files(&["scripts/deploy.sh"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+
+    assert_eq!(
+        select_suite(&suite("bats", Some("tests/cli/")), &changes, dir.path()),
+        Selection::Skip("no changes under tests/cli/ or its dependencies".to_string())
+    );
+}
+
+#[test]
+fn path_suite_runs_when_workspace_dependency_changed() {
+    let dir = cargo_workspace();
+    let changed = files(&["crates/core/src/lib.rs"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+
+    assert_eq!(
+        select_suite(&suite("cargo", Some("crates/cli")), &changes, dir.path()),
+        Selection::Run
+    );
+    assert!(matches!(
+        select_suite(&suite("cargo", Some("crates/docs-gen")), &changes, dir.path()),
+        Selection::Skip(_)
+    ));
+}
+
+#[test]
+fn path_suite_runs_when_js_workspace_dependency_changed() {
+    let dir = TempDir::new().unwrap();
+    write(dir.path(), "package.json", r#"{"workspaces": ["packages/*"]}"#);
+    write(dir.path(), "packages/core/package.json", r#"{"name": "@app/core"}"#);
+    write(
+        dir.path(),
+        "packages/web/package.json",
+        r#"{"name": "@app/web", "dependencies": {"@app/core": "workspace:*"}}"#,
+    );
+    let changed = files(&["packages/core/src/index.ts"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+
+    assert_eq!(
+        select_suite(&suite("vitest", Some("packages/web")), &changes, dir.path()),
+        Selection::Run
+    );
+}
+
+#[test]
+fn glob_targets_select_suite() {
+    let dir = TempDir::new().unwrap();
+    let changed = files(&["scripts/deploy.sh"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+    let mut bats = suite("bats", Some("tests/cli/"));
+    bats.targets = vec!["scripts/*.sh".to_string()];
+
+    assert_eq!(select_suite(&bats, &changes, dir.path()), Selection::Run);
+}
+
+#[test]
+fn absolute_changed_paths_are_made_relative() {
+    let dir = TempDir::new().unwrap();
+    let changed = vec![dir.path().join("tests/cli/help.bats")];
+    let changes = ChangeSet { files: &changed, base: None };
+
+    assert_eq!(
+        select_suite(&suite("bats", Some("tests/cli")), &changes, dir.path()),
+        Selection::Run
+    );
+}
+
+// =============================================================================
+// PACKAGE NARROWING
+// =============================================================================
+
+#[test]
+fn cargo_packages_include_dependents() {
+    let dir = cargo_workspace();
+    let changed = files(&["crates/core/src/lib.rs"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+
+    assert_eq!(
+        cargo_packages(&changes, dir.path()),
+        Some(vec!["cli".to_string(), "core".to_string()])
+    );
+}
+
+#[test]
+fn cargo_packages_only_changed_leaf() {
+    let dir = cargo_workspace();
+    let changed = files(&["crates/cli/src/main.rs", "README.md"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+
+    assert_eq!(cargo_packages(&changes, dir.path()), Some(vec!["cli".to_string()]));
+}
+
+#[test]
+fn cargo_packages_none_when_workspace_manifest_changed() {
+    let dir = cargo_workspace();
+    let changed = files(&["Cargo.lock", "crates/cli/src/main.rs"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+
+    assert_eq!(cargo_packages(&changes, dir.path()), None);
+}
+
+#[test]
+fn cargo_packages_none_for_single_package() {
+    let dir = TempDir::new().unwrap();
+    write(dir.path(), "Cargo.toml", "[package]\nname = \"app\"\n");
+    let changed = files(&["src/lib.rs"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+
+    assert_eq!(cargo_packages(&changes, dir.path()), None);
+}
+
+#[test]
+fn go_packages_from_changed_dirs() {
+    let dir = TempDir::new().unwrap();
+    let changed = files(&["internal/api/handler.go", "internal/api/routes.go", "pkg/util/str.go"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+
+    assert_eq!(
+        go_packages(&changes, dir.path()),
+        Some(vec!["./internal/api/...".to_string(), "./pkg/util/...".to_string()])
+    );
+}
+
+#[test]
+fn go_packages_none_when_module_changed() {
+    let dir = TempDir::new().unwrap();
+    let changed = files(&["go.sum", "pkg/util/str.go"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+
+    assert_eq!(go_packages(&changes, dir.path()), None);
+}
+
+#[test]
+fn go_packages_none_for_root_package() {
+    let dir = TempDir::new().unwrap();
+    let changed = files(&["main.go"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+
+    assert_eq!(go_packages(&changes, dir.path()), None);
+}
+
+#[test]
+fn narrowing_skipped_for_path_suites() {
+    let dir = cargo_workspace();
+    let changed = files(&["crates/core/src/lib.rs"]);
+    let changes = ChangeSet { files: &changed, base: Some("main") };
+
+    assert_eq!(narrowed_packages(&suite("cargo", Some("crates/cli")), &changes, dir.path()), None);
+    assert!(narrowed_packages(&suite("cargo", None), &changes, dir.path()).is_some());
+}
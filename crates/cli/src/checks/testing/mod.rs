@@ -8,6 +8,8 @@
 pub mod auto_detect;
 pub mod correlation;
 pub mod diff;
+pub mod impact;
+pub mod mutation;
 pub mod patterns;
 pub mod placeholder;
 pub mod runners;
@@ -29,12 +31,17 @@ use crate::adapter::{
 use crate::check::{Check, CheckContext, CheckResult, Violation};
 
 use self::auto_detect::{
-    auto_detect_go_suite, auto_detect_js_suite, auto_detect_py_suite, auto_detect_rust_suite,
+    auto_detect_adapter_suites, auto_detect_go_suite, auto_detect_js_suite, auto_detect_py_suite,
+    auto_detect_rust_suite,
 };
 use self::correlation::CorrelationConfig;
+use self::impact::change_set;
+use self::mutation::{MutationRun, run_mutation};
 use self::runners::{RunnerContext, filter_suites_for_mode};
-use self::suite::{SuiteResult, run_single_suite, run_suites};
-use self::thresholds::{check_coverage_thresholds, check_time_thresholds};
+use self::suite::{EnvSuiteResult, SuiteResult, run_single_suite, run_suites};
+use self::thresholds::{
+    check_coverage_thresholds, check_mutation_threshold, check_time_thresholds,
+};
 
 pub struct TestsCheck;
 
@@ -84,6 +91,9 @@ impl Check for TestsCheck {
                 auto_detected_suites.push((suite, source));
             }
 
+            // Declarative adapters with a test command
+            auto_detected_suites.extend(auto_detect_adapter_suites(ctx.config));
+
             // If we found any auto-detected suites, run them all
             if !auto_detected_suites.is_empty() {
                 return self.run_auto_detected_suites(ctx, auto_detected_suites);
@@ -163,9 +173,15 @@ impl TestsCheck {
                 if s.skipped_count > 0 {
                     obj["skipped_count"] = json!(s.skipped_count);
                 }
+                if s.skipped {
+                    obj["skipped"] = json!(true);
+                }
                 if let Some(ref err) = s.error {
                     obj["error"] = json!(err);
                 }
+                if let Some(ref packages) = s.affected_packages {
+                    obj["affected_packages"] = json!(packages);
+                }
                 if s.total_ms > 0 {
                     obj["total_ms"] = json!(s.total_ms);
                 }
@@ -187,10 +203,17 @@ impl TestsCheck {
                 if let Some(p99) = s.p99_ms {
                     obj["p99_ms"] = json!(p99);
                 }
+                if !s.envs.is_empty() {
+                    obj["envs"] = s.envs.iter().map(env_metrics).collect();
+                }
                 obj
             }).collect::<Vec<_>>(),
         });
 
+        if ctx.config.check.tests.select_affected() {
+            metrics["select"] = json!("affected");
+        }
+
         // Add optional aggregated timing metrics
         if let Some(avg) = agg.avg_ms {
             metrics["avg_ms"] = json!(avg);
@@ -213,12 +236,17 @@ impl TestsCheck {
         }
 
         // Collect coverage threshold violations
-        let coverage_violations = check_coverage_thresholds(
+        let mut coverage_violations = check_coverage_thresholds(
             &ctx.config.check.tests,
             &aggregated_coverage,
             &packages_coverage,
         );
 
+        // Mutation testing only runs once the suites pass
+        if suite_results.passed {
+            coverage_violations.extend(self.run_mutation_testing(ctx, &mut metrics));
+        }
+
         // Collect time threshold violations from each suite
         let mut time_violations = Vec::new();
         let active_suites = filter_suites_for_mode(&ctx.config.check.tests.suite, ctx.ci_mode);
@@ -252,6 +280,40 @@ impl TestsCheck {
         }
     }
 
+    /// Run mutation testing, adding its metrics and threshold violations.
+    fn run_mutation_testing(
+        &self,
+        ctx: &CheckContext,
+        metrics: &mut serde_json::Value,
+    ) -> Vec<(Violation, bool)> {
+        let is_error = ctx.config.check.tests.mutation.as_ref().is_some_and(|m| m.check == "error");
+        match run_mutation(ctx) {
+            None => Vec::new(),
+            Some(Err(err)) => {
+                vec![(Violation::file_only("<mutation>", "mutation_failed", err), is_error)]
+            }
+            Some(Ok(MutationRun { tool, report, partial })) => {
+                let Some(score) = report.total.score() else {
+                    return Vec::new();
+                };
+                metrics["mutation"] = json!({
+                    "tool": tool.name(),
+                    "score": score,
+                    "caught": report.total.caught,
+                    "missed": report.total.missed,
+                });
+                if partial {
+                    metrics["mutation"]["partial"] = json!(true);
+                }
+                let packages = report.package_scores();
+                if !packages.is_empty() {
+                    metrics["mutation_by_package"] = json!(packages);
+                }
+                check_mutation_threshold(&ctx.config.check.tests, tool.name(), score)
+            }
+        }
+    }
+
     /// Run all auto-detected test suites and aggregate results.
     fn run_auto_detected_suites(
         &self,
@@ -274,6 +336,7 @@ impl TestsCheck {
             collect_coverage: true,
             config: ctx.config,
             verbose: ctx.verbose,
+            changes: change_set(ctx),
         };
 
         // Run all auto-detected suites
@@ -424,6 +487,23 @@ fn aggregate_suite_coverage(
     (by_language, by_package)
 }
 
+/// Metrics for one environment of a tox/nox suite.
+fn env_metrics(env: &EnvSuiteResult) -> serde_json::Value {
+    let mut obj = json!({
+        "name": env.name,
+        "passed": env.passed,
+        "test_count": env.test_count,
+        "total_ms": env.total_ms,
+    });
+    if let Some(ref err) = env.error {
+        obj["error"] = json!(err);
+    }
+    if let Some(coverage) = env.coverage {
+        obj["coverage"] = json!(coverage);
+    }
+    obj
+}
+
 /// Build violations from failed suites.
 fn build_suite_violations(suites: &[&SuiteResult]) -> Vec<Violation> {
     suites
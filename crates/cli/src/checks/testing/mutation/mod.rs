@@ -0,0 +1,347 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Mutation testing.
+//!
+//! In CI mode, runs the configured (or detected) mutation tool once the
+//! test suites pass and reduces its JSON report to a mutation score.
+//!
+//! Reference: docs/specs/11-test-runners.md#mutation-testing
+
+mod report;
+
+pub use report::{
+    MutantCounts, MutationReport, parse_cargo_mutants, parse_go_mutesting,
+    parse_go_mutesting_summary, parse_mutmut_meta, parse_stryker,
+};
+
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::SystemTime;
+
+use crate::adapter::javascript::PackageManager;
+use crate::adapter::{ProjectLanguage, detect_language};
+use crate::check::CheckContext;
+use crate::config::TestsMutationConfig;
+
+use super::runners::{report_cutoff, run_with_timeout, scratch_path};
+
+/// Supported mutation testing tools.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MutationTool {
+    CargoMutants,
+    Mutmut,
+    Stryker,
+    GoMutesting,
+}
+
+impl MutationTool {
+    /// Look up a tool by its configured name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "cargo-mutants" => Some(Self::CargoMutants),
+            "mutmut" => Some(Self::Mutmut),
+            "stryker" => Some(Self::Stryker),
+            "go-mutesting" => Some(Self::GoMutesting),
+            _ => None,
+        }
+    }
+
+    /// Tool name as used in config and metrics.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::CargoMutants => "cargo-mutants",
+            Self::Mutmut => "mutmut",
+            Self::Stryker => "stryker",
+            Self::GoMutesting => "go-mutesting",
+        }
+    }
+
+    /// Default tool for a project language.
+    pub fn for_language(lang: ProjectLanguage) -> Option<Self> {
+        match lang {
+            ProjectLanguage::Rust => Some(Self::CargoMutants),
+            ProjectLanguage::Python => Some(Self::Mutmut),
+            ProjectLanguage::JavaScript => Some(Self::Stryker),
+            ProjectLanguage::Go => Some(Self::GoMutesting),
+            _ => None,
+        }
+    }
+
+    /// Check if a (root-relative) file is a source file this tool mutates.
+    pub fn mutates(self, path: &Path) -> bool {
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        let in_tests_dir = path.components().any(|c| c.as_os_str() == "tests");
+        match self {
+            Self::CargoMutants => ext == "rs" && !in_tests_dir && !name.ends_with("_tests.rs"),
+            Self::Mutmut => {
+                ext == "py" && !in_tests_dir && !name.starts_with("test_") && name != "conftest.py"
+            }
+            Self::Stryker => {
+                matches!(ext, "js" | "jsx" | "ts" | "tsx" | "mjs" | "mts" | "cjs" | "cts")
+                    && !name.contains(".test.")
+                    && !name.contains(".spec.")
+            }
+            Self::GoMutesting => ext == "go" && !name.ends_with("_test.go"),
+        }
+    }
+}
+
+/// Result of a mutation run.
+#[derive(Debug, Clone)]
+pub struct MutationRun {
+    pub tool: MutationTool,
+    pub report: MutationReport,
+    /// Only changed files were mutated, so the total covers part of the project.
+    pub partial: bool,
+}
+
+/// Run mutation testing if configured.
+///
+/// Returns None when it doesn't apply: outside CI mode, when disabled, or
+/// when the change set contains no source files for the tool.
+pub fn run_mutation(ctx: &CheckContext) -> Option<Result<MutationRun, String>> {
+    let config = ctx.config.check.tests.mutation.as_ref()?;
+    if !ctx.ci_mode || config.check == "off" {
+        return None;
+    }
+
+    let tool = match config.tool.as_deref() {
+        Some(name) => match MutationTool::from_name(name) {
+            Some(tool) => tool,
+            None => return Some(Err(format!("unknown mutation tool: {name}"))),
+        },
+        None => match MutationTool::for_language(detect_language(ctx.root)) {
+            Some(tool) => tool,
+            None => {
+                return Some(Err(
+                    "no mutation tool detected; set [check.tests.mutation] tool".to_string()
+                ));
+            }
+        },
+    };
+
+    // Limit mutations to changed source files when the change set is known
+    let files = match ctx.changed_files {
+        Some(changed) if config.changed_only => {
+            let files = changed_sources(tool, changed, ctx.root);
+            if files.is_empty() {
+                return None;
+            }
+            files
+        }
+        _ => Vec::new(),
+    };
+
+    if ctx.verbose {
+        let scope = if files.is_empty() {
+            "all files".to_string()
+        } else {
+            format!("{} changed files", files.len())
+        };
+        eprintln!("  Mutation testing: {} ({})", tool.name(), scope);
+    }
+
+    let partial = !files.is_empty();
+    Some(run_tool(tool, &files, config, ctx.root).map(|report| MutationRun {
+        tool,
+        report,
+        partial,
+    }))
+}
+
+/// Changed files the tool should mutate, relative to the project root.
+fn changed_sources(tool: MutationTool, changed: &[PathBuf], root: &Path) -> Vec<String> {
+    let mut files: Vec<String> = changed
+        .iter()
+        .map(|f| f.strip_prefix(root).unwrap_or(f))
+        .filter(|f| tool.mutates(f))
+        .map(|f| f.to_string_lossy().replace('\\', "/"))
+        .collect();
+    files.sort();
+    files.dedup();
+    files
+}
+
+/// Run the tool and parse its report.
+fn run_tool(
+    tool: MutationTool,
+    files: &[String],
+    config: &TestsMutationConfig,
+    root: &Path,
+) -> Result<MutationReport, String> {
+    let scratch = scratch_path("mutants");
+    let started = report_cutoff();
+
+    let mut cmd = mutation_command(tool, files, root, &scratch);
+    cmd.current_dir(root);
+    cmd.stdout(Stdio::piped());
+    cmd.stderr(Stdio::piped());
+
+    let child = cmd.spawn().map_err(|e| format!("failed to spawn {}: {e}", tool.name()))?;
+    let output = run_with_timeout(child, config.timeout).map_err(|e| match e.kind() {
+        ErrorKind::TimedOut => format!(
+            "{} timed out after {:?} - mutate fewer files or raise the timeout",
+            tool.name(),
+            config.timeout.unwrap_or_default()
+        ),
+        _ => format!("failed to run {}: {e}", tool.name()),
+    })?;
+
+    let report = read_report(tool, files, root, &scratch, started, &output.stdout);
+    let _ = std::fs::remove_dir_all(&scratch);
+
+    report.ok_or_else(|| {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        match stderr.lines().rev().find(|l| !l.trim().is_empty()) {
+            Some(line) => format!("{} produced no report: {}", tool.name(), line.trim()),
+            None => format!("{} produced no report", tool.name()),
+        }
+    })?
+}
+
+/// Build the tool command, restricted to `files` when non-empty.
+fn mutation_command(tool: MutationTool, files: &[String], root: &Path, scratch: &Path) -> Command {
+    match tool {
+        MutationTool::CargoMutants => {
+            let mut cmd = Command::new("cargo");
+            cmd.args(["mutants", "--no-shuffle", "--output"]);
+            cmd.arg(scratch);
+            for file in files {
+                cmd.args(["--file", file]);
+            }
+            cmd
+        }
+        MutationTool::Mutmut => {
+            let mut cmd = Command::new("mutmut");
+            cmd.arg("run");
+            cmd.args(files.iter().map(|f| mutmut_pattern(f)));
+            cmd
+        }
+        MutationTool::Stryker => {
+            let exec_cmd = PackageManager::detect(root).exec_command();
+            let mut cmd = Command::new(&exec_cmd[0]);
+            cmd.args(&exec_cmd[1..]);
+            cmd.args(["stryker", "run", "--reporters", "json"]);
+            if !files.is_empty() {
+                cmd.args(["--mutate", &files.join(",")]);
+            }
+            cmd
+        }
+        MutationTool::GoMutesting => {
+            let mut cmd = Command::new("go-mutesting");
+            if files.is_empty() {
+                cmd.arg("./...");
+            } else {
+                cmd.args(files);
+            }
+            cmd
+        }
+    }
+}
+
+/// mutmut mutant-name pattern for a source file (e.g., `src/pkg/mod.py` -> `pkg.mod.*`).
+pub fn mutmut_pattern(path: &str) -> String {
+    let module = path.strip_prefix("src/").unwrap_or(path);
+    let module = module.strip_suffix(".py").unwrap_or(module);
+    format!("{}.*", module.replace('/', "."))
+}
+
+/// Read the report written by this run, if any.
+fn read_report(
+    tool: MutationTool,
+    files: &[String],
+    root: &Path,
+    scratch: &Path,
+    started: SystemTime,
+    stdout: &[u8],
+) -> Option<Result<MutationReport, String>> {
+    match tool {
+        MutationTool::CargoMutants => {
+            let json = std::fs::read_to_string(scratch.join("mutants.out/outcomes.json")).ok()?;
+            Some(parse_cargo_mutants(&json))
+        }
+        MutationTool::Mutmut => read_mutmut_reports(&root.join("mutants"), files, started),
+        MutationTool::Stryker => {
+            let json = read_fresh(&root.join("reports/mutation/mutation.json"), started)?;
+            Some(parse_stryker(&json))
+        }
+        MutationTool::GoMutesting => match read_fresh(&root.join("report.json"), started) {
+            Some(json) => Some(parse_go_mutesting(&json)),
+            None => parse_go_mutesting_summary(&String::from_utf8_lossy(stdout)).map(Ok),
+        },
+    }
+}
+
+/// Read a report file only if it was written after `started` (not a stale run).
+fn read_fresh(path: &Path, started: SystemTime) -> Option<String> {
+    if !written_since(path, started) {
+        return None;
+    }
+    std::fs::read_to_string(path).ok()
+}
+
+/// Check if a file was modified at or after `started`.
+fn written_since(path: &Path, started: SystemTime) -> bool {
+    std::fs::metadata(path).and_then(|m| m.modified()).is_ok_and(|modified| modified >= started)
+}
+
+/// Combine mutmut `.meta` files written by this run, limited to `files` when non-empty.
+fn read_mutmut_reports(
+    dir: &Path,
+    files: &[String],
+    started: SystemTime,
+) -> Option<Result<MutationReport, String>> {
+    let mut metas = Vec::new();
+    collect_meta_files(dir, &mut metas);
+    // Metas left by earlier runs (or for deleted sources) are not this run's results
+    metas.retain(|meta| written_since(meta, started));
+    if metas.is_empty() {
+        return None;
+    }
+
+    let mut combined = MutationReport::default();
+    for meta in metas {
+        let Ok(rel) = meta.strip_prefix(dir) else {
+            continue;
+        };
+        let rel = rel.to_string_lossy().replace('\\', "/");
+        let source = rel.strip_suffix(".meta").unwrap_or(&rel);
+        if !files.is_empty() && !files.iter().any(|f| f == source) {
+            continue;
+        }
+        let Ok(json) = std::fs::read_to_string(&meta) else {
+            continue;
+        };
+        match parse_mutmut_meta(source, &json) {
+            Ok(report) => {
+                for (package, counts) in report.by_package {
+                    combined.add(package, counts);
+                }
+            }
+            Err(e) => return Some(Err(e)),
+        }
+    }
+    Some(Ok(combined))
+}
+
+/// Recursively collect `*.meta` files.
+fn collect_meta_files(dir: &Path, out: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_meta_files(&path, out);
+        } else if path.extension().is_some_and(|e| e == "meta") {
+            out.push(path);
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
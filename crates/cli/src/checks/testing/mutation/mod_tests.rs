@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use super::*;
+
+#[test]
+fn tool_names_round_trip() {
+    for tool in [
+        MutationTool::CargoMutants,
+        MutationTool::Mutmut,
+        MutationTool::Stryker,
+        MutationTool::GoMutesting,
+    ] {
+        assert_eq!(MutationTool::from_name(tool.name()), Some(tool));
+    }
+    assert_eq!(MutationTool::from_name("pitest"), None);
+}
+
+#[test]
+fn tool_detected_from_language() {
+    assert_eq!(MutationTool::for_language(ProjectLanguage::Rust), Some(MutationTool::CargoMutants));
+    assert_eq!(MutationTool::for_language(ProjectLanguage::Python), Some(MutationTool::Mutmut));
+    assert_eq!(
+        MutationTool::for_language(ProjectLanguage::JavaScript),
+        Some(MutationTool::Stryker)
+    );
+    assert_eq!(MutationTool::for_language(ProjectLanguage::Go), Some(MutationTool::GoMutesting));
+    assert_eq!(MutationTool::for_language(ProjectLanguage::Shell), None);
+}
+
+#[test]
+fn mutates_only_source_files() {
+    let rust = MutationTool::CargoMutants;
+    assert!(rust.mutates(Path::new("crates/core/src/lib.rs")));
+    assert!(!rust.mutates(Path::new("crates/core/src/lib_tests.rs")));
+    assert!(!rust.mutates(Path::new("tests/specs/main.rs")));
+    assert!(!rust.mutates(Path::new("README.md")));
+
+    let python = MutationTool::Mutmut;
+    assert!(python.mutates(Path::new("src/myapp/core.py")));
+    assert!(!python.mutates(Path::new("tests/test_core.py")));
+    assert!(!python.mutates(Path::new("conftest.py")));
+
+    let js = MutationTool::Stryker;
+    assert!(js.mutates(Path::new("src/math.ts")));
+    assert!(!js.mutates(Path::new("src/math.test.ts")));
+    assert!(!js.mutates(Path::new("src/math.spec.js")));
+
+    let go = MutationTool::GoMutesting;
+    assert!(go.mutates(Path::new("pkg/math/math.go")));
+    assert!(!go.mutates(Path::new("pkg/math/math_test.go")));
+}
+
+#[test]
+fn changed_sources_are_relative_sorted_and_filtered() {
+    let root = Path::new("/repo");
+    let changed = vec![
+        PathBuf::from("/repo/src/b.rs"),
+        PathBuf::from("/repo/src/a.rs"),
+        PathBuf::from("/repo/src/a_tests.rs"),
+        PathBuf::from("/repo/docs/guide.md"),
+    ];
+
+    let files = changed_sources(MutationTool::CargoMutants, &changed, root);
+
+    assert_eq!(files, vec!["src/a.rs", "src/b.rs"]);
+}
+
+#[test]
+fn mutmut_pattern_from_path() {
+    assert_eq!(mutmut_pattern("src/myapp/core.py"), "myapp.core.*");
+    assert_eq!(mutmut_pattern("myapp/util.py"), "myapp.util.*");
+}
+
+#[test]
+fn cargo_mutants_command_limits_files() {
+    let files = vec!["src/a.rs".to_string(), "src/b.rs".to_string()];
+    let cmd =
+        mutation_command(MutationTool::CargoMutants, &files, Path::new("."), Path::new("/tmp/out"));
+    let args: Vec<_> = cmd.get_args().map(|a| a.to_string_lossy().to_string()).collect();
+
+    assert_eq!(cmd.get_program(), "cargo");
+    assert_eq!(
+        args,
+        vec![
+            "mutants",
+            "--no-shuffle",
+            "--output",
+            "/tmp/out",
+            "--file",
+            "src/a.rs",
+            "--file",
+            "src/b.rs"
+        ]
+    );
+}
+
+#[test]
+fn go_mutesting_command_defaults_to_all_packages() {
+    let cmd = mutation_command(MutationTool::GoMutesting, &[], Path::new("."), Path::new("/tmp"));
+    let args: Vec<_> = cmd.get_args().map(|a| a.to_string_lossy().to_string()).collect();
+
+    assert_eq!(args, vec!["./..."]);
+}
+
+#[test]
+fn mutmut_reports_filtered_to_changed_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let mutants = dir.path().join("mutants");
+    std::fs::create_dir_all(mutants.join("src/myapp")).unwrap();
+    std::fs::write(
+        mutants.join("src/myapp/core.py.meta"),
+        r#"{"exit_code_by_key": {"a": 1, "b": 0}}"#,
+    )
+    .unwrap();
+    std::fs::write(mutants.join("src/myapp/util.py.meta"), r#"{"exit_code_by_key": {"c": 1}}"#)
+        .unwrap();
+
+    let all = read_mutmut_reports(&mutants, &[], SystemTime::UNIX_EPOCH).unwrap().unwrap();
+    assert_eq!(all.total, MutantCounts { caught: 2, missed: 1 });
+
+    let changed =
+        read_mutmut_reports(&mutants, &["src/myapp/core.py".to_string()], SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .unwrap();
+    assert_eq!(changed.total, MutantCounts { caught: 1, missed: 1 });
+}
+
+#[test]
+fn mutmut_without_meta_files_has_no_report() {
+    let dir = tempfile::tempdir().unwrap();
+    assert!(
+        read_mutmut_reports(&dir.path().join("mutants"), &[], SystemTime::UNIX_EPOCH).is_none()
+    );
+}
+
+#[test]
+fn mutmut_ignores_stale_meta_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let mutants = dir.path().join("mutants");
+    std::fs::create_dir_all(mutants.join("src/myapp")).unwrap();
+    std::fs::write(mutants.join("src/myapp/core.py.meta"), r#"{"exit_code_by_key": {"a": 1}}"#)
+        .unwrap();
+    let stale = mutants.join("src/myapp/deleted.py.meta");
+    std::fs::write(&stale, r#"{"exit_code_by_key": {"b": 0}}"#).unwrap();
+
+    let started = SystemTime::now() - std::time::Duration::from_secs(60);
+    let old = started - std::time::Duration::from_secs(3600);
+    std::fs::File::options().write(true).open(&stale).unwrap().set_modified(old).unwrap();
+
+    let report = read_mutmut_reports(&mutants, &[], started).unwrap().unwrap();
+    assert_eq!(report.total, MutantCounts { caught: 1, missed: 0 });
+
+    let future = SystemTime::now() + std::time::Duration::from_secs(60);
+    assert!(read_mutmut_reports(&mutants, &[], future).is_none());
+}
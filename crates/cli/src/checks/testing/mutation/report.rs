@@ -0,0 +1,242 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Mutation report parsing.
+//!
+//! Each tool's JSON report is reduced to caught/missed counts per package.
+//! Mutants that never ran against the tests (unviable, skipped, compile
+//! errors) don't count towards the score.
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+use crate::checks::testing::runners::{
+    extract_go_package, extract_js_package, extract_python_package,
+};
+
+/// Caught and missed mutant counts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MutantCounts {
+    /// Mutants the tests detected (killed or timed out).
+    pub caught: usize,
+    /// Mutants the tests let through (survived or not covered).
+    pub missed: usize,
+}
+
+impl MutantCounts {
+    /// Mutation score as a percentage, None when no mutant was tested.
+    pub fn score(&self) -> Option<f64> {
+        let tested = self.caught + self.missed;
+        if tested == 0 {
+            return None;
+        }
+        Some(self.caught as f64 / tested as f64 * 100.0)
+    }
+
+    fn add(&mut self, other: MutantCounts) {
+        self.caught += other.caught;
+        self.missed += other.missed;
+    }
+}
+
+/// Mutant counts overall and per package.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MutationReport {
+    pub total: MutantCounts,
+    pub by_package: BTreeMap<String, MutantCounts>,
+}
+
+impl MutationReport {
+    /// Record one tested mutant.
+    fn record(&mut self, package: String, caught: bool) {
+        let counts = MutantCounts { caught: caught as usize, missed: !caught as usize };
+        self.add(package, counts);
+    }
+
+    /// Add counts for a package.
+    pub fn add(&mut self, package: String, counts: MutantCounts) {
+        self.total.add(counts);
+        self.by_package.entry(package).or_default().add(counts);
+    }
+
+    /// Per-package scores, skipping packages without tested mutants.
+    pub fn package_scores(&self) -> BTreeMap<String, f64> {
+        self.by_package
+            .iter()
+            .filter_map(|(pkg, counts)| counts.score().map(|s| (pkg.clone(), s)))
+            .collect()
+    }
+}
+
+// =============================================================================
+// cargo-mutants
+// =============================================================================
+
+/// `mutants.out/outcomes.json` written by cargo-mutants.
+#[derive(Debug, Deserialize)]
+struct CargoMutantsOutcomes {
+    #[serde(default)]
+    outcomes: Vec<CargoMutantsOutcome>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoMutantsOutcome {
+    /// "Baseline" or `{"Mutant": {...}}`.
+    scenario: serde_json::Value,
+    summary: String,
+}
+
+/// Parse cargo-mutants `outcomes.json`.
+pub fn parse_cargo_mutants(json: &str) -> Result<MutationReport, String> {
+    let outcomes: CargoMutantsOutcomes =
+        serde_json::from_str(json).map_err(|e| format!("invalid cargo-mutants report: {e}"))?;
+
+    let mut report = MutationReport::default();
+    for outcome in outcomes.outcomes {
+        let Some(mutant) = outcome.scenario.get("Mutant") else {
+            continue;
+        };
+        let caught = match outcome.summary.as_str() {
+            "CaughtMutant" | "Timeout" => true,
+            "MissedMutant" => false,
+            _ => continue,
+        };
+        let package = mutant.get("package").and_then(|p| p.as_str()).unwrap_or("root");
+        report.record(package.to_string(), caught);
+    }
+    Ok(report)
+}
+
+// =============================================================================
+// mutmut
+// =============================================================================
+
+/// Per-file `.meta` JSON written by mutmut under `mutants/`.
+#[derive(Debug, Deserialize)]
+struct MutmutMeta {
+    #[serde(default)]
+    exit_code_by_key: BTreeMap<String, Option<i64>>,
+}
+
+/// Parse a mutmut `.meta` file for the given source path.
+///
+/// Exit code 0 (tests passed) and 5 (no tests) mean the mutant survived;
+/// 33/34 are skipped mutants and null is not yet tested.
+pub fn parse_mutmut_meta(source_path: &str, json: &str) -> Result<MutationReport, String> {
+    let meta: MutmutMeta =
+        serde_json::from_str(json).map_err(|e| format!("invalid mutmut meta file: {e}"))?;
+
+    let package = extract_python_package(source_path);
+    let mut report = MutationReport::default();
+    for code in meta.exit_code_by_key.into_values().flatten() {
+        match code {
+            0 | 5 => report.record(package.clone(), false),
+            33 | 34 => {}
+            _ => report.record(package.clone(), true),
+        }
+    }
+    Ok(report)
+}
+
+// =============================================================================
+// Stryker
+// =============================================================================
+
+/// Stryker `mutation.json` (mutation-testing-report-schema).
+#[derive(Debug, Deserialize)]
+struct StrykerReport {
+    #[serde(default)]
+    files: BTreeMap<String, StrykerFile>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StrykerFile {
+    #[serde(default)]
+    mutants: Vec<StrykerMutant>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StrykerMutant {
+    status: String,
+}
+
+/// Parse Stryker's JSON reporter output.
+pub fn parse_stryker(json: &str) -> Result<MutationReport, String> {
+    let stryker: StrykerReport =
+        serde_json::from_str(json).map_err(|e| format!("invalid stryker report: {e}"))?;
+
+    let mut report = MutationReport::default();
+    for (path, file) in stryker.files {
+        let package = extract_js_package(&path);
+        for mutant in file.mutants {
+            match mutant.status.as_str() {
+                "Killed" | "Timeout" => report.record(package.clone(), true),
+                "Survived" | "NoCoverage" => report.record(package.clone(), false),
+                _ => {}
+            }
+        }
+    }
+    Ok(report)
+}
+
+// =============================================================================
+// go-mutesting
+// =============================================================================
+
+/// go-mutesting `report.json` (written when `json_output` is enabled).
+#[derive(Debug, Deserialize)]
+struct GoMutestingReport {
+    #[serde(default)]
+    killed: Vec<GoMutestingEntry>,
+    #[serde(default)]
+    timeouted: Vec<GoMutestingEntry>,
+    #[serde(default)]
+    escaped: Vec<GoMutestingEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GoMutestingEntry {
+    mutator: GoMutator,
+}
+
+#[derive(Debug, Deserialize)]
+struct GoMutator {
+    #[serde(rename = "originalFilePath", default)]
+    original_file_path: String,
+}
+
+/// Parse go-mutesting's JSON report.
+pub fn parse_go_mutesting(json: &str) -> Result<MutationReport, String> {
+    let go: GoMutestingReport =
+        serde_json::from_str(json).map_err(|e| format!("invalid go-mutesting report: {e}"))?;
+
+    let mut report = MutationReport::default();
+    let caught = go.killed.iter().chain(&go.timeouted).map(|e| (e, true));
+    for (entry, caught) in caught.chain(go.escaped.iter().map(|e| (e, false))) {
+        report.record(extract_go_package(&entry.mutator.original_file_path), caught);
+    }
+    Ok(report)
+}
+
+/// Parse the summary line go-mutesting prints when no JSON report exists.
+///
+/// Example: `The mutation score is 0.750000 (3 passed, 1 failed, 0 duplicated, 0 skipped, total is 4)`
+pub fn parse_go_mutesting_summary(stdout: &str) -> Option<MutationReport> {
+    let line = stdout.lines().rev().find(|l| l.starts_with("The mutation score is"))?;
+    let counts = &line[line.find('(')? + 1..];
+    let count = |label: &str| -> Option<usize> {
+        counts.split(',').find_map(|part| part.trim().strip_suffix(label)?.trim().parse().ok())
+    };
+
+    let mut report = MutationReport::default();
+    report.add(
+        "root".to_string(),
+        MutantCounts { caught: count("passed")?, missed: count("failed")? },
+    );
+    Some(report)
+}
+
+#[cfg(test)]
+#[path = "report_tests.rs"]
+mod tests;
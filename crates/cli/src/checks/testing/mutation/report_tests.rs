@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use super::*;
+
+#[test]
+fn score_is_percentage_of_tested_mutants() {
+    let counts = MutantCounts { caught: 3, missed: 1 };
+    assert_eq!(counts.score(), Some(75.0));
+}
+
+#[test]
+fn score_is_none_without_tested_mutants() {
+    assert_eq!(MutantCounts::default().score(), None);
+}
+
+#[test]
+fn parses_cargo_mutants_outcomes() {
+    let json = r#"{
+        "outcomes": [
+            {"scenario": "Baseline", "summary": "Success"},
+            {"scenario": {"Mutant": {"package": "core", "file": "crates/core/src/lib.rs"}}, "summary": "CaughtMutant"},
+            {"scenario": {"Mutant": {"package": "core", "file": "crates/core/src/lib.rs"}}, "summary": "MissedMutant"},
+            {"scenario": {"Mutant": {"package": "cli", "file": "crates/cli/src/main.rs"}}, "summary": "Timeout"},
+            {"scenario": {"Mutant": {"package": "cli", "file": "crates/cli/src/main.rs"}}, "summary": "Unviable"}
+        ],
+        "total_mutants": 4
+    }"#;
+
+    let report = parse_cargo_mutants(json).unwrap();
+
+    assert_eq!(report.total, MutantCounts { caught: 2, missed: 1 });
+    assert_eq!(report.by_package["core"], MutantCounts { caught: 1, missed: 1 });
+    assert_eq!(report.by_package["cli"], MutantCounts { caught: 1, missed: 0 });
+    assert_eq!(report.package_scores()["core"], 50.0);
+}
+
+#[test]
+fn cargo_mutants_without_package_uses_root() {
+    let json = r#"{"outcomes": [{"scenario": {"Mutant": {"file": "src/lib.rs"}}, "summary": "MissedMutant"}]}"#;
+
+    let report = parse_cargo_mutants(json).unwrap();
+
+    assert_eq!(report.by_package["root"], MutantCounts { caught: 0, missed: 1 });
+}
+
+#[test]
+fn invalid_cargo_mutants_report_is_error() {
+    let err = parse_cargo_mutants("not json").unwrap_err();
+    assert!(err.contains("invalid cargo-mutants report"));
+}
+
+#[test]
+fn parses_mutmut_meta_exit_codes() {
+    let json = r#"{
+        "exit_code_by_key": {
+            "myapp.core.x_add__mutmut_1": 1,
+            "myapp.core.x_add__mutmut_2": 0,
+            "myapp.core.x_add__mutmut_3": 5,
+            "myapp.core.x_add__mutmut_4": 34,
+            "myapp.core.x_add__mutmut_5": null,
+            "myapp.core.x_add__mutmut_6": 36
+        },
+        "durations_by_key": {}
+    }"#;
+
+    let report = parse_mutmut_meta("src/myapp/core.py", json).unwrap();
+
+    assert_eq!(report.total, MutantCounts { caught: 2, missed: 2 });
+    assert!(report.by_package.contains_key("myapp"));
+}
+
+#[test]
+fn parses_stryker_report() {
+    let json = r#"{
+        "schemaVersion": "2",
+        "files": {
+            "packages/core/src/math.ts": {
+                "language": "typescript",
+                "mutants": [
+                    {"id": "1", "status": "Killed"},
+                    {"id": "2", "status": "Survived"},
+                    {"id": "3", "status": "NoCoverage"},
+                    {"id": "4", "status": "Timeout"},
+                    {"id": "5", "status": "CompileError"}
+                ]
+            },
+            "src/index.js": {"mutants": [{"id": "6", "status": "Killed"}]}
+        }
+    }"#;
+
+    let report = parse_stryker(json).unwrap();
+
+    assert_eq!(report.total, MutantCounts { caught: 3, missed: 2 });
+    assert_eq!(report.by_package["packages/core"], MutantCounts { caught: 2, missed: 2 });
+    assert_eq!(report.by_package["root"], MutantCounts { caught: 1, missed: 0 });
+}
+
+#[test]
+fn parses_go_mutesting_report() {
+    let json = r#"{
+        "stats": {"totalMutantsCount": 3, "killedCount": 1, "escapedCount": 1, "timeOutCount": 1},
+        "killed": [{"mutator": {"mutatorName": "branch/if", "originalFilePath": "pkg/math/math.go"}}],
+        "escaped": [{"mutator": {"mutatorName": "expression/remove", "originalFilePath": "pkg/math/math.go"}}],
+        "timeouted": [{"mutator": {"mutatorName": "statement/remove", "originalFilePath": "main.go"}}]
+    }"#;
+
+    let report = parse_go_mutesting(json).unwrap();
+
+    assert_eq!(report.total, MutantCounts { caught: 2, missed: 1 });
+    assert_eq!(report.by_package["pkg/math"], MutantCounts { caught: 1, missed: 1 });
+    assert_eq!(report.by_package["root"], MutantCounts { caught: 1, missed: 0 });
+}
+
+#[test]
+fn parses_go_mutesting_summary_line() {
+    let stdout = "PASS \"/tmp/go-mutesting/math.go.0\" with checksum abc\n\
+                  The mutation score is 0.750000 (3 passed, 1 failed, 0 duplicated, 0 skipped, total is 4)\n";
+
+    let report = parse_go_mutesting_summary(stdout).unwrap();
+
+    assert_eq!(report.total, MutantCounts { caught: 3, missed: 1 });
+}
+
+#[test]
+fn go_mutesting_summary_missing_returns_none() {
+    assert!(parse_go_mutesting_summary("no mutants\n").is_none());
+}
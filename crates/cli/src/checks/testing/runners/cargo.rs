@@ -7,6 +7,7 @@ use std::io::ErrorKind;
 use std::process::{Command, Stdio};
 use std::time::{Duration, Instant};
 
+use super::super::impact::cargo_packages;
 use super::coverage::collect_rust_coverage;
 use super::{
     RunnerContext, TestResult, TestRunResult, TestRunner, handle_timeout_error, run_setup_or_fail,
@@ -34,7 +35,24 @@ impl TestRunner for CargoRunner {
 
         // Build command - use standard cargo test output (stable Rust compatible)
         let mut cmd = Command::new("cargo");
-        cmd.args(["test", "--all"]);
+        cmd.arg("test");
+
+        // Narrow to affected workspace packages when selecting by impact
+        let packages = ctx
+            .changes
+            .filter(|_| config.path.is_none())
+            .and_then(|changes| cargo_packages(&changes, ctx.root))
+            .filter(|packages| !packages.is_empty());
+        match packages {
+            Some(packages) => {
+                for package in packages {
+                    cmd.args(["-p", &package]);
+                }
+            }
+            None => {
+                cmd.arg("--all");
+            }
+        }
 
         // Set working directory
         let work_dir = config
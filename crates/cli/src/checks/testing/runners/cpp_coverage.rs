@@ -0,0 +1,213 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Coverage collection for C/C++ projects.
+//!
+//! Two sources are supported, tried in order:
+//! - gcov counters (`*.gcda`) written by binaries built with `--coverage`,
+//!   captured with `lcov --capture --directory <build>`
+//! - an lcov file written to the build directory during the run, e.g. by a
+//!   CTest fixture calling `llvm-cov export -format=lcov` for clang's
+//!   source-based coverage
+//!
+//! Paths are reported relative to the project root; files outside the root
+//! (system headers) and inside the build directory (generated code) are
+//! skipped.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant, SystemTime};
+
+use super::CoverageResult;
+use super::tool_available;
+
+/// Delete gcov counter files (`*.gcda`) under the build directory.
+pub(crate) fn remove_gcov_counters(build: &Path) {
+    let mut counters = Vec::new();
+    find_files(build, &mut counters, &|path| has_extension(path, "gcda"));
+    for file in counters {
+        std::fs::remove_file(file).ok();
+    }
+}
+
+/// Collect coverage written by a ctest run that started at `started`.
+pub(crate) fn collect_cpp_coverage(
+    root: &Path,
+    build: &Path,
+    started: SystemTime,
+) -> CoverageResult {
+    let start = Instant::now();
+    // lcov reports absolute, symlink-resolved paths
+    let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+    let build = build.canonicalize().unwrap_or_else(|_| build.to_path_buf());
+    let (root, build) = (root.as_path(), build.as_path());
+
+    let mut counters = Vec::new();
+    find_files(build, &mut counters, &|path| has_extension(path, "gcda"));
+    if !counters.is_empty() && tool_available("lcov") {
+        return capture_lcov(root, build, start);
+    }
+
+    let mut exports = Vec::new();
+    find_files(build, &mut exports, &|path| {
+        let is_lcov =
+            has_extension(path, "lcov") || path.file_name().is_some_and(|n| n == "lcov.info");
+        is_lcov && std::fs::metadata(path).and_then(|m| m.modified()).is_ok_and(|m| m >= started)
+    });
+    if exports.is_empty() {
+        return CoverageResult::skipped();
+    }
+
+    let content: String = exports.iter().filter_map(|f| std::fs::read_to_string(f).ok()).collect();
+    parse_cpp_lcov(&content, root, build, start.elapsed())
+}
+
+/// Run `lcov --capture` over the build directory and parse its output.
+fn capture_lcov(root: &Path, build: &Path, start: Instant) -> CoverageResult {
+    let output_path = build.join("quench-coverage.info");
+    let output = Command::new("lcov")
+        .arg("--capture")
+        .arg("--quiet")
+        .arg("--directory")
+        .arg(build)
+        .arg("--output-file")
+        .arg(&output_path)
+        .current_dir(root)
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .output();
+
+    let content = std::fs::read_to_string(&output_path);
+    std::fs::remove_file(&output_path).ok();
+
+    match (output, content) {
+        (Ok(out), Ok(content)) if out.status.success() => {
+            parse_cpp_lcov(&content, root, build, start.elapsed())
+        }
+        (Ok(out), _) => {
+            let stderr = String::from_utf8_lossy(&out.stderr);
+            let msg = stderr.lines().take(10).collect::<Vec<_>>().join("\n");
+            CoverageResult::failed(start.elapsed(), format!("lcov failed:\n{msg}"))
+        }
+        (Err(e), _) => CoverageResult::failed(start.elapsed(), format!("failed to run lcov: {e}")),
+    }
+}
+
+/// Parse an lcov report of C/C++ sources.
+///
+/// Line hits are merged across records, since a header compiled into
+/// several translation units appears once per unit. Files are grouped into
+/// packages by their first directory (`src`, `lib`, ...; `root` for files at
+/// the project root).
+pub(crate) fn parse_cpp_lcov(
+    content: &str,
+    root: &Path,
+    build: &Path,
+    duration: Duration,
+) -> CoverageResult {
+    // path -> line -> covered
+    let mut files: HashMap<String, BTreeMap<u32, bool>> = HashMap::new();
+    let mut current: Option<String> = None;
+
+    for line in content.lines() {
+        let line = line.trim();
+        if let Some(path) = line.strip_prefix("SF:") {
+            current = relative_source(path, root, build);
+        } else if let Some(data) = line.strip_prefix("DA:") {
+            let Some(file) = &current else {
+                continue;
+            };
+            let mut fields = data.split(',');
+            let (Some(Ok(line_no)), Some(count)) =
+                (fields.next().map(str::parse::<u32>), fields.next())
+            else {
+                continue;
+            };
+            let hit = count.trim().parse::<f64>().is_ok_and(|c| c > 0.0);
+            *files.entry(file.clone()).or_default().entry(line_no).or_default() |= hit;
+        } else if line == "end_of_record" {
+            current = None;
+        }
+    }
+
+    let mut result = CoverageResult {
+        success: true,
+        error: None,
+        duration,
+        line_coverage: None,
+        files: HashMap::new(),
+        packages: HashMap::new(),
+    };
+
+    let mut packages: HashMap<String, (usize, usize)> = HashMap::new();
+    let (mut total_hit, mut total_found) = (0, 0);
+    for (path, lines) in files {
+        if lines.is_empty() {
+            continue;
+        }
+        let hit = lines.values().filter(|h| **h).count();
+        let found = lines.len();
+        result.files.insert(path.clone(), hit as f64 / found as f64 * 100.0);
+
+        let entry = packages.entry(extract_cpp_package(&path)).or_default();
+        entry.0 += hit;
+        entry.1 += found;
+        total_hit += hit;
+        total_found += found;
+    }
+
+    result.packages = packages
+        .into_iter()
+        .map(|(pkg, (hit, found))| (pkg, hit as f64 / found as f64 * 100.0))
+        .collect();
+    if total_found > 0 {
+        result.line_coverage = Some(total_hit as f64 / total_found as f64 * 100.0);
+    }
+    result
+}
+
+/// Make an lcov source path relative to the root.
+///
+/// Returns None for files outside the root or inside the build directory.
+fn relative_source(path: &str, root: &Path, build: &Path) -> Option<String> {
+    let path = Path::new(path);
+    let absolute = if path.is_absolute() { path.to_path_buf() } else { root.join(path) };
+    if absolute.starts_with(build) {
+        return None;
+    }
+    let relative = absolute.strip_prefix(root).ok()?;
+    let relative = relative.to_string_lossy().replace('\\', "/");
+    (!relative.is_empty() && !relative.starts_with("../")).then_some(relative)
+}
+
+/// Package of a C/C++ source: its first directory, or `root`.
+pub(crate) fn extract_cpp_package(path: &str) -> String {
+    match path.split_once('/') {
+        Some((dir, _)) => dir.to_string(),
+        None => "root".to_string(),
+    }
+}
+
+fn has_extension(path: &Path, ext: &str) -> bool {
+    path.extension().is_some_and(|e| e == ext)
+}
+
+/// Recursively collect files matching a predicate.
+fn find_files(dir: &Path, out: &mut Vec<PathBuf>, matches: &dyn Fn(&Path) -> bool) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            find_files(&path, out, matches);
+        } else if matches(&path) {
+            out.push(path);
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "cpp_coverage_tests.rs"]
+mod tests;
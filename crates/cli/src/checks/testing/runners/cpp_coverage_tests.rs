@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::path::Path;
+use std::time::Duration;
+
+use super::*;
+
+const ROOT: &str = "/work/engine";
+const BUILD: &str = "/work/engine/build";
+
+fn parse(report: &str) -> CoverageResult {
+    parse_cpp_lcov(report, Path::new(ROOT), Path::new(BUILD), Duration::ZERO)
+}
+
+#[test]
+fn reports_files_relative_to_root() {
+    let report = "\
+SF:/work/engine/src/parser.c
+DA:1,4
+DA:2,0
+DA:3,1
+DA:4,0
+end_of_record
+SF:/work/engine/main.c
+DA:1,1
+end_of_record
+";
+    let result = parse(report);
+
+    assert!(result.success);
+    assert_eq!(result.files.get("src/parser.c"), Some(&50.0));
+    assert_eq!(result.files.get("main.c"), Some(&100.0));
+    assert_eq!(result.line_coverage, Some(60.0));
+}
+
+#[test]
+fn skips_system_headers_and_generated_sources() {
+    let report = "\
+SF:/usr/include/c++/13/bits/vector.h
+DA:10,0
+end_of_record
+SF:/work/engine/build/generated/version.c
+DA:1,0
+end_of_record
+SF:/work/engine/src/lexer.cc
+DA:1,1
+end_of_record
+";
+    let result = parse(report);
+
+    assert_eq!(result.files.len(), 1);
+    assert!(result.files.contains_key("src/lexer.cc"));
+    assert_eq!(result.line_coverage, Some(100.0));
+}
+
+#[test]
+fn merges_headers_across_translation_units() {
+    // The same inline header function, executed by only one of two units
+    let report = "\
+SF:/work/engine/include/engine/buffer.hpp
+DA:5,0
+DA:6,0
+end_of_record
+SF:/work/engine/include/engine/buffer.hpp
+DA:5,3
+DA:6,0
+end_of_record
+";
+    let result = parse(report);
+
+    assert_eq!(result.files.get("include/engine/buffer.hpp"), Some(&50.0));
+}
+
+#[test]
+fn accepts_relative_paths_and_fractional_counts() {
+    // llvm-cov exports may use paths relative to the compilation directory
+    let report = "SF:src/util.cpp\nDA:1,1.5\nDA:2,0\nend_of_record\n";
+    let result = parse(report);
+
+    assert_eq!(result.files.get("src/util.cpp"), Some(&50.0));
+}
+
+#[test]
+fn groups_packages_by_top_level_directory() {
+    let report = "\
+SF:/work/engine/src/a.c
+DA:1,1
+end_of_record
+SF:/work/engine/src/net/b.c
+DA:1,0
+end_of_record
+SF:/work/engine/lib/c.c
+DA:1,1
+end_of_record
+";
+    let result = parse(report);
+
+    assert_eq!(result.packages.get("src"), Some(&50.0));
+    assert_eq!(result.packages.get("lib"), Some(&100.0));
+}
+
+#[test]
+fn empty_report_has_no_coverage() {
+    let result = parse("");
+    assert!(result.success);
+    assert_eq!(result.line_coverage, None);
+    assert!(result.files.is_empty());
+}
+
+#[test]
+fn extract_cpp_package_uses_first_directory() {
+    assert_eq!(extract_cpp_package("src/net/socket.c"), "src");
+    assert_eq!(extract_cpp_package("main.cpp"), "root");
+}
@@ -0,0 +1,167 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! CTest runner for C/C++ projects.
+//!
+//! Runs `ctest --test-dir <build> --output-junit <report>` against an
+//! already-configured CMake build tree (`build/` unless `path` is set) and
+//! parses the JUnit report. Building the tests is left to `setup`
+//! (e.g., `cmake --build build`). Coverage comes from gcov counters via
+//! `lcov`, or from an lcov file exported during the run (see `cpp_coverage`).
+
+use std::io::ErrorKind;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use super::cpp_coverage::{collect_cpp_coverage, remove_gcov_counters};
+use super::junit::parse_junit_xml;
+use super::{
+    RunnerContext, TestResult, TestRunResult, TestRunner, handle_timeout_error, report_cutoff,
+    run_setup_or_fail, run_with_timeout, scratch_path, tool_available,
+};
+use crate::config::TestSuiteConfig;
+
+/// Build directory used when the suite sets no `path`.
+const DEFAULT_BUILD_DIR: &str = "build";
+
+/// CTest runner for CMake test suites.
+pub struct CtestRunner;
+
+impl TestRunner for CtestRunner {
+    fn name(&self) -> &'static str {
+        "ctest"
+    }
+
+    fn available(&self, _ctx: &RunnerContext) -> bool {
+        tool_available("ctest")
+    }
+
+    fn run(&self, config: &TestSuiteConfig, ctx: &RunnerContext) -> TestRunResult {
+        run_setup_or_fail!(config, ctx);
+
+        let build_dir = config.path.as_deref().unwrap_or(DEFAULT_BUILD_DIR);
+        let build_path = ctx.root.join(build_dir);
+        if !build_path.join("CTestTestfile.cmake").is_file() {
+            return TestRunResult::failed(
+                Duration::ZERO,
+                format!(
+                    "no CTest build tree in {build_dir}/ (configure with CMake and enable_testing(), \
+or set `path` to the build directory)"
+                ),
+            );
+        }
+
+        // Counters from an earlier run would inflate coverage
+        if ctx.collect_coverage {
+            remove_gcov_counters(&build_path);
+        }
+
+        let report = scratch_path("ctest").with_extension("xml");
+
+        let started = report_cutoff();
+        let start = Instant::now();
+
+        let mut cmd = ctest_command(build_dir, &report);
+        cmd.current_dir(ctx.root);
+        cmd.stdout(Stdio::piped());
+        cmd.stderr(Stdio::piped());
+
+        let child = match cmd.spawn() {
+            Ok(c) => c,
+            Err(e) => {
+                return TestRunResult::failed(
+                    start.elapsed(),
+                    format!("failed to spawn ctest: {e}"),
+                );
+            }
+        };
+
+        let output = match run_with_timeout(child, config.timeout) {
+            Ok(out) => out,
+            Err(e) if e.kind() == ErrorKind::TimedOut => {
+                std::fs::remove_file(&report).ok();
+                return handle_timeout_error(start.elapsed(), config.timeout, "ctest");
+            }
+            Err(e) => {
+                std::fs::remove_file(&report).ok();
+                return TestRunResult::failed(start.elapsed(), format!("failed to run ctest: {e}"));
+            }
+        };
+
+        let total_time = start.elapsed();
+        let xml = std::fs::read_to_string(&report).unwrap_or_default();
+        std::fs::remove_file(&report).ok();
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let mut result =
+            parse_ctest_report(&xml, &stdout, &stderr, output.status.success(), total_time);
+
+        if ctx.collect_coverage {
+            let coverage = collect_cpp_coverage(ctx.root, &build_path, started);
+            result = result.with_collected_coverage(coverage, "cpp");
+        }
+
+        result
+    }
+}
+
+/// Build the ctest command.
+///
+/// `--no-tests=error` turns an empty test list (tests not built, or
+/// `enable_testing()` missing) into a failure instead of a silent pass.
+pub(crate) fn ctest_command(build_dir: &str, report: &Path) -> Command {
+    let mut cmd = Command::new("ctest");
+    cmd.args(["--test-dir", build_dir, "--output-on-failure", "--no-tests=error"]);
+    cmd.arg("--output-junit").arg(report);
+    cmd
+}
+
+/// Build a result from the ctest JUnit report and output.
+pub(crate) fn parse_ctest_report(
+    xml: &str,
+    stdout: &str,
+    stderr: &str,
+    success: bool,
+    total_time: Duration,
+) -> TestRunResult {
+    let tests: Vec<_> = parse_junit_xml(xml).into_iter().map(dedupe_test_name).collect();
+
+    if success {
+        return TestRunResult::passed(total_time).with_tests(tests);
+    }
+
+    let error = if tests.iter().any(|t| !t.passed) {
+        "tests failed".to_string()
+    } else {
+        ctest_error(stdout, stderr).unwrap_or_else(|| "ctest failed".to_string())
+    };
+    let mut result = TestRunResult::failed(total_time, error);
+    result.tests = tests;
+    result
+}
+
+/// CTest sets `classname` to the test name; report `name` instead of `name::name`.
+fn dedupe_test_name(mut test: TestResult) -> TestResult {
+    if let Some((class, name)) = test.name.split_once("::")
+        && class == name
+    {
+        test.name = name.to_string();
+    }
+    test
+}
+
+/// Extract the first error line from ctest output (e.g., "No tests were found!!!").
+pub(crate) fn ctest_error(stdout: &str, stderr: &str) -> Option<String> {
+    stderr
+        .lines()
+        .chain(stdout.lines())
+        .map(str::trim)
+        .find(|l| l.starts_with("No tests were found") || l.contains("Error"))
+        .map(String::from)
+}
+
+#[cfg(test)]
+#[path = "ctest_tests.rs"]
+mod tests;
@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::path::Path;
+use std::time::Duration;
+
+use super::*;
+
+const REPORT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuite name="Linux-c++" tests="3" failures="1" disabled="0" skipped="1" hostname="" time="1" timestamp="2026-01-01T00:00:00">
+    <testcase name="parser_test" classname="parser_test" time="0.012" status="run">
+        <system-out>[==========] 4 tests ran.</system-out>
+    </testcase>
+    <testcase name="lexer_test" classname="lexer_test" time="0.030" status="fail">
+        <failure message="Failed"/>
+        <system-out>Expected: 1, actual: 2</system-out>
+    </testcase>
+    <testcase name="network_test" classname="network_test" time="0" status="notrun">
+        <skipped message="Disabled"/>
+    </testcase>
+</testsuite>"#;
+
+#[test]
+fn command_writes_junit_report_for_build_dir() {
+    let cmd = ctest_command("build", Path::new("/tmp/report.xml"));
+    let args: Vec<_> = cmd.get_args().map(|a| a.to_string_lossy().into_owned()).collect();
+
+    assert_eq!(cmd.get_program(), "ctest");
+    assert_eq!(args[..2], ["--test-dir", "build"]);
+    assert!(args.contains(&"--no-tests=error".to_string()));
+    assert_eq!(args[args.len() - 2..], ["--output-junit", "/tmp/report.xml"]);
+}
+
+#[test]
+fn parses_report_with_deduplicated_names() {
+    let result = parse_ctest_report(REPORT, "", "", false, Duration::from_secs(1));
+
+    assert!(!result.passed);
+    assert_eq!(result.error.as_deref(), Some("tests failed"));
+    let names: Vec<_> = result.tests.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, ["parser_test", "lexer_test", "network_test"]);
+    assert_eq!(result.passed_count(), 1);
+    assert_eq!(result.failed_count(), 1);
+    assert_eq!(result.skipped_count(), 1);
+}
+
+#[test]
+fn passing_run_keeps_tests() {
+    let xml =
+        r#"<testsuite><testcase name="a" classname="a" time="0.5" status="run"/></testsuite>"#;
+    let result = parse_ctest_report(xml, "", "", true, Duration::from_secs(1));
+
+    assert!(result.passed);
+    assert_eq!(result.tests.len(), 1);
+    assert_eq!(result.tests[0].duration, Duration::from_millis(500));
+}
+
+#[test]
+fn failure_without_tests_reports_ctest_error() {
+    let result = parse_ctest_report(
+        "",
+        "Test project /work/engine/build\n",
+        "No tests were found!!!\n",
+        false,
+        Duration::ZERO,
+    );
+
+    assert!(!result.passed);
+    assert_eq!(result.error.as_deref(), Some("No tests were found!!!"));
+}
+
+#[test]
+fn failure_without_output_has_generic_error() {
+    let result = parse_ctest_report("", "", "", false, Duration::ZERO);
+    assert_eq!(result.error.as_deref(), Some("ctest failed"));
+}
+
+#[test]
+fn distinct_classname_is_kept() {
+    let xml = r#"<testcase name="adds" classname="MathSuite" time="0"/>"#;
+    let result = parse_ctest_report(xml, "", "", true, Duration::ZERO);
+    assert_eq!(result.tests[0].name, "MathSuite::adds");
+}
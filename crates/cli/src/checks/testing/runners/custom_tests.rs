@@ -17,11 +17,20 @@ fn make_config(command: Option<&str>) -> TestSuiteConfig {
         max_avg: None,
         max_test: None,
         timeout: None,
+        exclusive: false,
+        envs: vec![],
     }
 }
 
 fn make_ctx<'a>(root: &'a std::path::Path, config: &'a crate::config::Config) -> RunnerContext<'a> {
-    RunnerContext { root, ci_mode: false, collect_coverage: false, config, verbose: false }
+    RunnerContext {
+        root,
+        ci_mode: false,
+        collect_coverage: false,
+        config,
+        verbose: false,
+        changes: None,
+    }
 }
 
 #[test]
@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Deno test runner.
+//!
+//! Executes tests using `deno test --reporter=junit` and parses the JUnit XML
+//! report (Deno has no JSON reporter).
+
+use std::io::ErrorKind;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use super::js_coverage::collect_deno_coverage;
+use super::junit::parse_junit_xml;
+use super::{
+    RunnerContext, TestRunResult, TestRunner, handle_timeout_error, run_setup_or_fail,
+    run_with_timeout,
+};
+use crate::config::TestSuiteConfig;
+
+/// Deno runner for JavaScript/TypeScript test suites.
+pub struct DenoRunner;
+
+impl TestRunner for DenoRunner {
+    fn name(&self) -> &'static str {
+        "deno"
+    }
+
+    fn available(&self, _ctx: &RunnerContext) -> bool {
+        // Deno runs tests without any project manifest
+        deno_available()
+    }
+
+    fn run(&self, config: &TestSuiteConfig, ctx: &RunnerContext) -> TestRunResult {
+        run_setup_or_fail!(config, ctx);
+
+        let start = Instant::now();
+
+        // Build command: deno test -A --reporter=junit
+        // Tests get full permissions, as with every other runner
+        let mut cmd = Command::new("deno");
+        cmd.args(["test", "-A", "--reporter=junit"]);
+
+        // Add test path if specified
+        if let Some(path) = &config.path {
+            cmd.arg(path);
+        }
+
+        cmd.current_dir(ctx.root);
+        cmd.stdout(Stdio::piped());
+        cmd.stderr(Stdio::piped());
+
+        let child = match cmd.spawn() {
+            Ok(c) => c,
+            Err(e) => {
+                return TestRunResult::failed(
+                    start.elapsed(),
+                    format!("failed to spawn deno: {e}"),
+                );
+            }
+        };
+
+        let output = match run_with_timeout(child, config.timeout) {
+            Ok(out) => out,
+            Err(e) if e.kind() == ErrorKind::TimedOut => {
+                return handle_timeout_error(start.elapsed(), config.timeout, "deno");
+            }
+            Err(e) => {
+                return TestRunResult::failed(start.elapsed(), format!("failed to run deno: {e}"));
+            }
+        };
+
+        let total_time = start.elapsed();
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        let mut result = parse_deno_output(&stdout, &stderr, output.status.success(), total_time);
+
+        // Collect coverage if requested
+        if ctx.collect_coverage {
+            let coverage = collect_deno_coverage(ctx.root, config.path.as_deref());
+            result = result.with_collected_coverage(coverage, "javascript");
+        }
+
+        result
+    }
+}
+
+/// Check if deno is installed.
+pub(crate) fn deno_available() -> bool {
+    Command::new("deno")
+        .arg("--version")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|s| s.success())
+}
+
+/// Parse `deno test --reporter=junit` output.
+pub(crate) fn parse_deno_output(
+    stdout: &str,
+    stderr: &str,
+    success: bool,
+    total_time: Duration,
+) -> TestRunResult {
+    let tests = parse_junit_xml(stdout);
+
+    if success {
+        return TestRunResult::passed(total_time).with_tests(tests);
+    }
+
+    let error = if tests.iter().any(|t| !t.passed) {
+        "tests failed"
+    } else {
+        // Failed outside of any test (type errors, missing modules, leaks)
+        stderr.lines().map(str::trim).find(|l| l.starts_with("error")).unwrap_or("deno test failed")
+    };
+    let mut result = TestRunResult::failed(total_time, error);
+    result.tests = tests;
+    result
+}
+
+#[cfg(test)]
+#[path = "deno_tests.rs"]
+mod tests;
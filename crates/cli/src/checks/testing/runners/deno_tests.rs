@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::time::Duration;
+
+use super::*;
+
+const REPORT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="deno test" tests="2" failures="0" errors="0" time="0.004">
+    <testsuite name="./math_test.ts" tests="2" disabled="0" errors="0" failures="0">
+        <testcase name="adds" classname="./math_test.ts" time="0.001" line="3" col="6">
+        </testcase>
+        <testcase name="multiplies" classname="./math_test.ts" time="0.003" line="7" col="6">
+        </testcase>
+    </testsuite>
+</testsuites>
+"#;
+
+#[test]
+fn parses_passing_report() {
+    let result = parse_deno_output(REPORT, "", true, Duration::from_secs(1));
+
+    assert!(result.passed);
+    assert_eq!(result.tests.len(), 2);
+    assert_eq!(result.tests[1].name, "./math_test.ts::multiplies");
+    assert_eq!(result.tests[1].duration, Duration::from_millis(3));
+}
+
+#[test]
+fn failing_testcase_fails_run() {
+    let report = REPORT.replacen(
+        "</testcase>",
+        "<failure message=\"Uncaught error\">AssertionError</failure></testcase>",
+        1,
+    );
+
+    let result = parse_deno_output(&report, "", false, Duration::from_secs(1));
+
+    assert!(!result.passed);
+    assert_eq!(result.error.as_deref(), Some("tests failed"));
+    assert_eq!(result.failed_count(), 1);
+}
+
+#[test]
+fn failure_outside_tests_reports_deno_error() {
+    let stderr = "Check file:///app/math_test.ts\nerror: TS2304 [ERROR]: Cannot find name 'ad'.\n";
+
+    let result = parse_deno_output("", stderr, false, Duration::from_secs(1));
+
+    assert!(!result.passed);
+    assert_eq!(result.error.as_deref(), Some("error: TS2304 [ERROR]: Cannot find name 'ad'."));
+}
+
+#[test]
+fn exit_failure_wins_over_passing_tests() {
+    let result = parse_deno_output(REPORT, "error: Leaks detected", false, Duration::from_secs(1));
+
+    assert!(!result.passed);
+    assert_eq!(result.tests.len(), 2);
+}
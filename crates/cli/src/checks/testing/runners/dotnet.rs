@@ -0,0 +1,239 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `dotnet test` runner for C#/.NET projects.
+//!
+//! Runs `dotnet test [path] --logger trx --results-directory <dir>` and parses
+//! the TRX reports (one per test project) written to the results directory.
+//! With coverage enabled, `--collect "XPlat Code Coverage"` makes coverlet
+//! write a `<dir>/<guid>/coverage.cobertura.xml` per project, which is parsed
+//! with the same Cobertura parser as Python coverage.
+
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use super::junit::junit_attr;
+use super::python_coverage::{merge_coverage_results, parse_cobertura_xml};
+use super::{
+    CoverageResult, RunnerContext, TestResult, TestRunResult, TestRunner, handle_timeout_error,
+    run_setup_or_fail, run_with_timeout, scratch_path, tool_available,
+};
+use crate::config::TestSuiteConfig;
+
+/// `dotnet test` runner for C# test projects.
+pub struct DotnetRunner;
+
+impl TestRunner for DotnetRunner {
+    fn name(&self) -> &'static str {
+        "dotnet"
+    }
+
+    fn available(&self, _ctx: &RunnerContext) -> bool {
+        tool_available("dotnet")
+    }
+
+    fn run(&self, config: &TestSuiteConfig, ctx: &RunnerContext) -> TestRunResult {
+        run_setup_or_fail!(config, ctx);
+
+        let results_dir = scratch_path("dotnet");
+        std::fs::remove_dir_all(&results_dir).ok();
+
+        let start = Instant::now();
+
+        let mut cmd = dotnet_command(config.path.as_deref(), &results_dir, ctx.collect_coverage);
+        cmd.current_dir(ctx.root);
+        cmd.stdout(Stdio::piped());
+        cmd.stderr(Stdio::piped());
+
+        let child = match cmd.spawn() {
+            Ok(c) => c,
+            Err(e) => {
+                return TestRunResult::failed(
+                    start.elapsed(),
+                    format!("failed to spawn dotnet: {e}"),
+                );
+            }
+        };
+
+        let output = match run_with_timeout(child, config.timeout) {
+            Ok(out) => out,
+            Err(e) if e.kind() == ErrorKind::TimedOut => {
+                std::fs::remove_dir_all(&results_dir).ok();
+                return handle_timeout_error(start.elapsed(), config.timeout, "dotnet");
+            }
+            Err(e) => {
+                std::fs::remove_dir_all(&results_dir).ok();
+                return TestRunResult::failed(
+                    start.elapsed(),
+                    format!("failed to run dotnet: {e}"),
+                );
+            }
+        };
+
+        let total_time = start.elapsed();
+        let reports: Vec<String> = find_files(&results_dir, &|p| has_extension(p, "trx"))
+            .iter()
+            .filter_map(|f| std::fs::read_to_string(f).ok())
+            .collect();
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let mut result =
+            parse_dotnet_output(&reports, &stdout, &stderr, output.status.success(), total_time);
+
+        if ctx.collect_coverage {
+            let coverage = collect_dotnet_coverage(&results_dir);
+            result = result.with_collected_coverage(coverage, "csharp");
+        }
+
+        std::fs::remove_dir_all(&results_dir).ok();
+        result
+    }
+}
+
+/// Build the `dotnet test` command.
+///
+/// `path` selects a solution, project, or directory; without it `dotnet test`
+/// uses the solution or project in the working directory.
+pub(crate) fn dotnet_command(path: Option<&str>, results_dir: &Path, coverage: bool) -> Command {
+    let mut cmd = Command::new("dotnet");
+    cmd.arg("test");
+    if let Some(path) = path {
+        cmd.arg(path);
+    }
+    cmd.args(["--logger", "trx", "--results-directory"]);
+    cmd.arg(results_dir);
+    if coverage {
+        cmd.args(["--collect", "XPlat Code Coverage"]);
+    }
+    cmd
+}
+
+/// Build a result from the TRX reports and `dotnet test` output.
+pub(crate) fn parse_dotnet_output(
+    reports: &[String],
+    stdout: &str,
+    stderr: &str,
+    success: bool,
+    total_time: Duration,
+) -> TestRunResult {
+    let tests: Vec<_> = reports.iter().flat_map(|xml| parse_trx(xml)).collect();
+
+    if success {
+        return TestRunResult::passed(total_time).with_tests(tests);
+    }
+
+    let error = if tests.iter().any(|t| !t.passed) {
+        "tests failed".to_string()
+    } else {
+        dotnet_error(stdout, stderr).unwrap_or_else(|| "dotnet test failed".to_string())
+    };
+    let mut result = TestRunResult::failed(total_time, error);
+    result.tests = tests;
+    result
+}
+
+/// Parse `<UnitTestResult>` elements from a TRX (Visual Studio test results) report.
+///
+/// ```xml
+/// <UnitTestResult testName="Acme.Tests.ParserTests.Parses" duration="00:00:00.0012345" outcome="Passed" />
+/// ```
+///
+/// `Passed` tests pass, `NotExecuted`/`Skipped`/`Inconclusive` tests are
+/// skipped, and every other outcome (`Failed`, `Error`, `Timeout`,
+/// `Aborted`, ...) fails.
+pub(crate) fn parse_trx(xml: &str) -> Vec<TestResult> {
+    let mut tests = Vec::new();
+
+    for (start, _) in xml.match_indices("<UnitTestResult ") {
+        let rest = &xml[start..];
+        let Some(tag_end) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[..tag_end];
+        let Some(name) = junit_attr(tag, "testName") else {
+            continue;
+        };
+        let duration =
+            junit_attr(tag, "duration").and_then(|d| parse_trx_duration(&d)).unwrap_or_default();
+
+        let test = match junit_attr(tag, "outcome").as_deref() {
+            Some("Passed") => TestResult::passed(name, duration),
+            Some("NotExecuted" | "Skipped" | "Inconclusive") => TestResult::skipped(name),
+            _ => TestResult::failed(name, duration),
+        };
+        tests.push(test);
+    }
+
+    tests
+}
+
+/// Parse a TRX duration (`hh:mm:ss.fffffff`).
+///
+/// Malformed values (`NaN`, negative or overflowing) give None.
+fn parse_trx_duration(value: &str) -> Option<Duration> {
+    let mut parts = value.splitn(3, ':');
+    let hours: u64 = parts.next()?.parse().ok()?;
+    let minutes: u64 = parts.next()?.parse().ok()?;
+    let seconds = Duration::try_from_secs_f64(parts.next()?.parse().ok()?).ok()?;
+    let whole = hours.checked_mul(3600)?.checked_add(minutes.checked_mul(60)?)?;
+    Duration::from_secs(whole).checked_add(seconds)
+}
+
+/// Extract the first build or run error from `dotnet test` output.
+///
+/// Covers MSBuild errors (`Program.cs(3,1): error CS1002: ; expected`) and
+/// missing projects (`MSBUILD : error MSB1003: Specify a project or solution file.`).
+pub(crate) fn dotnet_error(stdout: &str, stderr: &str) -> Option<String> {
+    stderr
+        .lines()
+        .chain(stdout.lines())
+        .map(str::trim)
+        .find(|l| l.contains(": error ") || l.starts_with("error "))
+        .map(String::from)
+}
+
+/// Collect and merge the Cobertura reports written by coverlet.
+fn collect_dotnet_coverage(results_dir: &Path) -> CoverageResult {
+    let start = Instant::now();
+    let reports =
+        find_files(results_dir, &|p| p.file_name().is_some_and(|n| n == "coverage.cobertura.xml"));
+    if reports.is_empty() {
+        return CoverageResult::skipped();
+    }
+
+    let results: Vec<_> = reports
+        .iter()
+        .filter_map(|f| std::fs::read_to_string(f).ok())
+        .map(|xml| parse_cobertura_xml(&xml, start.elapsed()))
+        .collect();
+    merge_coverage_results(&results, start.elapsed())
+}
+
+fn has_extension(path: &Path, ext: &str) -> bool {
+    path.extension().is_some_and(|e| e == ext)
+}
+
+/// Recursively collect files matching a predicate.
+fn find_files(dir: &Path, matches: &dyn Fn(&Path) -> bool) -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return found;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            found.extend(find_files(&path, matches));
+        } else if matches(&path) {
+            found.push(path);
+        }
+    }
+    found.sort();
+    found
+}
+
+#[cfg(test)]
+#[path = "dotnet_tests.rs"]
+mod tests;
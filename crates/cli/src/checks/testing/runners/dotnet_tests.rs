@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::path::Path;
+use std::time::Duration;
+
+use super::*;
+
+const TRX: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<TestRun id="0c1d" name="runner@host 2026-01-01 00:00:00" xmlns="http://microsoft.com/schemas/VisualStudio/TeamTest/2010">
+  <Results>
+    <UnitTestResult executionId="a1" testId="t1" testName="Acme.Tests.ParserTests.Parses" computerName="host" duration="00:00:00.0125000" startTime="2026-01-01T00:00:00" outcome="Passed" testListId="l1" />
+    <UnitTestResult executionId="a2" testId="t2" testName="Acme.Tests.ParserTests.Rejects(input: &quot;&lt;&gt;&quot;)" computerName="host" duration="00:00:01.5000000" startTime="2026-01-01T00:00:00" outcome="Failed" testListId="l1">
+      <Output>
+        <ErrorInfo>
+          <Message>Assert.Equal() Failure</Message>
+        </ErrorInfo>
+      </Output>
+    </UnitTestResult>
+    <UnitTestResult executionId="a3" testId="t3" testName="Acme.Tests.NetworkTests.Connects" computerName="host" startTime="2026-01-01T00:00:00" outcome="NotExecuted" testListId="l1" />
+  </Results>
+</TestRun>"#;
+
+#[test]
+fn command_writes_trx_to_results_directory() {
+    let cmd = dotnet_command(None, Path::new("/tmp/results"), false);
+    let args: Vec<_> = cmd.get_args().map(|a| a.to_string_lossy().into_owned()).collect();
+
+    assert_eq!(cmd.get_program(), "dotnet");
+    assert_eq!(args, ["test", "--logger", "trx", "--results-directory", "/tmp/results"]);
+}
+
+#[test]
+fn command_with_path_and_coverage() {
+    let cmd = dotnet_command(Some("tests/Acme.Tests"), Path::new("/tmp/results"), true);
+    let args: Vec<_> = cmd.get_args().map(|a| a.to_string_lossy().into_owned()).collect();
+
+    assert_eq!(args[..2], ["test", "tests/Acme.Tests"]);
+    assert_eq!(args[args.len() - 2..], ["--collect", "XPlat Code Coverage"]);
+}
+
+#[test]
+fn parses_trx_outcomes_and_durations() {
+    let tests = parse_trx(TRX);
+
+    assert_eq!(tests.len(), 3);
+    assert_eq!(tests[0].name, "Acme.Tests.ParserTests.Parses");
+    assert!(tests[0].passed);
+    assert_eq!(tests[0].duration, Duration::from_micros(12_500));
+    assert_eq!(tests[1].name, "Acme.Tests.ParserTests.Rejects(input: \"<>\")");
+    assert!(!tests[1].passed);
+    assert_eq!(tests[1].duration, Duration::from_millis(1500));
+    assert!(tests[2].skipped);
+}
+
+#[test]
+fn parses_long_durations() {
+    assert_eq!(parse_trx_duration("01:02:03.5"), Some(Duration::from_millis(3_723_500)));
+    assert_eq!(parse_trx_duration("bogus"), None);
+    assert_eq!(parse_trx_duration("00:00:NaN"), None);
+    assert_eq!(parse_trx_duration("00:00:inf"), None);
+    assert_eq!(parse_trx_duration("00:00:-1.5"), None);
+}
+
+#[test]
+fn failing_tests_report_tests_failed() {
+    let result = parse_dotnet_output(&[TRX.to_string()], "", "", false, Duration::from_secs(2));
+
+    assert!(!result.passed);
+    assert_eq!(result.error.as_deref(), Some("tests failed"));
+    assert_eq!(result.passed_count(), 1);
+    assert_eq!(result.failed_count(), 1);
+    assert_eq!(result.skipped_count(), 1);
+}
+
+#[test]
+fn combines_reports_from_several_projects() {
+    let other = r#"<UnitTestResult testName="Acme.Api.Tests.Health" duration="00:00:00.001" outcome="Passed" />"#;
+    let result =
+        parse_dotnet_output(&[TRX.to_string(), other.to_string()], "", "", false, Duration::ZERO);
+    assert_eq!(result.tests.len(), 4);
+}
+
+#[test]
+fn build_failure_reports_msbuild_error() {
+    let stdout = "  Determining projects to restore...\n/work/src/Acme/Parser.cs(12,5): error CS1002: ; expected [/work/src/Acme/Acme.csproj]\n";
+    let result = parse_dotnet_output(&[], stdout, "", false, Duration::ZERO);
+
+    assert_eq!(
+        result.error.as_deref(),
+        Some(
+            "/work/src/Acme/Parser.cs(12,5): error CS1002: ; expected [/work/src/Acme/Acme.csproj]"
+        )
+    );
+}
+
+#[test]
+fn failure_without_output_has_generic_error() {
+    let result = parse_dotnet_output(&[], "", "", false, Duration::ZERO);
+    assert_eq!(result.error.as_deref(), Some("dotnet test failed"));
+}
+
+#[test]
+fn merges_cobertura_reports_from_results_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    let report = |rate: &str| {
+        format!(
+            r#"<?xml version="1.0"?>
+<coverage line-rate="{rate}" version="1.9">
+  <packages><package name="Acme.Core" line-rate="{rate}"><classes>
+    <class name="Acme.Core.Parser" filename="src/Acme.Core/Parser.cs" line-rate="{rate}"/>
+  </classes></package></packages>
+</coverage>"#
+        )
+    };
+    for (guid, rate) in [("a1", "0.5"), ("b2", "0.8")] {
+        std::fs::create_dir(dir.path().join(guid)).unwrap();
+        std::fs::write(dir.path().join(guid).join("coverage.cobertura.xml"), report(rate)).unwrap();
+    }
+
+    let coverage = collect_dotnet_coverage(dir.path());
+
+    assert!(coverage.success);
+    assert_eq!(coverage.line_coverage, Some(80.0));
+    assert_eq!(coverage.files.get("src/Acme.Core/Parser.cs"), Some(&80.0));
+}
+
+#[test]
+fn missing_cobertura_reports_skip_coverage() {
+    let dir = tempfile::tempdir().unwrap();
+    let coverage = collect_dotnet_coverage(dir.path());
+    assert!(coverage.line_coverage.is_none());
+}
@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Coverage collection for Elixir projects.
+//!
+//! `mix coveralls.json` (from `excoveralls`) writes `cover/excoveralls.json`
+//! with per-line hit counts for each source file, relative to the project root:
+//!
+//! ```json
+//! {"source_files": [{"name": "lib/acme/accounts.ex", "coverage": [null, 1, 0]}]}
+//! ```
+//!
+//! `null` marks lines that are not relevant (blank lines, comments, `end`).
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime};
+
+use serde::Deserialize;
+
+use super::CoverageResult;
+
+/// Report written by `mix coveralls.json`.
+const EXCOVERALLS_REPORT: &str = "cover/excoveralls.json";
+
+/// Collect coverage from an excoveralls report written since `started`.
+pub(crate) fn collect_elixir_coverage(root: &Path, started: SystemTime) -> CoverageResult {
+    let start = Instant::now();
+    let report = root.join(EXCOVERALLS_REPORT);
+    let fresh = std::fs::metadata(&report).and_then(|m| m.modified()).is_ok_and(|m| m >= started);
+    if !fresh {
+        return CoverageResult::skipped();
+    }
+
+    match std::fs::read_to_string(&report) {
+        Ok(json) => parse_excoveralls_json(&json, start.elapsed()),
+        Err(e) => CoverageResult::failed(
+            start.elapsed(),
+            format!("failed to read {EXCOVERALLS_REPORT}: {e}"),
+        ),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ExcoverallsReport {
+    source_files: Vec<ExcoverallsFile>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExcoverallsFile {
+    name: String,
+    coverage: Vec<Option<u64>>,
+}
+
+/// Parse an excoveralls JSON report.
+///
+/// Overall coverage counts every relevant line, so large modules weigh more
+/// than small ones.
+pub(crate) fn parse_excoveralls_json(json: &str, duration: Duration) -> CoverageResult {
+    let report: ExcoverallsReport = match serde_json::from_str(json) {
+        Ok(r) => r,
+        Err(e) => {
+            return CoverageResult::failed(
+                duration,
+                format!("failed to parse excoveralls JSON: {e}"),
+            );
+        }
+    };
+
+    let mut files = HashMap::new();
+    let mut package_lines: HashMap<String, (usize, usize)> = HashMap::new();
+    let (mut covered, mut total) = (0, 0);
+
+    for file in &report.source_files {
+        let relevant: Vec<u64> = file.coverage.iter().flatten().copied().collect();
+        if relevant.is_empty() {
+            continue;
+        }
+        let file_covered = relevant.iter().filter(|hits| **hits > 0).count();
+        let file_total = relevant.len();
+
+        files.insert(file.name.clone(), percent(file_covered, file_total));
+        let package = package_lines.entry(extract_elixir_package(&file.name)).or_default();
+        package.0 += file_covered;
+        package.1 += file_total;
+        covered += file_covered;
+        total += file_total;
+    }
+
+    if total == 0 {
+        return CoverageResult::skipped();
+    }
+
+    CoverageResult {
+        success: true,
+        error: None,
+        duration,
+        line_coverage: Some(percent(covered, total)),
+        files,
+        packages: package_lines.into_iter().map(|(p, (c, t))| (p, percent(c, t))).collect(),
+    }
+}
+
+fn percent(covered: usize, total: usize) -> f64 {
+    covered as f64 / total as f64 * 100.0
+}
+
+/// Package of an Elixir source: the umbrella app (`apps/<app>/...`), else the
+/// first directory under `lib/`, else `root`.
+pub(crate) fn extract_elixir_package(path: &str) -> String {
+    if let Some(rest) = path.strip_prefix("apps/")
+        && let Some((app, _)) = rest.split_once('/')
+    {
+        return app.to_string();
+    }
+    match path.strip_prefix("lib/").and_then(|rest| rest.split_once('/')) {
+        Some((dir, _)) => dir.to_string(),
+        None => "root".to_string(),
+    }
+}
+
+#[cfg(test)]
+#[path = "elixir_coverage_tests.rs"]
+mod tests;
@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::time::Duration;
+
+use super::*;
+
+fn parse(json: &str) -> CoverageResult {
+    parse_excoveralls_json(json, Duration::ZERO)
+}
+
+#[test]
+fn counts_relevant_lines() {
+    let json = r#"{"source_files": [
+        {"name": "lib/acme/accounts.ex", "source": "", "coverage": [null, 1, 3, 0, null]},
+        {"name": "lib/acme.ex", "source": "", "coverage": [null, 0, 1]}
+    ]}"#;
+    let result = parse(json);
+
+    assert!(result.success);
+    let accounts = result.files.get("lib/acme/accounts.ex").copied().unwrap();
+    assert!((accounts - 66.666).abs() < 0.01);
+    assert_eq!(result.files.get("lib/acme.ex"), Some(&50.0));
+    assert_eq!(result.line_coverage, Some(60.0));
+}
+
+#[test]
+fn files_without_relevant_lines_are_skipped() {
+    let json = r#"{"source_files": [
+        {"name": "lib/acme/types.ex", "coverage": [null, null]},
+        {"name": "lib/acme/repo.ex", "coverage": [1]}
+    ]}"#;
+    let result = parse(json);
+
+    assert_eq!(result.files.len(), 1);
+    assert_eq!(result.line_coverage, Some(100.0));
+}
+
+#[test]
+fn groups_by_app_or_lib_directory() {
+    let json = r#"{"source_files": [
+        {"name": "lib/acme/accounts.ex", "coverage": [1, 0]},
+        {"name": "lib/acme_web/router.ex", "coverage": [1]},
+        {"name": "apps/billing/lib/billing/invoice.ex", "coverage": [0]}
+    ]}"#;
+    let result = parse(json);
+
+    assert_eq!(result.packages.get("acme"), Some(&50.0));
+    assert_eq!(result.packages.get("acme_web"), Some(&100.0));
+    assert_eq!(result.packages.get("billing"), Some(&0.0));
+}
+
+#[test]
+fn empty_report_is_skipped() {
+    let result = parse(r#"{"source_files": []}"#);
+    assert!(result.line_coverage.is_none());
+}
+
+#[test]
+fn invalid_json_fails() {
+    let result = parse("not json");
+    assert!(!result.success);
+}
+
+#[test]
+fn package_names() {
+    assert_eq!(extract_elixir_package("lib/acme/accounts.ex"), "acme");
+    assert_eq!(extract_elixir_package("apps/web/lib/web/endpoint.ex"), "web");
+    assert_eq!(extract_elixir_package("lib/acme.ex"), "root");
+}
@@ -11,6 +11,7 @@ use std::time::{Duration, Instant};
 
 use serde::Deserialize;
 
+use super::super::impact::go_packages;
 use super::go_coverage::collect_go_coverage;
 use super::{
     RunnerContext, TestResult, TestRunResult, TestRunner, handle_timeout_error, run_setup_or_fail,
@@ -48,9 +49,20 @@ impl TestRunner for GoRunner {
         let mut cmd = Command::new("go");
         cmd.args(["test", "-json"]);
 
-        // Add test path (default: ./...)
-        let test_path = config.path.as_deref().unwrap_or("./...");
-        cmd.arg(test_path);
+        // Add test path (default: ./..., or only affected packages when selecting by impact)
+        let packages = ctx
+            .changes
+            .filter(|_| config.path.is_none())
+            .and_then(|changes| go_packages(&changes, ctx.root))
+            .filter(|packages| !packages.is_empty());
+        match packages {
+            Some(packages) => {
+                cmd.args(packages);
+            }
+            None => {
+                cmd.arg(config.path.as_deref().unwrap_or("./..."));
+            }
+        }
 
         cmd.current_dir(ctx.root);
         cmd.stdout(Stdio::piped());
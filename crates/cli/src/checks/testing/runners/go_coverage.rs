@@ -220,7 +220,7 @@ fn normalize_go_path(path: &str) -> String {
 /// - `github.com/user/repo/pkg/math/math.go` -> `pkg/math`
 /// - `github.com/user/repo/internal/core/core.go` -> `internal/core`
 /// - `github.com/user/repo/main.go` -> `root`
-fn extract_go_package(path: &str) -> String {
+pub fn extract_go_package(path: &str) -> String {
     // Find common patterns: pkg/, internal/, cmd/
     for marker in ["pkg/", "internal/", "cmd/"] {
         if let Some(idx) = path.find(marker) {
@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Gradle test runner.
+//!
+//! Runs `./gradlew cleanTest test` (or `gradle` without a wrapper) and parses the
+//! JUnit XML reports under `build/test-results/`. Coverage is read from the
+//! JaCoCo plugin's `jacocoTestReport.xml` when the build produces one
+//! (e.g., `tasks.test { finalizedBy(tasks.jacocoTestReport) }`).
+
+use std::path::Path;
+use std::process::Command;
+
+use super::jvm_build::{JvmBuild, build_tool_available, run_jvm_build, wrapper_command};
+use super::{RunnerContext, TestRunResult, TestRunner, run_setup_or_fail};
+use crate::config::TestSuiteConfig;
+
+const GRADLE: JvmBuild = JvmBuild {
+    name: "gradle",
+    junit_dir: "build/test-results",
+    jacoco_report: "jacocoTestReport.xml",
+};
+
+/// Gradle runner for Java/Kotlin test suites.
+pub struct GradleRunner;
+
+impl TestRunner for GradleRunner {
+    fn name(&self) -> &'static str {
+        "gradle"
+    }
+
+    fn available(&self, ctx: &RunnerContext) -> bool {
+        build_tool_available(ctx.root, "gradlew", "gradle")
+    }
+
+    fn run(&self, config: &TestSuiteConfig, ctx: &RunnerContext) -> TestRunResult {
+        run_setup_or_fail!(config, ctx);
+
+        let cmd = gradle_command(ctx.root, config.path.as_deref());
+        run_jvm_build(&GRADLE, cmd, config, ctx)
+    }
+}
+
+/// Build the Gradle command.
+///
+/// `cleanTest` forces the tests to run even when Gradle considers them
+/// up to date; `--continue` keeps testing other modules after one fails.
+pub(crate) fn gradle_command(root: &Path, path: Option<&str>) -> Command {
+    let mut cmd = wrapper_command(root, "gradlew", "gradle");
+    if let Some(path) = path {
+        cmd.args(["-p", path]);
+    }
+    cmd.args(["cleanTest", "test", "--continue"]);
+    cmd
+}
+
+#[cfg(test)]
+#[path = "gradle_tests.rs"]
+mod tests;
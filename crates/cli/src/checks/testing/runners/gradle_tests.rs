@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::path::Path;
+
+use super::*;
+
+fn args(cmd: &Command) -> Vec<String> {
+    cmd.get_args().map(|a| a.to_string_lossy().to_string()).collect()
+}
+
+#[test]
+fn runs_tests_without_up_to_date_skip() {
+    let cmd = gradle_command(Path::new("/nonexistent"), None);
+
+    assert_eq!(cmd.get_program(), "gradle");
+    assert_eq!(args(&cmd), ["cleanTest", "test", "--continue"]);
+}
+
+#[test]
+fn path_selects_project_directory() {
+    let cmd = gradle_command(Path::new("/nonexistent"), Some("services/api"));
+
+    assert_eq!(args(&cmd), ["-p", "services/api", "cleanTest", "test", "--continue"]);
+}
@@ -59,6 +59,14 @@ impl TestRunner for JestRunner {
         cmd.args(&exec_cmd[1..]);
         cmd.args(["jest", "--json"]);
 
+        // Only run tests related to changed files when selecting by impact
+        if let Some(changes) = ctx.changes {
+            match changes.base {
+                Some(base) => cmd.arg(format!("--changedSince={base}")),
+                None => cmd.arg("--onlyChanged"),
+            };
+        }
+
         // Add test path if specified
         if let Some(path) = &config.path {
             cmd.arg(path);
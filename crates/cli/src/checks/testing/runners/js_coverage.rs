@@ -21,7 +21,7 @@ use crate::adapter::javascript::PackageManager;
 /// Generate a unique coverage directory name for a suite.
 ///
 /// Uses the suite's test path to avoid conflicts when multiple suites
-/// collect coverage in parallel (CI mode runs suites via `par_iter`).
+/// collect coverage in parallel (see `[check.tests] jobs`).
 fn coverage_dir_for(test_path: Option<&str>) -> String {
     match test_path {
         Some(path) => {
@@ -195,6 +195,73 @@ pub fn collect_bun_coverage(root: &Path, test_path: Option<&str>) -> CoverageRes
     }
 }
 
+/// Collect Deno coverage.
+///
+/// Runs `deno test --coverage`, converts the profile with `deno coverage --lcov`
+/// and parses the LCOV output.
+pub fn collect_deno_coverage(root: &Path, test_path: Option<&str>) -> CoverageResult {
+    let start = Instant::now();
+
+    let coverage_dir = root.join(coverage_dir_for(test_path));
+    let profile_dir = coverage_dir.join("profile");
+    let lcov_path = coverage_dir.join("lcov.info");
+
+    let mut cmd = Command::new("deno");
+    cmd.args(["test", "-A"]);
+    cmd.arg(format!("--coverage={}", profile_dir.display()));
+    if let Some(path) = test_path {
+        cmd.arg(path);
+    }
+    cmd.current_dir(root);
+    cmd.stdout(Stdio::piped());
+    cmd.stderr(Stdio::piped());
+
+    let output = match cmd.output() {
+        Ok(out) => out,
+        Err(e) => {
+            return CoverageResult::failed(
+                start.elapsed(),
+                format!("failed to run deno coverage: {e}"),
+            );
+        }
+    };
+
+    // Convert the raw V8 profile to LCOV
+    let report = Command::new("deno")
+        .arg("coverage")
+        .arg(&profile_dir)
+        .arg("--lcov")
+        .arg(format!("--output={}", lcov_path.display()))
+        .current_dir(root)
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .output();
+
+    let duration = start.elapsed();
+
+    match std::fs::read_to_string(&lcov_path) {
+        Ok(content) => {
+            // Cleanup coverage directory
+            std::fs::remove_dir_all(&coverage_dir).ok();
+            parse_lcov_report(&content, duration)
+        }
+        Err(e) => {
+            std::fs::remove_dir_all(&coverage_dir).ok();
+            // Report the first step that failed
+            let failed = [Some(&output), report.as_ref().ok()]
+                .into_iter()
+                .flatten()
+                .find(|out| !out.status.success());
+            if let Some(failed) = failed {
+                let stderr = String::from_utf8_lossy(&failed.stderr);
+                let msg = stderr.lines().take(10).collect::<Vec<_>>().join("\n");
+                return CoverageResult::failed(duration, format!("deno failed:\n{msg}"));
+            }
+            CoverageResult::failed(duration, format!("no coverage output: {e}"))
+        }
+    }
+}
+
 // =============================================================================
 // LCOV Parsing
 // =============================================================================
@@ -5,9 +5,11 @@
 //!
 //! Detection priority (first match wins):
 //! 1. Package manager (bun.lock -> Bun if installed)
-//! 2. Config files (most specific signal)
+//! 2. Config files (most specific signal; `deno.json` needs no package.json)
 //! 3. package.json devDependencies
 //! 4. package.json scripts.test command
+//! 5. Playwright config or dependency (e2e suites usually sit alongside a
+//!    unit test runner, so Playwright is only picked when it's the only one)
 
 use std::path::Path;
 use std::process::{Command, Stdio};
@@ -20,6 +22,9 @@ pub enum JsRunner {
     Vitest,
     Jest,
     Bun,
+    Deno,
+    Mocha,
+    Playwright,
 }
 
 impl JsRunner {
@@ -29,6 +34,9 @@ impl JsRunner {
             JsRunner::Vitest => "vitest",
             JsRunner::Jest => "jest",
             JsRunner::Bun => "bun",
+            JsRunner::Deno => "deno",
+            JsRunner::Mocha => "mocha",
+            JsRunner::Playwright => "playwright",
         }
     }
 }
@@ -94,7 +102,12 @@ pub fn detect_js_runner(root: &Path) -> Option<DetectionResult> {
     }
 
     // 3b. Check scripts.test
-    detect_from_test_script(&json)
+    if let Some(result) = detect_from_test_script(&json) {
+        return Some(result);
+    }
+
+    // 4. Fall back to Playwright when it's the only runner configured
+    detect_playwright(root, &json)
 }
 
 /// Detect test runner from package manager lock file.
@@ -148,12 +161,68 @@ fn detect_from_config_files(root: &Path) -> Option<DetectionResult> {
         }
     }
 
+    // Mocha config files
+    const MOCHA_CONFIGS: &[&str] = &[
+        ".mocharc.js",
+        ".mocharc.cjs",
+        ".mocharc.mjs",
+        ".mocharc.json",
+        ".mocharc.jsonc",
+        ".mocharc.yml",
+        ".mocharc.yaml",
+    ];
+    for config in MOCHA_CONFIGS {
+        if root.join(config).exists() {
+            return Some(DetectionResult {
+                runner: JsRunner::Mocha,
+                source: DetectionSource::ConfigFile((*config).to_string()),
+            });
+        }
+    }
+
+    // Deno config files (Deno projects often have no package.json)
+    const DENO_CONFIGS: &[&str] = &["deno.json", "deno.jsonc"];
+    for config in DENO_CONFIGS {
+        if root.join(config).exists() {
+            return Some(DetectionResult {
+                runner: JsRunner::Deno,
+                source: DetectionSource::ConfigFile((*config).to_string()),
+            });
+        }
+    }
+
     // Bun config (bun.toml with [test] section - less common)
     // Bun detection primarily via dependencies/scripts
 
     None
 }
 
+fn detect_playwright(root: &Path, json: &serde_json::Value) -> Option<DetectionResult> {
+    const PLAYWRIGHT_CONFIGS: &[&str] = &[
+        "playwright.config.ts",
+        "playwright.config.js",
+        "playwright.config.mts",
+        "playwright.config.mjs",
+    ];
+    for config in PLAYWRIGHT_CONFIGS {
+        if root.join(config).exists() {
+            return Some(DetectionResult {
+                runner: JsRunner::Playwright,
+                source: DetectionSource::ConfigFile((*config).to_string()),
+            });
+        }
+    }
+
+    if json.get("devDependencies").and_then(|d| d.get("@playwright/test")).is_some() {
+        return Some(DetectionResult {
+            runner: JsRunner::Playwright,
+            source: DetectionSource::DevDependency("@playwright/test".to_string()),
+        });
+    }
+
+    None
+}
+
 fn detect_from_dependencies(json: &serde_json::Value) -> Option<DetectionResult> {
     let dev_deps = json.get("devDependencies")?;
 
@@ -172,6 +241,13 @@ fn detect_from_dependencies(json: &serde_json::Value) -> Option<DetectionResult>
         });
     }
 
+    if dev_deps.get("mocha").is_some() {
+        return Some(DetectionResult {
+            runner: JsRunner::Mocha,
+            source: DetectionSource::DevDependency("mocha".to_string()),
+        });
+    }
+
     // Bun is typically used as a runtime, not a devDependency
     // Check dependencies too for bun-specific test setup
     let deps = json.get("dependencies");
@@ -210,6 +286,27 @@ fn detect_from_test_script(json: &serde_json::Value) -> Option<DetectionResult>
         });
     }
 
+    if test_script.contains("mocha") {
+        return Some(DetectionResult {
+            runner: JsRunner::Mocha,
+            source: DetectionSource::TestScript(test_script.to_string()),
+        });
+    }
+
+    if test_script.contains("deno test") {
+        return Some(DetectionResult {
+            runner: JsRunner::Deno,
+            source: DetectionSource::TestScript(test_script.to_string()),
+        });
+    }
+
+    if test_script.contains("playwright test") {
+        return Some(DetectionResult {
+            runner: JsRunner::Playwright,
+            source: DetectionSource::TestScript(test_script.to_string()),
+        });
+    }
+
     None
 }
 
@@ -200,6 +200,79 @@ fn vitest_takes_priority_over_jest_in_dependencies() {
     assert_eq!(result.runner, JsRunner::Vitest);
 }
 
+// =============================================================================
+// DENO, MOCHA AND PLAYWRIGHT DETECTION
+// =============================================================================
+
+#[test]
+fn detects_deno_from_config_without_package_json() {
+    let temp = temp_project();
+    fs::write(temp.path().join("deno.json"), r#"{"tasks": {}}"#).unwrap();
+
+    let result = detect_js_runner(temp.path()).unwrap();
+    assert_eq!(result.runner, JsRunner::Deno);
+    assert!(matches!(result.source, DetectionSource::ConfigFile(ref s) if s == "deno.json"));
+}
+
+#[test]
+fn detects_mocha_from_mocharc() {
+    let temp = temp_project();
+    fs::write(temp.path().join(".mocharc.yml"), "spec: test/**/*.spec.js\n").unwrap();
+
+    let result = detect_js_runner(temp.path()).unwrap();
+    assert_eq!(result.runner, JsRunner::Mocha);
+    assert!(matches!(result.source, DetectionSource::ConfigFile(ref s) if s == ".mocharc.yml"));
+}
+
+#[test]
+fn detects_mocha_from_dev_dependencies() {
+    let temp = temp_project();
+    fs::write(temp.path().join("package.json"), r#"{"devDependencies": {"mocha": "^10.0.0"}}"#)
+        .unwrap();
+
+    let result = detect_js_runner(temp.path()).unwrap();
+    assert_eq!(result.runner, JsRunner::Mocha);
+}
+
+#[test]
+fn detects_playwright_from_config() {
+    let temp = temp_project();
+    fs::write(temp.path().join("package.json"), r#"{"name": "e2e"}"#).unwrap();
+    fs::write(temp.path().join("playwright.config.ts"), "export default {}").unwrap();
+
+    let result = detect_js_runner(temp.path()).unwrap();
+    assert_eq!(result.runner, JsRunner::Playwright);
+    assert!(
+        matches!(result.source, DetectionSource::ConfigFile(ref s) if s == "playwright.config.ts")
+    );
+}
+
+#[test]
+fn unit_test_runner_takes_priority_over_playwright() {
+    let temp = temp_project();
+    fs::write(
+        temp.path().join("package.json"),
+        r#"{"devDependencies": {"@playwright/test": "^1.40.0", "vitest": "^2.0.0"}}"#,
+    )
+    .unwrap();
+    fs::write(temp.path().join("playwright.config.ts"), "export default {}").unwrap();
+
+    let result = detect_js_runner(temp.path()).unwrap();
+    assert_eq!(result.runner, JsRunner::Vitest);
+}
+
+#[test]
+fn detects_deno_and_playwright_from_test_script() {
+    let temp = temp_project();
+    fs::write(temp.path().join("package.json"), r#"{"scripts": {"test": "deno test -A"}}"#)
+        .unwrap();
+    assert_eq!(detect_js_runner(temp.path()).unwrap().runner, JsRunner::Deno);
+
+    fs::write(temp.path().join("package.json"), r#"{"scripts": {"test": "playwright test"}}"#)
+        .unwrap();
+    assert_eq!(detect_js_runner(temp.path()).unwrap().runner, JsRunner::Playwright);
+}
+
 // =============================================================================
 // NO DETECTION CASES
 // =============================================================================
@@ -245,6 +318,9 @@ fn js_runner_name_returns_correct_strings() {
     assert_eq!(JsRunner::Vitest.name(), "vitest");
     assert_eq!(JsRunner::Jest.name(), "jest");
     assert_eq!(JsRunner::Bun.name(), "bun");
+    assert_eq!(JsRunner::Deno.name(), "deno");
+    assert_eq!(JsRunner::Mocha.name(), "mocha");
+    assert_eq!(JsRunner::Playwright.name(), "playwright");
 }
 
 #[test]
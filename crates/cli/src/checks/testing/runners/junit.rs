@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! JUnit XML report parsing.
+//!
+//! JUnit XML is the common denominator for runners without a JSON reporter
+//! (e.g., `deno test --reporter=junit`).
+//!
+//! ```xml
+//! <testsuites>
+//!   <testsuite name="./math_test.ts" tests="2" failures="1">
+//!     <testcase name="adds" classname="./math_test.ts" time="0.001"/>
+//!     <testcase name="divides" classname="./math_test.ts" time="0.002">
+//!       <failure message="assertion failed"/>
+//!     </testcase>
+//!   </testsuite>
+//! </testsuites>
+//! ```
+
+use std::time::Duration;
+
+use super::TestResult;
+
+/// Parse `<testcase>` elements from a JUnit XML report.
+///
+/// Test names are `classname::name` when a classname is present. Testcases
+/// containing `<failure>` or `<error>` fail; those containing `<skipped>` are
+/// skipped.
+pub fn parse_junit_xml(xml: &str) -> Vec<TestResult> {
+    // Parse using simple string matching (avoid adding XML dependency)
+    let mut tests = Vec::new();
+
+    for (start, _) in xml.match_indices("<testcase") {
+        let rest = &xml[start..];
+        // Skip longer element names that share the prefix (e.g., <testcases>)
+        if !rest["<testcase".len()..].starts_with(|c: char| c.is_whitespace() || c == '>') {
+            continue;
+        }
+        let Some(tag_end) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[..tag_end];
+        let body = if tag.ends_with('/') {
+            ""
+        } else {
+            rest[tag_end..].find("</testcase>").map_or("", |end| &rest[tag_end..tag_end + end])
+        };
+
+        let Some(name) = junit_attr(tag, "name") else {
+            continue;
+        };
+        let name = match junit_attr(tag, "classname") {
+            Some(class) if !class.is_empty() => format!("{class}::{name}"),
+            _ => name,
+        };
+        let duration = junit_attr(tag, "time")
+            .and_then(|t| t.parse::<f64>().ok())
+            .filter(|t| t.is_finite() && *t >= 0.0)
+            .map(Duration::from_secs_f64)
+            .unwrap_or(Duration::ZERO);
+
+        tests.push(if body.contains("<skipped") {
+            TestResult::skipped(name)
+        } else if body.contains("<failure") || body.contains("<error") {
+            TestResult::failed(name, duration)
+        } else {
+            TestResult::passed(name, duration)
+        });
+    }
+
+    tests
+}
+
+/// Extract an attribute value from an XML start tag, decoding entities.
+///
+/// Matches whole attribute names (`name` does not match `classname`).
+pub(super) fn junit_attr(tag: &str, attr: &str) -> Option<String> {
+    let pattern = format!("{attr}=\"");
+    let mut search = 0;
+    while let Some(pos) = tag[search..].find(&pattern) {
+        let start = search + pos;
+        let preceded_by_space = tag[..start].ends_with(|c: char| c.is_whitespace());
+        let value_start = start + pattern.len();
+        if preceded_by_space {
+            let value_end = tag[value_start..].find('"')? + value_start;
+            return Some(decode_entities(&tag[value_start..value_end]));
+        }
+        search = value_start;
+    }
+    None
+}
+
+/// Decode the predefined XML entities.
+fn decode_entities(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+#[path = "junit_tests.rs"]
+mod tests;
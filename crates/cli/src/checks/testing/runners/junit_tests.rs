@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::time::Duration;
+
+use super::*;
+
+#[test]
+fn parses_passing_failing_and_skipped_testcases() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="deno test" tests="3" failures="1" errors="0" time="0.012">
+    <testsuite name="./math_test.ts" tests="3" disabled="1" errors="0" failures="1">
+        <testcase name="adds" classname="./math_test.ts" time="0.001" line="3" col="6">
+        </testcase>
+        <testcase name="divides" classname="./math_test.ts" time="0.250" line="7" col="6">
+            <failure message="Uncaught error">AssertionError: Values are not equal</failure>
+        </testcase>
+        <testcase name="pending" classname="./math_test.ts" time="0" line="11" col="6">
+            <skipped/>
+        </testcase>
+    </testsuite>
+</testsuites>"#;
+
+    let tests = parse_junit_xml(xml);
+
+    assert_eq!(tests.len(), 3);
+    assert_eq!(tests[0].name, "./math_test.ts::adds");
+    assert!(tests[0].passed);
+    assert_eq!(tests[0].duration, Duration::from_millis(1));
+    assert_eq!(tests[1].name, "./math_test.ts::divides");
+    assert!(!tests[1].passed);
+    assert_eq!(tests[1].duration, Duration::from_millis(250));
+    assert!(tests[2].skipped);
+}
+
+#[test]
+fn parses_self_closing_testcases() {
+    let xml = r#"<testsuite><testcase name="a" time="0.5"/><testcase name="b"/></testsuite>"#;
+
+    let tests = parse_junit_xml(xml);
+
+    assert_eq!(tests.len(), 2);
+    assert_eq!(tests[0].name, "a");
+    assert_eq!(tests[0].duration, Duration::from_millis(500));
+    assert_eq!(tests[1].duration, Duration::ZERO);
+}
+
+#[test]
+fn error_elements_fail_testcase() {
+    let xml = r#"<testcase name="boom" classname="Suite"><error type="panic"/></testcase>"#;
+
+    let tests = parse_junit_xml(xml);
+
+    assert!(!tests[0].passed);
+}
+
+#[test]
+fn name_attribute_does_not_match_classname() {
+    let xml = r#"<testcase classname="Suite" name="works" time="0"/>"#;
+
+    assert_eq!(parse_junit_xml(xml)[0].name, "Suite::works");
+}
+
+#[test]
+fn decodes_entities_in_names() {
+    let xml = r#"<testcase name="a &lt; b &amp;&amp; c" time="0"/>"#;
+
+    assert_eq!(parse_junit_xml(xml)[0].name, "a < b && c");
+}
+
+#[test]
+fn ignores_non_testcase_elements() {
+    assert!(parse_junit_xml("<testsuites><testsuite name=\"x\"/></testsuites>").is_empty());
+    assert!(parse_junit_xml("not xml").is_empty());
+}
@@ -0,0 +1,209 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Shared execution for the Gradle and Maven runners.
+//!
+//! Both builds write one JUnit XML file per test class (`TEST-*.xml`) and,
+//! with the JaCoCo plugin applied, an XML coverage report per module. Only
+//! reports written during this run are read, so stale results from an
+//! earlier build never count.
+
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant, SystemTime};
+
+use super::junit::parse_junit_xml;
+use super::jvm_coverage::jacoco_coverage;
+use super::{
+    RunnerContext, TestRunResult, handle_timeout_error, report_cutoff, run_with_timeout,
+    tool_available,
+};
+use crate::config::TestSuiteConfig;
+
+/// Where a JVM build tool writes its reports.
+pub(crate) struct JvmBuild {
+    /// Runner name ("gradle" or "maven").
+    pub name: &'static str,
+    /// Directory holding the JUnit XML reports, relative to a module.
+    pub junit_dir: &'static str,
+    /// File name of the JaCoCo XML report.
+    pub jacoco_report: &'static str,
+}
+
+/// Directories never searched for reports.
+const SKIP_DIRS: &[&str] = &["src", "node_modules", ".git", ".gradle", ".idea", ".mvn"];
+
+/// Build the tool command, preferring the project's wrapper script.
+pub(crate) fn wrapper_command(root: &Path, wrapper: &str, tool: &str) -> Command {
+    let script = root.join(wrapper);
+    if script.is_file() { Command::new(script) } else { Command::new(tool) }
+}
+
+/// Check if the wrapper script exists or the tool is installed.
+pub(crate) fn build_tool_available(root: &Path, wrapper: &str, tool: &str) -> bool {
+    root.join(wrapper).is_file() || tool_available(tool)
+}
+
+/// Run the build command and read the reports it wrote.
+pub(crate) fn run_jvm_build(
+    build: &JvmBuild,
+    mut cmd: Command,
+    config: &TestSuiteConfig,
+    ctx: &RunnerContext,
+) -> TestRunResult {
+    let started = report_cutoff();
+    let start = Instant::now();
+
+    cmd.current_dir(ctx.root);
+    cmd.stdout(Stdio::piped());
+    cmd.stderr(Stdio::piped());
+
+    let child = match cmd.spawn() {
+        Ok(c) => c,
+        Err(e) => {
+            return TestRunResult::failed(
+                start.elapsed(),
+                format!("failed to spawn {}: {e}", build.name),
+            );
+        }
+    };
+
+    let output = match run_with_timeout(child, config.timeout) {
+        Ok(out) => out,
+        Err(e) if e.kind() == ErrorKind::TimedOut => {
+            return handle_timeout_error(start.elapsed(), config.timeout, build.name);
+        }
+        Err(e) => {
+            return TestRunResult::failed(
+                start.elapsed(),
+                format!("failed to run {}: {e}", build.name),
+            );
+        }
+    };
+
+    let total_time = start.elapsed();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    // Reports live under the selected module (`-p dir` or `-f dir/pom.xml`)
+    let search_root = match &config.path {
+        Some(path) if ctx.root.join(path).is_file() => {
+            ctx.root.join(path).parent().unwrap_or(ctx.root).to_path_buf()
+        }
+        Some(path) => ctx.root.join(path),
+        None => ctx.root.to_path_buf(),
+    };
+
+    let mut junit_files = Vec::new();
+    find_reports(&search_root, started, &mut junit_files, &|path| {
+        is_junit_report(path, build.junit_dir)
+    });
+    junit_files.sort();
+    let junit: Vec<String> =
+        junit_files.iter().filter_map(|f| std::fs::read_to_string(f).ok()).collect();
+
+    let mut result =
+        parse_build_reports(&junit, &stdout, &stderr, output.status.success(), total_time);
+
+    if ctx.collect_coverage {
+        let coverage_start = Instant::now();
+        let mut jacoco_files = Vec::new();
+        find_reports(&search_root, started, &mut jacoco_files, &|path| {
+            path.file_name().is_some_and(|n| n == build.jacoco_report)
+        });
+        if !jacoco_files.is_empty() {
+            let reports: Vec<String> =
+                jacoco_files.iter().filter_map(|f| std::fs::read_to_string(f).ok()).collect();
+            let coverage = jacoco_coverage(&reports, coverage_start.elapsed());
+            result = result.with_collected_coverage(coverage, "jvm");
+        }
+    }
+
+    result
+}
+
+/// Build a result from the JUnit reports and build output.
+pub(crate) fn parse_build_reports(
+    junit: &[String],
+    stdout: &str,
+    stderr: &str,
+    success: bool,
+    total_time: Duration,
+) -> TestRunResult {
+    let tests: Vec<_> = junit.iter().flat_map(|xml| parse_junit_xml(xml)).collect();
+
+    if success {
+        return TestRunResult::passed(total_time).with_tests(tests);
+    }
+
+    let error = if tests.iter().any(|t| !t.passed) {
+        "tests failed".to_string()
+    } else {
+        // Failed before or outside of the tests (compilation, dependency resolution)
+        build_error(stdout, stderr).unwrap_or_else(|| "build failed".to_string())
+    };
+    let mut result = TestRunResult::failed(total_time, error);
+    result.tests = tests;
+    result
+}
+
+/// Extract the first error from Maven (`[ERROR] ...` on stdout) or
+/// Gradle (`* What went wrong:` on stderr) output.
+pub(crate) fn build_error(stdout: &str, stderr: &str) -> Option<String> {
+    let maven = stdout
+        .lines()
+        .filter_map(|l| l.trim().strip_prefix("[ERROR]"))
+        .map(str::trim)
+        .find(|l| !l.is_empty());
+    if let Some(line) = maven {
+        return Some(line.to_string());
+    }
+
+    let mut lines = stderr.lines().map(str::trim);
+    if lines.any(|l| l == "* What went wrong:") {
+        return lines.find(|l| !l.is_empty()).map(String::from);
+    }
+
+    stderr.lines().map(str::trim).rfind(|l| !l.is_empty()).map(String::from)
+}
+
+/// Check if a file is a JUnit report inside `junit_dir` (e.g., `target/surefire-reports`).
+pub(crate) fn is_junit_report(path: &Path, junit_dir: &str) -> bool {
+    let is_report = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| n.starts_with("TEST-") && n.ends_with(".xml"));
+    let dir = path.to_string_lossy().replace('\\', "/");
+    is_report && dir.contains(&format!("{junit_dir}/"))
+}
+
+/// Recursively collect report files written after `started`.
+fn find_reports(
+    dir: &Path,
+    started: SystemTime,
+    out: &mut Vec<PathBuf>,
+    matches: &dyn Fn(&Path) -> bool,
+) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            let skip =
+                path.file_name().and_then(|n| n.to_str()).is_some_and(|n| SKIP_DIRS.contains(&n));
+            if !skip {
+                find_reports(&path, started, out, matches);
+            }
+        } else if matches(&path)
+            && std::fs::metadata(&path).and_then(|m| m.modified()).is_ok_and(|m| m >= started)
+        {
+            out.push(path);
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "jvm_build_tests.rs"]
+mod tests;
@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::path::Path;
+use std::time::Duration;
+
+use super::*;
+
+const PASSING: &str = r#"<testsuite name="com.example.ParserTest" tests="2" failures="0">
+  <testcase name="parsesEmpty" classname="com.example.ParserTest" time="0.012"/>
+  <testcase name="parsesList" classname="com.example.ParserTest" time="0.030"/>
+</testsuite>"#;
+
+const FAILING: &str = r#"<testsuite name="com.example.LexerTest" tests="1" failures="1">
+  <testcase name="lexesNumbers" classname="com.example.LexerTest" time="0.005">
+    <failure message="expected: 1 but was: 2" type="org.opentest4j.AssertionFailedError"/>
+  </testcase>
+</testsuite>"#;
+
+#[test]
+fn passing_build_collects_tests_from_all_reports() {
+    let junit = vec![PASSING.to_string(), PASSING.to_string()];
+
+    let result = parse_build_reports(&junit, "", "", true, Duration::from_secs(3));
+
+    assert!(result.passed);
+    assert_eq!(result.tests.len(), 4);
+    assert_eq!(result.tests[0].name, "com.example.ParserTest::parsesEmpty");
+}
+
+#[test]
+fn failing_tests_fail_the_suite() {
+    let junit = vec![PASSING.to_string(), FAILING.to_string()];
+
+    let result = parse_build_reports(&junit, "", "", false, Duration::from_secs(3));
+
+    assert!(!result.passed);
+    assert_eq!(result.error.as_deref(), Some("tests failed"));
+    assert_eq!(result.tests.len(), 3);
+}
+
+#[test]
+fn compile_failure_reports_maven_error() {
+    let stdout = "[INFO] Scanning for projects...\n\
+                  [ERROR] COMPILATION ERROR : \n\
+                  [ERROR] /app/src/main/java/App.java:[3,1] class, interface, or enum expected\n";
+
+    let result = parse_build_reports(&[], stdout, "", false, Duration::ZERO);
+
+    assert!(!result.passed);
+    assert_eq!(result.error.as_deref(), Some("COMPILATION ERROR :"));
+}
+
+#[test]
+fn compile_failure_reports_gradle_error() {
+    let stderr = "\nFAILURE: Build failed with an exception.\n\n\
+                  * What went wrong:\n\
+                  Execution failed for task ':compileKotlin'.\n\
+                  > Compilation error. See log for more details\n";
+
+    assert_eq!(
+        build_error("", stderr).as_deref(),
+        Some("Execution failed for task ':compileKotlin'.")
+    );
+}
+
+#[test]
+fn build_error_falls_back_to_last_stderr_line() {
+    assert_eq!(
+        build_error("", "gradle: command not found\n").as_deref(),
+        Some("gradle: command not found")
+    );
+    assert_eq!(build_error("", ""), None);
+}
+
+#[test]
+fn junit_reports_must_be_in_report_dir() {
+    let gradle = "build/test-results";
+    assert!(is_junit_report(
+        Path::new("/repo/app/build/test-results/test/TEST-com.example.AppTest.xml"),
+        gradle
+    ));
+    assert!(!is_junit_report(
+        Path::new("/repo/app/build/test-results/test/binary/output.bin"),
+        gradle
+    ));
+    assert!(!is_junit_report(Path::new("/repo/fixtures/TEST-sample.xml"), gradle));
+
+    let maven = "target/surefire-reports";
+    assert!(is_junit_report(Path::new("/repo/target/surefire-reports/TEST-AppTest.xml"), maven));
+}
+
+#[test]
+fn wrapper_preferred_when_present() {
+    let dir = tempfile::tempdir().unwrap();
+    assert_eq!(wrapper_command(dir.path(), "gradlew", "gradle").get_program(), "gradle");
+
+    std::fs::write(dir.path().join("gradlew"), "#!/bin/sh\n").unwrap();
+    assert_eq!(
+        wrapper_command(dir.path(), "gradlew", "gradle").get_program(),
+        dir.path().join("gradlew").as_os_str()
+    );
+}
@@ -0,0 +1,139 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! JaCoCo coverage parsing for Gradle and Maven projects.
+//!
+//! Reads the XML report written by the JaCoCo Gradle plugin
+//! (`build/reports/jacoco/test/jacocoTestReport.xml`) or Maven plugin
+//! (`target/site/jacoco/jacoco.xml`). Multi-module builds write one report
+//! per module; all fresh reports under the project are combined.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use super::CoverageResult;
+use super::junit::junit_attr;
+
+/// Line counters for one source file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct LineCounts {
+    pub covered: u64,
+    pub missed: u64,
+}
+
+impl LineCounts {
+    fn percent(self) -> Option<f64> {
+        let total = self.covered + self.missed;
+        (total > 0).then(|| self.covered as f64 / total as f64 * 100.0)
+    }
+}
+
+/// Per-file line counters from a JaCoCo XML report, keyed by
+/// `package/dir/File.java`.
+///
+/// ```xml
+/// <report name="app">
+///   <package name="com/example">
+///     <class name="com/example/App" sourcefilename="App.java">...</class>
+///     <sourcefile name="App.java">
+///       <line nr="3" mi="0" ci="2" mb="0" cb="0"/>
+///       <counter type="LINE" missed="1" covered="3"/>
+///     </sourcefile>
+///   </package>
+/// </report>
+/// ```
+pub(crate) fn parse_jacoco_xml(xml: &str) -> HashMap<String, LineCounts> {
+    // Parse using simple string matching (avoid adding XML dependency)
+    let mut files = HashMap::new();
+
+    for (pkg_start, _) in xml.match_indices("<package ") {
+        let pkg = &xml[pkg_start..];
+        let pkg_end = pkg.find("</package>").unwrap_or(pkg.len());
+        let pkg = &pkg[..pkg_end];
+        let Some(pkg_tag_end) = pkg.find('>') else {
+            continue;
+        };
+        let Some(package) = junit_attr(&pkg[..pkg_tag_end], "name") else {
+            continue;
+        };
+
+        for (file_start, _) in pkg.match_indices("<sourcefile ") {
+            let file = &pkg[file_start..];
+            let file = &file[..file.find("</sourcefile>").unwrap_or(file.len())];
+            let Some(tag_end) = file.find('>') else {
+                continue;
+            };
+            let Some(name) = junit_attr(&file[..tag_end], "name") else {
+                continue;
+            };
+            let Some(counts) = line_counter(file) else {
+                continue;
+            };
+            let path = if package.is_empty() { name } else { format!("{package}/{name}") };
+            files.insert(path, counts);
+        }
+    }
+
+    files
+}
+
+/// Find the `<counter type="LINE" .../>` element in a fragment.
+fn line_counter(fragment: &str) -> Option<LineCounts> {
+    fragment.match_indices("<counter ").find_map(|(start, _)| {
+        let tag = &fragment[start..];
+        let tag = &tag[..tag.find('>')?];
+        if junit_attr(tag, "type").as_deref() != Some("LINE") {
+            return None;
+        }
+        Some(LineCounts {
+            covered: junit_attr(tag, "covered")?.parse().ok()?,
+            missed: junit_attr(tag, "missed")?.parse().ok()?,
+        })
+    })
+}
+
+/// Combine JaCoCo reports into a coverage result.
+///
+/// A file reported by several reports (e.g., a module report and an
+/// aggregate report) keeps its best coverage. Packages use dotted JVM names.
+pub(crate) fn jacoco_coverage(reports: &[String], duration: Duration) -> CoverageResult {
+    let mut files: HashMap<String, LineCounts> = HashMap::new();
+    for xml in reports {
+        for (path, counts) in parse_jacoco_xml(xml) {
+            let entry = files.entry(path).or_default();
+            if counts.percent() > entry.percent() {
+                *entry = counts;
+            }
+        }
+    }
+
+    let mut total = LineCounts::default();
+    let mut packages: HashMap<String, LineCounts> = HashMap::new();
+    for (path, counts) in &files {
+        total.covered += counts.covered;
+        total.missed += counts.missed;
+        let package = match path.rsplit_once('/') {
+            Some((dir, _)) => dir.replace('/', "."),
+            None => "root".to_string(),
+        };
+        let entry = packages.entry(package).or_default();
+        entry.covered += counts.covered;
+        entry.missed += counts.missed;
+    }
+
+    CoverageResult {
+        success: true,
+        error: None,
+        duration,
+        line_coverage: total.percent(),
+        files: files.into_iter().filter_map(|(p, c)| c.percent().map(|pct| (p, pct))).collect(),
+        packages: packages
+            .into_iter()
+            .filter_map(|(p, c)| c.percent().map(|pct| (p, pct)))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+#[path = "jvm_coverage_tests.rs"]
+mod tests;
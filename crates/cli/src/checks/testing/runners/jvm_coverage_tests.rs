@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::time::Duration;
+
+use super::*;
+
+const REPORT: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<!DOCTYPE report PUBLIC "-//JACOCO//DTD Report 1.1//EN" "report.dtd">
+<report name="app">
+    <sessioninfo id="host-1" start="1" dump="2"/>
+    <package name="com/example/core">
+        <class name="com/example/core/Parser" sourcefilename="Parser.java">
+            <method name="parse" desc="()V" line="5">
+                <counter type="LINE" missed="9" covered="1"/>
+            </method>
+            <counter type="LINE" missed="9" covered="1"/>
+        </class>
+        <sourcefile name="Parser.java">
+            <line nr="5" mi="0" ci="3" mb="0" cb="0"/>
+            <counter type="INSTRUCTION" missed="4" covered="12"/>
+            <counter type="LINE" missed="1" covered="3"/>
+        </sourcefile>
+        <sourcefile name="Lexer.kt">
+            <counter type="LINE" missed="0" covered="4"/>
+        </sourcefile>
+        <counter type="LINE" missed="1" covered="7"/>
+    </package>
+    <package name="com/example/cli">
+        <sourcefile name="Main.java">
+            <counter type="LINE" missed="2" covered="0"/>
+        </sourcefile>
+    </package>
+    <counter type="LINE" missed="3" covered="7"/>
+</report>"#;
+
+#[test]
+fn parses_line_counters_per_source_file() {
+    let files = parse_jacoco_xml(REPORT);
+
+    assert_eq!(files.len(), 3);
+    assert_eq!(files["com/example/core/Parser.java"], LineCounts { covered: 3, missed: 1 });
+    assert_eq!(files["com/example/core/Lexer.kt"], LineCounts { covered: 4, missed: 0 });
+    assert_eq!(files["com/example/cli/Main.java"], LineCounts { covered: 0, missed: 2 });
+}
+
+#[test]
+fn coverage_totals_and_packages() {
+    let result = jacoco_coverage(&[REPORT.to_string()], Duration::ZERO);
+
+    assert!(result.success);
+    assert_eq!(result.line_coverage, Some(70.0));
+    assert_eq!(result.files["com/example/core/Parser.java"], 75.0);
+    assert_eq!(result.packages["com.example.core"], 87.5);
+    assert_eq!(result.packages["com.example.cli"], 0.0);
+}
+
+#[test]
+fn combines_module_reports() {
+    let api = r#"<report name="api"><package name="com/example/api">
+        <sourcefile name="Api.java"><counter type="LINE" missed="5" covered="5"/></sourcefile>
+    </package></report>"#;
+    let aggregate = r#"<report name="all"><package name="com/example/api">
+        <sourcefile name="Api.java"><counter type="LINE" missed="1" covered="9"/></sourcefile>
+    </package></report>"#;
+
+    let result = jacoco_coverage(&[api.to_string(), aggregate.to_string()], Duration::ZERO);
+
+    // The same file keeps its best coverage instead of being counted twice
+    assert_eq!(result.line_coverage, Some(90.0));
+    assert_eq!(result.files.len(), 1);
+}
+
+#[test]
+fn default_package_files_use_root_package() {
+    let xml = r#"<report name="x"><package name="">
+        <sourcefile name="App.java"><counter type="LINE" missed="1" covered="1"/></sourcefile>
+    </package></report>"#;
+
+    let result = jacoco_coverage(&[xml.to_string()], Duration::ZERO);
+
+    assert_eq!(result.files["App.java"], 50.0);
+    assert_eq!(result.packages["root"], 50.0);
+}
+
+#[test]
+fn empty_report_has_no_coverage() {
+    let result = jacoco_coverage(&["<report name=\"x\"/>".to_string()], Duration::ZERO);
+
+    assert_eq!(result.line_coverage, None);
+    assert!(result.files.is_empty());
+}
@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Maven test runner.
+//!
+//! Runs `./mvnw test` (or `mvn test` without a wrapper) and parses the
+//! Surefire JUnit XML reports under `target/surefire-reports/`. Coverage is
+//! read from `target/site/jacoco/jacoco.xml` when the JaCoCo plugin's
+//! `report` goal runs during the build.
+
+use std::path::Path;
+use std::process::Command;
+
+use super::jvm_build::{JvmBuild, build_tool_available, run_jvm_build, wrapper_command};
+use super::{RunnerContext, TestRunResult, TestRunner, run_setup_or_fail};
+use crate::config::TestSuiteConfig;
+
+const MAVEN: JvmBuild =
+    JvmBuild { name: "maven", junit_dir: "target/surefire-reports", jacoco_report: "jacoco.xml" };
+
+/// Maven runner for Java/Kotlin test suites.
+pub struct MavenRunner;
+
+impl TestRunner for MavenRunner {
+    fn name(&self) -> &'static str {
+        "maven"
+    }
+
+    fn available(&self, ctx: &RunnerContext) -> bool {
+        build_tool_available(ctx.root, "mvnw", "mvn")
+    }
+
+    fn run(&self, config: &TestSuiteConfig, ctx: &RunnerContext) -> TestRunResult {
+        run_setup_or_fail!(config, ctx);
+
+        let cmd = maven_command(ctx.root, config.path.as_deref());
+        run_jvm_build(&MAVEN, cmd, config, ctx)
+    }
+}
+
+/// Build the Maven command.
+///
+/// Batch mode keeps the output free of progress noise; `-fae` (fail at end)
+/// keeps testing other modules after one fails.
+pub(crate) fn maven_command(root: &Path, path: Option<&str>) -> Command {
+    let mut cmd = wrapper_command(root, "mvnw", "mvn");
+    cmd.args(["--batch-mode", "-fae"]);
+    if let Some(path) = path {
+        cmd.args(["-f", path]);
+    }
+    cmd.arg("test");
+    cmd
+}
+
+#[cfg(test)]
+#[path = "maven_tests.rs"]
+mod tests;
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::path::Path;
+
+use super::*;
+
+fn args(cmd: &Command) -> Vec<String> {
+    cmd.get_args().map(|a| a.to_string_lossy().to_string()).collect()
+}
+
+#[test]
+fn runs_tests_in_batch_mode() {
+    let cmd = maven_command(Path::new("/nonexistent"), None);
+
+    assert_eq!(cmd.get_program(), "mvn");
+    assert_eq!(args(&cmd), ["--batch-mode", "-fae", "test"]);
+}
+
+#[test]
+fn path_selects_module_pom() {
+    let cmd = maven_command(Path::new("/nonexistent"), Some("services/api/pom.xml"));
+
+    assert_eq!(args(&cmd), ["--batch-mode", "-fae", "-f", "services/api/pom.xml", "test"]);
+}
@@ -0,0 +1,174 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Mix (ExUnit) runner for Elixir projects.
+//!
+//! Runs `mix test [path] --formatter JUnitFormatter --formatter ExUnit.CLIFormatter`
+//! and parses the `test-junit-report.xml` files the `junit_formatter` package
+//! writes under `_build/test/lib/<app>/`. With coverage enabled, the tests run
+//! through `mix coveralls.json` instead, and the `cover/excoveralls.json`
+//! report is parsed (see `elixir_coverage`).
+
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant, SystemTime};
+
+use super::elixir_coverage::collect_elixir_coverage;
+use super::junit::parse_junit_xml;
+use super::{
+    RunnerContext, TestResult, TestRunResult, TestRunner, handle_timeout_error, report_cutoff,
+    run_setup_or_fail, run_with_timeout, tool_available,
+};
+use crate::config::TestSuiteConfig;
+
+/// File name used by `junit_formatter` for its report.
+const JUNIT_REPORT: &str = "test-junit-report.xml";
+
+/// Mix runner for ExUnit test suites.
+pub struct MixRunner;
+
+impl TestRunner for MixRunner {
+    fn name(&self) -> &'static str {
+        "mix"
+    }
+
+    fn available(&self, _ctx: &RunnerContext) -> bool {
+        tool_available("mix")
+    }
+
+    fn run(&self, config: &TestSuiteConfig, ctx: &RunnerContext) -> TestRunResult {
+        run_setup_or_fail!(config, ctx);
+
+        // Reports and coverage from an earlier run are ignored by modification time
+        let started = report_cutoff();
+        let start = Instant::now();
+
+        let mut cmd = mix_command(config.path.as_deref(), ctx.collect_coverage);
+        cmd.current_dir(ctx.root);
+        cmd.stdout(Stdio::piped());
+        cmd.stderr(Stdio::piped());
+
+        let child = match cmd.spawn() {
+            Ok(c) => c,
+            Err(e) => {
+                return TestRunResult::failed(start.elapsed(), format!("failed to spawn mix: {e}"));
+            }
+        };
+
+        let output = match run_with_timeout(child, config.timeout) {
+            Ok(out) => out,
+            Err(e) if e.kind() == ErrorKind::TimedOut => {
+                return handle_timeout_error(start.elapsed(), config.timeout, "mix");
+            }
+            Err(e) => {
+                return TestRunResult::failed(start.elapsed(), format!("failed to run mix: {e}"));
+            }
+        };
+
+        let total_time = start.elapsed();
+        let reports: Vec<String> = find_junit_reports(ctx.root, started)
+            .iter()
+            .filter_map(|f| std::fs::read_to_string(f).ok())
+            .collect();
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let mut result =
+            parse_mix_output(&reports, &stdout, &stderr, output.status.success(), total_time);
+
+        if ctx.collect_coverage {
+            let coverage = collect_elixir_coverage(ctx.root, started);
+            result = result.with_collected_coverage(coverage, "elixir");
+        }
+
+        result
+    }
+}
+
+/// Build the mix command.
+///
+/// `path` selects a test directory or file. With coverage, `mix coveralls.json`
+/// (from `excoveralls`) runs the tests and forwards the same arguments to
+/// `mix test`; `MIX_ENV=test` is set since the task does not force it.
+pub(crate) fn mix_command(path: Option<&str>, coverage: bool) -> Command {
+    let mut cmd = Command::new("mix");
+    if coverage {
+        cmd.arg("coveralls.json");
+        cmd.env("MIX_ENV", "test");
+    } else {
+        cmd.arg("test");
+    }
+    if let Some(path) = path {
+        cmd.arg(path);
+    }
+    cmd.args(["--formatter", "JUnitFormatter", "--formatter", "ExUnit.CLIFormatter"]);
+    cmd
+}
+
+/// Build a result from the JUnit reports and mix output.
+pub(crate) fn parse_mix_output(
+    reports: &[String],
+    stdout: &str,
+    stderr: &str,
+    success: bool,
+    total_time: Duration,
+) -> TestRunResult {
+    let tests: Vec<_> =
+        reports.iter().flat_map(|xml| parse_junit_xml(xml)).map(strip_module_prefix).collect();
+
+    if success {
+        return TestRunResult::passed(total_time).with_tests(tests);
+    }
+
+    let error = if tests.iter().any(|t| !t.passed) {
+        "tests failed".to_string()
+    } else {
+        mix_error(stdout, stderr).unwrap_or_else(|| "mix test failed".to_string())
+    };
+    let mut result = TestRunResult::failed(total_time, error);
+    result.tests = tests;
+    result
+}
+
+/// Report `Acme.AccountsTest::test creates user` instead of `Elixir.Acme.AccountsTest::...`.
+fn strip_module_prefix(mut test: TestResult) -> TestResult {
+    if let Some(name) = test.name.strip_prefix("Elixir.") {
+        test.name = name.to_string();
+    }
+    test
+}
+
+/// Extract the first compile or Mix error from mix output.
+///
+/// Covers exceptions (`** (CompileError) lib/acme.ex:3: undefined function x/0`,
+/// `** (Mix) Could not find a Mix.Project`) and compiler diagnostics
+/// (`error: undefined variable "x"`).
+pub(crate) fn mix_error(stdout: &str, stderr: &str) -> Option<String> {
+    stderr
+        .lines()
+        .chain(stdout.lines())
+        .map(str::trim)
+        .find(|l| l.starts_with("** (") || l.starts_with("error: "))
+        .map(String::from)
+}
+
+/// Find JUnit reports under `_build/test/lib/*/` written since `started`.
+fn find_junit_reports(root: &Path, started: SystemTime) -> Vec<PathBuf> {
+    let Ok(apps) = std::fs::read_dir(root.join("_build/test/lib")) else {
+        return Vec::new();
+    };
+    let mut reports: Vec<PathBuf> = apps
+        .flatten()
+        .map(|app| app.path().join(JUNIT_REPORT))
+        .filter(|report| {
+            std::fs::metadata(report).and_then(|m| m.modified()).is_ok_and(|m| m >= started)
+        })
+        .collect();
+    reports.sort();
+    reports
+}
+
+#[cfg(test)]
+#[path = "mix_tests.rs"]
+mod tests;
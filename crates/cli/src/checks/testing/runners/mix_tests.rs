@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::time::Duration;
+
+use super::*;
+
+const REPORT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites>
+  <testsuite errors="0" failures="1" name="Elixir.Acme.AccountsTest" skipped="1" tests="3" time="0.05">
+    <testcase classname="Elixir.Acme.AccountsTest" name="test creates user" time="0.012" file="test/acme/accounts_test.exs"/>
+    <testcase classname="Elixir.Acme.AccountsTest" name="test rejects duplicate email" time="0.030">
+      <failure message="Assertion with == failed">test/acme/accounts_test.exs:12</failure>
+    </testcase>
+    <testcase classname="Elixir.Acme.AccountsTest" name="test sends welcome email" time="0">
+      <skipped/>
+    </testcase>
+  </testsuite>
+</testsuites>"#;
+
+fn args(cmd: &Command) -> Vec<String> {
+    cmd.get_args().map(|a| a.to_string_lossy().into_owned()).collect()
+}
+
+#[test]
+fn command_runs_mix_test_with_junit_formatter() {
+    let cmd = mix_command(None, false);
+
+    assert_eq!(cmd.get_program(), "mix");
+    assert_eq!(
+        args(&cmd),
+        ["test", "--formatter", "JUnitFormatter", "--formatter", "ExUnit.CLIFormatter"]
+    );
+}
+
+#[test]
+fn coverage_command_runs_coveralls_json_in_test_env() {
+    let cmd = mix_command(Some("test/acme"), true);
+
+    assert_eq!(args(&cmd)[..2], ["coveralls.json", "test/acme"]);
+    let env: Vec<_> = cmd.get_envs().collect();
+    assert!(env.iter().any(|(k, v)| *k == "MIX_ENV" && v.is_some_and(|v| v == "test")));
+}
+
+#[test]
+fn parses_junit_report_without_module_prefix() {
+    let result = parse_mix_output(&[REPORT.to_string()], "", "", false, Duration::from_secs(1));
+
+    assert!(!result.passed);
+    assert_eq!(result.error.as_deref(), Some("tests failed"));
+    assert_eq!(result.tests[0].name, "Acme.AccountsTest::test creates user");
+    assert_eq!(result.passed_count(), 1);
+    assert_eq!(result.failed_count(), 1);
+    assert_eq!(result.skipped_count(), 1);
+}
+
+#[test]
+fn passing_run_keeps_tests() {
+    let xml = r#"<testsuite><testcase classname="Elixir.A" name="test a" time="0.5"/></testsuite>"#;
+    let result = parse_mix_output(&[xml.to_string()], "", "", true, Duration::from_secs(1));
+
+    assert!(result.passed);
+    assert_eq!(result.tests.len(), 1);
+    assert_eq!(result.tests[0].duration, Duration::from_millis(500));
+}
+
+#[test]
+fn compile_failure_reports_exception() {
+    let stderr = "Compiling 3 files (.ex)\n\
+** (CompileError) lib/acme/accounts.ex:3: undefined function x/0\n";
+    let result = parse_mix_output(&[], "", stderr, false, Duration::from_secs(1));
+
+    assert_eq!(
+        result.error.as_deref(),
+        Some("** (CompileError) lib/acme/accounts.ex:3: undefined function x/0")
+    );
+}
+
+#[test]
+fn compiler_diagnostic_reported() {
+    let stderr =
+        "error: undefined variable \"user\"\n  lib/acme/accounts.ex:7: Acme.Accounts.get/1\n";
+    let result = parse_mix_output(&[], "", stderr, false, Duration::from_secs(1));
+
+    assert_eq!(result.error.as_deref(), Some("error: undefined variable \"user\""));
+}
+
+#[test]
+fn failure_without_output_has_generic_error() {
+    let result = parse_mix_output(&[], "", "", false, Duration::from_secs(1));
+    assert_eq!(result.error.as_deref(), Some("mix test failed"));
+}
+
+#[test]
+fn finds_fresh_reports_per_app() {
+    let dir = tempfile::tempdir().unwrap();
+    let app = dir.path().join("_build/test/lib/acme");
+    std::fs::create_dir_all(&app).unwrap();
+    std::fs::write(app.join(JUNIT_REPORT), REPORT).unwrap();
+    std::fs::create_dir_all(dir.path().join("_build/test/lib/plug")).unwrap();
+
+    let past = SystemTime::now() - Duration::from_secs(60);
+    assert_eq!(find_junit_reports(dir.path(), past), [app.join(JUNIT_REPORT)]);
+
+    let future = SystemTime::now() + Duration::from_secs(60);
+    assert!(find_junit_reports(dir.path(), future).is_empty());
+}
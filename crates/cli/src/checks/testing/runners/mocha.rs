@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Mocha test runner.
+//!
+//! Executes JavaScript/TypeScript tests using the detected package manager's
+//! exec command (e.g., `npx mocha --reporter json`).
+
+use std::collections::HashSet;
+use std::io::ErrorKind;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+
+use super::json_utils::find_json_object;
+use super::{
+    RunnerContext, TestResult, TestRunResult, TestRunner, handle_timeout_error, run_setup_or_fail,
+    run_with_timeout,
+};
+use crate::adapter::javascript::PackageManager;
+use crate::config::TestSuiteConfig;
+
+/// Mocha runner for JavaScript/TypeScript test suites.
+pub struct MochaRunner;
+
+impl TestRunner for MochaRunner {
+    fn name(&self) -> &'static str {
+        "mocha"
+    }
+
+    fn available(&self, ctx: &RunnerContext) -> bool {
+        // Check if mocha is available via package manager's exec command
+        let pkg_mgr = PackageManager::detect(ctx.root);
+        let exec_cmd = pkg_mgr.exec_command();
+
+        let mut cmd = Command::new(&exec_cmd[0]);
+        cmd.args(&exec_cmd[1..]);
+        cmd.args(["mocha", "--version"]);
+        cmd.stdout(Stdio::null());
+        cmd.stderr(Stdio::null());
+
+        let mocha_installed = cmd.status().is_ok_and(|s| s.success());
+
+        // And project has package.json
+        mocha_installed && ctx.root.join("package.json").exists()
+    }
+
+    fn run(&self, config: &TestSuiteConfig, ctx: &RunnerContext) -> TestRunResult {
+        run_setup_or_fail!(config, ctx);
+
+        let start = Instant::now();
+
+        // Build command using detected package manager
+        let pkg_mgr = PackageManager::detect(ctx.root);
+        let exec_cmd = pkg_mgr.exec_command();
+
+        let mut cmd = Command::new(&exec_cmd[0]);
+        cmd.args(&exec_cmd[1..]);
+        cmd.args(["mocha", "--reporter", "json"]);
+
+        // Add test path if specified (otherwise .mocharc.* / ./test decide)
+        if let Some(path) = &config.path {
+            cmd.arg(path);
+        }
+
+        cmd.current_dir(ctx.root);
+        cmd.stdout(Stdio::piped());
+        cmd.stderr(Stdio::piped());
+
+        let child = match cmd.spawn() {
+            Ok(c) => c,
+            Err(e) => {
+                return TestRunResult::failed(
+                    start.elapsed(),
+                    format!("failed to spawn mocha: {e}"),
+                );
+            }
+        };
+
+        let output = match run_with_timeout(child, config.timeout) {
+            Ok(out) => out,
+            Err(e) if e.kind() == ErrorKind::TimedOut => {
+                return handle_timeout_error(start.elapsed(), config.timeout, "mocha");
+            }
+            Err(e) => {
+                return TestRunResult::failed(start.elapsed(), format!("failed to run mocha: {e}"));
+            }
+        };
+
+        let total_time = start.elapsed();
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        parse_mocha_json(&stdout, total_time)
+    }
+}
+
+/// Mocha JSON reporter output.
+#[derive(Debug, Deserialize)]
+struct MochaOutput {
+    stats: MochaStats,
+    #[serde(default)]
+    tests: Vec<MochaTest>,
+    #[serde(default)]
+    pending: Vec<MochaTest>,
+}
+
+/// Summary counts from the mocha JSON reporter.
+#[derive(Debug, Deserialize)]
+struct MochaStats {
+    #[serde(default)]
+    failures: usize,
+}
+
+/// A single test from the mocha JSON reporter.
+#[derive(Debug, Deserialize)]
+struct MochaTest {
+    #[serde(rename = "fullTitle")]
+    full_title: String,
+    duration: Option<u64>, // milliseconds
+    /// Error details; an empty object for passing tests.
+    #[serde(default)]
+    err: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parse output from `mocha --reporter json`.
+pub(crate) fn parse_mocha_json(stdout: &str, total_time: Duration) -> TestRunResult {
+    // Test code may log to stdout before the report
+    let output: MochaOutput =
+        match find_json_object(stdout).and_then(|s| serde_json::from_str(s).ok()) {
+            Some(o) => o,
+            None => return TestRunResult::failed(total_time, "mocha failed (no JSON output)"),
+        };
+
+    let pending: HashSet<&str> = output.pending.iter().map(|t| t.full_title.as_str()).collect();
+
+    let tests = output
+        .tests
+        .iter()
+        .map(|test| {
+            let duration = test.duration.map(Duration::from_millis).unwrap_or(Duration::ZERO);
+            if pending.contains(test.full_title.as_str()) {
+                TestResult::skipped(&test.full_title)
+            } else if test.err.is_empty() {
+                TestResult::passed(&test.full_title, duration)
+            } else {
+                TestResult::failed(&test.full_title, duration)
+            }
+        })
+        .collect();
+
+    let mut result = if output.stats.failures == 0 {
+        TestRunResult::passed(total_time)
+    } else {
+        TestRunResult::failed(total_time, "tests failed")
+    };
+    result.tests = tests;
+    result
+}
+
+#[cfg(test)]
+#[path = "mocha_tests.rs"]
+mod tests;
@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::time::Duration;
+
+use super::*;
+
+#[test]
+fn parses_passing_tests() {
+    let output = r#"{
+        "stats": {"suites": 1, "tests": 2, "passes": 2, "pending": 0, "failures": 0, "duration": 12},
+        "tests": [
+            {"title": "adds", "fullTitle": "math adds", "file": "/app/test/math.js", "duration": 3, "err": {}},
+            {"title": "subtracts", "fullTitle": "math subtracts", "file": "/app/test/math.js", "duration": 7, "err": {}}
+        ],
+        "pending": [],
+        "failures": [],
+        "passes": []
+    }"#;
+
+    let result = parse_mocha_json(output, Duration::from_secs(1));
+
+    assert!(result.passed);
+    assert_eq!(result.tests.len(), 2);
+    assert_eq!(result.tests[1].name, "math subtracts");
+    assert_eq!(result.tests[1].duration, Duration::from_millis(7));
+}
+
+#[test]
+fn parses_failing_tests() {
+    let output = r#"{
+        "stats": {"tests": 2, "passes": 1, "failures": 1},
+        "tests": [
+            {"fullTitle": "math adds", "duration": 3, "err": {}},
+            {"fullTitle": "math divides", "duration": 4, "err": {"message": "expected 2 to equal 3"}}
+        ]
+    }"#;
+
+    let result = parse_mocha_json(output, Duration::from_secs(1));
+
+    assert!(!result.passed);
+    assert_eq!(result.failed_count(), 1);
+    assert!(!result.tests[1].passed);
+}
+
+#[test]
+fn pending_tests_are_skipped() {
+    let output = r#"{
+        "stats": {"tests": 2, "passes": 1, "pending": 1, "failures": 0},
+        "tests": [
+            {"fullTitle": "math adds", "duration": 3, "err": {}},
+            {"fullTitle": "math todo", "err": {}}
+        ],
+        "pending": [{"fullTitle": "math todo", "err": {}}]
+    }"#;
+
+    let result = parse_mocha_json(output, Duration::from_secs(1));
+
+    assert!(result.passed);
+    assert_eq!(result.skipped_count(), 1);
+}
+
+#[test]
+fn ignores_console_output_before_report() {
+    let output = "connecting to db\n{\"stats\": {\"failures\": 0}, \"tests\": []}";
+
+    assert!(parse_mocha_json(output, Duration::from_secs(1)).passed);
+}
+
+#[test]
+fn missing_report_fails() {
+    let result = parse_mocha_json("Error: No test files found", Duration::from_secs(1));
+
+    assert!(!result.passed);
+    assert_eq!(result.error.as_deref(), Some("mocha failed (no JSON output)"));
+}
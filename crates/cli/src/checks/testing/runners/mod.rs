@@ -9,26 +9,46 @@ mod bats;
 mod bun;
 mod cargo;
 mod coverage;
+mod cpp_coverage;
+mod ctest;
 mod cucumber;
 mod custom;
+mod deno;
+mod dotnet;
+mod elixir_coverage;
 mod go;
 mod go_coverage;
 mod go_detect;
+mod gradle;
 mod instrumented;
 mod jest;
 mod js_coverage;
 mod js_detect;
 mod json_utils;
+mod junit;
+mod jvm_build;
+mod jvm_coverage;
 mod kcov;
+mod maven;
 mod minitest;
+mod mix;
+mod mocha;
+mod nox;
+mod php_coverage;
+mod phpunit;
+mod playwright;
 mod py_detect;
+mod py_envs;
 mod pytest;
 mod python_coverage;
 mod result;
 mod rspec;
 mod ruby_coverage;
 mod rust_detect;
+mod swift;
+mod swift_coverage;
 mod targets;
+mod tox;
 mod unittest;
 mod vitest;
 
@@ -36,44 +56,62 @@ pub use bats::BatsRunner;
 pub use bun::BunRunner;
 pub use cargo::{CargoRunner, categorize_cargo_error, parse_cargo_output};
 pub use coverage::CoverageResult;
+pub use ctest::CtestRunner;
 pub use cucumber::CucumberRunner;
 pub use custom::CustomRunner;
+pub use deno::DenoRunner;
+pub use dotnet::DotnetRunner;
 pub use go::GoRunner;
-pub use go_coverage::{collect_go_coverage, go_available};
+pub use go_coverage::{collect_go_coverage, extract_go_package, go_available};
 pub use go_detect::{
     GoDetectionResult, GoDetectionSource, GoRunner as GoDetectRunner, detect_go_runner,
 };
+pub use gradle::GradleRunner;
 pub use instrumented::{
     InstrumentedBuild, build_instrumented, collect_instrumented_coverage, coverage_env,
 };
 pub use jest::JestRunner;
-pub use js_coverage::{collect_bun_coverage, collect_jest_coverage, collect_vitest_coverage};
+pub use js_coverage::{
+    collect_bun_coverage, collect_deno_coverage, collect_jest_coverage, collect_vitest_coverage,
+    extract_js_package,
+};
 pub use js_detect::{DetectionResult, DetectionSource, JsRunner, detect_js_runner};
 pub use kcov::{collect_shell_coverage, kcov_available};
+pub use maven::MavenRunner;
 pub use minitest::MinitestRunner;
+pub use mix::MixRunner;
+pub use mocha::MochaRunner;
+pub use nox::NoxRunner;
+pub use phpunit::{PestRunner, PhpunitRunner};
+pub use playwright::PlaywrightRunner;
 pub use py_detect::{PyDetectionResult, PyDetectionSource, PyRunner, detect_py_runner};
 pub use pytest::PytestRunner;
-pub use python_coverage::collect_python_coverage;
-pub use result::{TestResult, TestRunResult};
+pub use python_coverage::{collect_python_coverage, extract_python_package};
+pub use result::{EnvRunResult, TestResult, TestRunResult};
 pub use rspec::RspecRunner;
 pub use ruby_coverage::collect_ruby_coverage;
 pub use rust_detect::{RustDetectionResult, RustDetectionSource, RustRunner, detect_rust_runner};
+pub use swift::SwiftRunner;
 pub use targets::{
     ResolvedTarget, TargetResolutionError, is_glob_pattern, resolve_target, resolve_targets,
     rust_binary_names, shell_script_files,
 };
+pub use tox::ToxRunner;
 pub use unittest::UnittestRunner;
 pub use vitest::VitestRunner;
 
 use std::collections::HashMap;
 use std::io::{self, Read};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::{Child, Command, Output, Stdio};
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant, SystemTime};
 
 use crate::config::TestSuiteConfig;
 
+use super::impact::ChangeSet;
+
 // =============================================================================
 // Runner Helper Macros
 // =============================================================================
@@ -102,8 +140,31 @@ pub use run_setup_or_fail;
 
 /// List of known runner names.
 pub const RUNNER_NAMES: &[&str] = &[
-    "cargo", "go", "pytest", "unittest", "vitest", "bun", "jest", "bats", "rspec", "minitest",
-    "cucumber", "custom",
+    "cargo",
+    "go",
+    "pytest",
+    "unittest",
+    "tox",
+    "nox",
+    "gradle",
+    "maven",
+    "ctest",
+    "dotnet",
+    "phpunit",
+    "pest",
+    "swift",
+    "mix",
+    "vitest",
+    "bun",
+    "jest",
+    "deno",
+    "mocha",
+    "playwright",
+    "bats",
+    "rspec",
+    "minitest",
+    "cucumber",
+    "custom",
 ];
 
 /// Context passed to test runners during execution.
@@ -118,6 +179,8 @@ pub struct RunnerContext<'a> {
     pub config: &'a crate::config::Config,
     /// Whether verbose diagnostic output is enabled.
     pub verbose: bool,
+    /// Changed files when only affected suites should run (`select = "affected"`).
+    pub changes: Option<ChangeSet<'a>>,
 }
 
 /// Trait for pluggable test runners.
@@ -144,9 +207,22 @@ pub fn all_runners() -> Vec<Arc<dyn TestRunner>> {
         Arc::new(GoRunner),
         Arc::new(PytestRunner),
         Arc::new(UnittestRunner),
+        Arc::new(ToxRunner),
+        Arc::new(NoxRunner),
+        Arc::new(GradleRunner),
+        Arc::new(MavenRunner),
+        Arc::new(CtestRunner),
+        Arc::new(DotnetRunner),
+        Arc::new(PhpunitRunner),
+        Arc::new(PestRunner),
+        Arc::new(SwiftRunner),
+        Arc::new(MixRunner),
         Arc::new(VitestRunner),
         Arc::new(BunRunner),
         Arc::new(JestRunner),
+        Arc::new(DenoRunner),
+        Arc::new(MochaRunner),
+        Arc::new(PlaywrightRunner),
         Arc::new(RspecRunner),
         Arc::new(MinitestRunner),
         Arc::new(CucumberRunner),
@@ -365,6 +441,41 @@ pub fn merge_coverage_results(a: CoverageResult, b: CoverageResult) -> CoverageR
     }
 }
 
+// =============================================================================
+// Tools and Report Files
+// =============================================================================
+
+/// Distinguishes scratch files of suites running concurrently.
+static SCRATCH_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Whether a command-line tool is installed.
+pub(crate) fn tool_available(tool: &str) -> bool {
+    Command::new(tool)
+        .arg("--version")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|s| s.success())
+}
+
+/// Unique temp path (`quench-<prefix>-<pid>-<n>`) for a run's report files.
+pub(crate) fn scratch_path(prefix: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "quench-{prefix}-{}-{}",
+        std::process::id(),
+        SCRATCH_COUNTER.fetch_add(1, Ordering::SeqCst)
+    ))
+}
+
+/// Cutoff for reports written by a run starting now.
+///
+/// Reports modified before it are left from earlier runs. Filesystem
+/// timestamps are coarser than the system clock, so it allows a second of slack.
+pub(crate) fn report_cutoff() -> SystemTime {
+    let now = SystemTime::now();
+    now.checked_sub(Duration::from_secs(1)).unwrap_or(now)
+}
+
 // =============================================================================
 // Timeout Support
 // =============================================================================
@@ -376,8 +487,18 @@ pub fn format_timeout_error(runner: &str, timeout: Duration) -> String {
         "cargo" => "check for infinite loops or deadlocks",
         "bats" => "check for infinite loops in shell scripts",
         "pytest" => "check for slow tests or missing mocks",
+        "tox" | "nox" => "check for slow environment installs or hanging tests",
         "go" => "check for goroutine leaks or infinite loops",
-        "jest" | "vitest" | "bun" => "check for unresolved promises or infinite loops",
+        "gradle" | "maven" => "check for hanging tests or slow dependency downloads",
+        "ctest" => "check for deadlocks or set per-test TIMEOUT properties",
+        "dotnet" => "check for hanging tests or set --blame-hang-timeout",
+        "phpunit" | "pest" => "check for slow database or HTTP calls, or missing mocks",
+        "swift" => "check for unfulfilled XCTest expectations or deadlocked actors",
+        "mix" => "check for unawaited tasks, missing assert_receive timeouts, or sandbox checkouts",
+        "jest" | "vitest" | "bun" | "deno" | "mocha" => {
+            "check for unresolved promises or infinite loops"
+        }
+        "playwright" => "check for unresolved locators or a dev server that never started",
         "rspec" | "minitest" | "cucumber" => "check for slow database queries or missing mocks",
         _ => "check for slow or hanging tests",
     };
@@ -7,8 +7,10 @@ use tempfile::tempdir;
 #[test]
 fn all_runners_returns_expected_count() {
     let runners = all_runners();
-    // cargo, bats, go, pytest, unittest, vitest, bun, jest, rspec, minitest, cucumber, custom = 12 runners
-    assert_eq!(runners.len(), 12);
+    // cargo, bats, go, pytest, unittest, tox, nox, gradle, maven, ctest, dotnet, phpunit,
+    // pest, swift, mix, vitest, bun, jest, deno, mocha, playwright, rspec, minitest,
+    // cucumber, custom = 25 runners
+    assert_eq!(runners.len(), 25);
 }
 
 #[test]
@@ -32,6 +34,78 @@ fn get_runner_finds_unittest() {
     assert_eq!(runner.unwrap().name(), "unittest");
 }
 
+#[test]
+fn get_runner_finds_js_runners() {
+    for name in ["deno", "mocha", "playwright"] {
+        let runner = get_runner(name);
+        assert!(runner.is_some(), "{name} not registered");
+        assert_eq!(runner.unwrap().name(), name);
+        assert!(RUNNER_NAMES.contains(&name));
+    }
+}
+
+#[test]
+fn get_runner_finds_python_env_runners() {
+    for name in ["tox", "nox"] {
+        let runner = get_runner(name);
+        assert!(runner.is_some(), "{name} not registered");
+        assert_eq!(runner.unwrap().name(), name);
+        assert!(RUNNER_NAMES.contains(&name));
+    }
+}
+
+#[test]
+fn get_runner_finds_jvm_runners() {
+    for name in ["gradle", "maven"] {
+        let runner = get_runner(name);
+        assert!(runner.is_some(), "{name} not registered");
+        assert_eq!(runner.unwrap().name(), name);
+        assert!(RUNNER_NAMES.contains(&name));
+    }
+}
+
+#[test]
+fn get_runner_finds_ctest() {
+    let runner = get_runner("ctest");
+    assert!(runner.is_some(), "ctest not registered");
+    assert_eq!(runner.unwrap().name(), "ctest");
+    assert!(RUNNER_NAMES.contains(&"ctest"));
+}
+
+#[test]
+fn get_runner_finds_phpunit_and_pest() {
+    for name in ["phpunit", "pest"] {
+        let runner = get_runner(name);
+        assert!(runner.is_some(), "{name} not registered");
+        assert_eq!(runner.unwrap().name(), name);
+        assert!(RUNNER_NAMES.contains(&name));
+    }
+}
+
+#[test]
+fn get_runner_finds_swift() {
+    let runner = get_runner("swift");
+    assert!(runner.is_some(), "swift not registered");
+    assert_eq!(runner.unwrap().name(), "swift");
+    assert!(RUNNER_NAMES.contains(&"swift"));
+}
+
+#[test]
+fn get_runner_finds_mix() {
+    let runner = get_runner("mix");
+    assert!(runner.is_some(), "mix not registered");
+    assert_eq!(runner.unwrap().name(), "mix");
+    assert!(RUNNER_NAMES.contains(&"mix"));
+}
+
+#[test]
+fn get_runner_finds_dotnet() {
+    let runner = get_runner("dotnet");
+    assert!(runner.is_some(), "dotnet not registered");
+    assert_eq!(runner.unwrap().name(), "dotnet");
+    assert!(RUNNER_NAMES.contains(&"dotnet"));
+}
+
 #[test]
 fn get_runner_returns_none_for_unknown() {
     assert!(get_runner("unknown_runner").is_none());
@@ -52,6 +126,8 @@ fn filter_suites_ci_mode_includes_all() {
             max_avg: None,
             max_test: None,
             timeout: None,
+            exclusive: false,
+            envs: vec![],
         },
         TestSuiteConfig {
             runner: "pytest".to_string(),
@@ -65,6 +141,8 @@ fn filter_suites_ci_mode_includes_all() {
             max_avg: None,
             max_test: None,
             timeout: None,
+            exclusive: false,
+            envs: vec![],
         },
     ];
 
@@ -88,6 +166,8 @@ fn filter_suites_fast_mode_excludes_ci_only() {
             max_avg: None,
             max_test: None,
             timeout: None,
+            exclusive: false,
+            envs: vec![],
         },
         TestSuiteConfig {
             runner: "pytest".to_string(),
@@ -101,6 +181,8 @@ fn filter_suites_fast_mode_excludes_ci_only() {
             max_avg: None,
             max_test: None,
             timeout: None,
+            exclusive: false,
+            envs: vec![],
         },
     ];
 
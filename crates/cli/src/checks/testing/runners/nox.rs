@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Nox test runner.
+//!
+//! Runs each nox session (`nox -s <session> -- <pytest args>`) and parses
+//! its pytest JUnit report. Sessions default to those `nox --list` selects.
+
+use std::process::Command;
+
+use super::py_envs::{list_output, run_envs};
+use super::{RunnerContext, TestRunResult, TestRunner, run_setup_or_fail, tool_available};
+use crate::config::TestSuiteConfig;
+
+/// Nox runner for multi-interpreter Python test suites.
+pub struct NoxRunner;
+
+impl TestRunner for NoxRunner {
+    fn name(&self) -> &'static str {
+        "nox"
+    }
+
+    fn available(&self, _ctx: &RunnerContext) -> bool {
+        tool_available("nox")
+    }
+
+    fn run(&self, config: &TestSuiteConfig, ctx: &RunnerContext) -> TestRunResult {
+        run_setup_or_fail!(config, ctx);
+
+        let sessions = if config.envs.is_empty() {
+            let mut cmd = Command::new("nox");
+            cmd.arg("--list");
+            list_output(cmd, ctx.root).map(|out| parse_nox_sessions(&out)).unwrap_or_default()
+        } else {
+            config.envs.clone()
+        };
+
+        run_envs("nox", &sessions, config, ctx, |session| {
+            let mut cmd = Command::new("nox");
+            cmd.args(["-s", session]);
+            cmd
+        })
+    }
+}
+
+/// Parse selected sessions from `nox --list` output.
+///
+/// ```text
+/// Sessions defined in /app/noxfile.py:
+///
+/// * tests-3.12 -> Run the test suite.
+/// * tests-3.13 -> Run the test suite.
+/// - lint -> Run linters.
+/// ```
+///
+/// Sessions marked `*` run by default; `-` marks deselected sessions.
+pub(crate) fn parse_nox_sessions(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(|l| l.trim().strip_prefix("* "))
+        .filter_map(|l| l.split(" -> ").next())
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "nox_tests.rs"]
+mod tests;
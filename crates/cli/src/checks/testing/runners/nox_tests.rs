@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+#[test]
+fn parses_selected_sessions() {
+    let output = "Sessions defined in /app/noxfile.py:\n\n\
+                  * tests-3.12 -> Run the test suite.\n\
+                  * tests-3.13 -> Run the test suite.\n\
+                  - lint -> Run linters.\n\n\
+                  sessions marked with * are selected, sessions marked with - are skipped.\n";
+
+    assert_eq!(parse_nox_sessions(output), ["tests-3.12", "tests-3.13"]);
+}
+
+#[test]
+fn parses_sessions_without_descriptions() {
+    let output = "* tests(python='3.11')\n* tests(python='3.12')\n";
+
+    assert_eq!(parse_nox_sessions(output), ["tests(python='3.11')", "tests(python='3.12')"]);
+}
+
+#[test]
+fn no_sessions_when_output_is_empty() {
+    assert!(parse_nox_sessions("").is_empty());
+}
@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Clover XML coverage parsing for PHP projects.
+//!
+//! PHPUnit and Pest write Clover reports with `--coverage-clover <file>`:
+//!
+//! ```xml
+//! <coverage generated="1767225600">
+//!   <project timestamp="1767225600">
+//!     <package name="App\Http">
+//!       <file name="/work/app/src/Http/Kernel.php">
+//!         <line num="12" type="method" name="handle" count="3"/>
+//!         <line num="14" type="stmt" count="3"/>
+//!         <line num="15" type="stmt" count="0"/>
+//!       </file>
+//!     </package>
+//!   </project>
+//! </coverage>
+//! ```
+//!
+//! Only `stmt` lines count toward line coverage; `method` lines duplicate the
+//! first statement of each method.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Duration;
+
+use super::CoverageResult;
+use super::junit::junit_attr;
+
+/// Parse a Clover report, reporting files relative to `root`.
+pub(crate) fn parse_clover_xml(xml: &str, root: &Path, duration: Duration) -> CoverageResult {
+    let mut result = CoverageResult {
+        success: true,
+        error: None,
+        duration,
+        line_coverage: None,
+        files: HashMap::new(),
+        packages: HashMap::new(),
+    };
+
+    let mut packages: HashMap<String, (usize, usize)> = HashMap::new();
+    let (mut total_hit, mut total_found) = (0, 0);
+
+    for (start, _) in xml.match_indices("<file ") {
+        let rest = &xml[start..];
+        let Some(tag_end) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[..tag_end];
+        let Some(name) = junit_attr(tag, "name") else {
+            continue;
+        };
+        let body = if tag.ends_with('/') {
+            ""
+        } else {
+            rest[tag_end..].find("</file>").map_or("", |end| &rest[tag_end..tag_end + end])
+        };
+
+        let (mut hit, mut found) = (0, 0);
+        for (line_start, _) in body.match_indices("<line ") {
+            let line = &body[line_start..];
+            let line = &line[..line.find('>').unwrap_or(line.len())];
+            if junit_attr(line, "type").as_deref() != Some("stmt") {
+                continue;
+            }
+            found += 1;
+            if junit_attr(line, "count").and_then(|c| c.parse::<u64>().ok()).is_some_and(|c| c > 0)
+            {
+                hit += 1;
+            }
+        }
+        if found == 0 {
+            continue;
+        }
+
+        let path = relative_path(&name, root);
+        result.files.insert(path.clone(), hit as f64 / found as f64 * 100.0);
+
+        let entry = packages.entry(extract_php_package(&path)).or_default();
+        entry.0 += hit;
+        entry.1 += found;
+        total_hit += hit;
+        total_found += found;
+    }
+
+    result.packages = packages
+        .into_iter()
+        .map(|(pkg, (hit, found))| (pkg, hit as f64 / found as f64 * 100.0))
+        .collect();
+    if total_found > 0 {
+        result.line_coverage = Some(total_hit as f64 / total_found as f64 * 100.0);
+    }
+    result
+}
+
+/// Make a Clover file path relative to the root when it lies inside it.
+fn relative_path(path: &str, root: &Path) -> String {
+    let relative = Path::new(path).strip_prefix(root).unwrap_or(Path::new(path));
+    relative.to_string_lossy().replace('\\', "/")
+}
+
+/// Package of a PHP source: the first directory under `src/` or `app/`,
+/// otherwise the first directory, or `root`.
+///
+/// `src/Http/Kernel.php` -> `Http`, `lib/Cache.php` -> `lib`.
+pub(crate) fn extract_php_package(path: &str) -> String {
+    let inner = path.strip_prefix("src/").or_else(|| path.strip_prefix("app/"));
+    match inner.unwrap_or(path).split_once('/') {
+        Some((dir, _)) => dir.to_string(),
+        None if inner.is_some() => path[..path.find('/').unwrap_or(0)].to_string(),
+        None => "root".to_string(),
+    }
+}
+
+#[cfg(test)]
+#[path = "php_coverage_tests.rs"]
+mod tests;
@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::path::Path;
+use std::time::Duration;
+
+use super::*;
+
+const CLOVER: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<coverage generated="1767225600">
+  <project timestamp="1767225600">
+    <package name="App\Http">
+      <file name="/work/app/src/Http/Kernel.php">
+        <class name="App\Http\Kernel" namespace="App\Http">
+          <metrics methods="1" coveredmethods="1" statements="4" coveredstatements="3"/>
+        </class>
+        <line num="12" type="method" name="handle" visibility="public" complexity="1" crap="1" count="3"/>
+        <line num="14" type="stmt" count="3"/>
+        <line num="15" type="stmt" count="3"/>
+        <line num="16" type="stmt" count="1"/>
+        <line num="18" type="stmt" count="0"/>
+      </file>
+    </package>
+    <file name="/work/app/src/helpers.php">
+      <line num="3" type="stmt" count="1"/>
+      <line num="4" type="stmt" count="0"/>
+    </file>
+    <file name="/work/app/config/app.php">
+      <metrics loc="10" ncloc="10" statements="0"/>
+    </file>
+    <metrics files="3" loc="40" ncloc="30" statements="6" coveredstatements="4"/>
+  </project>
+</coverage>"#;
+
+#[test]
+fn parses_statement_coverage_per_file() {
+    let result = parse_clover_xml(CLOVER, Path::new("/work/app"), Duration::ZERO);
+
+    assert!(result.success);
+    assert_eq!(result.files.get("src/Http/Kernel.php"), Some(&75.0));
+    assert_eq!(result.files.get("src/helpers.php"), Some(&50.0));
+    assert_eq!(result.files.len(), 2, "files without statements are skipped");
+    assert!((result.line_coverage.unwrap() - 66.666).abs() < 0.01);
+}
+
+#[test]
+fn groups_files_into_packages() {
+    let result = parse_clover_xml(CLOVER, Path::new("/work/app"), Duration::ZERO);
+
+    assert_eq!(result.packages.get("Http"), Some(&75.0));
+    assert_eq!(result.packages.get("src"), Some(&50.0));
+}
+
+#[test]
+fn empty_report_has_no_coverage() {
+    let result =
+        parse_clover_xml("<coverage><project/></coverage>", Path::new("/"), Duration::ZERO);
+
+    assert!(result.success);
+    assert_eq!(result.line_coverage, None);
+}
+
+#[test]
+fn package_names() {
+    assert_eq!(extract_php_package("src/Http/Kernel.php"), "Http");
+    assert_eq!(extract_php_package("app/Models/User.php"), "Models");
+    assert_eq!(extract_php_package("src/helpers.php"), "src");
+    assert_eq!(extract_php_package("lib/Cache.php"), "lib");
+    assert_eq!(extract_php_package("index.php"), "root");
+}
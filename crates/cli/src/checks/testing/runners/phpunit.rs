@@ -0,0 +1,205 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! PHPUnit and Pest runners for PHP projects.
+//!
+//! Both tools share PHPUnit's command line: the runner invokes
+//! `<tool> [path] --log-junit <report>` and parses the JUnit XML report.
+//! With coverage enabled, `--coverage-clover <file>` is added and the Clover
+//! report is parsed for line coverage. The Composer-installed binary
+//! (`vendor/bin/<tool>`) is preferred over one on `PATH`.
+
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use super::junit::parse_junit_xml;
+use super::php_coverage::parse_clover_xml;
+use super::{
+    CoverageResult, RunnerContext, TestRunResult, TestRunner, handle_timeout_error,
+    run_setup_or_fail, run_with_timeout, scratch_path, tool_available,
+};
+use crate::config::TestSuiteConfig;
+
+/// PHPUnit runner.
+pub struct PhpunitRunner;
+
+/// Pest runner (PHPUnit-compatible command line).
+pub struct PestRunner;
+
+impl TestRunner for PhpunitRunner {
+    fn name(&self) -> &'static str {
+        "phpunit"
+    }
+
+    fn available(&self, ctx: &RunnerContext) -> bool {
+        php_tool_available("phpunit", ctx.root)
+    }
+
+    fn run(&self, config: &TestSuiteConfig, ctx: &RunnerContext) -> TestRunResult {
+        run_php_suite("phpunit", config, ctx)
+    }
+}
+
+impl TestRunner for PestRunner {
+    fn name(&self) -> &'static str {
+        "pest"
+    }
+
+    fn available(&self, ctx: &RunnerContext) -> bool {
+        php_tool_available("pest", ctx.root)
+    }
+
+    fn run(&self, config: &TestSuiteConfig, ctx: &RunnerContext) -> TestRunResult {
+        run_php_suite("pest", config, ctx)
+    }
+}
+
+/// Whether the tool is installed by Composer or on `PATH`.
+fn php_tool_available(tool: &str, root: &Path) -> bool {
+    vendor_bin(tool, root).is_some() || tool_available(tool)
+}
+
+/// `vendor/bin/<tool>` if Composer installed it.
+fn vendor_bin(tool: &str, root: &Path) -> Option<PathBuf> {
+    let path = root.join("vendor/bin").join(tool);
+    path.is_file().then_some(path)
+}
+
+fn run_php_suite(tool: &str, config: &TestSuiteConfig, ctx: &RunnerContext) -> TestRunResult {
+    run_setup_or_fail!(config, ctx);
+
+    let scratch = scratch_path(tool);
+    let report = scratch.with_extension("xml");
+    let clover = ctx.collect_coverage.then(|| {
+        let stem = scratch.file_name().map(|s| s.to_string_lossy()).unwrap_or_default();
+        scratch.with_file_name(format!("{stem}-clover.xml"))
+    });
+    let cleanup = || {
+        std::fs::remove_file(&report).ok();
+        if let Some(clover) = &clover {
+            std::fs::remove_file(clover).ok();
+        }
+    };
+
+    let start = Instant::now();
+
+    let program = vendor_bin(tool, ctx.root).unwrap_or_else(|| PathBuf::from(tool));
+    let mut cmd = php_command(&program, config.path.as_deref(), &report, clover.as_deref());
+    cmd.current_dir(ctx.root);
+    cmd.stdout(Stdio::piped());
+    cmd.stderr(Stdio::piped());
+
+    let child = match cmd.spawn() {
+        Ok(c) => c,
+        Err(e) => {
+            return TestRunResult::failed(start.elapsed(), format!("failed to spawn {tool}: {e}"));
+        }
+    };
+
+    let output = match run_with_timeout(child, config.timeout) {
+        Ok(out) => out,
+        Err(e) if e.kind() == ErrorKind::TimedOut => {
+            cleanup();
+            return handle_timeout_error(start.elapsed(), config.timeout, tool);
+        }
+        Err(e) => {
+            cleanup();
+            return TestRunResult::failed(start.elapsed(), format!("failed to run {tool}: {e}"));
+        }
+    };
+
+    let total_time = start.elapsed();
+    let xml = std::fs::read_to_string(&report).unwrap_or_default();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let mut result =
+        parse_php_report(tool, &xml, &stdout, &stderr, output.status.success(), total_time);
+
+    if let Some(clover) = &clover {
+        let coverage = match std::fs::read_to_string(clover) {
+            Ok(xml) => {
+                let root = ctx.root.canonicalize().unwrap_or_else(|_| ctx.root.to_path_buf());
+                parse_clover_xml(&xml, &root, total_time)
+            }
+            Err(_) => CoverageResult::skipped(),
+        };
+        result = result.with_collected_coverage(coverage, "php");
+    }
+
+    cleanup();
+    result
+}
+
+/// Build the PHPUnit/Pest command.
+///
+/// `path` selects a test directory or file; without it the tool runs the
+/// suites from `phpunit.xml(.dist)`. Coverage needs a driver: `XDEBUG_MODE`
+/// enables Xdebug's coverage mode and is ignored by PCOV.
+pub(crate) fn php_command(
+    program: &Path,
+    path: Option<&str>,
+    report: &Path,
+    clover: Option<&Path>,
+) -> Command {
+    let mut cmd = Command::new(program);
+    if let Some(path) = path {
+        cmd.arg(path);
+    }
+    cmd.arg("--log-junit").arg(report);
+    if let Some(clover) = clover {
+        cmd.arg("--coverage-clover").arg(clover);
+        cmd.env("XDEBUG_MODE", "coverage");
+    }
+    cmd
+}
+
+/// Build a result from the JUnit report and tool output.
+pub(crate) fn parse_php_report(
+    tool: &str,
+    xml: &str,
+    stdout: &str,
+    stderr: &str,
+    success: bool,
+    total_time: Duration,
+) -> TestRunResult {
+    let tests = parse_junit_xml(xml);
+
+    if success {
+        return TestRunResult::passed(total_time).with_tests(tests);
+    }
+
+    let error = if tests.iter().any(|t| !t.passed) {
+        "tests failed".to_string()
+    } else {
+        php_error(stdout, stderr).unwrap_or_else(|| format!("{tool} failed"))
+    };
+    let mut result = TestRunResult::failed(total_time, error);
+    result.tests = tests;
+    result
+}
+
+/// Extract the first PHP or configuration error from the tool output.
+///
+/// Covers PHP errors (`PHP Fatal error:  Uncaught Error: ...`), missing
+/// configuration (`Could not read "phpunit.xml".`) and unknown options.
+pub(crate) fn php_error(stdout: &str, stderr: &str) -> Option<String> {
+    stderr
+        .lines()
+        .chain(stdout.lines())
+        .map(str::trim)
+        .find(|l| {
+            l.contains("Fatal error")
+                || l.contains("Parse error")
+                || l.starts_with("Could not")
+                || l.starts_with("Cannot open")
+                || l.contains("Unknown option")
+        })
+        .map(String::from)
+}
+
+#[cfg(test)]
+#[path = "phpunit_tests.rs"]
+mod tests;
@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::path::Path;
+use std::time::Duration;
+
+use super::*;
+
+const JUNIT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites>
+  <testsuite name="Unit" tests="3" assertions="3" errors="0" failures="1" skipped="1" time="0.012">
+    <testsuite name="Tests\Unit\KernelTest" file="tests/Unit/KernelTest.php" tests="3">
+      <testcase name="testHandles" file="tests/Unit/KernelTest.php" line="12" class="Tests\Unit\KernelTest" classname="Tests.Unit.KernelTest" assertions="1" time="0.004"/>
+      <testcase name="testRejects with data set #0" file="tests/Unit/KernelTest.php" line="20" class="Tests\Unit\KernelTest" classname="Tests.Unit.KernelTest" assertions="1" time="0.008">
+        <failure type="PHPUnit\Framework\ExpectationFailedException">Failed asserting that false is true.</failure>
+      </testcase>
+      <testcase name="testNetwork" file="tests/Unit/KernelTest.php" line="30" class="Tests\Unit\KernelTest" classname="Tests.Unit.KernelTest" assertions="0" time="0.000">
+        <skipped/>
+      </testcase>
+    </testsuite>
+  </testsuite>
+</testsuites>"#;
+
+#[test]
+fn command_logs_junit() {
+    let cmd = php_command(Path::new("vendor/bin/phpunit"), None, Path::new("/tmp/r.xml"), None);
+    let args: Vec<_> = cmd.get_args().map(|a| a.to_string_lossy().into_owned()).collect();
+
+    assert_eq!(cmd.get_program(), "vendor/bin/phpunit");
+    assert_eq!(args, ["--log-junit", "/tmp/r.xml"]);
+}
+
+#[test]
+fn command_with_path_and_coverage() {
+    let cmd = php_command(
+        Path::new("pest"),
+        Some("tests/Feature"),
+        Path::new("/tmp/r.xml"),
+        Some(Path::new("/tmp/clover.xml")),
+    );
+    let args: Vec<_> = cmd.get_args().map(|a| a.to_string_lossy().into_owned()).collect();
+
+    assert_eq!(
+        args,
+        ["tests/Feature", "--log-junit", "/tmp/r.xml", "--coverage-clover", "/tmp/clover.xml"]
+    );
+    let xdebug = cmd.get_envs().find(|(k, _)| *k == "XDEBUG_MODE").and_then(|(_, v)| v);
+    assert_eq!(xdebug.map(|v| v.to_string_lossy().into_owned()), Some("coverage".to_string()));
+}
+
+#[test]
+fn parses_junit_report() {
+    let result = parse_php_report("phpunit", JUNIT, "", "", false, Duration::from_secs(1));
+
+    assert!(!result.passed);
+    assert_eq!(result.error.as_deref(), Some("tests failed"));
+    assert_eq!(result.tests.len(), 3);
+    assert_eq!(result.tests[0].name, "Tests.Unit.KernelTest::testHandles");
+    assert!(result.tests[0].passed);
+    assert!(!result.tests[1].passed);
+    assert!(result.tests[2].skipped);
+}
+
+#[test]
+fn passing_run() {
+    let xml = r#"<testsuites><testsuite name="Tests\Feature\HomeTest">
+<testcase name="it renders the home page" file="tests/Feature/HomeTest.php" time="0.010"/>
+</testsuite></testsuites>"#;
+    let result = parse_php_report("pest", xml, "", "", true, Duration::from_secs(1));
+
+    assert!(result.passed);
+    assert_eq!(result.tests.len(), 1);
+    assert_eq!(result.tests[0].name, "it renders the home page");
+}
+
+#[test]
+fn reports_fatal_error_without_tests() {
+    let stderr = "PHP Fatal error:  Uncaught Error: Class \"App\\Kernel\" not found in /work/tests/bootstrap.php:5\n";
+    let result = parse_php_report("phpunit", "", "", stderr, false, Duration::ZERO);
+
+    assert!(result.error.unwrap().starts_with("PHP Fatal error:"));
+}
+
+#[test]
+fn generic_error_names_tool() {
+    let result = parse_php_report("pest", "", "", "", false, Duration::ZERO);
+
+    assert_eq!(result.error.as_deref(), Some("pest failed"));
+}
+
+#[test]
+fn missing_configuration_error() {
+    assert_eq!(
+        php_error("Could not read \"phpunit.xml\".\n", ""),
+        Some("Could not read \"phpunit.xml\".".to_string())
+    );
+    assert_eq!(php_error("OK (3 tests, 3 assertions)", ""), None);
+}
@@ -0,0 +1,219 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Playwright test runner.
+//!
+//! Executes end-to-end tests using the detected package manager's exec
+//! command (e.g., `npx playwright test --reporter=json`).
+
+use std::io::ErrorKind;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+
+use super::json_utils::find_json_object;
+use super::{
+    RunnerContext, TestResult, TestRunResult, TestRunner, handle_timeout_error, run_setup_or_fail,
+    run_with_timeout,
+};
+use crate::adapter::javascript::PackageManager;
+use crate::config::TestSuiteConfig;
+
+/// Playwright runner for end-to-end test suites.
+pub struct PlaywrightRunner;
+
+impl TestRunner for PlaywrightRunner {
+    fn name(&self) -> &'static str {
+        "playwright"
+    }
+
+    fn available(&self, ctx: &RunnerContext) -> bool {
+        // Check if playwright is available via package manager's exec command
+        let pkg_mgr = PackageManager::detect(ctx.root);
+        let exec_cmd = pkg_mgr.exec_command();
+
+        let mut cmd = Command::new(&exec_cmd[0]);
+        cmd.args(&exec_cmd[1..]);
+        cmd.args(["playwright", "--version"]);
+        cmd.stdout(Stdio::null());
+        cmd.stderr(Stdio::null());
+
+        let playwright_installed = cmd.status().is_ok_and(|s| s.success());
+
+        // And project has package.json
+        playwright_installed && ctx.root.join("package.json").exists()
+    }
+
+    fn run(&self, config: &TestSuiteConfig, ctx: &RunnerContext) -> TestRunResult {
+        run_setup_or_fail!(config, ctx);
+
+        let start = Instant::now();
+
+        // Build command using detected package manager
+        let pkg_mgr = PackageManager::detect(ctx.root);
+        let exec_cmd = pkg_mgr.exec_command();
+
+        let mut cmd = Command::new(&exec_cmd[0]);
+        cmd.args(&exec_cmd[1..]);
+        cmd.args(["playwright", "test", "--reporter=json"]);
+
+        // Add test path if specified
+        if let Some(path) = &config.path {
+            cmd.arg(path);
+        }
+
+        cmd.current_dir(ctx.root);
+        cmd.stdout(Stdio::piped());
+        cmd.stderr(Stdio::piped());
+
+        let child = match cmd.spawn() {
+            Ok(c) => c,
+            Err(e) => {
+                return TestRunResult::failed(
+                    start.elapsed(),
+                    format!("failed to spawn playwright: {e}"),
+                );
+            }
+        };
+
+        let output = match run_with_timeout(child, config.timeout) {
+            Ok(out) => out,
+            Err(e) if e.kind() == ErrorKind::TimedOut => {
+                return handle_timeout_error(start.elapsed(), config.timeout, "playwright");
+            }
+            Err(e) => {
+                return TestRunResult::failed(
+                    start.elapsed(),
+                    format!("failed to run playwright: {e}"),
+                );
+            }
+        };
+
+        let total_time = start.elapsed();
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        // End-to-end tests exercise a running app, so no implicit coverage
+        parse_playwright_json(&stdout, total_time)
+    }
+}
+
+/// Playwright JSON reporter output.
+#[derive(Debug, Deserialize)]
+struct PlaywrightOutput {
+    #[serde(default)]
+    suites: Vec<PlaywrightSuite>,
+    /// Errors outside of tests (e.g., config or global setup failures).
+    #[serde(default)]
+    errors: Vec<serde_json::Value>,
+    stats: PlaywrightStats,
+}
+
+/// Summary counts from the Playwright JSON reporter.
+#[derive(Debug, Deserialize)]
+struct PlaywrightStats {
+    #[serde(default)]
+    unexpected: usize,
+}
+
+/// A file or `describe` block.
+#[derive(Debug, Deserialize)]
+struct PlaywrightSuite {
+    title: String,
+    #[serde(default)]
+    specs: Vec<PlaywrightSpec>,
+    #[serde(default)]
+    suites: Vec<PlaywrightSuite>,
+}
+
+/// A single `test()` declaration.
+#[derive(Debug, Deserialize)]
+struct PlaywrightSpec {
+    title: String,
+    /// One entry per project (browser) the spec ran in.
+    #[serde(default)]
+    tests: Vec<PlaywrightTest>,
+}
+
+/// A spec run in one project.
+#[derive(Debug, Deserialize)]
+struct PlaywrightTest {
+    #[serde(rename = "projectName", default)]
+    project_name: String,
+    /// "expected" | "unexpected" | "flaky" | "skipped"
+    status: String,
+    /// One entry per attempt (retries add attempts).
+    #[serde(default)]
+    results: Vec<PlaywrightAttempt>,
+}
+
+/// A single attempt of a test.
+#[derive(Debug, Deserialize)]
+struct PlaywrightAttempt {
+    #[serde(default)]
+    duration: f64, // milliseconds
+}
+
+/// Parse output from `playwright test --reporter=json`.
+pub(crate) fn parse_playwright_json(stdout: &str, total_time: Duration) -> TestRunResult {
+    let output: PlaywrightOutput =
+        match find_json_object(stdout).and_then(|s| serde_json::from_str(s).ok()) {
+            Some(o) => o,
+            None => return TestRunResult::failed(total_time, "playwright failed (no JSON output)"),
+        };
+
+    let mut tests = Vec::new();
+    for suite in &output.suites {
+        collect_suite_tests(suite, &[], &mut tests);
+    }
+
+    let mut result = if output.stats.unexpected == 0 && output.errors.is_empty() {
+        TestRunResult::passed(total_time)
+    } else {
+        TestRunResult::failed(total_time, "tests failed")
+    };
+    result.tests = tests;
+    result
+}
+
+/// Flatten nested suites into test results named `file > describe > test`.
+fn collect_suite_tests(suite: &PlaywrightSuite, parents: &[&str], tests: &mut Vec<TestResult>) {
+    let mut path = parents.to_vec();
+    if !suite.title.is_empty() {
+        path.push(&suite.title);
+    }
+
+    for spec in &suite.specs {
+        let mut name = path.clone();
+        name.push(&spec.title);
+        let name = name.join(" > ");
+
+        for test in &spec.tests {
+            let name = if test.project_name.is_empty() || spec.tests.len() == 1 {
+                name.clone()
+            } else {
+                format!("{} [{}]", name, test.project_name)
+            };
+            // The final attempt decides the outcome
+            let duration = test
+                .results
+                .last()
+                .map(|r| Duration::from_secs_f64(r.duration.max(0.0) / 1000.0))
+                .unwrap_or(Duration::ZERO);
+
+            tests.push(match test.status.as_str() {
+                "skipped" => TestResult::skipped(name),
+                "expected" | "flaky" => TestResult::passed(name, duration),
+                _ => TestResult::failed(name, duration),
+            });
+        }
+    }
+
+    for child in &suite.suites {
+        collect_suite_tests(child, &path, tests);
+    }
+}
+
+#[cfg(test)]
+#[path = "playwright_tests.rs"]
+mod tests;
@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::time::Duration;
+
+use super::*;
+
+#[test]
+fn parses_nested_suites() {
+    let output = r#"{
+        "config": {"rootDir": "/app/e2e"},
+        "suites": [{
+            "title": "login.spec.ts",
+            "file": "login.spec.ts",
+            "specs": [{
+                "title": "shows form",
+                "ok": true,
+                "tests": [{"projectName": "chromium", "status": "expected",
+                           "results": [{"status": "passed", "duration": 812}]}]
+            }],
+            "suites": [{
+                "title": "with bad password",
+                "specs": [{
+                    "title": "shows error",
+                    "ok": true,
+                    "tests": [{"projectName": "chromium", "status": "expected",
+                               "results": [{"status": "passed", "duration": 1500}]}]
+                }]
+            }]
+        }],
+        "errors": [],
+        "stats": {"expected": 2, "unexpected": 0, "flaky": 0, "skipped": 0, "duration": 2400.5}
+    }"#;
+
+    let result = parse_playwright_json(output, Duration::from_secs(3));
+
+    assert!(result.passed);
+    assert_eq!(result.tests.len(), 2);
+    assert_eq!(result.tests[0].name, "login.spec.ts > shows form");
+    assert_eq!(result.tests[1].name, "login.spec.ts > with bad password > shows error");
+    assert_eq!(result.tests[1].duration, Duration::from_millis(1500));
+}
+
+#[test]
+fn names_include_project_when_run_in_several() {
+    let output = r#"{
+        "suites": [{
+            "title": "home.spec.ts",
+            "specs": [{
+                "title": "loads",
+                "tests": [
+                    {"projectName": "chromium", "status": "expected", "results": [{"duration": 100}]},
+                    {"projectName": "firefox", "status": "unexpected", "results": [{"duration": 200}]}
+                ]
+            }]
+        }],
+        "stats": {"expected": 1, "unexpected": 1}
+    }"#;
+
+    let result = parse_playwright_json(output, Duration::from_secs(1));
+
+    assert!(!result.passed);
+    assert_eq!(result.tests[0].name, "home.spec.ts > loads [chromium]");
+    assert_eq!(result.tests[1].name, "home.spec.ts > loads [firefox]");
+    assert!(!result.tests[1].passed);
+}
+
+#[test]
+fn flaky_tests_pass_with_final_attempt_duration() {
+    let output = r#"{
+        "suites": [{
+            "title": "cart.spec.ts",
+            "specs": [{
+                "title": "adds item",
+                "tests": [{"status": "flaky",
+                           "results": [{"status": "failed", "duration": 900}, {"status": "passed", "duration": 300}]}]
+            }]
+        }],
+        "stats": {"expected": 0, "unexpected": 0, "flaky": 1}
+    }"#;
+
+    let result = parse_playwright_json(output, Duration::from_secs(1));
+
+    assert!(result.passed);
+    assert!(result.tests[0].passed);
+    assert_eq!(result.tests[0].duration, Duration::from_millis(300));
+}
+
+#[test]
+fn skipped_tests_are_reported() {
+    let output = r#"{
+        "suites": [{"title": "a.spec.ts", "specs": [{"title": "todo", "tests": [{"status": "skipped", "results": []}]}]}],
+        "stats": {"skipped": 1}
+    }"#;
+
+    let result = parse_playwright_json(output, Duration::from_secs(1));
+
+    assert!(result.passed);
+    assert_eq!(result.skipped_count(), 1);
+}
+
+#[test]
+fn global_errors_fail_run() {
+    let output = r#"{"suites": [], "errors": [{"message": "globalSetup failed"}], "stats": {"unexpected": 0}}"#;
+
+    let result = parse_playwright_json(output, Duration::from_secs(1));
+
+    assert!(!result.passed);
+}
+
+#[test]
+fn missing_report_fails() {
+    let result = parse_playwright_json("Error: No tests found", Duration::from_secs(1));
+
+    assert!(!result.passed);
+}
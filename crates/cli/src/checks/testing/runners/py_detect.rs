@@ -4,6 +4,7 @@
 //! Python test runner auto-detection.
 //!
 //! Detection priority (first match wins):
+//! 0. Multi-interpreter config (noxfile.py, tox.ini listing several envs)
 //! 1. Config files (pytest.ini, setup.cfg with [tool:pytest])
 //! 2. pyproject.toml [tool.pytest] section
 //! 3. conftest.py presence
@@ -18,6 +19,8 @@ use std::process::{Command, Stdio};
 pub enum PyRunner {
     Pytest,
     Unittest,
+    Tox,
+    Nox,
 }
 
 impl PyRunner {
@@ -26,6 +29,8 @@ impl PyRunner {
         match self {
             PyRunner::Pytest => "pytest",
             PyRunner::Unittest => "unittest",
+            PyRunner::Tox => "tox",
+            PyRunner::Nox => "nox",
         }
     }
 }
@@ -76,7 +81,12 @@ pub fn detect_py_runner(root: &Path) -> Option<PyDetectionResult> {
         return None;
     }
 
-    // 1. Check config files (highest priority)
+    // 0. Check for multi-interpreter test configs
+    if let Some(result) = detect_multi_env(root) {
+        return Some(result);
+    }
+
+    // 1. Check config files
     if let Some(result) = detect_from_config_files(root) {
         return Some(result);
     }
@@ -115,6 +125,50 @@ fn is_python_project(root: &Path) -> bool {
         || root.join("uv.lock").exists()
 }
 
+/// Detect tox or nox configured to test several environments.
+///
+/// A tox.ini with a single environment is treated as plain pytest.
+fn detect_multi_env(root: &Path) -> Option<PyDetectionResult> {
+    if root.join("noxfile.py").exists() {
+        return Some(PyDetectionResult {
+            runner: PyRunner::Nox,
+            source: PyDetectionSource::ConfigFile("noxfile.py".to_string()),
+        });
+    }
+
+    let content = std::fs::read_to_string(root.join("tox.ini")).ok()?;
+    if tox_env_count(&content) > 1 {
+        return Some(PyDetectionResult {
+            runner: PyRunner::Tox,
+            source: PyDetectionSource::ConfigFile("tox.ini".to_string()),
+        });
+    }
+
+    None
+}
+
+/// Count environments in tox.ini's `envlist` (brace expansions count as several).
+fn tox_env_count(content: &str) -> usize {
+    let mut lines = content.lines();
+    let Some(first) = lines.find_map(|l| {
+        let (key, value) = l.split_once('=')?;
+        (key.trim() == "envlist").then_some(value)
+    }) else {
+        return 0;
+    };
+
+    // The value may continue on indented lines
+    let continuation = lines.take_while(|l| l.starts_with([' ', '\t']));
+    let entries: Vec<&str> = std::iter::once(first)
+        .chain(continuation)
+        .flat_map(|l| l.split(','))
+        .map(str::trim)
+        .filter(|e| !e.is_empty())
+        .collect();
+
+    if entries.iter().any(|e| e.contains('{')) { entries.len().max(2) } else { entries.len() }
+}
+
 /// Detect test runner from pytest-specific config files.
 fn detect_from_config_files(root: &Path) -> Option<PyDetectionResult> {
     // pytest.ini is definitive
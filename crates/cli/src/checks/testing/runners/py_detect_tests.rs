@@ -186,6 +186,8 @@ fn is_python_project_detects_uv_lock() {
 fn py_runner_name() {
     assert_eq!(PyRunner::Pytest.name(), "pytest");
     assert_eq!(PyRunner::Unittest.name(), "unittest");
+    assert_eq!(PyRunner::Tox.name(), "tox");
+    assert_eq!(PyRunner::Nox.name(), "nox");
 }
 
 #[test]
@@ -220,3 +222,40 @@ fn detects_pytest_from_tox_ini() {
         PyDetectionSource::ConfigFile(ref name) if name == "tox.ini"
     ));
 }
+
+#[test]
+fn detects_tox_from_multi_env_tox_ini() {
+    let temp = create_temp_dir();
+    fs::write(temp.path().join("pyproject.toml"), "[project]\nname = \"test\"\n").unwrap();
+    fs::write(
+        temp.path().join("tox.ini"),
+        "[tox]\nenvlist =\n    py310\n    py311\n\n[testenv]\ncommands = pytest {posargs}\n",
+    )
+    .unwrap();
+
+    let result = detect_py_runner(temp.path()).unwrap();
+    assert_eq!(result.runner, PyRunner::Tox);
+    assert!(matches!(
+        result.source,
+        PyDetectionSource::ConfigFile(ref name) if name == "tox.ini"
+    ));
+}
+
+#[test]
+fn detects_nox_from_noxfile() {
+    let temp = create_temp_dir();
+    fs::write(temp.path().join("pyproject.toml"), "[tool.pytest.ini_options]\n").unwrap();
+    fs::write(temp.path().join("noxfile.py"), "import nox\n").unwrap();
+
+    let result = detect_py_runner(temp.path()).unwrap();
+    assert_eq!(result.runner, PyRunner::Nox);
+}
+
+#[test]
+fn tox_env_count_handles_lists_and_brace_expansion() {
+    assert_eq!(tox_env_count("[tox]\nenvlist = py39\n"), 1);
+    assert_eq!(tox_env_count("[tox]\nenvlist = py310, py311, lint\n"), 3);
+    assert_eq!(tox_env_count("[tox]\nenvlist = py3{10-13}\n"), 2);
+    assert_eq!(tox_env_count("[tox]\nenvlist =\n    py312\n    py313\n[testenv]\n"), 2);
+    assert_eq!(tox_env_count("[testenv]\ncommands = pytest\n"), 0);
+}
@@ -0,0 +1,195 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Shared execution for multi-environment Python runners (tox, nox).
+//!
+//! Each environment runs pytest with a JUnit XML report (and a Cobertura
+//! report when collecting coverage) written to a scratch directory. The
+//! environment's test command must forward extra arguments to pytest
+//! (`{posargs}` in tox, `session.posargs` in nox).
+
+use std::io::ErrorKind;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use super::junit::parse_junit_xml;
+use super::python_coverage::{merge_coverage_results, parse_cobertura_xml};
+use super::{
+    EnvRunResult, RunnerContext, TestResult, TestRunResult, handle_timeout_error, run_with_timeout,
+    scratch_path,
+};
+use crate::config::TestSuiteConfig;
+
+/// Run each environment and merge the results.
+///
+/// `env_command` builds the command that runs one environment; pytest
+/// arguments are appended after `--`.
+pub(crate) fn run_envs(
+    runner: &str,
+    envs: &[String],
+    config: &TestSuiteConfig,
+    ctx: &RunnerContext,
+    env_command: impl Fn(&str) -> Command,
+) -> TestRunResult {
+    let start = Instant::now();
+
+    if envs.is_empty() {
+        return TestRunResult::failed(start.elapsed(), format!("no {runner} environments found"));
+    }
+
+    let report_dir = scratch_path(runner);
+    if let Err(e) = std::fs::create_dir_all(&report_dir) {
+        return TestRunResult::failed(start.elapsed(), format!("failed to create report dir: {e}"));
+    }
+
+    // Coverage source, as for single-interpreter pytest suites
+    let source_dir = if ctx.root.join("src").is_dir() { "src" } else { "." };
+
+    let mut results = Vec::with_capacity(envs.len());
+    let mut coverage = Vec::new();
+
+    for env in envs {
+        let env_start = Instant::now();
+        let junit_path = report_dir.join(format!("{}.xml", safe_name(env)));
+        let coverage_path = report_dir.join(format!("{}.coverage.xml", safe_name(env)));
+
+        let mut cmd = env_command(env);
+        cmd.arg("--");
+        cmd.arg(format!("--junitxml={}", junit_path.display()));
+        if let Some(path) = &config.path {
+            cmd.arg(path);
+        }
+        if ctx.collect_coverage {
+            cmd.arg(format!("--cov={source_dir}"));
+            cmd.arg(format!("--cov-report=xml:{}", coverage_path.display()));
+        }
+        cmd.current_dir(ctx.root);
+        cmd.stdout(Stdio::piped());
+        cmd.stderr(Stdio::piped());
+
+        let child = match cmd.spawn() {
+            Ok(c) => c,
+            Err(e) => {
+                std::fs::remove_dir_all(&report_dir).ok();
+                return TestRunResult::failed(
+                    start.elapsed(),
+                    format!("failed to spawn {runner}: {e}"),
+                );
+            }
+        };
+
+        // The suite timeout covers all environments together
+        let remaining = config.timeout.map(|t| t.saturating_sub(start.elapsed()));
+        let output = match run_with_timeout(child, remaining) {
+            Ok(out) => out,
+            Err(e) if e.kind() == ErrorKind::TimedOut => {
+                std::fs::remove_dir_all(&report_dir).ok();
+                return handle_timeout_error(start.elapsed(), config.timeout, runner);
+            }
+            Err(e) => {
+                std::fs::remove_dir_all(&report_dir).ok();
+                return TestRunResult::failed(
+                    start.elapsed(),
+                    format!("failed to run {runner}: {e}"),
+                );
+            }
+        };
+
+        let junit = std::fs::read_to_string(&junit_path).unwrap_or_default();
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let mut result =
+            parse_env_report(&junit, &stderr, output.status.success(), env_start.elapsed());
+
+        if ctx.collect_coverage
+            && let Ok(xml) = std::fs::read_to_string(&coverage_path)
+        {
+            let env_coverage = parse_cobertura_xml(&xml, env_start.elapsed());
+            result = result.with_collected_coverage(env_coverage.clone(), "python");
+            coverage.push(env_coverage);
+        }
+
+        results.push(EnvRunResult { name: env.clone(), result });
+    }
+
+    std::fs::remove_dir_all(&report_dir).ok();
+
+    let mut result = merge_env_results(results, start.elapsed());
+    if !coverage.is_empty() {
+        let merged = merge_coverage_results(&coverage, start.elapsed());
+        result = result.with_collected_coverage(merged, "python");
+    }
+    result
+}
+
+/// Parse one environment's JUnit report.
+pub(crate) fn parse_env_report(
+    junit: &str,
+    stderr: &str,
+    success: bool,
+    total_time: Duration,
+) -> TestRunResult {
+    let tests = parse_junit_xml(junit);
+
+    if success {
+        return TestRunResult::passed(total_time).with_tests(tests);
+    }
+
+    let error = if tests.iter().any(|t| !t.passed) {
+        "tests failed".to_string()
+    } else if tests.is_empty() {
+        // Failed before pytest ran (e.g., missing interpreter, install error)
+        stderr
+            .lines()
+            .map(str::trim)
+            .rfind(|l| !l.is_empty())
+            .unwrap_or("environment failed")
+            .to_string()
+    } else {
+        "environment failed".to_string()
+    };
+    let mut result = TestRunResult::failed(total_time, error);
+    result.tests = tests;
+    result
+}
+
+/// Combine per-environment results into a single suite result.
+///
+/// Test names are prefixed with their environment (e.g., `[py312] test_x`).
+pub(crate) fn merge_env_results(envs: Vec<EnvRunResult>, total_time: Duration) -> TestRunResult {
+    let failed: Vec<&str> =
+        envs.iter().filter(|e| !e.result.passed).map(|e| e.name.as_str()).collect();
+
+    let mut result = if failed.is_empty() {
+        TestRunResult::passed(total_time)
+    } else {
+        TestRunResult::failed(total_time, format!("failed environments: {}", failed.join(", ")))
+    };
+
+    result.tests = envs
+        .iter()
+        .flat_map(|env| {
+            env.result.tests.iter().map(move |t| TestResult {
+                name: format!("[{}] {}", env.name, t.name),
+                ..t.clone()
+            })
+        })
+        .collect();
+    result.envs = envs;
+    result
+}
+
+/// Make an environment name safe for use as a file name.
+fn safe_name(env: &str) -> String {
+    env.chars().map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '_' }).collect()
+}
+
+/// Run a listing command and return its stdout, or None on failure.
+pub(crate) fn list_output(mut cmd: Command, root: &Path) -> Option<String> {
+    let output = cmd.current_dir(root).stderr(Stdio::null()).output().ok()?;
+    output.status.success().then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+#[path = "py_envs_tests.rs"]
+mod tests;
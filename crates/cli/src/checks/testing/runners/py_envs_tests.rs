@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::time::Duration;
+
+use super::*;
+
+const PASSING: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<testsuites><testsuite name="pytest" errors="0" failures="0" skipped="0" tests="2" time="0.05">
+<testcase classname="tests.test_math" name="test_add" time="0.010"/>
+<testcase classname="tests.test_math" name="test_sub" time="0.020"/>
+</testsuite></testsuites>"#;
+
+const FAILING: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<testsuites><testsuite name="pytest" errors="0" failures="1" skipped="0" tests="2" time="0.05">
+<testcase classname="tests.test_math" name="test_add" time="0.010"/>
+<testcase classname="tests.test_math" name="test_sub" time="0.020"><failure message="assert 1 == 2"/></testcase>
+</testsuite></testsuites>"#;
+
+fn env(name: &str, junit: &str, success: bool) -> EnvRunResult {
+    EnvRunResult {
+        name: name.to_string(),
+        result: parse_env_report(junit, "", success, Duration::from_secs(1)),
+    }
+}
+
+#[test]
+fn parses_env_report() {
+    let result = parse_env_report(PASSING, "", true, Duration::from_secs(1));
+
+    assert!(result.passed);
+    assert_eq!(result.tests.len(), 2);
+    assert_eq!(result.tests[0].name, "tests.test_math::test_add");
+}
+
+#[test]
+fn env_failing_before_pytest_reports_last_stderr_line() {
+    let stderr = "py39: skipped because could not find python interpreter\n\
+                  py39: FAIL code 1 (0.01 seconds)\n";
+
+    let result = parse_env_report("", stderr, false, Duration::from_secs(1));
+
+    assert!(!result.passed);
+    assert_eq!(result.error.as_deref(), Some("py39: FAIL code 1 (0.01 seconds)"));
+}
+
+#[test]
+fn merge_prefixes_tests_with_env() {
+    let merged = merge_env_results(
+        vec![env("py312", PASSING, true), env("py313", PASSING, true)],
+        Duration::from_secs(2),
+    );
+
+    assert!(merged.passed);
+    assert_eq!(merged.tests.len(), 4);
+    assert_eq!(merged.tests[0].name, "[py312] tests.test_math::test_add");
+    assert_eq!(merged.tests[3].name, "[py313] tests.test_math::test_sub");
+    assert_eq!(merged.envs.len(), 2);
+}
+
+#[test]
+fn merge_fails_when_any_env_fails() {
+    let merged = merge_env_results(
+        vec![env("py310", FAILING, false), env("py313", PASSING, true)],
+        Duration::from_secs(2),
+    );
+
+    assert!(!merged.passed);
+    assert_eq!(merged.error.as_deref(), Some("failed environments: py310"));
+    assert!(merged.envs[1].result.passed);
+}
+
+#[test]
+fn safe_name_replaces_path_characters() {
+    assert_eq!(safe_name("tests(python='3.12')"), "tests_python__3_12__");
+    assert_eq!(safe_name("py312-django"), "py312-django");
+}
@@ -398,6 +398,35 @@ pub(crate) fn parse_cobertura_xml(xml: &str, duration: Duration) -> CoverageResu
     CoverageResult { success: true, error: None, duration, line_coverage, files, packages }
 }
 
+/// Merge coverage collected from several environments of the same project.
+///
+/// Each environment runs the same code under a different interpreter, so a
+/// file counts as covered as well as its best environment covers it.
+pub(crate) fn merge_coverage_results(
+    results: &[CoverageResult],
+    duration: Duration,
+) -> CoverageResult {
+    let mut merged = CoverageResult::skipped();
+    merged.duration = duration;
+
+    for result in results.iter().filter(|r| r.success) {
+        merged.line_coverage = match (merged.line_coverage, result.line_coverage) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        };
+        for (file, coverage) in &result.files {
+            let entry = merged.files.entry(file.clone()).or_insert(*coverage);
+            *entry = entry.max(*coverage);
+        }
+        for (package, coverage) in &result.packages {
+            let entry = merged.packages.entry(package.clone()).or_insert(*coverage);
+            *entry = entry.max(*coverage);
+        }
+    }
+
+    merged
+}
+
 /// Extract an attribute value from an XML element string.
 fn extract_attr(xml: &str, attr_name: &str) -> Option<String> {
     let pattern = format!("{attr_name}=\"");
@@ -321,3 +321,49 @@ fn json_reports_multiple_packages() {
     // api package average: (50 + 75) / 2 = 62.5
     assert_eq!(result.packages.get("api"), Some(&62.5));
 }
+
+// =============================================================================
+// Multi-Environment Merge Tests
+// =============================================================================
+
+fn env_coverage(line: f64, files: &[(&str, f64)]) -> CoverageResult {
+    CoverageResult {
+        success: true,
+        error: None,
+        duration: Duration::ZERO,
+        line_coverage: Some(line),
+        files: files.iter().map(|(f, c)| (f.to_string(), *c)).collect(),
+        packages: HashMap::new(),
+    }
+}
+
+#[test]
+fn merge_takes_best_coverage_per_file() {
+    let py310 = env_coverage(70.0, &[("src/app/compat.py", 40.0), ("src/app/core.py", 90.0)]);
+    let py313 = env_coverage(75.0, &[("src/app/compat.py", 80.0), ("src/app/core.py", 85.0)]);
+
+    let merged = merge_coverage_results(&[py310, py313], Duration::from_secs(1));
+
+    assert!(merged.success);
+    assert_eq!(merged.line_coverage, Some(75.0));
+    assert_eq!(merged.files["src/app/compat.py"], 80.0);
+    assert_eq!(merged.files["src/app/core.py"], 90.0);
+}
+
+#[test]
+fn merge_ignores_failed_environments() {
+    let ok = env_coverage(60.0, &[("src/app/core.py", 60.0)]);
+    let failed = CoverageResult::failed(Duration::ZERO, "no coverage output");
+
+    let merged = merge_coverage_results(&[failed, ok], Duration::ZERO);
+
+    assert_eq!(merged.line_coverage, Some(60.0));
+    assert_eq!(merged.files.len(), 1);
+}
+
+#[test]
+fn merge_of_nothing_has_no_coverage() {
+    let merged = merge_coverage_results(&[], Duration::ZERO);
+
+    assert!(merged.line_coverage.is_none());
+}
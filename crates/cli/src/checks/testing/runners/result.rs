@@ -55,6 +55,17 @@ pub struct TestRunResult {
     pub coverage: Option<HashMap<String, f64>>,
     /// Per-package coverage percentage (0-100).
     pub coverage_by_package: Option<HashMap<String, f64>>,
+    /// Per-environment results for multi-environment runners (tox, nox).
+    pub envs: Vec<EnvRunResult>,
+}
+
+/// Result of running one environment of a multi-environment runner.
+#[derive(Debug, Clone)]
+pub struct EnvRunResult {
+    /// Environment name (e.g., "py312").
+    pub name: String,
+    /// Result of the environment's run.
+    pub result: TestRunResult,
 }
 
 impl TestRunResult {
@@ -68,6 +79,7 @@ impl TestRunResult {
             tests: Vec::new(),
             coverage: None,
             coverage_by_package: None,
+            envs: Vec::new(),
         }
     }
 
@@ -81,6 +93,7 @@ impl TestRunResult {
             tests: Vec::new(),
             coverage: None,
             coverage_by_package: None,
+            envs: Vec::new(),
         }
     }
 
@@ -94,6 +107,7 @@ impl TestRunResult {
             tests: Vec::new(),
             coverage: None,
             coverage_by_package: None,
+            envs: Vec::new(),
         }
     }
 
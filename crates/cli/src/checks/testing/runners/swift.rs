@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! SwiftPM runner for Swift packages.
+//!
+//! Runs `swift test --parallel --xunit-output <report>` in the package root
+//! (or `--package-path <path>`) and parses the xUnit report. Swift 6 writes
+//! swift-testing results to a sibling `<report>-swift-testing.xml`, which is
+//! read as well. With coverage enabled, `--enable-code-coverage` makes
+//! SwiftPM export llvm-cov JSON, located with `--show-codecov-path` (see
+//! `swift_coverage`).
+
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use super::junit::parse_junit_xml;
+use super::swift_coverage::collect_swift_coverage;
+use super::{
+    RunnerContext, TestRunResult, TestRunner, handle_timeout_error, run_setup_or_fail,
+    run_with_timeout, scratch_path, tool_available,
+};
+use crate::config::TestSuiteConfig;
+
+/// SwiftPM (`swift test`) runner.
+pub struct SwiftRunner;
+
+impl TestRunner for SwiftRunner {
+    fn name(&self) -> &'static str {
+        "swift"
+    }
+
+    fn available(&self, _ctx: &RunnerContext) -> bool {
+        tool_available("swift")
+    }
+
+    fn run(&self, config: &TestSuiteConfig, ctx: &RunnerContext) -> TestRunResult {
+        run_setup_or_fail!(config, ctx);
+
+        let report = scratch_path("swift").with_extension("xml");
+        let testing_report = swift_testing_report(&report);
+
+        let start = Instant::now();
+
+        let path = config.path.as_deref();
+        let mut cmd = swift_command(path, &report, ctx.collect_coverage);
+        cmd.current_dir(ctx.root);
+        cmd.stdout(Stdio::piped());
+        cmd.stderr(Stdio::piped());
+
+        let child = match cmd.spawn() {
+            Ok(c) => c,
+            Err(e) => {
+                return TestRunResult::failed(
+                    start.elapsed(),
+                    format!("failed to spawn swift: {e}"),
+                );
+            }
+        };
+
+        let output = match run_with_timeout(child, config.timeout) {
+            Ok(out) => out,
+            Err(e) if e.kind() == ErrorKind::TimedOut => {
+                std::fs::remove_file(&report).ok();
+                std::fs::remove_file(&testing_report).ok();
+                return handle_timeout_error(start.elapsed(), config.timeout, "swift");
+            }
+            Err(e) => {
+                std::fs::remove_file(&report).ok();
+                std::fs::remove_file(&testing_report).ok();
+                return TestRunResult::failed(start.elapsed(), format!("failed to run swift: {e}"));
+            }
+        };
+
+        let total_time = start.elapsed();
+        let reports: Vec<String> = [&report, &testing_report]
+            .into_iter()
+            .filter_map(|f| std::fs::read_to_string(f).ok())
+            .collect();
+        std::fs::remove_file(&report).ok();
+        std::fs::remove_file(&testing_report).ok();
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let mut result =
+            parse_swift_output(&reports, &stdout, &stderr, output.status.success(), total_time);
+
+        if ctx.collect_coverage {
+            let package = path.map_or_else(|| ctx.root.to_path_buf(), |p| ctx.root.join(p));
+            let coverage = collect_swift_coverage(ctx.root, &package);
+            result = result.with_collected_coverage(coverage, "swift");
+        }
+
+        result
+    }
+}
+
+/// Build the `swift test` command.
+///
+/// `path` selects a package directory other than the project root.
+pub(crate) fn swift_command(path: Option<&str>, report: &Path, coverage: bool) -> Command {
+    let mut cmd = Command::new("swift");
+    cmd.arg("test");
+    if let Some(path) = path {
+        cmd.args(["--package-path", path]);
+    }
+    cmd.arg("--parallel");
+    cmd.arg("--xunit-output").arg(report);
+    if coverage {
+        cmd.arg("--enable-code-coverage");
+    }
+    cmd
+}
+
+/// Report written by swift-testing next to the XCTest xUnit report.
+///
+/// `--xunit-output results.xml` also produces `results-swift-testing.xml`.
+pub(crate) fn swift_testing_report(report: &Path) -> PathBuf {
+    let stem = report.file_stem().map(|s| s.to_string_lossy()).unwrap_or_default();
+    report.with_file_name(format!("{stem}-swift-testing.xml"))
+}
+
+/// Build a result from the xUnit reports and `swift test` output.
+pub(crate) fn parse_swift_output(
+    reports: &[String],
+    stdout: &str,
+    stderr: &str,
+    success: bool,
+    total_time: Duration,
+) -> TestRunResult {
+    let tests: Vec<_> = reports.iter().flat_map(|xml| parse_junit_xml(xml)).collect();
+
+    if success {
+        return TestRunResult::passed(total_time).with_tests(tests);
+    }
+
+    let error = if tests.iter().any(|t| !t.passed) {
+        "tests failed".to_string()
+    } else {
+        swift_error(stdout, stderr).unwrap_or_else(|| "swift test failed".to_string())
+    };
+    let mut result = TestRunResult::failed(total_time, error);
+    result.tests = tests;
+    result
+}
+
+/// Extract the first build or package error from `swift test` output.
+///
+/// Covers compiler errors (`Sources/Acme/Parser.swift:3:5: error: cannot find 'x' in scope`)
+/// and SwiftPM errors (`error: Could not find Package.swift in this directory`).
+pub(crate) fn swift_error(stdout: &str, stderr: &str) -> Option<String> {
+    stderr
+        .lines()
+        .chain(stdout.lines())
+        .map(str::trim)
+        .find(|l| l.contains(": error: ") || l.starts_with("error: "))
+        .map(String::from)
+}
+
+#[cfg(test)]
+#[path = "swift_tests.rs"]
+mod tests;
@@ -0,0 +1,175 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Coverage collection for Swift packages.
+//!
+//! `swift test --enable-code-coverage` exports llvm-cov JSON for the package
+//! to the path printed by `swift test --show-codecov-path` (normally
+//! `.build/debug/codecov/<Package>.json`).
+//!
+//! Paths are reported relative to the project root; dependencies and
+//! generated code under `.build/`, test targets under `Tests/`, and files
+//! outside the root are skipped.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+
+use super::CoverageResult;
+
+/// Locate and parse the coverage JSON exported by the last `swift test` run.
+pub(crate) fn collect_swift_coverage(root: &Path, package: &Path) -> CoverageResult {
+    let start = Instant::now();
+    let Some(report) = codecov_path(package) else {
+        return CoverageResult::skipped();
+    };
+    let Ok(json) = std::fs::read_to_string(&report) else {
+        return CoverageResult::skipped();
+    };
+
+    // llvm-cov reports absolute, symlink-resolved paths
+    let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+    parse_swift_coverage(&json, &root, start.elapsed())
+}
+
+/// Ask SwiftPM where it exported the coverage JSON, falling back to the default location.
+fn codecov_path(package: &Path) -> Option<PathBuf> {
+    let output = Command::new("swift")
+        .args(["test", "--show-codecov-path"])
+        .current_dir(package)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .output()
+        .ok()
+        .filter(|o| o.status.success());
+    if let Some(output) = output {
+        let path = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if !path.is_empty() && Path::new(&path).is_file() {
+            return Some(PathBuf::from(path));
+        }
+    }
+
+    let mut exports: Vec<_> = std::fs::read_dir(package.join(".build/debug/codecov"))
+        .ok()?
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|e| e == "json"))
+        .collect();
+    exports.sort();
+    exports.pop()
+}
+
+#[derive(Debug, Deserialize)]
+struct LlvmCovReport {
+    data: Vec<LlvmCovData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LlvmCovData {
+    files: Vec<LlvmCovFile>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LlvmCovFile {
+    filename: String,
+    summary: LlvmCovSummary,
+}
+
+#[derive(Debug, Deserialize)]
+struct LlvmCovSummary {
+    lines: LlvmCovLines,
+}
+
+#[derive(Debug, Deserialize)]
+struct LlvmCovLines {
+    count: u64,
+    covered: u64,
+}
+
+/// Parse llvm-cov export JSON, keeping package sources under `root`.
+///
+/// Overall coverage is recomputed from the kept files, since the report
+/// totals include dependencies and test targets.
+pub(crate) fn parse_swift_coverage(json: &str, root: &Path, duration: Duration) -> CoverageResult {
+    let report: LlvmCovReport = match serde_json::from_str(json) {
+        Ok(r) => r,
+        Err(e) => {
+            return CoverageResult::failed(duration, format!("failed to parse coverage JSON: {e}"));
+        }
+    };
+
+    // Path -> (covered, count)
+    let mut lines: BTreeMap<String, (u64, u64)> = BTreeMap::new();
+    for file in report.data.iter().flat_map(|d| &d.files) {
+        let Some(path) = relative_source(&file.filename, root) else {
+            continue;
+        };
+        let entry = lines.entry(path).or_default();
+        entry.0 += file.summary.lines.covered;
+        entry.1 += file.summary.lines.count;
+    }
+
+    let mut files = HashMap::new();
+    let mut package_lines: HashMap<String, (u64, u64)> = HashMap::new();
+    let (mut covered, mut total) = (0, 0);
+    for (path, (file_covered, file_total)) in lines {
+        if file_total == 0 {
+            continue;
+        }
+        files.insert(path.clone(), percent(file_covered, file_total));
+        let package = package_lines.entry(extract_swift_package(&path)).or_default();
+        package.0 += file_covered;
+        package.1 += file_total;
+        covered += file_covered;
+        total += file_total;
+    }
+
+    if total == 0 {
+        return CoverageResult::skipped();
+    }
+
+    CoverageResult {
+        success: true,
+        error: None,
+        duration,
+        line_coverage: Some(percent(covered, total)),
+        files,
+        packages: package_lines.into_iter().map(|(p, (c, t))| (p, percent(c, t))).collect(),
+    }
+}
+
+fn percent(covered: u64, total: u64) -> f64 {
+    covered as f64 / total as f64 * 100.0
+}
+
+/// Make an llvm-cov source path relative to the root.
+///
+/// Returns None for files outside the root, under `.build/`, or in a `Tests/` target.
+fn relative_source(path: &str, root: &Path) -> Option<String> {
+    let relative = Path::new(path).strip_prefix(root).ok()?;
+    let relative = relative.to_string_lossy().replace('\\', "/");
+    let skipped = relative.is_empty()
+        || relative.split('/').any(|component| component == ".build" || component == "Tests");
+    (!skipped).then_some(relative)
+}
+
+/// Package of a Swift source: its target (`Sources/<Target>`), else its first directory, else `root`.
+pub(crate) fn extract_swift_package(path: &str) -> String {
+    let parts: Vec<&str> = path.split('/').collect();
+    if let Some(idx) = parts.iter().position(|p| *p == "Sources")
+        && idx + 2 < parts.len()
+    {
+        return parts[..=idx + 1].join("/");
+    }
+    match path.split_once('/') {
+        Some((dir, _)) => dir.to_string(),
+        None => "root".to_string(),
+    }
+}
+
+#[cfg(test)]
+#[path = "swift_coverage_tests.rs"]
+mod tests;
@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::path::Path;
+use std::time::Duration;
+
+use super::*;
+
+const ROOT: &str = "/work/acme";
+
+fn file(name: &str, covered: u64, count: u64) -> String {
+    format!(
+        r#"{{"filename": "{name}", "summary": {{"lines": {{"count": {count}, "covered": {covered}, "percent": 0}}}}}}"#
+    )
+}
+
+fn report(files: &[String]) -> String {
+    format!(
+        r#"{{"data": [{{"files": [{}], "totals": {{"lines": {{"count": 0, "covered": 0, "percent": 0}}}}}}], "type": "llvm.coverage.json.export", "version": "2.0.1"}}"#,
+        files.join(",")
+    )
+}
+
+fn parse(json: &str) -> CoverageResult {
+    parse_swift_coverage(json, Path::new(ROOT), Duration::ZERO)
+}
+
+#[test]
+fn reports_files_relative_to_root() {
+    let json = report(&[
+        file("/work/acme/Sources/Acme/Parser.swift", 3, 4),
+        file("/work/acme/Sources/Acme/Lexer.swift", 1, 4),
+    ]);
+    let result = parse(&json);
+
+    assert!(result.success);
+    assert_eq!(result.files.get("Sources/Acme/Parser.swift"), Some(&75.0));
+    assert_eq!(result.files.get("Sources/Acme/Lexer.swift"), Some(&25.0));
+    assert_eq!(result.line_coverage, Some(50.0));
+}
+
+#[test]
+fn skips_dependencies_tests_and_outside_files() {
+    let json = report(&[
+        file("/work/acme/Sources/Acme/Parser.swift", 1, 2),
+        file("/work/acme/.build/checkouts/swift-nio/Sources/NIO/Channel.swift", 0, 100),
+        file("/work/acme/Tests/AcmeTests/ParserTests.swift", 10, 10),
+        file("/usr/lib/swift/Foundation.swift", 0, 50),
+    ]);
+    let result = parse(&json);
+
+    assert_eq!(result.files.len(), 1);
+    assert_eq!(result.line_coverage, Some(50.0));
+}
+
+#[test]
+fn groups_by_target() {
+    let json = report(&[
+        file("/work/acme/Sources/Acme/Parser.swift", 1, 2),
+        file("/work/acme/Sources/Acme/Lexer.swift", 2, 2),
+        file("/work/acme/Sources/AcmeCLI/main.swift", 0, 4),
+    ]);
+    let result = parse(&json);
+
+    assert_eq!(result.packages.get("Sources/Acme"), Some(&75.0));
+    assert_eq!(result.packages.get("Sources/AcmeCLI"), Some(&0.0));
+}
+
+#[test]
+fn empty_report_is_skipped() {
+    let result = parse(&report(&[]));
+    assert!(result.line_coverage.is_none());
+}
+
+#[test]
+fn invalid_json_fails() {
+    let result = parse("not json");
+    assert!(!result.success);
+}
+
+#[test]
+fn package_names() {
+    assert_eq!(extract_swift_package("Sources/Acme/Parser.swift"), "Sources/Acme");
+    assert_eq!(
+        extract_swift_package("Packages/Core/Sources/Core/Model.swift"),
+        "Packages/Core/Sources/Core"
+    );
+    assert_eq!(extract_swift_package("Plugins/Gen/main.swift"), "Plugins");
+    assert_eq!(extract_swift_package("Package.swift"), "root");
+}
@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::path::Path;
+use std::time::Duration;
+
+use super::*;
+
+const REPORT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites>
+  <testsuite name="TestResults" errors="0" tests="3" failures="1" time="0.05">
+    <testcase classname="AcmeTests.ParserTests" name="testParsesNumbers" time="0.012" />
+    <testcase classname="AcmeTests.ParserTests" name="testRejectsGarbage" time="0.030">
+      <failure message="failed"></failure>
+    </testcase>
+    <testcase classname="AcmeTests.NetworkTests" name="testFetch" time="0">
+      <skipped/>
+    </testcase>
+  </testsuite>
+</testsuites>"#;
+
+#[test]
+fn command_runs_parallel_with_xunit_output() {
+    let cmd = swift_command(None, Path::new("/tmp/report.xml"), false);
+    let args: Vec<_> = cmd.get_args().map(|a| a.to_string_lossy().into_owned()).collect();
+
+    assert_eq!(cmd.get_program(), "swift");
+    assert_eq!(args, ["test", "--parallel", "--xunit-output", "/tmp/report.xml"]);
+}
+
+#[test]
+fn command_with_package_path_and_coverage() {
+    let cmd = swift_command(Some("Packages/Core"), Path::new("/tmp/report.xml"), true);
+    let args: Vec<_> = cmd.get_args().map(|a| a.to_string_lossy().into_owned()).collect();
+
+    assert_eq!(args[..3], ["test", "--package-path", "Packages/Core"]);
+    assert_eq!(args.last().map(String::as_str), Some("--enable-code-coverage"));
+}
+
+#[test]
+fn swift_testing_report_is_sibling() {
+    assert_eq!(
+        swift_testing_report(Path::new("/tmp/quench-swift-1-0.xml")),
+        Path::new("/tmp/quench-swift-1-0-swift-testing.xml")
+    );
+}
+
+#[test]
+fn parses_xunit_report() {
+    let result = parse_swift_output(&[REPORT.to_string()], "", "", false, Duration::from_secs(1));
+
+    assert!(!result.passed);
+    assert_eq!(result.error.as_deref(), Some("tests failed"));
+    assert_eq!(result.tests[0].name, "AcmeTests.ParserTests::testParsesNumbers");
+    assert_eq!(result.passed_count(), 1);
+    assert_eq!(result.failed_count(), 1);
+    assert_eq!(result.skipped_count(), 1);
+}
+
+#[test]
+fn merges_xctest_and_swift_testing_reports() {
+    let xctest = r#"<testsuite><testcase classname="A" name="testOne" time="0.5"/></testsuite>"#;
+    let testing = r#"<testsuite><testcase classname="B" name="two()" time="0.1"/></testsuite>"#;
+    let result = parse_swift_output(
+        &[xctest.to_string(), testing.to_string()],
+        "",
+        "",
+        true,
+        Duration::from_secs(1),
+    );
+
+    assert!(result.passed);
+    assert_eq!(result.tests.len(), 2);
+    assert_eq!(result.tests[0].duration, Duration::from_millis(500));
+}
+
+#[test]
+fn build_failure_reports_compiler_error() {
+    let stderr = "Building for debugging...\n\
+/work/acme/Sources/Acme/Parser.swift:3:5: error: cannot find 'x' in scope\n";
+    let result = parse_swift_output(&[], "", stderr, false, Duration::from_secs(1));
+
+    assert_eq!(
+        result.error.as_deref(),
+        Some("/work/acme/Sources/Acme/Parser.swift:3:5: error: cannot find 'x' in scope")
+    );
+}
+
+#[test]
+fn missing_manifest_reports_swiftpm_error() {
+    let stderr =
+        "error: Could not find Package.swift in this directory or any of its parent directories.";
+    let result = parse_swift_output(&[], "", stderr, false, Duration::from_secs(1));
+
+    assert!(result.error.as_deref().unwrap().starts_with("error: Could not find Package.swift"));
+}
+
+#[test]
+fn failure_without_output_has_generic_error() {
+    let result = parse_swift_output(&[], "", "", false, Duration::from_secs(1));
+    assert_eq!(result.error.as_deref(), Some("swift test failed"));
+}
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Tox test runner.
+//!
+//! Runs each tox environment (`tox -e <env> -- <pytest args>`) and parses
+//! its pytest JUnit report. Environments default to `tox -l`.
+
+use std::process::Command;
+
+use super::py_envs::{list_output, run_envs};
+use super::{RunnerContext, TestRunResult, TestRunner, run_setup_or_fail, tool_available};
+use crate::config::TestSuiteConfig;
+
+/// Tox runner for multi-interpreter Python test suites.
+pub struct ToxRunner;
+
+impl TestRunner for ToxRunner {
+    fn name(&self) -> &'static str {
+        "tox"
+    }
+
+    fn available(&self, _ctx: &RunnerContext) -> bool {
+        tool_available("tox")
+    }
+
+    fn run(&self, config: &TestSuiteConfig, ctx: &RunnerContext) -> TestRunResult {
+        run_setup_or_fail!(config, ctx);
+
+        let envs = if config.envs.is_empty() {
+            let mut cmd = Command::new("tox");
+            cmd.arg("-l");
+            list_output(cmd, ctx.root).map(|out| parse_tox_envs(&out)).unwrap_or_default()
+        } else {
+            config.envs.clone()
+        };
+
+        run_envs("tox", &envs, config, ctx, |env| {
+            let mut cmd = Command::new("tox");
+            cmd.args(["-e", env]);
+            cmd
+        })
+    }
+}
+
+/// Parse `tox -l` output (one environment per line).
+pub(crate) fn parse_tox_envs(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.contains(char::is_whitespace))
+        .map(String::from)
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "tox_tests.rs"]
+mod tests;
@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+#[test]
+fn parses_env_list() {
+    let output = "py310\npy311\npy312\npy313\nlint\n";
+
+    assert_eq!(parse_tox_envs(output), ["py310", "py311", "py312", "py313", "lint"]);
+}
+
+#[test]
+fn skips_blank_and_message_lines() {
+    let output = "\nROOT: will run in automatically provisioned tox\npy312\n\n";
+
+    assert_eq!(parse_tox_envs(output), ["py312"]);
+}
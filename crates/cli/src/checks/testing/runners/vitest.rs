@@ -59,6 +59,14 @@ impl TestRunner for VitestRunner {
         cmd.args(&exec_cmd[1..]);
         cmd.args(["vitest", "run", "--reporter=json"]);
 
+        // Only run tests related to changed files when selecting by impact
+        if let Some(changes) = ctx.changes {
+            cmd.arg("--changed");
+            if let Some(base) = changes.base {
+                cmd.arg(base);
+            }
+        }
+
         // Add test path if specified
         if let Some(path) = &config.path {
             cmd.arg(path);
@@ -4,13 +4,21 @@
 //! Test suite execution and result types.
 
 use std::collections::HashMap;
-
-use rayon::prelude::*;
+use std::panic::{AssertUnwindSafe, catch_unwind, resume_unwind};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{PoisonError, RwLock};
+use std::thread;
+use std::time::{Duration, Instant};
 
 use crate::check::CheckContext;
 use crate::config::TestSuiteConfig;
 
-use super::runners::{RunnerContext, filter_suites_for_mode, get_runner, run_setup_command};
+use super::impact::{
+    Selection, change_set, narrowed_packages, runner_filters_changes, select_suite,
+};
+use super::runners::{
+    EnvRunResult, RunnerContext, filter_suites_for_mode, get_runner, run_setup_command,
+};
 
 /// Format milliseconds as a human-friendly duration string.
 ///
@@ -110,6 +118,40 @@ pub struct SuiteResult {
     pub coverage: Option<HashMap<String, f64>>,
     /// Per-package coverage data (package name -> percentage).
     pub coverage_by_package: Option<HashMap<String, f64>>,
+    /// Packages the run was narrowed to by affected-suite selection.
+    pub affected_packages: Option<Vec<String>>,
+    /// Per-environment results (tox and nox suites).
+    pub envs: Vec<EnvSuiteResult>,
+}
+
+/// Result from one environment of a multi-environment suite.
+#[derive(Debug, Default)]
+pub struct EnvSuiteResult {
+    /// Environment name (e.g., "py312").
+    pub name: String,
+    /// Whether all tests in the environment passed.
+    pub passed: bool,
+    /// Error message if the environment failed.
+    pub error: Option<String>,
+    /// Number of tests run.
+    pub test_count: usize,
+    /// Total time in milliseconds.
+    pub total_ms: u64,
+    /// Line coverage percentage, if collected.
+    pub coverage: Option<f64>,
+}
+
+impl EnvSuiteResult {
+    fn from_run(env: &EnvRunResult) -> Self {
+        Self {
+            name: env.name.clone(),
+            passed: env.result.passed,
+            error: env.result.error.clone(),
+            test_count: env.result.test_count(),
+            total_ms: env.result.total_time.as_millis() as u64,
+            coverage: env.result.coverage.as_ref().and_then(|c| c.values().next().copied()),
+        }
+    }
 }
 
 /// Run configured test suites.
@@ -117,11 +159,13 @@ pub struct SuiteResult {
 /// Returns None if no suites are configured.
 ///
 /// Execution strategy:
-/// - CI mode with 2+ suites: Parallel execution via rayon
-/// - Fast mode: Sequential with early termination on failure
+/// - Up to `[check.tests] jobs` suites run at once (default: all suites in
+///   CI mode, one at a time in fast mode); `exclusive` suites run alone
+/// - Fast mode: no new suites start after the first failure
+/// - Results are reported in configuration order
 pub fn run_suites(ctx: &CheckContext) -> Option<SuiteResults> {
-    let suites = &ctx.config.check.tests.suite;
-    if suites.is_empty() {
+    let tests = &ctx.config.check.tests;
+    if tests.suite.is_empty() {
         return None;
     }
 
@@ -131,64 +175,232 @@ pub fn run_suites(ctx: &CheckContext) -> Option<SuiteResults> {
         collect_coverage: ctx.ci_mode, // Coverage only in CI
         config: ctx.config,
         verbose: ctx.verbose,
+        changes: change_set(ctx),
     };
 
     // Filter suites for current mode
-    let active_suites = filter_suites_for_mode(suites, ctx.ci_mode);
+    let active_suites = filter_suites_for_mode(&tests.suite, ctx.ci_mode);
     if active_suites.is_empty() {
         return None;
     }
 
-    let mut results = Vec::with_capacity(active_suites.len());
-    let mut all_passed = true;
+    let jobs = effective_jobs(tests.jobs, ctx.ci_mode, active_suites.len());
+    let budget = tests.timeout.map(|timeout| (timeout, Instant::now() + timeout));
 
-    // Parallel execution in CI mode when multiple suites
-    if ctx.ci_mode && active_suites.len() > 1 {
-        results =
-            active_suites.par_iter().map(|suite| run_single_suite(suite, &runner_ctx)).collect();
-        all_passed = results.iter().all(|r| r.passed || r.skipped);
-    } else {
-        // Sequential with early termination for fast mode
-        for suite in active_suites {
-            let result = run_single_suite(suite, &runner_ctx);
-            let failed = !result.passed && !result.skipped;
-            results.push(result);
-
-            // Early termination in fast mode on first failure
-            if failed && !ctx.ci_mode {
-                all_passed = false;
+    let results = schedule_suites(&active_suites, jobs, !ctx.ci_mode, |suite| {
+        // Buffer verbose output so concurrent suites don't interleave
+        let mut log = if jobs > 1 { SuiteLog::buffered() } else { SuiteLog::direct() };
+        let result = match budget {
+            Some((timeout, deadline)) => match within_budget(suite, deadline) {
+                Some(budgeted) => execute_suite(&budgeted, &runner_ctx, &mut log),
+                None => budget_exhausted(suite, timeout),
+            },
+            None => execute_suite(suite, &runner_ctx, &mut log),
+        };
+        log.flush();
+        result
+    });
+
+    let passed = results.iter().all(|r| r.passed || r.skipped);
+    Some(SuiteResults { passed, suites: results })
+}
+
+/// Number of suites to run concurrently.
+///
+/// Without an explicit `jobs` setting, CI mode runs every suite at once and
+/// fast mode runs them one at a time.
+fn effective_jobs(jobs: Option<usize>, ci_mode: bool, suite_count: usize) -> usize {
+    let jobs = jobs.unwrap_or(if ci_mode { suite_count } else { 1 });
+    jobs.clamp(1, suite_count.max(1))
+}
+
+/// Run suites on up to `jobs` threads, returning results in configuration order.
+///
+/// Suites start in order. An `exclusive` suite waits for running suites to
+/// finish and holds off others until it completes. With `stop_on_failure`,
+/// no new suites start once one has failed. A suite whose runner panics
+/// gets a failed result.
+fn schedule_suites<F>(
+    suites: &[&TestSuiteConfig],
+    jobs: usize,
+    stop_on_failure: bool,
+    run: F,
+) -> Vec<SuiteResult>
+where
+    F: Fn(&TestSuiteConfig) -> SuiteResult + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let failed = AtomicBool::new(false);
+    let gate = RwLock::new(());
+
+    let worker = || {
+        let mut done = Vec::new();
+        loop {
+            if stop_on_failure && failed.load(Ordering::SeqCst) {
                 break;
             }
-            if failed {
-                all_passed = false;
+            let index = next.fetch_add(1, Ordering::SeqCst);
+            let Some(suite) = suites.get(index) else {
+                break;
+            };
+            let run_caught = || {
+                catch_unwind(AssertUnwindSafe(|| run(suite)))
+                    .unwrap_or_else(|_| runner_panicked(suite))
+            };
+            let result = if suite.exclusive {
+                let _guard = gate.write().unwrap_or_else(PoisonError::into_inner);
+                run_caught()
+            } else {
+                let _guard = gate.read().unwrap_or_else(PoisonError::into_inner);
+                run_caught()
+            };
+            if !result.passed && !result.skipped {
+                failed.store(true, Ordering::SeqCst);
             }
+            done.push((index, result));
         }
+        done
+    };
+
+    let mut results = if jobs <= 1 {
+        worker()
+    } else {
+        thread::scope(|scope| {
+            let handles: Vec<_> = (0..jobs).map(|_| scope.spawn(worker)).collect();
+            // Suite panics are caught above, so a failed join is a scheduler bug
+            let joined = handles.into_iter().map(|h| h.join().unwrap_or_else(|e| resume_unwind(e)));
+            joined.flatten().collect()
+        })
+    };
+
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Cap a suite's timeout at the time left in the overall budget.
+///
+/// Returns None once the budget is spent.
+fn within_budget(suite: &TestSuiteConfig, deadline: Instant) -> Option<TestSuiteConfig> {
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    if remaining.is_zero() {
+        return None;
     }
+    let mut budgeted = suite.clone();
+    budgeted.timeout = Some(suite.timeout.map_or(remaining, |t| t.min(remaining)));
+    Some(budgeted)
+}
 
-    Some(SuiteResults { passed: all_passed, suites: results })
+/// Result for a suite that never started because the overall budget ran out.
+fn budget_exhausted(suite: &TestSuiteConfig, timeout: Duration) -> SuiteResult {
+    SuiteResult {
+        name: suite.name.clone().unwrap_or_else(|| suite.runner.clone()),
+        runner: suite.runner.clone(),
+        error: Some(format!(
+            "not started: tests timeout of {} exhausted",
+            format_duration_ms(timeout.as_millis() as u64)
+        )),
+        ..Default::default()
+    }
+}
+
+/// Result for a suite whose runner panicked.
+fn runner_panicked(suite: &TestSuiteConfig) -> SuiteResult {
+    SuiteResult {
+        name: suite.name.clone().unwrap_or_else(|| suite.runner.clone()),
+        runner: suite.runner.clone(),
+        error: Some("suite runner panicked".to_string()),
+        ..Default::default()
+    }
+}
+
+/// Verbose output for one suite, printed immediately or held until it completes.
+struct SuiteLog {
+    buffer: Option<String>,
+}
+
+impl SuiteLog {
+    fn direct() -> Self {
+        Self { buffer: None }
+    }
+
+    fn buffered() -> Self {
+        Self { buffer: Some(String::new()) }
+    }
+
+    fn line(&mut self, line: String) {
+        match self.buffer {
+            Some(ref mut buffer) => {
+                buffer.push_str(&line);
+                buffer.push('\n');
+            }
+            None => eprintln!("{}", line),
+        }
+    }
+
+    /// Print buffered output as a single block.
+    fn flush(self) {
+        if let Some(buffer) = self.buffer
+            && !buffer.is_empty()
+        {
+            eprint!("{}", buffer);
+        }
+    }
 }
 
 /// Execute a single test suite and return its result.
 pub fn run_single_suite(suite: &TestSuiteConfig, runner_ctx: &RunnerContext) -> SuiteResult {
+    execute_suite(suite, runner_ctx, &mut SuiteLog::direct())
+}
+
+fn execute_suite(
+    suite: &TestSuiteConfig,
+    runner_ctx: &RunnerContext,
+    log: &mut SuiteLog,
+) -> SuiteResult {
     let suite_name = suite.name.clone().unwrap_or_else(|| suite.runner.clone());
 
     // Verbose: show which suite is starting
     if runner_ctx.verbose {
-        eprintln!("  Running suite: {} ...", suite_name);
+        log.line(format!("  Running suite: {} ...", suite_name));
         if let Some(ref cmd) = suite.command {
-            eprintln!("    command: {}", cmd);
+            log.line(format!("    command: {}", cmd));
         } else {
-            eprintln!("    runner: {}", suite.runner);
+            log.line(format!("    runner: {}", suite.runner));
         }
     }
 
+    // Skip suites not affected by the changed files
+    let mut affected_packages = None;
+    let mut partial_run = false;
+    if let Some(ref changes) = runner_ctx.changes {
+        if let Selection::Skip(reason) = select_suite(suite, changes, runner_ctx.root) {
+            if runner_ctx.verbose {
+                log.line(format!("  Suite {:?} skipped: {}", suite_name, reason));
+            }
+            return SuiteResult {
+                name: suite_name,
+                runner: suite.runner.clone(),
+                skipped: true,
+                error: Some(reason),
+                ..Default::default()
+            };
+        }
+        affected_packages = narrowed_packages(suite, changes, runner_ctx.root);
+        if runner_ctx.verbose
+            && let Some(ref packages) = affected_packages
+        {
+            log.line(format!("    affected packages: {}", packages.join(", ")));
+        }
+        partial_run = affected_packages.is_some() || runner_filters_changes(suite);
+    }
+
     // Run setup command if configured
     if let Some(ref setup) = suite.setup
         && let Err(e) = run_setup_command(setup, runner_ctx.root)
     {
         // Setup failure skips the suite
         if runner_ctx.verbose {
-            eprintln!("  Suite {:?} skipped: setup failed", suite_name);
+            log.line(format!("  Suite {:?} skipped: setup failed", suite_name));
         }
         return SuiteResult {
             name: suite_name,
@@ -204,7 +416,7 @@ pub fn run_single_suite(suite: &TestSuiteConfig, runner_ctx: &RunnerContext) ->
         Some(r) => r,
         None => {
             if runner_ctx.verbose {
-                eprintln!("  Suite {:?} skipped: unknown runner", suite_name);
+                log.line(format!("  Suite {:?} skipped: unknown runner", suite_name));
             }
             return SuiteResult {
                 name: suite_name,
@@ -219,7 +431,7 @@ pub fn run_single_suite(suite: &TestSuiteConfig, runner_ctx: &RunnerContext) ->
     // Check runner availability
     if !runner.available(runner_ctx) {
         if runner_ctx.verbose {
-            eprintln!("  Suite {:?} skipped: runner not available", suite_name);
+            log.line(format!("  Suite {:?} skipped: runner not available", suite_name));
         }
         return SuiteResult {
             name: suite_name,
@@ -240,34 +452,39 @@ pub fn run_single_suite(suite: &TestSuiteConfig, runner_ctx: &RunnerContext) ->
     let avg_ms = run_result.avg_duration().map(|d| d.as_millis() as u64);
     let max_ms = run_result.slowest_test().map(|t| t.duration.as_millis() as u64);
     let max_test = run_result.slowest_test().map(|t| t.name.clone());
+    let envs = run_result.envs.iter().map(EnvSuiteResult::from_run).collect();
     let p50_ms = run_result.percentile_duration(50.0).map(|d| d.as_millis() as u64);
     let p90_ms = run_result.percentile_duration(90.0).map(|d| d.as_millis() as u64);
     let p99_ms = run_result.percentile_duration(99.0).map(|d| d.as_millis() as u64);
-    let coverage = run_result.coverage.clone();
-    let coverage_by_package = run_result.coverage_by_package.clone();
+    // Coverage from a narrowed run only reflects part of the project
+    let (coverage, coverage_by_package) = if partial_run {
+        (None, None)
+    } else {
+        (run_result.coverage.clone(), run_result.coverage_by_package.clone())
+    };
 
     // Verbose: show suite completion
     if runner_ctx.verbose {
         let exit_status = if run_result.passed { "passed" } else { "FAILED" };
         if run_result.passed {
-            eprintln!(
+            log.line(format!(
                 "  Suite {:?} completed: {}, {} tests, {}",
                 suite_name,
                 exit_status,
                 test_count,
                 format_duration_ms(total_ms),
-            );
+            ));
         } else {
             let failing =
                 test_count.saturating_sub(run_result.tests.iter().filter(|t| t.passed).count());
-            eprintln!(
+            log.line(format!(
                 "  Suite {:?} completed: {}, {} tests ({} failing), {}",
                 suite_name,
                 exit_status,
                 test_count,
                 failing,
                 format_duration_ms(total_ms),
-            );
+            ));
         }
     }
 
@@ -288,6 +505,8 @@ pub fn run_single_suite(suite: &TestSuiteConfig, runner_ctx: &RunnerContext) ->
         p99_ms,
         coverage,
         coverage_by_package,
+        affected_packages,
+        envs,
     }
 }
 
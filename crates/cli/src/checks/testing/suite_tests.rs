@@ -21,3 +21,180 @@ fn format_duration_ms_large_values() {
     assert_eq!(format_duration_ms(60000), "60.0s");
     assert_eq!(format_duration_ms(123456), "123.5s");
 }
+
+// =============================================================================
+// SCHEDULING
+// =============================================================================
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+fn suite(name: &str) -> TestSuiteConfig {
+    TestSuiteConfig {
+        runner: "custom".to_string(),
+        name: Some(name.to_string()),
+        path: None,
+        setup: None,
+        command: None,
+        targets: vec![],
+        ci: false,
+        max_total: None,
+        max_avg: None,
+        max_test: None,
+        timeout: None,
+        exclusive: false,
+        envs: vec![],
+    }
+}
+
+fn result(suite: &TestSuiteConfig, passed: bool) -> SuiteResult {
+    SuiteResult { name: suite.name.clone().unwrap(), passed, ..Default::default() }
+}
+
+fn names(results: &[SuiteResult]) -> Vec<&str> {
+    results.iter().map(|r| r.name.as_str()).collect()
+}
+
+#[test]
+fn effective_jobs_defaults_by_mode() {
+    assert_eq!(effective_jobs(None, false, 3), 1);
+    assert_eq!(effective_jobs(None, true, 3), 3);
+}
+
+#[test]
+fn effective_jobs_clamped_to_suite_count() {
+    assert_eq!(effective_jobs(Some(8), false, 3), 3);
+    assert_eq!(effective_jobs(Some(0), true, 3), 1);
+    assert_eq!(effective_jobs(Some(2), false, 3), 2);
+}
+
+#[test]
+fn schedule_returns_results_in_config_order() {
+    let configs: Vec<_> = ["slow", "medium", "fast"].iter().map(|n| suite(n)).collect();
+    let suites: Vec<_> = configs.iter().collect();
+
+    let results = schedule_suites(&suites, 3, false, |s| {
+        let delay = match s.name.as_deref() {
+            Some("slow") => 60,
+            Some("medium") => 30,
+            _ => 0,
+        };
+        std::thread::sleep(Duration::from_millis(delay));
+        result(s, true)
+    });
+
+    assert_eq!(names(&results), ["slow", "medium", "fast"]);
+}
+
+#[test]
+fn schedule_runs_suites_concurrently_up_to_jobs() {
+    let configs: Vec<_> = ["a", "b", "c", "d"].iter().map(|n| suite(n)).collect();
+    let suites: Vec<_> = configs.iter().collect();
+    let running = AtomicUsize::new(0);
+    let peak = AtomicUsize::new(0);
+
+    schedule_suites(&suites, 2, false, |s| {
+        let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+        peak.fetch_max(now, Ordering::SeqCst);
+        std::thread::sleep(Duration::from_millis(30));
+        running.fetch_sub(1, Ordering::SeqCst);
+        result(s, true)
+    });
+
+    assert_eq!(peak.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn panicking_suite_runner_fails_suite() {
+    let configs: Vec<_> = ["a", "boom", "c"].iter().map(|n| suite(n)).collect();
+    let suites: Vec<_> = configs.iter().collect();
+
+    let results = schedule_suites(&suites, 2, false, |s| {
+        if s.name.as_deref() == Some("boom") {
+            panic!("runner bug");
+        }
+        result(s, true)
+    });
+
+    assert_eq!(names(&results), ["a", "boom", "c"]);
+    assert!(!results[1].passed);
+    assert_eq!(results[1].error.as_deref(), Some("suite runner panicked"));
+}
+
+#[test]
+fn exclusive_suite_runs_alone() {
+    let mut configs: Vec<_> = ["a", "db", "b", "c"].iter().map(|n| suite(n)).collect();
+    configs[1].exclusive = true;
+    let suites: Vec<_> = configs.iter().collect();
+    let running = AtomicUsize::new(0);
+    let overlaps = AtomicUsize::new(0);
+
+    schedule_suites(&suites, 4, false, |s| {
+        running.fetch_add(1, Ordering::SeqCst);
+        std::thread::sleep(Duration::from_millis(30));
+        if s.exclusive && running.load(Ordering::SeqCst) > 1 {
+            overlaps.fetch_add(1, Ordering::SeqCst);
+        }
+        running.fetch_sub(1, Ordering::SeqCst);
+        result(s, true)
+    });
+
+    assert_eq!(overlaps.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn stop_on_failure_starts_no_new_suites() {
+    let configs: Vec<_> = ["a", "b", "c"].iter().map(|n| suite(n)).collect();
+    let suites: Vec<_> = configs.iter().collect();
+
+    let results = schedule_suites(&suites, 1, true, |s| result(s, s.name.as_deref() != Some("a")));
+
+    assert_eq!(names(&results), ["a"]);
+}
+
+#[test]
+fn without_stop_on_failure_all_suites_run() {
+    let configs: Vec<_> = ["a", "b", "c"].iter().map(|n| suite(n)).collect();
+    let suites: Vec<_> = configs.iter().collect();
+
+    let results = schedule_suites(&suites, 1, false, |s| result(s, s.name.as_deref() != Some("a")));
+
+    assert_eq!(names(&results), ["a", "b", "c"]);
+}
+
+// =============================================================================
+// TIME BUDGET
+// =============================================================================
+
+#[test]
+fn within_budget_caps_suite_timeout() {
+    let mut long = suite("long");
+    long.timeout = Some(Duration::from_secs(600));
+    let deadline = Instant::now() + Duration::from_secs(60);
+
+    let budgeted = within_budget(&long, deadline).unwrap();
+    assert!(budgeted.timeout.unwrap() <= Duration::from_secs(60));
+
+    let budgeted = within_budget(&suite("unbounded"), deadline).unwrap();
+    assert!(budgeted.timeout.unwrap() <= Duration::from_secs(60));
+}
+
+#[test]
+fn within_budget_keeps_shorter_suite_timeout() {
+    let mut short = suite("short");
+    short.timeout = Some(Duration::from_secs(5));
+    let deadline = Instant::now() + Duration::from_secs(60);
+
+    assert_eq!(within_budget(&short, deadline).unwrap().timeout, Some(Duration::from_secs(5)));
+}
+
+#[test]
+fn exhausted_budget_fails_suite() {
+    let unit = suite("unit");
+    assert!(within_budget(&unit, Instant::now()).is_none());
+
+    let result = budget_exhausted(&unit, Duration::from_secs(300));
+    assert!(!result.passed);
+    assert!(!result.skipped);
+    assert_eq!(result.error.as_deref(), Some("not started: tests timeout of 300.0s exhausted"));
+}
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Alfred Jean LLC
 
-//! Threshold checking for test coverage and timing.
+//! Threshold checking for test coverage, mutation score and timing.
 
 use std::collections::HashMap;
 
@@ -59,6 +59,28 @@ pub fn check_coverage_thresholds(
     violations
 }
 
+/// Check the mutation score against the configured minimum.
+pub fn check_mutation_threshold(
+    config: &TestsConfig,
+    tool: &str,
+    score: f64,
+) -> Vec<(Violation, bool)> {
+    let Some(ref mutation) = config.mutation else {
+        return Vec::new();
+    };
+    let Some(min) = mutation.min else {
+        return Vec::new();
+    };
+    if mutation.check == "off" || score >= min {
+        return Vec::new();
+    }
+
+    let advice = format!("Mutation score {:.1}% below minimum {:.1}%", score, min);
+    let v = Violation::file_only(format!("<mutation:{}>", tool), "mutation_below_min", advice)
+        .with_threshold(score as i64, min as i64);
+    vec![(v, mutation.check == "error")]
+}
+
 /// Check time thresholds for a suite.
 pub fn check_time_thresholds(
     config: &TestsConfig,
@@ -34,12 +34,20 @@ pub enum Command {
     Check(CheckArgs),
     /// Count lines of code by language
     Cloc(ClocArgs),
+    /// Inspect agent context files
+    Agents(AgentsArgs),
     /// Generate reports from stored metrics
     Report(ReportArgs),
     /// Initialize quench configuration
     Init(InitArgs),
     /// Read configuration reference documentation
     Config(ConfigArgs),
+    /// Serve checks as tools over the Model Context Protocol (stdio)
+    Mcp,
+    /// Install git hooks that run quench
+    Hooks(HooksArgs),
+    /// Update CHANGELOG.md from conventional commits
+    Changelog(ChangelogArgs),
     /// Generate shell completions
     Completions(CompletionsArgs),
 }
@@ -59,6 +67,71 @@ pub struct ClocArgs {
     pub output: OutputFormat,
 }
 
+#[derive(clap::Args)]
+pub struct AgentsArgs {
+    #[command(subcommand)]
+    pub command: AgentsCommand,
+}
+
+#[derive(Subcommand)]
+pub enum AgentsCommand {
+    /// Print the agent files loaded together in a directory
+    Context(AgentsContextArgs),
+}
+
+#[derive(clap::Args)]
+pub struct AgentsContextArgs {
+    /// Directory (or file) an agent is working in
+    #[arg(value_name = "PATH", default_value = ".")]
+    pub path: PathBuf,
+
+    /// Output format
+    #[arg(short, long, default_value = "text")]
+    pub output: OutputFormat,
+}
+
+#[derive(clap::Args)]
+pub struct HooksArgs {
+    #[command(subcommand)]
+    pub command: HooksCommand,
+}
+
+#[derive(Subcommand)]
+pub enum HooksCommand {
+    /// Install pre-commit and commit-msg hooks
+    Install(HooksInstallArgs),
+}
+
+#[derive(clap::Args)]
+pub struct HooksInstallArgs {
+    /// Hook manager: write git hooks, or print pre-commit or lefthook config
+    #[arg(long, default_value = "git")]
+    pub manager: HookManager,
+}
+
+#[derive(Clone, Copy, Default, clap::ValueEnum)]
+pub enum HookManager {
+    #[default]
+    Git,
+    PreCommit,
+    Lefthook,
+}
+
+#[derive(clap::Args)]
+pub struct ChangelogArgs {
+    /// List commits since this tag or ref (default: latest tag)
+    #[arg(long, value_name = "TAG")]
+    pub since: Option<String>,
+
+    /// Release under this version instead of Unreleased ("auto" bumps from commits)
+    #[arg(long, value_name = "VERSION")]
+    pub next_version: Option<String>,
+
+    /// Fail if CHANGELOG.md is missing entries instead of writing them
+    #[arg(long)]
+    pub check: bool,
+}
+
 #[derive(clap::Args)]
 pub struct CheckArgs {
     /// Files or directories to check
@@ -89,6 +162,10 @@ pub struct CheckArgs {
     #[arg(long)]
     pub staged: bool,
 
+    /// Validate a commit message file (commit-msg hook)
+    #[arg(long, value_name = "FILE")]
+    pub commit_msg: Option<PathBuf>,
+
     /// Bypass the cache (force fresh check)
     #[arg(long)]
     pub no_cache: bool,
@@ -7,11 +7,11 @@
 //! heuristics rather than full parsing. Consistent with how `cloc` works.
 
 /// Comment syntax for a language.
-pub struct CommentStyle {
+pub struct CommentStyle<'a> {
     /// Single-line comment prefixes (e.g. `["//", "#"]`).
-    pub line: &'static [&'static str],
+    pub line: &'a [&'a str],
     /// Block comment delimiter pairs `(open, close)`.
-    pub block: &'static [(&'static str, &'static str)],
+    pub block: &'a [(&'a str, &'a str)],
 }
 
 /// Line count breakdown.
@@ -23,17 +23,21 @@ pub struct LineCounts {
 }
 
 /// Return the comment style for a file extension, or `None` if unknown.
-pub fn comment_style(ext: &str) -> Option<CommentStyle> {
+pub fn comment_style(ext: &str) -> Option<CommentStyle<'static>> {
     match ext {
         "rs" => Some(CommentStyle { line: &["//"], block: &[("/*", "*/")] }),
         "go" => Some(CommentStyle { line: &["//"], block: &[("/*", "*/")] }),
-        "py" | "rb" | "sh" | "bash" | "zsh" | "fish" | "bats" | "r" => {
+        "py" | "rb" | "sh" | "bash" | "zsh" | "fish" | "bats" | "r" | "ex" | "exs" => {
             Some(CommentStyle { line: &["#"], block: &[] })
         }
-        "js" | "jsx" | "ts" | "tsx" | "mjs" | "mts" | "cjs" | "cts" | "java" | "kt" | "scala"
-        | "swift" | "c" | "cpp" | "h" | "hpp" | "cs" | "m" | "mm" => {
-            Some(CommentStyle { line: &["//"], block: &[("/*", "*/")] })
+        "js" | "jsx" | "ts" | "tsx" | "mjs" | "mts" | "cjs" | "cts" | "java" | "kt" | "kts"
+        | "scala" | "swift" | "c" | "cpp" | "cc" | "cxx" | "h" | "hpp" | "hh" | "hxx" | "cs"
+        | "m" | "mm" => Some(CommentStyle { line: &["//"], block: &[("/*", "*/")] }),
+        "erl" | "hrl" => Some(CommentStyle { line: &["%"], block: &[] }),
+        "tf" | "tfvars" | "hcl" => {
+            Some(CommentStyle { line: &["#", "//"], block: &[("/*", "*/")] })
         }
+        "dockerfile" => Some(CommentStyle { line: &["#"], block: &[] }),
         "lua" => Some(CommentStyle { line: &["--"], block: &[("--[[", "]]")] }),
         "sql" => Some(CommentStyle { line: &["--"], block: &[("/*", "*/")] }),
         "php" => Some(CommentStyle { line: &["//", "#"], block: &[("/*", "*/")] }),
@@ -56,7 +60,7 @@ pub fn comment_style(ext: &str) -> Option<CommentStyle> {
 /// is counted as code, consistent with `cloc` behavior.
 pub fn count_lines(content: &str, style: &CommentStyle) -> LineCounts {
     let mut counts = LineCounts::default();
-    let mut in_block: Option<&'static str> = None; // The close delimiter we're looking for
+    let mut in_block: Option<&str> = None; // The close delimiter we're looking for
 
     for line in content.lines() {
         let trimmed = line.trim();
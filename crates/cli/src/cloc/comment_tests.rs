@@ -111,6 +111,46 @@ fn php_dual_line_comments() {
     assert_eq!(counts, LineCounts { blank: 0, comment: 3, code: 1 });
 }
 
+// =============================================================================
+// BEAM comment detection
+// =============================================================================
+
+#[test]
+fn elixir_and_erlang_line_comments() {
+    let style = comment_style("exs").unwrap();
+    let content = "# Fixtures
+defmodule FooTest do
+end
+";
+    assert_eq!(count_lines(content, &style), LineCounts { blank: 0, comment: 1, code: 2 });
+
+    let style = comment_style("erl").unwrap();
+    let content = "%% @doc Entry point
+-module(foo).
+";
+    assert_eq!(count_lines(content, &style), LineCounts { blank: 0, comment: 1, code: 1 });
+}
+
+// =============================================================================
+// Infrastructure-as-code comment detection
+// =============================================================================
+
+#[test]
+fn terraform_hash_slash_and_block_comments() {
+    let style = comment_style("tf").unwrap();
+    let content = "# Network\n// legacy\n/* block\n */\nresource \"aws_vpc\" \"main\" {}\n";
+    let counts = count_lines(content, &style);
+    assert_eq!(counts, LineCounts { blank: 0, comment: 4, code: 1 });
+}
+
+#[test]
+fn dockerfile_hash_comments() {
+    let style = comment_style("dockerfile").unwrap();
+    let content = "# syntax=docker/dockerfile:1\nFROM alpine:3.20\n\nRUN apk add curl\n";
+    let counts = count_lines(content, &style);
+    assert_eq!(counts, LineCounts { blank: 1, comment: 1, code: 2 });
+}
+
 // =============================================================================
 // Edge cases
 // =============================================================================
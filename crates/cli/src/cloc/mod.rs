@@ -6,8 +6,12 @@
 //! Provides file-level metrics (blank, comment, code, tokens) and
 //! per-language comment detection.
 
+use std::path::Path;
+
 pub mod comment;
 
+pub use comment::CommentStyle;
+
 /// Metrics for a single file.
 pub struct FileMetrics {
     /// Total line count (matches `wc -l`).
@@ -29,12 +33,19 @@ pub struct FileMetrics {
 /// If no comment style is known for the extension, all non-blank lines are
 /// counted as code (matching `cloc` behavior for unknown languages).
 pub fn count_file_metrics(content: &str, ext: &str) -> FileMetrics {
+    count_metrics_with_style(content, comment::comment_style(ext).as_ref())
+}
+
+/// Count metrics from file content with an explicit comment style.
+///
+/// Used for declarative adapters, whose comment syntax comes from configuration.
+pub fn count_metrics_with_style(content: &str, style: Option<&CommentStyle>) -> FileMetrics {
     let lines = content.lines().count();
-    let tokens = content.chars().count() / 4;
+    let tokens = crate::tokens::approx(content);
 
-    let (blank, comment_count, code) = match comment::comment_style(ext) {
+    let (blank, comment_count, code) = match style {
         Some(style) => {
-            let lc = comment::count_lines(content, &style);
+            let lc = comment::count_lines(content, style);
             (lc.blank, lc.comment, lc.code)
         }
         None => {
@@ -55,6 +66,19 @@ pub fn count_file_metrics(content: &str, ext: &str) -> FileMetrics {
     }
 }
 
+/// Extension used for language lookup, lowercased.
+///
+/// Files identified by name rather than extension (`Dockerfile`, `Dockerfile.dev`,
+/// `Containerfile`) map to the `dockerfile` pseudo-extension.
+pub fn file_extension(path: &Path) -> Option<String> {
+    let name = path.file_name()?.to_str()?;
+    let stem = name.split_once('.').map_or(name, |(stem, _)| stem);
+    if matches!(stem, "Dockerfile" | "Containerfile") {
+        return Some("dockerfile".to_string());
+    }
+    path.extension().and_then(|e| e.to_str()).map(str::to_lowercase)
+}
+
 /// Map file extension to human-readable language name.
 pub fn language_name(ext: &str) -> &str {
     match ext {
@@ -65,11 +89,11 @@ pub fn language_name(ext: &str) -> &str {
         "js" | "jsx" | "mjs" | "cjs" => "JavaScript",
         "ts" | "tsx" | "mts" | "cts" => "TypeScript",
         "java" => "Java",
-        "kt" => "Kotlin",
+        "kt" | "kts" => "Kotlin",
         "scala" => "Scala",
         "swift" => "Swift",
         "c" => "C",
-        "cpp" | "hpp" => "C++",
+        "cpp" | "cc" | "cxx" | "hpp" | "hh" | "hxx" => "C++",
         "h" => "C/C++ Header",
         "cs" => "C#",
         "m" => "Objective-C",
@@ -78,8 +102,13 @@ pub fn language_name(ext: &str) -> &str {
         "ps1" => "PowerShell",
         "bat" | "cmd" => "Batch",
         "php" => "PHP",
+        "ex" | "exs" => "Elixir",
+        "erl" | "hrl" => "Erlang",
         "lua" => "Lua",
         "sql" => "SQL",
+        "tf" | "tfvars" => "Terraform",
+        "hcl" => "HCL",
+        "dockerfile" => "Dockerfile",
         "r" => "R",
         "pl" | "pm" => "Perl",
         "vue" => "Vue",
@@ -94,10 +123,14 @@ pub fn is_text_extension(ext: &str) -> bool {
         ext,
         // Systems languages
         "rs" | "c" | "cpp" | "h" | "hpp" | "go"
+        // C++ extension variants
+        | "cc" | "cxx" | "hh" | "hxx"
         // JVM languages
-        | "java" | "kt" | "scala"
+        | "java" | "kt" | "kts" | "scala"
         // Dynamic languages
         | "py" | "rb" | "php" | "lua" | "pl" | "pm" | "r"
+        // BEAM languages
+        | "ex" | "exs" | "erl" | "hrl"
         // JavaScript/TypeScript
         | "js" | "ts" | "jsx" | "tsx"
         // Module variants
@@ -112,6 +145,8 @@ pub fn is_text_extension(ext: &str) -> bool {
         | "vue" | "svelte"
         // SQL
         | "sql"
+        // Infrastructure as code
+        | "tf" | "tfvars" | "hcl" | "dockerfile"
     )
 }
 
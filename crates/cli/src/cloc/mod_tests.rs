@@ -26,6 +26,16 @@ fn count_file_metrics_python() {
     assert_eq!(m.code, 2);
 }
 
+#[test]
+fn count_file_metrics_kotlin_script() {
+    let content = "// build script\nplugins {\n    /* the JVM plugin */\n    kotlin(\"jvm\")\n}\n";
+    assert!(is_text_extension("kts"));
+    let m = count_file_metrics(content, "kts");
+    assert_eq!(m.comment, 2);
+    assert_eq!(m.code, 3);
+    assert_eq!(language_name("kts"), "Kotlin");
+}
+
 #[test]
 fn count_file_metrics_unknown_extension() {
     let content = "line 1\n\nline 3\n";
@@ -55,6 +65,8 @@ fn language_name_known() {
     assert_eq!(language_name("tsx"), "TypeScript");
     assert_eq!(language_name("sh"), "Shell");
     assert_eq!(language_name("bash"), "Shell");
+    assert_eq!(language_name("cc"), "C++");
+    assert_eq!(language_name("hxx"), "C++");
 }
 
 #[test]
@@ -72,6 +84,13 @@ fn is_text_extension_known() {
     assert!(is_text_extension("sh"));
     assert!(is_text_extension("mjs"));
     assert!(is_text_extension("cts"));
+    assert!(is_text_extension("cc"));
+    assert!(is_text_extension("hh"));
+    assert!(is_text_extension("ex"));
+    assert!(is_text_extension("exs"));
+    assert!(is_text_extension("tf"));
+    assert!(is_text_extension("hcl"));
+    assert!(is_text_extension("dockerfile"));
 }
 
 #[test]
@@ -81,3 +100,21 @@ fn is_text_extension_unknown() {
     assert!(!is_text_extension("json"));
     assert!(!is_text_extension("yaml"));
 }
+
+#[test]
+fn file_extension_maps_dockerfiles_by_name() {
+    assert_eq!(file_extension(Path::new("Dockerfile")).as_deref(), Some("dockerfile"));
+    assert_eq!(file_extension(Path::new("svc/Dockerfile.dev")).as_deref(), Some("dockerfile"));
+    assert_eq!(file_extension(Path::new("Containerfile")).as_deref(), Some("dockerfile"));
+    assert_eq!(file_extension(Path::new("api.Dockerfile")).as_deref(), Some("dockerfile"));
+    assert_eq!(file_extension(Path::new("infra/main.TF")).as_deref(), Some("tf"));
+    assert_eq!(file_extension(Path::new("Makefile")), None);
+}
+
+#[test]
+fn count_file_metrics_dockerfile() {
+    let m = count_file_metrics("# base\nFROM alpine\n", "dockerfile");
+    assert_eq!(m.comment, 1);
+    assert_eq!(m.code, 1);
+    assert_eq!(language_name("dockerfile"), "Dockerfile");
+}
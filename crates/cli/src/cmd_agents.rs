@@ -0,0 +1,139 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `quench agents` command implementation.
+//!
+//! `quench agents context <PATH>` prints the agent files an agent loads
+//! together when working in a directory, with their combined token count.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, bail};
+
+use quench::adapter::project::apply_language_defaults;
+use quench::checks::agents::context::{ContextFile, ContextSources};
+use quench::cli::{AgentsArgs, AgentsCommand, AgentsContextArgs, OutputFormat};
+use quench::color;
+use quench::config;
+use quench::discovery;
+use quench::error::ExitCode;
+
+/// Run the `quench agents` command.
+pub fn run(args: &AgentsArgs) -> anyhow::Result<ExitCode> {
+    match &args.command {
+        AgentsCommand::Context(args) => run_context(args),
+    }
+}
+
+fn run_context(args: &AgentsContextArgs) -> anyhow::Result<ExitCode> {
+    let cwd = std::env::current_dir()?;
+
+    // The project root holds quench.toml; fall back to the working directory
+    let config_path = discovery::find_config(&cwd);
+    let root = config_path.as_deref().and_then(Path::parent).unwrap_or(&cwd).to_path_buf();
+    let mut config = match &config_path {
+        Some(path) => config::load_with_warnings(path)?,
+        None => config::Config::default(),
+    };
+    apply_language_defaults(&root, &mut config);
+
+    let target = cwd.join(&args.path);
+    let target =
+        target.canonicalize().with_context(|| format!("cannot read {}", args.path.display()))?;
+    let root = root.canonicalize()?;
+    let Ok(rel) = target.strip_prefix(&root) else {
+        bail!("{} is outside the project root {}", args.path.display(), root.display());
+    };
+
+    // A file path selects its directory, matching rules against that file only
+    let (dir, files) = if target.is_file() {
+        let dir = rel.parent().unwrap_or(Path::new("")).to_path_buf();
+        (dir, vec![rel.to_path_buf()])
+    } else {
+        (rel.to_path_buf(), list_files(&root, rel))
+    };
+
+    let agents = &config.check.agents;
+    let sources = ContextSources::collect(
+        &root,
+        &config.project.packages,
+        &agents.files,
+        agents.tokenizer,
+        [dir.as_path()],
+    );
+    let loaded = sources.assemble(&dir, &files);
+    let tokens: usize = loaded.iter().map(|f| f.tokens).sum();
+
+    match args.output {
+        OutputFormat::Json => print_json(&dir, &loaded, tokens, agents.effective_max_tokens)?,
+        _ => print_text(&loaded, tokens, agents.effective_max_tokens, agents.tokenizer.name()),
+    }
+
+    Ok(ExitCode::Success)
+}
+
+/// Files directly inside `dir`, relative to `root`.
+fn list_files(root: &Path, dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(root.join(dir)) else {
+        return Vec::new();
+    };
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .filter(|e| e.path().is_file())
+        .map(|e| dir.join(e.file_name()))
+        .collect();
+    files.sort();
+    files
+}
+
+/// Print each file under a header, then the combined token count.
+fn print_text(loaded: &[&ContextFile], tokens: usize, max: Option<usize>, tokenizer: &str) {
+    for file in loaded {
+        let header =
+            format!("==> {} ({}, {} tokens) <==", file.path.display(), file.reason, file.tokens);
+        println!("{}", color::header(&header));
+        println!("{}", file.content.trim_end());
+        println!();
+    }
+
+    let files = match loaded.len() {
+        1 => "1 file".to_string(),
+        n => format!("{n} files"),
+    };
+    let budget = match max {
+        Some(max) => format!(", effective_max_tokens: {max}"),
+        None => String::new(),
+    };
+    println!(
+        "{}",
+        color::header(&format!("Total: {tokens} tokens in {files} ({tokenizer}{budget})"))
+    );
+}
+
+fn print_json(
+    dir: &Path,
+    loaded: &[&ContextFile],
+    tokens: usize,
+    max: Option<usize>,
+) -> anyhow::Result<()> {
+    let files: Vec<serde_json::Value> = loaded
+        .iter()
+        .map(|f| {
+            serde_json::json!({
+                "path": f.path,
+                "reason": f.reason,
+                "tokens": f.tokens,
+                "content": f.content,
+            })
+        })
+        .collect();
+    let dir = if dir.as_os_str().is_empty() { Path::new(".") } else { dir };
+    let output = serde_json::json!({
+        "directory": dir,
+        "files": files,
+        "tokens": tokens,
+        "effective_max_tokens": max,
+    });
+    println!("{}", serde_json::to_string_pretty(&output)?);
+    Ok(())
+}
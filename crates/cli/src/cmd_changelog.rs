@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `quench changelog` command implementation.
+//!
+//! Adds entries for the conventional commits since the latest tag to
+//! `CHANGELOG.md`, or checks that they are all there.
+
+use quench::changelog::{self, UNRELEASED, Version};
+use quench::cli::ChangelogArgs;
+use quench::error::ExitCode;
+use quench::git;
+
+/// Changelog file, relative to the current directory.
+const CHANGELOG: &str = "CHANGELOG.md";
+
+/// Run the `quench changelog` command.
+pub fn run(args: &ChangelogArgs) -> anyhow::Result<ExitCode> {
+    let cwd = std::env::current_dir()?;
+    let since = match &args.since {
+        Some(since) => Some(since.clone()),
+        None => git::latest_tag(&cwd)?,
+    };
+    let commits = match &since {
+        Some(since) => git::get_commits_since(&cwd, since)?,
+        None => git::get_all_commits(&cwd)?,
+    };
+    let entries = changelog::entries(&commits);
+
+    let path = cwd.join(CHANGELOG);
+    let existing = std::fs::read_to_string(&path).ok();
+    let missing = changelog::missing(existing.as_deref().unwrap_or_default(), &entries);
+
+    if args.check {
+        if missing.is_empty() {
+            println!("{CHANGELOG} is up to date");
+            return Ok(ExitCode::Success);
+        }
+        println!("{CHANGELOG} is missing {} entries:", missing.len());
+        for entry in &missing {
+            println!("  {} {}", entry.hash, entry.line());
+        }
+        return Ok(ExitCode::CheckFailed);
+    }
+
+    let release = match args.next_version.as_deref() {
+        None => UNRELEASED.to_string(),
+        Some("auto") => {
+            // Every entry is already in a released version, e.g. from an earlier run
+            if missing.is_empty()
+                && existing.as_deref().is_some_and(|c| !changelog::has_unreleased(c))
+            {
+                println!("{CHANGELOG} is up to date");
+                return Ok(ExitCode::Success);
+            }
+            let Some(bump) = changelog::bump(&entries) else {
+                println!("Nothing to release");
+                return Ok(ExitCode::Success);
+            };
+            let current = since
+                .as_deref()
+                .and_then(Version::parse)
+                .or_else(|| existing.as_deref().and_then(changelog::latest_version))
+                .unwrap_or_default();
+            current.bump(bump).to_string()
+        }
+        Some(version) => match Version::parse(version) {
+            Some(version) => version.to_string(),
+            None => {
+                eprintln!("invalid --next-version: {version} (expected auto or MAJOR.MINOR.PATCH)");
+                return Ok(ExitCode::ConfigError);
+            }
+        },
+    };
+
+    let date = chrono::Local::now().format("%Y-%m-%d").to_string();
+    let current = existing.unwrap_or_else(|| changelog::HEADER.to_string());
+    let updated = changelog::insert(&current, &release, Some(&date), &missing);
+    if updated == current && path.exists() {
+        println!("{CHANGELOG} is up to date");
+        return Ok(ExitCode::Success);
+    }
+
+    std::fs::write(&path, updated)?;
+    if missing.is_empty() {
+        println!("Created [{release}] in {CHANGELOG}");
+    } else {
+        println!("Added {} entries to [{release}] in {CHANGELOG}", missing.len());
+    }
+    Ok(ExitCode::Success)
+}
@@ -68,7 +68,7 @@ pub fn run(_cli: &Cli, args: &CheckArgs) -> anyhow::Result<ExitCode> {
     verbose::discovery(&verbose, args, &files, &stats);
 
     // === Setup Phase ===
-    let checks_list = checks::filter_checks(&args.enabled_checks(), &args.disabled_checks());
+    let checks_list = checks::filter_checks(&enabled_checks(args), &args.disabled_checks());
     let base_branch = resolve_base_branch(args, &root);
     let changed_files = resolve_changed_files(args, &root, &base_branch, &verbose);
 
@@ -84,6 +84,7 @@ pub fn run(_cli: &Cli, args: &CheckArgs) -> anyhow::Result<ExitCode> {
         ci_mode: args.ci,
         base_branch: base_branch.clone(),
         staged: args.staged,
+        commit_msg: args.commit_msg.as_ref().map(|path| cwd.join(path)),
         verbose: verbose.is_enabled(),
     });
 
@@ -170,9 +171,28 @@ fn validate_flags(args: &CheckArgs) -> Option<ExitCode> {
         eprintln!("--staged and --base cannot be used together");
         return Some(ExitCode::ConfigError);
     }
+    if let Some(path) = &args.commit_msg {
+        if args.staged || args.base.is_some() {
+            eprintln!("--commit-msg cannot be used with --staged or --base");
+            return Some(ExitCode::ConfigError);
+        }
+        if !path.is_file() {
+            eprintln!("commit message file not found: {}", path.display());
+            return Some(ExitCode::ConfigError);
+        }
+    }
     None
 }
 
+/// Checks enabled by flags; `--commit-msg` alone runs only the git check.
+fn enabled_checks(args: &CheckArgs) -> Vec<String> {
+    let enabled = args.enabled_checks();
+    if args.commit_msg.is_some() && enabled.is_empty() {
+        return vec!["git".to_string()];
+    }
+    enabled
+}
+
 fn setup_verbose(args: &CheckArgs) -> VerboseLogger {
     let verbose_enabled = args.ci || args.verbose || quench::env::quench_debug();
     VerboseLogger::new(verbose_enabled)
@@ -191,7 +211,7 @@ fn load_config(
     root: &std::path::Path,
 ) -> anyhow::Result<(config::Config, Option<std::path::PathBuf>)> {
     let config_path = discovery::find_config(root);
-    let config = match &config_path {
+    let mut config = match &config_path {
         Some(path) => {
             tracing::debug!("loading config from {}", path.display());
             config::load_with_warnings(path)?
@@ -201,6 +221,7 @@ fn load_config(
             config::Config::default()
         }
     };
+    config::load_adapter_dir(root, &mut config)?;
     tracing::trace!("check command starting");
     Ok((config, config_path))
 }
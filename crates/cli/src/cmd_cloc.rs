@@ -10,7 +10,9 @@ use std::collections::HashMap;
 use std::path::Path;
 
 use quench::adapter::project::apply_language_defaults;
-use quench::adapter::{AdapterRegistry, FileKind, RustAdapter, patterns::LanguageDefaults};
+use quench::adapter::{
+    Adapter, AdapterRegistry, FileKind, RustAdapter, patterns::LanguageDefaults,
+};
 use quench::cli::{ClocArgs, OutputFormat};
 use quench::cloc;
 use quench::color::{self, codes};
@@ -45,6 +47,7 @@ pub fn run(args: &ClocArgs) -> anyhow::Result<ExitCode> {
         Some(path) => config::load_with_warnings(&path)?,
         None => config::Config::default(),
     };
+    config::load_adapter_dir(&root, &mut config)?;
 
     // Apply language defaults (excludes + package auto-detection)
     let mut exclude_patterns = apply_language_defaults(&root, &mut config);
@@ -104,12 +107,10 @@ pub fn run(args: &ClocArgs) -> anyhow::Result<ExitCode> {
     let packages = &config.project.packages;
 
     for file in rx {
-        let ext = match file.path.extension().and_then(|e| e.to_str()) {
-            Some(e) => e.to_lowercase(),
-            None => continue,
-        };
+        let declarative = registry.declarative_for(&file.path);
+        let ext = cloc::file_extension(&file.path).unwrap_or_default();
 
-        if !cloc::is_text_extension(&ext) {
+        if declarative.is_none() && !cloc::is_text_extension(&ext) {
             continue;
         }
 
@@ -129,8 +130,15 @@ pub fn run(args: &ClocArgs) -> anyhow::Result<ExitCode> {
             continue;
         };
 
-        let lang = cloc::language_name(&ext).to_string();
-        let metrics = cloc::count_file_metrics(text, &ext);
+        let (lang, metrics) = match declarative {
+            Some(adapter) => (
+                adapter.name().to_string(),
+                adapter
+                    .with_comment_style(|style| cloc::count_metrics_with_style(text, Some(style)))
+                    .unwrap_or_else(|| cloc::count_metrics_with_style(text, None)),
+            ),
+            None => (cloc::language_name(&ext).to_string(), cloc::count_file_metrics(text, &ext)),
+        };
 
         // Handle Rust cfg_test splitting
         if let Some(adapter) = rust_adapter.as_ref()
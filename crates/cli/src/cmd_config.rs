@@ -18,15 +18,35 @@ const TEMPLATES: &[(&str, &str)] = &[
     ("agents", include_str!("../../../docs/specs/templates/guide.agents.md")),
     ("build", include_str!("../../../docs/specs/templates/guide.build.md")),
     ("cloc", include_str!("../../../docs/specs/templates/guide.cloc.md")),
+    ("cpp", include_str!("../../../docs/specs/templates/guide.cpp.md")),
+    ("c", include_str!("../../../docs/specs/templates/guide.cpp.md")),
+    ("c++", include_str!("../../../docs/specs/templates/guide.cpp.md")),
+    ("csharp", include_str!("../../../docs/specs/templates/guide.csharp.md")),
+    ("cs", include_str!("../../../docs/specs/templates/guide.csharp.md")),
+    ("dotnet", include_str!("../../../docs/specs/templates/guide.csharp.md")),
+    ("elixir", include_str!("../../../docs/specs/templates/guide.elixir.md")),
+    ("ex", include_str!("../../../docs/specs/templates/guide.elixir.md")),
+    ("mix", include_str!("../../../docs/specs/templates/guide.elixir.md")),
+    ("php", include_str!("../../../docs/specs/templates/guide.php.md")),
+    ("composer", include_str!("../../../docs/specs/templates/guide.php.md")),
+    ("swift", include_str!("../../../docs/specs/templates/guide.swift.md")),
+    ("spm", include_str!("../../../docs/specs/templates/guide.swift.md")),
     ("docs", include_str!("../../../docs/specs/templates/guide.docs.md")),
     ("escapes", include_str!("../../../docs/specs/templates/guide.escapes.md")),
     ("git", include_str!("../../../docs/specs/templates/guide.git.md")),
     ("golang", include_str!("../../../docs/specs/templates/guide.golang.md")),
+    ("iac", include_str!("../../../docs/specs/templates/guide.iac.md")),
+    ("terraform", include_str!("../../../docs/specs/templates/guide.iac.md")),
+    ("docker", include_str!("../../../docs/specs/templates/guide.iac.md")),
     ("go", include_str!("../../../docs/specs/templates/guide.golang.md")),
     ("javascript", include_str!("../../../docs/specs/templates/guide.javascript.md")),
     ("js", include_str!("../../../docs/specs/templates/guide.javascript.md")),
     ("typescript", include_str!("../../../docs/specs/templates/guide.javascript.md")),
     ("ts", include_str!("../../../docs/specs/templates/guide.javascript.md")),
+    ("java", include_str!("../../../docs/specs/templates/guide.jvm.md")),
+    ("jvm", include_str!("../../../docs/specs/templates/guide.jvm.md")),
+    ("kotlin", include_str!("../../../docs/specs/templates/guide.jvm.md")),
+    ("kt", include_str!("../../../docs/specs/templates/guide.jvm.md")),
     ("license", include_str!("../../../docs/specs/templates/guide.license.md")),
     ("python", include_str!("../../../docs/specs/templates/guide.python.md")),
     ("py", include_str!("../../../docs/specs/templates/guide.python.md")),
@@ -40,6 +60,11 @@ const TEMPLATES: &[(&str, &str)] = &[
     ("tests", include_str!("../../../docs/specs/templates/guide.tests.md")),
 ];
 
+/// The configuration guide for `feature` (a check or language name).
+pub fn template(feature: &str) -> Option<&'static str> {
+    TEMPLATES.iter().find(|(name, _)| *name == feature).map(|(_, content)| *content)
+}
+
 pub fn run(args: &ConfigArgs) -> Result<ExitCode> {
     let feature = match &args.feature {
         Some(f) => f.to_lowercase(),
@@ -57,17 +82,23 @@ pub fn run(args: &ConfigArgs) -> Result<ExitCode> {
             );
             println!(
                 "  Languages: {}",
-                color::literal("golang (go), javascript (js/ts), python (py), ruby (rb),")
+                color::literal(
+                    "cpp (c/c++), csharp (cs/dotnet), elixir (ex/mix), golang (go), javascript (js/ts),"
+                )
             );
-            println!("             {}", color::literal("rust (rs), shell (sh/bash)"));
+            println!(
+                "             {}",
+                color::literal(
+                    "jvm (java/kotlin/kt), php (composer), python (py), ruby (rb), rust (rs),"
+                )
+            );
+            println!("             {}", color::literal("shell (sh/bash), swift (spm)"));
+            println!("  Infra:     {}", color::literal("iac (terraform/docker)"));
             return Ok(ExitCode::Success);
         }
     };
 
-    // Find the template
-    let template = TEMPLATES.iter().find(|(name, _)| *name == feature).map(|(_, content)| *content);
-
-    match template {
+    match template(&feature) {
         Some(content) => {
             let stdout = std::io::stdout();
             let mut handle = stdout.lock();
@@ -80,7 +111,8 @@ pub fn run(args: &ConfigArgs) -> Result<ExitCode> {
                 "Unknown feature '{}'\n\n\
                 Available features:\n\
                   Checks:  agents, build, cloc, docs, escapes, git, license, tests\n\
-                  Languages: golang (go), javascript (js/ts/typescript), python (py), ruby (rb), rust (rs), shell (sh/bash)",
+                  Languages: cpp (c/c++), csharp (cs/dotnet), elixir (ex/mix), golang (go), javascript (js/ts/typescript), jvm (java/kotlin/kt), php (composer), python (py), ruby (rb), rust (rs), shell (sh/bash), swift (spm)\n\
+                  Infra: iac (terraform/docker)",
                 feature
             );
         }
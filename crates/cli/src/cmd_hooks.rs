@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `quench hooks` command implementation.
+//!
+//! `quench hooks install` writes `pre-commit` and `commit-msg` git hooks
+//! that run quench, or prints equivalent pre-commit or lefthook config.
+
+use quench::cli::{HookManager, HooksArgs, HooksCommand, HooksInstallArgs};
+use quench::error::ExitCode;
+use quench::hooks::{self, HOOKS, HookAction};
+
+/// Run the `quench hooks` command.
+pub fn run(args: &HooksArgs) -> anyhow::Result<ExitCode> {
+    match &args.command {
+        HooksCommand::Install(args) => run_install(args),
+    }
+}
+
+fn run_install(args: &HooksInstallArgs) -> anyhow::Result<ExitCode> {
+    match args.manager {
+        HookManager::Git => {}
+        HookManager::PreCommit => {
+            print!("{}", hooks::PRE_COMMIT_CONFIG);
+            return Ok(ExitCode::Success);
+        }
+        HookManager::Lefthook => {
+            print!("{}", hooks::LEFTHOOK_CONFIG);
+            return Ok(ExitCode::Success);
+        }
+    }
+
+    let cwd = std::env::current_dir()?;
+    let dir = hooks::hooks_dir(&cwd)?;
+    let display_dir = dir.strip_prefix(&cwd).unwrap_or(&dir);
+
+    for hook in HOOKS {
+        let path = display_dir.join(hook.name);
+        match hooks::install_hook(&dir, hook)? {
+            HookAction::Created => println!("Installed {}", path.display()),
+            HookAction::Chained(previous) => {
+                let previous = previous.strip_prefix(&cwd).unwrap_or(&previous);
+                println!(
+                    "Installed {} (runs existing hook, moved to {})",
+                    path.display(),
+                    previous.display()
+                );
+            }
+            HookAction::Updated => println!("Updated {}", path.display()),
+            HookAction::Unchanged => println!("{} is up to date", path.display()),
+        }
+    }
+
+    Ok(ExitCode::Success)
+}
@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `quench mcp` command implementation.
+//!
+//! Serves quench to coding agents over the Model Context Protocol: JSON-RPC
+//! 2.0 messages, one per line, on stdin and stdout. Tools run the quench
+//! binary in a child process, so results use the same JSON schema as
+//! `quench check -o json` and nothing the checks print can corrupt the
+//! protocol stream.
+
+mod tools;
+
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+
+use serde_json::{Value, json};
+
+use quench::error::ExitCode;
+
+/// Protocol versions this server speaks, newest first.
+const PROTOCOL_VERSIONS: &[&str] = &["2025-06-18", "2025-03-26", "2024-11-05"];
+
+/// JSON-RPC error codes.
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+/// Guidance sent to the client on initialization.
+const INSTRUCTIONS: &str = "Run `check` after editing code and fix the reported violations. \
+Each violation includes advice; call `explain_violation` for the matching configuration guide. \
+`fix` previews (dry_run) or applies automatic fixes.";
+
+/// Run the `quench mcp` command until stdin closes.
+pub fn run() -> anyhow::Result<ExitCode> {
+    let server = Server { exe: std::env::current_exe()?, cwd: std::env::current_dir()? };
+
+    let stdin = std::io::stdin().lock();
+    let mut stdout = std::io::stdout().lock();
+    for line in stdin.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(response) = server.handle(&line) {
+            writeln!(stdout, "{response}")?;
+            stdout.flush()?;
+        }
+    }
+
+    Ok(ExitCode::Success)
+}
+
+struct Server {
+    /// The quench binary, run for each tool call.
+    exe: PathBuf,
+    /// Working directory tool paths resolve against.
+    cwd: PathBuf,
+}
+
+impl Server {
+    /// Handle one message, returning the response for requests.
+    fn handle(&self, line: &str) -> Option<Value> {
+        let message: Value = match serde_json::from_str(line) {
+            Ok(message) => message,
+            Err(e) => return Some(error(Value::Null, PARSE_ERROR, &format!("parse error: {e}"))),
+        };
+        let Some(method) = message.get("method").and_then(Value::as_str) else {
+            // Responses to server requests are never expected; ignore them
+            if message.get("result").is_some() || message.get("error").is_some() {
+                return None;
+            }
+            let id = message.get("id").cloned().unwrap_or(Value::Null);
+            return Some(error(id, INVALID_REQUEST, "invalid request: missing method"));
+        };
+        // Notifications (no id) get no response
+        let id = message.get("id").cloned()?;
+        let params = message.get("params").cloned().unwrap_or_else(|| json!({}));
+
+        let result = match method {
+            "initialize" => Ok(initialize(&params)),
+            "ping" => Ok(json!({})),
+            "tools/list" => Ok(json!({ "tools": tools::definitions() })),
+            "tools/call" => self.call_tool(&params),
+            _ => Err((METHOD_NOT_FOUND, format!("method not found: {method}"))),
+        };
+
+        Some(match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err((code, message)) => error(id, code, &message),
+        })
+    }
+
+    fn call_tool(&self, params: &Value) -> Result<Value, (i64, String)> {
+        let Some(name) = params.get("name").and_then(Value::as_str) else {
+            return Err((INVALID_PARAMS, "missing tool name".to_string()));
+        };
+        let empty = json!({});
+        let args = params.get("arguments").unwrap_or(&empty);
+        if !args.is_object() {
+            return Err((INVALID_PARAMS, "tool arguments must be an object".to_string()));
+        }
+
+        // Argument and execution errors are tool results the agent can act on
+        match tools::call(&self.exe, &self.cwd, name, args) {
+            None => Err((INVALID_PARAMS, format!("unknown tool: {name}"))),
+            Some(Ok(output)) => Ok(output.into_result()),
+            Some(Err(message)) => Ok(json!({
+                "content": [{ "type": "text", "text": message }],
+                "isError": true,
+            })),
+        }
+    }
+}
+
+/// Response to `initialize`, agreeing on the client's protocol version when
+/// supported.
+fn initialize(params: &Value) -> Value {
+    let requested = params.get("protocolVersion").and_then(Value::as_str);
+    let version =
+        PROTOCOL_VERSIONS.iter().find(|v| Some(**v) == requested).unwrap_or(&PROTOCOL_VERSIONS[0]);
+
+    json!({
+        "protocolVersion": version,
+        "capabilities": { "tools": { "listChanged": false } },
+        "serverInfo": { "name": "quench", "version": env!("CARGO_PKG_VERSION") },
+        "instructions": INSTRUCTIONS,
+    })
+}
+
+fn error(id: Value, code: i64, message: &str) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
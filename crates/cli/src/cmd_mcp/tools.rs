@@ -0,0 +1,371 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Tools served by `quench mcp`.
+
+use std::path::Path;
+use std::process::Command;
+
+use serde_json::{Map, Value, json};
+
+use quench::checks::CHECK_NAMES;
+use quench::env::names;
+
+use crate::cmd_config;
+
+/// JSON schema of `quench check -o json`, shared by `check` and `fix`.
+const OUTPUT_SCHEMA: &str = include_str!("../../../../docs/specs/output.schema.json");
+
+/// Result of a successful tool call.
+pub struct ToolOutput {
+    /// Structured result, also sent as text for clients without structured content.
+    pub value: Value,
+    /// Whether the result reports a failure (e.g. violations found).
+    pub is_error: bool,
+}
+
+impl ToolOutput {
+    /// The `tools/call` result.
+    pub fn into_result(self) -> Value {
+        json!({
+            "content": [{ "type": "text", "text": self.value.to_string() }],
+            "structuredContent": self.value,
+            "isError": self.is_error,
+        })
+    }
+}
+
+/// Definitions returned by `tools/list`.
+pub fn definitions() -> Value {
+    let checks = json!({
+        "type": "array",
+        "items": { "type": "string", "enum": CHECK_NAMES },
+        "description": "Only run these checks (default: all enabled checks)",
+    });
+    let paths = json!({
+        "type": "array",
+        "items": { "type": "string" },
+        "description": "Files or directories to check (default: the project)",
+    });
+    let output_schema: Value = serde_json::from_str(OUTPUT_SCHEMA).unwrap_or_else(|_| json!({}));
+
+    json!([
+        {
+            "name": "check",
+            "title": "Run quality checks",
+            "description": "Run quench checks and return violations with advice for fixing each one.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "paths": paths,
+                    "checks": checks,
+                    "base": {
+                        "type": "string",
+                        "description": "Compare against a git ref and only check changed files",
+                    },
+                    "staged": {
+                        "type": "boolean",
+                        "description": "Only check staged changes",
+                    },
+                    "limit": {
+                        "type": "integer",
+                        "minimum": 0,
+                        "description": "Maximum violations to return (0 for no limit, default 15)",
+                    },
+                },
+            },
+            "outputSchema": output_schema,
+        },
+        {
+            "name": "fix",
+            "title": "Fix violations",
+            "description": "Apply automatic fixes (ratchet baselines, synced agent files). \
+                            Previews the changes unless dry_run is false.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "paths": paths,
+                    "checks": checks,
+                    "dry_run": {
+                        "type": "boolean",
+                        "description": "Show what would change without writing (default: true)",
+                    },
+                },
+            },
+            "outputSchema": output_schema,
+        },
+        {
+            "name": "report",
+            "title": "Report metrics",
+            "description": "Report quality metrics from the stored baseline.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "checks": checks,
+                    "base": {
+                        "type": "string",
+                        "description": "Read the baseline from a git ref",
+                    },
+                },
+            },
+        },
+        {
+            "name": "explain_violation",
+            "title": "Explain a violation",
+            "description": "Explain a violation type: its advice and the configuration guide \
+                            section from `quench config <check>`. Pass file and line to look up \
+                            the violation's advice in the current project.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "check": {
+                        "type": "string",
+                        "enum": CHECK_NAMES,
+                        "description": "Check that reported the violation",
+                    },
+                    "type": {
+                        "type": "string",
+                        "description": "Violation type (e.g. file_too_large)",
+                    },
+                    "file": { "type": "string", "description": "File of the violation" },
+                    "line": { "type": "integer", "description": "Line of the violation" },
+                },
+                "required": ["check", "type"],
+            },
+        },
+    ])
+}
+
+/// Run tool `name`, or `None` if there is no such tool.
+///
+/// Errors are messages for the agent: invalid arguments or a quench run that
+/// failed to produce output.
+pub fn call(
+    exe: &Path,
+    cwd: &Path,
+    name: &str,
+    args: &Value,
+) -> Option<Result<ToolOutput, String>> {
+    Some(match name {
+        "check" => check(exe, cwd, args),
+        "fix" => fix(exe, cwd, args),
+        "report" => report(exe, cwd, args),
+        "explain_violation" => explain_violation(exe, cwd, args),
+        _ => return None,
+    })
+}
+
+fn check(exe: &Path, cwd: &Path, args: &Value) -> Result<ToolOutput, String> {
+    let mut cmd = vec!["check".to_string(), "-o".to_string(), "json".to_string()];
+    match args.get("limit").map(Value::as_u64) {
+        None => {}
+        Some(Some(0)) => cmd.push("--no-limit".to_string()),
+        Some(Some(limit)) => cmd.extend(["--limit".to_string(), limit.to_string()]),
+        Some(None) => return Err("`limit` must be a non-negative integer".to_string()),
+    }
+    if let Some(base) = string_arg(args, "base")? {
+        cmd.extend(["--base".to_string(), base]);
+    }
+    if bool_arg(args, "staged")?.unwrap_or(false) {
+        cmd.push("--staged".to_string());
+    }
+    cmd.extend(check_flags(args)?);
+    cmd.extend(paths_arg(args)?);
+
+    let (value, code) = run_quench(exe, cwd, &cmd)?;
+    Ok(ToolOutput { value, is_error: code != 0 })
+}
+
+fn fix(exe: &Path, cwd: &Path, args: &Value) -> Result<ToolOutput, String> {
+    let mut cmd =
+        vec!["check".to_string(), "--fix".to_string(), "-o".to_string(), "json".to_string()];
+    if bool_arg(args, "dry_run")?.unwrap_or(true) {
+        cmd.push("--dry-run".to_string());
+    }
+    cmd.extend(check_flags(args)?);
+    cmd.extend(paths_arg(args)?);
+
+    let (value, code) = run_quench(exe, cwd, &cmd)?;
+    Ok(ToolOutput { value, is_error: code != 0 })
+}
+
+fn report(exe: &Path, cwd: &Path, args: &Value) -> Result<ToolOutput, String> {
+    let mut cmd = vec!["report".to_string(), "-o".to_string(), "json".to_string()];
+    if let Some(base) = string_arg(args, "base")? {
+        cmd.extend(["--base".to_string(), base]);
+    }
+    cmd.extend(check_flags(args)?);
+
+    let (value, _) = run_quench(exe, cwd, &cmd)?;
+    Ok(ToolOutput { value, is_error: false })
+}
+
+fn explain_violation(exe: &Path, cwd: &Path, args: &Value) -> Result<ToolOutput, String> {
+    let check = string_arg(args, "check")?.ok_or("`check` is required")?;
+    let kind = string_arg(args, "type")?.ok_or("`type` is required")?;
+    if !CHECK_NAMES.contains(&check.as_str()) {
+        return Err(format!(
+            "unknown check `{check}` (expected one of: {})",
+            CHECK_NAMES.join(", ")
+        ));
+    }
+
+    // Look up the reported violation for its file-specific advice
+    let violation = match string_arg(args, "file")? {
+        Some(file) => find_violation(exe, cwd, &check, &kind, &file, args.get("line"))?,
+        None => Value::Null,
+    };
+
+    let mut result = Map::new();
+    result.insert("check".to_string(), json!(check));
+    result.insert("type".to_string(), json!(kind));
+    if let Some(advice) = violation.get("advice") {
+        result.insert("advice".to_string(), advice.clone());
+    }
+    result.insert("violation".to_string(), violation);
+    result.insert("topic".to_string(), json!(format!("quench config {check}")));
+    if let Some(guide) = cmd_config::template(&check) {
+        result.insert("guide".to_string(), json!(guide_sections(guide, &kind)));
+    }
+
+    Ok(ToolOutput { value: Value::Object(result), is_error: false })
+}
+
+/// The reported `kind` violation in `file` (at `line`, when given), if any.
+fn find_violation(
+    exe: &Path,
+    cwd: &Path,
+    check: &str,
+    kind: &str,
+    file: &str,
+    line: Option<&Value>,
+) -> Result<Value, String> {
+    let cmd = ["check", "-o", "json", "--no-limit", &format!("--{check}")].map(String::from);
+    let (output, _) = run_quench(exe, cwd, &cmd)?;
+
+    let file = file.strip_prefix("./").unwrap_or(file);
+    let found = output["checks"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|c| c["violations"].as_array())
+        .flatten()
+        .filter(|v| v["type"] == kind && v["file"] == file)
+        .find(|v| line.is_none_or(|line| v["line"] == *line));
+    Ok(found.cloned().unwrap_or(Value::Null))
+}
+
+/// Sections of a `quench config` guide relevant to violation type `kind`.
+///
+/// Prefers sections that mention the type, then sections whose heading
+/// shares a word with it, and falls back to the whole guide.
+fn guide_sections(guide: &str, kind: &str) -> String {
+    let mut sections = Vec::new();
+    let mut current = String::new();
+    for line in guide.lines() {
+        if line.starts_with("## ") && !current.is_empty() {
+            sections.push(std::mem::take(&mut current));
+        }
+        current.push_str(line);
+        current.push('\n');
+    }
+    sections.push(current);
+
+    let mentions: Vec<&str> =
+        sections.iter().filter(|s| s.contains(kind)).map(String::as_str).collect();
+    if !mentions.is_empty() {
+        return mentions.concat();
+    }
+
+    let words: Vec<String> =
+        kind.split('_').filter(|w| w.len() > 3).map(str::to_lowercase).collect();
+    let headed: Vec<&str> = sections
+        .iter()
+        .filter(|s| {
+            let heading = s.lines().next().unwrap_or_default().to_lowercase();
+            heading.starts_with("## ") && words.iter().any(|w| heading.contains(w.as_str()))
+        })
+        .map(String::as_str)
+        .collect();
+    if !headed.is_empty() {
+        return headed.concat();
+    }
+
+    guide.to_string()
+}
+
+/// `--<check>` flags for the `checks` argument.
+fn check_flags(args: &Value) -> Result<Vec<String>, String> {
+    let checks = strings_arg(args, "checks")?;
+    for check in &checks {
+        if !CHECK_NAMES.contains(&check.as_str()) {
+            return Err(format!(
+                "unknown check `{check}` (expected one of: {})",
+                CHECK_NAMES.join(", ")
+            ));
+        }
+    }
+    Ok(checks.iter().map(|check| format!("--{check}")).collect())
+}
+
+fn string_arg(args: &Value, key: &str) -> Result<Option<String>, String> {
+    match args.get(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(s)) => Ok(Some(s.clone())),
+        Some(_) => Err(format!("`{key}` must be a string")),
+    }
+}
+
+fn bool_arg(args: &Value, key: &str) -> Result<Option<bool>, String> {
+    match args.get(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::Bool(b)) => Ok(Some(*b)),
+        Some(_) => Err(format!("`{key}` must be a boolean")),
+    }
+}
+
+fn strings_arg(args: &Value, key: &str) -> Result<Vec<String>, String> {
+    match args.get(key) {
+        None | Some(Value::Null) => Ok(Vec::new()),
+        Some(Value::Array(items)) => items
+            .iter()
+            .map(|item| item.as_str().map(String::from))
+            .collect::<Option<_>>()
+            .ok_or_else(|| format!("`{key}` must be an array of strings")),
+        Some(_) => Err(format!("`{key}` must be an array of strings")),
+    }
+}
+
+/// Path arguments, placed after `--` so they are never parsed as quench flags.
+fn paths_arg(args: &Value) -> Result<Vec<String>, String> {
+    let paths = strings_arg(args, "paths")?;
+    if let Some(flag) = paths.iter().find(|path| path.starts_with('-')) {
+        return Err(format!("`paths` must not start with `-`: `{flag}`"));
+    }
+    if paths.is_empty() {
+        return Ok(paths);
+    }
+    Ok(std::iter::once("--".to_string()).chain(paths).collect())
+}
+
+/// Run quench with `args` and parse its JSON output.
+///
+/// Exit codes 0 (passed) and 1 (checks failed) both produce output; other
+/// codes are configuration or internal errors reported on stderr.
+fn run_quench(exe: &Path, cwd: &Path, args: &[String]) -> Result<(Value, i32), String> {
+    let output = Command::new(exe)
+        .args(args)
+        .current_dir(cwd)
+        .env(names::NO_COLOR, "1")
+        .output()
+        .map_err(|e| format!("failed to run quench: {e}"))?;
+
+    let code = output.status.code().unwrap_or(-1);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if code != 0 && code != 1 {
+        return Err(format!("quench {} failed: {}", args[0], stderr.trim()));
+    }
+    serde_json::from_slice(&output.stdout)
+        .map(|value| (value, code))
+        .map_err(|e| format!("quench {} produced invalid JSON ({e}): {}", args[0], stderr.trim()))
+}
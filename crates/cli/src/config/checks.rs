@@ -9,6 +9,7 @@ use serde::Deserialize;
 use serde::de::{self, Deserializer};
 
 use crate::config::{ContentRule, RequiredSection, deserialize_optional_usize};
+use crate::tokens::Tokenizer;
 
 /// Documentation check configuration.
 #[derive(Debug, Default, Clone, Deserialize)]
@@ -29,6 +30,10 @@ pub struct DocsConfig {
     #[serde(default)]
     pub specs: SpecsConfig,
 
+    /// Orphaned doc detection settings.
+    #[serde(default)]
+    pub orphans: OrphansConfig,
+
     /// Commit checking configuration (CI mode).
     #[serde(default)]
     pub commit: DocsCommitConfig,
@@ -158,6 +163,39 @@ impl LinksConfig {
     }
 }
 
+/// Configuration for orphaned doc detection.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct OrphansConfig {
+    /// Check level: "error" | "warn" | "off" (default: "off")
+    #[serde(default = "OrphansConfig::default_check")]
+    pub check: String,
+
+    /// Directory searched for orphaned markdown files (default: "docs").
+    #[serde(default = "OrphansConfig::default_path")]
+    pub path: String,
+
+    /// Exclude patterns for docs that don't need to be linked.
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+impl Default for OrphansConfig {
+    fn default() -> Self {
+        Self { check: Self::default_check(), path: Self::default_path(), exclude: Vec::new() }
+    }
+}
+
+impl OrphansConfig {
+    fn default_check() -> String {
+        "off".to_string()
+    }
+
+    fn default_path() -> String {
+        "docs".to_string()
+    }
+}
+
 /// Configuration for specs directory validation.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default, deny_unknown_fields)]
@@ -209,6 +247,10 @@ pub struct SpecsConfig {
         deserialize_with = "deserialize_optional_usize"
     )]
     pub max_tokens: Option<usize>,
+
+    /// Tokenizer for `max_tokens` (default: approx).
+    #[serde(default)]
+    pub tokenizer: Tokenizer,
 }
 
 impl Default for SpecsConfig {
@@ -225,6 +267,7 @@ impl Default for SpecsConfig {
             mermaid: ContentRule::allow(),
             max_lines: Self::default_max_lines(),
             max_tokens: Self::default_max_tokens(),
+            tokenizer: Tokenizer::default(),
         }
     }
 }
@@ -390,6 +433,10 @@ pub struct ClocConfig {
     )]
     pub max_tokens: Option<usize>,
 
+    /// Tokenizer for `max_tokens` (default: approx).
+    #[serde(default)]
+    pub tokenizer: Tokenizer,
+
     /// Advice message for source file violations.
     #[serde(default = "ClocConfig::default_advice")]
     pub advice: String,
@@ -409,6 +456,7 @@ impl Default for ClocConfig {
             test_patterns: Self::default_test_patterns(),
             exclude: Vec::new(),
             max_tokens: Self::default_max_tokens(),
+            tokenizer: Tokenizer::default(),
             advice: Self::default_advice(),
             advice_test: Self::default_advice_test(),
         }
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+use std::path::PathBuf;
+
+fn parse_config(content: &str) -> Config {
+    let path = PathBuf::from("quench.toml");
+    parse(content, &path).unwrap()
+}
+
+#[test]
+fn cpp_config_defaults() {
+    let config = parse_config("version = 1\n");
+    assert!(config.cpp.source.contains(&"**/*.c".to_string()));
+    assert!(config.cpp.source.contains(&"**/*.hpp".to_string()));
+    assert!(config.cpp.tests.contains(&"**/tests/**".to_string()));
+    assert!(config.cpp.tests.contains(&"**/*_test.cc".to_string()));
+    assert!(config.cpp.tests.contains(&"**/*_unittest.cc".to_string()));
+    assert!(config.cpp.exclude.contains(&"build/".to_string()));
+}
+
+#[test]
+fn cpp_suppress_defaults() {
+    let config = parse_config("version = 1\n");
+    assert_eq!(config.cpp.suppress.check, SuppressLevel::Comment);
+    assert_eq!(config.cpp.suppress.test.check, Some(SuppressLevel::Allow));
+}
+
+#[test]
+fn cpp_policy_defaults() {
+    let config = parse_config("version = 1\n");
+    assert!(config.cpp.policy.lint_config.contains(&".clang-tidy".to_string()));
+    assert!(config.cpp.policy.lint_config.contains(&".clang-format".to_string()));
+}
+
+#[test]
+fn cpp_suppress_and_policy_parse() {
+    let config = parse_config(
+        r#"
+version = 1
+
+[cpp.suppress]
+check = "forbid"
+
+[cpp.policy]
+check = "warn"
+"#,
+    );
+    assert_eq!(config.cpp.suppress.check, SuppressLevel::Forbid);
+    assert_eq!(config.policy_check_level_for_language("cpp"), CheckLevel::Warn);
+}
+
+#[test]
+fn cpp_cloc_advice_resolves_by_extension() {
+    let config = parse_config("version = 1\n");
+    let advice = config.cloc_advice_for_language("hpp", 750);
+    assert!(advice.contains("translation units"));
+    assert_eq!(advice, config.cloc_advice_for_language("c", 750));
+}
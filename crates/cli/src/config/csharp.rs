@@ -0,0 +1,173 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! C# language-specific configuration.
+
+use serde::Deserialize;
+
+use super::lang_common::{LanguageDefaults, define_policy_config};
+use super::{CheckLevel, LangClocConfig, LintChangesPolicy, SuppressLevel, SuppressScopeConfig};
+
+/// C# language-specific configuration.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CSharpConfig {
+    /// Source file patterns.
+    #[serde(default = "CSharpDefaults::default_source")]
+    pub source: Vec<String>,
+
+    /// Test file patterns.
+    #[serde(default = "CSharpDefaults::default_tests")]
+    pub tests: Vec<String>,
+
+    /// Exclude patterns (walker-level: prevents I/O on subtrees).
+    #[serde(default = "CSharpDefaults::default_exclude", alias = "ignore")]
+    pub exclude: Vec<String>,
+
+    /// Lint suppression settings.
+    #[serde(default)]
+    pub suppress: CSharpSuppressConfig,
+
+    /// Lint configuration policy.
+    #[serde(default)]
+    pub policy: CSharpPolicyConfig,
+
+    /// Per-language cloc settings.
+    #[serde(default)]
+    pub cloc: Option<LangClocConfig>,
+
+    /// Custom cloc advice for source files (None = use generic default).
+    /// Note: Deprecated in favor of cloc.advice.
+    #[serde(default)]
+    pub cloc_advice: Option<String>,
+}
+
+impl Default for CSharpConfig {
+    fn default() -> Self {
+        Self {
+            source: CSharpDefaults::default_source(),
+            tests: CSharpDefaults::default_tests(),
+            exclude: CSharpDefaults::default_exclude(),
+            suppress: CSharpSuppressConfig::default(),
+            policy: CSharpPolicyConfig::default(),
+            cloc: None,
+            cloc_advice: None,
+        }
+    }
+}
+
+/// C# language defaults.
+pub struct CSharpDefaults;
+
+impl LanguageDefaults for CSharpDefaults {
+    fn default_source() -> Vec<String> {
+        vec!["**/*.cs".to_string()]
+    }
+
+    fn default_tests() -> Vec<String> {
+        vec![
+            // Test projects (`MyApp.Tests/`, `MyApp.UnitTests/`, `MyApp.IntegrationTests/`)
+            "**/*.Tests/**".to_string(),
+            "**/*.Test/**".to_string(),
+            "**/*.UnitTests/**".to_string(),
+            "**/*.IntegrationTests/**".to_string(),
+            "**/*Tests.cs".to_string(),
+            "**/*Test.cs".to_string(),
+        ]
+    }
+
+    fn default_exclude() -> Vec<String> {
+        vec![
+            // MSBuild writes bin/ and obj/ next to every project
+            "**/bin/".to_string(),
+            "**/obj/".to_string(),
+            "packages/".to_string(),
+            ".vs/".to_string(),
+            "**/TestResults/".to_string(),
+        ]
+    }
+
+    fn default_cloc_advice(threshold: usize) -> String {
+        let range = super::defaults::advice::target_range(threshold);
+        format!(
+            "First, look for repetitive patterns that could be extracted into helper \
+methods or extension methods.\n\
+\n\
+Then split into smaller classes by responsibility (target {range} each); \
+partial classes only hide the size of a type.\n\
+\n\
+Avoid removing individual lines to satisfy the linter; \
+prefer extracting testable code blocks."
+        )
+    }
+}
+
+impl CSharpConfig {
+    pub(crate) fn default_source() -> Vec<String> {
+        CSharpDefaults::default_source()
+    }
+
+    pub(crate) fn default_tests() -> Vec<String> {
+        CSharpDefaults::default_tests()
+    }
+
+    pub(crate) fn default_exclude() -> Vec<String> {
+        CSharpDefaults::default_exclude()
+    }
+
+    pub(crate) fn default_cloc_advice(threshold: usize) -> String {
+        CSharpDefaults::default_cloc_advice(threshold)
+    }
+}
+
+/// C# suppress configuration (`#pragma warning disable`, `[SuppressMessage]`).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CSharpSuppressConfig {
+    /// Check level: forbid, comment, or allow (default: "comment").
+    #[serde(default = "CSharpSuppressConfig::default_check")]
+    pub check: SuppressLevel,
+
+    /// Optional comment pattern required (default: any comment).
+    #[serde(default)]
+    pub comment: Option<String>,
+
+    /// Source-specific settings.
+    #[serde(default)]
+    pub source: SuppressScopeConfig,
+
+    /// Test-specific settings (overrides base settings for test code).
+    #[serde(default = "CSharpSuppressConfig::default_test")]
+    pub test: SuppressScopeConfig,
+}
+
+impl Default for CSharpSuppressConfig {
+    fn default() -> Self {
+        Self {
+            check: Self::default_check(),
+            comment: None,
+            source: SuppressScopeConfig::default(),
+            test: Self::default_test(),
+        }
+    }
+}
+
+impl CSharpSuppressConfig {
+    pub(crate) fn default_check() -> SuppressLevel {
+        SuppressLevel::Comment // C# defaults to comment (require justification)
+    }
+
+    pub(crate) fn default_test() -> SuppressScopeConfig {
+        SuppressScopeConfig {
+            check: Some(SuppressLevel::Allow),
+            allow: Vec::new(),
+            forbid: Vec::new(),
+            patterns: std::collections::HashMap::new(),
+        }
+    }
+}
+
+define_policy_config!(
+    CSharpPolicyConfig,
+    [".editorconfig", ".globalconfig", "Directory.Build.props", "stylecop.json",]
+);
@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+use std::path::PathBuf;
+
+fn parse_config(content: &str) -> Config {
+    let path = PathBuf::from("quench.toml");
+    parse(content, &path).unwrap()
+}
+
+#[test]
+fn csharp_config_defaults() {
+    let config = parse_config("version = 1\n");
+    assert_eq!(config.csharp.source, vec!["**/*.cs".to_string()]);
+    assert!(config.csharp.tests.contains(&"**/*.Tests/**".to_string()));
+    assert!(config.csharp.tests.contains(&"**/*Tests.cs".to_string()));
+    assert!(config.csharp.exclude.contains(&"**/bin/".to_string()));
+    assert!(config.csharp.exclude.contains(&"**/obj/".to_string()));
+}
+
+#[test]
+fn csharp_suppress_defaults() {
+    let config = parse_config("version = 1\n");
+    assert_eq!(config.csharp.suppress.check, SuppressLevel::Comment);
+    assert_eq!(config.csharp.suppress.test.check, Some(SuppressLevel::Allow));
+}
+
+#[test]
+fn csharp_policy_defaults() {
+    let config = parse_config("version = 1\n");
+    assert!(config.csharp.policy.lint_config.contains(&".editorconfig".to_string()));
+    assert!(config.csharp.policy.lint_config.contains(&"Directory.Build.props".to_string()));
+}
+
+#[test]
+fn csharp_suppress_and_policy_parse() {
+    let config = parse_config(
+        r#"
+version = 1
+
+[csharp.suppress]
+check = "forbid"
+
+[csharp.suppress.source]
+allow = ["CS8618"]
+
+[csharp.policy]
+check = "warn"
+"#,
+    );
+    assert_eq!(config.csharp.suppress.check, SuppressLevel::Forbid);
+    assert_eq!(config.csharp.suppress.source.allow, vec!["CS8618".to_string()]);
+    assert_eq!(config.policy_check_level_for_language("csharp"), CheckLevel::Warn);
+}
+
+#[test]
+fn csharp_cloc_advice_resolves_by_extension() {
+    let config = parse_config("version = 1\n");
+    let advice = config.cloc_advice_for_language("cs", 750);
+    assert!(advice.contains("partial classes"));
+    assert_eq!(advice, config.cloc_advice_for_language("csharp", 750));
+}
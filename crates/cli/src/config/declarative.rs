@@ -0,0 +1,187 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Declarative adapter configuration.
+//!
+//! Languages without a built-in adapter are described in TOML, either as
+//! `[[adapter]]` entries in `quench.toml` or one adapter per file under
+//! `.quench/adapters/*.toml`.
+
+use std::path::Path;
+
+use serde::Deserialize;
+
+use super::lang_common::define_policy_config;
+use super::{
+    CheckLevel, Config, EscapePattern, LangClocConfig, LintChangesPolicy, SuppressLevel,
+    SuppressScopeConfig,
+};
+use crate::error::{Error, Result};
+
+/// Directory holding one declarative adapter spec per `*.toml` file.
+pub const ADAPTERS_DIR: &str = ".quench/adapters";
+
+/// A language adapter described in configuration rather than code.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DeclarativeAdapterConfig {
+    /// Adapter name, used in output and for per-language lookups (e.g., "zig").
+    pub name: String,
+
+    /// File extensions handled by this adapter, without the dot (e.g., `["zig"]`).
+    #[serde(default)]
+    pub extensions: Vec<String>,
+
+    /// File name globs handled regardless of extension (e.g., `["Justfile", "*.just"]`).
+    #[serde(default)]
+    pub filenames: Vec<String>,
+
+    /// Comment syntax for line counting and justification comments.
+    #[serde(default)]
+    pub comment: DeclarativeCommentConfig,
+
+    /// Source file patterns (default: derived from extensions and filenames).
+    #[serde(default)]
+    pub source: Vec<String>,
+
+    /// Test file patterns (default: `[project].tests`, then generic test patterns).
+    #[serde(default)]
+    pub tests: Vec<String>,
+
+    /// Exclude patterns (walker-level: prevents I/O on subtrees).
+    #[serde(default, alias = "ignore")]
+    pub exclude: Vec<String>,
+
+    /// Escape patterns applied to this adapter's files.
+    #[serde(default)]
+    pub escapes: Vec<EscapePattern>,
+
+    /// Lint suppression directives and settings.
+    #[serde(default)]
+    pub suppress: DeclarativeSuppressConfig,
+
+    /// Lint configuration policy.
+    #[serde(default)]
+    pub policy: DeclarativePolicyConfig,
+
+    /// Per-language cloc settings.
+    #[serde(default)]
+    pub cloc: Option<LangClocConfig>,
+
+    /// Shell command that runs the test suite (auto-detected in CI mode).
+    #[serde(default)]
+    pub test_command: Option<String>,
+}
+
+impl DeclarativeAdapterConfig {
+    /// Source patterns, derived from extensions and filenames when not configured.
+    pub fn effective_source(&self) -> Vec<String> {
+        if !self.source.is_empty() {
+            return self.source.clone();
+        }
+        self.file_patterns()
+    }
+
+    /// Globs matching every file this adapter handles.
+    pub fn file_patterns(&self) -> Vec<String> {
+        let extensions = self.extensions.iter().map(|ext| format!("**/*.{ext}"));
+        let filenames = self.filenames.iter().map(|name| format!("**/{name}"));
+        extensions.chain(filenames).collect()
+    }
+}
+
+/// Comment syntax for a declarative adapter.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DeclarativeCommentConfig {
+    /// Single-line comment prefixes (e.g., `["//"]`). The first one marks
+    /// justification comments above suppress directives.
+    #[serde(default)]
+    pub line: Vec<String>,
+
+    /// Block comment delimiter pairs (e.g., `[["/*", "*/"]]`).
+    #[serde(default)]
+    pub block: Vec<(String, String)>,
+}
+
+/// Declarative suppress configuration.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DeclarativeSuppressConfig {
+    /// Regexes matching suppress directives. A `codes` named group (or the first
+    /// capture group) captures the lint codes, separated by commas or spaces.
+    #[serde(default)]
+    pub directives: Vec<String>,
+
+    /// Check level: forbid, comment, or allow (default: "comment").
+    #[serde(default = "DeclarativeSuppressConfig::default_check")]
+    pub check: SuppressLevel,
+
+    /// Optional comment pattern required (default: any comment).
+    #[serde(default)]
+    pub comment: Option<String>,
+
+    /// Source-specific settings.
+    #[serde(default)]
+    pub source: SuppressScopeConfig,
+
+    /// Test-specific settings (overrides base settings for test code).
+    #[serde(default = "DeclarativeSuppressConfig::default_test")]
+    pub test: SuppressScopeConfig,
+}
+
+impl Default for DeclarativeSuppressConfig {
+    fn default() -> Self {
+        Self {
+            directives: Vec::new(),
+            check: Self::default_check(),
+            comment: None,
+            source: SuppressScopeConfig::default(),
+            test: Self::default_test(),
+        }
+    }
+}
+
+impl DeclarativeSuppressConfig {
+    pub(crate) fn default_check() -> SuppressLevel {
+        SuppressLevel::Comment
+    }
+
+    pub(crate) fn default_test() -> SuppressScopeConfig {
+        SuppressScopeConfig {
+            check: Some(SuppressLevel::Allow),
+            allow: Vec::new(),
+            forbid: Vec::new(),
+            patterns: std::collections::HashMap::new(),
+        }
+    }
+}
+
+define_policy_config!(DeclarativePolicyConfig, []);
+
+/// Load adapter specs from `.quench/adapters/*.toml` into `config`.
+///
+/// Files are read in name order and appended after `[[adapter]]` entries.
+pub fn load_adapter_dir(root: &Path, config: &mut Config) -> Result<()> {
+    let Ok(entries) = std::fs::read_dir(root.join(ADAPTERS_DIR)) else {
+        return Ok(());
+    };
+    let mut paths: Vec<_> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .collect();
+    paths.sort();
+
+    for path in paths {
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| Error::Io { path: path.clone(), source: e })?;
+        // Name the file in the message: several specs may be loaded at once
+        let adapter = toml::from_str(&content).map_err(|e| Error::Config {
+            message: format!("{}: {e}", path.display()),
+            path: Some(path.clone()),
+        })?;
+        config.adapters.push(adapter);
+    }
+    Ok(())
+}
@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+use std::path::PathBuf;
+
+fn parse_config(content: &str) -> Config {
+    let path = PathBuf::from("quench.toml");
+    parse(content, &path).unwrap()
+}
+
+const ZIG: &str = r#"
+version = 1
+
+[[adapter]]
+name = "zig"
+extensions = ["zig"]
+exclude = ["zig-cache/"]
+test_command = "zig build test"
+
+[adapter.comment]
+line = ["//"]
+
+[[adapter.escapes]]
+name = "unreachable"
+pattern = "unreachable"
+action = "comment"
+comment = "// SAFETY:"
+
+[adapter.suppress]
+directives = ['zlint:ignore\s+(?P<codes>\S+)']
+
+[adapter.suppress.source]
+allow = ["naming"]
+
+[adapter.policy]
+lint_changes = "standalone"
+lint_config = [".zlint.json"]
+check = "warn"
+
+[adapter.cloc]
+check = "off"
+"#;
+
+#[test]
+fn no_adapters_by_default() {
+    let config = parse_config("version = 1\n");
+    assert!(config.adapters.is_empty());
+}
+
+#[test]
+fn adapter_parses_all_sections() {
+    let config = parse_config(ZIG);
+    let zig = &config.adapters[0];
+
+    assert_eq!(zig.name, "zig");
+    assert_eq!(zig.extensions, vec!["zig".to_string()]);
+    assert_eq!(zig.exclude, vec!["zig-cache/".to_string()]);
+    assert_eq!(zig.test_command.as_deref(), Some("zig build test"));
+    assert_eq!(zig.comment.line, vec!["//".to_string()]);
+    assert_eq!(zig.escapes[0].effective_name(), "unreachable");
+    assert_eq!(zig.suppress.directives.len(), 1);
+    assert_eq!(zig.suppress.source.allow, vec!["naming".to_string()]);
+    assert_eq!(zig.policy.lint_changes, LintChangesPolicy::Standalone);
+    assert_eq!(zig.policy.lint_config, vec![".zlint.json".to_string()]);
+}
+
+#[test]
+fn adapter_suppress_defaults() {
+    let config = parse_config("version = 1\n[[adapter]]\nname = \"zig\"\n");
+    let suppress = &config.adapters[0].suppress;
+    assert!(suppress.directives.is_empty());
+    assert_eq!(suppress.check, SuppressLevel::Comment);
+    assert_eq!(suppress.test.check, Some(SuppressLevel::Allow));
+}
+
+#[test]
+fn source_defaults_to_extensions_and_filenames() {
+    let config = parse_config(
+        "version = 1\n[[adapter]]\nname = \"just\"\nextensions = [\"just\"]\nfilenames = [\"Justfile\"]\n",
+    );
+    assert_eq!(
+        config.adapters[0].effective_source(),
+        vec!["**/*.just".to_string(), "**/Justfile".to_string()]
+    );
+}
+
+#[test]
+fn adapter_lookups_by_name_and_extension() {
+    let config = parse_config(ZIG);
+    assert_eq!(config.policy_check_level_for_language("zig"), CheckLevel::Warn);
+    assert_eq!(config.cloc_check_level_for_language("zig"), CheckLevel::Off);
+    assert!(config.declarative_adapter("ZIG").is_some());
+    assert!(config.declarative_adapter("rust").is_none());
+}
+
+#[test]
+fn unknown_adapter_field_is_rejected() {
+    let path = PathBuf::from("quench.toml");
+    assert!(
+        parse("version = 1\n[[adapter]]\nname = \"zig\"\nextension = [\"zig\"]\n", &path).is_err()
+    );
+}
+
+#[test]
+fn adapter_dir_specs_append_in_name_order() {
+    let dir = tempfile::tempdir().unwrap();
+    let adapters = dir.path().join(ADAPTERS_DIR);
+    std::fs::create_dir_all(&adapters).unwrap();
+    std::fs::write(adapters.join("b-zig.toml"), "name = \"zig\"\nextensions = [\"zig\"]\n")
+        .unwrap();
+    std::fs::write(adapters.join("a-nim.toml"), "name = \"nim\"\nextensions = [\"nim\"]\n")
+        .unwrap();
+    std::fs::write(adapters.join("README.md"), "not a spec").unwrap();
+
+    let mut config = parse_config("version = 1\n[[adapter]]\nname = \"lua\"\n");
+    load_adapter_dir(dir.path(), &mut config).unwrap();
+
+    let names: Vec<&str> = config.adapters.iter().map(|a| a.name.as_str()).collect();
+    assert_eq!(names, vec!["lua", "nim", "zig"]);
+}
+
+#[test]
+fn adapter_dir_reports_invalid_spec() {
+    let dir = tempfile::tempdir().unwrap();
+    let adapters = dir.path().join(ADAPTERS_DIR);
+    std::fs::create_dir_all(&adapters).unwrap();
+    std::fs::write(adapters.join("zig.toml"), "extensions = [\"zig\"]\n").unwrap();
+
+    let mut config = parse_config("version = 1\n");
+    let err = load_adapter_dir(dir.path(), &mut config).unwrap_err();
+    assert!(err.to_string().contains("zig.toml"), "{err}");
+}
+
+#[test]
+fn missing_adapter_dir_is_ok() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut config = parse_config("version = 1\n");
+    load_adapter_dir(dir.path(), &mut config).unwrap();
+    assert!(config.adapters.is_empty());
+}
@@ -0,0 +1,169 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Elixir language-specific configuration.
+
+use serde::Deserialize;
+
+use super::lang_common::{LanguageDefaults, define_policy_config};
+use super::{CheckLevel, LangClocConfig, LintChangesPolicy, SuppressLevel, SuppressScopeConfig};
+
+/// Elixir language-specific configuration.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ElixirConfig {
+    /// Source file patterns.
+    #[serde(default = "ElixirDefaults::default_source")]
+    pub source: Vec<String>,
+
+    /// Test file patterns.
+    #[serde(default = "ElixirDefaults::default_tests")]
+    pub tests: Vec<String>,
+
+    /// Exclude patterns (walker-level: prevents I/O on subtrees).
+    #[serde(default = "ElixirDefaults::default_exclude", alias = "ignore")]
+    pub exclude: Vec<String>,
+
+    /// Lint suppression settings.
+    #[serde(default)]
+    pub suppress: ElixirSuppressConfig,
+
+    /// Lint configuration policy.
+    #[serde(default)]
+    pub policy: ElixirPolicyConfig,
+
+    /// Per-language cloc settings.
+    #[serde(default)]
+    pub cloc: Option<LangClocConfig>,
+
+    /// Custom cloc advice for source files (None = use generic default).
+    /// Note: Deprecated in favor of cloc.advice.
+    #[serde(default)]
+    pub cloc_advice: Option<String>,
+}
+
+impl Default for ElixirConfig {
+    fn default() -> Self {
+        Self {
+            source: ElixirDefaults::default_source(),
+            tests: ElixirDefaults::default_tests(),
+            exclude: ElixirDefaults::default_exclude(),
+            suppress: ElixirSuppressConfig::default(),
+            policy: ElixirPolicyConfig::default(),
+            cloc: None,
+            cloc_advice: None,
+        }
+    }
+}
+
+/// Elixir language defaults.
+pub struct ElixirDefaults;
+
+impl LanguageDefaults for ElixirDefaults {
+    fn default_source() -> Vec<String> {
+        vec!["**/*.ex".to_string(), "**/*.exs".to_string()]
+    }
+
+    fn default_tests() -> Vec<String> {
+        vec![
+            // ExUnit tests and support modules, including umbrella apps
+            "test/**".to_string(),
+            "**/test/**".to_string(),
+            "**/*_test.exs".to_string(),
+        ]
+    }
+
+    fn default_exclude() -> Vec<String> {
+        vec![
+            // Mix build products, fetched dependencies, and tool state
+            "_build/".to_string(),
+            "deps/".to_string(),
+            ".elixir_ls/".to_string(),
+            "cover/".to_string(),
+        ]
+    }
+
+    fn default_cloc_advice(threshold: usize) -> String {
+        let range = super::defaults::advice::target_range(threshold);
+        format!(
+            "First, look for repetitive patterns that could be extracted into helper \
+functions or shared modules.\n\
+\n\
+Then split into smaller modules by responsibility (target {range} each); \
+move protocol implementations and behaviour callbacks into their own files.\n\
+\n\
+Avoid removing individual lines to satisfy the linter; \
+prefer extracting testable code blocks."
+        )
+    }
+}
+
+impl ElixirConfig {
+    pub(crate) fn default_source() -> Vec<String> {
+        ElixirDefaults::default_source()
+    }
+
+    pub(crate) fn default_tests() -> Vec<String> {
+        ElixirDefaults::default_tests()
+    }
+
+    pub(crate) fn default_exclude() -> Vec<String> {
+        ElixirDefaults::default_exclude()
+    }
+
+    pub(crate) fn default_cloc_advice(threshold: usize) -> String {
+        ElixirDefaults::default_cloc_advice(threshold)
+    }
+}
+
+/// Elixir suppress configuration (`# credo:disable-for-*` directives).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ElixirSuppressConfig {
+    /// Check level: forbid, comment, or allow (default: "comment").
+    #[serde(default = "ElixirSuppressConfig::default_check")]
+    pub check: SuppressLevel,
+
+    /// Optional comment pattern required (default: any comment).
+    #[serde(default)]
+    pub comment: Option<String>,
+
+    /// Source-specific settings.
+    #[serde(default)]
+    pub source: SuppressScopeConfig,
+
+    /// Test-specific settings (overrides base settings for test code).
+    #[serde(default = "ElixirSuppressConfig::default_test")]
+    pub test: SuppressScopeConfig,
+}
+
+impl Default for ElixirSuppressConfig {
+    fn default() -> Self {
+        Self {
+            check: Self::default_check(),
+            comment: None,
+            source: SuppressScopeConfig::default(),
+            test: Self::default_test(),
+        }
+    }
+}
+
+impl ElixirSuppressConfig {
+    pub(crate) fn default_check() -> SuppressLevel {
+        SuppressLevel::Comment // Elixir defaults to comment (require justification)
+    }
+
+    pub(crate) fn default_test() -> SuppressScopeConfig {
+        SuppressScopeConfig {
+            check: Some(SuppressLevel::Allow),
+            allow: Vec::new(),
+            forbid: Vec::new(),
+            patterns: std::collections::HashMap::new(),
+        }
+    }
+}
+
+define_policy_config!(
+    ElixirPolicyConfig,
+    [".credo.exs", ".formatter.exs", ".dialyzer_ignore.exs",]
+);
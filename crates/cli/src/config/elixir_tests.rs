@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+use std::path::PathBuf;
+
+fn parse_config(content: &str) -> Config {
+    let path = PathBuf::from("quench.toml");
+    parse(content, &path).unwrap()
+}
+
+#[test]
+fn elixir_config_defaults() {
+    let config = parse_config("version = 1\n");
+    assert!(config.elixir.source.contains(&"**/*.ex".to_string()));
+    assert!(config.elixir.source.contains(&"**/*.exs".to_string()));
+    assert!(config.elixir.tests.contains(&"test/**".to_string()));
+    assert!(config.elixir.exclude.contains(&"_build/".to_string()));
+    assert!(config.elixir.exclude.contains(&"deps/".to_string()));
+}
+
+#[test]
+fn elixir_suppress_defaults() {
+    let config = parse_config("version = 1\n");
+    assert_eq!(config.elixir.suppress.check, SuppressLevel::Comment);
+    assert_eq!(config.elixir.suppress.test.check, Some(SuppressLevel::Allow));
+}
+
+#[test]
+fn elixir_policy_defaults() {
+    let config = parse_config("version = 1\n");
+    assert!(config.elixir.policy.lint_config.contains(&".credo.exs".to_string()));
+    assert!(config.elixir.policy.lint_config.contains(&".formatter.exs".to_string()));
+}
+
+#[test]
+fn elixir_suppress_and_policy_parse() {
+    let config = parse_config(
+        r#"
+version = 1
+
+[elixir.suppress]
+check = "forbid"
+
+[elixir.suppress.source]
+allow = ["Credo.Check.Readability.MaxLineLength"]
+
+[elixir.policy]
+check = "warn"
+"#,
+    );
+    assert_eq!(config.elixir.suppress.check, SuppressLevel::Forbid);
+    assert_eq!(
+        config.elixir.suppress.source.allow,
+        vec!["Credo.Check.Readability.MaxLineLength".to_string()]
+    );
+    assert_eq!(config.policy_check_level_for_language("elixir"), CheckLevel::Warn);
+}
+
+#[test]
+fn elixir_cloc_advice_resolves_by_extension() {
+    let config = parse_config("version = 1\n");
+    let advice = config.cloc_advice_for_language("exs", 750);
+    assert!(advice.contains("behaviour callbacks"));
+}
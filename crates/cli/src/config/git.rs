@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Git configuration.
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+/// Git configuration.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GitConfig {
+    /// Baseline file path for ratcheting.
+    #[serde(default = "GitConfig::default_baseline")]
+    pub baseline: String,
+
+    /// Commit message validation settings.
+    #[serde(default)]
+    pub commit: GitCommitConfig,
+
+    /// Branch naming and size settings.
+    #[serde(default)]
+    pub branch: GitBranchConfig,
+}
+
+impl Default for GitConfig {
+    fn default() -> Self {
+        Self {
+            baseline: Self::default_baseline(),
+            commit: GitCommitConfig::default(),
+            branch: GitBranchConfig::default(),
+        }
+    }
+}
+
+impl GitConfig {
+    fn default_baseline() -> String {
+        "notes".to_string()
+    }
+
+    /// Check if baseline is configured to use git notes.
+    pub fn uses_notes(&self) -> bool {
+        self.baseline == "notes"
+    }
+
+    /// Get baseline file path (returns None if using notes mode).
+    pub fn baseline_path(&self) -> Option<&str> {
+        if self.uses_notes() { None } else { Some(&self.baseline) }
+    }
+}
+
+/// Git commit message configuration.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GitCommitConfig {
+    /// Check level: "error" | "warn" | "off"
+    pub check: Option<String>,
+
+    /// Commit format: "conventional" | "none" (default: "conventional")
+    pub format: Option<String>,
+
+    /// Allowed commit types (None = use defaults, Some([]) = any type)
+    pub types: Option<Vec<String>>,
+
+    /// Allowed scopes (None = any scope allowed)
+    pub scopes: Option<Vec<String>>,
+
+    /// Check that commit format is documented in agent files (default: true)
+    pub agents: bool,
+
+    /// Create .gitmessage template with --fix (default: true)
+    pub template: bool,
+
+    /// Skip merge commits (e.g., "Merge branch 'x'") (default: true)
+    pub skip_merge: bool,
+
+    /// Maximum subject line length in characters (None = unlimited)
+    pub subject_max: Option<usize>,
+
+    /// Require the subject to use the imperative mood (default: false)
+    pub imperative: bool,
+
+    /// Require a blank line between the subject and body (default: false)
+    pub body_blank_line: bool,
+
+    /// Maximum body line length in characters (None = unlimited)
+    pub body_wrap: Option<usize>,
+
+    /// Required trailers: key -> regex the value must match
+    pub trailers: BTreeMap<String, String>,
+
+    /// Require a `Signed-off-by` trailer matching the author (DCO) (default: false)
+    pub signoff: bool,
+
+    /// Require a `BREAKING CHANGE:` footer on `type!:` commits (default: false)
+    pub breaking_footer: bool,
+}
+
+impl Default for GitCommitConfig {
+    fn default() -> Self {
+        Self {
+            check: None,
+            format: None,
+            types: None,
+            scopes: None,
+            agents: true,
+            template: true,
+            skip_merge: true,
+            subject_max: None,
+            imperative: false,
+            body_blank_line: false,
+            body_wrap: None,
+            trailers: BTreeMap::new(),
+            signoff: false,
+            breaking_footer: false,
+        }
+    }
+}
+
+impl GitCommitConfig {
+    /// Get effective format (default: "conventional").
+    pub fn effective_format(&self) -> &str {
+        self.format.as_deref().unwrap_or("conventional")
+    }
+}
+
+/// Git branch configuration.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GitBranchConfig {
+    /// Regex the current branch name must match (None = any name)
+    pub pattern: Option<String>,
+
+    /// Maximum distinct commit scopes and areas per branch (None = unlimited)
+    pub max_scopes: Option<usize>,
+}
@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Infrastructure-as-code configuration (Terraform/HCL, Dockerfiles, Kubernetes YAML).
+
+use serde::Deserialize;
+
+use super::lang_common::{LanguageDefaults, define_policy_config};
+use super::{CheckLevel, LangClocConfig, LintChangesPolicy};
+
+/// Infrastructure-as-code configuration.
+///
+/// Unlike language sections, `[iac]` applies alongside the detected project
+/// language: infra code lives next to application code.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct IacConfig {
+    /// Source file patterns.
+    #[serde(default = "IacDefaults::default_source")]
+    pub source: Vec<String>,
+
+    /// Test file patterns.
+    #[serde(default = "IacDefaults::default_tests")]
+    pub tests: Vec<String>,
+
+    /// Exclude patterns (walker-level: prevents I/O on subtrees).
+    #[serde(default = "IacDefaults::default_exclude", alias = "ignore")]
+    pub exclude: Vec<String>,
+
+    /// Lint configuration policy.
+    #[serde(default)]
+    pub policy: IacPolicyConfig,
+
+    /// Per-language cloc settings.
+    #[serde(default)]
+    pub cloc: Option<LangClocConfig>,
+
+    /// Custom cloc advice for source files (None = use generic default).
+    /// Note: Deprecated in favor of cloc.advice.
+    #[serde(default)]
+    pub cloc_advice: Option<String>,
+}
+
+impl Default for IacConfig {
+    fn default() -> Self {
+        Self {
+            source: IacDefaults::default_source(),
+            tests: IacDefaults::default_tests(),
+            exclude: IacDefaults::default_exclude(),
+            policy: IacPolicyConfig::default(),
+            cloc: None,
+            cloc_advice: None,
+        }
+    }
+}
+
+/// Infrastructure-as-code defaults.
+pub struct IacDefaults;
+
+impl LanguageDefaults for IacDefaults {
+    fn default_source() -> Vec<String> {
+        vec![
+            // Terraform, OpenTofu and Packer
+            "**/*.tf".to_string(),
+            "**/*.tfvars".to_string(),
+            "**/*.hcl".to_string(),
+            // Container images
+            "**/Dockerfile".to_string(),
+            "**/Dockerfile.*".to_string(),
+            "**/*.dockerfile".to_string(),
+            "**/Containerfile".to_string(),
+            // Kubernetes manifests
+            "**/k8s/**/*.{yaml,yml}".to_string(),
+            "**/kubernetes/**/*.{yaml,yml}".to_string(),
+            "**/manifests/**/*.{yaml,yml}".to_string(),
+            "**/kustomization.{yaml,yml}".to_string(),
+        ]
+    }
+
+    fn default_tests() -> Vec<String> {
+        vec!["**/*.tftest.hcl".to_string()]
+    }
+
+    fn default_exclude() -> Vec<String> {
+        vec![
+            // Provider plugins and module caches
+            "**/.terraform/".to_string(),
+            "**/.terragrunt-cache/".to_string(),
+        ]
+    }
+
+    fn default_cloc_advice(threshold: usize) -> String {
+        let range = super::defaults::advice::target_range(threshold);
+        format!(
+            "First, look for repeated resources that could become a module or use \
+for_each.\n\
+\n\
+Then split into smaller files by concern (target {range} each); \
+keep variables, outputs and providers in their own files.\n\
+\n\
+Avoid removing individual lines to satisfy the linter; \
+prefer extracting reusable modules."
+        )
+    }
+}
+
+impl IacConfig {
+    pub(crate) fn default_source() -> Vec<String> {
+        IacDefaults::default_source()
+    }
+
+    pub(crate) fn default_tests() -> Vec<String> {
+        IacDefaults::default_tests()
+    }
+
+    pub(crate) fn default_exclude() -> Vec<String> {
+        IacDefaults::default_exclude()
+    }
+
+    pub(crate) fn default_cloc_advice(threshold: usize) -> String {
+        IacDefaults::default_cloc_advice(threshold)
+    }
+}
+
+define_policy_config!(
+    IacPolicyConfig,
+    [".tflint.hcl", ".hadolint.yaml", ".hadolint.yml", ".checkov.yaml", ".checkov.yml",]
+);
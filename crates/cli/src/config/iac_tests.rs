@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+use std::path::PathBuf;
+
+fn parse_config(content: &str) -> Config {
+    let path = PathBuf::from("quench.toml");
+    parse(content, &path).unwrap()
+}
+
+#[test]
+fn iac_config_defaults() {
+    let config = parse_config("version = 1\n");
+    assert!(config.iac.source.contains(&"**/*.tf".to_string()));
+    assert!(config.iac.source.contains(&"**/Dockerfile".to_string()));
+    assert!(config.iac.tests.contains(&"**/*.tftest.hcl".to_string()));
+    assert!(config.iac.exclude.contains(&"**/.terraform/".to_string()));
+}
+
+#[test]
+fn iac_policy_defaults() {
+    let config = parse_config("version = 1\n");
+    assert!(config.iac.policy.lint_config.contains(&".tflint.hcl".to_string()));
+    assert!(config.iac.policy.lint_config.contains(&".hadolint.yaml".to_string()));
+    assert_eq!(config.iac.policy.lint_changes, LintChangesPolicy::None);
+}
+
+#[test]
+fn iac_policy_and_cloc_parse() {
+    let config = parse_config(
+        r#"
+version = 1
+
+[iac]
+source = ["infra/**/*.tf"]
+
+[iac.cloc]
+check = "off"
+
+[iac.policy]
+check = "warn"
+lint_changes = "standalone"
+"#,
+    );
+    assert_eq!(config.iac.source, vec!["infra/**/*.tf".to_string()]);
+    assert_eq!(config.iac.policy.lint_changes, LintChangesPolicy::Standalone);
+    assert_eq!(config.policy_check_level_for_language("iac"), CheckLevel::Warn);
+    assert_eq!(config.cloc_check_level_for_language("tf"), CheckLevel::Off);
+    assert_eq!(config.cloc_check_level_for_language("dockerfile"), CheckLevel::Off);
+}
+
+#[test]
+fn iac_cloc_advice_resolves_by_extension() {
+    let config = parse_config("version = 1\n");
+    let advice = config.cloc_advice_for_language("hcl", 750);
+    assert!(advice.contains("for_each"));
+}
@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+use std::path::PathBuf;
+
+fn parse_config(content: &str) -> Config {
+    let path = PathBuf::from("quench.toml");
+    parse(content, &path).unwrap()
+}
+
+#[test]
+fn java_config_defaults() {
+    let config = parse_config("version = 1\n");
+    assert_eq!(config.java.source, vec!["**/*.java"]);
+    assert!(config.java.tests.contains(&"**/src/test/**".to_string()));
+    assert!(config.java.tests.contains(&"**/*Test.java".to_string()));
+    assert!(config.java.exclude.contains(&"build/".to_string()));
+    assert!(config.java.exclude.contains(&"target/".to_string()));
+}
+
+#[test]
+fn kotlin_config_defaults() {
+    let config = parse_config("version = 1\n");
+    assert!(config.kotlin.source.contains(&"**/*.kt".to_string()));
+    assert!(config.kotlin.source.contains(&"**/*.kts".to_string()));
+    assert!(config.kotlin.tests.contains(&"**/src/*Test/**".to_string()));
+    assert!(config.kotlin.exclude.contains(&".gradle/".to_string()));
+}
+
+#[test]
+fn jvm_suppress_defaults() {
+    let config = parse_config("version = 1\n");
+    assert_eq!(config.java.suppress.check, SuppressLevel::Comment);
+    assert_eq!(config.kotlin.suppress.test.check, Some(SuppressLevel::Allow));
+}
+
+#[test]
+fn jvm_policy_defaults() {
+    let config = parse_config("version = 1\n");
+    assert!(config.java.policy.lint_config.contains(&"checkstyle.xml".to_string()));
+    assert!(config.kotlin.policy.lint_config.contains(&"detekt.yml".to_string()));
+}
+
+#[test]
+fn kotlin_suppress_and_policy_parse() {
+    let config = parse_config(
+        r#"
+version = 1
+
+[kotlin.suppress]
+check = "forbid"
+
+[kotlin.policy]
+check = "warn"
+"#,
+    );
+    assert_eq!(config.kotlin.suppress.check, SuppressLevel::Forbid);
+    assert_eq!(config.java.suppress.check, SuppressLevel::Comment);
+    assert_eq!(config.policy_check_level_for_language("kotlin"), CheckLevel::Warn);
+    assert_eq!(config.policy_check_level_for_language("java"), CheckLevel::Error);
+}
+
+#[test]
+fn jvm_cloc_advice_resolves_by_extension() {
+    let config = parse_config("version = 1\n");
+    let advice = config.cloc_advice_for_language("kt", 750);
+    assert!(advice.contains("companion classes"));
+    assert_eq!(advice, config.cloc_advice_for_language("java", 750));
+}
@@ -6,16 +6,26 @@
 //! Handles quench.toml parsing with version validation and unknown key warnings.
 
 mod checks;
+mod cpp;
+mod csharp;
+mod declarative;
 pub mod defaults;
 pub mod duration;
+mod elixir;
+mod git;
 mod go;
+mod iac;
 mod javascript;
+mod jvm;
 mod lang_common;
+mod php;
 mod python;
 mod ratchet;
 mod ruby;
+mod rust;
 mod shell;
 mod suppress;
+mod swift;
 mod test_config;
 
 use std::path::Path;
@@ -30,18 +40,30 @@ pub(crate) use checks::{
     ClocConfig, DocsAreaConfig, DocsCommitConfig, DocsConfig, EscapeAction, EscapePattern,
     EscapesConfig, LangClocConfig, LineMetric, SpecsConfig, SpecsSectionsConfig,
 };
+pub(crate) use cpp::{CppConfig, CppSuppressConfig};
+pub(crate) use csharp::{CSharpConfig, CSharpSuppressConfig};
+pub use declarative::{
+    ADAPTERS_DIR, DeclarativeAdapterConfig, DeclarativeSuppressConfig, load_adapter_dir,
+};
+pub(crate) use elixir::{ElixirConfig, ElixirSuppressConfig};
+pub use git::{GitBranchConfig, GitCommitConfig, GitConfig};
 pub(crate) use go::{GoConfig, GoPolicyConfig, GoSuppressConfig};
+pub(crate) use iac::IacConfig;
 pub(crate) use javascript::{JavaScriptConfig, JavaScriptPolicyConfig, JavaScriptSuppressConfig};
+pub(crate) use jvm::{JavaConfig, JvmSuppressConfig, KotlinConfig};
+pub(crate) use php::{PhpConfig, PhpSuppressConfig};
 pub(crate) use python::{PythonConfig, PythonPolicyConfig, PythonSuppressConfig};
 pub(crate) use ratchet::RatchetConfig;
 #[cfg(test)]
 pub(crate) use ratchet::RatchetPackageConfig;
 pub(crate) use ruby::{RubyConfig, RubyPolicyConfig, RubySuppressConfig};
+pub use rust::{CfgTestSplitMode, RustConfig, RustDefaults, RustPolicyConfig};
 pub(crate) use shell::{ShellConfig, ShellPolicyConfig, ShellSuppressConfig};
 pub(crate) use suppress::{SuppressConfig, SuppressLevel, SuppressScopeConfig};
+pub(crate) use swift::{SwiftConfig, SwiftSuppressConfig};
 #[cfg(test)]
-pub(crate) use test_config::TestsCommitConfig;
-pub(crate) use test_config::{TestSuiteConfig, TestsConfig};
+pub(crate) use test_config::{SuiteSelection, TestsCommitConfig};
+pub(crate) use test_config::{TestSuiteConfig, TestsConfig, TestsMutationConfig};
 
 pub(crate) use crate::checks::agents::config::{
     AgentsConfig, ContentRule, RequiredSection, SectionsConfig, deserialize_optional_usize,
@@ -84,10 +106,38 @@ pub struct Config {
     #[serde(default)]
     pub golang: GoConfig,
 
+    /// C/C++-specific configuration.
+    #[serde(default)]
+    pub cpp: CppConfig,
+
+    /// C#-specific configuration.
+    #[serde(default)]
+    pub csharp: CSharpConfig,
+
+    /// Elixir-specific configuration.
+    #[serde(default)]
+    pub elixir: ElixirConfig,
+
+    /// Infrastructure-as-code configuration (applies alongside the project language).
+    #[serde(default)]
+    pub iac: IacConfig,
+
     /// JavaScript/TypeScript-specific configuration.
     #[serde(default)]
     pub javascript: JavaScriptConfig,
 
+    /// Java-specific configuration.
+    #[serde(default)]
+    pub java: JavaConfig,
+
+    /// Kotlin-specific configuration.
+    #[serde(default)]
+    pub kotlin: KotlinConfig,
+
+    /// PHP-specific configuration.
+    #[serde(default)]
+    pub php: PhpConfig,
+
     /// Python-specific configuration.
     #[serde(default)]
     pub python: PythonConfig,
@@ -99,88 +149,14 @@ pub struct Config {
     /// Shell-specific configuration.
     #[serde(default)]
     pub shell: ShellConfig,
-}
-
-/// Git configuration.
-#[derive(Debug, Clone, Deserialize)]
-#[serde(default, deny_unknown_fields)]
-pub struct GitConfig {
-    /// Baseline file path for ratcheting.
-    #[serde(default = "GitConfig::default_baseline")]
-    pub baseline: String,
 
-    /// Commit message validation settings.
+    /// Swift-specific configuration.
     #[serde(default)]
-    pub commit: GitCommitConfig,
-}
+    pub swift: SwiftConfig,
 
-impl Default for GitConfig {
-    fn default() -> Self {
-        Self { baseline: Self::default_baseline(), commit: GitCommitConfig::default() }
-    }
-}
-
-impl GitConfig {
-    fn default_baseline() -> String {
-        "notes".to_string()
-    }
-
-    /// Check if baseline is configured to use git notes.
-    pub fn uses_notes(&self) -> bool {
-        self.baseline == "notes"
-    }
-
-    /// Get baseline file path (returns None if using notes mode).
-    pub fn baseline_path(&self) -> Option<&str> {
-        if self.uses_notes() { None } else { Some(&self.baseline) }
-    }
-}
-
-/// Git commit message configuration.
-#[derive(Debug, Clone, Deserialize)]
-#[serde(default, deny_unknown_fields)]
-pub struct GitCommitConfig {
-    /// Check level: "error" | "warn" | "off"
-    pub check: Option<String>,
-
-    /// Commit format: "conventional" | "none" (default: "conventional")
-    pub format: Option<String>,
-
-    /// Allowed commit types (None = use defaults, Some([]) = any type)
-    pub types: Option<Vec<String>>,
-
-    /// Allowed scopes (None = any scope allowed)
-    pub scopes: Option<Vec<String>>,
-
-    /// Check that commit format is documented in agent files (default: true)
-    pub agents: bool,
-
-    /// Create .gitmessage template with --fix (default: true)
-    pub template: bool,
-
-    /// Skip merge commits (e.g., "Merge branch 'x'") (default: true)
-    pub skip_merge: bool,
-}
-
-impl Default for GitCommitConfig {
-    fn default() -> Self {
-        Self {
-            check: None,
-            format: None,
-            types: None,
-            scopes: None,
-            agents: true,
-            template: true,
-            skip_merge: true,
-        }
-    }
-}
-
-impl GitCommitConfig {
-    /// Get effective format (default: "conventional").
-    pub fn effective_format(&self) -> &str {
-        self.format.as_deref().unwrap_or("conventional")
-    }
+    /// Declarative adapters (`[[adapter]]`), plus specs from `.quench/adapters/`.
+    #[serde(default, rename = "adapter")]
+    pub adapters: Vec<DeclarativeAdapterConfig>,
 }
 
 /// Identify a language from an adapter name or file extension.
@@ -189,21 +165,39 @@ impl GitCommitConfig {
 enum ResolvedLanguage {
     Rust,
     Go,
+    Cpp,
+    CSharp,
+    Elixir,
+    Iac,
     JavaScript,
+    Java,
+    Kotlin,
+    Php,
     Python,
     Ruby,
     Shell,
+    Swift,
 }
 
 fn resolve_language(language: &str) -> Option<ResolvedLanguage> {
     match language {
         "rust" | "rs" => Some(ResolvedLanguage::Rust),
         "go" => Some(ResolvedLanguage::Go),
+        "cpp" | "c" | "c++" | "h" | "cc" | "cxx" | "hh" | "hpp" | "hxx" => {
+            Some(ResolvedLanguage::Cpp)
+        }
+        "csharp" | "cs" | "c#" => Some(ResolvedLanguage::CSharp),
+        "elixir" | "ex" | "exs" => Some(ResolvedLanguage::Elixir),
+        "iac" | "terraform" | "tf" | "tfvars" | "hcl" | "dockerfile" => Some(ResolvedLanguage::Iac),
         "javascript" | "typescript" | "js" | "jsx" | "ts" | "tsx" | "mjs" | "mts" | "cjs"
         | "cts" => Some(ResolvedLanguage::JavaScript),
+        "java" => Some(ResolvedLanguage::Java),
+        "kotlin" | "kt" | "kts" => Some(ResolvedLanguage::Kotlin),
+        "php" => Some(ResolvedLanguage::Php),
         "python" | "py" => Some(ResolvedLanguage::Python),
         "ruby" | "rb" | "rake" => Some(ResolvedLanguage::Ruby),
         "shell" | "sh" | "bash" | "zsh" | "fish" | "bats" => Some(ResolvedLanguage::Shell),
+        "swift" => Some(ResolvedLanguage::Swift),
         _ => None,
     }
 }
@@ -211,13 +205,24 @@ fn resolve_language(language: &str) -> Option<ResolvedLanguage> {
 impl Config {
     /// Get the language-specific cloc config (if any) for a given language name or extension.
     fn lang_cloc_config(&self, language: &str) -> Option<&LangClocConfig> {
-        match resolve_language(language)? {
+        let Some(resolved) = resolve_language(language) else {
+            return self.declarative_adapter(language).and_then(|a| a.cloc.as_ref());
+        };
+        match resolved {
             ResolvedLanguage::Rust => self.rust.cloc.as_ref(),
             ResolvedLanguage::Go => self.golang.cloc.as_ref(),
+            ResolvedLanguage::Cpp => self.cpp.cloc.as_ref(),
+            ResolvedLanguage::CSharp => self.csharp.cloc.as_ref(),
+            ResolvedLanguage::Elixir => self.elixir.cloc.as_ref(),
+            ResolvedLanguage::Iac => self.iac.cloc.as_ref(),
             ResolvedLanguage::JavaScript => self.javascript.cloc.as_ref(),
+            ResolvedLanguage::Java => self.java.cloc.as_ref(),
+            ResolvedLanguage::Kotlin => self.kotlin.cloc.as_ref(),
+            ResolvedLanguage::Php => self.php.cloc.as_ref(),
             ResolvedLanguage::Python => self.python.cloc.as_ref(),
             ResolvedLanguage::Ruby => self.ruby.cloc.as_ref(),
             ResolvedLanguage::Shell => self.shell.cloc.as_ref(),
+            ResolvedLanguage::Swift => self.swift.cloc.as_ref(),
         }
     }
 
@@ -226,10 +231,18 @@ impl Config {
         match resolve_language(language)? {
             ResolvedLanguage::Rust => self.rust.cloc_advice.as_deref(),
             ResolvedLanguage::Go => self.golang.cloc_advice.as_deref(),
+            ResolvedLanguage::Cpp => self.cpp.cloc_advice.as_deref(),
+            ResolvedLanguage::CSharp => self.csharp.cloc_advice.as_deref(),
+            ResolvedLanguage::Elixir => self.elixir.cloc_advice.as_deref(),
+            ResolvedLanguage::Iac => self.iac.cloc_advice.as_deref(),
             ResolvedLanguage::JavaScript => self.javascript.cloc_advice.as_deref(),
+            ResolvedLanguage::Java => self.java.cloc_advice.as_deref(),
+            ResolvedLanguage::Kotlin => self.kotlin.cloc_advice.as_deref(),
+            ResolvedLanguage::Php => self.php.cloc_advice.as_deref(),
             ResolvedLanguage::Python => self.python.cloc_advice.as_deref(),
             ResolvedLanguage::Ruby => self.ruby.cloc_advice.as_deref(),
             ResolvedLanguage::Shell => self.shell.cloc_advice.as_deref(),
+            ResolvedLanguage::Swift => self.swift.cloc_advice.as_deref(),
         }
     }
 
@@ -276,10 +289,18 @@ impl Config {
         match resolve_language(language) {
             Some(ResolvedLanguage::Rust) => RustConfig::default_cloc_advice(threshold),
             Some(ResolvedLanguage::Go) => GoConfig::default_cloc_advice(threshold),
+            Some(ResolvedLanguage::Cpp) => CppConfig::default_cloc_advice(threshold),
+            Some(ResolvedLanguage::CSharp) => CSharpConfig::default_cloc_advice(threshold),
+            Some(ResolvedLanguage::Elixir) => ElixirConfig::default_cloc_advice(threshold),
+            Some(ResolvedLanguage::Iac) => IacConfig::default_cloc_advice(threshold),
             Some(ResolvedLanguage::JavaScript) => JavaScriptConfig::default_cloc_advice(threshold),
+            Some(ResolvedLanguage::Java) => JavaConfig::default_cloc_advice(threshold),
+            Some(ResolvedLanguage::Kotlin) => KotlinConfig::default_cloc_advice(threshold),
+            Some(ResolvedLanguage::Php) => PhpConfig::default_cloc_advice(threshold),
             Some(ResolvedLanguage::Python) => PythonConfig::default_cloc_advice(threshold),
             Some(ResolvedLanguage::Ruby) => RubyConfig::default_cloc_advice(threshold),
             Some(ResolvedLanguage::Shell) => ShellConfig::default_cloc_advice(threshold),
+            Some(ResolvedLanguage::Swift) => SwiftConfig::default_cloc_advice(threshold),
             None => defaults::advice::cloc_source(threshold),
         }
     }
@@ -295,145 +316,31 @@ impl Config {
         let lang_level = match language {
             "rust" => self.rust.policy.check,
             "go" | "golang" => self.golang.policy.check,
+            "cpp" | "c" => self.cpp.policy.check,
+            "csharp" | "cs" => self.csharp.policy.check,
+            "elixir" | "ex" => self.elixir.policy.check,
+            "iac" | "terraform" => self.iac.policy.check,
             "javascript" | "js" => self.javascript.policy.check,
+            "java" => self.java.policy.check,
+            "kotlin" | "kt" => self.kotlin.policy.check,
+            "php" => self.php.policy.check,
             "python" | "py" => self.python.policy.check,
             "ruby" | "rb" => self.ruby.policy.check,
             "shell" | "sh" => self.shell.policy.check,
-            _ => None,
+            "swift" => self.swift.policy.check,
+            _ => self.declarative_adapter(language).and_then(|a| a.policy.check),
         };
         lang_level.unwrap_or(CheckLevel::Error)
     }
-}
-
-/// Mode for handling #[cfg(test)] blocks in Rust files.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum CfgTestSplitMode {
-    /// Split #[cfg(test)] blocks into test LOC (default).
-    #[default]
-    Count,
-    /// Fail if source files contain inline #[cfg(test)] blocks.
-    Require,
-    /// Count all lines as source LOC, don't parse for #[cfg(test)].
-    Off,
-}
-
-use lang_common::{LanguageDefaults, define_policy_config};
-
-/// Rust language-specific configuration.
-#[derive(Debug, Clone, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct RustConfig {
-    /// Source file patterns.
-    #[serde(default = "RustDefaults::default_source")]
-    pub source: Vec<String>,
-
-    /// Test file patterns.
-    #[serde(default = "RustDefaults::default_tests")]
-    pub tests: Vec<String>,
-
-    /// Exclude patterns (walker-level: prevents I/O on subtrees).
-    #[serde(default = "RustDefaults::default_exclude", alias = "ignore")]
-    pub exclude: Vec<String>,
-
-    /// How to handle #[cfg(test)] blocks (default: "count").
-    #[serde(default)]
-    pub cfg_test_split: CfgTestSplitMode,
-
-    /// Lint suppression settings.
-    #[serde(default)]
-    pub suppress: SuppressConfig,
-
-    /// Lint configuration policy.
-    #[serde(default)]
-    pub policy: RustPolicyConfig,
-
-    /// Per-language cloc settings.
-    #[serde(default)]
-    pub cloc: Option<LangClocConfig>,
-
-    /// Custom cloc advice for source files (None = use generic default).
-    /// Note: Deprecated in favor of cloc.advice.
-    #[serde(default)]
-    pub cloc_advice: Option<String>,
-}
-
-impl Default for RustConfig {
-    fn default() -> Self {
-        Self {
-            source: RustDefaults::default_source(),
-            tests: RustDefaults::default_tests(),
-            exclude: RustDefaults::default_exclude(),
-            cfg_test_split: CfgTestSplitMode::default(),
-            suppress: SuppressConfig::default(),
-            policy: RustPolicyConfig::default(),
-            cloc: None,
-            cloc_advice: None,
-        }
-    }
-}
-
-/// Rust language defaults.
-pub struct RustDefaults;
-
-impl LanguageDefaults for RustDefaults {
-    fn default_source() -> Vec<String> {
-        vec!["**/*.rs".to_string()]
-    }
-
-    fn default_tests() -> Vec<String> {
-        vec![
-            "**/tests/**".to_string(),
-            "**/test/**/*.rs".to_string(),
-            "**/benches/**".to_string(),
-            "**/*_test.rs".to_string(),
-            "**/*_tests.rs".to_string(),
-            "**/*_tests/**".to_string(),
-        ]
-    }
-
-    fn default_exclude() -> Vec<String> {
-        vec!["target/**".to_string()]
-    }
-
-    fn default_cloc_advice(threshold: usize) -> String {
-        let range = defaults::advice::target_range(threshold);
-        format!(
-            "First, look for repetitive patterns that could be extracted into helper \
-functions, or refactor to be more unit testable and concise.\n\
-\n\
-Then split into sibling modules or submodules in a folder by semantic concern \
-(target {range} each).\n\
-\n\
-Avoid removing individual lines to satisfy the linter; \
-prefer extracting testable code blocks."
-        )
-    }
-}
-
-impl RustConfig {
-    pub(crate) fn default_source() -> Vec<String> {
-        RustDefaults::default_source()
-    }
 
-    pub(crate) fn default_tests() -> Vec<String> {
-        RustDefaults::default_tests()
-    }
-
-    pub(crate) fn default_exclude() -> Vec<String> {
-        RustDefaults::default_exclude()
-    }
-
-    pub(crate) fn default_cloc_advice(threshold: usize) -> String {
-        RustDefaults::default_cloc_advice(threshold)
+    /// Find a declarative adapter by name or by one of its extensions.
+    pub fn declarative_adapter(&self, language: &str) -> Option<&DeclarativeAdapterConfig> {
+        self.adapters.iter().find(|a| {
+            a.name == language || a.extensions.iter().any(|ext| ext.eq_ignore_ascii_case(language))
+        })
     }
 }
 
-define_policy_config!(
-    RustPolicyConfig,
-    ["rustfmt.toml", ".rustfmt.toml", "clippy.toml", ".clippy.toml",]
-);
-
 /// Lint changes policy.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -491,6 +398,16 @@ pub struct LicenseConfig {
     /// Copyright holder.
     pub copyright: Option<String>,
 
+    /// Header template: "spdx" (default), "apache-2.0", or custom text with
+    /// `{year}`, `{holder}` and `{license}` placeholders.
+    pub template: Option<String>,
+
+    /// Copyright year policy.
+    pub year: LicenseYearPolicy,
+
+    /// Dependency license compliance settings.
+    pub deps: LicenseDepsConfig,
+
     /// File patterns to check, organized by language.
     /// Example: { rust = ["**/*.rs"], shell = ["**/*.sh"] }
     #[serde(default)]
@@ -501,6 +418,48 @@ pub struct LicenseConfig {
     pub exclude: Vec<String>,
 }
 
+/// Dependency license compliance configuration (`[check.license.deps]`).
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LicenseDepsConfig {
+    /// Check level: "error" | "warn" | "off"
+    pub check: Option<String>,
+
+    /// Allowed SPDX identifiers (empty: everything not denied).
+    /// A trailing `*` matches any suffix (e.g., "BSD-*").
+    pub allow: Vec<String>,
+
+    /// Denied SPDX identifiers (e.g., "GPL-*", "AGPL-3.0-only").
+    pub deny: Vec<String>,
+
+    /// Dependencies to skip, as "name" or "name@version".
+    pub ignore: Vec<String>,
+}
+
+impl LicenseDepsConfig {
+    /// Enabled by an explicit check level or any allow/deny list.
+    pub fn is_enabled(&self) -> bool {
+        match self.check.as_deref() {
+            Some("off") => false,
+            Some(_) => true,
+            None => !self.allow.is_empty() || !self.deny.is_empty(),
+        }
+    }
+}
+
+/// Which copyright year a license header must carry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LicenseYearPolicy {
+    /// Year or range must include the current year (e.g., `2026`, `2020-2026`).
+    #[default]
+    Current,
+    /// Year the file was first committed (e.g., `2020`).
+    FirstCommit,
+    /// First-commit year through the current year (e.g., `2020-2026`).
+    Range,
+}
+
 /// Build check configuration.
 #[derive(Debug, Default, Deserialize)]
 #[serde(default, deny_unknown_fields)]
@@ -686,14 +645,42 @@ mod defaults_tests;
 #[path = "ratchet_tests.rs"]
 mod ratchet_tests;
 
+#[cfg(test)]
+#[path = "cpp_tests.rs"]
+mod cpp_tests;
+
+#[cfg(test)]
+#[path = "csharp_tests.rs"]
+mod csharp_tests;
+
+#[cfg(test)]
+#[path = "declarative_tests.rs"]
+mod declarative_tests;
+
+#[cfg(test)]
+#[path = "elixir_tests.rs"]
+mod elixir_tests;
+
 #[cfg(test)]
 #[path = "go_tests.rs"]
 mod go_tests;
 
+#[cfg(test)]
+#[path = "iac_tests.rs"]
+mod iac_tests;
+
 #[cfg(test)]
 #[path = "javascript_tests.rs"]
 mod javascript_tests;
 
+#[cfg(test)]
+#[path = "jvm_tests.rs"]
+mod jvm_tests;
+
+#[cfg(test)]
+#[path = "php_tests.rs"]
+mod php_tests;
+
 #[cfg(test)]
 #[path = "python_tests.rs"]
 mod python_tests;
@@ -706,6 +693,10 @@ mod ruby_tests;
 #[path = "shell_tests.rs"]
 mod shell_tests;
 
+#[cfg(test)]
+#[path = "swift_tests.rs"]
+mod swift_tests;
+
 #[cfg(test)]
 #[path = "test_config_tests.rs"]
 mod test_config_tests;
@@ -688,6 +688,28 @@ skip_merge = false
     assert!(!config.git.commit.skip_merge);
 }
 
+// Git branch config tests
+
+#[test]
+fn git_branch_rules_default_to_off() {
+    let config = GitConfig::default();
+    assert!(config.branch.pattern.is_none());
+    assert!(config.branch.max_scopes.is_none());
+}
+
+#[test]
+fn git_branch_rules_can_be_configured() {
+    let toml = r#"
+version = 1
+[git.branch]
+pattern = "^feat/"
+max_scopes = 2
+"#;
+    let config: Config = parse(toml, Path::new("test.toml")).unwrap();
+    assert_eq!(config.git.branch.pattern.as_deref(), Some("^feat/"));
+    assert_eq!(config.git.branch.max_scopes, Some(2));
+}
+
 // Test suite configuration tests
 
 #[test]
@@ -861,7 +883,11 @@ fn git_baseline_defaults_to_notes() {
 
 #[test]
 fn git_baseline_uses_notes_returns_true_for_notes() {
-    let config = GitConfig { baseline: "notes".to_string(), commit: GitCommitConfig::default() };
+    let config = GitConfig {
+        baseline: "notes".to_string(),
+        commit: GitCommitConfig::default(),
+        branch: GitBranchConfig::default(),
+    };
     assert!(config.uses_notes());
     assert!(config.baseline_path().is_none());
 }
@@ -871,6 +897,7 @@ fn git_baseline_uses_notes_returns_false_for_file_path() {
     let config = GitConfig {
         baseline: ".quench/baseline.json".to_string(),
         commit: GitCommitConfig::default(),
+        branch: GitBranchConfig::default(),
     };
     assert!(!config.uses_notes());
     assert_eq!(config.baseline_path(), Some(".quench/baseline.json"));
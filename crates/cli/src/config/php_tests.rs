@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+use std::path::PathBuf;
+
+fn parse_config(content: &str) -> Config {
+    let path = PathBuf::from("quench.toml");
+    parse(content, &path).unwrap()
+}
+
+#[test]
+fn php_config_defaults() {
+    let config = parse_config("version = 1\n");
+    assert_eq!(config.php.source, vec!["**/*.php".to_string()]);
+    assert!(config.php.tests.contains(&"tests/**".to_string()));
+    assert!(config.php.tests.contains(&"**/*Test.php".to_string()));
+    assert!(config.php.exclude.contains(&"vendor/".to_string()));
+}
+
+#[test]
+fn php_suppress_defaults() {
+    let config = parse_config("version = 1\n");
+    assert_eq!(config.php.suppress.check, SuppressLevel::Comment);
+    assert_eq!(config.php.suppress.test.check, Some(SuppressLevel::Allow));
+}
+
+#[test]
+fn php_policy_defaults() {
+    let config = parse_config("version = 1\n");
+    assert!(config.php.policy.lint_config.contains(&"phpstan.neon".to_string()));
+    assert!(config.php.policy.lint_config.contains(&"psalm.xml".to_string()));
+}
+
+#[test]
+fn php_suppress_and_policy_parse() {
+    let config = parse_config(
+        r#"
+version = 1
+
+[php.suppress]
+check = "forbid"
+
+[php.suppress.source]
+allow = ["missingType.iterableValue"]
+
+[php.policy]
+check = "warn"
+"#,
+    );
+    assert_eq!(config.php.suppress.check, SuppressLevel::Forbid);
+    assert_eq!(config.php.suppress.source.allow, vec!["missingType.iterableValue".to_string()]);
+    assert_eq!(config.policy_check_level_for_language("php"), CheckLevel::Warn);
+}
+
+#[test]
+fn php_cloc_advice_resolves_by_extension() {
+    let config = parse_config("version = 1\n");
+    let advice = config.cloc_advice_for_language("php", 750);
+    assert!(advice.contains("PSR-4"));
+}
@@ -50,10 +50,18 @@ pub struct RatchetConfig {
     #[serde(default)]
     pub test_time_max: bool,
 
+    /// Ratchet mutation score (default: false).
+    #[serde(default)]
+    pub mutation: bool,
+
     /// Coverage tolerance (percentage points allowed to drop).
     #[serde(default)]
     pub coverage_tolerance: Option<f64>,
 
+    /// Mutation score tolerance (percentage points allowed to drop).
+    #[serde(default)]
+    pub mutation_tolerance: Option<f64>,
+
     /// Binary size tolerance (e.g., "100KB").
     #[serde(default)]
     pub binary_size_tolerance: Option<String>,
@@ -96,6 +104,11 @@ impl RatchetConfig {
         self.coverage_tolerance
     }
 
+    /// Get mutation score tolerance in percentage points.
+    pub fn mutation_tolerance_pct(&self) -> Option<f64> {
+        self.mutation_tolerance
+    }
+
     /// Get binary size tolerance in bytes.
     pub fn binary_size_tolerance_bytes(&self) -> Option<u64> {
         self.binary_size_tolerance.as_ref().and_then(|s| parse_size(s).ok())
@@ -0,0 +1,136 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Rust language-specific configuration.
+
+use serde::Deserialize;
+
+use super::lang_common::{LanguageDefaults, define_policy_config};
+use super::{CheckLevel, LangClocConfig, LintChangesPolicy, SuppressConfig, defaults};
+
+/// Mode for handling #[cfg(test)] blocks in Rust files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CfgTestSplitMode {
+    /// Split #[cfg(test)] blocks into test LOC (default).
+    #[default]
+    Count,
+    /// Fail if source files contain inline #[cfg(test)] blocks.
+    Require,
+    /// Count all lines as source LOC, don't parse for #[cfg(test)].
+    Off,
+}
+
+/// Rust language-specific configuration.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RustConfig {
+    /// Source file patterns.
+    #[serde(default = "RustDefaults::default_source")]
+    pub source: Vec<String>,
+
+    /// Test file patterns.
+    #[serde(default = "RustDefaults::default_tests")]
+    pub tests: Vec<String>,
+
+    /// Exclude patterns (walker-level: prevents I/O on subtrees).
+    #[serde(default = "RustDefaults::default_exclude", alias = "ignore")]
+    pub exclude: Vec<String>,
+
+    /// How to handle #[cfg(test)] blocks (default: "count").
+    #[serde(default)]
+    pub cfg_test_split: CfgTestSplitMode,
+
+    /// Lint suppression settings.
+    #[serde(default)]
+    pub suppress: SuppressConfig,
+
+    /// Lint configuration policy.
+    #[serde(default)]
+    pub policy: RustPolicyConfig,
+
+    /// Per-language cloc settings.
+    #[serde(default)]
+    pub cloc: Option<LangClocConfig>,
+
+    /// Custom cloc advice for source files (None = use generic default).
+    /// Note: Deprecated in favor of cloc.advice.
+    #[serde(default)]
+    pub cloc_advice: Option<String>,
+}
+
+impl Default for RustConfig {
+    fn default() -> Self {
+        Self {
+            source: RustDefaults::default_source(),
+            tests: RustDefaults::default_tests(),
+            exclude: RustDefaults::default_exclude(),
+            cfg_test_split: CfgTestSplitMode::default(),
+            suppress: SuppressConfig::default(),
+            policy: RustPolicyConfig::default(),
+            cloc: None,
+            cloc_advice: None,
+        }
+    }
+}
+
+/// Rust language defaults.
+pub struct RustDefaults;
+
+impl LanguageDefaults for RustDefaults {
+    fn default_source() -> Vec<String> {
+        vec!["**/*.rs".to_string()]
+    }
+
+    fn default_tests() -> Vec<String> {
+        vec![
+            "**/tests/**".to_string(),
+            "**/test/**/*.rs".to_string(),
+            "**/benches/**".to_string(),
+            "**/*_test.rs".to_string(),
+            "**/*_tests.rs".to_string(),
+            "**/*_tests/**".to_string(),
+        ]
+    }
+
+    fn default_exclude() -> Vec<String> {
+        vec!["target/**".to_string()]
+    }
+
+    fn default_cloc_advice(threshold: usize) -> String {
+        let range = defaults::advice::target_range(threshold);
+        format!(
+            "First, look for repetitive patterns that could be extracted into helper \
+functions, or refactor to be more unit testable and concise.\n\
+\n\
+Then split into sibling modules or submodules in a folder by semantic concern \
+(target {range} each).\n\
+\n\
+Avoid removing individual lines to satisfy the linter; \
+prefer extracting testable code blocks."
+        )
+    }
+}
+
+impl RustConfig {
+    pub(crate) fn default_source() -> Vec<String> {
+        RustDefaults::default_source()
+    }
+
+    pub(crate) fn default_tests() -> Vec<String> {
+        RustDefaults::default_tests()
+    }
+
+    pub(crate) fn default_exclude() -> Vec<String> {
+        RustDefaults::default_exclude()
+    }
+
+    pub(crate) fn default_cloc_advice(threshold: usize) -> String {
+        RustDefaults::default_cloc_advice(threshold)
+    }
+}
+
+define_policy_config!(
+    RustPolicyConfig,
+    ["rustfmt.toml", ".rustfmt.toml", "clippy.toml", ".clippy.toml",]
+);
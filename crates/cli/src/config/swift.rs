@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Swift language-specific configuration.
+
+use serde::Deserialize;
+
+use super::lang_common::{LanguageDefaults, define_policy_config};
+use super::{CheckLevel, LangClocConfig, LintChangesPolicy, SuppressLevel, SuppressScopeConfig};
+
+/// Swift language-specific configuration.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SwiftConfig {
+    /// Source file patterns.
+    #[serde(default = "SwiftDefaults::default_source")]
+    pub source: Vec<String>,
+
+    /// Test file patterns.
+    #[serde(default = "SwiftDefaults::default_tests")]
+    pub tests: Vec<String>,
+
+    /// Exclude patterns (walker-level: prevents I/O on subtrees).
+    #[serde(default = "SwiftDefaults::default_exclude", alias = "ignore")]
+    pub exclude: Vec<String>,
+
+    /// Lint suppression settings.
+    #[serde(default)]
+    pub suppress: SwiftSuppressConfig,
+
+    /// Lint configuration policy.
+    #[serde(default)]
+    pub policy: SwiftPolicyConfig,
+
+    /// Per-language cloc settings.
+    #[serde(default)]
+    pub cloc: Option<LangClocConfig>,
+
+    /// Custom cloc advice for source files (None = use generic default).
+    /// Note: Deprecated in favor of cloc.advice.
+    #[serde(default)]
+    pub cloc_advice: Option<String>,
+}
+
+impl Default for SwiftConfig {
+    fn default() -> Self {
+        Self {
+            source: SwiftDefaults::default_source(),
+            tests: SwiftDefaults::default_tests(),
+            exclude: SwiftDefaults::default_exclude(),
+            suppress: SwiftSuppressConfig::default(),
+            policy: SwiftPolicyConfig::default(),
+            cloc: None,
+            cloc_advice: None,
+        }
+    }
+}
+
+/// Swift language defaults.
+pub struct SwiftDefaults;
+
+impl LanguageDefaults for SwiftDefaults {
+    fn default_source() -> Vec<String> {
+        vec!["**/*.swift".to_string()]
+    }
+
+    fn default_tests() -> Vec<String> {
+        vec![
+            // SwiftPM test targets live under `Tests/<Target>Tests/`
+            "Tests/**".to_string(),
+            "**/Tests/**".to_string(),
+            "**/*Tests.swift".to_string(),
+            "**/*Test.swift".to_string(),
+        ]
+    }
+
+    fn default_exclude() -> Vec<String> {
+        vec![
+            // SwiftPM build products and checked-out dependencies
+            ".build/".to_string(),
+            ".swiftpm/".to_string(),
+            "DerivedData/".to_string(),
+            "Pods/".to_string(),
+            "Carthage/".to_string(),
+        ]
+    }
+
+    fn default_cloc_advice(threshold: usize) -> String {
+        let range = super::defaults::advice::target_range(threshold);
+        format!(
+            "First, look for repetitive patterns that could be extracted into helper \
+functions or protocol extensions.\n\
+\n\
+Then split into smaller files by responsibility (target {range} each); \
+move protocol conformances into their own `extension` files.\n\
+\n\
+Avoid removing individual lines to satisfy the linter; \
+prefer extracting testable code blocks."
+        )
+    }
+}
+
+impl SwiftConfig {
+    pub(crate) fn default_source() -> Vec<String> {
+        SwiftDefaults::default_source()
+    }
+
+    pub(crate) fn default_tests() -> Vec<String> {
+        SwiftDefaults::default_tests()
+    }
+
+    pub(crate) fn default_exclude() -> Vec<String> {
+        SwiftDefaults::default_exclude()
+    }
+
+    pub(crate) fn default_cloc_advice(threshold: usize) -> String {
+        SwiftDefaults::default_cloc_advice(threshold)
+    }
+}
+
+/// Swift suppress configuration (`// swiftlint:disable` directives).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SwiftSuppressConfig {
+    /// Check level: forbid, comment, or allow (default: "comment").
+    #[serde(default = "SwiftSuppressConfig::default_check")]
+    pub check: SuppressLevel,
+
+    /// Optional comment pattern required (default: any comment).
+    #[serde(default)]
+    pub comment: Option<String>,
+
+    /// Source-specific settings.
+    #[serde(default)]
+    pub source: SuppressScopeConfig,
+
+    /// Test-specific settings (overrides base settings for test code).
+    #[serde(default = "SwiftSuppressConfig::default_test")]
+    pub test: SuppressScopeConfig,
+}
+
+impl Default for SwiftSuppressConfig {
+    fn default() -> Self {
+        Self {
+            check: Self::default_check(),
+            comment: None,
+            source: SuppressScopeConfig::default(),
+            test: Self::default_test(),
+        }
+    }
+}
+
+impl SwiftSuppressConfig {
+    pub(crate) fn default_check() -> SuppressLevel {
+        SuppressLevel::Comment // Swift defaults to comment (require justification)
+    }
+
+    pub(crate) fn default_test() -> SuppressScopeConfig {
+        SuppressScopeConfig {
+            check: Some(SuppressLevel::Allow),
+            allow: Vec::new(),
+            forbid: Vec::new(),
+            patterns: std::collections::HashMap::new(),
+        }
+    }
+}
+
+define_policy_config!(
+    SwiftPolicyConfig,
+    [".swiftlint.yml", ".swiftlint.yaml", ".swift-format", ".swiftformat",]
+);
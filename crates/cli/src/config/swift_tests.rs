@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+use super::*;
+use std::path::PathBuf;
+
+fn parse_config(content: &str) -> Config {
+    let path = PathBuf::from("quench.toml");
+    parse(content, &path).unwrap()
+}
+
+#[test]
+fn swift_config_defaults() {
+    let config = parse_config("version = 1\n");
+    assert_eq!(config.swift.source, vec!["**/*.swift".to_string()]);
+    assert!(config.swift.tests.contains(&"Tests/**".to_string()));
+    assert!(config.swift.tests.contains(&"**/*Tests.swift".to_string()));
+    assert!(config.swift.exclude.contains(&".build/".to_string()));
+}
+
+#[test]
+fn swift_suppress_defaults() {
+    let config = parse_config("version = 1\n");
+    assert_eq!(config.swift.suppress.check, SuppressLevel::Comment);
+    assert_eq!(config.swift.suppress.test.check, Some(SuppressLevel::Allow));
+}
+
+#[test]
+fn swift_policy_defaults() {
+    let config = parse_config("version = 1\n");
+    assert!(config.swift.policy.lint_config.contains(&".swiftlint.yml".to_string()));
+    assert!(config.swift.policy.lint_config.contains(&".swift-format".to_string()));
+}
+
+#[test]
+fn swift_suppress_and_policy_parse() {
+    let config = parse_config(
+        r#"
+version = 1
+
+[swift.suppress]
+check = "forbid"
+
+[swift.suppress.source]
+allow = ["line_length"]
+
+[swift.policy]
+check = "warn"
+"#,
+    );
+    assert_eq!(config.swift.suppress.check, SuppressLevel::Forbid);
+    assert_eq!(config.swift.suppress.source.allow, vec!["line_length".to_string()]);
+    assert_eq!(config.policy_check_level_for_language("swift"), CheckLevel::Warn);
+}
+
+#[test]
+fn swift_cloc_advice_resolves_by_extension() {
+    let config = parse_config("version = 1\n");
+    let advice = config.cloc_advice_for_language("swift", 750);
+    assert!(advice.contains("protocol extensions"));
+}
@@ -20,6 +20,20 @@ pub struct TestsConfig {
     #[serde(default)]
     pub auto: bool,
 
+    /// Suite selection: "all" | "affected" (only suites whose inputs changed).
+    #[serde(default)]
+    pub select: SuiteSelection,
+
+    /// Maximum number of suites to run concurrently.
+    ///
+    /// Defaults to 1 in fast mode and to the number of suites in CI mode.
+    #[serde(default)]
+    pub jobs: Option<usize>,
+
+    /// Time budget shared by all suites (suites not started in time fail).
+    #[serde(default, deserialize_with = "duration::deserialize_option")]
+    pub timeout: Option<std::time::Duration>,
+
     /// Commit message validation settings.
     #[serde(default)]
     pub commit: TestsCommitConfig,
@@ -35,6 +49,28 @@ pub struct TestsConfig {
     /// Coverage threshold checking.
     #[serde(default)]
     pub coverage: TestsCoverageConfig,
+
+    /// Mutation testing (CI only; disabled unless configured).
+    #[serde(default)]
+    pub mutation: Option<TestsMutationConfig>,
+}
+
+impl TestsConfig {
+    /// Whether only suites affected by changed files should run.
+    pub fn select_affected(&self) -> bool {
+        self.select == SuiteSelection::Affected
+    }
+}
+
+/// Which configured test suites to run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SuiteSelection {
+    /// Run every suite (default).
+    #[default]
+    All,
+    /// Run only suites whose inputs changed.
+    Affected,
 }
 
 /// Configuration for a single test suite.
@@ -83,6 +119,14 @@ pub struct TestSuiteConfig {
     /// Timeout for suite execution (kills process if exceeded).
     #[serde(default, deserialize_with = "duration::deserialize_option")]
     pub timeout: Option<std::time::Duration>,
+
+    /// Never run concurrently with other suites (e.g., shares a local database).
+    #[serde(default)]
+    pub exclusive: bool,
+
+    /// Environments to run for tox/nox (default: the tool's default environments).
+    #[serde(default)]
+    pub envs: Vec<String>,
 }
 
 /// Time limit configuration for test suites.
@@ -143,6 +187,54 @@ impl TestsCoverageConfig {
     }
 }
 
+/// Mutation testing configuration.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TestsMutationConfig {
+    /// Check level: "error" | "warn" | "off"
+    #[serde(default = "TestsMutationConfig::default_check")]
+    pub check: String,
+
+    /// Tool: "cargo-mutants" | "mutmut" | "stryker" | "go-mutesting"
+    /// (default: detected from the project language).
+    #[serde(default)]
+    pub tool: Option<String>,
+
+    /// Minimum mutation score percentage (0-100).
+    #[serde(default)]
+    pub min: Option<f64>,
+
+    /// Only mutate changed files when a change set is known (default: true).
+    #[serde(default = "TestsMutationConfig::default_changed_only")]
+    pub changed_only: bool,
+
+    /// Timeout for the mutation run (kills process if exceeded).
+    #[serde(default, deserialize_with = "duration::deserialize_option")]
+    pub timeout: Option<std::time::Duration>,
+}
+
+impl Default for TestsMutationConfig {
+    fn default() -> Self {
+        Self {
+            check: Self::default_check(),
+            tool: None,
+            min: None,
+            changed_only: Self::default_changed_only(),
+            timeout: None,
+        }
+    }
+}
+
+impl TestsMutationConfig {
+    fn default_check() -> String {
+        "warn".to_string()
+    }
+
+    fn default_changed_only() -> bool {
+        true
+    }
+}
+
 /// Tests commit check configuration.
 #[derive(Debug, Deserialize)]
 #[serde(default, deny_unknown_fields)]
@@ -15,9 +15,22 @@ fn tests_config_defaults() {
     let config = parse_config("version = 1\n");
     assert!(config.check.tests.check.is_none());
     assert!(!config.check.tests.auto);
+    assert_eq!(config.check.tests.select, SuiteSelection::All);
     assert!(config.check.tests.suite.is_empty());
 }
 
+#[test]
+fn tests_select_parses_affected() {
+    let config = parse_config("version = 1\n[check.tests]\nselect = \"affected\"\n");
+    assert!(config.check.tests.select_affected());
+}
+
+#[test]
+fn tests_select_rejects_unknown_value() {
+    let path = PathBuf::from("quench.toml");
+    assert!(parse("version = 1\n[check.tests]\nselect = \"afected\"\n", &path).is_err());
+}
+
 #[test]
 fn tests_commit_config_defaults() {
     let commit = TestsCommitConfig::default();
@@ -96,3 +109,29 @@ min = 90.0
     assert_eq!(config.check.tests.coverage.min, Some(80.0));
     assert_eq!(config.check.tests.coverage.package["core"].min, 90.0);
 }
+
+#[test]
+fn tests_mutation_disabled_by_default() {
+    let config = parse_config("version = 1\n");
+    assert!(config.check.tests.mutation.is_none());
+}
+
+#[test]
+fn tests_mutation_config_parses() {
+    let config = parse_config(
+        r#"
+version = 1
+
+[check.tests.mutation]
+tool = "cargo-mutants"
+min = 70
+timeout = "30m"
+"#,
+    );
+    let mutation = config.check.tests.mutation.unwrap();
+    assert_eq!(mutation.check, "warn");
+    assert_eq!(mutation.tool.as_deref(), Some("cargo-mutants"));
+    assert_eq!(mutation.min, Some(70.0));
+    assert!(mutation.changed_only);
+    assert_eq!(mutation.timeout, Some(std::time::Duration::from_secs(1800)));
+}
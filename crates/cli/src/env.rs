@@ -83,6 +83,40 @@ pub fn xdg_config_home() -> Option<PathBuf> {
     std::env::var_os(names::XDG_CONFIG_HOME).map(PathBuf::from)
 }
 
+/// Returns the Cargo home directory from `CARGO_HOME`.
+pub fn cargo_home() -> Option<PathBuf> {
+    std::env::var_os(names::CARGO_HOME).map(PathBuf::from)
+}
+
+/// Returns the Go module cache directory from `GOMODCACHE`.
+pub fn go_mod_cache() -> Option<PathBuf> {
+    std::env::var_os(names::GOMODCACHE).map(PathBuf::from)
+}
+
+/// Returns the raw `GOPATH` value (a path list).
+pub fn go_path() -> Option<std::ffi::OsString> {
+    std::env::var_os(names::GOPATH)
+}
+
+/// Returns the commit author as `Name <email>` from `GIT_AUTHOR_NAME` and
+/// `GIT_AUTHOR_EMAIL` (set by git when running hooks).
+pub fn git_author() -> Option<String> {
+    let name = std::env::var(names::GIT_AUTHOR_NAME).ok()?;
+    let email = std::env::var(names::GIT_AUTHOR_EMAIL).ok()?;
+    Some(format!("{name} <{email}>"))
+}
+
+/// Returns the source branch of a CI pull or merge request, from
+/// `GITHUB_HEAD_REF` or `CI_MERGE_REQUEST_SOURCE_BRANCH_NAME`.
+///
+/// CI usually checks out a detached merge commit, so HEAD has no branch.
+pub fn ci_branch() -> Option<String> {
+    [names::GITHUB_HEAD_REF, names::CI_MERGE_REQUEST_SOURCE_BRANCH_NAME]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|branch| !branch.is_empty())
+}
+
 #[cfg(test)]
 #[path = "env_tests.rs"]
 mod tests;
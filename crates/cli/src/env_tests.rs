@@ -65,6 +65,29 @@ fn names_xdg_config_home_is_correct() {
     assert_eq!(names::XDG_CONFIG_HOME, "XDG_CONFIG_HOME");
 }
 
+#[test]
+fn names_cargo_home_is_correct() {
+    assert_eq!(names::CARGO_HOME, "CARGO_HOME");
+}
+
+#[test]
+fn names_go_module_vars_are_correct() {
+    assert_eq!(names::GOMODCACHE, "GOMODCACHE");
+    assert_eq!(names::GOPATH, "GOPATH");
+}
+
+#[test]
+fn names_git_author_vars_are_correct() {
+    assert_eq!(names::GIT_AUTHOR_NAME, "GIT_AUTHOR_NAME");
+    assert_eq!(names::GIT_AUTHOR_EMAIL, "GIT_AUTHOR_EMAIL");
+}
+
+#[test]
+fn names_ci_branch_vars_are_correct() {
+    assert_eq!(names::GITHUB_HEAD_REF, "GITHUB_HEAD_REF");
+    assert_eq!(names::CI_MERGE_REQUEST_SOURCE_BRANCH_NAME, "CI_MERGE_REQUEST_SOURCE_BRANCH_NAME");
+}
+
 #[test]
 fn quench_log_var_returns_correct_name() {
     assert_eq!(quench_log_var(), "QUENCH_LOG");
@@ -13,9 +13,11 @@
 //! - Renamed files: path from `new_file()` (the new location)
 //! - Deleted files: path from `old_file()` (since `new_file()` is empty)
 
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use anyhow::Context;
+use chrono::Datelike;
 use git2::Repository;
 
 /// Extract file path from a diff delta.
@@ -34,6 +36,10 @@ pub struct Commit {
     pub hash: String,
     /// Full commit message (subject line only).
     pub message: String,
+    /// Complete commit message, including the body and trailers.
+    pub full_message: String,
+    /// Commit author as `Name <email>`.
+    pub author: String,
 }
 
 /// Collect commits from a revwalk iterator into a Vec.
@@ -45,6 +51,8 @@ fn collect_commits(repo: &Repository, revwalk: git2::Revwalk) -> anyhow::Result<
         commits.push(Commit {
             hash: oid.to_string()[..7].to_string(),
             message: commit.summary().unwrap_or("").to_string(),
+            full_message: commit.message().unwrap_or("").to_string(),
+            author: commit.author().to_string(),
         });
     }
     Ok(commits)
@@ -110,18 +118,37 @@ pub fn get_all_branch_commits(root: &Path) -> anyhow::Result<Vec<Commit>> {
         get_commits_since(root, &base)
     } else {
         // No base branch found, get all commits
-        let repo = Repository::discover(root).context("Failed to open repository")?;
-        let head_oid = repo
-            .head()
-            .context("Failed to get HEAD")?
-            .target()
-            .ok_or_else(|| anyhow::anyhow!("HEAD has no target"))?;
+        get_all_commits(root)
+    }
+}
 
-        let mut revwalk = repo.revwalk()?;
-        revwalk.push(head_oid)?;
+/// Get all commits reachable from HEAD.
+///
+/// Returns commits from newest to oldest.
+pub fn get_all_commits(root: &Path) -> anyhow::Result<Vec<Commit>> {
+    let repo = Repository::discover(root).context("Failed to open repository")?;
+    let head_oid = repo
+        .head()
+        .context("Failed to get HEAD")?
+        .target()
+        .ok_or_else(|| anyhow::anyhow!("HEAD has no target"))?;
 
-        collect_commits(&repo, revwalk)
-    }
+    let mut revwalk = repo.revwalk()?;
+    revwalk.push(head_oid)?;
+
+    collect_commits(&repo, revwalk)
+}
+
+/// Get the most recent tag reachable from HEAD (like `git describe --tags --abbrev=0`).
+///
+/// Returns None if no tag is reachable.
+pub fn latest_tag(root: &Path) -> anyhow::Result<Option<String>> {
+    let repo = Repository::discover(root).context("Failed to open repository")?;
+    let Ok(describe) = repo.describe(git2::DescribeOptions::new().describe_tags()) else {
+        return Ok(None);
+    };
+    let tag = describe.format(Some(git2::DescribeFormatOptions::new().abbreviated_size(0)))?;
+    Ok(Some(tag))
 }
 
 /// Get list of changed files compared to a git base ref.
@@ -286,6 +313,79 @@ pub fn find_ratchet_base(root: &Path, base_ref: Option<&str>) -> anyhow::Result<
     Ok(head.id().to_string())
 }
 
+/// Get the year each file was first committed, following renames.
+///
+/// Matches the oldest entry of `git log --follow` for every file, computed in a
+/// single history walk. Paths are relative to `root`; untracked files are absent.
+/// Fails on shallow clones, whose grafted root commit would "add" every file.
+pub fn first_commit_years(root: &Path) -> anyhow::Result<HashMap<PathBuf, i32>> {
+    let repo = Repository::discover(root).context("Failed to open repository")?;
+    if repo.is_shallow() {
+        anyhow::bail!(
+            "shallow clone; fetch the full history (`git fetch --unshallow`, or `fetch-depth: 0` for actions/checkout)"
+        );
+    }
+    let workdir = repo.workdir().ok_or_else(|| anyhow::anyhow!("bare repository"))?;
+    let prefix = root
+        .canonicalize()?
+        .strip_prefix(workdir.canonicalize()?)
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+
+    let mut revwalk = repo.revwalk()?;
+    if revwalk.push_head().is_err() {
+        // No commits yet
+        return Ok(HashMap::new());
+    }
+    revwalk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::REVERSE)?;
+
+    let mut years: HashMap<PathBuf, i32> = HashMap::new();
+    for oid in revwalk {
+        let commit = repo.find_commit(oid?)?;
+        let when = commit.author().when();
+        let seconds = when.seconds() + i64::from(when.offset_minutes()) * 60;
+        let Some(year) = chrono::DateTime::from_timestamp(seconds, 0).map(|d| d.year()) else {
+            continue;
+        };
+
+        let parent_tree = match commit.parent(0) {
+            Ok(parent) => Some(parent.tree()?),
+            Err(_) => None,
+        };
+        let mut diff = repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&commit.tree()?), None)?;
+        diff.find_similar(Some(git2::DiffFindOptions::new().renames(true)))?;
+
+        for delta in diff.deltas() {
+            if delta.status() == git2::Delta::Deleted {
+                continue;
+            }
+            let Some(path) = delta.new_file().path() else {
+                continue;
+            };
+            // Renamed files keep the year of their original path
+            let inherited = match delta.status() {
+                git2::Delta::Renamed | git2::Delta::Copied => {
+                    delta.old_file().path().and_then(|old| years.get(old).copied())
+                }
+                _ => None,
+            };
+            match inherited {
+                Some(first) => {
+                    years.insert(path.to_path_buf(), first);
+                }
+                None => {
+                    years.entry(path.to_path_buf()).or_insert(year);
+                }
+            }
+        }
+    }
+
+    Ok(years
+        .into_iter()
+        .filter_map(|(path, year)| Some((path.strip_prefix(&prefix).ok()?.to_path_buf(), year)))
+        .collect())
+}
+
 #[cfg(test)]
 #[path = "git_tests.rs"]
 mod tests;
@@ -493,3 +493,120 @@ fn find_ratchet_base_errors_for_unborn_branch() {
     let result = find_ratchet_base(temp.path(), None);
     assert!(result.is_err());
 }
+
+// =============================================================================
+// FIRST_COMMIT_YEARS TESTS
+// =============================================================================
+
+/// Commit staged changes with a fixed author date.
+fn git_commit_dated(temp: &TempDir, message: &str, date: &str) {
+    Command::new("git")
+        .args(["commit", "-m", message])
+        .env("GIT_AUTHOR_DATE", date)
+        .env("GIT_COMMITTER_DATE", date)
+        .current_dir(temp.path())
+        .output()
+        .expect("Failed to git commit");
+}
+
+#[test]
+fn first_commit_years_uses_oldest_commit() {
+    let temp = TempDir::new().unwrap();
+    init_git_repo(&temp);
+
+    create_and_stage(&temp, "lib.rs", "fn a() {}\n");
+    git_commit_dated(&temp, "feat: add lib", "2020-06-01T12:00:00Z");
+    create_and_stage(&temp, "lib.rs", "fn a() {}\nfn b() {}\n");
+    create_and_stage(&temp, "new.rs", "fn c() {}\n");
+    git_commit_dated(&temp, "feat: add new", "2023-06-01T12:00:00Z");
+
+    let years = first_commit_years(temp.path()).unwrap();
+    assert_eq!(years.get(Path::new("lib.rs")), Some(&2020));
+    assert_eq!(years.get(Path::new("new.rs")), Some(&2023));
+}
+
+#[test]
+fn first_commit_years_follows_renames() {
+    let temp = TempDir::new().unwrap();
+    init_git_repo(&temp);
+
+    create_and_stage(&temp, "old.rs", "fn parse() {}\nfn lex() {}\nfn emit() {}\n");
+    git_commit_dated(&temp, "feat: add parser", "2019-03-01T12:00:00Z");
+    git_mv(&temp, "old.rs", "new.rs");
+    git_commit_dated(&temp, "refactor: rename", "2024-03-01T12:00:00Z");
+
+    let years = first_commit_years(temp.path()).unwrap();
+    assert_eq!(years.get(Path::new("new.rs")), Some(&2019));
+}
+
+#[test]
+fn first_commit_years_relative_to_subdirectory() {
+    let temp = TempDir::new().unwrap();
+    init_git_repo(&temp);
+
+    std::fs::create_dir_all(temp.path().join("crates/core")).unwrap();
+    create_and_stage(&temp, "crates/core/lib.rs", "fn a() {}\n");
+    git_commit_dated(&temp, "feat: add core", "2021-01-15T12:00:00Z");
+
+    let years = first_commit_years(&temp.path().join("crates/core")).unwrap();
+    assert_eq!(years.get(Path::new("lib.rs")), Some(&2021));
+}
+
+#[test]
+fn first_commit_years_empty_repo() {
+    let temp = TempDir::new().unwrap();
+    init_git_repo(&temp);
+
+    assert!(first_commit_years(temp.path()).unwrap().is_empty());
+}
+
+// =============================================================================
+// HISTORY AND TAG TESTS
+// =============================================================================
+
+/// Tag HEAD with a lightweight tag.
+fn git_tag(temp: &TempDir, name: &str) {
+    Command::new("git")
+        .args(["tag", name])
+        .current_dir(temp.path())
+        .output()
+        .expect("Failed to tag");
+}
+
+#[test]
+fn get_all_commits_returns_newest_first() {
+    let temp = TempDir::new().unwrap();
+    init_git_repo(&temp);
+    create_initial_commit(&temp);
+    create_and_stage(&temp, "a.txt", "a");
+    git_commit(&temp, "feat: add a");
+
+    let commits = get_all_commits(temp.path()).unwrap();
+
+    let messages: Vec<_> = commits.iter().map(|c| c.message.as_str()).collect();
+    assert_eq!(messages, ["feat: add a", "chore: initial commit"]);
+}
+
+#[test]
+fn latest_tag_finds_most_recent_reachable_tag() {
+    let temp = TempDir::new().unwrap();
+    init_git_repo(&temp);
+    create_initial_commit(&temp);
+    git_tag(&temp, "v0.1.0");
+    create_and_stage(&temp, "a.txt", "a");
+    git_commit(&temp, "feat: add a");
+    git_tag(&temp, "v0.2.0");
+    create_and_stage(&temp, "b.txt", "b");
+    git_commit(&temp, "fix: b");
+
+    assert_eq!(latest_tag(temp.path()).unwrap().as_deref(), Some("v0.2.0"));
+}
+
+#[test]
+fn latest_tag_none_without_tags() {
+    let temp = TempDir::new().unwrap();
+    init_git_repo(&temp);
+    create_initial_commit(&temp);
+
+    assert_eq!(latest_tag(temp.path()).unwrap(), None);
+}
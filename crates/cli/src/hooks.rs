@@ -0,0 +1,172 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Git hook installation.
+//!
+//! `quench hooks install` runs `quench check --staged` from the `pre-commit`
+//! hook and `quench check --commit-msg` from the `commit-msg` hook, so bad
+//! code and commit messages are caught before they land. Existing hooks are
+//! kept and run first. Projects using the pre-commit framework or lefthook
+//! get equivalent configuration instead.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use git2::Repository;
+
+/// Marker line identifying hooks written by quench.
+pub const HOOK_MARKER: &str = "# Installed by `quench hooks install`";
+
+/// Suffix for an existing hook that a quench hook chains into.
+pub const CHAINED_SUFFIX: &str = ".pre-quench";
+
+/// A git hook that runs quench.
+#[derive(Debug)]
+pub struct Hook {
+    /// Hook name (file name in the hooks directory).
+    pub name: &'static str,
+    /// Shell command run by the hook.
+    pub command: &'static str,
+}
+
+/// Hooks installed by `quench hooks install`.
+pub const HOOKS: &[Hook] = &[
+    Hook { name: "pre-commit", command: "quench check --staged" },
+    Hook { name: "commit-msg", command: "quench check --commit-msg \"$1\"" },
+];
+
+/// `.pre-commit-config.yaml` equivalent of [`HOOKS`].
+pub const PRE_COMMIT_CONFIG: &str = r#"# .pre-commit-config.yaml
+default_install_hook_types: [pre-commit, commit-msg]
+repos:
+  - repo: local
+    hooks:
+      - id: quench
+        name: quench check
+        entry: quench check --staged
+        language: system
+        pass_filenames: false
+        always_run: true
+        stages: [pre-commit]
+      - id: quench-commit-msg
+        name: quench commit message
+        entry: quench check --commit-msg
+        language: system
+        stages: [commit-msg]
+"#;
+
+/// `lefthook.yml` equivalent of [`HOOKS`].
+pub const LEFTHOOK_CONFIG: &str = r#"# lefthook.yml
+pre-commit:
+  commands:
+    quench:
+      run: quench check --staged
+commit-msg:
+  commands:
+    quench:
+      run: quench check --commit-msg {1}
+"#;
+
+/// Result of installing a hook.
+#[derive(Debug, PartialEq, Eq)]
+pub enum HookAction {
+    /// No hook existed; the quench hook was written.
+    Created,
+    /// An existing hook was moved to this path and runs before quench.
+    Chained(PathBuf),
+    /// A quench hook from an earlier install was rewritten.
+    Updated,
+    /// The quench hook is already installed.
+    Unchanged,
+}
+
+/// Directory git runs hooks from: `core.hooksPath` when set, otherwise
+/// `hooks` in the repository's git directory (shared by worktrees).
+pub fn hooks_dir(root: &Path) -> anyhow::Result<PathBuf> {
+    let repo = Repository::discover(root).context("not a git repository")?;
+    if let Ok(path) = repo.config().and_then(|config| config.get_path("core.hooksPath")) {
+        // Relative paths resolve from the top of the working tree
+        let base = repo.workdir().unwrap_or_else(|| repo.path());
+        return Ok(base.join(path));
+    }
+    Ok(common_dir(&repo).join("hooks"))
+}
+
+/// Git directory shared by all worktrees (a worktree's git directory names
+/// it in its `commondir` file).
+fn common_dir(repo: &Repository) -> PathBuf {
+    match std::fs::read_to_string(repo.path().join("commondir")) {
+        Ok(common) => repo.path().join(common.trim()),
+        Err(_) => repo.path().to_path_buf(),
+    }
+}
+
+/// Shell script for `hook`, running the chained hook first when present.
+///
+/// Like git, the chained hook is skipped unless it is executable.
+pub fn hook_script(hook: &Hook, chained: bool) -> String {
+    let mut script = format!("#!/bin/sh\n{HOOK_MARKER}\n\n");
+    if chained {
+        script.push_str(&format!(
+            "chained=\"$(dirname \"$0\")/{}{CHAINED_SUFFIX}\"\n\
+             if [ -x \"$chained\" ]; then\n    \"$chained\" \"$@\" || exit $?\nfi\n",
+            hook.name
+        ));
+    }
+    script.push_str(&format!("exec {}\n", hook.command));
+    script
+}
+
+/// Install `hook` in `dir`, chaining into an existing hook.
+pub fn install_hook(dir: &Path, hook: &Hook) -> anyhow::Result<HookAction> {
+    let path = dir.join(hook.name);
+    let chained_path = dir.join(format!("{}{CHAINED_SUFFIX}", hook.name));
+
+    let action = match std::fs::read_to_string(&path) {
+        Ok(existing) if existing.contains(HOOK_MARKER) => {
+            if existing == hook_script(hook, chained_path.exists()) {
+                return Ok(HookAction::Unchanged);
+            }
+            HookAction::Updated
+        }
+        Ok(_) => {
+            if chained_path.exists() {
+                anyhow::bail!(
+                    "{} exists and {} is already taken; merge them by hand",
+                    path.display(),
+                    chained_path.display()
+                );
+            }
+            std::fs::rename(&path, &chained_path)
+                .with_context(|| format!("cannot move {}", path.display()))?;
+            HookAction::Chained(chained_path.clone())
+        }
+        // Never replace a hook that exists but can't be read (e.g. a dangling symlink)
+        Err(_) if path.symlink_metadata().is_ok() => {
+            anyhow::bail!("cannot read existing hook {}", path.display())
+        }
+        Err(_) => HookAction::Created,
+    };
+
+    std::fs::create_dir_all(dir).with_context(|| format!("cannot create {}", dir.display()))?;
+    std::fs::write(&path, hook_script(hook, chained_path.exists()))
+        .with_context(|| format!("cannot write {}", path.display()))?;
+    make_executable(&path)?;
+    Ok(action)
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> anyhow::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))
+        .with_context(|| format!("cannot make {} executable", path.display()))
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> anyhow::Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "hooks_tests.rs"]
+mod tests;
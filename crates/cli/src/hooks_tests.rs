@@ -0,0 +1,196 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use super::*;
+use tempfile::TempDir;
+
+fn pre_commit() -> &'static Hook {
+    &HOOKS[0]
+}
+
+// =============================================================================
+// HOOK SCRIPT TESTS
+// =============================================================================
+
+#[test]
+fn hook_script_runs_quench() {
+    let script = hook_script(pre_commit(), false);
+    assert!(script.starts_with("#!/bin/sh\n"));
+    assert!(script.contains(HOOK_MARKER));
+    assert!(script.ends_with("exec quench check --staged\n"));
+}
+
+#[test]
+fn hook_script_runs_chained_hook_first() {
+    let script = hook_script(pre_commit(), true);
+    assert!(script.contains("chained=\"$(dirname \"$0\")/pre-commit.pre-quench\""));
+    let chained = script.find("\"$chained\" \"$@\" || exit $?").unwrap();
+    let quench = script.find("exec quench").unwrap();
+    assert!(chained < quench);
+}
+
+#[test]
+fn hook_script_skips_non_executable_chained_hook() {
+    let script = hook_script(pre_commit(), true);
+    let guard = script.find("if [ -x \"$chained\" ]; then").unwrap();
+    let chained = script.find("\"$chained\" \"$@\"").unwrap();
+    assert!(guard < chained);
+}
+
+#[test]
+fn commit_msg_hook_passes_message_file() {
+    let hook = HOOKS.iter().find(|h| h.name == "commit-msg").unwrap();
+    assert!(hook_script(hook, false).contains("quench check --commit-msg \"$1\""));
+}
+
+// =============================================================================
+// INSTALL TESTS
+// =============================================================================
+
+#[test]
+fn install_creates_hook() {
+    let temp = TempDir::new().unwrap();
+    let dir = temp.path().join("hooks");
+
+    let action = install_hook(&dir, pre_commit()).unwrap();
+
+    assert_eq!(action, HookAction::Created);
+    let content = std::fs::read_to_string(dir.join("pre-commit")).unwrap();
+    assert_eq!(content, hook_script(pre_commit(), false));
+}
+
+#[cfg(unix)]
+#[test]
+fn install_makes_hook_executable() {
+    use std::os::unix::fs::PermissionsExt;
+    let temp = TempDir::new().unwrap();
+
+    install_hook(temp.path(), pre_commit()).unwrap();
+
+    let mode = std::fs::metadata(temp.path().join("pre-commit")).unwrap().permissions().mode();
+    assert_eq!(mode & 0o111, 0o111);
+}
+
+#[test]
+fn install_chains_existing_hook() {
+    let temp = TempDir::new().unwrap();
+    std::fs::write(temp.path().join("pre-commit"), "#!/bin/sh\nmake lint\n").unwrap();
+
+    let action = install_hook(temp.path(), pre_commit()).unwrap();
+
+    let chained = temp.path().join("pre-commit.pre-quench");
+    assert_eq!(action, HookAction::Chained(chained.clone()));
+    assert_eq!(std::fs::read_to_string(chained).unwrap(), "#!/bin/sh\nmake lint\n");
+    let content = std::fs::read_to_string(temp.path().join("pre-commit")).unwrap();
+    assert_eq!(content, hook_script(pre_commit(), true));
+}
+
+/// Run an installed hook script, returning whether it succeeded.
+#[cfg(unix)]
+fn run_hook(path: &Path) -> bool {
+    std::process::Command::new("sh").arg(path).status().unwrap().success()
+}
+
+#[cfg(unix)]
+#[test]
+fn installed_hook_skips_non_executable_chained_hook() {
+    use std::os::unix::fs::PermissionsExt;
+    const HOOK: Hook = Hook { name: "pre-commit", command: "true" };
+    let temp = TempDir::new().unwrap();
+    let existing = temp.path().join("pre-commit");
+    std::fs::write(&existing, "#!/bin/sh\nexit 1\n").unwrap();
+    std::fs::set_permissions(&existing, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+    install_hook(temp.path(), &HOOK).unwrap();
+
+    let chained = temp.path().join("pre-commit.pre-quench");
+    let mode = std::fs::metadata(&chained).unwrap().permissions().mode();
+    assert_eq!(mode & 0o111, 0, "chained hook keeps its original mode");
+    assert!(run_hook(&existing), "non-executable chained hook is skipped");
+
+    std::fs::set_permissions(&chained, std::fs::Permissions::from_mode(0o755)).unwrap();
+    assert!(!run_hook(&existing), "executable chained hook runs first");
+}
+
+#[test]
+fn install_twice_is_unchanged() {
+    let temp = TempDir::new().unwrap();
+    std::fs::write(temp.path().join("pre-commit"), "#!/bin/sh\nmake lint\n").unwrap();
+
+    install_hook(temp.path(), pre_commit()).unwrap();
+    let action = install_hook(temp.path(), pre_commit()).unwrap();
+
+    assert_eq!(action, HookAction::Unchanged);
+    let content = std::fs::read_to_string(temp.path().join("pre-commit")).unwrap();
+    assert_eq!(content, hook_script(pre_commit(), true));
+}
+
+#[test]
+fn install_updates_outdated_quench_hook() {
+    let temp = TempDir::new().unwrap();
+    let old = format!("#!/bin/sh\n{HOOK_MARKER}\nquench check\n");
+    std::fs::write(temp.path().join("pre-commit"), old).unwrap();
+
+    let action = install_hook(temp.path(), pre_commit()).unwrap();
+
+    assert_eq!(action, HookAction::Updated);
+    let content = std::fs::read_to_string(temp.path().join("pre-commit")).unwrap();
+    assert_eq!(content, hook_script(pre_commit(), false));
+}
+
+#[test]
+fn install_refuses_to_overwrite_chained_hook() {
+    let temp = TempDir::new().unwrap();
+    std::fs::write(temp.path().join("pre-commit"), "#!/bin/sh\nnew\n").unwrap();
+    std::fs::write(temp.path().join("pre-commit.pre-quench"), "#!/bin/sh\nold\n").unwrap();
+
+    assert!(install_hook(temp.path(), pre_commit()).is_err());
+    let content = std::fs::read_to_string(temp.path().join("pre-commit")).unwrap();
+    assert_eq!(content, "#!/bin/sh\nnew\n");
+}
+
+// =============================================================================
+// HOOKS DIRECTORY TESTS
+// =============================================================================
+
+#[test]
+fn hooks_dir_defaults_to_git_dir() {
+    let temp = TempDir::new().unwrap();
+    Repository::init(temp.path()).unwrap();
+
+    let dir = hooks_dir(temp.path()).unwrap();
+
+    assert_eq!(dir.canonicalize().unwrap(), temp.path().join(".git/hooks").canonicalize().unwrap());
+}
+
+#[test]
+fn hooks_dir_respects_core_hooks_path() {
+    let temp = TempDir::new().unwrap();
+    let repo = Repository::init(temp.path()).unwrap();
+    repo.config().unwrap().set_str("core.hooksPath", ".githooks").unwrap();
+
+    let dir = hooks_dir(temp.path()).unwrap();
+
+    assert!(dir.ends_with(".githooks"));
+    assert_eq!(dir.parent().unwrap().canonicalize().unwrap(), temp.path().canonicalize().unwrap());
+}
+
+#[test]
+fn hooks_dir_requires_git_repository() {
+    let temp = TempDir::new().unwrap();
+    assert!(hooks_dir(temp.path()).is_err());
+}
+
+// =============================================================================
+// FRAMEWORK CONFIG TESTS
+// =============================================================================
+
+#[test]
+fn framework_configs_run_both_hooks() {
+    for config in [PRE_COMMIT_CONFIG, LEFTHOOK_CONFIG] {
+        assert!(config.contains("quench check --staged"));
+        assert!(config.contains("quench check --commit-msg"));
+    }
+}
@@ -4,6 +4,7 @@
 pub mod adapter;
 pub mod baseline;
 pub mod cache;
+pub mod changelog;
 pub mod check;
 pub mod checks;
 pub mod cli;
@@ -19,6 +20,7 @@ pub mod file_reader;
 pub mod file_size;
 pub mod git;
 pub mod help;
+pub mod hooks;
 pub mod init;
 pub mod latest;
 pub mod output;
@@ -28,6 +30,7 @@ pub mod ratchet;
 pub mod report;
 pub mod runner;
 pub mod timing;
+pub mod tokens;
 pub mod tolerance;
 pub mod verbose;
 pub mod walker;
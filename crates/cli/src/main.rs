@@ -13,9 +13,13 @@ use quench::cli::{Cli, Command};
 use quench::error::ExitCode;
 use quench::help::format_help;
 
+mod cmd_agents;
+mod cmd_changelog;
 mod cmd_check;
 mod cmd_cloc;
 mod cmd_config;
+mod cmd_hooks;
+mod cmd_mcp;
 mod cmd_report;
 
 fn init_logging() {
@@ -75,12 +79,16 @@ fn run() -> anyhow::Result<ExitCode> {
         }
         Some(Command::Check(args)) => cmd_check::run(&cli, args),
         Some(Command::Cloc(args)) => cmd_cloc::run(args),
+        Some(Command::Agents(args)) => cmd_agents::run(args),
         Some(Command::Report(args)) => {
             cmd_report::run(&cli, args)?;
             Ok(ExitCode::Success)
         }
         Some(Command::Init(args)) => quench::cmd_init::run(args),
         Some(Command::Config(args)) => cmd_config::run(args),
+        Some(Command::Mcp) => cmd_mcp::run(),
+        Some(Command::Hooks(args)) => cmd_hooks::run(args),
+        Some(Command::Changelog(args)) => cmd_changelog::run(args),
         Some(Command::Completions(args)) => {
             let mut cmd = Cli::command();
             generate(args.shell, &mut cmd, "quench", &mut io::stdout());
@@ -107,6 +115,20 @@ fn print_custom_help(args: &[String]) {
                 print!("{}", format_help(subcmd));
             }
         }
+        Some("agents") => {
+            if let Some(subcmd) = cmd.find_subcommand_mut("agents") {
+                // `quench agents context --help` shows the nested command
+                let nested = args.iter().skip(2).find(|arg| !arg.starts_with('-'));
+                match nested.filter(|n| subcmd.find_subcommand(n.as_str()).is_some()) {
+                    Some(name) => {
+                        if let Some(nested) = subcmd.find_subcommand_mut(name.as_str()) {
+                            print!("{}", format_help(nested));
+                        }
+                    }
+                    None => print!("{}", format_help(subcmd)),
+                }
+            }
+        }
         Some("report") => {
             if let Some(subcmd) = cmd.find_subcommand_mut("report") {
                 print!("{}", format_help(subcmd));
@@ -122,6 +144,30 @@ fn print_custom_help(args: &[String]) {
                 print!("{}", format_help(subcmd));
             }
         }
+        Some("mcp") => {
+            if let Some(subcmd) = cmd.find_subcommand_mut("mcp") {
+                print!("{}", format_help(subcmd));
+            }
+        }
+        Some("hooks") => {
+            if let Some(subcmd) = cmd.find_subcommand_mut("hooks") {
+                // `quench hooks install --help` shows the nested command
+                let nested = args.iter().skip(2).find(|arg| !arg.starts_with('-'));
+                match nested.filter(|n| subcmd.find_subcommand(n.as_str()).is_some()) {
+                    Some(name) => {
+                        if let Some(nested) = subcmd.find_subcommand_mut(name.as_str()) {
+                            print!("{}", format_help(nested));
+                        }
+                    }
+                    None => print!("{}", format_help(subcmd)),
+                }
+            }
+        }
+        Some("changelog") => {
+            if let Some(subcmd) = cmd.find_subcommand_mut("changelog") {
+                print!("{}", format_help(subcmd));
+            }
+        }
         Some("completions") => {
             if let Some(subcmd) = cmd.find_subcommand_mut("completions") {
                 print!("{}", format_help(subcmd));
@@ -267,6 +267,19 @@ impl TextFormatter {
                     _ => self.format_default_desc(v),
                 }
             }
+            "context_budget_exceeded" => {
+                let files = v.files.as_deref().unwrap_or_default();
+                let files: Vec<_> = files.iter().map(|f| f.display().to_string()).collect();
+                match (v.value, v.threshold) {
+                    (Some(val), Some(thresh)) => format!(
+                        "context budget exceeded (tokens: {} vs {}): {}",
+                        val,
+                        thresh,
+                        files.join(", ")
+                    ),
+                    _ => "context budget exceeded".to_string(),
+                }
+            }
             // Docs commit check - show commit hash and message
             "missing_docs" => match (&v.commit, &v.message) {
                 (Some(hash), Some(msg)) => {
@@ -274,6 +287,13 @@ impl TextFormatter {
                 }
                 _ => "feature commits without documentation".to_string(),
             },
+            // Git check - branch name with the pattern it should match
+            "invalid_branch_name" => match (&v.found, &v.expected) {
+                (Some(branch), Some(pattern)) => {
+                    format!("invalid branch name: {} (expected: {})", branch, pattern)
+                }
+                _ => "invalid branch name".to_string(),
+            },
             // Build check - size violations with human-readable formatting
             "size_exceeded" => {
                 let target = v.target.as_deref().unwrap_or("binary");
@@ -327,6 +347,23 @@ impl TextFormatter {
                 }
                 _ => "outdated copyright year".to_string(),
             },
+            "wrong_year" => match (&v.expected, &v.found) {
+                (Some(expected), Some(found)) => {
+                    format!("wrong copyright year (expected: {}, found: {})", expected, found)
+                }
+                _ => "wrong copyright year".to_string(),
+            },
+            "header_mismatch" => "license header does not match template".to_string(),
+            "disallowed_dependency_license" => match (&v.path, &v.found) {
+                (Some(path), Some(found)) => {
+                    format!("disallowed dependency license: {} ({})", path, found)
+                }
+                _ => "disallowed dependency license".to_string(),
+            },
+            "unknown_license" => match &v.path {
+                Some(path) => format!("unknown dependency license: {}", path),
+                None => "unknown dependency license".to_string(),
+            },
             // Other checks - existing behavior
             _ => self.format_default_desc(v),
         }
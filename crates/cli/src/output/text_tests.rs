@@ -158,6 +158,20 @@ fn cloc_file_too_large_uses_lines_label() {
     assert_eq!(desc, "file_too_large (lines: 800 vs 750)");
 }
 
+// =============================================================================
+// GIT VIOLATION DESCRIPTION TESTS
+// =============================================================================
+
+#[test]
+fn git_invalid_branch_name_description() {
+    let formatter = TextFormatter::new(ColorChoice::Never, FormatOptions::default());
+    let mut violation = Violation::file_only("", "invalid_branch_name", "Rename the branch")
+        .with_expected_found("^feat/", "my-branch");
+    violation.file = None;
+    let desc = formatter.format_violation_desc(&violation);
+    assert_eq!(desc, "invalid branch name: my-branch (expected: ^feat/)");
+}
+
 // =============================================================================
 // BUILD VIOLATION DESCRIPTION TESTS
 // =============================================================================
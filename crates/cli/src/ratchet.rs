@@ -9,7 +9,7 @@ use std::time::Duration;
 use crate::baseline::{
     Baseline, BaselineMetrics, BuildTimeMetrics as BaselineBuildTime,
     CoverageMetrics as BaselineCoverage, EscapesMetrics as BaselineEscapes,
-    TestTimeMetrics as BaselineTestTime,
+    MutationMetrics as BaselineMutation, TestTimeMetrics as BaselineTestTime,
 };
 use crate::check::CheckOutput;
 use crate::config::RatchetConfig;
@@ -22,6 +22,7 @@ pub struct CurrentMetrics {
     pub binary_size: Option<HashMap<String, u64>>,
     pub build_time: Option<BuildTimeCurrent>,
     pub test_time: Option<TestTimeCurrent>,
+    pub mutation: Option<MutationCurrent>,
 }
 
 /// Current coverage metrics extracted from tests output.
@@ -31,6 +32,15 @@ pub struct CoverageCurrent {
     pub by_package: HashMap<String, f64>,
 }
 
+/// Current mutation score metrics extracted from tests output.
+#[derive(Debug, Clone)]
+pub struct MutationCurrent {
+    pub total: f64,
+    pub by_package: HashMap<String, f64>,
+    /// Only changed files were mutated, so `total` is not comparable to the baseline.
+    pub partial: bool,
+}
+
 /// Current escape metrics extracted from check output.
 #[derive(Debug, Clone)]
 pub struct EscapesCurrent {
@@ -73,12 +83,13 @@ impl CurrentMetrics {
             metrics.build_time = extract_build_time(metrics_json);
         }
 
-        // Extract test time and coverage metrics
+        // Extract test time, coverage and mutation metrics
         if let Some(tests_result) = output.checks.iter().find(|c| c.name == "tests")
             && let Some(ref metrics_json) = tests_result.metrics
         {
             metrics.test_time = extract_test_time(metrics_json);
             metrics.coverage = extract_coverage_metrics(metrics_json);
+            metrics.mutation = extract_mutation_metrics(metrics_json);
         }
 
         metrics
@@ -154,6 +165,23 @@ fn extract_coverage_metrics(json: &serde_json::Value) -> Option<CoverageCurrent>
     Some(CoverageCurrent { total, by_package })
 }
 
+/// Extract mutation score metrics from tests check JSON.
+///
+/// The JSON format has the score under "mutation" (e.g., {"score": 87.5}).
+fn extract_mutation_metrics(json: &serde_json::Value) -> Option<MutationCurrent> {
+    let mutation = json.get("mutation")?;
+    let total = mutation.get("score")?.as_f64()?;
+    let partial = mutation.get("partial").and_then(|v| v.as_bool()).unwrap_or(false);
+
+    let by_package = json
+        .get("mutation_by_package")
+        .and_then(|v| v.as_object())
+        .map(|obj| obj.iter().filter_map(|(k, v)| v.as_f64().map(|f| (k.clone(), f))).collect())
+        .unwrap_or_default();
+
+    Some(MutationCurrent { total, by_package, partial })
+}
+
 /// Result of ratchet comparison.
 #[derive(Debug, Clone)]
 pub struct RatchetResult {
@@ -207,6 +235,8 @@ impl MetricComparison {
             "Reduce test time: parallelize tests or optimize slow tests."
         } else if self.name.starts_with("coverage.") {
             "Increase test coverage for changed code."
+        } else if self.name.starts_with("mutation.") {
+            "Strengthen assertions so tests catch the surviving mutants."
         } else {
             "Metric regressed. Clean up or update baseline with --fix."
         }
@@ -227,6 +257,8 @@ fn format_metric_value(name: &str, value: f64) -> String {
         format!("{:.1}s", value)
     } else if name.starts_with("coverage.") {
         format!("{:.1}%", value * 100.0)
+    } else if name.starts_with("mutation.") {
+        format!("{:.1}%", value)
     } else {
         format!("{}", value as i64)
     }
@@ -350,6 +382,56 @@ pub fn compare(
         }
     }
 
+    // Mutation score: ratchets UP (higher is better)
+    if config.mutation
+        && let (Some(curr), Some(base)) = (&current.mutation, &baseline.mutation)
+    {
+        let tolerance = config.mutation_tolerance_pct().unwrap_or(0.0);
+        // A run over changed files only says nothing about the project total
+        let mut scores = Vec::new();
+        if !curr.partial {
+            scores.push(("mutation.total".to_string(), curr.total, base.total));
+        }
+
+        // Packages absent from the current run (e.g., no changed files) are not compared
+        if let Some(base_by_pkg) = &base.by_package {
+            let mut packages: Vec<_> = base_by_pkg
+                .iter()
+                .filter_map(|(pkg, &base_pct)| {
+                    let curr_pct = curr.by_package.get(pkg)?;
+                    Some((format!("mutation.{}", pkg), *curr_pct, base_pct))
+                })
+                .collect();
+            packages.sort_by(|a, b| a.0.cmp(&b.0));
+            scores.extend(packages);
+        }
+
+        for (name, curr_pct, base_pct) in scores {
+            let min_allowed = base_pct - tolerance;
+            let comparison = MetricComparison {
+                name: name.clone(),
+                current: curr_pct,
+                baseline: base_pct,
+                tolerance,
+                threshold: min_allowed,
+                passed: curr_pct >= min_allowed,
+                improved: curr_pct > base_pct,
+            };
+
+            if !comparison.passed {
+                passed = false;
+            }
+            if comparison.improved {
+                improvements.push(MetricImprovement {
+                    name,
+                    old_value: base_pct,
+                    new_value: curr_pct,
+                });
+            }
+            comparisons.push(comparison);
+        }
+    }
+
     // Binary size: ratchets down (smaller is better)
     if config.binary_size
         && let (Some(curr), Some(base)) = (&current.binary_size, &baseline.binary_size)
@@ -522,6 +604,25 @@ pub fn update_baseline(baseline: &mut Baseline, current: &CurrentMetrics) {
         });
     }
 
+    // Update mutation metrics
+    // (packages keep their last score when a run only mutated some of them,
+    // and the total is only taken from runs over all files)
+    if let Some(curr_mut) = &current.mutation {
+        let base_mut = baseline
+            .metrics
+            .mutation
+            .get_or_insert(BaselineMutation { total: 0.0, by_package: None });
+        if !curr_mut.partial {
+            base_mut.total = curr_mut.total;
+        }
+        if !curr_mut.by_package.is_empty() {
+            let base_pkgs = base_mut.by_package.get_or_insert_with(HashMap::new);
+            for (pkg, &score) in &curr_mut.by_package {
+                base_pkgs.insert(pkg.clone(), score);
+            }
+        }
+    }
+
     // Update binary size metrics
     if let Some(curr_sizes) = &current.binary_size {
         let base_sizes = baseline.metrics.binary_size.get_or_insert_with(HashMap::new);
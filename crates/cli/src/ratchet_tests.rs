@@ -728,3 +728,161 @@ fn is_escapes_ratcheted_package_override() {
     assert!(config.is_escapes_ratcheted("core")); // Uses global
     assert!(!config.is_escapes_ratcheted("tests")); // Explicitly disabled
 }
+
+// =============================================================================
+// Mutation Ratcheting Tests
+// =============================================================================
+
+use crate::baseline::MutationMetrics as BaselineMutation;
+
+fn make_mutation_config(tolerance: Option<f64>) -> RatchetConfig {
+    RatchetConfig {
+        check: CheckLevel::Error,
+        mutation: true,
+        mutation_tolerance: tolerance,
+        ..Default::default()
+    }
+}
+
+fn make_mutation_baseline(total: f64, by_package: Option<HashMap<String, f64>>) -> BaselineMetrics {
+    BaselineMetrics { mutation: Some(BaselineMutation { total, by_package }), ..Default::default() }
+}
+
+fn make_mutation_current(total: f64, by_package: HashMap<String, f64>) -> CurrentMetrics {
+    CurrentMetrics {
+        mutation: Some(MutationCurrent { total, by_package, partial: false }),
+        ..Default::default()
+    }
+}
+
+fn make_partial_mutation_current(total: f64, by_package: HashMap<String, f64>) -> CurrentMetrics {
+    let mut current = make_mutation_current(total, by_package);
+    if let Some(mutation) = &mut current.mutation {
+        mutation.partial = true;
+    }
+    current
+}
+
+#[test]
+fn mutation_regression_fails() {
+    let config = make_mutation_config(None);
+    let baseline = make_mutation_baseline(80.0, None);
+    let current = make_mutation_current(75.0, HashMap::new());
+
+    let result = compare(&current, &baseline, &config);
+
+    assert!(!result.passed);
+    assert_eq!(result.comparisons.len(), 1);
+    assert_eq!(result.comparisons[0].name, "mutation.total");
+    assert_eq!(result.comparisons[0].format_value(75.0), "75.0%");
+}
+
+#[test]
+fn mutation_within_tolerance_passes() {
+    let config = make_mutation_config(Some(5.0));
+    let baseline = make_mutation_baseline(80.0, None);
+    let current = make_mutation_current(76.0, HashMap::new());
+
+    let result = compare(&current, &baseline, &config);
+
+    assert!(result.passed);
+    assert_eq!(result.comparisons[0].threshold, 75.0);
+}
+
+#[test]
+fn mutation_disabled_by_default() {
+    let config = RatchetConfig { check: CheckLevel::Error, ..Default::default() };
+    let baseline = make_mutation_baseline(80.0, None);
+    let current = make_mutation_current(50.0, HashMap::new());
+
+    let result = compare(&current, &baseline, &config);
+
+    assert!(result.passed);
+    assert!(result.comparisons.is_empty());
+}
+
+#[test]
+fn mutation_packages_missing_from_run_are_not_compared() {
+    let config = make_mutation_config(None);
+    let baseline = make_mutation_baseline(
+        80.0,
+        Some(HashMap::from([("core".to_string(), 90.0), ("cli".to_string(), 70.0)])),
+    );
+    let current = make_mutation_current(85.0, HashMap::from([("core".to_string(), 95.0)]));
+
+    let result = compare(&current, &baseline, &config);
+
+    assert!(result.passed);
+    let names: Vec<_> = result.comparisons.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, vec!["mutation.total", "mutation.core"]);
+    assert_eq!(result.improvements.len(), 2);
+}
+
+#[test]
+fn partial_mutation_run_does_not_compare_total() {
+    let config = make_mutation_config(None);
+    let baseline = make_mutation_baseline(80.0, Some(HashMap::from([("core".to_string(), 90.0)])));
+    let current = make_partial_mutation_current(40.0, HashMap::from([("core".to_string(), 95.0)]));
+
+    let result = compare(&current, &baseline, &config);
+
+    assert!(result.passed);
+    let names: Vec<_> = result.comparisons.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, vec!["mutation.core"]);
+}
+
+#[test]
+fn extract_mutation_from_tests_output() {
+    let metrics_json = json!({
+        "mutation": { "tool": "cargo-mutants", "score": 87.5, "caught": 7, "missed": 1 },
+        "mutation_by_package": { "core": 87.5 }
+    });
+
+    let check_result = CheckResult::passed("tests").with_metrics(metrics_json);
+    let output = CheckOutput::new("2026-01-20T00:00:00Z".to_string(), vec![check_result]);
+
+    let mutation = CurrentMetrics::from_output(&output).mutation.unwrap();
+    assert_eq!(mutation.total, 87.5);
+    assert_eq!(mutation.by_package.get("core"), Some(&87.5));
+}
+
+#[test]
+fn update_baseline_merges_mutation_packages() {
+    let mut baseline = Baseline::new();
+    baseline.metrics =
+        make_mutation_baseline(80.0, Some(HashMap::from([("cli".to_string(), 70.0)])));
+    let current = make_mutation_current(90.0, HashMap::from([("core".to_string(), 90.0)]));
+
+    update_baseline(&mut baseline, &current);
+
+    let mutation = baseline.metrics.mutation.unwrap();
+    assert_eq!(mutation.total, 90.0);
+    let by_package = mutation.by_package.unwrap();
+    assert_eq!(by_package.get("cli"), Some(&70.0));
+    assert_eq!(by_package.get("core"), Some(&90.0));
+}
+
+#[test]
+fn update_baseline_keeps_mutation_total_for_partial_runs() {
+    let mut baseline = Baseline::new();
+    baseline.metrics = make_mutation_baseline(80.0, None);
+    let current = make_partial_mutation_current(40.0, HashMap::from([("core".to_string(), 95.0)]));
+
+    update_baseline(&mut baseline, &current);
+
+    let mutation = baseline.metrics.mutation.unwrap();
+    assert_eq!(mutation.total, 80.0);
+    assert_eq!(mutation.by_package.unwrap().get("core"), Some(&95.0));
+}
+
+#[test]
+fn extract_partial_mutation_from_tests_output() {
+    let metrics_json = json!({
+        "mutation": { "tool": "cargo-mutants", "score": 50.0, "caught": 1, "missed": 1, "partial": true }
+    });
+
+    let check_result = CheckResult::passed("tests").with_metrics(metrics_json);
+    let output = CheckOutput::new("2026-01-20T00:00:00Z".to_string(), vec![check_result]);
+
+    assert!(CurrentMetrics::from_output(&output).mutation.unwrap().partial);
+}
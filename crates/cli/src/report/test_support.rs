@@ -66,6 +66,7 @@ pub fn create_test_baseline() -> Baseline {
             build_time: Some(BuildTimeMetrics { cold: 45.0, hot: 12.5 }),
             binary_size: Some([("quench".to_string(), 5_242_880)].into_iter().collect()),
             test_time: Some(TestTimeMetrics { total: 30.5, avg: 0.5, max: 2.0 }),
+            mutation: None,
         },
     }
 }
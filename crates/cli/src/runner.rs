@@ -17,6 +17,8 @@ use rayon::prelude::*;
 
 use crate::cache::{CachedViolation, FileCache, FileCacheKey};
 use crate::check::{Check, CheckContext, CheckResult, Violation};
+use crate::checks::docs::{ORPHANED_DOC, is_markdown};
+use crate::checks::license::DEPENDENCY_VIOLATION_TYPES;
 use crate::config::Config;
 use crate::walker::WalkedFile;
 
@@ -39,6 +41,8 @@ pub struct RunnerConfig {
     pub base_branch: Option<String>,
     /// Whether checking only staged changes (--staged flag).
     pub staged: bool,
+    /// Commit message file to validate (--commit-msg flag).
+    pub commit_msg: Option<PathBuf>,
     /// Whether verbose diagnostic output is enabled.
     pub verbose: bool,
 }
@@ -64,6 +68,7 @@ impl RunnerConfig {
             ci_mode: self.ci_mode,
             base_branch: self.base_branch.as_deref(),
             staged: self.staged,
+            commit_msg: self.commit_msg.as_deref(),
             verbose: self.verbose,
         }
     }
@@ -125,6 +130,16 @@ impl CheckRunner {
             }
         }
 
+        // Anchor links (`guide.md#setup`) depend on the headings of other markdown
+        // files, so all markdown is re-checked when any markdown file changes
+        if uncached_files.iter().any(|f| is_markdown(&f.path)) {
+            for file in files.iter().filter(|f| is_markdown(&f.path)) {
+                if cached_violations.remove(&file.path).is_some() {
+                    uncached_files.push(file);
+                }
+            }
+        }
+
         // Build owned files for uncached (needed for CheckContext)
         // Note: We need owned WalkedFiles for the context, so we clone
         let uncached_owned: Vec<WalkedFile> = uncached_files
@@ -238,6 +253,14 @@ impl CheckRunner {
 
         for result in &results {
             for violation in &result.violations {
+                // Dependency licenses come from lockfiles and package caches, and
+                // orphaned docs from links in other files, so they are
+                // re-evaluated every run instead of cached
+                if DEPENDENCY_VIOLATION_TYPES.contains(&violation.violation_type.as_str())
+                    || violation.violation_type == ORPHANED_DOC
+                {
+                    continue;
+                }
                 if let Some(file_path) = &violation.file {
                     // Only cache violations from files we just processed
                     let abs_path = if file_path.is_absolute() {
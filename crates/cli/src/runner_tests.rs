@@ -73,6 +73,7 @@ fn runner_executes_all_checks() {
         ci_mode: false,
         base_branch: None,
         staged: false,
+        commit_msg: None,
         verbose: false,
     });
     let config = Config::default();
@@ -100,6 +101,7 @@ fn runner_isolates_panicking_check() {
         ci_mode: false,
         base_branch: None,
         staged: false,
+        commit_msg: None,
         verbose: false,
     });
     let config = Config::default();
@@ -136,6 +138,7 @@ fn runner_continues_after_check_failure() {
         ci_mode: false,
         base_branch: None,
         staged: false,
+        commit_msg: None,
         verbose: false,
     });
     let config = Config::default();
@@ -170,6 +173,7 @@ fn should_terminate_with_limit() {
         ci_mode: false,
         base_branch: None,
         staged: false,
+        commit_msg: None,
         verbose: false,
     });
     assert!(!runner.should_terminate(5));
@@ -187,6 +191,7 @@ fn should_terminate_without_limit() {
         ci_mode: false,
         base_branch: None,
         staged: false,
+        commit_msg: None,
         verbose: false,
     });
     assert!(!runner.should_terminate(1000));
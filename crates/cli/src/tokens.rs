@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Token counting for `max_tokens` limits.
+//!
+//! `approx` uses the `chars / 4` heuristic. `cl100k` and `o200k` run a BPE
+//! tokenizer whose vocabulary is compiled into the binary (no network access);
+//! they are only available when built with the `bpe-tokenizer` feature.
+
+use serde::Deserialize;
+
+/// Tokenizer used to count tokens.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(try_from = "String")]
+pub enum Tokenizer {
+    /// `chars / 4` approximation.
+    #[default]
+    Approx,
+    /// `cl100k_base` BPE encoding (GPT-4, GPT-3.5).
+    Cl100k,
+    /// `o200k_base` BPE encoding (GPT-4o and later).
+    O200k,
+}
+
+impl Tokenizer {
+    /// Config name of the tokenizer.
+    pub fn name(self) -> &'static str {
+        match self {
+            Tokenizer::Approx => "approx",
+            Tokenizer::Cl100k => "cl100k",
+            Tokenizer::O200k => "o200k",
+        }
+    }
+
+    /// Whether this build can run the tokenizer.
+    pub fn is_available(self) -> bool {
+        self == Tokenizer::Approx || cfg!(feature = "bpe-tokenizer")
+    }
+
+    /// Count tokens in `text`.
+    pub fn count(self, text: &str) -> usize {
+        match self {
+            Tokenizer::Approx => approx(text),
+            #[cfg(feature = "bpe-tokenizer")]
+            Tokenizer::Cl100k => tiktoken_rs::cl100k_base_singleton().encode_ordinary(text).len(),
+            #[cfg(feature = "bpe-tokenizer")]
+            Tokenizer::O200k => tiktoken_rs::o200k_base_singleton().encode_ordinary(text).len(),
+            // Rejected when the config is parsed
+            #[cfg(not(feature = "bpe-tokenizer"))]
+            Tokenizer::Cl100k | Tokenizer::O200k => approx(text),
+        }
+    }
+}
+
+impl TryFrom<String> for Tokenizer {
+    type Error = String;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        let tokenizer = match name.as_str() {
+            "approx" => Tokenizer::Approx,
+            "cl100k" => Tokenizer::Cl100k,
+            "o200k" => Tokenizer::O200k,
+            other => {
+                return Err(format!(
+                    "unknown tokenizer \"{other}\" (expected approx, cl100k, or o200k)"
+                ));
+            }
+        };
+        if !tokenizer.is_available() {
+            return Err(format!(
+                "tokenizer \"{name}\" requires quench built with the `bpe-tokenizer` feature"
+            ));
+        }
+        Ok(tokenizer)
+    }
+}
+
+/// Approximate token count: `chars / 4` (standard LLM heuristic).
+pub fn approx(text: &str) -> usize {
+    text.chars().count() / 4
+}
+
+#[cfg(test)]
+#[path = "tokens_tests.rs"]
+mod tests;
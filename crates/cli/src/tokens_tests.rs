@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+#[test]
+fn default_is_approx() {
+    assert_eq!(Tokenizer::default(), Tokenizer::Approx);
+}
+
+#[test]
+fn parses_approx() {
+    assert_eq!(Tokenizer::try_from("approx".to_string()), Ok(Tokenizer::Approx));
+}
+
+#[test]
+fn rejects_unknown_name() {
+    let err = Tokenizer::try_from("gpt2".to_string()).unwrap_err();
+    assert!(err.contains("unknown tokenizer \"gpt2\""));
+}
+
+#[test]
+fn bpe_tokenizers_depend_on_feature() {
+    for (name, tokenizer) in [("cl100k", Tokenizer::Cl100k), ("o200k", Tokenizer::O200k)] {
+        let parsed = Tokenizer::try_from(name.to_string());
+        if cfg!(feature = "bpe-tokenizer") {
+            assert_eq!(parsed, Ok(tokenizer));
+        } else {
+            assert!(parsed.unwrap_err().contains("bpe-tokenizer"));
+        }
+        assert_eq!(tokenizer.name(), name);
+    }
+}
+
+#[test]
+fn approx_counts_chars_over_four() {
+    assert_eq!(approx(""), 0);
+    assert_eq!(approx("abc"), 0);
+    assert_eq!(approx("abcdefgh"), 2);
+    // Characters, not bytes
+    assert_eq!(approx("日本語です"), 1);
+}
+
+#[test]
+fn approx_tokenizer_matches_approx() {
+    let text = "The quick brown fox jumps over the lazy dog.";
+    assert_eq!(Tokenizer::Approx.count(text), approx(text));
+}
+
+#[test]
+#[cfg(feature = "bpe-tokenizer")]
+fn bpe_tokenizers_count_exact_tokens() {
+    assert_eq!(Tokenizer::Cl100k.count("hello world"), 2);
+    assert_eq!(Tokenizer::O200k.count("hello world"), 2);
+    assert_eq!(Tokenizer::Cl100k.count(""), 0);
+}
@@ -33,6 +33,15 @@ mod cli_toggles;
 #[path = "specs/cli/report.rs"]
 mod cli_report;
 
+#[path = "specs/cli/mcp.rs"]
+mod cli_mcp;
+
+#[path = "specs/cli/hooks.rs"]
+mod cli_hooks;
+
+#[path = "specs/cli/changelog.rs"]
+mod cli_changelog;
+
 #[path = "specs/cli/timing.rs"]
 mod cli_timing;
 
@@ -84,6 +93,9 @@ mod checks_build;
 #[path = "specs/checks/license.rs"]
 mod checks_license;
 
+#[path = "specs/checks/license_deps.rs"]
+mod checks_license_deps;
+
 // output/
 #[path = "specs/output/format.rs"]
 mod output_format;
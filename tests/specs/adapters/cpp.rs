@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the C/C++ language adapter.
+//!
+//! Tests that quench correctly:
+//! - Detects C/C++ projects via CMake and other build files
+//! - Classifies test directories and `*_test.cc` files as test code
+//! - Applies C/C++ escape patterns (`reinterpret_cast`, `goto`, ...)
+//! - Checks clang-tidy `// NOLINT` directives
+//!
+//! Reference: docs/specs/langs/cpp.md
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+/// CMake project with one C++ source file.
+fn cmake_project(source: &str) -> Project {
+    let temp = Project::empty();
+    temp.config("");
+    temp.file("CMakeLists.txt", "project(engine CXX)\n");
+    temp.file("src/engine.cc", source);
+    temp
+}
+
+// =============================================================================
+// DETECTION AND PATTERN SPECS
+// =============================================================================
+
+/// Spec: docs/specs/langs/cpp.md#test-code-detection
+///
+/// > Files in `tests/` or `test/` directories
+/// > Files named `*_test.{c,cc,cpp,cxx}` or `test_*.{c,cc,cpp,cxx}`
+#[test]
+fn test_files_counted_as_test_code() {
+    let temp = Project::empty();
+    temp.config("");
+    temp.file("CMakeLists.txt", "project(engine C CXX)\n");
+    temp.file("src/parser.c", "int parse(void) {\n    return 1;\n}\n");
+    temp.file("src/parser_test.cc", "TEST(Parser, Parses) {\n    EXPECT_EQ(1, parse());\n}\n");
+    temp.file("tests/fixtures.h", "#pragma once\nint fixture(void);\n");
+
+    let cloc = check("cloc").pwd(temp.path()).json().passes();
+    let metrics = cloc.require("metrics");
+
+    assert_eq!(metrics.get("source_lines").and_then(|v| v.as_u64()), Some(3));
+    assert_eq!(metrics.get("test_lines").and_then(|v| v.as_u64()), Some(5));
+}
+
+/// Spec: docs/specs/langs/cpp.md#default-patterns
+///
+/// > ignore = ["build/", "cmake-build-*/", "out/", "third_party/", "vendor/"]
+#[test]
+fn build_and_vendored_code_is_ignored() {
+    let temp = cmake_project("int engine() { return 0; }\n");
+    temp.file("cmake-build-debug/gen.cc", "auto* p = reinterpret_cast<int*>(q);\n");
+    temp.file("third_party/zlib/inflate.c", "goto done;\n");
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+// =============================================================================
+// ESCAPE PATTERN SPECS
+// =============================================================================
+
+/// Spec: docs/specs/langs/cpp.md#default-escape-patterns
+///
+/// > `reinterpret_cast<` requires `// SAFETY:`
+#[test]
+fn reinterpret_cast_without_safety_comment_fails() {
+    let temp = cmake_project("auto* header = reinterpret_cast<Header*>(buf);\n");
+
+    check("escapes").pwd(temp.path()).fails().stdout_has("// SAFETY:");
+}
+
+/// Spec: docs/specs/langs/cpp.md#default-escape-patterns
+///
+/// > `goto label;` requires `// GOTO:`
+#[test]
+fn goto_with_comment_passes() {
+    let temp = cmake_project(
+        "int open_all() {\n    // GOTO: single cleanup path releases every handle\n    goto cleanup;\ncleanup:\n    return 0;\n}\n",
+    );
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+// =============================================================================
+// SUPPRESS SPECS
+// =============================================================================
+
+/// Spec: docs/specs/langs/cpp.md#suppress
+///
+/// > Default: `"comment"` for source
+#[test]
+fn nolint_without_comment_fails() {
+    let temp = cmake_project("int n = atoi(arg);  // NOLINT(cert-err34-c)\n");
+
+    check("escapes")
+        .pwd(temp.path())
+        .fails()
+        .stdout_has("suppress_missing_comment")
+        .stdout_has("NOLINT");
+}
+
+/// Spec: docs/specs/langs/cpp.md#suppress
+///
+/// > A justification is either a comment on the line(s) above, or text after
+/// > the directive on the same line
+#[test]
+fn nolint_with_justification_passes() {
+    let temp = cmake_project(
+        "// The C API never writes through the pointer\n// NOLINTNEXTLINE(cppcoreguidelines-pro-type-const-cast)\nvoid f(const char* s) { legacy(const_cast<char*>(s)); }  // SAFETY: read-only API\nint rc = close(fd);  // NOLINT(cert-err33-c): errors on close are not recoverable\n",
+    );
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/langs/cpp.md#suppress
+///
+/// > `"forbid"` - Never allowed
+#[test]
+fn nolint_forbidden_by_config() {
+    let temp = cmake_project("// Reviewed\nint n = atoi(arg);  // NOLINT(cert-err34-c)\n");
+    temp.config("[cpp.suppress]\ncheck = \"forbid\"\n");
+
+    check("escapes").pwd(temp.path()).fails().stdout_has("suppress_forbidden");
+}
+
+/// Spec: docs/specs/langs/cpp.md#suppress
+///
+/// > `"allow"` for test code
+#[test]
+fn nolint_in_test_code_allowed() {
+    let temp = cmake_project("int engine() { return 0; }\n");
+    temp.file("tests/engine_test.cc", "int n = atoi(arg);  // NOLINT\n");
+
+    check("escapes").pwd(temp.path()).passes();
+}
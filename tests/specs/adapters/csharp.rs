@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the C# language adapter.
+//!
+//! Tests that quench correctly:
+//! - Detects C# projects via solution and project files
+//! - Classifies `*.Tests` projects as test code
+//! - Applies C# escape patterns (`unsafe`, `!`, `dynamic`)
+//! - Checks `#pragma warning disable` and `[SuppressMessage]` suppressions
+//!
+//! Reference: docs/specs/langs/csharp.md
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+/// Solution with one C# source file.
+fn dotnet_project(source: &str) -> Project {
+    let temp = Project::empty();
+    temp.config("");
+    temp.file("Acme.sln", "Microsoft Visual Studio Solution File, Format Version 12.00\n");
+    temp.file("src/Acme.Core/Acme.Core.csproj", "<Project Sdk=\"Microsoft.NET.Sdk\" />\n");
+    temp.file("src/Acme.Core/Engine.cs", source);
+    temp
+}
+
+// =============================================================================
+// DETECTION AND PATTERN SPECS
+// =============================================================================
+
+/// Spec: docs/specs/langs/csharp.md#test-code-detection
+///
+/// > Files in test projects: directories named `*.Tests`, `*.Test`,
+/// > `*.UnitTests` or `*.IntegrationTests`
+#[test]
+fn test_projects_counted_as_test_code() {
+    let temp = dotnet_project("namespace Acme;\n\npublic static class Engine\n{\n}\n");
+    temp.file(
+        "tests/Acme.Core.Tests/EngineFacts.cs",
+        "public class EngineFacts\n{\n    [Fact]\n    public void Runs() { }\n}\n",
+    );
+
+    let cloc = check("cloc").pwd(temp.path()).json().passes();
+    let metrics = cloc.require("metrics");
+
+    assert_eq!(metrics.get("source_lines").and_then(|v| v.as_u64()), Some(4));
+    assert_eq!(metrics.get("test_lines").and_then(|v| v.as_u64()), Some(5));
+}
+
+/// Spec: docs/specs/langs/csharp.md#default-patterns
+///
+/// > ignore = ["**/bin/", "**/obj/", "packages/", ".vs/", "**/TestResults/"]
+#[test]
+fn build_output_is_ignored() {
+    let temp = dotnet_project("namespace Acme;\n");
+    temp.file("src/Acme.Core/obj/Debug/Acme.Core.AssemblyInfo.cs", "var x = y!.Z;\n");
+    temp.file("src/Acme.Core/bin/Debug/Generated.cs", "unsafe { }\n");
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+// =============================================================================
+// ESCAPE PATTERN SPECS
+// =============================================================================
+
+/// Spec: docs/specs/langs/csharp.md#default-escape-patterns
+///
+/// > `!` null-forgiving operator requires `// SAFETY:`
+#[test]
+fn null_forgiving_without_safety_comment_fails() {
+    let temp = dotnet_project("public string Name { get; set; } = null!;\n");
+
+    check("escapes").pwd(temp.path()).fails().stdout_has("// SAFETY:");
+}
+
+/// Spec: docs/specs/langs/csharp.md#default-escape-patterns
+///
+/// > `unsafe` block or member requires `// SAFETY:`
+#[test]
+fn unsafe_with_comment_passes() {
+    let temp = dotnet_project(
+        "void Copy(byte[] src)\n{\n    // SAFETY: length checked against the buffer above\n    unsafe { Write(src); }\n}\n",
+    );
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+// =============================================================================
+// SUPPRESS SPECS
+// =============================================================================
+
+/// Spec: docs/specs/langs/csharp.md#suppress
+///
+/// > Default: `"comment"` for source
+#[test]
+fn pragma_without_comment_fails() {
+    let temp = dotnet_project("#pragma warning disable CS0618\nLegacy.Call();\n");
+
+    check("escapes")
+        .pwd(temp.path())
+        .fails()
+        .stdout_has("suppress_missing_comment")
+        .stdout_has("#pragma warning disable CS0618");
+}
+
+/// Spec: docs/specs/langs/csharp.md#suppress
+///
+/// > A justification is a comment on the line(s) above, a trailing comment on
+/// > the pragma, or a non-empty `Justification` argument on the attribute.
+#[test]
+fn suppressions_with_justification_pass() {
+    let temp = dotnet_project(
+        "#pragma warning disable CS0618 // Obsolete API kept until the v3 migration\n\n[SuppressMessage(\"Design\", \"CA1031\",\n    Justification = \"Top-level handler logs and rethrows\")]\npublic void Run() { }\n",
+    );
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/langs/csharp.md#suppress
+///
+/// > `"forbid"` - Never allowed
+#[test]
+fn suppress_message_forbidden_by_config() {
+    let temp = dotnet_project(
+        "[SuppressMessage(\"Security\", \"CA2100:Review SQL queries\", Justification = \"Reviewed\")]\npublic void Query() { }\n",
+    );
+    temp.config("[csharp.suppress.source]\nforbid = [\"CA2100\"]\n");
+
+    check("escapes").pwd(temp.path()).fails().stdout_has("suppress_forbidden");
+}
+
+/// Spec: docs/specs/langs/csharp.md#suppress
+///
+/// > `"allow"` for test code
+#[test]
+fn pragma_in_test_code_allowed() {
+    let temp = dotnet_project("namespace Acme;\n");
+    temp.file("tests/Acme.Core.Tests/EngineTests.cs", "#pragma warning disable CS8618\n");
+
+    check("escapes").pwd(temp.path()).passes();
+}
@@ -0,0 +1,201 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for declarative adapters.
+//!
+//! Tests that quench correctly:
+//! - Loads adapters from `[[adapter]]` and `.quench/adapters/*.toml`
+//! - Counts lines with the declared comment syntax
+//! - Applies declared escape patterns only to the adapter's files
+//! - Checks declared suppress directives and lint config policy
+//!
+//! Reference: docs/specs/10-language-adapters.md#declarative-adapters
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+const ZIG_ADAPTER: &str = r#"
+name = "zig"
+extensions = ["zig"]
+tests = ["tests/**"]
+exclude = ["zig-cache/"]
+
+[comment]
+line = ["//"]
+
+[[escapes]]
+name = "unreachable"
+pattern = "unreachable"
+action = "comment"
+comment = "// SAFETY:"
+
+[suppress]
+directives = ['zlint:ignore\s+(?P<codes>[\w,-]+)']
+
+[policy]
+lint_changes = "standalone"
+lint_config = [".zlint.json"]
+"#;
+
+/// Zig project with its adapter in `.quench/adapters/zig.toml`.
+fn zig_project(main: &str) -> Project {
+    let temp = Project::empty();
+    temp.config("");
+    temp.file(".quench/adapters/zig.toml", ZIG_ADAPTER);
+    temp.file("build.zig", "const std = @import(\"std\");\n");
+    temp.file("src/main.zig", main);
+    temp
+}
+
+const MAIN: &str = "const std = @import(\"std\");\n\npub fn main() void {}\n";
+
+// =============================================================================
+// LOADING AND LINE COUNTING SPECS
+// =============================================================================
+
+/// Spec: docs/specs/10-language-adapters.md#declarative-adapters
+///
+/// > **CLOC**: Counted with the spec's `comment` syntax and reported under the adapter's `name`
+#[test]
+fn cloc_counts_comments_with_declared_syntax() {
+    let temp = zig_project("// Entry point\n// Prints nothing\npub fn main() void {}\n");
+
+    let mut cmd = quench_cmd();
+    cmd.args(["cloc", "--output", "json"]);
+    cmd.current_dir(temp.path());
+    let output = cmd.output().expect("command should run");
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    let zig = json["languages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|l| l["language"] == "zig" && l["kind"] == "source")
+        .expect("zig source entry");
+    assert_eq!(zig["files"], 2);
+    assert_eq!(zig["comment"], 2);
+    assert_eq!(zig["code"], 2);
+}
+
+/// Spec: docs/specs/10-language-adapters.md#declarative-adapters
+///
+/// > **Test detection**: `tests` patterns, falling back to `[project].tests`
+#[test]
+fn test_patterns_classify_adapter_files() {
+    let temp = zig_project(MAIN);
+    temp.file("tests/parser.zig", "test \"parse\" {}\n");
+    temp.file("zig-cache/o/main.zig", "const cached = 1;\n");
+
+    let cloc = check("cloc").pwd(temp.path()).json().passes();
+    let metrics = cloc.require("metrics");
+
+    // build.zig (1) + src/main.zig (2), non-blank lines; zig-cache is excluded
+    assert_eq!(metrics.get("source_lines").and_then(|v| v.as_u64()), Some(3));
+    assert_eq!(metrics.get("test_lines").and_then(|v| v.as_u64()), Some(1));
+}
+
+/// Spec: docs/specs/10-language-adapters.md#declarative-adapters
+///
+/// > as `[[adapter]]` entries in `quench.toml`
+#[test]
+fn adapter_declared_in_quench_toml() {
+    let temp = Project::empty();
+    temp.config(
+        r#"
+[[adapter]]
+name = "just"
+filenames = ["Justfile"]
+
+[[adapter.escapes]]
+name = "curl_pipe"
+pattern = "curl .*\\| *sh"
+action = "forbid"
+"#,
+    );
+    temp.file("Justfile", "install:\n    curl -fsSL https://example.com/install | sh\n");
+
+    check("escapes").pwd(temp.path()).fails().stdout_has("curl_pipe");
+}
+
+// =============================================================================
+// ESCAPE AND SUPPRESS SPECS
+// =============================================================================
+
+/// Spec: docs/specs/10-language-adapters.md#declarative-adapters
+///
+/// > **Escape patterns**: `[[adapter.escapes]]`, scoped to the adapter's files unless `source` is set
+#[test]
+fn escape_without_comment_fails() {
+    let temp = zig_project("pub fn main() void {\n    unreachable;\n}\n");
+
+    check("escapes").pwd(temp.path()).fails().stdout_has("// SAFETY:");
+}
+
+/// Spec: docs/specs/10-language-adapters.md#declarative-adapters
+///
+/// > **Escape patterns**: `[[adapter.escapes]]`, scoped to the adapter's files unless `source` is set
+#[test]
+fn escape_ignored_in_other_files() {
+    let temp = zig_project(MAIN);
+    temp.file("scripts/check.py", "def check():\n    raise Exception(\"unreachable\")\n");
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/10-language-adapters.md#declarative-adapters
+///
+/// > **Lint suppression**: `suppress.directives` regexes; a `codes` named group
+/// > (or the first capture group) lists the lint codes
+#[test]
+fn suppress_directive_without_comment_fails() {
+    let temp = zig_project("// zlint:ignore unused-var\nconst x = 1;\n");
+
+    check("escapes")
+        .pwd(temp.path())
+        .fails()
+        .stdout_has("suppress_missing_comment")
+        .stdout_has("unused-var");
+}
+
+/// Spec: docs/specs/10-language-adapters.md#declarative-adapters
+///
+/// > Justification comments use the first `comment.line` prefix.
+#[test]
+fn suppress_directive_with_comment_passes() {
+    let temp = zig_project("// Kept for the C ABI\n// zlint:ignore unused-var\nconst x = 1;\n");
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/10-language-adapters.md#declarative-adapters
+///
+/// > **Lint suppression**: `suppress.directives` regexes
+#[test]
+fn suppress_directive_in_test_code_allowed() {
+    let temp = zig_project(MAIN);
+    temp.file("tests/parser.zig", "// zlint:ignore unused-var\nconst x = 1;\n");
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+// =============================================================================
+// POLICY SPECS
+// =============================================================================
+
+/// Spec: docs/specs/10-language-adapters.md#declarative-adapters
+///
+/// > **Policy**: `policy.lint_config` files for the standalone lint-change policy
+#[test]
+fn lint_config_changes_with_source_fail() {
+    let temp = zig_project(MAIN);
+    git_init(&temp);
+    git_initial_commit(&temp);
+
+    temp.file(".zlint.json", "{}\n");
+    temp.file("src/main.zig", "pub fn main() void {}\npub fn helper() void {}\n");
+    git_add_all(&temp);
+
+    check("escapes").pwd(temp.path()).args(&["--base", "HEAD"]).fails().stdout_has("lint_policy");
+}
@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the Elixir language adapter.
+//!
+//! Tests that quench correctly:
+//! - Detects Mix projects via `mix.exs`
+//! - Classifies `test/` and `*_test.exs` as test code
+//! - Applies Elixir escape patterns (`String.to_atom`, `:erlang.binary_to_term`, `Code.eval_string`)
+//! - Checks Credo directives
+//!
+//! Reference: docs/specs/langs/elixir.md
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+/// Mix project with one Elixir source file.
+fn mix_project(source: &str) -> Project {
+    let temp = Project::empty();
+    temp.config("");
+    temp.file("mix.exs", "defmodule Acme.MixProject do\n  use Mix.Project\nend\n");
+    temp.file("lib/acme/accounts.ex", source);
+    temp
+}
+
+// =============================================================================
+// DETECTION AND PATTERN SPECS
+// =============================================================================
+
+/// Spec: docs/specs/langs/elixir.md#test-code-detection
+///
+/// > Files under `test/`, including `test/support/` and umbrella `apps/*/test/`
+#[test]
+fn test_directory_counted_as_test_code() {
+    let temp = mix_project("defmodule Acme.Accounts do\n  def list, do: []\nend\n");
+    temp.file(
+        "test/acme/accounts_test.exs",
+        "defmodule Acme.AccountsTest do\n  use ExUnit.Case\n\n  test \"lists\", do: assert([] == Acme.Accounts.list())\nend\n",
+    );
+    temp.file("test/support/data_case.ex", "defmodule Acme.DataCase do\nend\n");
+
+    let cloc = check("cloc").pwd(temp.path()).json().passes();
+    let metrics = cloc.require("metrics");
+
+    // mix.exs (3) + accounts.ex (3)
+    assert_eq!(metrics.get("source_lines").and_then(|v| v.as_u64()), Some(6));
+    assert_eq!(metrics.get("test_lines").and_then(|v| v.as_u64()), Some(6));
+}
+
+/// Spec: docs/specs/langs/elixir.md#default-patterns
+///
+/// > ignore = ["_build/", "deps/", ".elixir_ls/", "cover/"]
+#[test]
+fn deps_and_build_are_ignored() {
+    let temp = mix_project("defmodule Acme.Accounts do\nend\n");
+    temp.file("deps/jason/lib/jason/decode.ex", "key = String.to_atom(raw)\n");
+    temp.file("_build/dev/lib/acme/consolidated/x.ex", "Code.eval_string(src)\n");
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+// =============================================================================
+// ESCAPE PATTERN SPECS
+// =============================================================================
+
+/// Spec: docs/specs/langs/elixir.md#default-escape-patterns
+///
+/// > `String.to_atom` requires `# ATOM:`
+#[test]
+fn string_to_atom_without_comment_fails() {
+    let temp =
+        mix_project("defmodule Acme.Accounts do\n  def key(raw), do: String.to_atom(raw)\nend\n");
+
+    check("escapes").pwd(temp.path()).fails().stdout_has("# ATOM:");
+}
+
+/// Spec: docs/specs/langs/elixir.md#default-escape-patterns
+///
+/// > `:erlang.binary_to_term` requires `# SAFETY:`
+#[test]
+fn binary_to_term_with_comment_passes() {
+    let temp = mix_project(
+        "defmodule Acme.Cache do\n  # SAFETY: the blob was written by this node and is signed\n  def load(blob), do: :erlang.binary_to_term(blob, [:safe])\nend\n",
+    );
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/langs/elixir.md#default-escape-patterns
+///
+/// > `Code.eval_string` requires `# EVAL:`
+#[test]
+fn eval_string_in_test_code_allowed() {
+    let temp = mix_project("defmodule Acme.Accounts do\nend\n");
+    temp.file("test/acme/eval_test.exs", "Code.eval_string(\"1 + 2\")\n");
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+// =============================================================================
+// SUPPRESS SPECS
+// =============================================================================
+
+/// Spec: docs/specs/langs/elixir.md#suppress
+///
+/// > Default: `"comment"` for source
+#[test]
+fn credo_disable_without_comment_fails() {
+    let temp = mix_project(
+        "defmodule Acme.Accounts do\n  # credo:disable-for-next-line Credo.Check.Warning.IoInspect\n  def debug(x), do: IO.inspect(x)\nend\n",
+    );
+
+    check("escapes")
+        .pwd(temp.path())
+        .fails()
+        .stdout_has("suppress_missing_comment")
+        .stdout_has("credo:disable-for-next-line");
+}
+
+/// Spec: docs/specs/langs/elixir.md#suppress
+///
+/// > A justification is a comment on the line(s) above; module attributes such as
+/// > `@doc` or `@spec` in between are skipped
+#[test]
+fn credo_disable_with_justification_passes() {
+    let temp = mix_project(
+        "defmodule Acme.Accounts do\n  # Debug output is part of the CLI contract\n  @doc false\n  # credo:disable-for-next-line Credo.Check.Warning.IoInspect\n  def debug(x), do: IO.inspect(x)\nend\n",
+    );
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/langs/elixir.md#suppress
+///
+/// > `"forbid"` - Never allowed
+#[test]
+fn credo_check_forbidden_by_config() {
+    let temp = mix_project(
+        "defmodule Acme.Accounts do\n  # Legacy nesting, split in the accounts rewrite\n  # credo:disable-for-this-file Credo.Check.Refactor.Nesting\nend\n",
+    );
+    temp.config("[elixir.suppress.source]\nforbid = [\"Credo.Check.Refactor.Nesting\"]\n");
+
+    check("escapes").pwd(temp.path()).fails().stdout_has("suppress_forbidden");
+}
+
+/// Spec: docs/specs/langs/elixir.md#suppress
+///
+/// > `"allow"` for test code
+#[test]
+fn suppress_in_test_code_allowed() {
+    let temp = mix_project("defmodule Acme.Accounts do\nend\n");
+    temp.file("test/acme/accounts_test.exs", "# credo:disable-for-next-line\nIO.inspect(:debug)\n");
+
+    check("escapes").pwd(temp.path()).passes();
+}
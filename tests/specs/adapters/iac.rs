@@ -0,0 +1,141 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the infrastructure-as-code adapter.
+//!
+//! Tests that quench correctly:
+//! - Counts Terraform and Dockerfile lines alongside the project language
+//! - Applies IaC escape patterns (scanner ignores, `USER root`, `latest` tags)
+//! - Keeps IaC escape patterns out of application code
+//!
+//! Reference: docs/specs/langs/iac.md
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+/// Python project with a Dockerfile and one Terraform file.
+fn infra_project(dockerfile: &str, terraform: &str) -> Project {
+    let temp = Project::empty();
+    temp.config("");
+    temp.file("pyproject.toml", "[project]\nname = \"acme\"\n");
+    temp.file("src/acme/app.py", "def main():\n    return 0\n");
+    temp.file("Dockerfile", dockerfile);
+    temp.file("infra/main.tf", terraform);
+    temp
+}
+
+const DOCKERFILE: &str = "FROM python:3.12-slim\nCOPY . /app\nCMD [\"python\", \"-m\", \"acme\"]\n";
+const TERRAFORM: &str = "resource \"aws_s3_bucket\" \"site\" {\n  bucket = \"acme-site\"\n}\n";
+
+// =============================================================================
+// DETECTION AND LINE COUNTING SPECS
+// =============================================================================
+
+/// Spec: docs/specs/langs/iac.md#detection
+///
+/// > The IaC adapter is always active, alongside the detected project language
+#[test]
+fn dockerfile_and_terraform_counted_as_source() {
+    let temp = infra_project(
+        "# Runtime image\nFROM python:3.12-slim\nCOPY . /app\n",
+        "# Static site\nresource \"aws_s3_bucket\" \"site\" {\n  // bucket name is global\n  bucket = \"acme-site\"\n}\n",
+    );
+    temp.file("infra/tests/defaults.tftest.hcl", "run \"defaults\" {\n  command = plan\n}\n");
+
+    let cloc = check("cloc").pwd(temp.path()).json().passes();
+    let metrics = cloc.require("metrics");
+
+    // app.py (2) + Dockerfile (3) + main.tf (5), non-blank lines
+    assert_eq!(metrics.get("source_lines").and_then(|v| v.as_u64()), Some(10));
+    assert_eq!(metrics.get("test_lines").and_then(|v| v.as_u64()), Some(3));
+}
+
+/// Spec: docs/specs/langs/iac.md#default-patterns
+///
+/// > ignore = ["**/.terraform/", "**/.terragrunt-cache/"]
+#[test]
+fn terraform_module_cache_is_ignored() {
+    let temp = infra_project(DOCKERFILE, TERRAFORM);
+    temp.file(".terraform/modules/vpc/main.tf", "#tfsec:ignore:aws-vpc-no-public-ingress\n");
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+// =============================================================================
+// ESCAPE PATTERN SPECS
+// =============================================================================
+
+/// Spec: docs/specs/langs/iac.md#default-escape-patterns
+///
+/// > `:latest` image tag requires `# LATEST:`
+#[test]
+fn latest_tag_without_comment_fails() {
+    let temp = infra_project("FROM python:latest\nCOPY . /app\n", TERRAFORM);
+
+    check("escapes").pwd(temp.path()).fails().stdout_has("# LATEST:");
+}
+
+/// Spec: docs/specs/langs/iac.md#default-escape-patterns
+///
+/// > `USER root`, `runAsUser: 0` requires `# ROOT:`
+#[test]
+fn user_root_without_comment_fails() {
+    let temp = infra_project("FROM python:3.12-slim\nUSER root\n", TERRAFORM);
+
+    check("escapes").pwd(temp.path()).fails().stdout_has("# ROOT:");
+}
+
+/// Spec: docs/specs/langs/iac.md#default-escape-patterns
+///
+/// > `tfsec:ignore` / `trivy:ignore` requires `# SECURITY:`
+#[test]
+fn tfsec_ignore_with_comment_passes() {
+    let temp = infra_project(
+        DOCKERFILE,
+        "resource \"aws_s3_bucket\" \"site\" {\n  # SECURITY: public static website, no user data\n  #tfsec:ignore:aws-s3-block-public-acls\n  bucket = \"acme-site\"\n}\n",
+    );
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/langs/iac.md#default-escape-patterns
+///
+/// > `checkov:skip=` requires `# SECURITY:`
+#[test]
+fn checkov_skip_without_comment_fails() {
+    let temp = infra_project(
+        DOCKERFILE,
+        "resource \"aws_s3_bucket\" \"site\" {\n  #checkov:skip=CKV_AWS_20:public website\n  bucket = \"acme-site\"\n}\n",
+    );
+
+    check("escapes").pwd(temp.path()).fails().stdout_has("# SECURITY:");
+}
+
+/// Spec: docs/specs/langs/iac.md#default-escape-patterns
+///
+/// > `:latest` image tag requires `# LATEST:`
+#[test]
+fn kubernetes_latest_image_without_comment_fails() {
+    let temp = infra_project(DOCKERFILE, TERRAFORM);
+    temp.file(
+        "k8s/deployment.yaml",
+        "spec:\n  containers:\n    - name: api\n      image: acme/api:latest\n",
+    );
+
+    check("escapes").pwd(temp.path()).fails().stdout_has("# LATEST:");
+}
+
+/// Spec: docs/specs/langs/iac.md#default-escape-patterns
+///
+/// > These patterns apply only to files matching `[iac].source`
+#[test]
+fn iac_patterns_do_not_apply_to_application_code() {
+    let temp = infra_project(DOCKERFILE, TERRAFORM);
+    temp.file(
+        "src/acme/deploy.py",
+        "DEFAULT_IMAGE = \"image: acme/api:latest\"\nSHELL_USER = \"\"\"\nUSER root\n\"\"\"\n",
+    );
+
+    check("escapes").pwd(temp.path()).passes();
+}
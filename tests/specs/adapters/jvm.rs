@@ -0,0 +1,161 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the JVM (Java/Kotlin) language adapter.
+//!
+//! Tests that quench correctly:
+//! - Detects JVM projects via Gradle and Maven build files
+//! - Classifies Gradle/Maven test source sets as test code
+//! - Applies JVM escape patterns (`@SuppressWarnings`, `!!`)
+//! - Checks `@Suppress` / `// noinspection` directives
+//!
+//! Reference: docs/specs/langs/jvm.md
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+/// Gradle project with one Kotlin source file.
+fn kotlin_project(source: &str) -> Project {
+    let temp = Project::empty();
+    temp.config("");
+    temp.file("build.gradle.kts", "plugins { kotlin(\"jvm\") version \"2.0.0\" }\n");
+    temp.file("src/main/kotlin/App.kt", source);
+    temp
+}
+
+// =============================================================================
+// DETECTION AND PATTERN SPECS
+// =============================================================================
+
+/// Spec: docs/specs/langs/jvm.md#test-code-detection
+///
+/// > Files in Gradle/Maven test source sets: `src/test/`, and `src/<name>Test/`
+#[test]
+fn test_source_sets_counted_as_test_code() {
+    let temp = Project::empty();
+    temp.config("");
+    temp.file("pom.xml", "<project/>\n");
+    temp.file("src/main/java/App.java", "class App {\n    int run() { return 1; }\n}\n");
+    temp.file("src/test/java/AppTest.java", "class AppTest {\n    void runs() {}\n}\n");
+    temp.file("src/integrationTest/java/Db.java", "class Db {\n    void connects() {}\n}\n");
+
+    let cloc = check("cloc").pwd(temp.path()).json().passes();
+    let metrics = cloc.require("metrics");
+
+    assert_eq!(metrics.get("source_lines").and_then(|v| v.as_u64()), Some(3));
+    assert_eq!(metrics.get("test_lines").and_then(|v| v.as_u64()), Some(6));
+}
+
+/// Spec: docs/specs/langs/jvm.md#default-patterns
+///
+/// > ignore = ["build/", "target/", ".gradle/", "out/"]
+#[test]
+fn build_output_is_ignored() {
+    let temp = kotlin_project("fun main() {\n    println(\"hi\")\n}\n");
+    temp.file("build/generated/Gen.kt", "val x = y!!\n");
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+// =============================================================================
+// ESCAPE PATTERN SPECS
+// =============================================================================
+
+/// Spec: docs/specs/langs/jvm.md#default-escape-patterns
+///
+/// > `!!` (Kotlin not-null assertion) requires `// SAFETY:`
+#[test]
+fn not_null_assertion_without_safety_comment_fails() {
+    let temp = kotlin_project("fun name(user: User?) = user!!.name\n");
+
+    check("escapes").pwd(temp.path()).fails().stdout_has("// SAFETY:");
+}
+
+/// Spec: docs/specs/langs/jvm.md#default-escape-patterns
+///
+/// > `!!` with a `// SAFETY:` comment passes
+#[test]
+fn not_null_assertion_with_safety_comment_passes() {
+    let temp = kotlin_project(
+        "// SAFETY: callers check for a logged-in user first\nfun name(user: User?) = user!!.name\n",
+    );
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/langs/jvm.md#default-escape-patterns
+///
+/// > The `!!` pattern ignores `!!` at the end of string literals
+#[test]
+fn exclamation_in_string_is_not_an_escape() {
+    let temp = kotlin_project("fun greet() = \"Hi!!\"\n");
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+// =============================================================================
+// SUPPRESS SPECS
+// =============================================================================
+
+/// Spec: docs/specs/langs/jvm.md#suppress
+///
+/// > Default: `"comment"` for source
+#[test]
+fn suppress_without_comment_fails() {
+    let temp = kotlin_project("@Suppress(\"UNCHECKED_CAST\")\nfun <T> cast(v: Any) = v as T\n");
+
+    check("escapes")
+        .pwd(temp.path())
+        .fails()
+        .stdout_has("suppress_missing_comment")
+        .stdout_has("@Suppress");
+}
+
+/// Spec: docs/specs/langs/jvm.md#suppress
+///
+/// > Other annotations between the comment and `@Suppress` are skipped
+#[test]
+fn suppress_with_comment_above_annotations_passes() {
+    let temp = kotlin_project(
+        "// Values come from a typed registry\n@JvmStatic\n@Suppress(\"UNCHECKED_CAST\")\nfun <T> cast(v: Any) = v as T\n",
+    );
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/langs/jvm.md#suppress
+///
+/// > `.java` files use `[java.suppress]`; `.kt` and `.kts` files use `[kotlin.suppress]`.
+#[test]
+fn noinspection_in_java_uses_java_config() {
+    let temp = Project::empty();
+    temp.config("[java.suppress]\ncheck = \"forbid\"\n");
+    temp.file("pom.xml", "<project/>\n");
+    temp.file(
+        "src/main/java/App.java",
+        "class App {\n    // Kept for reflection\n    //noinspection unused\n    private int x;\n}\n",
+    );
+    temp.file(
+        "src/main/kotlin/Util.kt",
+        "// Kept for reflection\n@Suppress(\"unused\")\nprivate val x = 1\n",
+    );
+
+    let result = check("escapes").pwd(temp.path()).json().fails();
+    assert!(result.has_violation_for_file("App.java"));
+    assert!(!result.has_violation_for_file("Util.kt"));
+}
+
+/// Spec: docs/specs/langs/jvm.md#suppress
+///
+/// > `"allow"` for test code
+#[test]
+fn suppress_in_test_code_allowed() {
+    let temp = kotlin_project("fun main() {}\n");
+    temp.file(
+        "src/test/kotlin/AppTest.kt",
+        "@Suppress(\"UNCHECKED_CAST\")\nfun <T> cast(v: Any) = v as T\n",
+    );
+
+    check("escapes").pwd(temp.path()).passes();
+}
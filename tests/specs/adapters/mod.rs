@@ -7,9 +7,17 @@
 //!
 //! Reference: docs/specs/10-language-adapters.md
 
+pub mod cpp;
+pub mod csharp;
+pub mod declarative;
+pub mod elixir;
 pub mod golang;
+pub mod iac;
 pub mod javascript;
+pub mod jvm;
+pub mod php;
 pub mod python;
 pub mod ruby;
 pub mod rust;
 pub mod shell;
+pub mod swift;
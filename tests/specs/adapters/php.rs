@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the PHP language adapter.
+//!
+//! Tests that quench correctly:
+//! - Detects PHP projects via `composer.json`
+//! - Classifies `tests/` and `*Test.php` as test code
+//! - Applies PHP escape patterns (`eval(`, `@`, `extract(`)
+//! - Checks phpstan, psalm and phpcs suppressions
+//!
+//! Reference: docs/specs/langs/php.md
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+/// Composer project with one PHP source file.
+fn composer_project(source: &str) -> Project {
+    let temp = Project::empty();
+    temp.config("");
+    temp.file("composer.json", "{\"name\": \"acme/app\"}\n");
+    temp.file("src/Kernel.php", source);
+    temp
+}
+
+// =============================================================================
+// DETECTION AND PATTERN SPECS
+// =============================================================================
+
+/// Spec: docs/specs/langs/php.md#test-code-detection
+///
+/// > Files under `tests/` or `test/` (PHPUnit and Pest conventions)
+#[test]
+fn tests_directory_counted_as_test_code() {
+    let temp = composer_project("<?php\n\nnamespace App;\n\nfinal class Kernel {}\n");
+    temp.file(
+        "tests/Unit/KernelTest.php",
+        "<?php\n\nfinal class KernelTest extends TestCase\n{\n    public function testBoots(): void {}\n}\n",
+    );
+
+    let cloc = check("cloc").pwd(temp.path()).json().passes();
+    let metrics = cloc.require("metrics");
+
+    assert_eq!(metrics.get("source_lines").and_then(|v| v.as_u64()), Some(3));
+    assert_eq!(metrics.get("test_lines").and_then(|v| v.as_u64()), Some(5));
+}
+
+/// Spec: docs/specs/langs/php.md#default-patterns
+///
+/// > ignore = ["vendor/", "var/cache/", "storage/framework/", "bootstrap/cache/"]
+#[test]
+fn vendor_is_ignored() {
+    let temp = composer_project("<?php\n");
+    temp.file("vendor/acme/lib/src/Loader.php", "<?php\neval($code);\n");
+    temp.file("var/cache/dev/Container.php", "<?php\nextract($params);\n");
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+// =============================================================================
+// ESCAPE PATTERN SPECS
+// =============================================================================
+
+/// Spec: docs/specs/langs/php.md#default-escape-patterns
+///
+/// > `@` error suppression operator requires `// SUPPRESS:`
+#[test]
+fn error_suppression_without_comment_fails() {
+    let temp = composer_project("<?php\n$data = @file_get_contents($url);\n");
+
+    check("escapes").pwd(temp.path()).fails().stdout_has("// SUPPRESS:");
+}
+
+/// Spec: docs/specs/langs/php.md#default-escape-patterns
+///
+/// > `eval(` requires `// EVAL:`
+#[test]
+fn eval_with_comment_passes() {
+    let temp = composer_project(
+        "<?php\n// EVAL: template cache is compiled from trusted views only\neval($compiled);\n",
+    );
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+// =============================================================================
+// SUPPRESS SPECS
+// =============================================================================
+
+/// Spec: docs/specs/langs/php.md#suppress
+///
+/// > Default: `"comment"` for source
+#[test]
+fn phpstan_ignore_without_comment_fails() {
+    let temp = composer_project("<?php\n// @phpstan-ignore-next-line\n$client->send();\n");
+
+    check("escapes")
+        .pwd(temp.path())
+        .fails()
+        .stdout_has("suppress_missing_comment")
+        .stdout_has("@phpstan-ignore-next-line");
+}
+
+/// Spec: docs/specs/langs/php.md#suppress
+///
+/// > A justification is a comment on the line(s) above, the tool's own reason
+/// > syntax [...] or a description line in the same docblock
+#[test]
+fn suppressions_with_justification_pass() {
+    let temp = composer_project(
+        "<?php\n/**\n * Legacy callers still pass numeric strings here.\n *\n * @psalm-suppress InvalidArgument\n */\nfunction find($id) {}\n\n// phpcs:ignore Generic.Files.LineLength -- generated SQL fixture\n$sql = '';\n\n/** @phpstan-ignore argument.type (facade returns mixed) */\n$x = f();\n",
+    );
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/langs/php.md#suppress
+///
+/// > `"forbid"` - Never allowed
+#[test]
+fn psalm_suppress_forbidden_by_config() {
+    let temp = composer_project(
+        "<?php\n/** @psalm-suppress MixedAssignment decoded JSON is validated below */\n$x = $json['a'];\n",
+    );
+    temp.config("[php.suppress.source]\nforbid = [\"MixedAssignment\"]\n");
+
+    check("escapes").pwd(temp.path()).fails().stdout_has("suppress_forbidden");
+}
+
+/// Spec: docs/specs/langs/php.md#suppress
+///
+/// > `"allow"` for test code
+#[test]
+fn suppress_in_test_code_allowed() {
+    let temp = composer_project("<?php\n");
+    temp.file("tests/Unit/KernelTest.php", "<?php\n// @phpstan-ignore-next-line\n$this->mock();\n");
+
+    check("escapes").pwd(temp.path()).passes();
+}
@@ -0,0 +1,162 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the Swift language adapter.
+//!
+//! Tests that quench correctly:
+//! - Detects Swift packages via `Package.swift`
+//! - Classifies `Tests/` targets as test code
+//! - Applies Swift escape patterns (`try!`, `as!`, force unwrap, `unsafeBitCast`)
+//! - Checks SwiftLint directives
+//!
+//! Reference: docs/specs/langs/swift.md
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+/// SwiftPM package with one Swift source file.
+fn swift_package(source: &str) -> Project {
+    let temp = Project::empty();
+    temp.config("");
+    temp.file("Package.swift", "// swift-tools-version:5.9\nimport PackageDescription\n");
+    temp.file("Sources/Acme/Parser.swift", source);
+    temp
+}
+
+// =============================================================================
+// DETECTION AND PATTERN SPECS
+// =============================================================================
+
+/// Spec: docs/specs/langs/swift.md#test-code-detection
+///
+/// > Files in SwiftPM test targets under `Tests/` (including nested packages)
+#[test]
+fn tests_directory_counted_as_test_code() {
+    let temp = swift_package("public struct Parser {\n    public init() {}\n}\n");
+    temp.file(
+        "Tests/AcmeTests/Fixtures.swift",
+        "import XCTest\n\nlet fixture = \"1 + 2\"\nlet expected = 3\n",
+    );
+
+    let cloc = check("cloc").pwd(temp.path()).json().passes();
+    let metrics = cloc.require("metrics");
+
+    // Package.swift (2) + Parser.swift (3)
+    assert_eq!(metrics.get("source_lines").and_then(|v| v.as_u64()), Some(5));
+    assert_eq!(metrics.get("test_lines").and_then(|v| v.as_u64()), Some(3));
+}
+
+/// Spec: docs/specs/langs/swift.md#default-patterns
+///
+/// > ignore = [".build/", ".swiftpm/", "DerivedData/", "Pods/", "Carthage/"]
+#[test]
+fn build_directory_is_ignored() {
+    let temp = swift_package("public struct Parser {}\n");
+    temp.file(".build/checkouts/swift-nio/Sources/NIO/Channel.swift", "let c = try! open()\n");
+    temp.file("Pods/Alamofire/Source/Session.swift", "let s = x as! Session\n");
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+// =============================================================================
+// ESCAPE PATTERN SPECS
+// =============================================================================
+
+/// Spec: docs/specs/langs/swift.md#default-escape-patterns
+///
+/// > `try!` requires `// SAFETY:`
+#[test]
+fn force_try_without_comment_fails() {
+    let temp = swift_package("let data = try! Data(contentsOf: url)\n");
+
+    check("escapes").pwd(temp.path()).fails().stdout_has("// SAFETY:");
+}
+
+/// Spec: docs/specs/langs/swift.md#default-escape-patterns
+///
+/// > force unwrap `!` requires `// SAFETY:`
+#[test]
+fn force_unwrap_without_comment_fails() {
+    let temp = swift_package("let name = user!.name\n");
+
+    check("escapes").pwd(temp.path()).fails().stdout_has("force_unwrap");
+}
+
+/// Spec: docs/specs/langs/swift.md#default-escape-patterns
+///
+/// > Comparisons (`!=`) and prefix negation (`!isEmpty`) are not matched.
+#[test]
+fn negation_and_comparison_pass() {
+    let temp = swift_package("if a != b && !items.isEmpty {\n    print(\"Done!\")\n}\n");
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/langs/swift.md#default-escape-patterns
+///
+/// > `as!` requires `// SAFETY:`
+#[test]
+fn force_cast_with_comment_passes() {
+    let temp = swift_package(
+        "// SAFETY: the table view registers only this cell type\nlet cell = view as! CustomCell\n",
+    );
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+// =============================================================================
+// SUPPRESS SPECS
+// =============================================================================
+
+/// Spec: docs/specs/langs/swift.md#suppress
+///
+/// > Default: `"comment"` for source
+#[test]
+fn swiftlint_disable_without_comment_fails() {
+    let temp = swift_package("// swiftlint:disable:next identifier_name\nlet x = 1\n");
+
+    check("escapes")
+        .pwd(temp.path())
+        .fails()
+        .stdout_has("suppress_missing_comment")
+        .stdout_has("swiftlint:disable:next identifier_name");
+}
+
+/// Spec: docs/specs/langs/swift.md#suppress
+///
+/// > A justification is a comment on the line(s) above [...] or SwiftLint's
+/// > trailing ` - reason`
+#[test]
+fn swiftlint_with_justification_passes() {
+    let temp = swift_package(
+        "// Generated from the server schema; field names must match\n// swiftlint:disable identifier_name\nlet user_id = 1\n\n// swiftlint:disable:next line_length - long URL literal\nlet url = \"\"\n",
+    );
+
+    check("escapes").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/langs/swift.md#suppress
+///
+/// > `"forbid"` - Never allowed
+#[test]
+fn swiftlint_rule_forbidden_by_config() {
+    let temp = swift_package("// Legacy API\n// swiftlint:disable:next force_try\nlet x = 1\n");
+    temp.config("[swift.suppress.source]\nforbid = [\"force_try\"]\n");
+
+    check("escapes").pwd(temp.path()).fails().stdout_has("suppress_forbidden");
+}
+
+/// Spec: docs/specs/langs/swift.md#suppress
+///
+/// > `"allow"` for test code
+#[test]
+fn suppress_in_test_code_allowed() {
+    let temp = swift_package("public struct Parser {}\n");
+    temp.file(
+        "Tests/AcmeTests/ParserTests.swift",
+        "// swiftlint:disable:next force_cast\nlet p = x as! Parser\n",
+    );
+
+    check("escapes").pwd(temp.path()).passes();
+}
@@ -57,6 +57,30 @@ fn agents_file_over_max_tokens_generates_violation() {
     );
 }
 
+/// Spec: docs/specs/checks/cloc.md#tokenizers
+///
+/// > `tokenizer` selects how `max_tokens` is counted (also available in `[check.agents]`,
+/// > where it applies to agent files and specs)
+#[test]
+fn agents_max_tokens_uses_configured_tokenizer() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[check.agents]
+files = ["CLAUDE.md"]
+required = ["CLAUDE.md"]
+max_tokens = 10
+tokenizer = "approx"
+"#,
+    );
+    // 48 characters = 12 tokens
+    temp.file("CLAUDE.md", "# Agents\n\naaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\n");
+
+    let agents = check("agents").pwd(temp.path()).json().fails();
+    let violation = agents.require_violation("file_too_large");
+
+    assert_eq!(violation.get("value").and_then(|v| v.as_i64()), Some(12));
+}
+
 /// Spec: docs/specs/checks/agents.md#box-diagrams
 ///
 /// > Box diagrams generate a violation when box_diagrams = "forbid".
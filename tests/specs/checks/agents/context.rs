@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the effective context budget and `quench agents context`.
+//!
+//! Reference: docs/specs/templates/guide.agents.md#context-budget
+
+use crate::prelude::*;
+
+/// Project with root, module, and Cursor rule files around `src/api`.
+fn layered_project(budget: usize) -> Project {
+    let temp = Project::empty();
+    temp.config(&format!(
+        r#"[check.agents]
+sections.required = []
+effective_max_tokens = {budget}
+"#
+    ));
+    // 40 characters each = 10 tokens
+    temp.file("CLAUDE.md", "# Root\n\naaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\n");
+    temp.file("src/api/CLAUDE.md", "# Api\n\naaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\n");
+    temp.file(".cursor/rules/manual.mdc", "---\ndescription: \"Manual\"\n---\n\nManual rule.\n");
+    temp.file(".cursor/rules/ts.mdc", "---\nglobs: \"**/*.ts\"\n---\n\nTypeScript rule.\n");
+    temp.file("src/main.rs", "fn main() {}\n");
+    temp.file("src/api/mod.rs", "pub fn handler() {}\n");
+    temp
+}
+
+/// Spec: docs/specs/templates/guide.agents.md#context-budget
+///
+/// > Directories over budget report `context_budget_exceeded` with the contributing
+/// > files.
+#[test]
+fn context_budget_exceeded_lists_contributing_files() {
+    let temp = layered_project(15);
+
+    let agents = check("agents").pwd(temp.path()).json().fails();
+    let violation = agents.require_violation("context_budget_exceeded");
+
+    assert_eq!(violation.get("file").and_then(|f| f.as_str()), Some("src/api"));
+    assert_eq!(violation.get("value").and_then(|v| v.as_i64()), Some(20));
+    assert_eq!(violation.get("threshold").and_then(|v| v.as_i64()), Some(15));
+    let files: Vec<&str> = violation
+        .get("files")
+        .and_then(|f| f.as_array())
+        .unwrap()
+        .iter()
+        .filter_map(|f| f.as_str())
+        .collect();
+    assert_eq!(files, vec!["CLAUDE.md", "src/api/CLAUDE.md"]);
+}
+
+/// Spec: docs/specs/templates/guide.agents.md#context-budget
+///
+/// > Bound the combined size loaded in any source directory
+#[test]
+fn context_within_budget_passes() {
+    let temp = layered_project(20);
+
+    let agents = check("agents").pwd(temp.path()).json().passes();
+
+    assert_eq!(
+        agents.require("metrics").get("max_context_tokens").and_then(|t| t.as_i64()),
+        Some(20)
+    );
+}
+
+/// Spec: docs/specs/templates/guide.agents.md#context-budget
+///
+/// > effective_max_tokens = 8000   # Default: disabled
+#[test]
+fn context_budget_disabled_by_default() {
+    let temp = Project::empty();
+    temp.config("[check.agents]\nsections.required = []\n");
+    temp.file("CLAUDE.md", &format!("# Root\n\n{}\n", "a".repeat(60_000)));
+    temp.file("src/api/CLAUDE.md", &format!("# Api\n\n{}\n", "a".repeat(60_000)));
+    temp.file("src/api/mod.rs", "pub fn handler() {}\n");
+
+    let agents = check("agents").pwd(temp.path()).json().passes();
+
+    assert!(!agents.has_violation("context_budget_exceeded"));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-agents-context
+///
+/// > Print the agent files an agent loads together when working in a directory,
+/// > followed by their combined token count.
+#[test]
+fn agents_context_prints_assembled_context() {
+    let temp = layered_project(15);
+
+    quench_cmd()
+        .args(["agents", "context", "src/api"])
+        .current_dir(temp.path())
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("==> CLAUDE.md (root, 10 tokens) <=="))
+        .stdout(predicates::str::contains("==> src/api/CLAUDE.md (module, 10 tokens) <=="))
+        .stdout(predicates::str::contains("Manual rule.").not())
+        .stdout(predicates::str::contains(
+            "Total: 20 tokens in 2 files (approx, effective_max_tokens: 15)",
+        ));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-agents-context
+///
+/// > file globs matching a file in `PATH` (or `PATH` itself, when it is a file)
+#[test]
+fn agents_context_for_file_includes_matching_rules() {
+    let temp = layered_project(15);
+    temp.file("web/app.ts", "export {};\n");
+
+    let output = quench_cmd()
+        .args(["agents", "context", "web/app.ts", "-o", "json"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let files: Vec<&str> =
+        json["files"].as_array().unwrap().iter().filter_map(|f| f["path"].as_str()).collect();
+    assert_eq!(files, vec!["CLAUDE.md", ".cursor/rules/ts.mdc"]);
+    assert_eq!(json["directory"], "web");
+}
@@ -8,14 +8,18 @@
 //! - Validates file synchronization
 //! - Checks required/forbidden sections
 //! - Enforces content rules (tables, max_lines, max_tokens)
+//! - Bounds the combined context loaded per directory
 //! - Generates correct violation types
 //! - Outputs metrics in JSON format
 //!
 //! Reference: docs/specs/checks/agents.md
 
 mod content;
+mod context;
 mod cursor;
 mod defaults;
 mod detection;
 mod edge_cases;
 mod output;
+mod references;
+mod tools;
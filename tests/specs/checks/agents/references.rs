@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for stale path and declaration references in agent files.
+//!
+//! Reference: docs/specs/templates/guide.agents.md#stale-references
+
+use crate::prelude::*;
+
+/// Project whose CLAUDE.md references `content`, with references enabled.
+fn project_with_references(content: &str) -> Project {
+    let temp = Project::empty();
+    temp.config(
+        r#"[check.agents]
+sections.required = []
+
+[check.agents.references]
+check = "error"
+ignore = ["generated/**"]
+"#,
+    );
+    temp.file("CLAUDE.md", content);
+    temp.file("Cargo.toml", "[package]\nname = \"test\"\nversion = \"0.1.0\"\n");
+    temp.file("src/walker.rs", "pub struct Walker;\n\npub fn walk_collect() {}\n");
+    temp
+}
+
+/// Spec: docs/specs/templates/guide.agents.md#stale-references
+///
+/// > Missing references report `stale_reference` at their line.
+#[test]
+fn missing_path_reports_stale_reference() {
+    let temp = project_with_references("# Project\n\nThe walker lives in `src/walk.rs`.\n");
+
+    let agents = check("agents").pwd(temp.path()).json().fails();
+    let violation = agents.require_violation("stale_reference");
+
+    assert_eq!(violation.get("file").and_then(|f| f.as_str()), Some("CLAUDE.md"));
+    assert_eq!(violation.get("line").and_then(|l| l.as_i64()), Some(3));
+    assert_eq!(violation.get("path").and_then(|p| p.as_str()), Some("src/walk.rs"));
+}
+
+/// Spec: docs/specs/templates/guide.agents.md#stale-references
+///
+/// > Paths like `src/walker.rs` or `docs/` resolve from the project root or the
+/// > agent file's directory; a bare file name may live anywhere in the project.
+#[test]
+fn existing_paths_pass() {
+    let temp = project_with_references(
+        "# Project\n\nSee `src/walker.rs:3`, `src/`, and `walker.rs`. Branch `origin/main`.\n",
+    );
+
+    check("agents").pwd(temp.path()).json().passes();
+}
+
+/// Spec: docs/specs/templates/guide.agents.md#stale-references
+///
+/// > Declarations like `fn detect_agent_files` or `class Parser` must be declared
+/// > in a source file of one of the project's languages.
+#[test]
+fn missing_declaration_reports_stale_reference() {
+    let temp = project_with_references("# Project\n\nUse `fn walk_collect`, not `fn walk_all`.\n");
+
+    let agents = check("agents").pwd(temp.path()).json().fails();
+    let violations = agents.violations_of_type("stale_reference");
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].get("path").and_then(|p| p.as_str()), Some("fn walk_all"));
+}
+
+/// Spec: docs/specs/templates/guide.agents.md#stale-references
+///
+/// > Code in fenced blocks is skipped.
+#[test]
+fn fenced_blocks_and_ignored_paths_are_skipped() {
+    let temp = project_with_references(
+        "# Project\n\n```\n`src/old.rs`\n```\n\nOutput goes to `generated/out.rs`.\n",
+    );
+
+    check("agents").pwd(temp.path()).json().passes();
+}
+
+/// Spec: docs/specs/templates/guide.agents.md#stale-references
+///
+/// > check = "error"                 # Default: off
+#[test]
+fn references_off_by_default() {
+    let temp = Project::empty();
+    temp.config("[check.agents]\nsections.required = []\n");
+    temp.file("CLAUDE.md", "# Project\n\nSee `src/missing.rs`.\n");
+
+    check("agents").pwd(temp.path()).json().passes();
+}
@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for Claude, Copilot, and Gemini agent files.
+//!
+//! Reference: docs/specs/checks/agents.tools.md
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+/// Project with a root CLAUDE.md and no required sections.
+fn project() -> Project {
+    let temp = Project::empty();
+    temp.config("[check.agents]\nsections.required = []\n");
+    temp.file("CLAUDE.md", "# Project\n\nGuidance.\n");
+    temp.file("src/app.ts", "export const app = 1;\n");
+    temp
+}
+
+fn files_found(agents: &CheckJson) -> Vec<String> {
+    let files = agents.require("metrics").get("files_found").unwrap().as_array().unwrap();
+    files.iter().filter_map(|f| f.as_str().map(String::from)).collect()
+}
+
+/// Spec: docs/specs/checks/agents.tools.md#agent-files
+///
+/// > These patterns are part of the default `files` list
+#[test]
+fn detects_claude_copilot_and_gemini_files() {
+    let temp = project();
+    temp.file("GEMINI.md", "# Project\n\nGuidance.\n");
+    temp.file(".github/copilot-instructions.md", "# Project\n\nGuidance.\n");
+    temp.file(".github/instructions/ts.instructions.md", "---\napplyTo: \"**/*.ts\"\n---\n\nTS.\n");
+    temp.file(".claude/commands/review.md", "Review the diff.\n");
+    temp.file(".claude/agents/tester.md", "---\nname: tester\ndescription: Runs tests\n---\n");
+    temp.file(".claude/skills/deploy/SKILL.md", "---\nname: deploy\ndescription: Deploys\n---\n");
+
+    let agents = check("agents").pwd(temp.path()).json().passes();
+    let found = files_found(&agents);
+
+    for file in [
+        "GEMINI.md",
+        ".github/copilot-instructions.md",
+        ".github/instructions/ts.instructions.md",
+        ".claude/commands/review.md",
+        ".claude/agents/tester.md",
+        ".claude/skills/deploy/SKILL.md",
+    ] {
+        assert!(found.iter().any(|f| f == file), "should detect {file}: {found:?}");
+    }
+}
+
+/// Spec: docs/specs/checks/agents.tools.md#agent-files
+///
+/// > Root-scope files are held to the same rules as the root CLAUDE.md: required
+/// > sections, size limits, and sync.
+#[test]
+fn copilot_instructions_sync_with_claude_md() {
+    let temp = project();
+    temp.file(".github/copilot-instructions.md", "# Project\n\nOther guidance.\n");
+
+    let agents = check("agents").pwd(temp.path()).json().fails();
+    let violation = agents.require_violation("out_of_sync");
+
+    assert_eq!(
+        violation.get("file").and_then(|f| f.as_str()),
+        Some(".github/copilot-instructions.md")
+    );
+}
+
+/// Spec: docs/specs/checks/agents.tools.md#fields
+///
+/// > | Subagent | `name`, `description` | `tools`, `model`, `color`, `permissionMode`, `skills` |
+#[test]
+fn subagent_missing_description_is_invalid() {
+    let temp = project();
+    temp.file(".claude/agents/tester.md", "---\nname: tester\n---\n\nRun tests.\n");
+
+    let agents = check("agents").pwd(temp.path()).json().fails();
+    let violation = agents.require_violation("invalid_frontmatter");
+
+    assert_eq!(violation.get("file").and_then(|f| f.as_str()), Some(".claude/agents/tester.md"));
+    assert!(violation.get("advice").and_then(|a| a.as_str()).unwrap().contains("`description`"));
+}
+
+/// Spec: docs/specs/checks/agents.tools.md#fields
+///
+/// > Unknown keys are reported: a misspelled key is silently ignored by the tool.
+#[test]
+fn unknown_command_key_is_invalid() {
+    let temp = project();
+    temp.file(".claude/commands/review.md", "---\ndescription: Review\nallowed_tools: Bash\n---\n");
+
+    let agents = check("agents").pwd(temp.path()).json().fails();
+    let violation = agents.require_violation("invalid_frontmatter");
+
+    assert_eq!(violation.get("line").and_then(|l| l.as_i64()), Some(3));
+}
+
+/// Spec: docs/specs/checks/agents.tools.md#applyto
+///
+/// > Each glob must be valid and match at least one file in the project; otherwise
+/// > the instructions never load.
+#[test]
+fn apply_to_matching_no_files_is_reported() {
+    let temp = project();
+    temp.file(
+        ".github/instructions/ui.instructions.md",
+        "---\napplyTo: \"**/*.ts,**/*.vue\"\n---\n\nUI.\n",
+    );
+
+    let agents = check("agents").pwd(temp.path()).json().fails();
+    let violations = agents.violations_of_type("unmatched_glob");
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].get("pattern").and_then(|p| p.as_str()), Some("**/*.vue"));
+    assert_eq!(violations[0].get("line").and_then(|l| l.as_i64()), Some(2));
+}
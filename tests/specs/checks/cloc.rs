@@ -444,3 +444,54 @@ metric = "nonblank"
     let nonblank = v.get("nonblank").and_then(|v| v.as_i64()).unwrap();
     assert_eq!(value, nonblank, "metric=nonblank should use nonblank for value");
 }
+
+// =============================================================================
+// TOKENIZER SPECS
+// =============================================================================
+
+/// Spec: docs/specs/checks/cloc.md#tokenizers
+///
+/// > `approx` (default) | `chars / 4` heuristic
+#[test]
+fn cloc_approx_tokenizer_counts_chars_over_four() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[check.cloc]
+max_tokens = 10
+tokenizer = "approx"
+"#,
+    );
+    // 48 characters = 12 tokens
+    temp.file("src/lib.rs", "// aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\n");
+
+    let cloc = check("cloc").pwd(temp.path()).json().fails();
+    let violations = cloc.require("violations").as_array().unwrap();
+
+    assert_eq!(violations[0].get("value").and_then(|v| v.as_i64()), Some(12));
+    assert_eq!(violations[0].get("threshold").and_then(|v| v.as_i64()), Some(10));
+}
+
+/// Spec: docs/specs/checks/cloc.md#tokenizers
+///
+/// > Selecting `cl100k` or `o200k` in a build without the feature is a config error.
+#[test]
+fn cloc_bpe_tokenizer_requires_feature() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[check.cloc]
+tokenizer = "cl100k"
+"#,
+    );
+    temp.file("src/lib.rs", "fn main() {}\n");
+
+    if cfg!(feature = "bpe-tokenizer") {
+        check("cloc").pwd(temp.path()).passes();
+    } else {
+        quench_cmd()
+            .args(["check", "--cloc"])
+            .current_dir(temp.path())
+            .assert()
+            .failure()
+            .stderr(predicates::str::contains("bpe-tokenizer"));
+    }
+}
@@ -231,6 +231,31 @@ max_lines = false
     check("docs").pwd(temp.path()).fails().stdout_has("spec_too_large").stdout_has("tokens");
 }
 
+/// Spec: docs/specs/checks/docs.md#content-rules
+///
+/// > tokenizer = "approx"             # approx | cl100k | o200k
+#[test]
+fn spec_tokenizer_configured_under_specs() {
+    let temp = default_project();
+    temp.config(
+        r#"[check.docs.specs]
+tokenizer = "cl100k"
+"#,
+    );
+    temp.file("docs/specs/CLAUDE.md", "# Overview\n");
+
+    if cfg!(feature = "bpe-tokenizer") {
+        check("docs").pwd(temp.path()).passes();
+    } else {
+        quench_cmd()
+            .args(["check", "--docs"])
+            .current_dir(temp.path())
+            .assert()
+            .failure()
+            .stderr(predicates::str::contains("bpe-tokenizer"));
+    }
+}
+
 // =============================================================================
 // DEFAULT BEHAVIOR SPECS
 // =============================================================================
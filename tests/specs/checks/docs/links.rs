@@ -65,3 +65,84 @@ fn relative_path_links_validated() {
     // ../config.md doesn't exist relative to docs/specs/overview.md
     check("docs").pwd(temp.path()).fails().stdout_has("config.md");
 }
+
+// =============================================================================
+// ANCHOR VALIDATION SPECS
+// =============================================================================
+
+/// Spec: docs/specs/checks/docs.md#anchors
+///
+/// > Links with a fragment must match an anchor in the linked markdown file
+#[test]
+fn link_to_missing_heading_generates_violation() {
+    let temp = default_project();
+    temp.file("docs/guide.md", "# Guide\n\n## Getting Started\n");
+    temp.file(
+        "README.md",
+        "See [setup](docs/guide.md#getting-started).\nSee [old](docs/guide.md#old-heading).\n",
+    );
+
+    let docs = check("docs").pwd(temp.path()).json().fails();
+    let violations = docs.violations_of_type("broken_anchor");
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0]["file"], "README.md");
+    assert_eq!(violations[0]["line"], 2);
+    assert_eq!(violations[0]["target"], "docs/guide.md#old-heading");
+}
+
+/// Spec: docs/specs/checks/docs.md#anchors
+///
+/// > or in the same file for `#section` links
+#[test]
+fn same_file_anchor_links_validated() {
+    let temp = default_project();
+    temp.file("README.md", "# Project\n\n[Options](#options) and [Usage](#usage)\n\n## Usage\n");
+
+    let docs = check("docs").pwd(temp.path()).json().fails();
+    let violation = docs.require_violation("broken_anchor");
+    assert_eq!(violation["target"], "#options");
+}
+
+/// Spec: docs/specs/checks/docs.md#anchors
+///
+/// > Repeated headings get `-1`, `-2`, ... suffixes, and explicit
+/// > `<a id="...">` or `<a name="...">` tags also define anchors.
+#[test]
+fn duplicate_headings_and_explicit_anchors_pass() {
+    let temp = default_project();
+    temp.file(
+        "docs/guide.md",
+        "# Guide\n\n## Output\n\n## Output\n\n<a id=\"legacy-config\"></a>\n## What's New?\n",
+    );
+    temp.file(
+        "README.md",
+        "[a](docs/guide.md#output-1) [b](docs/guide.md#legacy-config) [c](docs/guide.md#whats-new)\n",
+    );
+
+    check("docs").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/checks/docs.md#anchors
+///
+/// > Both `#` headings and setext headings (text underlined with
+/// > `===` or `---`) define anchors.
+#[test]
+fn setext_headings_define_anchors() {
+    let temp = default_project();
+    temp.file("docs/guide.md", "Guide\n=====\n\nSetup Steps\n-----------\n\nRun it.\n");
+    temp.file("README.md", "[a](docs/guide.md#guide) [b](docs/guide.md#setup-steps)\n");
+
+    check("docs").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/checks/docs.md#anchors
+///
+/// > Fragments on non-markdown files (e.g. `src/lib.rs#L10`) are not validated.
+#[test]
+fn fragments_on_non_markdown_files_not_validated() {
+    let temp = default_project();
+    temp.file("src/lib.rs", "pub fn f() {}\n");
+    temp.file("README.md", "See [f](src/lib.rs#L1).\n");
+
+    check("docs").pwd(temp.path()).passes();
+}
@@ -5,7 +5,8 @@
 //!
 //! Tests that quench correctly:
 //! - Validates TOC directory trees in markdown files
-//! - Validates markdown links to local files
+//! - Validates markdown links to local files and headings
+//! - Detects docs unreachable from the README, specs index, or agent files
 //! - Detects and validates specs index files
 //! - Checks required/forbidden sections in spec files
 //! - Checks feature commits have doc updates (CI mode)
@@ -17,6 +18,7 @@ mod commit;
 mod content;
 mod index;
 mod links;
+mod orphans;
 mod output;
 mod sections;
 mod toc;
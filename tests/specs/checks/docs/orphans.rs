@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for orphaned doc detection in the docs check.
+//!
+//! Reference: docs/specs/checks/docs.md#fast-mode-orphaned-docs
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+/// Project with orphan detection enabled and a README linking to the guide.
+fn orphans_project() -> Project {
+    let temp = Project::empty();
+    temp.config("[check.docs.orphans]\ncheck = \"error\"\n");
+    temp.file("README.md", "# Project\n\nSee the [guide](docs/guide.md).\n");
+    temp.file("docs/guide.md", "# Guide\n\nSee the [API](api/).\n");
+    temp.file("docs/api/README.md", "# API\n");
+    temp
+}
+
+/// Spec: docs/specs/checks/docs.md#fast-mode-orphaned-docs
+///
+/// > Reports markdown files under `docs/` that can't be reached by following
+/// > markdown links from the entry points
+#[test]
+fn unlinked_doc_generates_violation() {
+    let temp = orphans_project();
+    temp.file("docs/old-design.md", "# Old Design\n");
+
+    let docs = check("docs").pwd(temp.path()).json().fails();
+    let violations = docs.violations_of_type("orphaned_doc");
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0]["file"], "docs/old-design.md");
+}
+
+/// Spec: docs/specs/checks/docs.md#fast-mode-orphaned-docs
+///
+/// > Links are followed transitively through any linked markdown file, and a
+/// > link to a directory reaches its `README.md` or `index.md`.
+#[test]
+fn transitively_linked_docs_pass() {
+    let temp = orphans_project();
+    check("docs").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/checks/docs.md#fast-mode-orphaned-docs
+///
+/// > - The specs index (see [Index File](#index-file))
+/// > - Agent files (`CLAUDE.md`, `AGENTS.md`, `.cursor/rules/*.mdc`, ...)
+#[test]
+fn docs_linked_from_specs_index_or_agent_files_pass() {
+    let temp = orphans_project();
+    temp.file("docs/specs/00-overview.md", "# Overview\n\n- [CLI](01-cli.md)\n");
+    temp.file("docs/specs/01-cli.md", "# CLI\n");
+    temp.file("CLAUDE.md", "# Agents\n\nRead [conventions](docs/conventions.md).\n");
+    temp.file("docs/conventions.md", "# Conventions\n");
+
+    check("docs").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/checks/docs.md#configuration-2
+///
+/// > exclude = ["docs/archive/**"]    # docs that don't need to be linked
+#[test]
+fn excluded_docs_not_reported() {
+    let temp = orphans_project();
+    temp.config("[check.docs.orphans]\ncheck = \"error\"\nexclude = [\"docs/archive/**\"]\n");
+    temp.file("docs/archive/2024.md", "# 2024\n");
+
+    check("docs").pwd(temp.path()).passes();
+}
+
+/// Spec: docs/specs/checks/docs.md#fast-mode-orphaned-docs
+///
+/// > Off by default.
+#[test]
+fn orphans_off_by_default() {
+    let temp = orphans_project();
+    temp.config("");
+    temp.file("docs/old-design.md", "# Old Design\n");
+
+    check("docs").pwd(temp.path()).passes();
+}
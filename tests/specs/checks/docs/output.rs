@@ -54,7 +54,7 @@ fn docs_text_output_on_broken_link() {
 
 /// Spec: docs/specs/checks/docs.md#json-output
 ///
-/// > Violation types: missing_section, forbidden_section, broken_toc, broken_link, unreachable_spec
+/// > Violation types: missing_section, forbidden_section, broken_toc, broken_link, broken_anchor, orphaned_doc, unreachable_spec
 #[test]
 fn docs_violation_type_is_one_of_expected_values() {
     let docs = check("docs").on("docs/toc-broken").json().fails();
@@ -65,6 +65,8 @@ fn docs_violation_type_is_one_of_expected_values() {
         "forbidden_section",
         "broken_toc",
         "broken_link",
+        "broken_anchor",
+        "orphaned_doc",
         "unreachable_spec",
         "missing_docs",
     ];
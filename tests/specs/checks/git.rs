@@ -706,3 +706,374 @@ agents = false
     // Should pass - commit has valid conventional format
     check("git").pwd(temp.path()).args(&["--ci"]).passes();
 }
+
+// =============================================================================
+// COMMIT MESSAGE HOOK SPECS
+// =============================================================================
+
+/// Project with the git check enabled and a repository, for `--commit-msg`.
+fn commit_msg_project() -> Project {
+    let temp = Project::empty();
+    temp.config(
+        r#"[git.commit]
+check = "error"
+agents = false
+scopes = ["api", "cli"]
+"#,
+    );
+    git_init(&temp);
+    temp
+}
+
+/// Spec: docs/specs/checks/git.md#commit-message-hook
+///
+/// > The subject line is then validated against the configured `types` and `scopes`
+#[test]
+fn git_commit_msg_accepts_valid_message() {
+    let temp = commit_msg_project();
+    temp.file(".git/COMMIT_EDITMSG", "feat(cli): add hooks\n\n# Please enter the commit message\n");
+
+    check("git").pwd(temp.path()).args(&["--commit-msg", ".git/COMMIT_EDITMSG"]).passes();
+}
+
+/// Spec: docs/specs/checks/git.md#commit-message-hook
+///
+/// > The subject line is then validated against the configured `types` and `scopes`
+#[test]
+fn git_commit_msg_rejects_invalid_scope() {
+    let temp = commit_msg_project();
+    temp.file(".git/COMMIT_EDITMSG", "feat(core): add hooks\n");
+
+    let git =
+        check("git").pwd(temp.path()).args(&["--commit-msg", ".git/COMMIT_EDITMSG"]).json().fails();
+    let violation = git.require_violation("invalid_scope");
+    assert_eq!(violation.get("file").and_then(|f| f.as_str()), Some(".git/COMMIT_EDITMSG"));
+    assert_eq!(violation.get("scope").and_then(|s| s.as_str()), Some("core"));
+}
+
+/// Spec: docs/specs/checks/git.md#commit-message-hook
+///
+/// > The file is cleaned the way git cleans it: `#` comment lines and everything
+/// > below the `--verbose` scissors line are removed.
+#[test]
+fn git_commit_msg_ignores_comments_and_scissors() {
+    let temp = commit_msg_project();
+    temp.file(
+        ".git/COMMIT_EDITMSG",
+        "# Please enter the commit message\nfix: typo\n\
+         # ------------------------ >8 ------------------------\nupdate stuff\n",
+    );
+
+    check("git").pwd(temp.path()).args(&["--commit-msg", ".git/COMMIT_EDITMSG"]).passes();
+}
+
+/// Spec: docs/specs/checks/git.md#commit-message-hook
+///
+/// > `fixup!`, `squash!` and `amend!` commits are skipped
+#[test]
+fn git_commit_msg_skips_fixup_commits() {
+    let temp = commit_msg_project();
+    temp.file(".git/COMMIT_EDITMSG", "fixup! update stuff\n");
+
+    check("git").pwd(temp.path()).args(&["--commit-msg", ".git/COMMIT_EDITMSG"]).passes();
+}
+
+/// Spec: docs/specs/checks/git.md#commit-message-hook
+///
+/// > It runs only the git check unless other checks are enabled explicitly.
+#[test]
+fn git_commit_msg_runs_only_git_check() {
+    let temp = commit_msg_project();
+    temp.file(".git/COMMIT_EDITMSG", "update stuff\n");
+
+    let result = cli().pwd(temp.path()).args(&["--commit-msg", ".git/COMMIT_EDITMSG"]).fails();
+    result.stdout_has("git: FAIL").stdout_has(".git/COMMIT_EDITMSG: invalid_format");
+    let json = cli().pwd(temp.path()).args(&["--commit-msg", ".git/COMMIT_EDITMSG"]).json().fails();
+    assert_eq!(check_names(json.value()), ["git"]);
+}
+
+/// Spec: docs/specs/01-cli.md#scope-flags
+///
+/// > `--commit-msg <FILE>` | Validate a commit message file (commit-msg hook)
+#[test]
+fn git_commit_msg_missing_file_is_config_error() {
+    let temp = commit_msg_project();
+
+    cli()
+        .pwd(temp.path())
+        .args(&["--commit-msg", "missing.txt"])
+        .exits(2)
+        .stderr_has("commit message file not found");
+}
+
+// =============================================================================
+// MESSAGE RULE SPECS
+// =============================================================================
+
+fn rules_project(rules: &str) -> Project {
+    let temp = Project::empty();
+    temp.config(&format!("[git.commit]\ncheck = \"error\"\nagents = false\n{rules}"));
+    git_init(&temp);
+    temp
+}
+
+/// Spec: docs/specs/checks/git.md#message-rules
+///
+/// > `subject_max = 72` | `subject_too_long` | Subject has at most 72 characters
+#[test]
+fn git_subject_over_max_generates_violation() {
+    let temp = rules_project("subject_max = 20\n");
+    temp.file(".git/COMMIT_EDITMSG", "feat: add a subject that is too long\n");
+
+    let git =
+        check("git").pwd(temp.path()).args(&["--commit-msg", ".git/COMMIT_EDITMSG"]).json().fails();
+    let violation = git.require_violation("subject_too_long");
+    assert_eq!(violation.get("value").and_then(|v| v.as_i64()), Some(36));
+    assert_eq!(violation.get("threshold").and_then(|v| v.as_i64()), Some(20));
+}
+
+/// Spec: docs/specs/checks/git.md#message-rules
+///
+/// > Required trailers map a key to a regex its value must match
+#[test]
+fn git_required_trailer_must_match_pattern() {
+    let temp = rules_project("\n[git.commit.trailers]\nRefs = \"^TICKET-\\\\d+$\"\n");
+    temp.file(".git/COMMIT_EDITMSG", "fix: typo\n\nRefs: ISSUE-1\n");
+    check("git")
+        .pwd(temp.path())
+        .args(&["--commit-msg", ".git/COMMIT_EDITMSG"])
+        .json()
+        .fails()
+        .require_violation("missing_trailer");
+
+    temp.file(".git/COMMIT_EDITMSG", "fix: typo\n\nRefs: TICKET-123\n");
+    check("git").pwd(temp.path()).args(&["--commit-msg", ".git/COMMIT_EDITMSG"]).passes();
+}
+
+/// Spec: docs/specs/checks/git.md#message-rules
+///
+/// > a `Signed-off-by` trailer (added by `git commit -s`) must have the commit
+/// > author's email.
+#[test]
+fn git_signoff_must_match_commit_author() {
+    let temp = rules_project("signoff = true\n");
+    git_initial_commit(&temp);
+    git_branch(&temp, "feature");
+    git_commit(&temp, "feat: unsigned\n\nSigned-off-by: Other <other@example.com>");
+
+    let git = check("git").pwd(temp.path()).args(&["--base", "main"]).json().fails();
+    git.require_violation("missing_signoff");
+}
+
+/// Spec: docs/specs/checks/git.md#message-rules
+///
+/// > With `--commit-msg`, the author comes from `GIT_AUTHOR_EMAIL` or the git
+/// > `user.email` config.
+#[test]
+fn git_commit_msg_signoff_uses_configured_author() {
+    let temp = rules_project("signoff = true\n");
+    temp.file(".git/COMMIT_EDITMSG", "fix: typo\n\nSigned-off-by: Test User <test@example.com>\n");
+
+    check("git").pwd(temp.path()).args(&["--commit-msg", ".git/COMMIT_EDITMSG"]).passes();
+}
+
+/// Spec: docs/specs/checks/git.md#message-rules
+///
+/// > `breaking_footer = true` | `missing_breaking_change` | `type!:` commits have a `BREAKING CHANGE:` footer
+#[test]
+fn git_breaking_change_requires_footer() {
+    let temp = rules_project("breaking_footer = true\n");
+    temp.file(".git/COMMIT_EDITMSG", "feat(api)!: drop v1\n");
+    check("git")
+        .pwd(temp.path())
+        .args(&["--commit-msg", ".git/COMMIT_EDITMSG"])
+        .json()
+        .fails()
+        .require_violation("missing_breaking_change");
+
+    temp.file(
+        ".git/COMMIT_EDITMSG",
+        "feat(api)!: drop v1\n\nBREAKING CHANGE: v1 endpoints are removed.\n",
+    );
+    check("git").pwd(temp.path()).args(&["--commit-msg", ".git/COMMIT_EDITMSG"]).passes();
+}
+
+/// Spec: docs/specs/checks/git.md#message-rules
+///
+/// > An invalid pattern skips the check with an error.
+#[test]
+fn git_invalid_trailer_pattern_skips_check() {
+    let temp = rules_project("\n[git.commit.trailers]\nRefs = \"(\"\n");
+    temp.file(".git/COMMIT_EDITMSG", "fix: typo\n");
+
+    let git = check("git")
+        .pwd(temp.path())
+        .args(&["--commit-msg", ".git/COMMIT_EDITMSG"])
+        .json()
+        .passes();
+    assert_eq!(git.check()["skipped"], true);
+    assert!(git.check()["error"].as_str().unwrap().contains("Invalid trailer pattern"));
+}
+
+// =============================================================================
+// BRANCH RULE SPECS
+// =============================================================================
+
+/// Project on a `name` branch off `main`, with the given `[git.branch]` rules.
+fn branch_project(name: &str, rules: &str) -> Project {
+    let temp = Project::empty();
+    temp.config(&format!(
+        "[git.commit]\ncheck = \"error\"\nagents = false\n\n[git.branch]\n{rules}"
+    ));
+    git_init(&temp);
+    git_initial_commit(&temp);
+    git_branch(&temp, name);
+    temp
+}
+
+/// Spec: docs/specs/checks/git.md#naming
+///
+/// > The current branch must match `pattern`, e.g. `feat/PROJ-123-short-desc`.
+#[test]
+fn git_branch_name_must_match_pattern() {
+    let rules = "pattern = \"^(feat|fix)/PROJ-\\\\d+-[a-z0-9-]+$\"\n";
+    let temp = branch_project("my-branch", rules);
+    git_commit(&temp, "feat: add thing");
+
+    let git = check("git")
+        .pwd(temp.path())
+        .args(&["--base", "main"])
+        .env("GITHUB_HEAD_REF", "")
+        .json()
+        .fails();
+    let violation = git.require_violation("invalid_branch_name");
+    assert_eq!(violation.get("found").and_then(|f| f.as_str()), Some("my-branch"));
+
+    let temp = branch_project("feat/PROJ-123-short-desc", rules);
+    git_commit(&temp, "feat: add thing");
+    check("git").pwd(temp.path()).args(&["--base", "main"]).env("GITHUB_HEAD_REF", "").passes();
+}
+
+/// Spec: docs/specs/checks/git.md#naming
+///
+/// > In CI, the source branch of the pull request is used (`GITHUB_HEAD_REF`
+/// > or `CI_MERGE_REQUEST_SOURCE_BRANCH_NAME`)
+#[test]
+fn git_branch_name_uses_ci_source_branch() {
+    let temp = branch_project("feat/local", "pattern = \"^feat/\"\n");
+    git_commit(&temp, "feat: add thing");
+
+    check("git")
+        .pwd(temp.path())
+        .args(&["--base", "main"])
+        .env("GITHUB_HEAD_REF", "bugfix/other")
+        .json()
+        .fails()
+        .require_violation("invalid_branch_name");
+}
+
+/// Spec: docs/specs/checks/git.md#naming
+///
+/// > The base branch itself is exempt.
+#[test]
+fn git_branch_name_skips_base_branch() {
+    let temp = branch_project("feature", "pattern = \"^feat/\"\n");
+    git_checkout(&temp, "main");
+
+    check("git").pwd(temp.path()).args(&["--base", "main"]).env("GITHUB_HEAD_REF", "").passes();
+}
+
+/// Spec: docs/specs/checks/git.md#scope-limits
+///
+/// > | `too_many_scopes` | Distinct conventional commit scopes (`feat(api):` → `api`) |
+#[test]
+fn git_branch_with_too_many_scopes_fails() {
+    let temp = branch_project("feature", "max_scopes = 2\n");
+    git_commit(&temp, "feat(api): add export");
+    git_commit(&temp, "fix(api): handle empty input");
+    git_commit(&temp, "feat(cli): add flag");
+    check("git").pwd(temp.path()).args(&["--base", "main"]).passes();
+
+    git_commit(&temp, "docs(guide): document flag");
+    let git = check("git").pwd(temp.path()).args(&["--base", "main"]).json().fails();
+    let violation = git.require_violation("too_many_scopes");
+    assert_eq!(violation.get("value").and_then(|v| v.as_i64()), Some(3));
+    assert_eq!(violation.get("threshold").and_then(|v| v.as_i64()), Some(2));
+}
+
+/// Spec: docs/specs/checks/git.md#scope-limits
+///
+/// > Areas are the `[check.docs.area]` entries with a `source` pattern when
+/// > any are configured, otherwise the `[project] packages`.
+#[test]
+fn git_branch_with_too_many_areas_fails() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[project]
+packages = ["crates/api", "crates/cli"]
+
+[git.commit]
+check = "error"
+agents = false
+
+[git.branch]
+max_scopes = 1
+"#,
+    );
+    git_init(&temp);
+    git_initial_commit(&temp);
+    git_branch(&temp, "feature");
+    temp.file("crates/api/src/lib.rs", "pub fn api() {}\n");
+    temp.file("crates/cli/src/main.rs", "fn main() {}\n");
+    git_commit(&temp, "feat: add api and cli");
+
+    let git = check("git").pwd(temp.path()).args(&["--base", "main"]).json().fails();
+    let violation = git.require_violation("too_many_areas");
+    let advice = violation.get("advice").and_then(|a| a.as_str()).unwrap();
+    assert!(advice.contains("crates/api, crates/cli"));
+}
+
+/// Spec: docs/specs/checks/git.md#scope-limits
+///
+/// > An invalid area `source` pattern skips the check with an error.
+#[test]
+fn git_invalid_area_source_pattern_skips_check() {
+    let rules =
+        "max_scopes = 1\n\n[check.docs.area.api]\ndocs = \"docs/api/**\"\nsource = \"src/{api\"\n";
+    let temp = branch_project("feature", rules);
+    git_commit(&temp, "feat: add thing");
+
+    let git = check("git").pwd(temp.path()).args(&["--base", "main"]).json().passes();
+    assert_eq!(git.check()["skipped"], true);
+    assert!(git.check()["error"].as_str().unwrap().contains("Invalid area source pattern"));
+}
+
+/// Spec: docs/specs/checks/git.md#branch-rules
+///
+/// > They run with the git check, even when `format = "none"`.
+#[test]
+fn git_branch_rules_apply_without_commit_format() {
+    let temp = Project::empty();
+    temp.config(
+        r#"[git.commit]
+check = "error"
+format = "none"
+
+[git.branch]
+pattern = "^feat/"
+"#,
+    );
+    git_init(&temp);
+    git_initial_commit(&temp);
+    git_branch(&temp, "my-branch");
+    git_commit(&temp, "update stuff");
+
+    let git = check("git")
+        .pwd(temp.path())
+        .args(&["--base", "main"])
+        .env("GITHUB_HEAD_REF", "")
+        .json()
+        .fails();
+    git.require_violation("invalid_branch_name");
+    assert!(git.violations().iter().all(|v| v["type"] != "invalid_format"));
+}
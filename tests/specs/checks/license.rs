@@ -7,6 +7,8 @@
 //! - Detects SPDX-License-Identifier headers
 //! - Reports missing, wrong, and outdated headers
 //! - Preserves shebangs when fixing
+//! - Renders templates and block comment styles
+//! - Applies copyright year policies from git history
 //!
 //! Reference: docs/specs/checks/license-headers.md
 
@@ -454,3 +456,305 @@ rust = ["**/*.rs"]
     // Should pass - LICENSE and README.md are silently skipped when they don't have copyright lines
     check("license").pwd(temp.path()).args(&["--ci"]).passes();
 }
+
+// =============================================================================
+// TEMPLATE SPECS
+// =============================================================================
+
+/// Spec: docs/specs/checks/license-headers.md#comment-syntax-by-language
+///
+/// > `.css`, `.scss`, `.less`, `.sql` | `/* ... */` with ` * ` line prefix
+#[test]
+fn license_fix_adds_block_comment_header_to_css() {
+    let temp = Project::empty();
+    temp.config(
+        r#"
+[check.license]
+check = "error"
+license = "MIT"
+copyright = "Test Org"
+
+[check.license.patterns]
+css = ["**/*.css"]
+"#,
+    );
+    temp.file("styles/main.css", "body { margin: 0; }\n");
+
+    check("license").pwd(temp.path()).args(&["--ci", "--fix"]).passes();
+
+    let content = std::fs::read_to_string(temp.path().join("styles/main.css")).unwrap();
+    assert!(
+        content.starts_with(
+            "/*\n * SPDX-License-Identifier: MIT\n * Copyright (c) 2026 Test Org\n */\n"
+        ),
+        "should add block comment header: {}",
+        content
+    );
+    check("license").pwd(temp.path()).args(&["--ci"]).passes();
+}
+
+/// Spec: docs/specs/checks/license-headers.md#header-format
+///
+/// > Shebangs, `<?xml` declarations and `<!DOCTYPE` lines are preserved at the top of the file.
+#[test]
+fn license_fix_preserves_xml_declaration() {
+    let temp = Project::empty();
+    temp.config(
+        r#"
+[check.license]
+check = "error"
+license = "MIT"
+copyright = "Test Org"
+
+[check.license.patterns]
+xml = ["**/*.xml"]
+"#,
+    );
+    temp.file("config.xml", "<?xml version=\"1.0\"?>\n<root/>\n");
+
+    check("license").pwd(temp.path()).args(&["--ci", "--fix"]).passes();
+
+    let content = std::fs::read_to_string(temp.path().join("config.xml")).unwrap();
+    assert!(content.starts_with("<?xml version=\"1.0\"?>\n<!--\n"), "{}", content);
+    check("license").pwd(temp.path()).args(&["--ci"]).passes();
+}
+
+/// Spec: docs/specs/checks/license-headers.md#templates
+///
+/// > `"apache-2.0"` | SPDX identifier, copyright line, and the Apache-2.0 boilerplate notice
+#[test]
+fn license_fix_adds_apache_boilerplate() {
+    let temp = Project::empty();
+    temp.config(
+        r#"
+[check.license]
+check = "error"
+license = "Apache-2.0"
+copyright = "Test Org"
+template = "apache-2.0"
+
+[check.license.patterns]
+rust = ["**/*.rs"]
+"#,
+    );
+    temp.file("src/lib.rs", "pub fn hello() {}\n");
+
+    check("license").pwd(temp.path()).args(&["--ci", "--fix"]).passes();
+
+    let content = std::fs::read_to_string(temp.path().join("src/lib.rs")).unwrap();
+    assert!(content.contains("// Licensed under the Apache License, Version 2.0"));
+    assert!(content.contains("//     http://www.apache.org/licenses/LICENSE-2.0\n"));
+    check("license").pwd(temp.path()).args(&["--ci"]).passes();
+}
+
+/// Spec: docs/specs/checks/license-headers.md#templates
+///
+/// > When `template` is set explicitly, a header that does not match it is reported
+/// > as `header_mismatch`.
+#[test]
+fn license_custom_template_mismatch_generates_violation() {
+    let temp = Project::empty();
+    temp.config(
+        r#"
+[check.license]
+check = "error"
+license = "MIT"
+copyright = "Test Org"
+template = "Copyright {year} {holder}. Licensed under {license}."
+
+[check.license.patterns]
+rust = ["**/*.rs"]
+"#,
+    );
+    temp.file("src/good.rs", "// Copyright 2026 Test Org. Licensed under MIT.\n\npub fn a() {}\n");
+    temp.file(
+        "src/old.rs",
+        "// SPDX-License-Identifier: MIT\n// Copyright (c) 2026 Test Org\n\npub fn b() {}\n",
+    );
+
+    let license = check("license").pwd(temp.path()).args(&["--ci"]).json().fails();
+    let violations = license.violations();
+    assert_eq!(violations.len(), 1, "{:?}", violations);
+    let violation = license.require_violation("header_mismatch");
+    assert_eq!(violation.get("file").and_then(|v| v.as_str()), Some("src/old.rs"));
+}
+
+/// Spec: docs/specs/checks/license-headers.md#auto-fix
+///
+/// > Normalize headers: Replace a header that doesn't match the configured `template`
+#[test]
+fn license_fix_normalizes_header_to_template() {
+    let temp = Project::empty();
+    temp.config(
+        r#"
+[check.license]
+check = "error"
+license = "MIT"
+copyright = "Test Org"
+template = "Copyright {year} {holder}. Licensed under {license}."
+
+[check.license.patterns]
+rust = ["**/*.rs"]
+"#,
+    );
+    temp.file(
+        "src/lib.rs",
+        "// SPDX-License-Identifier: MIT\n// Copyright (c) 2020 Test Org\n\npub fn hello() {}\n",
+    );
+
+    check("license").pwd(temp.path()).args(&["--ci", "--fix"]).passes();
+
+    let content = std::fs::read_to_string(temp.path().join("src/lib.rs")).unwrap();
+    assert_eq!(
+        content,
+        "// Copyright 2020-2026 Test Org. Licensed under MIT.\n\npub fn hello() {}\n"
+    );
+}
+
+// =============================================================================
+// YEAR POLICY SPECS
+// =============================================================================
+
+/// Commit all files with a fixed author and committer date.
+fn git_commit_dated(project: &Project, date: &str) {
+    for args in [vec!["add", "."], vec!["commit", "-m", "feat: add files"]] {
+        std::process::Command::new("git")
+            .args(&args)
+            .env("GIT_AUTHOR_DATE", date)
+            .env("GIT_COMMITTER_DATE", date)
+            .current_dir(project.path())
+            .output()
+            .expect("git should succeed");
+    }
+}
+
+fn year_policy_project(policy: &str) -> Project {
+    let temp = Project::empty();
+    temp.config(&format!(
+        r#"
+[check.license]
+check = "error"
+license = "MIT"
+copyright = "Test Org"
+year = "{policy}"
+
+[check.license.patterns]
+rust = ["**/*.rs"]
+"#
+    ));
+    git_init(&temp);
+    temp.file(
+        "src/lib.rs",
+        "// SPDX-License-Identifier: MIT\n// Copyright (c) 2026 Test Org\n\npub fn hello() {}\n",
+    );
+    git_commit_dated(&temp, "2020-06-01T12:00:00Z");
+    temp
+}
+
+/// Spec: docs/specs/checks/license-headers.md#year-policies
+///
+/// > `"first-commit"` | Year the file was first committed | `2020`
+#[test]
+fn license_first_commit_policy_requires_first_commit_year() {
+    let temp = year_policy_project("first-commit");
+
+    let license = check("license").pwd(temp.path()).args(&["--ci"]).json().fails();
+    let violation = license.require_violation("wrong_year");
+    assert_eq!(violation.get("expected").and_then(|v| v.as_str()), Some("2020"));
+    assert_eq!(violation.get("found").and_then(|v| v.as_str()), Some("2026"));
+
+    check("license").pwd(temp.path()).args(&["--ci", "--fix"]).passes();
+    let content = std::fs::read_to_string(temp.path().join("src/lib.rs")).unwrap();
+    assert!(content.contains("// Copyright (c) 2020 Test Org\n"), "{}", content);
+}
+
+/// Spec: docs/specs/checks/license-headers.md#year-policies
+///
+/// > `"range"` | First-commit year through the current year | `2020-2026`
+#[test]
+fn license_range_policy_requires_first_through_current_year() {
+    let temp = year_policy_project("range");
+
+    let license = check("license").pwd(temp.path()).args(&["--ci"]).json().fails();
+    let violation = license.require_violation("wrong_year");
+    assert_eq!(violation.get("expected").and_then(|v| v.as_str()), Some("2020-2026"));
+
+    check("license").pwd(temp.path()).args(&["--ci", "--fix"]).passes();
+    let content = std::fs::read_to_string(temp.path().join("src/lib.rs")).unwrap();
+    assert!(content.contains("// Copyright (c) 2020-2026 Test Org\n"), "{}", content);
+}
+
+/// Spec: docs/specs/checks/license-headers.md#year-policies
+///
+/// > Files not yet committed use the current year.
+#[test]
+fn license_range_policy_uses_current_year_for_uncommitted_files() {
+    let temp = year_policy_project("range");
+    temp.file(
+        "src/lib.rs",
+        "// SPDX-License-Identifier: MIT\n// Copyright (c) 2020-2026 Test Org\n\npub fn hello() {}\n",
+    );
+    temp.file(
+        "src/new.rs",
+        "// SPDX-License-Identifier: MIT\n// Copyright (c) 2026 Test Org\n\npub fn new() {}\n",
+    );
+
+    check("license").pwd(temp.path()).args(&["--ci"]).passes();
+}
+
+/// Spec: docs/specs/checks/license-headers.md#year-policies
+///
+/// > These policies need the full history: outside a git repository, or in a
+/// > shallow clone (CI checkouts often fetch a single commit), the check is
+/// > skipped with an error.
+#[test]
+fn license_first_commit_policy_skips_shallow_clone() {
+    let origin = year_policy_project("first-commit");
+    let temp = Project::empty();
+    let clone = temp.path().join("clone");
+    let url = format!("file://{}", origin.path().display());
+    std::process::Command::new("git")
+        .args(["clone", "--depth", "1", &url])
+        .arg(&clone)
+        .output()
+        .expect("git clone should succeed");
+
+    let license = check("license").pwd(&clone).args(&["--ci"]).json().passes();
+    assert_eq!(license.check()["skipped"], true);
+    let error = license.check()["error"].as_str().unwrap();
+    assert!(error.contains("shallow clone"), "{}", error);
+    assert!(error.contains("fetch-depth: 0"), "{}", error);
+}
+
+/// Spec: docs/specs/checks/license-headers.md#year-policies
+///
+/// > These policies need the full history: outside a git repository, or in a
+/// > shallow clone (CI checkouts often fetch a single commit), the check is
+/// > skipped with an error.
+#[test]
+fn license_range_policy_skips_outside_git_repository() {
+    let temp = Project::empty();
+    temp.config(
+        r#"
+[check.license]
+check = "error"
+license = "MIT"
+copyright = "Test Org"
+year = "range"
+
+[check.license.patterns]
+rust = ["**/*.rs"]
+"#,
+    );
+    temp.file(
+        "src/lib.rs",
+        "// SPDX-License-Identifier: MIT\n// Copyright (c) 2020-2026 Test Org\n\npub fn hello() {}\n",
+    );
+
+    let license = check("license").pwd(temp.path()).args(&["--ci"]).json().passes();
+    assert_eq!(license.check()["skipped"], true);
+
+    check("license").pwd(temp.path()).args(&["--ci", "--fix"]).passes();
+    let content = std::fs::read_to_string(temp.path().join("src/lib.rs")).unwrap();
+    assert!(content.contains("// Copyright (c) 2020-2026 Test Org\n"), "{}", content);
+}
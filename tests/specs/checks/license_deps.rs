@@ -0,0 +1,280 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for dependency license compliance.
+//!
+//! Tests that quench correctly:
+//! - Reads licenses from lockfiles and local package caches (offline)
+//! - Evaluates SPDX expressions against allow/deny lists
+//! - Reports disallowed and unknown licenses with the dependency path
+//!
+//! Reference: docs/specs/checks/license-headers.md#dependency-licenses
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+const CARGO_LOCK: &str = r#"
+version = 3
+
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = ["dual", "wrapper"]
+
+[[package]]
+name = "dual"
+version = "1.0.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "wrapper"
+version = "2.0.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+dependencies = ["copyleft"]
+
+[[package]]
+name = "copyleft"
+version = "3.0.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+"#;
+
+/// Write an unpacked crate into a fake `$CARGO_HOME` registry.
+fn registry_crate(cargo_home: &std::path::Path, name: &str, version: &str, license: &str) {
+    let dir = cargo_home.join(format!("registry/src/index.crates.io-0000/{name}-{version}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(
+        dir.join("Cargo.toml"),
+        format!("[package]\nname = \"{name}\"\nversion = \"{version}\"\nlicense = \"{license}\"\n"),
+    )
+    .unwrap();
+}
+
+fn cargo_project(deps_config: &str) -> (Project, tempfile::TempDir) {
+    let temp = Project::empty();
+    temp.config(&format!("[check.license.deps]\n{deps_config}"));
+    temp.file("Cargo.lock", CARGO_LOCK);
+
+    let cargo_home = tempfile::tempdir().unwrap();
+    registry_crate(cargo_home.path(), "dual", "1.0.0", "MIT OR GPL-3.0-only");
+    registry_crate(cargo_home.path(), "wrapper", "2.0.0", "Apache-2.0");
+    registry_crate(cargo_home.path(), "copyleft", "3.0.0", "GPL-3.0-only");
+    (temp, cargo_home)
+}
+
+// =============================================================================
+// EVALUATION SPECS
+// =============================================================================
+
+/// Spec: docs/specs/checks/license-headers.md#violations
+///
+/// > `disallowed_dependency_license` | The license expression fails the allow/deny lists
+#[test]
+fn deps_disallowed_license_reports_dependency_path() {
+    let (temp, cargo_home) = cargo_project("allow = [\"MIT\", \"Apache-2.0\"]\n");
+
+    let license = check("license")
+        .pwd(temp.path())
+        .args(&["--ci"])
+        .env("CARGO_HOME", cargo_home.path().to_str().unwrap())
+        .json()
+        .fails();
+
+    let violations = license.violations_of_type("disallowed_dependency_license");
+    assert_eq!(violations.len(), 1, "MIT OR GPL passes through its MIT branch");
+    let violation = violations[0];
+    assert_eq!(violation.get("file").and_then(|v| v.as_str()), Some("Cargo.lock"));
+    assert_eq!(
+        violation.get("path").and_then(|v| v.as_str()),
+        Some("app > wrapper > copyleft@3.0.0")
+    );
+    assert_eq!(violation.get("found").and_then(|v| v.as_str()), Some("GPL-3.0-only"));
+}
+
+/// Spec: docs/specs/checks/license-headers.md#evaluation
+///
+/// > `deny` wins over `allow`. Entries match case-insensitively; a trailing `*` matches any suffix.
+#[test]
+fn deps_deny_list_with_wildcard() {
+    let (temp, cargo_home) = cargo_project("deny = [\"gpl-*\"]\n");
+
+    let license = check("license")
+        .pwd(temp.path())
+        .args(&["--ci"])
+        .env("CARGO_HOME", cargo_home.path().to_str().unwrap())
+        .json()
+        .fails();
+
+    assert_eq!(license.violations_of_type("disallowed_dependency_license").len(), 1);
+    let metrics = license.require("metrics");
+    assert_eq!(metrics["dependencies"]["dependencies_checked"], 3);
+    assert_eq!(metrics["dependencies"]["dependencies_disallowed"], 1);
+}
+
+/// Spec: docs/specs/checks/license-headers.md#dependency-licenses
+///
+/// > ignore = ["internal-crate", "left-pad@1.3.0"]   # "name" or "name@version"
+#[test]
+fn deps_ignore_skips_dependency() {
+    let (temp, cargo_home) = cargo_project("deny = [\"GPL-*\"]\nignore = [\"copyleft@3.0.0\"]\n");
+
+    check("license")
+        .pwd(temp.path())
+        .args(&["--ci"])
+        .env("CARGO_HOME", cargo_home.path().to_str().unwrap())
+        .passes();
+}
+
+/// Spec: docs/specs/checks/license-headers.md#violations
+///
+/// > `unknown_license` | No license metadata was found locally
+#[test]
+fn deps_missing_metadata_is_unknown_license() {
+    let (temp, _cargo_home) = cargo_project("deny = [\"GPL-*\"]\n");
+    let empty_home = tempfile::tempdir().unwrap();
+
+    let license = check("license")
+        .pwd(temp.path())
+        .args(&["--ci"])
+        .env("CARGO_HOME", empty_home.path().to_str().unwrap())
+        .json()
+        .fails();
+
+    assert_eq!(license.violations_of_type("unknown_license").len(), 3);
+}
+
+/// Spec: docs/specs/checks/license-headers.md#dependency-licenses
+///
+/// > The sub-check is enabled when `allow` or `deny` is set, or with an explicit `check = "error"`.
+#[test]
+fn deps_disabled_without_lists() {
+    let temp = Project::empty();
+    temp.config("[check.license]\ncheck = \"error\"\n");
+    temp.file("Cargo.lock", CARGO_LOCK);
+
+    check("license").pwd(temp.path()).args(&["--ci"]).passes();
+}
+
+/// Spec: docs/specs/checks/license-headers.md#dependency-licenses
+///
+/// > It runs in CI mode alongside header checks
+#[test]
+fn deps_skipped_outside_ci_mode() {
+    let (temp, cargo_home) = cargo_project("deny = [\"GPL-*\"]\n");
+
+    check("license")
+        .pwd(temp.path())
+        .env("CARGO_HOME", cargo_home.path().to_str().unwrap())
+        .passes();
+}
+
+// =============================================================================
+// ECOSYSTEM SPECS
+// =============================================================================
+
+/// Spec: docs/specs/checks/license-headers.md#sources
+///
+/// > `package-lock.json` | `license` in the lockfile, then `node_modules/<path>/package.json`
+#[test]
+fn deps_package_lock_reports_nested_install_path() {
+    let temp = Project::empty();
+    temp.config("[check.license.deps]\ndeny = [\"AGPL-*\"]\n");
+    temp.file(
+        "package-lock.json",
+        r#"{
+  "lockfileVersion": 3,
+  "packages": {
+    "": { "name": "app" },
+    "node_modules/left": { "version": "1.0.0", "license": "MIT" },
+    "node_modules/left/node_modules/right": { "version": "2.0.0" }
+  }
+}"#,
+    );
+    temp.file(
+        "node_modules/left/node_modules/right/package.json",
+        r#"{ "name": "right", "license": "AGPL-3.0-only" }"#,
+    );
+
+    let license = check("license").pwd(temp.path()).args(&["--ci"]).json().fails();
+    let violation = license.require_violation("disallowed_dependency_license");
+    assert_eq!(violation.get("file").and_then(|v| v.as_str()), Some("package-lock.json"));
+    assert_eq!(violation.get("path").and_then(|v| v.as_str()), Some("left > right@2.0.0"));
+}
+
+/// Spec: docs/specs/checks/license-headers.md#sources
+///
+/// > `go.sum` | License files in `$GOMODCACHE/<module>@<version>`
+#[test]
+fn deps_go_module_license_classified_from_text() {
+    let temp = Project::empty();
+    temp.config("[check.license.deps]\nallow = [\"MIT\"]\n");
+    temp.file(
+        "go.sum",
+        "github.com/Example/lib v1.0.0 h1:abc=\ngithub.com/Example/lib v1.0.0/go.mod h1:def=\n",
+    );
+    let mod_cache = tempfile::tempdir().unwrap();
+    let module_dir = mod_cache.path().join("github.com/!example/lib@v1.0.0");
+    std::fs::create_dir_all(&module_dir).unwrap();
+    std::fs::write(
+        module_dir.join("LICENSE"),
+        "Apache License\nVersion 2.0, January 2004\nhttp://www.apache.org/licenses/\n",
+    )
+    .unwrap();
+
+    let license = check("license")
+        .pwd(temp.path())
+        .args(&["--ci"])
+        .env("GOMODCACHE", mod_cache.path().to_str().unwrap())
+        .json()
+        .fails();
+    let violation = license.require_violation("disallowed_dependency_license");
+    assert_eq!(
+        violation.get("path").and_then(|v| v.as_str()),
+        Some("github.com/Example/lib@v1.0.0")
+    );
+    assert_eq!(violation.get("found").and_then(|v| v.as_str()), Some("Apache-2.0"));
+}
+
+/// Spec: docs/specs/checks/license-headers.md#sources
+///
+/// > `*.dist-info/METADATA`: `License-Expression`, `License`, or license classifiers
+#[test]
+fn deps_python_dist_info_metadata() {
+    let temp = Project::empty();
+    temp.config("[check.license.deps]\ndeny = [\"GPL-*\"]\n");
+    temp.file(
+        ".venv/lib/python3.12/site-packages/ok-1.0.dist-info/METADATA",
+        "Metadata-Version: 2.4\nName: ok\nVersion: 1.0\nLicense-Expression: MIT\n",
+    );
+    temp.file(
+        ".venv/lib/python3.12/site-packages/gpl_pkg-2.0.dist-info/METADATA",
+        "Metadata-Version: 2.1\nName: gpl-pkg\nVersion: 2.0\n\
+         Classifier: License :: OSI Approved :: GNU General Public License v3 (GPLv3)\n",
+    );
+
+    let license = check("license").pwd(temp.path()).args(&["--ci"]).json().fails();
+    let violations = license.violations_of_type("disallowed_dependency_license");
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].get("path").and_then(|v| v.as_str()), Some("gpl-pkg@2.0"));
+}
+
+// =============================================================================
+// OUTPUT SPECS
+// =============================================================================
+
+/// Spec: docs/specs/checks/license-headers.md#violations
+///
+/// > Cargo.lock: disallowed dependency license: app > serde_yaml > unsafe-libyaml@0.2.11 (GPL-3.0-only)
+#[test]
+fn deps_text_output_shows_path_and_license() {
+    let (temp, cargo_home) = cargo_project("deny = [\"GPL-*\"]\n");
+
+    check("license")
+        .pwd(temp.path())
+        .args(&["--ci"])
+        .env("CARGO_HOME", cargo_home.path().to_str().unwrap())
+        .fails()
+        .stdout_has(
+            "Cargo.lock: disallowed dependency license: app > wrapper > copyleft@3.0.0 (GPL-3.0-only)",
+        );
+}
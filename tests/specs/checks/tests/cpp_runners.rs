@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the CTest runner.
+//!
+//! Reference: docs/specs/11-test-runners.md#ctest
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+/// Stand-in for ctest: writes a JUnit report (one failure) and an llvm-cov
+/// lcov export into the build directory.
+const FAKE_CTEST: &str = r#"#!/bin/sh
+[ "$1" = "--version" ] && { echo "ctest version 3.28.0"; exit 0; }
+while [ $# -gt 0 ]; do
+  case "$1" in
+    --test-dir) dir="$2"; shift ;;
+    --output-junit) out="$2"; shift ;;
+  esac
+  shift
+done
+cat > "$out" <<XML
+<testsuite name="Linux-c++" tests="2" failures="1">
+<testcase name="parser_test" classname="parser_test" time="0.01" status="run"/>
+<testcase name="lexer_test" classname="lexer_test" time="0.02" status="fail"><failure message="Failed"/></testcase>
+</testsuite>
+XML
+root="$(pwd -P)"
+cat > "$dir/coverage.lcov" <<LCOV
+SF:$root/src/engine.c
+DA:1,1
+DA:2,1
+DA:3,1
+DA:4,0
+end_of_record
+SF:/usr/include/stdio.h
+DA:1,0
+end_of_record
+LCOV
+exit 8
+"#;
+
+/// CMake project with a configured build tree and a fake `ctest` on PATH;
+/// returns the PATH to use.
+fn ctest_project(config: &str) -> (Project, String) {
+    let temp = Project::empty();
+    temp.config(config);
+    temp.file("CMakeLists.txt", "project(engine C)\nenable_testing()\n");
+    temp.file("src/engine.c", "int engine(void) { return 0; }\n");
+    temp.file("build/CTestTestfile.cmake", "add_test(parser_test parser_test)\n");
+    temp.file("bin/ctest", FAKE_CTEST);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let ctest = temp.path().join("bin/ctest");
+        std::fs::set_permissions(&ctest, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+    let path = format!(
+        "{}:{}",
+        temp.path().join("bin").display(),
+        std::env::var("PATH").unwrap_or_default()
+    );
+    (temp, path)
+}
+
+/// Spec: docs/specs/11-test-runners.md#ctest
+///
+/// > Runs the tests of an already-configured CMake build directory (`path`,
+/// > default `build`) and parses the JUnit report
+#[test]
+#[cfg(unix)]
+fn ctest_parses_junit_report() {
+    let (temp, path) = ctest_project("[[check.tests.suite]]\nrunner = \"ctest\"\n");
+
+    let result = check("tests").pwd(temp.path()).env("PATH", &path).json().fails();
+    let suites = result.require("metrics").get("suites").and_then(|v| v.as_array()).unwrap();
+
+    assert_eq!(suites[0]["runner"], "ctest");
+    assert_eq!(suites[0]["test_count"], 2);
+    assert_eq!(suites[0]["passed"], false);
+}
+
+/// Spec: docs/specs/11-test-runners.md#ctest
+///
+/// > an lcov file (`*.lcov`, `lcov.info`) written to the build directory
+/// > during the run is read
+#[test]
+#[cfg(unix)]
+fn ctest_collects_lcov_export_in_ci() {
+    let (temp, path) = ctest_project("[[check.tests.suite]]\nrunner = \"ctest\"\n");
+
+    let result = check("tests").pwd(temp.path()).args(&["--ci"]).env("PATH", &path).json().fails();
+    let coverage = result.require("metrics").get("coverage").and_then(|v| v.as_object()).unwrap();
+
+    assert_eq!(coverage.get("cpp").and_then(|v| v.as_f64()), Some(75.0));
+}
+
+/// Spec: docs/specs/11-test-runners.md#ctest
+///
+/// > The suite fails early when the directory has no `CTestTestfile.cmake`.
+#[test]
+#[cfg(unix)]
+fn ctest_requires_configured_build_dir() {
+    let (temp, path) =
+        ctest_project("[[check.tests.suite]]\nrunner = \"ctest\"\npath = \"cmake-build-debug\"\n");
+
+    check("tests")
+        .pwd(temp.path())
+        .env("PATH", &path)
+        .fails()
+        .stdout_has("no CTest build tree in cmake-build-debug/");
+}
@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the `dotnet test` runner.
+//!
+//! Reference: docs/specs/11-test-runners.md#dotnet
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+/// Stand-in for dotnet: writes a TRX report (one failure) and, when coverage
+/// is requested, a coverlet Cobertura report into the results directory.
+const FAKE_DOTNET: &str = r#"#!/bin/sh
+[ "$1" = "--version" ] && { echo "8.0.100"; exit 0; }
+while [ $# -gt 0 ]; do
+  case "$1" in
+    --results-directory) dir="$2"; shift ;;
+    --collect) coverage=1; shift ;;
+  esac
+  shift
+done
+mkdir -p "$dir/3f2a"
+cat > "$dir/runner_host_2026-01-01.trx" <<XML
+<TestRun><Results>
+<UnitTestResult testName="Acme.Tests.EngineTests.Runs" duration="00:00:00.0100000" outcome="Passed" />
+<UnitTestResult testName="Acme.Tests.EngineTests.Stops" duration="00:00:00.0200000" outcome="Failed" />
+<UnitTestResult testName="Acme.Tests.EngineTests.Later" outcome="NotExecuted" />
+</Results></TestRun>
+XML
+if [ -n "$coverage" ]; then
+cat > "$dir/3f2a/coverage.cobertura.xml" <<XML
+<coverage line-rate="0.75" version="1.9">
+<packages><package name="Acme.Core" line-rate="0.75"><classes>
+<class name="Acme.Core.Engine" filename="src/Acme.Core/Engine.cs" line-rate="0.75"/>
+</classes></package></packages>
+</coverage>
+XML
+fi
+echo "Failed!  - Failed: 1, Passed: 1, Skipped: 1, Total: 3"
+exit 1
+"#;
+
+/// Solution with a fake `dotnet` on PATH; returns the PATH to use.
+fn dotnet_project(config: &str) -> (Project, String) {
+    let temp = Project::empty();
+    temp.config(config);
+    temp.file("Acme.sln", "Microsoft Visual Studio Solution File, Format Version 12.00\n");
+    temp.file("src/Acme.Core/Engine.cs", "namespace Acme.Core;\n");
+    temp.file("bin/dotnet", FAKE_DOTNET);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let dotnet = temp.path().join("bin/dotnet");
+        std::fs::set_permissions(&dotnet, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+    let path = format!(
+        "{}:{}",
+        temp.path().join("bin").display(),
+        std::env::var("PATH").unwrap_or_default()
+    );
+    (temp, path)
+}
+
+/// Spec: docs/specs/11-test-runners.md#dotnet
+///
+/// > parses the TRX report written for each test project. `Passed` tests
+/// > pass, `NotExecuted`/`Skipped`/`Inconclusive` tests are skipped, and other
+/// > outcomes fail.
+#[test]
+#[cfg(unix)]
+fn dotnet_parses_trx_report() {
+    let (temp, path) = dotnet_project("[[check.tests.suite]]\nrunner = \"dotnet\"\n");
+
+    let result = check("tests").pwd(temp.path()).env("PATH", &path).json().fails();
+    let suites = result.require("metrics").get("suites").and_then(|v| v.as_array()).unwrap();
+
+    assert_eq!(suites[0]["runner"], "dotnet");
+    assert_eq!(suites[0]["test_count"], 3);
+    assert_eq!(suites[0]["passed"], false);
+}
+
+/// Spec: docs/specs/11-test-runners.md#dotnet
+///
+/// > With coverage enabled, `--collect "XPlat Code Coverage"` is added
+/// > [...] line coverage is reported under the `csharp` language key.
+#[test]
+#[cfg(unix)]
+fn dotnet_collects_cobertura_coverage_in_ci() {
+    let (temp, path) = dotnet_project("[[check.tests.suite]]\nrunner = \"dotnet\"\n");
+
+    let result = check("tests").pwd(temp.path()).args(&["--ci"]).env("PATH", &path).json().fails();
+    let coverage = result.require("metrics").get("coverage").and_then(|v| v.as_object()).unwrap();
+
+    assert_eq!(coverage.get("csharp").and_then(|v| v.as_f64()), Some(75.0));
+}
@@ -157,6 +157,28 @@ runner = "cargo"
     assert!(metrics.get("auto_detected").is_none());
 }
 
+/// Spec: docs/specs/11-test-runners.md#deno
+///
+/// > Detected from `deno.json` / `deno.jsonc` (no `package.json` needed).
+#[test]
+fn auto_detects_deno_without_package_json() {
+    let temp = Project::empty();
+    temp.config("[check.tests]\nauto = true");
+    temp.file("deno.json", r#"{"tasks": {}}"#);
+
+    let result = check("tests").pwd(temp.path()).args(&["--ci"]).json().passes();
+    let metrics = result.require("metrics");
+
+    assert_eq!(metrics.get("auto_detected"), Some(&serde_json::json!(true)));
+    let suites = metrics.get("suites").and_then(|s| s.as_array()).expect("suites");
+    assert_eq!(suites.len(), 1);
+    assert_eq!(suites[0].get("runner").and_then(|r| r.as_str()), Some("deno"));
+    assert_eq!(
+        suites[0].get("detection_source").and_then(|v| v.as_str()),
+        Some("config_file:deno.json")
+    );
+}
+
 // =============================================================================
 // NO DETECTION CASES
 // =============================================================================
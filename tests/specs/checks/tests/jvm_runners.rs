@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the Gradle and Maven test runners.
+//!
+//! Reference: docs/specs/11-test-runners.md#gradle
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+/// Stand-in for `gradlew`: writes a JUnit report (one failure) and a JaCoCo report.
+const FAKE_GRADLEW: &str = r#"#!/bin/sh
+mkdir -p build/test-results/test build/reports/jacoco/test
+cat > build/test-results/test/TEST-com.example.AppTest.xml <<XML
+<testsuite name="com.example.AppTest" tests="2" failures="1" time="0.05">
+<testcase classname="com.example.AppTest" name="adds" time="0.01"/>
+<testcase classname="com.example.AppTest" name="divides" time="0.04"><failure message="boom"/></testcase>
+</testsuite>
+XML
+cat > build/reports/jacoco/test/jacocoTestReport.xml <<XML
+<report name="app"><package name="com/example">
+<sourcefile name="App.java"><counter type="LINE" missed="1" covered="3"/></sourcefile>
+</package></report>
+XML
+echo "* What went wrong:" >&2
+echo "Execution failed for task ':test'." >&2
+exit 1
+"#;
+
+/// Gradle project with a fake `gradlew` wrapper.
+fn gradle_project() -> Project {
+    let temp = Project::empty();
+    temp.config("[[check.tests.suite]]\nrunner = \"gradle\"\n");
+    temp.file("build.gradle.kts", "plugins { java }\n");
+    temp.file("gradlew", FAKE_GRADLEW);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let gradlew = temp.path().join("gradlew");
+        std::fs::set_permissions(&gradlew, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+    temp
+}
+
+/// Spec: docs/specs/11-test-runners.md#gradle
+///
+/// > Parses the JUnit XML reports written under `build/test-results/`.
+#[test]
+#[cfg(unix)]
+fn gradle_parses_junit_reports() {
+    let temp = gradle_project();
+
+    let result = check("tests").pwd(temp.path()).json().fails();
+    let suites = result.require("metrics").get("suites").and_then(|v| v.as_array()).unwrap();
+
+    assert_eq!(suites[0]["runner"], "gradle");
+    assert_eq!(suites[0]["test_count"], 2);
+    assert_eq!(suites[0]["passed"], false);
+}
+
+/// Spec: docs/specs/11-test-runners.md#maven
+///
+/// > JaCoCo line coverage is reported under the `jvm` language key.
+#[test]
+#[cfg(unix)]
+fn gradle_collects_jacoco_coverage_in_ci() {
+    let temp = gradle_project();
+
+    let result = check("tests").pwd(temp.path()).args(&["--ci"]).json().fails();
+    let coverage = result.require("metrics").get("coverage").and_then(|v| v.as_object()).unwrap();
+
+    assert_eq!(coverage.get("jvm").and_then(|v| v.as_f64()), Some(75.0));
+}
+
+/// Spec: docs/specs/11-test-runners.md#maven
+///
+/// > Only reports written during the run are read.
+#[test]
+#[cfg(unix)]
+fn maven_ignores_missing_reports() {
+    let temp = Project::empty();
+    temp.config("[[check.tests.suite]]\nrunner = \"maven\"\n");
+    temp.file("pom.xml", "<project/>\n");
+    temp.file("mvnw", "#!/bin/sh\necho '[ERROR] No tests were executed!'\nexit 1\n");
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mvnw = temp.path().join("mvnw");
+        std::fs::set_permissions(&mvnw, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    let result = check("tests").pwd(temp.path()).json().fails();
+    let suites = result.require("metrics").get("suites").and_then(|v| v.as_array()).unwrap();
+
+    assert_eq!(suites[0]["test_count"], 0);
+    assert_eq!(suites[0]["error"], "No tests were executed!");
+}
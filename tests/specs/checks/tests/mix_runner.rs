@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the Mix (`mix test`) runner.
+//!
+//! Reference: docs/specs/11-test-runners.md#mix
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+/// Stand-in for mix: writes a `junit_formatter` report (one failure) and, when
+/// run as `mix coveralls.json`, an excoveralls report.
+const FAKE_MIX: &str = r#"#!/bin/sh
+[ "$1" = "--version" ] && { echo "Mix 1.17.3 (compiled with Erlang/OTP 27)"; exit 0; }
+mkdir -p _build/test/lib/acme
+cat > _build/test/lib/acme/test-junit-report.xml <<XML
+<testsuites><testsuite name="Elixir.Acme.AccountsTest" tests="3" failures="1">
+<testcase classname="Elixir.Acme.AccountsTest" name="test lists users" time="0.010"/>
+<testcase classname="Elixir.Acme.AccountsTest" name="test creates user" time="0.020"><failure message="Assertion with == failed"/></testcase>
+<testcase classname="Elixir.Acme.AccountsTest" name="test sends email" time="0"><skipped/></testcase>
+</testsuite></testsuites>
+XML
+if [ "$1" = "coveralls.json" ]; then
+mkdir -p cover
+cat > cover/excoveralls.json <<JSON
+{"source_files": [{"name": "lib/acme/accounts.ex", "coverage": [null, 1, 1, 1, 0, null]}]}
+JSON
+fi
+echo "3 tests, 1 failure, 1 skipped"
+exit 2
+"#;
+
+/// Mix project with a fake `mix` on PATH; returns the PATH to use.
+fn mix_project(config: &str) -> (Project, String) {
+    let temp = Project::empty();
+    temp.config(config);
+    temp.file("mix.exs", "defmodule Acme.MixProject do\n  use Mix.Project\nend\n");
+    temp.file("lib/acme/accounts.ex", "defmodule Acme.Accounts do\nend\n");
+    temp.file("bin/mix", FAKE_MIX);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mix = temp.path().join("bin/mix");
+        std::fs::set_permissions(&mix, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+    let path = format!(
+        "{}:{}",
+        temp.path().join("bin").display(),
+        std::env::var("PATH").unwrap_or_default()
+    );
+    (temp, path)
+}
+
+/// Spec: docs/specs/11-test-runners.md#mix
+///
+/// > per-test results come from the JUnit reports it writes to
+/// > `_build/test/lib/<app>/test-junit-report.xml`
+#[test]
+#[cfg(unix)]
+fn mix_parses_junit_report() {
+    let (temp, path) = mix_project("[[check.tests.suite]]\nrunner = \"mix\"\n");
+
+    let result = check("tests").pwd(temp.path()).env("PATH", &path).json().fails();
+    let suites = result.require("metrics").get("suites").and_then(|v| v.as_array()).unwrap();
+
+    assert_eq!(suites[0]["runner"], "mix");
+    assert_eq!(suites[0]["test_count"], 3);
+    assert_eq!(suites[0]["passed"], false);
+}
+
+/// Spec: docs/specs/11-test-runners.md#mix
+///
+/// > With coverage enabled, `mix coveralls.json` [...] runs instead of `mix test`
+/// > [...] Line coverage is reported under the `elixir` language key
+#[test]
+#[cfg(unix)]
+fn mix_collects_excoveralls_coverage_in_ci() {
+    let (temp, path) = mix_project("[[check.tests.suite]]\nrunner = \"mix\"\n");
+
+    let result = check("tests").pwd(temp.path()).args(&["--ci"]).env("PATH", &path).json().fails();
+    let coverage = result.require("metrics").get("coverage").and_then(|v| v.as_object()).unwrap();
+
+    assert_eq!(coverage.get("elixir").and_then(|v| v.as_f64()), Some(75.0));
+}
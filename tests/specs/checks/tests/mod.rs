@@ -9,9 +9,19 @@
 mod ci_metrics;
 mod correlation;
 mod coverage;
+mod cpp_runners;
+mod dotnet_runner;
 mod js_runners;
+mod jvm_runners;
+mod mix_runner;
+mod mutation;
 mod output;
+mod parallel;
+mod php_runners;
+mod python_envs;
 mod runners;
+mod selection;
+mod swift_runner;
 mod thresholds;
 mod timeout;
 mod timing;
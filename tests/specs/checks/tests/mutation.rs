@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for mutation testing.
+//!
+//! Reference: docs/specs/11-test-runners.md#mutation-testing
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+/// Stand-in for mutmut: one killed and one surviving mutant in `src/app/core.py`.
+const FAKE_MUTMUT: &str = r#"#!/bin/sh
+mkdir -p mutants/src/app
+printf '{"exit_code_by_key": {"app.core.x_add__mutmut_1": 1, "app.core.x_add__mutmut_2": 0}}' \
+  > mutants/src/app/core.py.meta
+"#;
+
+/// Python project with a passing suite and a fake `mutmut` on PATH; returns the PATH to use.
+fn mutmut_project(mutation: &str) -> (Project, String) {
+    let temp = Project::empty();
+    temp.config(&format!(
+        "[[check.tests.suite]]\nrunner = \"custom\"\ncommand = \"true\"\n\n{mutation}"
+    ));
+    temp.file("pyproject.toml", "[project]\nname = \"app\"\n");
+    temp.file("src/app/core.py", "def add(a, b):\n    return a + b\n");
+    temp.file("bin/mutmut", FAKE_MUTMUT);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mutmut = temp.path().join("bin/mutmut");
+        std::fs::set_permissions(&mutmut, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+    let path = format!(
+        "{}:{}",
+        temp.path().join("bin").display(),
+        std::env::var("PATH").unwrap_or_default()
+    );
+    (temp, path)
+}
+
+/// Spec: docs/specs/11-test-runners.md#mutation-testing
+///
+/// > The mutation score is reported under `mutation`, with a per-package
+/// > breakdown under `mutation_by_package`.
+#[test]
+#[cfg(unix)]
+fn mutation_score_reported_in_ci() {
+    let (temp, path) = mutmut_project("[check.tests.mutation]\n");
+
+    let result = check("tests").pwd(temp.path()).args(&["--ci"]).env("PATH", &path).json().passes();
+    let metrics = result.require("metrics");
+
+    assert_eq!(metrics["mutation"]["tool"], "mutmut");
+    assert_eq!(metrics["mutation"]["score"], 50.0);
+    assert_eq!(metrics["mutation"]["caught"], 1);
+    assert_eq!(metrics["mutation"]["missed"], 1);
+    assert_eq!(metrics["mutation_by_package"]["app"], 50.0);
+}
+
+/// Spec: docs/specs/11-test-runners.md#mutation-testing
+///
+/// > Mutation testing only runs in CI mode.
+#[test]
+#[cfg(unix)]
+fn mutation_skipped_outside_ci() {
+    let (temp, path) = mutmut_project("[check.tests.mutation]\n");
+
+    let result = check("tests").pwd(temp.path()).env("PATH", &path).json().passes();
+
+    assert!(result.require("metrics").get("mutation").is_none());
+}
+
+/// Spec: docs/specs/11-test-runners.md#mutation-testing
+///
+/// > A score below `min` generates a `mutation_below_min` violation.
+#[test]
+#[cfg(unix)]
+fn mutation_below_min_fails() {
+    let (temp, path) = mutmut_project("[check.tests.mutation]\ncheck = \"error\"\nmin = 80\n");
+
+    let result = check("tests").pwd(temp.path()).args(&["--ci"]).env("PATH", &path).json().fails();
+
+    assert!(result.has_violation("mutation_below_min"));
+}
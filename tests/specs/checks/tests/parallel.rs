@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for parallel suite execution.
+//!
+//! Reference: docs/specs/11-test-runners.md#parallel-suites
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+fn suite_names(metrics: &serde_json::Value) -> Vec<String> {
+    metrics
+        .get("suites")
+        .and_then(|v| v.as_array())
+        .unwrap()
+        .iter()
+        .map(|s| s["name"].as_str().unwrap().to_string())
+        .collect()
+}
+
+/// Spec: docs/specs/11-test-runners.md#parallel-suites
+///
+/// > Results are always reported in configuration order, regardless of
+/// > completion order.
+#[test]
+fn parallel_suites_report_in_config_order() {
+    let temp = Project::empty();
+    temp.config(
+        r#"
+[check.tests]
+jobs = 3
+
+[[check.tests.suite]]
+runner = "custom"
+name = "slow"
+command = "sleep 0.3"
+
+[[check.tests.suite]]
+runner = "custom"
+name = "medium"
+command = "sleep 0.1"
+
+[[check.tests.suite]]
+runner = "custom"
+name = "fast"
+command = "true"
+"#,
+    );
+
+    let result = check("tests").pwd(temp.path()).json().passes();
+    assert_eq!(suite_names(result.require("metrics")), ["slow", "medium", "fast"]);
+}
+
+/// Spec: docs/specs/11-test-runners.md#parallel-suites
+///
+/// > `exclusive` suites wait for running suites to finish and hold off others
+/// > until they complete.
+#[test]
+fn exclusive_suite_does_not_overlap_others() {
+    let temp = Project::empty();
+    temp.config(
+        r#"
+[check.tests]
+jobs = 3
+
+[[check.tests.suite]]
+runner = "custom"
+name = "a"
+command = "touch a.running && sleep 0.2 && rm a.running"
+
+[[check.tests.suite]]
+runner = "custom"
+name = "db"
+command = "test ! -e a.running && test ! -e b.running"
+exclusive = true
+
+[[check.tests.suite]]
+runner = "custom"
+name = "b"
+command = "touch b.running && sleep 0.2 && rm b.running"
+"#,
+    );
+
+    check("tests").pwd(temp.path()).args(&["--ci"]).passes();
+}
+
+/// Spec: docs/specs/11-test-runners.md#parallel-suites
+///
+/// > Suites not started before the budget runs out fail.
+#[test]
+fn timeout_budget_fails_suites_not_started() {
+    let temp = Project::empty();
+    temp.config(
+        r#"
+[check.tests]
+jobs = 1
+timeout = "200ms"
+
+[[check.tests.suite]]
+runner = "custom"
+name = "hangs"
+command = "sleep 5"
+
+[[check.tests.suite]]
+runner = "custom"
+name = "later"
+command = "true"
+"#,
+    );
+
+    let result = check("tests").pwd(temp.path()).args(&["--ci"]).json().fails();
+    let suites = result.require("metrics").get("suites").and_then(|v| v.as_array()).unwrap();
+    assert_eq!(suites.len(), 2);
+    assert_eq!(suites[0]["passed"], false);
+    assert!(suites[1]["error"].as_str().unwrap().contains("exhausted"));
+}
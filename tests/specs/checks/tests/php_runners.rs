@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the PHPUnit and Pest runners.
+//!
+//! Reference: docs/specs/11-test-runners.md#phpunit-and-pest
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+/// Stand-in for PHPUnit/Pest: writes a JUnit report (one failure) and, when
+/// coverage is requested, a Clover report.
+const FAKE_PHPUNIT: &str = r#"#!/bin/sh
+while [ $# -gt 0 ]; do
+  case "$1" in
+    --log-junit) junit="$2"; shift ;;
+    --coverage-clover) clover="$2"; shift ;;
+  esac
+  shift
+done
+cat > "$junit" <<XML
+<testsuites><testsuite name="Unit">
+<testcase name="testBoots" classname="Tests.Unit.KernelTest" time="0.010"/>
+<testcase name="testHandles" classname="Tests.Unit.KernelTest" time="0.020"><failure>Failed asserting that false is true.</failure></testcase>
+<testcase name="testNetwork" classname="Tests.Unit.KernelTest" time="0.000"><skipped/></testcase>
+</testsuite></testsuites>
+XML
+if [ -n "$clover" ]; then
+cat > "$clover" <<XML
+<coverage><project><file name="$PWD/src/Kernel.php">
+<line num="3" type="method" name="boot" count="1"/>
+<line num="4" type="stmt" count="1"/>
+<line num="5" type="stmt" count="1"/>
+<line num="6" type="stmt" count="1"/>
+<line num="7" type="stmt" count="0"/>
+</file></project></coverage>
+XML
+fi
+echo "FAILURES!"
+exit 1
+"#;
+
+/// Composer project with a fake `vendor/bin/<tool>`.
+fn php_project(tool: &str, config: &str) -> Project {
+    let temp = Project::empty();
+    temp.config(config);
+    temp.file("composer.json", "{\"name\": \"acme/app\"}\n");
+    temp.file("src/Kernel.php", "<?php\n");
+    temp.file(format!("vendor/bin/{tool}"), FAKE_PHPUNIT);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let bin = temp.path().join("vendor/bin").join(tool);
+        std::fs::set_permissions(&bin, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+    temp
+}
+
+/// Spec: docs/specs/11-test-runners.md#phpunit-and-pest
+///
+/// > Runs the Composer-installed binary when `vendor/bin/<tool>` exists [...]
+/// > Per-test results come from the JUnit report
+#[test]
+#[cfg(unix)]
+fn phpunit_parses_junit_report() {
+    let temp = php_project("phpunit", "[[check.tests.suite]]\nrunner = \"phpunit\"\n");
+
+    let result = check("tests").pwd(temp.path()).json().fails();
+    let suites = result.require("metrics").get("suites").and_then(|v| v.as_array()).unwrap();
+
+    assert_eq!(suites[0]["runner"], "phpunit");
+    assert_eq!(suites[0]["test_count"], 3);
+    assert_eq!(suites[0]["passed"], false);
+}
+
+/// Spec: docs/specs/11-test-runners.md#phpunit-and-pest
+///
+/// > With coverage enabled, `--coverage-clover <tmp>` is added [...]
+/// > Statement coverage is reported under the `php` language key
+#[test]
+#[cfg(unix)]
+fn pest_collects_clover_coverage_in_ci() {
+    let temp = php_project("pest", "[[check.tests.suite]]\nrunner = \"pest\"\n");
+
+    let result = check("tests").pwd(temp.path()).args(&["--ci"]).json().fails();
+    let coverage = result.require("metrics").get("coverage").and_then(|v| v.as_object()).unwrap();
+
+    assert_eq!(coverage.get("php").and_then(|v| v.as_f64()), Some(75.0));
+}
@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for multi-environment Python runners (tox, nox).
+//!
+//! Reference: docs/specs/11-test-runners.md#tox--nox
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+/// Stand-in for tox: lists py310 and py313, and fails `test_compat` on py310.
+const FAKE_TOX: &str = r#"#!/bin/sh
+case "$1" in
+  --version) echo "tox 4.0.0"; exit 0 ;;
+  -l) printf 'py310\npy313\n'; exit 0 ;;
+  -e) env="$2" ;;
+esac
+for arg in "$@"; do
+  case "$arg" in --junitxml=*) out="${arg#--junitxml=}" ;; esac
+done
+fail=""
+if [ "$env" = "py310" ]; then fail='<failure message="boom"/>'; fi
+cat > "$out" <<XML
+<testsuites><testsuite name="pytest">
+<testcase classname="tests.test_app" name="test_ok" time="0.01"/>
+<testcase classname="tests.test_app" name="test_compat" time="0.02">$fail</testcase>
+</testsuite></testsuites>
+XML
+[ -z "$fail" ]
+"#;
+
+/// Project with a fake `tox` on PATH; returns the PATH to use.
+fn tox_project(config: &str) -> (Project, String) {
+    let temp = Project::empty();
+    temp.config(config);
+    temp.file("bin/tox", FAKE_TOX);
+    let tox = temp.path().join("bin/tox");
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&tox, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+    let path = format!(
+        "{}:{}",
+        temp.path().join("bin").display(),
+        std::env::var("PATH").unwrap_or_default()
+    );
+    (temp, path)
+}
+
+/// Spec: docs/specs/11-test-runners.md#tox--nox
+///
+/// > The suite fails if any environment fails.
+#[test]
+#[cfg(unix)]
+fn tox_reports_per_env_results() {
+    let (temp, path) = tox_project("[[check.tests.suite]]\nrunner = \"tox\"\n");
+
+    let result = check("tests").pwd(temp.path()).env("PATH", &path).json().fails();
+    let suites = result.require("metrics").get("suites").and_then(|v| v.as_array()).unwrap();
+    let tox = &suites[0];
+
+    assert_eq!(tox["test_count"], 4);
+    assert_eq!(tox["error"], "failed environments: py310");
+    let envs = tox["envs"].as_array().unwrap();
+    assert_eq!(envs.len(), 2);
+    assert_eq!(envs[0]["name"], "py310");
+    assert_eq!(envs[0]["passed"], false);
+    assert_eq!(envs[1]["name"], "py313");
+    assert_eq!(envs[1]["passed"], true);
+    assert_eq!(envs[1]["test_count"], 2);
+}
+
+/// Spec: docs/specs/11-test-runners.md#tox--nox
+///
+/// > `envs` runs a subset.
+#[test]
+#[cfg(unix)]
+fn tox_runs_configured_env_subset() {
+    let (temp, path) = tox_project("[[check.tests.suite]]\nrunner = \"tox\"\nenvs = [\"py313\"]\n");
+
+    let result = check("tests").pwd(temp.path()).env("PATH", &path).json().passes();
+    let suites = result.require("metrics").get("suites").and_then(|v| v.as_array()).unwrap();
+    let envs = suites[0]["envs"].as_array().unwrap();
+
+    assert_eq!(envs.len(), 1);
+    assert_eq!(envs[0]["name"], "py313");
+}
@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for affected-suite selection.
+//!
+//! Reference: docs/specs/11-test-runners.md#affected-suites
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+use std::process::Command;
+
+/// Initialize a git repo with user config and initial commit.
+fn init_git_repo(path: &std::path::Path) {
+    Command::new("git").args(["init", "-b", "main"]).current_dir(path).output().unwrap();
+    Command::new("git")
+        .args(["config", "user.email", "test@test.com"])
+        .current_dir(path)
+        .output()
+        .unwrap();
+    Command::new("git").args(["config", "user.name", "Test"]).current_dir(path).output().unwrap();
+    Command::new("git").args(["add", "."]).current_dir(path).output().unwrap();
+    Command::new("git")
+        .args(["commit", "-m", "chore: initial commit", "--allow-empty"])
+        .current_dir(path)
+        .output()
+        .unwrap();
+}
+
+/// Add and commit all changes on a new feature branch.
+fn commit_on_branch(path: &std::path::Path, msg: &str) {
+    Command::new("git").args(["checkout", "-b", "feature"]).current_dir(path).output().unwrap();
+    Command::new("git").args(["add", "."]).current_dir(path).output().unwrap();
+    Command::new("git").args(["commit", "-m", msg]).current_dir(path).output().unwrap();
+}
+
+fn two_suite_project() -> Project {
+    let temp = Project::empty();
+    temp.config(
+        r#"
+[check.tests]
+select = "affected"
+
+[[check.tests.suite]]
+runner = "custom"
+name = "api"
+path = "api"
+command = "true"
+
+[[check.tests.suite]]
+runner = "custom"
+name = "web"
+path = "web"
+command = "true"
+"#,
+    );
+    temp.file("api/handler.sh", "echo api\n");
+    temp.file("web/app.sh", "echo web\n");
+    init_git_repo(temp.path());
+    temp
+}
+
+/// Spec: docs/specs/11-test-runners.md#affected-suites
+///
+/// > With `select = "affected"`, only suites whose inputs changed relative
+/// > to `--base` run.
+#[test]
+fn affected_selection_skips_unchanged_suites_with_reason() {
+    let temp = two_suite_project();
+    temp.file("api/handler.sh", "echo api v2\n");
+    commit_on_branch(temp.path(), "feat: update api");
+
+    let result = check("tests").pwd(temp.path()).args(&["--base", "main"]).json().passes();
+    let metrics = result.require("metrics");
+    assert_eq!(metrics.get("select").and_then(|v| v.as_str()), Some("affected"));
+
+    let suites = metrics.get("suites").and_then(|v| v.as_array()).unwrap();
+    let api = suites.iter().find(|s| s["name"] == "api").unwrap();
+    let web = suites.iter().find(|s| s["name"] == "web").unwrap();
+    assert_eq!(api["passed"], true);
+    assert!(api.get("skipped").is_none());
+    assert_eq!(web["skipped"], true);
+    assert_eq!(web["error"], "no changes under web or its dependencies");
+}
+
+/// Spec: docs/specs/11-test-runners.md#affected-suites
+///
+/// > Without changed files, every suite runs.
+#[test]
+fn affected_selection_runs_all_suites_without_base() {
+    let temp = two_suite_project();
+
+    let result = check("tests").pwd(temp.path()).json().passes();
+    let suites = result.require("metrics").get("suites").and_then(|v| v.as_array()).unwrap();
+    assert_eq!(suites.len(), 2);
+    assert!(suites.iter().all(|s| s["passed"] == true && s.get("skipped").is_none()));
+}
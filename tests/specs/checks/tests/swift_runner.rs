@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the SwiftPM (`swift test`) runner.
+//!
+//! Reference: docs/specs/11-test-runners.md#swift
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+/// Stand-in for swift: writes an xUnit report (one failure) and, when coverage
+/// is requested, an llvm-cov JSON export reported by `--show-codecov-path`.
+const FAKE_SWIFT: &str = r#"#!/bin/sh
+[ "$1" = "--version" ] && { echo "Swift version 6.0"; exit 0; }
+codecov="$PWD/.build/debug/codecov/Acme.json"
+[ "$2" = "--show-codecov-path" ] && { echo "$codecov"; exit 0; }
+while [ $# -gt 0 ]; do
+  case "$1" in
+    --xunit-output) report="$2"; shift ;;
+    --enable-code-coverage) coverage=1 ;;
+  esac
+  shift
+done
+cat > "$report" <<XML
+<testsuites><testsuite name="TestResults">
+<testcase classname="AcmeTests.ParserTests" name="testParses" time="0.010"/>
+<testcase classname="AcmeTests.ParserTests" name="testRejects" time="0.020"><failure message="failed"/></testcase>
+<testcase classname="AcmeTests.NetworkTests" name="testFetch" time="0"><skipped/></testcase>
+</testsuite></testsuites>
+XML
+if [ -n "$coverage" ]; then
+root="$(pwd -P)"
+mkdir -p "$(dirname "$codecov")"
+cat > "$codecov" <<JSON
+{"data": [{"files": [
+{"filename": "$root/Sources/Acme/Parser.swift", "summary": {"lines": {"count": 4, "covered": 3, "percent": 75}}},
+{"filename": "$root/Tests/AcmeTests/ParserTests.swift", "summary": {"lines": {"count": 10, "covered": 10, "percent": 100}}}
+], "totals": {"lines": {"count": 14, "covered": 13, "percent": 92.8}}}]}
+JSON
+fi
+echo "error: 1 test failed"
+exit 1
+"#;
+
+/// Swift package with a fake `swift` on PATH; returns the PATH to use.
+fn swift_project(config: &str) -> (Project, String) {
+    let temp = Project::empty();
+    temp.config(config);
+    temp.file("Package.swift", "// swift-tools-version:5.9\nimport PackageDescription\n");
+    temp.file("Sources/Acme/Parser.swift", "public struct Parser {}\n");
+    temp.file("bin/swift", FAKE_SWIFT);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let swift = temp.path().join("bin/swift");
+        std::fs::set_permissions(&swift, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+    let path = format!(
+        "{}:{}",
+        temp.path().join("bin").display(),
+        std::env::var("PATH").unwrap_or_default()
+    );
+    (temp, path)
+}
+
+/// Spec: docs/specs/11-test-runners.md#swift
+///
+/// > Per-test results come from the xUnit report
+#[test]
+#[cfg(unix)]
+fn swift_parses_xunit_report() {
+    let (temp, path) = swift_project("[[check.tests.suite]]\nrunner = \"swift\"\n");
+
+    let result = check("tests").pwd(temp.path()).env("PATH", &path).json().fails();
+    let suites = result.require("metrics").get("suites").and_then(|v| v.as_array()).unwrap();
+
+    assert_eq!(suites[0]["runner"], "swift");
+    assert_eq!(suites[0]["test_count"], 3);
+    assert_eq!(suites[0]["passed"], false);
+}
+
+/// Spec: docs/specs/11-test-runners.md#swift
+///
+/// > With coverage enabled, `--enable-code-coverage` is added [...] Line
+/// > coverage is reported under the `swift` language key for project files
+/// > outside `.build/` and `Tests/`
+#[test]
+#[cfg(unix)]
+fn swift_collects_llvm_cov_coverage_in_ci() {
+    let (temp, path) = swift_project("[[check.tests.suite]]\nrunner = \"swift\"\n");
+
+    let result = check("tests").pwd(temp.path()).args(&["--ci"]).env("PATH", &path).json().fails();
+    let coverage = result.require("metrics").get("coverage").and_then(|v| v.as_object()).unwrap();
+
+    assert_eq!(coverage.get("swift").and_then(|v| v.as_f64()), Some(75.0));
+}
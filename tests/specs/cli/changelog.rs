@@ -0,0 +1,192 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the quench changelog command.
+//!
+//! Tests that quench changelog correctly:
+//! - Groups commits since the latest tag into changelog sections
+//! - Updates CHANGELOG.md idempotently
+//! - Computes the next version from commit types
+//! - Fails in --check mode when entries are missing
+//!
+//! Reference: docs/specs/01-cli.md#quench-changelog
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+fn changelog(project: &Project, args: &[&str]) -> std::process::Output {
+    quench_cmd()
+        .arg("changelog")
+        .args(args)
+        .current_dir(project.path())
+        .output()
+        .expect("command should run")
+}
+
+fn git_tag(project: &Project, name: &str) {
+    std::process::Command::new("git")
+        .args(["tag", name])
+        .current_dir(project.path())
+        .output()
+        .expect("git tag should succeed");
+}
+
+fn read_changelog(project: &Project) -> String {
+    std::fs::read_to_string(project.path().join("CHANGELOG.md")).unwrap()
+}
+
+/// Project with a `v0.1.0` tag followed by a feature, a fix and a chore.
+fn released_project() -> Project {
+    let temp = Project::empty();
+    git_init(&temp);
+    git_commit(&temp, "feat: initial release");
+    git_tag(&temp, "v0.1.0");
+    git_commit(&temp, "feat(api): add export endpoint");
+    git_commit(&temp, "fix: handle empty input");
+    git_commit(&temp, "chore: update dependencies");
+    temp
+}
+
+/// Spec: docs/specs/01-cli.md#quench-changelog
+///
+/// > Update `CHANGELOG.md` from the conventional commits since the latest tag.
+#[test]
+fn changelog_adds_commits_since_latest_tag() {
+    let temp = released_project();
+
+    let output = changelog(&temp, &[]);
+    assert!(output.status.success());
+
+    let content = read_changelog(&temp);
+    assert!(content.starts_with("# Changelog\n"));
+    assert!(content.contains(
+        "## [Unreleased]\n\n### Added\n\n- **api**: Add export endpoint\n\n### Fixed\n\n- Handle empty input\n"
+    ));
+    assert!(!content.contains("Initial release"));
+    assert!(!content.contains("dependencies"));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-changelog
+///
+/// > running again adds only new commits
+#[test]
+fn changelog_is_idempotent() {
+    let temp = released_project();
+    changelog(&temp, &[]);
+    let first = read_changelog(&temp);
+
+    let output = changelog(&temp, &[]);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("CHANGELOG.md is up to date"));
+    assert_eq!(read_changelog(&temp), first);
+
+    git_commit(&temp, "fix: respect timeout");
+    changelog(&temp, &[]);
+    let content = read_changelog(&temp);
+    assert!(content.contains("- Handle empty input\n- Respect timeout\n"));
+    assert_eq!(content.matches("Add export endpoint").count(), 1);
+}
+
+/// Spec: docs/specs/01-cli.md#quench-changelog
+///
+/// > With `auto`, the version after the `--since` tag (or the latest version
+/// > in `CHANGELOG.md`) is bumped: major for breaking changes, minor for
+/// > `feat`, patch otherwise.
+#[test]
+fn changelog_next_version_auto_bumps_from_commits() {
+    let temp = released_project();
+    changelog(&temp, &[]);
+
+    let output = changelog(&temp, &["--next-version", "auto"]);
+    assert!(output.status.success());
+
+    let content = read_changelog(&temp);
+    assert!(content.contains("## [Unreleased]\n\n## [0.2.0] - "));
+    assert!(content.contains("### Added\n\n- **api**: Add export endpoint\n"));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-changelog
+///
+/// > running again adds only new commits
+#[test]
+fn changelog_next_version_auto_is_idempotent() {
+    let temp = Project::empty();
+    git_init(&temp);
+    git_commit(&temp, "feat: initial release");
+
+    changelog(&temp, &["--next-version", "auto"]);
+    let first = read_changelog(&temp);
+    assert!(first.contains("## [0.1.0] - "));
+
+    let output = changelog(&temp, &["--next-version", "auto"]);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("CHANGELOG.md is up to date"));
+    assert_eq!(read_changelog(&temp), first);
+}
+
+/// Spec: docs/specs/01-cli.md#quench-changelog
+///
+/// > bumped: major for breaking changes
+#[test]
+fn changelog_breaking_change_bumps_major_version() {
+    let temp = Project::empty();
+    git_init(&temp);
+    git_commit(&temp, "feat: initial release");
+    git_tag(&temp, "v1.4.2");
+    git_commit(&temp, "feat(api)!: drop v1 endpoints");
+
+    changelog(&temp, &["--next-version", "auto"]);
+
+    let content = read_changelog(&temp);
+    assert!(content.contains("## [2.0.0] - "));
+    assert!(content.contains("### Added\n\n- **api**: Drop v1 endpoints (breaking)\n"));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-changelog
+///
+/// > `--check` writes nothing and exits 1 when commits are missing from
+/// > `CHANGELOG.md`, listing them
+#[test]
+fn changelog_check_fails_on_missing_entries() {
+    let temp = released_project();
+
+    let output = changelog(&temp, &["--check"]);
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("CHANGELOG.md is missing 2 entries:"));
+    assert!(stdout.contains("- **api**: Add export endpoint"));
+    assert!(!temp.path().join("CHANGELOG.md").exists());
+
+    changelog(&temp, &[]);
+    let output = changelog(&temp, &["--check"]);
+    assert!(output.status.success());
+}
+
+/// Spec: docs/specs/01-cli.md#quench-changelog
+///
+/// > quench changelog --since v0.4.0         # Commits since a tag (or any ref)
+#[test]
+fn changelog_since_limits_commits() {
+    let temp = released_project();
+    git_tag(&temp, "v0.2.0");
+    git_commit(&temp, "fix: respect timeout");
+
+    changelog(&temp, &["--since", "v0.2.0"]);
+
+    let content = read_changelog(&temp);
+    assert!(content.contains("- Respect timeout"));
+    assert!(!content.contains("Add export endpoint"));
+}
+
+/// Spec: docs/specs/01-cli.md#exit-codes
+///
+/// > | 2 | Configuration or argument error |
+#[test]
+fn changelog_rejects_invalid_version() {
+    let temp = released_project();
+
+    let output = changelog(&temp, &["--next-version", "next"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid --next-version"));
+}
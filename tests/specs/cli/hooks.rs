@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the quench hooks command.
+//!
+//! Tests that quench hooks install correctly:
+//! - Writes pre-commit and commit-msg hooks
+//! - Chains into existing hooks
+//! - Prints pre-commit framework and lefthook config
+//!
+//! Reference: docs/specs/01-cli.md#quench-hooks-install
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::prelude::*;
+
+fn hooks_install(project: &Project, args: &[&str]) -> std::process::Output {
+    quench_cmd()
+        .args(["hooks", "install"])
+        .args(args)
+        .current_dir(project.path())
+        .output()
+        .expect("command should run")
+}
+
+/// Spec: docs/specs/01-cli.md#quench-hooks-install
+///
+/// > | `pre-commit` | `quench check --staged` |
+/// > | `commit-msg` | `quench check --commit-msg <FILE>` |
+#[test]
+fn hooks_install_writes_git_hooks() {
+    let temp = Project::empty();
+    git_init(&temp);
+
+    let output = hooks_install(&temp, &[]);
+    assert!(output.status.success());
+
+    let pre_commit = std::fs::read_to_string(temp.path().join(".git/hooks/pre-commit")).unwrap();
+    assert!(pre_commit.contains("quench check --staged"));
+    let commit_msg = std::fs::read_to_string(temp.path().join(".git/hooks/commit-msg")).unwrap();
+    assert!(commit_msg.contains("quench check --commit-msg \"$1\""));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-hooks-install
+///
+/// > Hooks are written to `core.hooksPath` when set, otherwise `.git/hooks`.
+#[test]
+fn hooks_install_respects_core_hooks_path() {
+    let temp = Project::empty();
+    git_init(&temp);
+    std::process::Command::new("git")
+        .args(["config", "core.hooksPath", ".githooks"])
+        .current_dir(temp.path())
+        .output()
+        .unwrap();
+
+    assert!(hooks_install(&temp, &[]).status.success());
+    assert!(temp.path().join(".githooks/pre-commit").exists());
+    assert!(!temp.path().join(".git/hooks/pre-commit").exists());
+}
+
+/// Spec: docs/specs/01-cli.md#quench-hooks-install
+///
+/// > An existing hook is moved to `<hook>.pre-quench` and runs first
+#[test]
+fn hooks_install_chains_existing_hook() {
+    let temp = Project::empty();
+    git_init(&temp);
+    temp.file(".git/hooks/pre-commit", "#!/bin/sh\nmake lint\n");
+
+    let output = hooks_install(&temp, &[]);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("pre-commit.pre-quench"));
+
+    let previous =
+        std::fs::read_to_string(temp.path().join(".git/hooks/pre-commit.pre-quench")).unwrap();
+    assert_eq!(previous, "#!/bin/sh\nmake lint\n");
+    let hook = std::fs::read_to_string(temp.path().join(".git/hooks/pre-commit")).unwrap();
+    assert!(hook.contains("pre-commit.pre-quench"));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-hooks-install
+///
+/// > Installing again leaves up-to-date hooks unchanged.
+#[test]
+fn hooks_install_is_idempotent() {
+    let temp = Project::empty();
+    git_init(&temp);
+
+    assert!(hooks_install(&temp, &[]).status.success());
+    let output = hooks_install(&temp, &[]);
+
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("is up to date"));
+    assert!(!temp.path().join(".git/hooks/pre-commit.pre-quench").exists());
+}
+
+/// Spec: docs/specs/01-cli.md#quench-hooks-install
+///
+/// > With `--manager pre-commit` or `--manager lefthook`, nothing is written:
+/// > the equivalent configuration is printed
+#[test]
+fn hooks_install_prints_framework_config() {
+    let temp = Project::empty();
+    git_init(&temp);
+
+    let pre_commit = hooks_install(&temp, &["--manager", "pre-commit"]);
+    let stdout = String::from_utf8_lossy(&pre_commit.stdout);
+    assert!(stdout.contains("repo: local"));
+    assert!(stdout.contains("stages: [commit-msg]"));
+
+    let lefthook = hooks_install(&temp, &["--manager", "lefthook"]);
+    let stdout = String::from_utf8_lossy(&lefthook.stdout);
+    assert!(stdout.contains("run: quench check --commit-msg {1}"));
+
+    assert!(!temp.path().join(".git/hooks/pre-commit").exists());
+}
+
+/// Spec: docs/specs/01-cli.md#quench-hooks-install
+///
+/// > Install git hooks that run quench before each commit.
+#[test]
+fn hooks_install_requires_git_repository() {
+    let temp = Project::empty();
+
+    let output = hooks_install(&temp, &[]);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("not a git repository"));
+}
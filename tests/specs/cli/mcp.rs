@@ -0,0 +1,251 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Behavioral specs for the quench mcp command.
+//!
+//! Tests that quench mcp correctly:
+//! - Speaks JSON-RPC over stdio
+//! - Lists its tools
+//! - Returns check output as structured content
+//!
+//! Reference: docs/specs/01-cli.md#quench-mcp
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::io::Write;
+use std::process::Stdio;
+
+use crate::prelude::*;
+
+/// Send `messages` to `quench mcp` in `dir` and return its responses.
+fn mcp(dir: &std::path::Path, messages: &[serde_json::Value]) -> Vec<serde_json::Value> {
+    let mut child = quench_cmd()
+        .arg("mcp")
+        .current_dir(dir)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("command should run");
+
+    let mut stdin = child.stdin.take().unwrap();
+    for message in messages {
+        writeln!(stdin, "{message}").unwrap();
+    }
+    drop(stdin);
+
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success(), "quench mcp failed: {output:?}");
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("response should be JSON"))
+        .collect()
+}
+
+fn request(id: u32, method: &str, params: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params })
+}
+
+/// Project with one file over the cloc limit.
+fn oversized_project() -> Project {
+    let temp = Project::empty();
+    temp.config("[check.cloc]\nmax_lines = 5\n");
+    temp.file("src/lib.rs", &"let x = 1;\n".repeat(10));
+    temp
+}
+
+// =============================================================================
+// PROTOCOL
+// =============================================================================
+
+/// Spec: docs/specs/01-cli.md#quench-mcp
+///
+/// > Serve quench to coding agents over the Model Context Protocol on stdio.
+#[test]
+fn mcp_initialize_reports_server_and_tools_capability() {
+    let temp = Project::empty();
+    let responses = mcp(
+        temp.path(),
+        &[request(1, "initialize", serde_json::json!({ "protocolVersion": "2025-03-26" }))],
+    );
+
+    let result = &responses[0]["result"];
+    assert_eq!(responses[0]["id"], 1);
+    assert_eq!(result["protocolVersion"], "2025-03-26");
+    assert_eq!(result["serverInfo"]["name"], "quench");
+    assert!(result["capabilities"]["tools"].is_object());
+}
+
+/// Spec: docs/specs/01-cli.md#quench-mcp
+///
+/// > Serve quench to coding agents over the Model Context Protocol on stdio.
+#[test]
+fn mcp_notifications_get_no_response() {
+    let temp = Project::empty();
+    let responses = mcp(
+        temp.path(),
+        &[
+            serde_json::json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }),
+            request(2, "ping", serde_json::json!({})),
+        ],
+    );
+
+    assert_eq!(responses.len(), 1);
+    assert_eq!(responses[0]["id"], 2);
+}
+
+/// Spec: docs/specs/01-cli.md#quench-mcp
+///
+/// > Serve quench to coding agents over the Model Context Protocol on stdio.
+#[test]
+fn mcp_unknown_method_is_an_error() {
+    let temp = Project::empty();
+    let responses = mcp(temp.path(), &[request(1, "resources/list", serde_json::json!({}))]);
+
+    assert_eq!(responses[0]["error"]["code"], -32601);
+}
+
+// =============================================================================
+// TOOLS
+// =============================================================================
+
+/// Spec: docs/specs/01-cli.md#quench-mcp
+///
+/// > | `check` | `paths`, `checks`, `base`, `staged`, `limit` | `quench check -o json` output |
+#[test]
+fn mcp_lists_tools() {
+    let temp = Project::empty();
+    let responses = mcp(temp.path(), &[request(1, "tools/list", serde_json::json!({}))]);
+
+    let names: Vec<&str> = responses[0]["result"]["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|tool| tool["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, ["check", "fix", "report", "explain_violation"]);
+}
+
+/// Spec: docs/specs/01-cli.md#quench-mcp
+///
+/// > Results are returned as structured content matching the
+/// > JSON output schema, so agents read violations, advice, and metrics directly.
+#[test]
+fn mcp_check_returns_structured_violations() {
+    let temp = oversized_project();
+    let responses = mcp(
+        temp.path(),
+        &[request(
+            1,
+            "tools/call",
+            serde_json::json!({ "name": "check", "arguments": { "checks": ["cloc"] } }),
+        )],
+    );
+
+    let result = &responses[0]["result"];
+    assert_eq!(result["isError"], true);
+    let output = &result["structuredContent"];
+    assert_eq!(output["passed"], false);
+    let violation = &output["checks"][0]["violations"][0];
+    assert_eq!(violation["file"], "src/lib.rs");
+    assert_eq!(violation["type"], "file_too_large");
+}
+
+/// Spec: docs/specs/01-cli.md#quench-mcp
+///
+/// > | `explain_violation` | `check`, `type`, `file`, `line` | Advice plus the matching `quench config <check>` guide section |
+#[test]
+fn mcp_explain_violation_includes_advice_and_guide() {
+    let temp = oversized_project();
+    let responses = mcp(
+        temp.path(),
+        &[request(
+            1,
+            "tools/call",
+            serde_json::json!({
+                "name": "explain_violation",
+                "arguments": { "check": "cloc", "type": "file_too_large", "file": "src/lib.rs" },
+            }),
+        )],
+    );
+
+    let output = &responses[0]["result"]["structuredContent"];
+    assert_eq!(output["topic"], "quench config cloc");
+    assert!(output["advice"].as_str().unwrap().contains("split"));
+    assert!(output["guide"].as_str().unwrap().contains("[check.cloc]"));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-mcp
+///
+/// > Serve quench to coding agents over the Model Context Protocol on stdio.
+#[test]
+fn mcp_invalid_tool_arguments_are_tool_errors() {
+    let temp = Project::empty();
+    let responses = mcp(
+        temp.path(),
+        &[request(
+            1,
+            "tools/call",
+            serde_json::json!({ "name": "check", "arguments": { "checks": ["nope"] } }),
+        )],
+    );
+
+    let result = &responses[0]["result"];
+    assert_eq!(result["isError"], true);
+    assert!(result["content"][0]["text"].as_str().unwrap().contains("unknown check `nope`"));
+}
+
+/// Spec: docs/specs/01-cli.md#quench-mcp
+///
+/// > `paths` entries starting with `-` are rejected
+/// > rather than passed to quench as flags.
+#[test]
+fn mcp_flag_like_paths_are_refused() {
+    let temp = oversized_project();
+    let original = std::fs::read_to_string(temp.path().join("src/lib.rs")).unwrap();
+    let responses = mcp(
+        temp.path(),
+        &[
+            request(
+                1,
+                "tools/call",
+                serde_json::json!({ "name": "check", "arguments": { "paths": ["--fix"] } }),
+            ),
+            request(
+                2,
+                "tools/call",
+                serde_json::json!({ "name": "check", "arguments": { "paths": ["--help"] } }),
+            ),
+        ],
+    );
+
+    for response in &responses {
+        let result = &response["result"];
+        assert_eq!(result["isError"], true);
+        assert!(result["content"][0]["text"].as_str().unwrap().contains("must not start with `-`"));
+    }
+    let current = std::fs::read_to_string(temp.path().join("src/lib.rs")).unwrap();
+    assert_eq!(current, original);
+}
+
+/// Spec: docs/specs/01-cli.md#quench-mcp
+///
+/// > | `check` | `paths`, `checks`, `base`, `staged`, `limit` | `quench check -o json` output |
+#[test]
+fn mcp_check_limits_to_paths() {
+    let temp = oversized_project();
+    temp.file("src/small.rs", "fn small() {}\n");
+    let responses = mcp(
+        temp.path(),
+        &[request(
+            1,
+            "tools/call",
+            serde_json::json!({
+                "name": "check",
+                "arguments": { "checks": ["cloc"], "paths": ["src/small.rs"] },
+            }),
+        )],
+    );
+
+    let output = &responses[0]["result"]["structuredContent"];
+    assert_eq!(output["passed"], true);
+}
@@ -214,6 +214,33 @@ fn docs_cache_invalidated_when_target_created() {
         .stdout(predicates::str::contains(r#""passed": true"#));
 }
 
+/// Spec: docs/specs/20-performance.md#p0-file-level-caching-implement-early
+///
+/// > Markdown file changed → re-check all markdown (link anchors depend on
+/// > other files' headings)
+#[test]
+fn docs_cache_revalidates_anchor_links_when_heading_renamed() {
+    let temp = default_project();
+    fs::write(temp.path().join("README.md"), "See [foo](guide.md#foo).\n").unwrap();
+    fs::write(temp.path().join("guide.md"), "# Guide\n\n## Foo\n").unwrap();
+
+    // First run: anchor exists, populates the cache
+    quench_cmd().args(["check", "--docs"]).current_dir(temp.path()).assert().success();
+
+    // Rename the heading; README.md itself is unchanged and cached
+    thread::sleep(Duration::from_millis(10));
+    fs::write(temp.path().join("guide.md"), "# Guide\n\n## Bar\n").unwrap();
+
+    for _ in 0..2 {
+        quench_cmd()
+            .args(["check", "--docs"])
+            .current_dir(temp.path())
+            .assert()
+            .code(1)
+            .stdout(predicates::str::contains("broken_anchor"));
+    }
+}
+
 /// Spec: docs/specs/performance.md#file-caching
 ///
 /// > Docs violations are invalidated when target symlinks are created.